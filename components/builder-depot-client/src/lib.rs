@@ -41,19 +41,20 @@ pub mod error;
 pub use error::{Error, Result};
 
 use std::fmt;
-use std::fs::{self, File};
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use std::string::ToString;
 
 use broadcast::BroadcastWriter;
 use chrono::DateTime;
-use hab_core::package::{Identifiable, PackageArchive};
+use hab_core::package::{Identifiable, PackageArchive, PackageTarget};
 use hab_http::ApiClient;
 use hab_http::util::decoded_response;
 use hyper::client::{Body, IntoUrl, Response, RequestBuilder};
 use hyper::status::StatusCode;
-use hyper::header::{Authorization, Bearer};
+use hyper::header::{Authorization, Bearer, ByteRangeSpec, ContentLength, ContentRange,
+                    ContentRangeSpec, Range};
 use hyper::Url;
 use protobuf::core::ProtobufEnum;
 use protocol::{originsrv, net};
@@ -339,26 +340,34 @@ impl Client {
         Ok(sr)
     }
 
-    /// Schedules a job for a package ident
+    /// Schedules a job for a package ident, built for the given target
     ///
     /// # Failures
     ///
     /// * Key cannot be found
     /// * Remote Builder is not available
-    pub fn schedule_job<I>(&self, ident: &I, package_only: bool, token: &str) -> Result<(String)>
+    pub fn schedule_job<I>(
+        &self,
+        ident: &I,
+        target: &PackageTarget,
+        package_only: bool,
+        token: &str,
+    ) -> Result<(String)>
     where
         I: Identifiable,
     {
-        // TODO (SA): This API needs to be extended to support a target param.
         let path = format!("depot/pkgs/schedule/{}/{}", ident.origin(), ident.name());
-        let result = if package_only {
-            let custom =
-                |url: &mut Url| { url.query_pairs_mut().append_pair("package_only", "true"); };
-            self.add_authz(self.0.post_with_custom_url(&path, custom), token)
-                .send()
-        } else {
-            self.add_authz(self.0.post(&path), token).send()
+        let custom = |url: &mut Url| {
+            url.query_pairs_mut().append_pair(
+                "target",
+                &target.to_string(),
+            );
+            if package_only {
+                url.query_pairs_mut().append_pair("package_only", "true");
+            }
         };
+        let result = self.add_authz(self.0.post_with_custom_url(&path, custom), token)
+            .send();
         match result {
             Ok(response) => {
                 if response.status == StatusCode::Ok {
@@ -604,7 +613,7 @@ impl Client {
         }
     }
 
-    /// Download the latest release of a package.
+    /// Download the latest release of a package, built for the given target.
     ///
     /// By the time this function is called, the ident must be fully qualified. The download URL in
     /// the depot requires a fully qualified ident to work. If you want the latest version of
@@ -612,14 +621,20 @@ impl Client {
     /// e.g. /pkgs/core/redis/latest but that only _shows_ you the details - it doesn't download
     /// the package.
     ///
+    /// The artifact is written to `<dst_path>/<archive name>.part` as it arrives, and only moved
+    /// into place once complete. If the download fails part way, the partial file is kept, and the
+    /// next call asks for just the rest of it.
+    ///
     /// # Failures
     ///
     /// * Package cannot be found
+    /// * Package identifier is not fully qualified
     /// * Remote Builder is not available
     /// * File cannot be created and written to
     pub fn fetch_package<D, I, P>(
         &self,
         ident: &I,
+        target: &PackageTarget,
         token: Option<&str>,
         dst_path: &P,
         progress: Option<D>,
@@ -632,13 +647,27 @@ impl Client {
         // Given that the download URL requires a fully qualified package, the channel is
         // irrelevant, per https://github.com/habitat-sh/habitat/issues/2722. This function is fine
         // as is.
-        match self.download(&package_download(ident), dst_path.as_ref(), token, progress) {
+        let archive_name = hab_core::package::PackageIdent::new(
+            ident.origin(),
+            ident.name(),
+            ident.version(),
+            ident.release(),
+        ).archive_name_with_target(target)
+            .ok_or(Error::IdentNotFullyQualified)?;
+        match self.download_resumable(
+            &package_download(ident),
+            target,
+            dst_path.as_ref(),
+            &archive_name,
+            token,
+            progress,
+        ) {
             Ok(file) => Ok(PackageArchive::new(PathBuf::from(file))),
             Err(e) => Err(e),
         }
     }
 
-    /// Returns a package struct for the latest package.
+    /// Returns a package struct for the latest package built for the given target.
     ///
     /// An optional version can be specified which will scope the release returned to the latest
     /// release of that package.
@@ -651,6 +680,7 @@ impl Client {
         &self,
         package: &I,
         channel: Option<&str>,
+        target: &PackageTarget,
         token: Option<&str>,
     ) -> Result<originsrv::OriginPackage>
     where
@@ -668,7 +698,14 @@ impl Client {
             url.push_str("/latest");
         }
 
-        let mut res = self.maybe_add_authz(self.0.get(&url), token).send()?;
+        let custom = |url: &mut Url| {
+            url.query_pairs_mut().append_pair(
+                "target",
+                &target.to_string(),
+            );
+        };
+        let mut res = self.maybe_add_authz(self.0.get_with_custom_url(&url, custom), token)
+            .send()?;
         if res.status != StatusCode::Ok {
             return Err(err_from_response(res));
         }
@@ -970,6 +1007,101 @@ impl Client {
         Ok(dst_file_path)
     }
 
+    /// Like `download`, but resumes from what an earlier attempt left in the partial file for
+    /// `file_name`, using an HTTP range request. Servers which ignore the range send the whole
+    /// file, and we start over.
+    fn download_resumable<D>(
+        &self,
+        path: &str,
+        target: &PackageTarget,
+        dst_path: &Path,
+        file_name: &str,
+        token: Option<&str>,
+        progress: Option<D>,
+    ) -> Result<PathBuf>
+    where
+        D: DisplayProgress + Sized,
+    {
+        fs::create_dir_all(&dst_path)?;
+        let partial_file_path = dst_path.join(format!("{}.part", file_name));
+        let offset = match fs::metadata(&partial_file_path) {
+            Ok(meta) => meta.len(),
+            Err(_) => 0,
+        };
+        let custom = |url: &mut Url| {
+            url.query_pairs_mut().append_pair(
+                "target",
+                &target.to_string(),
+            );
+        };
+        let mut rb = self.maybe_add_authz(self.0.get_with_custom_url(path, custom), token);
+        if offset > 0 {
+            debug!(
+                "Resuming {} from byte {}",
+                &partial_file_path.display(),
+                offset
+            );
+            rb = rb.header(Range::Bytes(vec![ByteRangeSpec::AllFrom(offset)]));
+        }
+        let mut res = rb.send()?;
+
+        debug!("Response: {:?}", res);
+
+        let resumed = match res.status {
+            StatusCode::Ok => false,
+            StatusCode::PartialContent => {
+                match res.headers.get::<ContentRange>() {
+                    Some(&ContentRange(ContentRangeSpec::Bytes { range: Some((start, _)), .. }))
+                        if start == offset => true,
+                    _ => {
+                        fs::remove_file(&partial_file_path)?;
+                        return Err(Error::DownloadFailed(format!(
+                            "{} sent an unexpected range of {}",
+                            path,
+                            file_name
+                        )));
+                    }
+                }
+            }
+            StatusCode::RangeNotSatisfiable => {
+                // Whatever we have is no good; start over on the next attempt.
+                fs::remove_file(&partial_file_path)?;
+                return Err(Error::DownloadFailed(
+                    format!("could not resume download of {}", file_name),
+                ));
+            }
+            _ => return Err(err_from_response(res)),
+        };
+
+        let dst_file_path = match res.headers.get::<XFileName>() {
+            Some(filename) => dst_path.join(format!("{}", filename)),
+            None => dst_path.join(file_name),
+        };
+        debug!("Writing to {}", &partial_file_path.display());
+        let mut f = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(resumed)
+            .truncate(!resumed)
+            .open(&partial_file_path)?;
+        match progress {
+            Some(mut progress) => {
+                let size: u64 = res.headers.get::<ContentLength>().map_or(0, |v| **v);
+                progress.size(size);
+                let mut writer = BroadcastWriter::new(&mut f, progress);
+                io::copy(&mut res, &mut writer)?
+            }
+            None => io::copy(&mut res, &mut f)?,
+        };
+        debug!(
+            "Moving {} to {}",
+            &partial_file_path.display(),
+            &dst_file_path.display()
+        );
+        fs::rename(&partial_file_path, &dst_file_path)?;
+        Ok(dst_file_path)
+    }
+
     // TODO: Ideally we would have a single download function that can support
     // both progress and non-progress versions, however the Rust compiler cannot
     // infer the type for a None for a Display + Sized trait, and makes this task
@@ -1121,9 +1253,158 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::env;
+    use std::net::TcpListener;
+    use std::thread;
+
+    use rand;
     use serde_json;
     use super::*;
 
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            let path = env::temp_dir().join(format!("depot-client-{}", rand::random::<u64>()));
+            fs::create_dir_all(&path).unwrap();
+            TempDir(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    struct NoProgress;
+
+    impl Write for NoProgress {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl DisplayProgress for NoProgress {
+        fn size(&mut self, _size: u64) {}
+        fn finish(&mut self) {}
+    }
+
+    /// Serves a single canned response on a local port. Returns a client for the server, and a
+    /// handle yielding the request it was sent.
+    fn serve_once(response: &'static str) -> (Client, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let n = stream.read(&mut buf).unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (Client::new(url.as_str(), "test", "0.0.0", None).unwrap(), handle)
+    }
+
+    fn download(client: &Client, dir: &Path) -> Result<PathBuf> {
+        client.download_resumable(
+            "pkgs/x/download",
+            &PackageTarget::default(),
+            dir,
+            "x.hart",
+            None,
+            None::<NoProgress>,
+        )
+    }
+
+    fn read(path: &Path) -> String {
+        let mut content = String::new();
+        File::open(path)
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        content
+    }
+
+    #[test]
+    fn download_resumable_resumes_a_partial_download() {
+        let dir = TempDir::new();
+        File::create(dir.0.join("x.hart.part"))
+            .unwrap()
+            .write_all(b"hello ")
+            .unwrap();
+        let (client, request) = serve_once(
+            "HTTP/1.1 206 Partial Content\r\nContent-Length: 5\r\n\
+             Content-Range: bytes 6-10/11\r\nConnection: close\r\n\r\nworld",
+        );
+
+        let path = download(&client, &dir.0).unwrap();
+        let request = request.join().unwrap();
+        assert!(request.contains("Range: bytes=6-"));
+        assert!(request.contains(&format!("target={}", PackageTarget::default())));
+        assert_eq!(path, dir.0.join("x.hart"));
+        assert_eq!(read(&path), "hello world");
+        assert!(!dir.0.join("x.hart.part").exists());
+    }
+
+    #[test]
+    fn download_resumable_starts_over_when_the_range_is_ignored() {
+        let dir = TempDir::new();
+        File::create(dir.0.join("x.hart.part"))
+            .unwrap()
+            .write_all(b"hello ")
+            .unwrap();
+        let (client, _) = serve_once(
+            "HTTP/1.1 200 OK\r\nContent-Length: 11\r\nConnection: close\r\n\r\nhello world",
+        );
+
+        let path = download(&client, &dir.0).unwrap();
+        assert_eq!(read(&path), "hello world");
+    }
+
+    #[test]
+    fn download_resumable_discards_a_mismatched_range() {
+        let dir = TempDir::new();
+        File::create(dir.0.join("x.hart.part"))
+            .unwrap()
+            .write_all(b"hello ")
+            .unwrap();
+        let (client, _) = serve_once(
+            "HTTP/1.1 206 Partial Content\r\nContent-Length: 5\r\n\
+             Content-Range: bytes 2-6/11\r\nConnection: close\r\n\r\nllo w",
+        );
+
+        assert!(download(&client, &dir.0).is_err());
+        assert!(!dir.0.join("x.hart.part").exists());
+        assert!(!dir.0.join("x.hart").exists());
+    }
+
+    #[test]
+    fn download_resumable_discards_an_unsatisfiable_range() {
+        let dir = TempDir::new();
+        File::create(dir.0.join("x.hart.part"))
+            .unwrap()
+            .write_all(b"hello ")
+            .unwrap();
+        let (client, _) = serve_once(
+            "HTTP/1.1 416 Range Not Satisfiable\r\nContent-Length: 0\r\n\
+             Connection: close\r\n\r\n",
+        );
+
+        assert!(download(&client, &dir.0).is_err());
+        assert!(!dir.0.join("x.hart.part").exists());
+    }
+
     #[test]
     fn json_round_trip_u64_fields() {
         let pre = OriginSecretKey {
@@ -69,6 +69,8 @@ impl Default for Config {
             key_dir: PathBuf::from("/hab/svc/builder-api/files"),
             targets: vec![
                 PackageTarget::new(Platform::Linux, Architecture::X86_64),
+                PackageTarget::new(Platform::LinuxKernel2, Architecture::X86_64),
+                PackageTarget::new(Platform::Linux, Architecture::Aarch64),
                 PackageTarget::new(Platform::Windows, Architecture::X86_64),
             ],
         }
@@ -1001,8 +1001,18 @@ fn schedule(req: &mut Request) -> IronResult<Response> {
     let origin_only = helpers::extract_query_value("origin_only", req).is_some();
     let package_only = helpers::extract_query_value("package_only", req).is_some();
 
-    // We only support building for Linux x64 only currently
-    if target != "x86_64-linux" {
+    // We only build for the targets this depot stores packages for
+    let supported = {
+        let lock = req.get::<persistent::State<DepotUtil>>().expect(
+            "depot not found",
+        );
+        let depot = lock.read().expect("depot read lock is poisoned");
+        match PackageTarget::from_str(&target) {
+            Ok(t) => depot.config.targets.contains(&t),
+            Err(_) => false,
+        }
+    };
+    if !supported {
         info!("Rejecting build with target: {}", target);
         return Ok(Response::with(status::BadRequest));
     }
@@ -1213,7 +1223,10 @@ fn download_package(req: &mut Request) -> IronResult<Response> {
     ident_req.set_visibilities(vis);
     ident_req.set_ident(ident);
 
-    let agent_target = target_from_headers(&req.headers.get::<UserAgent>().unwrap()).unwrap();
+    let agent_target = match target_from_req(req) {
+        Ok(target) => target,
+        Err(response) => return Ok(response),
+    };
     if !depot.config.targets.contains(&agent_target) {
         return Ok(Response::with((
             status::NotImplemented,
@@ -1672,9 +1685,10 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
 
     if let Some(channel) = channel {
         if !qualified {
-            let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap())
-                .unwrap()
-                .to_string();
+            let target = match target_from_req(req) {
+                Ok(target) => target.to_string(),
+                Err(response) => return Ok(response),
+            };
             let mut request = OriginChannelPackageLatestGet::new();
             request.set_name(channel.clone());
             request.set_target(target);
@@ -1709,9 +1723,10 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
         }
     } else {
         if !qualified {
-            let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap())
-                .unwrap()
-                .to_string();
+            let target = match target_from_req(req) {
+                Ok(target) => target.to_string(),
+                Err(response) => return Ok(response),
+            };
             let mut request = OriginPackageLatestGet::new();
             request.set_target(target);
             request.set_visibilities(visibility_for_optional_session(
@@ -1744,7 +1759,10 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
                 let depot = lock.read().expect("depot read lock is poisoned");
 
                 // If we don't have a valid archive on disk, return NotFound
-                let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap()).unwrap();
+                let target = match target_from_req(req) {
+                    Ok(target) => target,
+                    Err(response) => return Ok(response),
+                };
 
                 if !depot.archive(&ident, &target).is_some() {
                     return Ok(Response::with((status::NotFound)));
@@ -2050,12 +2068,26 @@ fn download_content_as_file(content: &[u8], filename: String) -> IronResult<Resp
     Ok(response)
 }
 
+/// The target a request is for: the one given in its `target` query parameter, or else the
+/// client's own, from its user agent.
+fn target_from_req(req: &mut Request) -> result::Result<PackageTarget, Response> {
+    match helpers::extract_query_value("target", req) {
+        Some(target) => {
+            match PackageTarget::from_str(&target) {
+                Ok(t) => Ok(t),
+                Err(_) => Err(Response::with(status::BadRequest)),
+            }
+        }
+        None => target_from_headers(&req.headers.get::<UserAgent>().unwrap()),
+    }
+}
+
 fn target_from_headers(user_agent_header: &UserAgent) -> result::Result<PackageTarget, Response> {
     let user_agent = user_agent_header.as_str();
     debug!("Headers = {}", &user_agent);
 
     let user_agent_regex = Regex::new(
-        r"(?P<client>[^\s]+)\s?(\((?P<target>\w+-\w+); (?P<kernel>.*)\))?",
+        r"(?P<client>[^\s]+)\s?(\((?P<target>\w+-[\w-]+); (?P<kernel>.*)\))?",
     ).unwrap();
     let user_agent_capture = user_agent_regex.captures(user_agent).expect(
         "Invalid user agent supplied.",
@@ -2086,7 +2118,10 @@ where
         "depot not found",
     );
     let depot = lock.read().expect("depot read lock is poisoned");
-    let agent_target = target_from_headers(&req.headers.get::<UserAgent>().unwrap()).unwrap();
+    let agent_target = match target_from_req(req) {
+        Ok(target) => target,
+        Err(_) => return false,
+    };
 
     match depot.archive(ident, &agent_target) {
         Some(mut archive) => archive.is_a_service(),
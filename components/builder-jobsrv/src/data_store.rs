@@ -95,6 +95,12 @@ impl DataStore {
             None
         };
 
+        let target = if job.has_target() {
+            Some(job.get_target())
+        } else {
+            None
+        };
+
         if job.get_project().get_vcs_type() == "git" {
             let project = job.get_project();
             let install_id: Option<String> = {
@@ -106,7 +112,7 @@ impl DataStore {
             };
 
             let rows = conn.query(
-                "SELECT * FROM insert_job_v2($1, $2, $3, $4, $5, $6, $7, $8)",
+                "SELECT * FROM insert_job_v3($1, $2, $3, $4, $5, $6, $7, $8, $9)",
                 &[
                     &(job.get_owner_id() as i64),
                     &(project.get_id() as i64),
@@ -116,6 +122,7 @@ impl DataStore {
                     &project.get_vcs_type(),
                     &vec![Some(project.get_vcs_data().to_string()), install_id],
                     &channel,
+                    &target,
                 ],
             ).map_err(Error::JobCreate)?;
             let job = row_to_job(&rows.get(0))?;
@@ -534,8 +541,13 @@ impl DataStore {
             project_tuples.iter().cloned().unzip();
 
         let rows = conn.query(
-            "SELECT * FROM insert_group_v2($1, $2, $3)",
-            &[&root_project, &project_names, &project_idents],
+            "SELECT * FROM insert_group_v3($1, $2, $3, $4)",
+            &[
+                &root_project,
+                &project_names,
+                &project_idents,
+                &msg.get_target(),
+            ],
         ).map_err(Error::JobGroupCreate)?;
 
         let mut group = self.row_to_job_group(&rows.get(0))?;
@@ -546,6 +558,7 @@ impl DataStore {
             project.set_name(name);
             project.set_ident(ident);
             project.set_state(jobsrv::JobGroupProjectState::NotStarted);
+            project.set_target(msg.get_target().to_string());
             projects.push(project);
         }
 
@@ -689,6 +702,10 @@ impl DataStore {
         project.set_state(project_state);
         project.set_job_id(job_id as u64);
 
+        if let Some(Ok(target)) = row.get_opt::<&str, String>("target") {
+            project.set_target(target);
+        };
+
         Ok(project)
     }
 
@@ -946,5 +963,9 @@ fn row_to_job(row: &postgres::rows::Row) -> Result<jobsrv::Job> {
         job.set_worker(worker);
     };
 
+    if let Some(Ok(target)) = row.get_opt::<&str, String>("target") {
+        job.set_target(target);
+    };
+
     Ok(job)
 }
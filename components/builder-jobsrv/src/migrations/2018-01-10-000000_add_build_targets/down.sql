@@ -0,0 +1,4 @@
+DROP FUNCTION IF EXISTS insert_group_v3(text, text[], text[], text);
+ALTER TABLE group_projects DROP COLUMN IF EXISTS target;
+DROP FUNCTION IF EXISTS insert_job_v3(bigint, bigint, text, bigint, text, text, text[], text, text);
+ALTER TABLE jobs DROP COLUMN IF EXISTS target;
@@ -0,0 +1,38 @@
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS target TEXT DEFAULT NULL;
+
+CREATE OR REPLACE FUNCTION insert_job_v3 (
+  p_owner_id bigint,
+  p_project_id bigint,
+  p_project_name text,
+  p_project_owner_id bigint,
+  p_project_plan_path text,
+  p_vcs text,
+  p_vcs_arguments text[],
+  p_channel text,
+  p_target text
+  ) RETURNS SETOF jobs AS $$
+      INSERT INTO jobs (owner_id, job_state, project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, channel, target)
+      VALUES (p_owner_id, 'Pending', p_project_id, p_project_name, p_project_owner_id, p_project_plan_path, p_vcs, p_vcs_arguments, p_channel, p_target)
+      RETURNING *;
+$$ LANGUAGE SQL VOLATILE;
+
+ALTER TABLE group_projects ADD COLUMN IF NOT EXISTS target TEXT DEFAULT NULL;
+
+CREATE OR REPLACE FUNCTION insert_group_v3 (
+  root_project text,
+  project_names text[],
+  project_idents text[],
+  project_target text
+) RETURNS SETOF groups
+  LANGUAGE SQL
+  VOLATILE AS $$
+  WITH my_group AS (
+          INSERT INTO groups (project_name, group_state)
+          VALUES (root_project, 'Queued') RETURNING *
+      ), my_project AS (
+          INSERT INTO group_projects (owner_id, project_name, project_ident, project_state, target)
+          SELECT g.id, project_info.name, project_info.ident, 'NotStarted', project_target
+          FROM my_group AS g, unnest(project_names, project_idents) AS project_info(name, ident)
+      )
+  SELECT * FROM my_group;
+$$;
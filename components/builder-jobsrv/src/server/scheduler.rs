@@ -217,7 +217,7 @@ impl ScheduleMgr {
 
             assert!(project.get_state() == jobsrv::JobGroupProjectState::NotStarted);
 
-            match self.schedule_job(group.get_id(), project.get_name()) {
+            match self.schedule_job(group.get_id(), project.get_name(), project.get_target()) {
                 Ok(job_opt) => {
                     match job_opt {
                         Some(job) => self.datastore.set_job_group_job_state(&job).unwrap(),
@@ -380,7 +380,12 @@ impl ScheduleMgr {
         Ok(skipped.keys().map(|s| s.to_string()).collect())
     }
 
-    fn schedule_job(&mut self, group_id: u64, project_name: &str) -> Result<Option<jobsrv::Job>> {
+    fn schedule_job(
+        &mut self,
+        group_id: u64,
+        project_name: &str,
+        target: &str,
+    ) -> Result<Option<jobsrv::Job>> {
         let mut project_get = originsrv::OriginProjectGet::new();
         project_get.set_name(String::from(project_name));
 
@@ -412,6 +417,9 @@ impl ScheduleMgr {
         job_spec.set_owner_id(group_id);
         job_spec.set_project(project);
         job_spec.set_channel(bldr_channel_name(group_id));
+        if !target.is_empty() {
+            job_spec.set_target(target.to_string());
+        }
 
         let mut job: jobsrv::Job = job_spec.into();
         match self.datastore.create_job(&mut job) {
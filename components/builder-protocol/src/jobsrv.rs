@@ -39,6 +39,9 @@ impl Into<Job> for JobSpec {
         if self.has_channel() {
             job.set_channel(self.take_channel());
         }
+        if self.has_target() {
+            job.set_target(self.take_target());
+        }
         job
     }
 }
@@ -99,7 +102,7 @@ impl Serialize for Job {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("job", 10)?;
+        let mut strukt = serializer.serialize_struct("job", 11)?;
 
         // Technically, an ID is a 64-bit integer, but that can cause
         // issues when processing it in JavaScript on the front-end,
@@ -160,6 +163,10 @@ impl Serialize for Job {
             strukt.serialize_field("channel", self.get_channel())?;
         }
 
+        if self.has_target() {
+            strukt.serialize_field("target", self.get_target())?;
+        }
+
         strukt.end()
     }
 }
@@ -771,6 +771,7 @@ pub struct Job {
     channel: ::protobuf::SingularField<::std::string::String>,
     project_integrations: ::protobuf::RepeatedField<super::originsrv::OriginProjectIntegration>,
     worker: ::protobuf::SingularField<::std::string::String>,
+    target: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -1310,6 +1311,50 @@ impl Job {
     fn mut_worker_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
         &mut self.worker
     }
+
+    // optional string target = 16;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        self.target.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_target(&self) -> &str {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.target
+    }
+
+    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.target
+    }
 }
 
 impl ::protobuf::Message for Job {
@@ -1404,6 +1449,9 @@ impl ::protobuf::Message for Job {
                 15 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.worker)?;
                 },
+                16 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1463,6 +1511,9 @@ impl ::protobuf::Message for Job {
         if let Some(ref v) = self.worker.as_ref() {
             my_size += ::protobuf::rt::string_size(15, &v);
         }
+        if let Some(ref v) = self.target.as_ref() {
+            my_size += ::protobuf::rt::string_size(16, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1521,6 +1572,9 @@ impl ::protobuf::Message for Job {
         if let Some(ref v) = self.worker.as_ref() {
             os.write_string(15, &v)?;
         }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_string(16, &v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1635,6 +1689,11 @@ impl ::protobuf::MessageStatic for Job {
                     Job::get_worker_for_reflect,
                     Job::mut_worker_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "target",
+                    Job::get_target_for_reflect,
+                    Job::mut_target_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Job>(
                     "Job",
                     fields,
@@ -1661,6 +1720,7 @@ impl ::protobuf::Clear for Job {
         self.clear_channel();
         self.clear_project_integrations();
         self.clear_worker();
+        self.clear_target();
         self.unknown_fields.clear();
     }
 }
@@ -1856,6 +1916,7 @@ pub struct JobSpec {
     owner_id: ::std::option::Option<u64>,
     project: ::protobuf::SingularPtrField<super::originsrv::OriginProject>,
     channel: ::protobuf::SingularField<::std::string::String>,
+    target: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -1990,6 +2051,50 @@ impl JobSpec {
     fn mut_channel_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
         &mut self.channel
     }
+
+    // optional string target = 4;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        self.target.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_target(&self) -> &str {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.target
+    }
+
+    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.target
+    }
 }
 
 impl ::protobuf::Message for JobSpec {
@@ -2019,6 +2124,9 @@ impl ::protobuf::Message for JobSpec {
                 3 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.channel)?;
                 },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2041,6 +2149,9 @@ impl ::protobuf::Message for JobSpec {
         if let Some(ref v) = self.channel.as_ref() {
             my_size += ::protobuf::rt::string_size(3, &v);
         }
+        if let Some(ref v) = self.target.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2058,6 +2169,9 @@ impl ::protobuf::Message for JobSpec {
         if let Some(ref v) = self.channel.as_ref() {
             os.write_string(3, &v)?;
         }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_string(4, &v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2117,6 +2231,11 @@ impl ::protobuf::MessageStatic for JobSpec {
                     JobSpec::get_channel_for_reflect,
                     JobSpec::mut_channel_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "target",
+                    JobSpec::get_target_for_reflect,
+                    JobSpec::mut_target_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<JobSpec>(
                     "JobSpec",
                     fields,
@@ -2132,6 +2251,7 @@ impl ::protobuf::Clear for JobSpec {
         self.clear_owner_id();
         self.clear_project();
         self.clear_channel();
+        self.clear_target();
         self.unknown_fields.clear();
     }
 }
@@ -8163,7 +8283,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x0e2\x13.jobsrv.WorkerStateR\x05state\"[\n\nBusyWorker\x12\x14\n\x05ide\
     nt\x18\x01\x20\x01(\tR\x05ident\x12\x15\n\x06job_id\x18\x02\x20\x01(\x04\
     R\x05jobId\x12\x20\n\x0bquarantined\x18\x03\x20\x01(\x08R\x0bquarantined\
-    \"\xe6\x04\n\x03Job\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\x19\
+    \"\xfe\x04\n\x03Job\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\x19\
     \n\x08owner_id\x18\x02\x20\x01(\x04R\x07ownerId\x12&\n\x05state\x18\x03\
     \x20\x01(\x0e2\x10.jobsrv.JobStateR\x05state\x122\n\x07project\x18\x04\
     \x20\x01(\x0b2\x18.originsrv.OriginProjectR\x07project\x12#\n\x05error\
@@ -8176,465 +8296,475 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x0b2\x1c.originsrv.OriginIntegrationR\x0cintegrations\x12\x18\n\x07chan\
     nel\x18\r\x20\x01(\tR\x07channel\x12V\n\x14project_integrations\x18\x0e\
     \x20\x03(\x0b2#.originsrv.OriginProjectIntegrationR\x13projectIntegratio\
-    ns\x12\x16\n\x06worker\x18\x0f\x20\x01(\tR\x06workerJ\x04\x08\n\x10\x0bR\
-    \x07log_url\"\x18\n\x06JobGet\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02i\
-    d\"r\n\x07JobSpec\x12\x19\n\x08owner_id\x18\x01\x20\x01(\x04R\x07ownerId\
-    \x122\n\x07project\x18\x02\x20\x01(\x0b2\x18.originsrv.OriginProjectR\
-    \x07project\x12\x18\n\x07channel\x18\x03\x20\x01(\tR\x07channel\"N\n\x0e\
-    ProjectJobsGet\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x14\n\
-    \x05start\x18\x02\x20\x01(\x04R\x05start\x12\x12\n\x04stop\x18\x03\x20\
-    \x01(\x04R\x04stop\"y\n\x16ProjectJobsGetResponse\x12\x1f\n\x04jobs\x18\
-    \x01\x20\x03(\x0b2\x0b.jobsrv.JobR\x04jobs\x12\x14\n\x05start\x18\x02\
-    \x20\x01(\x04R\x05start\x12\x12\n\x04stop\x18\x03\x20\x01(\x04R\x04stop\
-    \x12\x14\n\x05count\x18\x04\x20\x01(\x04R\x05count\"P\n\x0bJobLogChunk\
-    \x12\x15\n\x06job_id\x18\x01\x20\x01(\x04R\x05jobId\x12\x10\n\x03seq\x18\
-    \x02\x20\x01(\x04R\x03seq\x12\x18\n\x07content\x18\x03\x20\x01(\tR\x07co\
-    ntent\"'\n\x0eJobLogComplete\x12\x15\n\x06job_id\x18\x01\x20\x01(\x04R\
-    \x05jobId\"1\n\tJobLogGet\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\
-    \x12\x14\n\x05start\x18\x02\x20\x01(\x04R\x05start\"m\n\x06JobLog\x12\
-    \x14\n\x05start\x18\x01\x20\x01(\x04R\x05start\x12\x12\n\x04stop\x18\x02\
-    \x20\x01(\x04R\x04stop\x12\x18\n\x07content\x18\x03\x20\x03(\tR\x07conte\
-    nt\x12\x1f\n\x0bis_complete\x18\x04\x20\x01(\x08R\nisComplete\"\xb9\x01\
-    \n\x0cJobGroupSpec\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06origin\x12\
-    \x18\n\x07package\x18\x02\x20\x01(\tR\x07package\x12\x1b\n\tdeps_only\
-    \x18\x03\x20\x01(\x08R\x08depsOnly\x12\x16\n\x06target\x18\x04\x20\x01(\
-    \tR\x06target\x12\x1f\n\x0borigin_only\x18\x05\x20\x01(\x08R\noriginOnly\
-    \x12!\n\x0cpackage_only\x18\x06\x20\x01(\x08R\x0bpackageOnly\"\x9e\x01\n\
-    \x0fJobGroupProject\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\
-    \x14\n\x05ident\x18\x02\x20\x01(\tR\x05ident\x122\n\x05state\x18\x03\x20\
-    \x01(\x0e2\x1c.jobsrv.JobGroupProjectStateR\x05state\x12\x15\n\x06job_id\
-    \x18\x04\x20\x01(\x04R\x05jobId\x12\x16\n\x06target\x18\x05\x20\x01(\tR\
-    \x06target\"*\n\rJobGroupAbort\x12\x19\n\x08group_id\x18\x01\x20\x01(\
-    \x04R\x07groupId\"+\n\x0eJobGroupCancel\x12\x19\n\x08group_id\x18\x01\
-    \x20\x01(\x04R\x07groupId\"(\n\x0bJobGroupGet\x12\x19\n\x08group_id\x18\
-    \x01\x20\x01(\x04R\x07groupId\"+\n\x11JobGroupOriginGet\x12\x16\n\x06ori\
-    gin\x18\x01\x20\x01(\tR\x06origin\"I\n\x16JobGroupOriginResponse\x12/\n\
-    \njob_groups\x18\x01\x20\x03(\x0b2\x10.jobsrv.JobGroupR\tjobGroups\"\xbe\
-    \x01\n\x08JobGroup\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12+\n\
-    \x05state\x18\x02\x20\x01(\x0e2\x15.jobsrv.JobGroupStateR\x05state\x123\
-    \n\x08projects\x18\x03\x20\x03(\x0b2\x17.jobsrv.JobGroupProjectR\x08proj\
-    ects\x12\x1d\n\ncreated_at\x18\x04\x20\x01(\tR\tcreatedAt\x12!\n\x0cproj\
-    ect_name\x18\x05\x20\x01(\tR\x0bprojectName\"S\n\x0fJobGraphPackage\x12\
-    \x14\n\x05ident\x18\x01\x20\x01(\tR\x05ident\x12\x12\n\x04deps\x18\x02\
-    \x20\x03(\tR\x04deps\x12\x16\n\x06target\x18\x03\x20\x01(\tR\x06target\"\
-    \\\n\x18JobGraphPackagePreCreate\x12\x14\n\x05ident\x18\x01\x20\x01(\tR\
-    \x05ident\x12\x12\n\x04deps\x18\x02\x20\x03(\tR\x04deps\x12\x16\n\x06tar\
-    get\x18\x03\x20\x01(\tR\x06target\"Y\n\x15JobGraphPackageCreate\x12\x14\
-    \n\x05ident\x18\x01\x20\x01(\tR\x05ident\x12\x12\n\x04deps\x18\x02\x20\
-    \x03(\tR\x04deps\x12\x16\n\x06target\x18\x03\x20\x01(\tR\x06target\"k\n%\
-    JobGraphPackageReverseDependenciesGet\x12\x16\n\x06origin\x18\x01\x20\
-    \x01(\tR\x06origin\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x16\
-    \n\x06target\x18\x03\x20\x01(\tR\x06target\"f\n\"JobGraphPackageReverseD\
-    ependencies\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06origin\x12\x12\n\
-    \x04name\x18\x02\x20\x01(\tR\x04name\x12\x14\n\x05rdeps\x18\x03\x20\x03(\
-    \tR\x05rdeps\"1\n\x17JobGraphPackageStatsGet\x12\x16\n\x06origin\x18\x01\
-    \x20\x01(\tR\x06origin\"m\n\x14JobGraphPackageStats\x12\x14\n\x05plans\
-    \x18\x01\x20\x01(\x04R\x05plans\x12\x16\n\x06builds\x18\x02\x20\x01(\x04\
-    R\x06builds\x12'\n\x0funique_packages\x18\x03\x20\x01(\x04R\x0euniquePac\
-    kages*(\n\x02Os\x12\t\n\x05Linux\x10\x01\x12\n\n\x06Darwin\x10\x02\x12\
-    \x0b\n\x07Windows\x10\x03*\"\n\x0bWorkerState\x12\t\n\x05Ready\x10\0\x12\
-    \x08\n\x04Busy\x10\x01*.\n\x0fWorkerOperation\x12\x0c\n\x08StartJob\x10\
-    \0\x12\r\n\tCancelJob\x10\x01*\x9c\x01\n\x08JobState\x12\x0b\n\x07Pendin\
-    g\x10\0\x12\x0e\n\nProcessing\x10\x01\x12\x0c\n\x08Complete\x10\x02\x12\
-    \x0c\n\x08Rejected\x10\x03\x12\n\n\x06Failed\x10\x04\x12\x0e\n\nDispatch\
-    ed\x10\x05\x12\x11\n\rCancelPending\x10\x06\x12\x14\n\x10CancelProcessin\
-    g\x10\x07\x12\x12\n\x0eCancelComplete\x10\x08*k\n\x14JobGroupProjectStat\
-    e\x12\x0e\n\nNotStarted\x10\0\x12\x0e\n\nInProgress\x10\x01\x12\x0b\n\
-    \x07Success\x10\x02\x12\x0b\n\x07Failure\x10\x03\x12\x0b\n\x07Skipped\
-    \x10\x04\x12\x0c\n\x08Canceled\x10\x05*\x7f\n\rJobGroupState\x12\x10\n\
-    \x0cGroupPending\x10\0\x12\x14\n\x10GroupDispatching\x10\x01\x12\x11\n\r\
-    GroupComplete\x10\x02\x12\x0f\n\x0bGroupFailed\x10\x03\x12\x0f\n\x0bGrou\
-    pQueued\x10\x04\x12\x11\n\rGroupCanceled\x10\x05J\xff?\n\x07\x12\x05\0\0\
-    \xd6\x01\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x01\
-    \x08\x0e\n\t\n\x02\x03\0\x12\x03\x02\x07\x1c\n\t\n\x02\x03\x01\x12\x03\
-    \x03\x07\"\n\n\n\x02\x05\0\x12\x04\x05\0\t\x01\n\n\n\x03\x05\0\x01\x12\
-    \x03\x05\x05\x07\n\x0b\n\x04\x05\0\x02\0\x12\x03\x06\x02\x0c\n\x0c\n\x05\
-    \x05\0\x02\0\x01\x12\x03\x06\x02\x07\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\
-    \x06\n\x0b\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x07\x02\r\n\x0c\n\x05\x05\0\
-    \x02\x01\x01\x12\x03\x07\x02\x08\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\
-    \x07\x0b\x0c\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x08\x02\x0e\n\x0c\n\x05\
-    \x05\0\x02\x02\x01\x12\x03\x08\x02\t\n\x0c\n\x05\x05\0\x02\x02\x02\x12\
-    \x03\x08\x0c\r\n\n\n\x02\x05\x01\x12\x04\x0b\0\x0e\x01\n\n\n\x03\x05\x01\
-    \x01\x12\x03\x0b\x05\x10\n\x0b\n\x04\x05\x01\x02\0\x12\x03\x0c\x02\x0c\n\
-    \x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x0c\x02\x07\n\x0c\n\x05\x05\x01\x02\
-    \0\x02\x12\x03\x0c\n\x0b\n\x0b\n\x04\x05\x01\x02\x01\x12\x03\r\x02\x0b\n\
-    \x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\r\x02\x06\n\x0c\n\x05\x05\x01\x02\
-    \x01\x02\x12\x03\r\t\n\n\n\n\x02\x05\x02\x12\x04\x10\0\x13\x01\n\n\n\x03\
-    \x05\x02\x01\x12\x03\x10\x05\x14\n\x0b\n\x04\x05\x02\x02\0\x12\x03\x11\
-    \x02\x0f\n\x0c\n\x05\x05\x02\x02\0\x01\x12\x03\x11\x02\n\n\x0c\n\x05\x05\
-    \x02\x02\0\x02\x12\x03\x11\r\x0e\n\x0b\n\x04\x05\x02\x02\x01\x12\x03\x12\
-    \x02\x10\n\x0c\n\x05\x05\x02\x02\x01\x01\x12\x03\x12\x02\x0b\n\x0c\n\x05\
-    \x05\x02\x02\x01\x02\x12\x03\x12\x0e\x0f\n\n\n\x02\x05\x03\x12\x04\x15\0\
-    \x1f\x01\n\n\n\x03\x05\x03\x01\x12\x03\x15\x05\r\n\x0b\n\x04\x05\x03\x02\
-    \0\x12\x03\x16\x02\x0e\n\x0c\n\x05\x05\x03\x02\0\x01\x12\x03\x16\x02\t\n\
-    \x0c\n\x05\x05\x03\x02\0\x02\x12\x03\x16\x0c\r\n\x0b\n\x04\x05\x03\x02\
-    \x01\x12\x03\x17\x02\x11\n\x0c\n\x05\x05\x03\x02\x01\x01\x12\x03\x17\x02\
-    \x0c\n\x0c\n\x05\x05\x03\x02\x01\x02\x12\x03\x17\x0f\x10\n\x0b\n\x04\x05\
-    \x03\x02\x02\x12\x03\x18\x02\x0f\n\x0c\n\x05\x05\x03\x02\x02\x01\x12\x03\
-    \x18\x02\n\n\x0c\n\x05\x05\x03\x02\x02\x02\x12\x03\x18\r\x0e\n\x0b\n\x04\
-    \x05\x03\x02\x03\x12\x03\x19\x02\x0f\n\x0c\n\x05\x05\x03\x02\x03\x01\x12\
-    \x03\x19\x02\n\n\x0c\n\x05\x05\x03\x02\x03\x02\x12\x03\x19\r\x0e\n\x0b\n\
-    \x04\x05\x03\x02\x04\x12\x03\x1a\x02\r\n\x0c\n\x05\x05\x03\x02\x04\x01\
-    \x12\x03\x1a\x02\x08\n\x0c\n\x05\x05\x03\x02\x04\x02\x12\x03\x1a\x0b\x0c\
-    \n\x0b\n\x04\x05\x03\x02\x05\x12\x03\x1b\x02\x11\n\x0c\n\x05\x05\x03\x02\
-    \x05\x01\x12\x03\x1b\x02\x0c\n\x0c\n\x05\x05\x03\x02\x05\x02\x12\x03\x1b\
-    \x0f\x10\n\x0b\n\x04\x05\x03\x02\x06\x12\x03\x1c\x02\x14\n\x0c\n\x05\x05\
-    \x03\x02\x06\x01\x12\x03\x1c\x02\x0f\n\x0c\n\x05\x05\x03\x02\x06\x02\x12\
-    \x03\x1c\x12\x13\n\x0b\n\x04\x05\x03\x02\x07\x12\x03\x1d\x02\x17\n\x0c\n\
-    \x05\x05\x03\x02\x07\x01\x12\x03\x1d\x02\x12\n\x0c\n\x05\x05\x03\x02\x07\
-    \x02\x12\x03\x1d\x15\x16\n\x0b\n\x04\x05\x03\x02\x08\x12\x03\x1e\x02\x15\
-    \n\x0c\n\x05\x05\x03\x02\x08\x01\x12\x03\x1e\x02\x10\n\x0c\n\x05\x05\x03\
-    \x02\x08\x02\x12\x03\x1e\x13\x14\n\n\n\x02\x04\0\x12\x04!\0#\x01\n\n\n\
-    \x03\x04\0\x01\x12\x03!\x08\x15\n\x0b\n\x04\x04\0\x02\0\x12\x03\"\x02\"\
-    \n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\"\x02\n\n\x0c\n\x05\x04\0\x02\0\x06\
-    \x12\x03\"\x0b\x1a\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\"\x1b\x1d\n\x0c\n\
-    \x05\x04\0\x02\0\x03\x12\x03\"\x20!\n\n\n\x02\x04\x01\x12\x04%\0)\x01\n\
-    \n\n\x03\x04\x01\x01\x12\x03%\x08\x11\n\x0b\n\x04\x04\x01\x02\0\x12\x03&\
-    \x02\x1f\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03&\x02\n\n\x0c\n\x05\x04\
-    \x01\x02\0\x05\x12\x03&\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03&\
-    \x12\x1a\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03&\x1d\x1e\n\x0b\n\x04\x04\
-    \x01\x02\x01\x12\x03'\x02\x15\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03'\
-    \x02\n\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03'\x0b\r\n\x0c\n\x05\x04\
-    \x01\x02\x01\x01\x12\x03'\x0e\x10\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\
-    \x03'\x13\x14\n\x0b\n\x04\x04\x01\x02\x02\x12\x03(\x02!\n\x0c\n\x05\x04\
-    \x01\x02\x02\x04\x12\x03(\x02\n\n\x0c\n\x05\x04\x01\x02\x02\x06\x12\x03(\
-    \x0b\x16\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03(\x17\x1c\n\x0c\n\x05\
-    \x04\x01\x02\x02\x03\x12\x03(\x1f\x20\n\n\n\x02\x04\x02\x12\x04+\0/\x01\
-    \n\n\n\x03\x04\x02\x01\x12\x03+\x08\x12\n\x0b\n\x04\x04\x02\x02\0\x12\
-    \x03,\x02\x1c\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03,\x02\n\n\x0c\n\x05\
-    \x04\x02\x02\0\x05\x12\x03,\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\
-    \x03,\x12\x17\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03,\x1a\x1b\n\x0b\n\x04\
-    \x04\x02\x02\x01\x12\x03-\x02\x1d\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\
-    \x03-\x02\n\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03-\x0b\x11\n\x0c\n\x05\
-    \x04\x02\x02\x01\x01\x12\x03-\x12\x18\n\x0c\n\x05\x04\x02\x02\x01\x03\
-    \x12\x03-\x1b\x1c\n\x0b\n\x04\x04\x02\x02\x02\x12\x03.\x02\x20\n\x0c\n\
-    \x05\x04\x02\x02\x02\x04\x12\x03.\x02\n\n\x0c\n\x05\x04\x02\x02\x02\x05\
-    \x12\x03.\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03.\x10\x1b\n\x0c\
-    \n\x05\x04\x02\x02\x02\x03\x12\x03.\x1e\x1f\n\n\n\x02\x04\x03\x12\x041\0\
-    B\x01\n\n\n\x03\x04\x03\x01\x12\x031\x08\x0b\n\n\n\x03\x04\x03\t\x12\x03\
-    2\x0b\x0e\n\x0b\n\x04\x04\x03\t\0\x12\x032\x0b\r\n\x0c\n\x05\x04\x03\t\0\
-    \x01\x12\x032\x0b\r\n\x0c\n\x05\x04\x03\t\0\x02\x12\x032\x0b\r\n\n\n\x03\
-    \x04\x03\n\x12\x033\x0b\x15\n\x0b\n\x04\x04\x03\n\0\x12\x033\x0b\x14\n\
-    \x0b\n\x04\x04\x03\x02\0\x12\x034\x02\x19\n\x0c\n\x05\x04\x03\x02\0\x04\
-    \x12\x034\x02\n\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x034\x0b\x11\n\x0c\n\
-    \x05\x04\x03\x02\0\x01\x12\x034\x12\x14\n\x0c\n\x05\x04\x03\x02\0\x03\
-    \x12\x034\x17\x18\n\x0b\n\x04\x04\x03\x02\x01\x12\x035\x02\x1f\n\x0c\n\
-    \x05\x04\x03\x02\x01\x04\x12\x035\x02\n\n\x0c\n\x05\x04\x03\x02\x01\x05\
-    \x12\x035\x0b\x11\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x035\x12\x1a\n\x0c\
-    \n\x05\x04\x03\x02\x01\x03\x12\x035\x1d\x1e\n\x0b\n\x04\x04\x03\x02\x02\
-    \x12\x036\x02\x1e\n\x0c\n\x05\x04\x03\x02\x02\x04\x12\x036\x02\n\n\x0c\n\
-    \x05\x04\x03\x02\x02\x06\x12\x036\x0b\x13\n\x0c\n\x05\x04\x03\x02\x02\
-    \x01\x12\x036\x14\x19\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x036\x1c\x1d\n\
-    \x0b\n\x04\x04\x03\x02\x03\x12\x037\x02/\n\x0c\n\x05\x04\x03\x02\x03\x04\
-    \x12\x037\x02\n\n\x0c\n\x05\x04\x03\x02\x03\x06\x12\x037\x0b\"\n\x0c\n\
-    \x05\x04\x03\x02\x03\x01\x12\x037#*\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\
-    \x037-.\n\x0b\n\x04\x04\x03\x02\x04\x12\x038\x02\"\n\x0c\n\x05\x04\x03\
-    \x02\x04\x04\x12\x038\x02\n\n\x0c\n\x05\x04\x03\x02\x04\x06\x12\x038\x0b\
-    \x17\n\x0c\n\x05\x04\x03\x02\x04\x01\x12\x038\x18\x1d\n\x0c\n\x05\x04\
-    \x03\x02\x04\x03\x12\x038\x20!\n%\n\x04\x04\x03\x02\x05\x12\x039\x02!\"\
-    \x18\x20RFC3339-formatted\x20time\n\n\x0c\n\x05\x04\x03\x02\x05\x04\x12\
-    \x039\x02\n\n\x0c\n\x05\x04\x03\x02\x05\x05\x12\x039\x0b\x11\n\x0c\n\x05\
-    \x04\x03\x02\x05\x01\x12\x039\x12\x1c\n\x0c\n\x05\x04\x03\x02\x05\x03\
-    \x12\x039\x1f\x20\n%\n\x04\x04\x03\x02\x06\x12\x03:\x02'\"\x18\x20RFC333\
-    9-formatted\x20time\n\n\x0c\n\x05\x04\x03\x02\x06\x04\x12\x03:\x02\n\n\
-    \x0c\n\x05\x04\x03\x02\x06\x05\x12\x03:\x0b\x11\n\x0c\n\x05\x04\x03\x02\
-    \x06\x01\x12\x03:\x12\"\n\x0c\n\x05\x04\x03\x02\x06\x03\x12\x03:%&\n\x0b\
-    \n\x04\x04\x03\x02\x07\x12\x03;\x02(\n\x0c\n\x05\x04\x03\x02\x07\x04\x12\
-    \x03;\x02\n\n\x0c\n\x05\x04\x03\x02\x07\x05\x12\x03;\x0b\x11\n\x0c\n\x05\
-    \x04\x03\x02\x07\x01\x12\x03;\x12#\n\x0c\n\x05\x04\x03\x02\x07\x03\x12\
-    \x03;&'\n\x0b\n\x04\x04\x03\x02\x08\x12\x03<\x02:\n\x0c\n\x05\x04\x03\
-    \x02\x08\x04\x12\x03<\x02\n\n\x0c\n\x05\x04\x03\x02\x08\x06\x12\x03<\x0b\
-    '\n\x0c\n\x05\x04\x03\x02\x08\x01\x12\x03<(5\n\x0c\n\x05\x04\x03\x02\x08\
-    \x03\x12\x03<89\n\x0b\n\x04\x04\x03\x02\t\x12\x03=\x02!\n\x0c\n\x05\x04\
-    \x03\x02\t\x04\x12\x03=\x02\n\n\x0c\n\x05\x04\x03\x02\t\x05\x12\x03=\x0b\
-    \x0f\n\x0c\n\x05\x04\x03\x02\t\x01\x12\x03=\x10\x1b\n\x0c\n\x05\x04\x03\
-    \x02\t\x03\x12\x03=\x1e\x20\n\x0b\n\x04\x04\x03\x02\n\x12\x03>\x029\n\
-    \x0c\n\x05\x04\x03\x02\n\x04\x12\x03>\x02\n\n\x0c\n\x05\x04\x03\x02\n\
-    \x06\x12\x03>\x0b&\n\x0c\n\x05\x04\x03\x02\n\x01\x12\x03>'3\n\x0c\n\x05\
-    \x04\x03\x02\n\x03\x12\x03>68\n\x0b\n\x04\x04\x03\x02\x0b\x12\x03?\x02\
-    \x1f\n\x0c\n\x05\x04\x03\x02\x0b\x04\x12\x03?\x02\n\n\x0c\n\x05\x04\x03\
-    \x02\x0b\x05\x12\x03?\x0b\x11\n\x0c\n\x05\x04\x03\x02\x0b\x01\x12\x03?\
-    \x12\x19\n\x0c\n\x05\x04\x03\x02\x0b\x03\x12\x03?\x1c\x1e\n\x0b\n\x04\
-    \x04\x03\x02\x0c\x12\x03@\x02H\n\x0c\n\x05\x04\x03\x02\x0c\x04\x12\x03@\
-    \x02\n\n\x0c\n\x05\x04\x03\x02\x0c\x06\x12\x03@\x0b-\n\x0c\n\x05\x04\x03\
-    \x02\x0c\x01\x12\x03@.B\n\x0c\n\x05\x04\x03\x02\x0c\x03\x12\x03@EG\n\x0b\
-    \n\x04\x04\x03\x02\r\x12\x03A\x02\x1e\n\x0c\n\x05\x04\x03\x02\r\x04\x12\
-    \x03A\x02\n\n\x0c\n\x05\x04\x03\x02\r\x05\x12\x03A\x0b\x11\n\x0c\n\x05\
-    \x04\x03\x02\r\x01\x12\x03A\x12\x18\n\x0c\n\x05\x04\x03\x02\r\x03\x12\
-    \x03A\x1b\x1d\n\n\n\x02\x04\x04\x12\x04D\0F\x01\n\n\n\x03\x04\x04\x01\
-    \x12\x03D\x08\x0e\n\x0b\n\x04\x04\x04\x02\0\x12\x03E\x02\x19\n\x0c\n\x05\
-    \x04\x04\x02\0\x04\x12\x03E\x02\n\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03E\
-    \x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03E\x12\x14\n\x0c\n\x05\x04\
-    \x04\x02\0\x03\x12\x03E\x17\x18\n\n\n\x02\x04\x05\x12\x04H\0L\x01\n\n\n\
-    \x03\x04\x05\x01\x12\x03H\x08\x0f\n\x0b\n\x04\x04\x05\x02\0\x12\x03I\x02\
-    \x1f\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x03I\x02\n\n\x0c\n\x05\x04\x05\
-    \x02\0\x05\x12\x03I\x0b\x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03I\x12\
-    \x1a\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03I\x1d\x1e\n\x0b\n\x04\x04\x05\
-    \x02\x01\x12\x03J\x02/\n\x0c\n\x05\x04\x05\x02\x01\x04\x12\x03J\x02\n\n\
-    \x0c\n\x05\x04\x05\x02\x01\x06\x12\x03J\x0b\"\n\x0c\n\x05\x04\x05\x02\
-    \x01\x01\x12\x03J#*\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03J-.\n\x0b\n\
-    \x04\x04\x05\x02\x02\x12\x03K\x02\x1e\n\x0c\n\x05\x04\x05\x02\x02\x04\
-    \x12\x03K\x02\n\n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x03K\x0b\x11\n\x0c\n\
-    \x05\x04\x05\x02\x02\x01\x12\x03K\x12\x19\n\x0c\n\x05\x04\x05\x02\x02\
-    \x03\x12\x03K\x1c\x1d\n\n\n\x02\x04\x06\x12\x04N\0R\x01\n\n\n\x03\x04\
-    \x06\x01\x12\x03N\x08\x16\n\x0b\n\x04\x04\x06\x02\0\x12\x03O\x02\x1b\n\
-    \x0c\n\x05\x04\x06\x02\0\x04\x12\x03O\x02\n\n\x0c\n\x05\x04\x06\x02\0\
-    \x05\x12\x03O\x0b\x11\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03O\x12\x16\n\
-    \x0c\n\x05\x04\x06\x02\0\x03\x12\x03O\x19\x1a\n\x0b\n\x04\x04\x06\x02\
-    \x01\x12\x03P\x02\x1c\n\x0c\n\x05\x04\x06\x02\x01\x04\x12\x03P\x02\n\n\
-    \x0c\n\x05\x04\x06\x02\x01\x05\x12\x03P\x0b\x11\n\x0c\n\x05\x04\x06\x02\
-    \x01\x01\x12\x03P\x12\x17\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03P\x1a\
-    \x1b\n\x0b\n\x04\x04\x06\x02\x02\x12\x03Q\x02\x1b\n\x0c\n\x05\x04\x06\
-    \x02\x02\x04\x12\x03Q\x02\n\n\x0c\n\x05\x04\x06\x02\x02\x05\x12\x03Q\x0b\
-    \x11\n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x03Q\x12\x16\n\x0c\n\x05\x04\
-    \x06\x02\x02\x03\x12\x03Q\x19\x1a\n\n\n\x02\x04\x07\x12\x04T\0Y\x01\n\n\
-    \n\x03\x04\x07\x01\x12\x03T\x08\x1e\n\x0b\n\x04\x04\x07\x02\0\x12\x03U\
-    \x02\x18\n\x0c\n\x05\x04\x07\x02\0\x04\x12\x03U\x02\n\n\x0c\n\x05\x04\
-    \x07\x02\0\x06\x12\x03U\x0b\x0e\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03U\
-    \x0f\x13\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03U\x16\x17\n\x0b\n\x04\x04\
-    \x07\x02\x01\x12\x03V\x02\x1c\n\x0c\n\x05\x04\x07\x02\x01\x04\x12\x03V\
-    \x02\n\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03V\x0b\x11\n\x0c\n\x05\x04\
-    \x07\x02\x01\x01\x12\x03V\x12\x17\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\
-    \x03V\x1a\x1b\n\x0b\n\x04\x04\x07\x02\x02\x12\x03W\x02\x1b\n\x0c\n\x05\
-    \x04\x07\x02\x02\x04\x12\x03W\x02\n\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\
-    \x03W\x0b\x11\n\x0c\n\x05\x04\x07\x02\x02\x01\x12\x03W\x12\x16\n\x0c\n\
-    \x05\x04\x07\x02\x02\x03\x12\x03W\x19\x1a\n\x0b\n\x04\x04\x07\x02\x03\
-    \x12\x03X\x02\x1c\n\x0c\n\x05\x04\x07\x02\x03\x04\x12\x03X\x02\n\n\x0c\n\
-    \x05\x04\x07\x02\x03\x05\x12\x03X\x0b\x11\n\x0c\n\x05\x04\x07\x02\x03\
-    \x01\x12\x03X\x12\x17\n\x0c\n\x05\x04\x07\x02\x03\x03\x12\x03X\x1a\x1b\n\
-    \n\n\x02\x04\x08\x12\x04[\0_\x01\n\n\n\x03\x04\x08\x01\x12\x03[\x08\x13\
-    \n\x0b\n\x04\x04\x08\x02\0\x12\x03\\\x02\x1d\n\x0c\n\x05\x04\x08\x02\0\
-    \x04\x12\x03\\\x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03\\\x0b\x11\n\
-    \x0c\n\x05\x04\x08\x02\0\x01\x12\x03\\\x12\x18\n\x0c\n\x05\x04\x08\x02\0\
-    \x03\x12\x03\\\x1b\x1c\n+\n\x04\x04\x08\x02\x01\x12\x03]\x02\x1a\"\x1e\
-    \x20Chunk\x20ordering\x20(line\x20number)\n\n\x0c\n\x05\x04\x08\x02\x01\
-    \x04\x12\x03]\x02\n\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x03]\x0b\x11\n\
-    \x0c\n\x05\x04\x08\x02\x01\x01\x12\x03]\x12\x15\n\x0c\n\x05\x04\x08\x02\
-    \x01\x03\x12\x03]\x18\x19\n5\n\x04\x04\x08\x02\x02\x12\x03^\x02\x1e\"(\
-    \x20Log\x20content\x20(TODO:\x20Make\x20repeatedfield)\n\n\x0c\n\x05\x04\
-    \x08\x02\x02\x04\x12\x03^\x02\n\n\x0c\n\x05\x04\x08\x02\x02\x05\x12\x03^\
-    \x0b\x11\n\x0c\n\x05\x04\x08\x02\x02\x01\x12\x03^\x12\x19\n\x0c\n\x05\
-    \x04\x08\x02\x02\x03\x12\x03^\x1c\x1d\n\n\n\x02\x04\t\x12\x04a\0c\x01\n\
-    \n\n\x03\x04\t\x01\x12\x03a\x08\x16\n\x0b\n\x04\x04\t\x02\0\x12\x03b\x02\
-    \x1d\n\x0c\n\x05\x04\t\x02\0\x04\x12\x03b\x02\n\n\x0c\n\x05\x04\t\x02\0\
-    \x05\x12\x03b\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03b\x12\x18\n\x0c\
-    \n\x05\x04\t\x02\0\x03\x12\x03b\x1b\x1c\n\n\n\x02\x04\n\x12\x04e\0h\x01\
-    \n\n\n\x03\x04\n\x01\x12\x03e\x08\x11\n\x0b\n\x04\x04\n\x02\0\x12\x03f\
-    \x02\x19\n\x0c\n\x05\x04\n\x02\0\x04\x12\x03f\x02\n\n\x0c\n\x05\x04\n\
-    \x02\0\x05\x12\x03f\x0b\x11\n\x0c\n\x05\x04\n\x02\0\x01\x12\x03f\x12\x14\
-    \n\x0c\n\x05\x04\n\x02\0\x03\x12\x03f\x17\x18\n.\n\x04\x04\n\x02\x01\x12\
-    \x03g\x02\x1c\"!\x20Zero-indexed\x20line\x20of\x20log\x20output\n\n\x0c\
-    \n\x05\x04\n\x02\x01\x04\x12\x03g\x02\n\n\x0c\n\x05\x04\n\x02\x01\x05\
-    \x12\x03g\x0b\x11\n\x0c\n\x05\x04\n\x02\x01\x01\x12\x03g\x12\x17\n\x0c\n\
-    \x05\x04\n\x02\x01\x03\x12\x03g\x1a\x1b\n\n\n\x02\x04\x0b\x12\x04j\0o\
-    \x01\n\n\n\x03\x04\x0b\x01\x12\x03j\x08\x0e\n,\n\x04\x04\x0b\x02\0\x12\
-    \x03k\x02\x1c\"\x1f\x20Zero-indexed\x20(inclusive)\x20line\n\n\x0c\n\x05\
-    \x04\x0b\x02\0\x04\x12\x03k\x02\n\n\x0c\n\x05\x04\x0b\x02\0\x05\x12\x03k\
-    \x0b\x11\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03k\x12\x17\n\x0c\n\x05\x04\
-    \x0b\x02\0\x03\x12\x03k\x1a\x1b\n,\n\x04\x04\x0b\x02\x01\x12\x03l\x02\
-    \x1b\"\x1f\x20Zero-indexed\x20(exclusive)\x20line\n\n\x0c\n\x05\x04\x0b\
-    \x02\x01\x04\x12\x03l\x02\n\n\x0c\n\x05\x04\x0b\x02\x01\x05\x12\x03l\x0b\
-    \x11\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03l\x12\x16\n\x0c\n\x05\x04\
-    \x0b\x02\x01\x03\x12\x03l\x19\x1a\n\x0b\n\x04\x04\x0b\x02\x02\x12\x03m\
-    \x02\x1e\n\x0c\n\x05\x04\x0b\x02\x02\x04\x12\x03m\x02\n\n\x0c\n\x05\x04\
-    \x0b\x02\x02\x05\x12\x03m\x0b\x11\n\x0c\n\x05\x04\x0b\x02\x02\x01\x12\
-    \x03m\x12\x19\n\x0c\n\x05\x04\x0b\x02\x02\x03\x12\x03m\x1c\x1d\n\x0b\n\
-    \x04\x04\x0b\x02\x03\x12\x03n\x02\x20\n\x0c\n\x05\x04\x0b\x02\x03\x04\
-    \x12\x03n\x02\n\n\x0c\n\x05\x04\x0b\x02\x03\x05\x12\x03n\x0b\x0f\n\x0c\n\
-    \x05\x04\x0b\x02\x03\x01\x12\x03n\x10\x1b\n\x0c\n\x05\x04\x0b\x02\x03\
-    \x03\x12\x03n\x1e\x1f\n\n\n\x02\x04\x0c\x12\x04q\0x\x01\n\n\n\x03\x04\
-    \x0c\x01\x12\x03q\x08\x14\n\x0b\n\x04\x04\x0c\x02\0\x12\x03r\x02\x1d\n\
-    \x0c\n\x05\x04\x0c\x02\0\x04\x12\x03r\x02\n\n\x0c\n\x05\x04\x0c\x02\0\
-    \x05\x12\x03r\x0b\x11\n\x0c\n\x05\x04\x0c\x02\0\x01\x12\x03r\x12\x18\n\
-    \x0c\n\x05\x04\x0c\x02\0\x03\x12\x03r\x1b\x1c\n\x0b\n\x04\x04\x0c\x02\
-    \x01\x12\x03s\x02\x1e\n\x0c\n\x05\x04\x0c\x02\x01\x04\x12\x03s\x02\n\n\
-    \x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03s\x0b\x11\n\x0c\n\x05\x04\x0c\x02\
-    \x01\x01\x12\x03s\x12\x19\n\x0c\n\x05\x04\x0c\x02\x01\x03\x12\x03s\x1c\
-    \x1d\n\x0b\n\x04\x04\x0c\x02\x02\x12\x03t\x02\x1e\n\x0c\n\x05\x04\x0c\
-    \x02\x02\x04\x12\x03t\x02\n\n\x0c\n\x05\x04\x0c\x02\x02\x05\x12\x03t\x0b\
-    \x0f\n\x0c\n\x05\x04\x0c\x02\x02\x01\x12\x03t\x10\x19\n\x0c\n\x05\x04\
-    \x0c\x02\x02\x03\x12\x03t\x1c\x1d\n\x0b\n\x04\x04\x0c\x02\x03\x12\x03u\
-    \x02\x1d\n\x0c\n\x05\x04\x0c\x02\x03\x04\x12\x03u\x02\n\n\x0c\n\x05\x04\
-    \x0c\x02\x03\x05\x12\x03u\x0b\x11\n\x0c\n\x05\x04\x0c\x02\x03\x01\x12\
-    \x03u\x12\x18\n\x0c\n\x05\x04\x0c\x02\x03\x03\x12\x03u\x1b\x1c\n\x0b\n\
-    \x04\x04\x0c\x02\x04\x12\x03v\x02\x20\n\x0c\n\x05\x04\x0c\x02\x04\x04\
-    \x12\x03v\x02\n\n\x0c\n\x05\x04\x0c\x02\x04\x05\x12\x03v\x0b\x0f\n\x0c\n\
-    \x05\x04\x0c\x02\x04\x01\x12\x03v\x10\x1b\n\x0c\n\x05\x04\x0c\x02\x04\
-    \x03\x12\x03v\x1e\x1f\n\x0b\n\x04\x04\x0c\x02\x05\x12\x03w\x02!\n\x0c\n\
-    \x05\x04\x0c\x02\x05\x04\x12\x03w\x02\n\n\x0c\n\x05\x04\x0c\x02\x05\x05\
-    \x12\x03w\x0b\x0f\n\x0c\n\x05\x04\x0c\x02\x05\x01\x12\x03w\x10\x1c\n\x0c\
-    \n\x05\x04\x0c\x02\x05\x03\x12\x03w\x1f\x20\n\x0b\n\x02\x05\x04\x12\x05z\
-    \0\x81\x01\x01\n\n\n\x03\x05\x04\x01\x12\x03z\x05\x19\n\x0b\n\x04\x05\
-    \x04\x02\0\x12\x03{\x02\x11\n\x0c\n\x05\x05\x04\x02\0\x01\x12\x03{\x02\
-    \x0c\n\x0c\n\x05\x05\x04\x02\0\x02\x12\x03{\x0f\x10\n\x0b\n\x04\x05\x04\
-    \x02\x01\x12\x03|\x02\x11\n\x0c\n\x05\x05\x04\x02\x01\x01\x12\x03|\x02\
-    \x0c\n\x0c\n\x05\x05\x04\x02\x01\x02\x12\x03|\x0f\x10\n\x0b\n\x04\x05\
-    \x04\x02\x02\x12\x03}\x02\x0e\n\x0c\n\x05\x05\x04\x02\x02\x01\x12\x03}\
-    \x02\t\n\x0c\n\x05\x05\x04\x02\x02\x02\x12\x03}\x0c\r\n\x0b\n\x04\x05\
-    \x04\x02\x03\x12\x03~\x02\x0e\n\x0c\n\x05\x05\x04\x02\x03\x01\x12\x03~\
-    \x02\t\n\x0c\n\x05\x05\x04\x02\x03\x02\x12\x03~\x0c\r\n\x0b\n\x04\x05\
-    \x04\x02\x04\x12\x03\x7f\x02\x0e\n\x0c\n\x05\x05\x04\x02\x04\x01\x12\x03\
-    \x7f\x02\t\n\x0c\n\x05\x05\x04\x02\x04\x02\x12\x03\x7f\x0c\r\n\x0c\n\x04\
-    \x05\x04\x02\x05\x12\x04\x80\x01\x02\x0f\n\r\n\x05\x05\x04\x02\x05\x01\
-    \x12\x04\x80\x01\x02\n\n\r\n\x05\x05\x04\x02\x05\x02\x12\x04\x80\x01\r\
-    \x0e\n\x0c\n\x02\x04\r\x12\x06\x83\x01\0\x89\x01\x01\n\x0b\n\x03\x04\r\
-    \x01\x12\x04\x83\x01\x08\x17\n\x0c\n\x04\x04\r\x02\0\x12\x04\x84\x01\x02\
-    \x1b\n\r\n\x05\x04\r\x02\0\x04\x12\x04\x84\x01\x02\n\n\r\n\x05\x04\r\x02\
-    \0\x05\x12\x04\x84\x01\x0b\x11\n\r\n\x05\x04\r\x02\0\x01\x12\x04\x84\x01\
-    \x12\x16\n\r\n\x05\x04\r\x02\0\x03\x12\x04\x84\x01\x19\x1a\n\x0c\n\x04\
-    \x04\r\x02\x01\x12\x04\x85\x01\x02\x1c\n\r\n\x05\x04\r\x02\x01\x04\x12\
-    \x04\x85\x01\x02\n\n\r\n\x05\x04\r\x02\x01\x05\x12\x04\x85\x01\x0b\x11\n\
-    \r\n\x05\x04\r\x02\x01\x01\x12\x04\x85\x01\x12\x17\n\r\n\x05\x04\r\x02\
-    \x01\x03\x12\x04\x85\x01\x1a\x1b\n\x0c\n\x04\x04\r\x02\x02\x12\x04\x86\
-    \x01\x02*\n\r\n\x05\x04\r\x02\x02\x04\x12\x04\x86\x01\x02\n\n\r\n\x05\
-    \x04\r\x02\x02\x06\x12\x04\x86\x01\x0b\x1f\n\r\n\x05\x04\r\x02\x02\x01\
-    \x12\x04\x86\x01\x20%\n\r\n\x05\x04\r\x02\x02\x03\x12\x04\x86\x01()\n\
-    \x0c\n\x04\x04\r\x02\x03\x12\x04\x87\x01\x02\x1d\n\r\n\x05\x04\r\x02\x03\
-    \x04\x12\x04\x87\x01\x02\n\n\r\n\x05\x04\r\x02\x03\x05\x12\x04\x87\x01\
-    \x0b\x11\n\r\n\x05\x04\r\x02\x03\x01\x12\x04\x87\x01\x12\x18\n\r\n\x05\
-    \x04\r\x02\x03\x03\x12\x04\x87\x01\x1b\x1c\n\x0c\n\x04\x04\r\x02\x04\x12\
-    \x04\x88\x01\x02\x1d\n\r\n\x05\x04\r\x02\x04\x04\x12\x04\x88\x01\x02\n\n\
-    \r\n\x05\x04\r\x02\x04\x05\x12\x04\x88\x01\x0b\x11\n\r\n\x05\x04\r\x02\
-    \x04\x01\x12\x04\x88\x01\x12\x18\n\r\n\x05\x04\r\x02\x04\x03\x12\x04\x88\
-    \x01\x1b\x1c\n\x0c\n\x02\x05\x05\x12\x06\x8b\x01\0\x92\x01\x01\n\x0b\n\
-    \x03\x05\x05\x01\x12\x04\x8b\x01\x05\x12\n\x0c\n\x04\x05\x05\x02\0\x12\
-    \x04\x8c\x01\x02\x13\n\r\n\x05\x05\x05\x02\0\x01\x12\x04\x8c\x01\x02\x0e\
-    \n\r\n\x05\x05\x05\x02\0\x02\x12\x04\x8c\x01\x11\x12\n\x0c\n\x04\x05\x05\
-    \x02\x01\x12\x04\x8d\x01\x02\x17\n\r\n\x05\x05\x05\x02\x01\x01\x12\x04\
-    \x8d\x01\x02\x12\n\r\n\x05\x05\x05\x02\x01\x02\x12\x04\x8d\x01\x15\x16\n\
-    \x0c\n\x04\x05\x05\x02\x02\x12\x04\x8e\x01\x02\x14\n\r\n\x05\x05\x05\x02\
-    \x02\x01\x12\x04\x8e\x01\x02\x0f\n\r\n\x05\x05\x05\x02\x02\x02\x12\x04\
-    \x8e\x01\x12\x13\n\x0c\n\x04\x05\x05\x02\x03\x12\x04\x8f\x01\x02\x12\n\r\
-    \n\x05\x05\x05\x02\x03\x01\x12\x04\x8f\x01\x02\r\n\r\n\x05\x05\x05\x02\
-    \x03\x02\x12\x04\x8f\x01\x10\x11\n\x0c\n\x04\x05\x05\x02\x04\x12\x04\x90\
-    \x01\x02\x12\n\r\n\x05\x05\x05\x02\x04\x01\x12\x04\x90\x01\x02\r\n\r\n\
-    \x05\x05\x05\x02\x04\x02\x12\x04\x90\x01\x10\x11\n\x0c\n\x04\x05\x05\x02\
-    \x05\x12\x04\x91\x01\x02\x14\n\r\n\x05\x05\x05\x02\x05\x01\x12\x04\x91\
-    \x01\x02\x0f\n\r\n\x05\x05\x05\x02\x05\x02\x12\x04\x91\x01\x12\x13\n\x0c\
-    \n\x02\x04\x0e\x12\x06\x94\x01\0\x96\x01\x01\n\x0b\n\x03\x04\x0e\x01\x12\
-    \x04\x94\x01\x08\x15\n\x0c\n\x04\x04\x0e\x02\0\x12\x04\x95\x01\x02\x1f\n\
-    \r\n\x05\x04\x0e\x02\0\x04\x12\x04\x95\x01\x02\n\n\r\n\x05\x04\x0e\x02\0\
-    \x05\x12\x04\x95\x01\x0b\x11\n\r\n\x05\x04\x0e\x02\0\x01\x12\x04\x95\x01\
-    \x12\x1a\n\r\n\x05\x04\x0e\x02\0\x03\x12\x04\x95\x01\x1d\x1e\n\x0c\n\x02\
-    \x04\x0f\x12\x06\x98\x01\0\x9a\x01\x01\n\x0b\n\x03\x04\x0f\x01\x12\x04\
-    \x98\x01\x08\x16\n\x0c\n\x04\x04\x0f\x02\0\x12\x04\x99\x01\x02\x1f\n\r\n\
-    \x05\x04\x0f\x02\0\x04\x12\x04\x99\x01\x02\n\n\r\n\x05\x04\x0f\x02\0\x05\
-    \x12\x04\x99\x01\x0b\x11\n\r\n\x05\x04\x0f\x02\0\x01\x12\x04\x99\x01\x12\
-    \x1a\n\r\n\x05\x04\x0f\x02\0\x03\x12\x04\x99\x01\x1d\x1e\n\x0c\n\x02\x04\
-    \x10\x12\x06\x9c\x01\0\x9e\x01\x01\n\x0b\n\x03\x04\x10\x01\x12\x04\x9c\
-    \x01\x08\x13\n\x0c\n\x04\x04\x10\x02\0\x12\x04\x9d\x01\x02\x1f\n\r\n\x05\
-    \x04\x10\x02\0\x04\x12\x04\x9d\x01\x02\n\n\r\n\x05\x04\x10\x02\0\x05\x12\
-    \x04\x9d\x01\x0b\x11\n\r\n\x05\x04\x10\x02\0\x01\x12\x04\x9d\x01\x12\x1a\
-    \n\r\n\x05\x04\x10\x02\0\x03\x12\x04\x9d\x01\x1d\x1e\n\x0c\n\x02\x04\x11\
-    \x12\x06\xa0\x01\0\xa2\x01\x01\n\x0b\n\x03\x04\x11\x01\x12\x04\xa0\x01\
-    \x08\x19\n\x0c\n\x04\x04\x11\x02\0\x12\x04\xa1\x01\x02\x1d\n\r\n\x05\x04\
-    \x11\x02\0\x04\x12\x04\xa1\x01\x02\n\n\r\n\x05\x04\x11\x02\0\x05\x12\x04\
-    \xa1\x01\x0b\x11\n\r\n\x05\x04\x11\x02\0\x01\x12\x04\xa1\x01\x12\x18\n\r\
-    \n\x05\x04\x11\x02\0\x03\x12\x04\xa1\x01\x1b\x1c\n\x0c\n\x02\x04\x12\x12\
-    \x06\xa4\x01\0\xa6\x01\x01\n\x0b\n\x03\x04\x12\x01\x12\x04\xa4\x01\x08\
-    \x1e\n\x0c\n\x04\x04\x12\x02\0\x12\x04\xa5\x01\x02#\n\r\n\x05\x04\x12\
-    \x02\0\x04\x12\x04\xa5\x01\x02\n\n\r\n\x05\x04\x12\x02\0\x06\x12\x04\xa5\
-    \x01\x0b\x13\n\r\n\x05\x04\x12\x02\0\x01\x12\x04\xa5\x01\x14\x1e\n\r\n\
-    \x05\x04\x12\x02\0\x03\x12\x04\xa5\x01!\"\n\x0c\n\x02\x04\x13\x12\x06\
-    \xa8\x01\0\xae\x01\x01\n\x0b\n\x03\x04\x13\x01\x12\x04\xa8\x01\x08\x10\n\
-    \x0c\n\x04\x04\x13\x02\0\x12\x04\xa9\x01\x02\x19\n\r\n\x05\x04\x13\x02\0\
-    \x04\x12\x04\xa9\x01\x02\n\n\r\n\x05\x04\x13\x02\0\x05\x12\x04\xa9\x01\
-    \x0b\x11\n\r\n\x05\x04\x13\x02\0\x01\x12\x04\xa9\x01\x12\x14\n\r\n\x05\
-    \x04\x13\x02\0\x03\x12\x04\xa9\x01\x17\x18\n\x0c\n\x04\x04\x13\x02\x01\
-    \x12\x04\xaa\x01\x02#\n\r\n\x05\x04\x13\x02\x01\x04\x12\x04\xaa\x01\x02\
-    \n\n\r\n\x05\x04\x13\x02\x01\x06\x12\x04\xaa\x01\x0b\x18\n\r\n\x05\x04\
-    \x13\x02\x01\x01\x12\x04\xaa\x01\x19\x1e\n\r\n\x05\x04\x13\x02\x01\x03\
-    \x12\x04\xaa\x01!\"\n\x0c\n\x04\x04\x13\x02\x02\x12\x04\xab\x01\x02(\n\r\
-    \n\x05\x04\x13\x02\x02\x04\x12\x04\xab\x01\x02\n\n\r\n\x05\x04\x13\x02\
-    \x02\x06\x12\x04\xab\x01\x0b\x1a\n\r\n\x05\x04\x13\x02\x02\x01\x12\x04\
-    \xab\x01\x1b#\n\r\n\x05\x04\x13\x02\x02\x03\x12\x04\xab\x01&'\n\x0c\n\
-    \x04\x04\x13\x02\x03\x12\x04\xac\x01\x02!\n\r\n\x05\x04\x13\x02\x03\x04\
-    \x12\x04\xac\x01\x02\n\n\r\n\x05\x04\x13\x02\x03\x05\x12\x04\xac\x01\x0b\
-    \x11\n\r\n\x05\x04\x13\x02\x03\x01\x12\x04\xac\x01\x12\x1c\n\r\n\x05\x04\
-    \x13\x02\x03\x03\x12\x04\xac\x01\x1f\x20\n\x0c\n\x04\x04\x13\x02\x04\x12\
-    \x04\xad\x01\x02#\n\r\n\x05\x04\x13\x02\x04\x04\x12\x04\xad\x01\x02\n\n\
-    \r\n\x05\x04\x13\x02\x04\x05\x12\x04\xad\x01\x0b\x11\n\r\n\x05\x04\x13\
-    \x02\x04\x01\x12\x04\xad\x01\x12\x1e\n\r\n\x05\x04\x13\x02\x04\x03\x12\
-    \x04\xad\x01!\"\n\x0c\n\x02\x04\x14\x12\x06\xb0\x01\0\xb4\x01\x01\n\x0b\
-    \n\x03\x04\x14\x01\x12\x04\xb0\x01\x08\x17\n\x0c\n\x04\x04\x14\x02\0\x12\
-    \x04\xb1\x01\x02\x1c\n\r\n\x05\x04\x14\x02\0\x04\x12\x04\xb1\x01\x02\n\n\
-    \r\n\x05\x04\x14\x02\0\x05\x12\x04\xb1\x01\x0b\x11\n\r\n\x05\x04\x14\x02\
-    \0\x01\x12\x04\xb1\x01\x12\x17\n\r\n\x05\x04\x14\x02\0\x03\x12\x04\xb1\
-    \x01\x1a\x1b\n\x0c\n\x04\x04\x14\x02\x01\x12\x04\xb2\x01\x02\x1b\n\r\n\
-    \x05\x04\x14\x02\x01\x04\x12\x04\xb2\x01\x02\n\n\r\n\x05\x04\x14\x02\x01\
-    \x05\x12\x04\xb2\x01\x0b\x11\n\r\n\x05\x04\x14\x02\x01\x01\x12\x04\xb2\
-    \x01\x12\x16\n\r\n\x05\x04\x14\x02\x01\x03\x12\x04\xb2\x01\x19\x1a\n\x0c\
-    \n\x04\x04\x14\x02\x02\x12\x04\xb3\x01\x02\x1d\n\r\n\x05\x04\x14\x02\x02\
-    \x04\x12\x04\xb3\x01\x02\n\n\r\n\x05\x04\x14\x02\x02\x05\x12\x04\xb3\x01\
-    \x0b\x11\n\r\n\x05\x04\x14\x02\x02\x01\x12\x04\xb3\x01\x12\x18\n\r\n\x05\
-    \x04\x14\x02\x02\x03\x12\x04\xb3\x01\x1b\x1c\n\x0c\n\x02\x04\x15\x12\x06\
-    \xb6\x01\0\xba\x01\x01\n\x0b\n\x03\x04\x15\x01\x12\x04\xb6\x01\x08\x20\n\
-    \x0c\n\x04\x04\x15\x02\0\x12\x04\xb7\x01\x02\x1c\n\r\n\x05\x04\x15\x02\0\
-    \x04\x12\x04\xb7\x01\x02\n\n\r\n\x05\x04\x15\x02\0\x05\x12\x04\xb7\x01\
-    \x0b\x11\n\r\n\x05\x04\x15\x02\0\x01\x12\x04\xb7\x01\x12\x17\n\r\n\x05\
-    \x04\x15\x02\0\x03\x12\x04\xb7\x01\x1a\x1b\n\x0c\n\x04\x04\x15\x02\x01\
-    \x12\x04\xb8\x01\x02\x1b\n\r\n\x05\x04\x15\x02\x01\x04\x12\x04\xb8\x01\
-    \x02\n\n\r\n\x05\x04\x15\x02\x01\x05\x12\x04\xb8\x01\x0b\x11\n\r\n\x05\
-    \x04\x15\x02\x01\x01\x12\x04\xb8\x01\x12\x16\n\r\n\x05\x04\x15\x02\x01\
-    \x03\x12\x04\xb8\x01\x19\x1a\n\x0c\n\x04\x04\x15\x02\x02\x12\x04\xb9\x01\
-    \x02\x1d\n\r\n\x05\x04\x15\x02\x02\x04\x12\x04\xb9\x01\x02\n\n\r\n\x05\
-    \x04\x15\x02\x02\x05\x12\x04\xb9\x01\x0b\x11\n\r\n\x05\x04\x15\x02\x02\
-    \x01\x12\x04\xb9\x01\x12\x18\n\r\n\x05\x04\x15\x02\x02\x03\x12\x04\xb9\
-    \x01\x1b\x1c\n\x0c\n\x02\x04\x16\x12\x06\xbc\x01\0\xc0\x01\x01\n\x0b\n\
-    \x03\x04\x16\x01\x12\x04\xbc\x01\x08\x1d\n\x0c\n\x04\x04\x16\x02\0\x12\
-    \x04\xbd\x01\x02\x1c\n\r\n\x05\x04\x16\x02\0\x04\x12\x04\xbd\x01\x02\n\n\
-    \r\n\x05\x04\x16\x02\0\x05\x12\x04\xbd\x01\x0b\x11\n\r\n\x05\x04\x16\x02\
-    \0\x01\x12\x04\xbd\x01\x12\x17\n\r\n\x05\x04\x16\x02\0\x03\x12\x04\xbd\
-    \x01\x1a\x1b\n\x0c\n\x04\x04\x16\x02\x01\x12\x04\xbe\x01\x02\x1b\n\r\n\
-    \x05\x04\x16\x02\x01\x04\x12\x04\xbe\x01\x02\n\n\r\n\x05\x04\x16\x02\x01\
-    \x05\x12\x04\xbe\x01\x0b\x11\n\r\n\x05\x04\x16\x02\x01\x01\x12\x04\xbe\
-    \x01\x12\x16\n\r\n\x05\x04\x16\x02\x01\x03\x12\x04\xbe\x01\x19\x1a\n\x0c\
-    \n\x04\x04\x16\x02\x02\x12\x04\xbf\x01\x02\x1d\n\r\n\x05\x04\x16\x02\x02\
-    \x04\x12\x04\xbf\x01\x02\n\n\r\n\x05\x04\x16\x02\x02\x05\x12\x04\xbf\x01\
-    \x0b\x11\n\r\n\x05\x04\x16\x02\x02\x01\x12\x04\xbf\x01\x12\x18\n\r\n\x05\
-    \x04\x16\x02\x02\x03\x12\x04\xbf\x01\x1b\x1c\n\x0c\n\x02\x04\x17\x12\x06\
-    \xc2\x01\0\xc6\x01\x01\n\x0b\n\x03\x04\x17\x01\x12\x04\xc2\x01\x08-\n\
-    \x0c\n\x04\x04\x17\x02\0\x12\x04\xc3\x01\x02\x1d\n\r\n\x05\x04\x17\x02\0\
-    \x04\x12\x04\xc3\x01\x02\n\n\r\n\x05\x04\x17\x02\0\x05\x12\x04\xc3\x01\
-    \x0b\x11\n\r\n\x05\x04\x17\x02\0\x01\x12\x04\xc3\x01\x12\x18\n\r\n\x05\
-    \x04\x17\x02\0\x03\x12\x04\xc3\x01\x1b\x1c\n\x0c\n\x04\x04\x17\x02\x01\
-    \x12\x04\xc4\x01\x02\x1b\n\r\n\x05\x04\x17\x02\x01\x04\x12\x04\xc4\x01\
-    \x02\n\n\r\n\x05\x04\x17\x02\x01\x05\x12\x04\xc4\x01\x0b\x11\n\r\n\x05\
-    \x04\x17\x02\x01\x01\x12\x04\xc4\x01\x12\x16\n\r\n\x05\x04\x17\x02\x01\
-    \x03\x12\x04\xc4\x01\x19\x1a\n\x0c\n\x04\x04\x17\x02\x02\x12\x04\xc5\x01\
-    \x02\x1d\n\r\n\x05\x04\x17\x02\x02\x04\x12\x04\xc5\x01\x02\n\n\r\n\x05\
-    \x04\x17\x02\x02\x05\x12\x04\xc5\x01\x0b\x11\n\r\n\x05\x04\x17\x02\x02\
-    \x01\x12\x04\xc5\x01\x12\x18\n\r\n\x05\x04\x17\x02\x02\x03\x12\x04\xc5\
-    \x01\x1b\x1c\n\x0c\n\x02\x04\x18\x12\x06\xc8\x01\0\xcc\x01\x01\n\x0b\n\
-    \x03\x04\x18\x01\x12\x04\xc8\x01\x08*\n\x0c\n\x04\x04\x18\x02\0\x12\x04\
-    \xc9\x01\x02\x1d\n\r\n\x05\x04\x18\x02\0\x04\x12\x04\xc9\x01\x02\n\n\r\n\
-    \x05\x04\x18\x02\0\x05\x12\x04\xc9\x01\x0b\x11\n\r\n\x05\x04\x18\x02\0\
-    \x01\x12\x04\xc9\x01\x12\x18\n\r\n\x05\x04\x18\x02\0\x03\x12\x04\xc9\x01\
-    \x1b\x1c\n\x0c\n\x04\x04\x18\x02\x01\x12\x04\xca\x01\x02\x1b\n\r\n\x05\
-    \x04\x18\x02\x01\x04\x12\x04\xca\x01\x02\n\n\r\n\x05\x04\x18\x02\x01\x05\
-    \x12\x04\xca\x01\x0b\x11\n\r\n\x05\x04\x18\x02\x01\x01\x12\x04\xca\x01\
-    \x12\x16\n\r\n\x05\x04\x18\x02\x01\x03\x12\x04\xca\x01\x19\x1a\n\x0c\n\
-    \x04\x04\x18\x02\x02\x12\x04\xcb\x01\x02\x1c\n\r\n\x05\x04\x18\x02\x02\
-    \x04\x12\x04\xcb\x01\x02\n\n\r\n\x05\x04\x18\x02\x02\x05\x12\x04\xcb\x01\
-    \x0b\x11\n\r\n\x05\x04\x18\x02\x02\x01\x12\x04\xcb\x01\x12\x17\n\r\n\x05\
-    \x04\x18\x02\x02\x03\x12\x04\xcb\x01\x1a\x1b\n\x0c\n\x02\x04\x19\x12\x06\
-    \xce\x01\0\xd0\x01\x01\n\x0b\n\x03\x04\x19\x01\x12\x04\xce\x01\x08\x1f\n\
-    \x0c\n\x04\x04\x19\x02\0\x12\x04\xcf\x01\x02\x1d\n\r\n\x05\x04\x19\x02\0\
-    \x04\x12\x04\xcf\x01\x02\n\n\r\n\x05\x04\x19\x02\0\x05\x12\x04\xcf\x01\
-    \x0b\x11\n\r\n\x05\x04\x19\x02\0\x01\x12\x04\xcf\x01\x12\x18\n\r\n\x05\
-    \x04\x19\x02\0\x03\x12\x04\xcf\x01\x1b\x1c\n\x0c\n\x02\x04\x1a\x12\x06\
-    \xd2\x01\0\xd6\x01\x01\n\x0b\n\x03\x04\x1a\x01\x12\x04\xd2\x01\x08\x1c\n\
-    \x0c\n\x04\x04\x1a\x02\0\x12\x04\xd3\x01\x02\x1c\n\r\n\x05\x04\x1a\x02\0\
-    \x04\x12\x04\xd3\x01\x02\n\n\r\n\x05\x04\x1a\x02\0\x05\x12\x04\xd3\x01\
-    \x0b\x11\n\r\n\x05\x04\x1a\x02\0\x01\x12\x04\xd3\x01\x12\x17\n\r\n\x05\
-    \x04\x1a\x02\0\x03\x12\x04\xd3\x01\x1a\x1b\n\x0c\n\x04\x04\x1a\x02\x01\
-    \x12\x04\xd4\x01\x02\x1d\n\r\n\x05\x04\x1a\x02\x01\x04\x12\x04\xd4\x01\
-    \x02\n\n\r\n\x05\x04\x1a\x02\x01\x05\x12\x04\xd4\x01\x0b\x11\n\r\n\x05\
-    \x04\x1a\x02\x01\x01\x12\x04\xd4\x01\x12\x18\n\r\n\x05\x04\x1a\x02\x01\
-    \x03\x12\x04\xd4\x01\x1b\x1c\n\x0c\n\x04\x04\x1a\x02\x02\x12\x04\xd5\x01\
-    \x02&\n\r\n\x05\x04\x1a\x02\x02\x04\x12\x04\xd5\x01\x02\n\n\r\n\x05\x04\
-    \x1a\x02\x02\x05\x12\x04\xd5\x01\x0b\x11\n\r\n\x05\x04\x1a\x02\x02\x01\
-    \x12\x04\xd5\x01\x12!\n\r\n\x05\x04\x1a\x02\x02\x03\x12\x04\xd5\x01$%\
+    ns\x12\x16\n\x06worker\x18\x0f\x20\x01(\tR\x06worker\x12\x16\n\x06target\
+    \x18\x10\x20\x01(\tR\x06targetJ\x04\x08\n\x10\x0bR\x07log_url\"\x18\n\
+    \x06JobGet\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\"\x8a\x01\n\x07Jo\
+    bSpec\x12\x19\n\x08owner_id\x18\x01\x20\x01(\x04R\x07ownerId\x122\n\x07p\
+    roject\x18\x02\x20\x01(\x0b2\x18.originsrv.OriginProjectR\x07project\x12\
+    \x18\n\x07channel\x18\x03\x20\x01(\tR\x07channel\x12\x16\n\x06target\x18\
+    \x04\x20\x01(\tR\x06target\"N\n\x0eProjectJobsGet\x12\x12\n\x04name\x18\
+    \x01\x20\x01(\tR\x04name\x12\x14\n\x05start\x18\x02\x20\x01(\x04R\x05sta\
+    rt\x12\x12\n\x04stop\x18\x03\x20\x01(\x04R\x04stop\"y\n\x16ProjectJobsGe\
+    tResponse\x12\x1f\n\x04jobs\x18\x01\x20\x03(\x0b2\x0b.jobsrv.JobR\x04job\
+    s\x12\x14\n\x05start\x18\x02\x20\x01(\x04R\x05start\x12\x12\n\x04stop\
+    \x18\x03\x20\x01(\x04R\x04stop\x12\x14\n\x05count\x18\x04\x20\x01(\x04R\
+    \x05count\"P\n\x0bJobLogChunk\x12\x15\n\x06job_id\x18\x01\x20\x01(\x04R\
+    \x05jobId\x12\x10\n\x03seq\x18\x02\x20\x01(\x04R\x03seq\x12\x18\n\x07con\
+    tent\x18\x03\x20\x01(\tR\x07content\"'\n\x0eJobLogComplete\x12\x15\n\x06\
+    job_id\x18\x01\x20\x01(\x04R\x05jobId\"1\n\tJobLogGet\x12\x0e\n\x02id\
+    \x18\x01\x20\x01(\x04R\x02id\x12\x14\n\x05start\x18\x02\x20\x01(\x04R\
+    \x05start\"m\n\x06JobLog\x12\x14\n\x05start\x18\x01\x20\x01(\x04R\x05sta\
+    rt\x12\x12\n\x04stop\x18\x02\x20\x01(\x04R\x04stop\x12\x18\n\x07content\
+    \x18\x03\x20\x03(\tR\x07content\x12\x1f\n\x0bis_complete\x18\x04\x20\x01\
+    (\x08R\nisComplete\"\xb9\x01\n\x0cJobGroupSpec\x12\x16\n\x06origin\x18\
+    \x01\x20\x01(\tR\x06origin\x12\x18\n\x07package\x18\x02\x20\x01(\tR\x07p\
+    ackage\x12\x1b\n\tdeps_only\x18\x03\x20\x01(\x08R\x08depsOnly\x12\x16\n\
+    \x06target\x18\x04\x20\x01(\tR\x06target\x12\x1f\n\x0borigin_only\x18\
+    \x05\x20\x01(\x08R\noriginOnly\x12!\n\x0cpackage_only\x18\x06\x20\x01(\
+    \x08R\x0bpackageOnly\"\x9e\x01\n\x0fJobGroupProject\x12\x12\n\x04name\
+    \x18\x01\x20\x01(\tR\x04name\x12\x14\n\x05ident\x18\x02\x20\x01(\tR\x05i\
+    dent\x122\n\x05state\x18\x03\x20\x01(\x0e2\x1c.jobsrv.JobGroupProjectSta\
+    teR\x05state\x12\x15\n\x06job_id\x18\x04\x20\x01(\x04R\x05jobId\x12\x16\
+    \n\x06target\x18\x05\x20\x01(\tR\x06target\"*\n\rJobGroupAbort\x12\x19\n\
+    \x08group_id\x18\x01\x20\x01(\x04R\x07groupId\"+\n\x0eJobGroupCancel\x12\
+    \x19\n\x08group_id\x18\x01\x20\x01(\x04R\x07groupId\"(\n\x0bJobGroupGet\
+    \x12\x19\n\x08group_id\x18\x01\x20\x01(\x04R\x07groupId\"+\n\x11JobGroup\
+    OriginGet\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06origin\"I\n\x16JobG\
+    roupOriginResponse\x12/\n\njob_groups\x18\x01\x20\x03(\x0b2\x10.jobsrv.J\
+    obGroupR\tjobGroups\"\xbe\x01\n\x08JobGroup\x12\x0e\n\x02id\x18\x01\x20\
+    \x01(\x04R\x02id\x12+\n\x05state\x18\x02\x20\x01(\x0e2\x15.jobsrv.JobGro\
+    upStateR\x05state\x123\n\x08projects\x18\x03\x20\x03(\x0b2\x17.jobsrv.Jo\
+    bGroupProjectR\x08projects\x12\x1d\n\ncreated_at\x18\x04\x20\x01(\tR\tcr\
+    eatedAt\x12!\n\x0cproject_name\x18\x05\x20\x01(\tR\x0bprojectName\"S\n\
+    \x0fJobGraphPackage\x12\x14\n\x05ident\x18\x01\x20\x01(\tR\x05ident\x12\
+    \x12\n\x04deps\x18\x02\x20\x03(\tR\x04deps\x12\x16\n\x06target\x18\x03\
+    \x20\x01(\tR\x06target\"\\\n\x18JobGraphPackagePreCreate\x12\x14\n\x05id\
+    ent\x18\x01\x20\x01(\tR\x05ident\x12\x12\n\x04deps\x18\x02\x20\x03(\tR\
+    \x04deps\x12\x16\n\x06target\x18\x03\x20\x01(\tR\x06target\"Y\n\x15JobGr\
+    aphPackageCreate\x12\x14\n\x05ident\x18\x01\x20\x01(\tR\x05ident\x12\x12\
+    \n\x04deps\x18\x02\x20\x03(\tR\x04deps\x12\x16\n\x06target\x18\x03\x20\
+    \x01(\tR\x06target\"k\n%JobGraphPackageReverseDependenciesGet\x12\x16\n\
+    \x06origin\x18\x01\x20\x01(\tR\x06origin\x12\x12\n\x04name\x18\x02\x20\
+    \x01(\tR\x04name\x12\x16\n\x06target\x18\x03\x20\x01(\tR\x06target\"f\n\
+    \"JobGraphPackageReverseDependencies\x12\x16\n\x06origin\x18\x01\x20\x01\
+    (\tR\x06origin\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x14\n\
+    \x05rdeps\x18\x03\x20\x03(\tR\x05rdeps\"1\n\x17JobGraphPackageStatsGet\
+    \x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06origin\"m\n\x14JobGraphPacka\
+    geStats\x12\x14\n\x05plans\x18\x01\x20\x01(\x04R\x05plans\x12\x16\n\x06b\
+    uilds\x18\x02\x20\x01(\x04R\x06builds\x12'\n\x0funique_packages\x18\x03\
+    \x20\x01(\x04R\x0euniquePackages*(\n\x02Os\x12\t\n\x05Linux\x10\x01\x12\
+    \n\n\x06Darwin\x10\x02\x12\x0b\n\x07Windows\x10\x03*\"\n\x0bWorkerState\
+    \x12\t\n\x05Ready\x10\0\x12\x08\n\x04Busy\x10\x01*.\n\x0fWorkerOperation\
+    \x12\x0c\n\x08StartJob\x10\0\x12\r\n\tCancelJob\x10\x01*\x9c\x01\n\x08Jo\
+    bState\x12\x0b\n\x07Pending\x10\0\x12\x0e\n\nProcessing\x10\x01\x12\x0c\
+    \n\x08Complete\x10\x02\x12\x0c\n\x08Rejected\x10\x03\x12\n\n\x06Failed\
+    \x10\x04\x12\x0e\n\nDispatched\x10\x05\x12\x11\n\rCancelPending\x10\x06\
+    \x12\x14\n\x10CancelProcessing\x10\x07\x12\x12\n\x0eCancelComplete\x10\
+    \x08*k\n\x14JobGroupProjectState\x12\x0e\n\nNotStarted\x10\0\x12\x0e\n\n\
+    InProgress\x10\x01\x12\x0b\n\x07Success\x10\x02\x12\x0b\n\x07Failure\x10\
+    \x03\x12\x0b\n\x07Skipped\x10\x04\x12\x0c\n\x08Canceled\x10\x05*\x7f\n\r\
+    JobGroupState\x12\x10\n\x0cGroupPending\x10\0\x12\x14\n\x10GroupDispatch\
+    ing\x10\x01\x12\x11\n\rGroupComplete\x10\x02\x12\x0f\n\x0bGroupFailed\
+    \x10\x03\x12\x0f\n\x0bGroupQueued\x10\x04\x12\x11\n\rGroupCanceled\x10\
+    \x05J\xc6A\n\x07\x12\x05\0\0\xd8\x01\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\
+    \n\x08\n\x01\x02\x12\x03\x01\0\x0f\n\t\n\x02\x03\0\x12\x03\x02\0\x1d\n\t\
+    \n\x02\x03\x01\x12\x03\x03\0#\n\n\n\x02\x05\0\x12\x04\x05\0\t\x01\n\n\n\
+    \x03\x05\0\x01\x12\x03\x05\x05\x07\n\x0b\n\x04\x05\0\x02\0\x12\x03\x06\
+    \x02\x0c\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x06\x02\x07\n\x0c\n\x05\x05\
+    \0\x02\0\x02\x12\x03\x06\n\x0b\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x07\x02\
+    \r\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x07\x02\x08\n\x0c\n\x05\x05\0\
+    \x02\x01\x02\x12\x03\x07\x0b\x0c\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x08\
+    \x02\x0e\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x08\x02\t\n\x0c\n\x05\x05\
+    \0\x02\x02\x02\x12\x03\x08\x0c\r\n\n\n\x02\x05\x01\x12\x04\x0b\0\x0e\x01\
+    \n\n\n\x03\x05\x01\x01\x12\x03\x0b\x05\x10\n\x0b\n\x04\x05\x01\x02\0\x12\
+    \x03\x0c\x02\x0c\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x0c\x02\x07\n\x0c\
+    \n\x05\x05\x01\x02\0\x02\x12\x03\x0c\n\x0b\n\x0b\n\x04\x05\x01\x02\x01\
+    \x12\x03\r\x02\x0b\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\r\x02\x06\n\
+    \x0c\n\x05\x05\x01\x02\x01\x02\x12\x03\r\t\n\n\n\n\x02\x05\x02\x12\x04\
+    \x10\0\x13\x01\n\n\n\x03\x05\x02\x01\x12\x03\x10\x05\x14\n\x0b\n\x04\x05\
+    \x02\x02\0\x12\x03\x11\x02\x0f\n\x0c\n\x05\x05\x02\x02\0\x01\x12\x03\x11\
+    \x02\n\n\x0c\n\x05\x05\x02\x02\0\x02\x12\x03\x11\r\x0e\n\x0b\n\x04\x05\
+    \x02\x02\x01\x12\x03\x12\x02\x10\n\x0c\n\x05\x05\x02\x02\x01\x01\x12\x03\
+    \x12\x02\x0b\n\x0c\n\x05\x05\x02\x02\x01\x02\x12\x03\x12\x0e\x0f\n\n\n\
+    \x02\x05\x03\x12\x04\x15\0\x1f\x01\n\n\n\x03\x05\x03\x01\x12\x03\x15\x05\
+    \r\n\x0b\n\x04\x05\x03\x02\0\x12\x03\x16\x02\x0e\n\x0c\n\x05\x05\x03\x02\
+    \0\x01\x12\x03\x16\x02\t\n\x0c\n\x05\x05\x03\x02\0\x02\x12\x03\x16\x0c\r\
+    \n\x0b\n\x04\x05\x03\x02\x01\x12\x03\x17\x02\x11\n\x0c\n\x05\x05\x03\x02\
+    \x01\x01\x12\x03\x17\x02\x0c\n\x0c\n\x05\x05\x03\x02\x01\x02\x12\x03\x17\
+    \x0f\x10\n\x0b\n\x04\x05\x03\x02\x02\x12\x03\x18\x02\x0f\n\x0c\n\x05\x05\
+    \x03\x02\x02\x01\x12\x03\x18\x02\n\n\x0c\n\x05\x05\x03\x02\x02\x02\x12\
+    \x03\x18\r\x0e\n\x0b\n\x04\x05\x03\x02\x03\x12\x03\x19\x02\x0f\n\x0c\n\
+    \x05\x05\x03\x02\x03\x01\x12\x03\x19\x02\n\n\x0c\n\x05\x05\x03\x02\x03\
+    \x02\x12\x03\x19\r\x0e\n\x0b\n\x04\x05\x03\x02\x04\x12\x03\x1a\x02\r\n\
+    \x0c\n\x05\x05\x03\x02\x04\x01\x12\x03\x1a\x02\x08\n\x0c\n\x05\x05\x03\
+    \x02\x04\x02\x12\x03\x1a\x0b\x0c\n\x0b\n\x04\x05\x03\x02\x05\x12\x03\x1b\
+    \x02\x11\n\x0c\n\x05\x05\x03\x02\x05\x01\x12\x03\x1b\x02\x0c\n\x0c\n\x05\
+    \x05\x03\x02\x05\x02\x12\x03\x1b\x0f\x10\n\x0b\n\x04\x05\x03\x02\x06\x12\
+    \x03\x1c\x02\x14\n\x0c\n\x05\x05\x03\x02\x06\x01\x12\x03\x1c\x02\x0f\n\
+    \x0c\n\x05\x05\x03\x02\x06\x02\x12\x03\x1c\x12\x13\n\x0b\n\x04\x05\x03\
+    \x02\x07\x12\x03\x1d\x02\x17\n\x0c\n\x05\x05\x03\x02\x07\x01\x12\x03\x1d\
+    \x02\x12\n\x0c\n\x05\x05\x03\x02\x07\x02\x12\x03\x1d\x15\x16\n\x0b\n\x04\
+    \x05\x03\x02\x08\x12\x03\x1e\x02\x15\n\x0c\n\x05\x05\x03\x02\x08\x01\x12\
+    \x03\x1e\x02\x10\n\x0c\n\x05\x05\x03\x02\x08\x02\x12\x03\x1e\x13\x14\n\n\
+    \n\x02\x04\0\x12\x04!\0#\x01\n\n\n\x03\x04\0\x01\x12\x03!\x08\x15\n\x0b\
+    \n\x04\x04\0\x02\0\x12\x03\"\x02\"\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\"\
+    \x02\n\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\"\x0b\x1a\n\x0c\n\x05\x04\0\
+    \x02\0\x01\x12\x03\"\x1b\x1d\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\"\x20!\
+    \n\n\n\x02\x04\x01\x12\x04%\0)\x01\n\n\n\x03\x04\x01\x01\x12\x03%\x08\
+    \x11\n\x0b\n\x04\x04\x01\x02\0\x12\x03&\x02\x1f\n\x0c\n\x05\x04\x01\x02\
+    \0\x04\x12\x03&\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03&\x0b\x11\n\
+    \x0c\n\x05\x04\x01\x02\0\x01\x12\x03&\x12\x1a\n\x0c\n\x05\x04\x01\x02\0\
+    \x03\x12\x03&\x1d\x1e\n\x0b\n\x04\x04\x01\x02\x01\x12\x03'\x02\x15\n\x0c\
+    \n\x05\x04\x01\x02\x01\x04\x12\x03'\x02\n\n\x0c\n\x05\x04\x01\x02\x01\
+    \x06\x12\x03'\x0b\r\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03'\x0e\x10\n\
+    \x0c\n\x05\x04\x01\x02\x01\x03\x12\x03'\x13\x14\n\x0b\n\x04\x04\x01\x02\
+    \x02\x12\x03(\x02!\n\x0c\n\x05\x04\x01\x02\x02\x04\x12\x03(\x02\n\n\x0c\
+    \n\x05\x04\x01\x02\x02\x06\x12\x03(\x0b\x16\n\x0c\n\x05\x04\x01\x02\x02\
+    \x01\x12\x03(\x17\x1c\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03(\x1f\x20\n\
+    \n\n\x02\x04\x02\x12\x04+\0/\x01\n\n\n\x03\x04\x02\x01\x12\x03+\x08\x12\
+    \n\x0b\n\x04\x04\x02\x02\0\x12\x03,\x02\x1c\n\x0c\n\x05\x04\x02\x02\0\
+    \x04\x12\x03,\x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03,\x0b\x11\n\x0c\
+    \n\x05\x04\x02\x02\0\x01\x12\x03,\x12\x17\n\x0c\n\x05\x04\x02\x02\0\x03\
+    \x12\x03,\x1a\x1b\n\x0b\n\x04\x04\x02\x02\x01\x12\x03-\x02\x1d\n\x0c\n\
+    \x05\x04\x02\x02\x01\x04\x12\x03-\x02\n\n\x0c\n\x05\x04\x02\x02\x01\x05\
+    \x12\x03-\x0b\x11\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03-\x12\x18\n\x0c\
+    \n\x05\x04\x02\x02\x01\x03\x12\x03-\x1b\x1c\n\x0b\n\x04\x04\x02\x02\x02\
+    \x12\x03.\x02\x20\n\x0c\n\x05\x04\x02\x02\x02\x04\x12\x03.\x02\n\n\x0c\n\
+    \x05\x04\x02\x02\x02\x05\x12\x03.\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\
+    \x01\x12\x03.\x10\x1b\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03.\x1e\x1f\n\
+    \n\n\x02\x04\x03\x12\x041\0C\x01\n\n\n\x03\x04\x03\x01\x12\x031\x08\x0b\
+    \n\n\n\x03\x04\x03\t\x12\x032\x02\x0e\n\x0b\n\x04\x04\x03\t\0\x12\x032\
+    \x0b\r\n\x0c\n\x05\x04\x03\t\0\x01\x12\x032\x0b\r\n\x0c\n\x05\x04\x03\t\
+    \0\x02\x12\x032\x0b\r\n\n\n\x03\x04\x03\n\x12\x033\x02\x15\n\x0b\n\x04\
+    \x04\x03\n\0\x12\x033\x0b\x14\n\x0b\n\x04\x04\x03\x02\0\x12\x034\x02\x19\
+    \n\x0c\n\x05\x04\x03\x02\0\x04\x12\x034\x02\n\n\x0c\n\x05\x04\x03\x02\0\
+    \x05\x12\x034\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x034\x12\x14\n\
+    \x0c\n\x05\x04\x03\x02\0\x03\x12\x034\x17\x18\n\x0b\n\x04\x04\x03\x02\
+    \x01\x12\x035\x02\x1f\n\x0c\n\x05\x04\x03\x02\x01\x04\x12\x035\x02\n\n\
+    \x0c\n\x05\x04\x03\x02\x01\x05\x12\x035\x0b\x11\n\x0c\n\x05\x04\x03\x02\
+    \x01\x01\x12\x035\x12\x1a\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x035\x1d\
+    \x1e\n\x0b\n\x04\x04\x03\x02\x02\x12\x036\x02\x1e\n\x0c\n\x05\x04\x03\
+    \x02\x02\x04\x12\x036\x02\n\n\x0c\n\x05\x04\x03\x02\x02\x06\x12\x036\x0b\
+    \x13\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x036\x14\x19\n\x0c\n\x05\x04\
+    \x03\x02\x02\x03\x12\x036\x1c\x1d\n\x0b\n\x04\x04\x03\x02\x03\x12\x037\
+    \x02/\n\x0c\n\x05\x04\x03\x02\x03\x04\x12\x037\x02\n\n\x0c\n\x05\x04\x03\
+    \x02\x03\x06\x12\x037\x0b\"\n\x0c\n\x05\x04\x03\x02\x03\x01\x12\x037#*\n\
+    \x0c\n\x05\x04\x03\x02\x03\x03\x12\x037-.\n\x0b\n\x04\x04\x03\x02\x04\
+    \x12\x038\x02\"\n\x0c\n\x05\x04\x03\x02\x04\x04\x12\x038\x02\n\n\x0c\n\
+    \x05\x04\x03\x02\x04\x06\x12\x038\x0b\x17\n\x0c\n\x05\x04\x03\x02\x04\
+    \x01\x12\x038\x18\x1d\n\x0c\n\x05\x04\x03\x02\x04\x03\x12\x038\x20!\n%\n\
+    \x04\x04\x03\x02\x05\x12\x039\x02!\"\x18\x20RFC3339-formatted\x20time\n\
+    \n\x0c\n\x05\x04\x03\x02\x05\x04\x12\x039\x02\n\n\x0c\n\x05\x04\x03\x02\
+    \x05\x05\x12\x039\x0b\x11\n\x0c\n\x05\x04\x03\x02\x05\x01\x12\x039\x12\
+    \x1c\n\x0c\n\x05\x04\x03\x02\x05\x03\x12\x039\x1f\x20\n%\n\x04\x04\x03\
+    \x02\x06\x12\x03:\x02'\"\x18\x20RFC3339-formatted\x20time\n\n\x0c\n\x05\
+    \x04\x03\x02\x06\x04\x12\x03:\x02\n\n\x0c\n\x05\x04\x03\x02\x06\x05\x12\
+    \x03:\x0b\x11\n\x0c\n\x05\x04\x03\x02\x06\x01\x12\x03:\x12\"\n\x0c\n\x05\
+    \x04\x03\x02\x06\x03\x12\x03:%&\n\x0b\n\x04\x04\x03\x02\x07\x12\x03;\x02\
+    (\n\x0c\n\x05\x04\x03\x02\x07\x04\x12\x03;\x02\n\n\x0c\n\x05\x04\x03\x02\
+    \x07\x05\x12\x03;\x0b\x11\n\x0c\n\x05\x04\x03\x02\x07\x01\x12\x03;\x12#\
+    \n\x0c\n\x05\x04\x03\x02\x07\x03\x12\x03;&'\n\x0b\n\x04\x04\x03\x02\x08\
+    \x12\x03<\x02:\n\x0c\n\x05\x04\x03\x02\x08\x04\x12\x03<\x02\n\n\x0c\n\
+    \x05\x04\x03\x02\x08\x06\x12\x03<\x0b'\n\x0c\n\x05\x04\x03\x02\x08\x01\
+    \x12\x03<(5\n\x0c\n\x05\x04\x03\x02\x08\x03\x12\x03<89\n\x0b\n\x04\x04\
+    \x03\x02\t\x12\x03=\x02!\n\x0c\n\x05\x04\x03\x02\t\x04\x12\x03=\x02\n\n\
+    \x0c\n\x05\x04\x03\x02\t\x05\x12\x03=\x0b\x0f\n\x0c\n\x05\x04\x03\x02\t\
+    \x01\x12\x03=\x10\x1b\n\x0c\n\x05\x04\x03\x02\t\x03\x12\x03=\x1e\x20\n\
+    \x0b\n\x04\x04\x03\x02\n\x12\x03>\x029\n\x0c\n\x05\x04\x03\x02\n\x04\x12\
+    \x03>\x02\n\n\x0c\n\x05\x04\x03\x02\n\x06\x12\x03>\x0b&\n\x0c\n\x05\x04\
+    \x03\x02\n\x01\x12\x03>'3\n\x0c\n\x05\x04\x03\x02\n\x03\x12\x03>68\n\x0b\
+    \n\x04\x04\x03\x02\x0b\x12\x03?\x02\x1f\n\x0c\n\x05\x04\x03\x02\x0b\x04\
+    \x12\x03?\x02\n\n\x0c\n\x05\x04\x03\x02\x0b\x05\x12\x03?\x0b\x11\n\x0c\n\
+    \x05\x04\x03\x02\x0b\x01\x12\x03?\x12\x19\n\x0c\n\x05\x04\x03\x02\x0b\
+    \x03\x12\x03?\x1c\x1e\n\x0b\n\x04\x04\x03\x02\x0c\x12\x03@\x02H\n\x0c\n\
+    \x05\x04\x03\x02\x0c\x04\x12\x03@\x02\n\n\x0c\n\x05\x04\x03\x02\x0c\x06\
+    \x12\x03@\x0b-\n\x0c\n\x05\x04\x03\x02\x0c\x01\x12\x03@.B\n\x0c\n\x05\
+    \x04\x03\x02\x0c\x03\x12\x03@EG\n\x0b\n\x04\x04\x03\x02\r\x12\x03A\x02\
+    \x1e\n\x0c\n\x05\x04\x03\x02\r\x04\x12\x03A\x02\n\n\x0c\n\x05\x04\x03\
+    \x02\r\x05\x12\x03A\x0b\x11\n\x0c\n\x05\x04\x03\x02\r\x01\x12\x03A\x12\
+    \x18\n\x0c\n\x05\x04\x03\x02\r\x03\x12\x03A\x1b\x1d\nB\n\x04\x04\x03\x02\
+    \x0e\x12\x03B\x02\x1e\"5\x20The\x20package\x20target\x20to\x20build\x20f\
+    or\x20(ex:\x20aarch64-linux)\n\n\x0c\n\x05\x04\x03\x02\x0e\x04\x12\x03B\
+    \x02\n\n\x0c\n\x05\x04\x03\x02\x0e\x05\x12\x03B\x0b\x11\n\x0c\n\x05\x04\
+    \x03\x02\x0e\x01\x12\x03B\x12\x18\n\x0c\n\x05\x04\x03\x02\x0e\x03\x12\
+    \x03B\x1b\x1d\n\n\n\x02\x04\x04\x12\x04E\0G\x01\n\n\n\x03\x04\x04\x01\
+    \x12\x03E\x08\x0e\n\x0b\n\x04\x04\x04\x02\0\x12\x03F\x02\x19\n\x0c\n\x05\
+    \x04\x04\x02\0\x04\x12\x03F\x02\n\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03F\
+    \x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03F\x12\x14\n\x0c\n\x05\x04\
+    \x04\x02\0\x03\x12\x03F\x17\x18\n\n\n\x02\x04\x05\x12\x04I\0N\x01\n\n\n\
+    \x03\x04\x05\x01\x12\x03I\x08\x0f\n\x0b\n\x04\x04\x05\x02\0\x12\x03J\x02\
+    \x1f\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x03J\x02\n\n\x0c\n\x05\x04\x05\
+    \x02\0\x05\x12\x03J\x0b\x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03J\x12\
+    \x1a\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03J\x1d\x1e\n\x0b\n\x04\x04\x05\
+    \x02\x01\x12\x03K\x02/\n\x0c\n\x05\x04\x05\x02\x01\x04\x12\x03K\x02\n\n\
+    \x0c\n\x05\x04\x05\x02\x01\x06\x12\x03K\x0b\"\n\x0c\n\x05\x04\x05\x02\
+    \x01\x01\x12\x03K#*\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03K-.\n\x0b\n\
+    \x04\x04\x05\x02\x02\x12\x03L\x02\x1e\n\x0c\n\x05\x04\x05\x02\x02\x04\
+    \x12\x03L\x02\n\n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x03L\x0b\x11\n\x0c\n\
+    \x05\x04\x05\x02\x02\x01\x12\x03L\x12\x19\n\x0c\n\x05\x04\x05\x02\x02\
+    \x03\x12\x03L\x1c\x1d\n\x0b\n\x04\x04\x05\x02\x03\x12\x03M\x02\x1d\n\x0c\
+    \n\x05\x04\x05\x02\x03\x04\x12\x03M\x02\n\n\x0c\n\x05\x04\x05\x02\x03\
+    \x05\x12\x03M\x0b\x11\n\x0c\n\x05\x04\x05\x02\x03\x01\x12\x03M\x12\x18\n\
+    \x0c\n\x05\x04\x05\x02\x03\x03\x12\x03M\x1b\x1c\n\n\n\x02\x04\x06\x12\
+    \x04P\0T\x01\n\n\n\x03\x04\x06\x01\x12\x03P\x08\x16\n\x0b\n\x04\x04\x06\
+    \x02\0\x12\x03Q\x02\x1b\n\x0c\n\x05\x04\x06\x02\0\x04\x12\x03Q\x02\n\n\
+    \x0c\n\x05\x04\x06\x02\0\x05\x12\x03Q\x0b\x11\n\x0c\n\x05\x04\x06\x02\0\
+    \x01\x12\x03Q\x12\x16\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03Q\x19\x1a\n\
+    \x0b\n\x04\x04\x06\x02\x01\x12\x03R\x02\x1c\n\x0c\n\x05\x04\x06\x02\x01\
+    \x04\x12\x03R\x02\n\n\x0c\n\x05\x04\x06\x02\x01\x05\x12\x03R\x0b\x11\n\
+    \x0c\n\x05\x04\x06\x02\x01\x01\x12\x03R\x12\x17\n\x0c\n\x05\x04\x06\x02\
+    \x01\x03\x12\x03R\x1a\x1b\n\x0b\n\x04\x04\x06\x02\x02\x12\x03S\x02\x1b\n\
+    \x0c\n\x05\x04\x06\x02\x02\x04\x12\x03S\x02\n\n\x0c\n\x05\x04\x06\x02\
+    \x02\x05\x12\x03S\x0b\x11\n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x03S\x12\
+    \x16\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x03S\x19\x1a\n\n\n\x02\x04\x07\
+    \x12\x04V\0[\x01\n\n\n\x03\x04\x07\x01\x12\x03V\x08\x1e\n\x0b\n\x04\x04\
+    \x07\x02\0\x12\x03W\x02\x18\n\x0c\n\x05\x04\x07\x02\0\x04\x12\x03W\x02\n\
+    \n\x0c\n\x05\x04\x07\x02\0\x06\x12\x03W\x0b\x0e\n\x0c\n\x05\x04\x07\x02\
+    \0\x01\x12\x03W\x0f\x13\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03W\x16\x17\n\
+    \x0b\n\x04\x04\x07\x02\x01\x12\x03X\x02\x1c\n\x0c\n\x05\x04\x07\x02\x01\
+    \x04\x12\x03X\x02\n\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03X\x0b\x11\n\
+    \x0c\n\x05\x04\x07\x02\x01\x01\x12\x03X\x12\x17\n\x0c\n\x05\x04\x07\x02\
+    \x01\x03\x12\x03X\x1a\x1b\n\x0b\n\x04\x04\x07\x02\x02\x12\x03Y\x02\x1b\n\
+    \x0c\n\x05\x04\x07\x02\x02\x04\x12\x03Y\x02\n\n\x0c\n\x05\x04\x07\x02\
+    \x02\x05\x12\x03Y\x0b\x11\n\x0c\n\x05\x04\x07\x02\x02\x01\x12\x03Y\x12\
+    \x16\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\x03Y\x19\x1a\n\x0b\n\x04\x04\
+    \x07\x02\x03\x12\x03Z\x02\x1c\n\x0c\n\x05\x04\x07\x02\x03\x04\x12\x03Z\
+    \x02\n\n\x0c\n\x05\x04\x07\x02\x03\x05\x12\x03Z\x0b\x11\n\x0c\n\x05\x04\
+    \x07\x02\x03\x01\x12\x03Z\x12\x17\n\x0c\n\x05\x04\x07\x02\x03\x03\x12\
+    \x03Z\x1a\x1b\n\n\n\x02\x04\x08\x12\x04]\0a\x01\n\n\n\x03\x04\x08\x01\
+    \x12\x03]\x08\x13\n\x0b\n\x04\x04\x08\x02\0\x12\x03^\x02\x1d\n\x0c\n\x05\
+    \x04\x08\x02\0\x04\x12\x03^\x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03^\
+    \x0b\x11\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x03^\x12\x18\n\x0c\n\x05\x04\
+    \x08\x02\0\x03\x12\x03^\x1b\x1c\n+\n\x04\x04\x08\x02\x01\x12\x03_\x02\
+    \x1a\"\x1e\x20Chunk\x20ordering\x20(line\x20number)\n\n\x0c\n\x05\x04\
+    \x08\x02\x01\x04\x12\x03_\x02\n\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x03_\
+    \x0b\x11\n\x0c\n\x05\x04\x08\x02\x01\x01\x12\x03_\x12\x15\n\x0c\n\x05\
+    \x04\x08\x02\x01\x03\x12\x03_\x18\x19\n5\n\x04\x04\x08\x02\x02\x12\x03`\
+    \x02\x1e\"(\x20Log\x20content\x20(TODO:\x20Make\x20repeatedfield)\n\n\
+    \x0c\n\x05\x04\x08\x02\x02\x04\x12\x03`\x02\n\n\x0c\n\x05\x04\x08\x02\
+    \x02\x05\x12\x03`\x0b\x11\n\x0c\n\x05\x04\x08\x02\x02\x01\x12\x03`\x12\
+    \x19\n\x0c\n\x05\x04\x08\x02\x02\x03\x12\x03`\x1c\x1d\n\n\n\x02\x04\t\
+    \x12\x04c\0e\x01\n\n\n\x03\x04\t\x01\x12\x03c\x08\x16\n\x0b\n\x04\x04\t\
+    \x02\0\x12\x03d\x02\x1d\n\x0c\n\x05\x04\t\x02\0\x04\x12\x03d\x02\n\n\x0c\
+    \n\x05\x04\t\x02\0\x05\x12\x03d\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\
+    \x03d\x12\x18\n\x0c\n\x05\x04\t\x02\0\x03\x12\x03d\x1b\x1c\n\n\n\x02\x04\
+    \n\x12\x04g\0j\x01\n\n\n\x03\x04\n\x01\x12\x03g\x08\x11\n\x0b\n\x04\x04\
+    \n\x02\0\x12\x03h\x02\x19\n\x0c\n\x05\x04\n\x02\0\x04\x12\x03h\x02\n\n\
+    \x0c\n\x05\x04\n\x02\0\x05\x12\x03h\x0b\x11\n\x0c\n\x05\x04\n\x02\0\x01\
+    \x12\x03h\x12\x14\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03h\x17\x18\n.\n\x04\
+    \x04\n\x02\x01\x12\x03i\x02\x1c\"!\x20Zero-indexed\x20line\x20of\x20log\
+    \x20output\n\n\x0c\n\x05\x04\n\x02\x01\x04\x12\x03i\x02\n\n\x0c\n\x05\
+    \x04\n\x02\x01\x05\x12\x03i\x0b\x11\n\x0c\n\x05\x04\n\x02\x01\x01\x12\
+    \x03i\x12\x17\n\x0c\n\x05\x04\n\x02\x01\x03\x12\x03i\x1a\x1b\n\n\n\x02\
+    \x04\x0b\x12\x04l\0q\x01\n\n\n\x03\x04\x0b\x01\x12\x03l\x08\x0e\n,\n\x04\
+    \x04\x0b\x02\0\x12\x03m\x02\x1c\"\x1f\x20Zero-indexed\x20(inclusive)\x20\
+    line\n\n\x0c\n\x05\x04\x0b\x02\0\x04\x12\x03m\x02\n\n\x0c\n\x05\x04\x0b\
+    \x02\0\x05\x12\x03m\x0b\x11\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03m\x12\
+    \x17\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03m\x1a\x1b\n,\n\x04\x04\x0b\x02\
+    \x01\x12\x03n\x02\x1b\"\x1f\x20Zero-indexed\x20(exclusive)\x20line\n\n\
+    \x0c\n\x05\x04\x0b\x02\x01\x04\x12\x03n\x02\n\n\x0c\n\x05\x04\x0b\x02\
+    \x01\x05\x12\x03n\x0b\x11\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03n\x12\
+    \x16\n\x0c\n\x05\x04\x0b\x02\x01\x03\x12\x03n\x19\x1a\n\x0b\n\x04\x04\
+    \x0b\x02\x02\x12\x03o\x02\x1e\n\x0c\n\x05\x04\x0b\x02\x02\x04\x12\x03o\
+    \x02\n\n\x0c\n\x05\x04\x0b\x02\x02\x05\x12\x03o\x0b\x11\n\x0c\n\x05\x04\
+    \x0b\x02\x02\x01\x12\x03o\x12\x19\n\x0c\n\x05\x04\x0b\x02\x02\x03\x12\
+    \x03o\x1c\x1d\n\x0b\n\x04\x04\x0b\x02\x03\x12\x03p\x02\x20\n\x0c\n\x05\
+    \x04\x0b\x02\x03\x04\x12\x03p\x02\n\n\x0c\n\x05\x04\x0b\x02\x03\x05\x12\
+    \x03p\x0b\x0f\n\x0c\n\x05\x04\x0b\x02\x03\x01\x12\x03p\x10\x1b\n\x0c\n\
+    \x05\x04\x0b\x02\x03\x03\x12\x03p\x1e\x1f\n\n\n\x02\x04\x0c\x12\x04s\0z\
+    \x01\n\n\n\x03\x04\x0c\x01\x12\x03s\x08\x14\n\x0b\n\x04\x04\x0c\x02\0\
+    \x12\x03t\x02\x1d\n\x0c\n\x05\x04\x0c\x02\0\x04\x12\x03t\x02\n\n\x0c\n\
+    \x05\x04\x0c\x02\0\x05\x12\x03t\x0b\x11\n\x0c\n\x05\x04\x0c\x02\0\x01\
+    \x12\x03t\x12\x18\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\x03t\x1b\x1c\n\x0b\n\
+    \x04\x04\x0c\x02\x01\x12\x03u\x02\x1e\n\x0c\n\x05\x04\x0c\x02\x01\x04\
+    \x12\x03u\x02\n\n\x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03u\x0b\x11\n\x0c\n\
+    \x05\x04\x0c\x02\x01\x01\x12\x03u\x12\x19\n\x0c\n\x05\x04\x0c\x02\x01\
+    \x03\x12\x03u\x1c\x1d\n\x0b\n\x04\x04\x0c\x02\x02\x12\x03v\x02\x1e\n\x0c\
+    \n\x05\x04\x0c\x02\x02\x04\x12\x03v\x02\n\n\x0c\n\x05\x04\x0c\x02\x02\
+    \x05\x12\x03v\x0b\x0f\n\x0c\n\x05\x04\x0c\x02\x02\x01\x12\x03v\x10\x19\n\
+    \x0c\n\x05\x04\x0c\x02\x02\x03\x12\x03v\x1c\x1d\n\x0b\n\x04\x04\x0c\x02\
+    \x03\x12\x03w\x02\x1d\n\x0c\n\x05\x04\x0c\x02\x03\x04\x12\x03w\x02\n\n\
+    \x0c\n\x05\x04\x0c\x02\x03\x05\x12\x03w\x0b\x11\n\x0c\n\x05\x04\x0c\x02\
+    \x03\x01\x12\x03w\x12\x18\n\x0c\n\x05\x04\x0c\x02\x03\x03\x12\x03w\x1b\
+    \x1c\n\x0b\n\x04\x04\x0c\x02\x04\x12\x03x\x02\x20\n\x0c\n\x05\x04\x0c\
+    \x02\x04\x04\x12\x03x\x02\n\n\x0c\n\x05\x04\x0c\x02\x04\x05\x12\x03x\x0b\
+    \x0f\n\x0c\n\x05\x04\x0c\x02\x04\x01\x12\x03x\x10\x1b\n\x0c\n\x05\x04\
+    \x0c\x02\x04\x03\x12\x03x\x1e\x1f\n\x0b\n\x04\x04\x0c\x02\x05\x12\x03y\
+    \x02!\n\x0c\n\x05\x04\x0c\x02\x05\x04\x12\x03y\x02\n\n\x0c\n\x05\x04\x0c\
+    \x02\x05\x05\x12\x03y\x0b\x0f\n\x0c\n\x05\x04\x0c\x02\x05\x01\x12\x03y\
+    \x10\x1c\n\x0c\n\x05\x04\x0c\x02\x05\x03\x12\x03y\x1f\x20\n\x0b\n\x02\
+    \x05\x04\x12\x05|\0\x83\x01\x01\n\n\n\x03\x05\x04\x01\x12\x03|\x05\x19\n\
+    \x0b\n\x04\x05\x04\x02\0\x12\x03}\x02\x11\n\x0c\n\x05\x05\x04\x02\0\x01\
+    \x12\x03}\x02\x0c\n\x0c\n\x05\x05\x04\x02\0\x02\x12\x03}\x0f\x10\n\x0b\n\
+    \x04\x05\x04\x02\x01\x12\x03~\x02\x11\n\x0c\n\x05\x05\x04\x02\x01\x01\
+    \x12\x03~\x02\x0c\n\x0c\n\x05\x05\x04\x02\x01\x02\x12\x03~\x0f\x10\n\x0b\
+    \n\x04\x05\x04\x02\x02\x12\x03\x7f\x02\x0e\n\x0c\n\x05\x05\x04\x02\x02\
+    \x01\x12\x03\x7f\x02\t\n\x0c\n\x05\x05\x04\x02\x02\x02\x12\x03\x7f\x0c\r\
+    \n\x0c\n\x04\x05\x04\x02\x03\x12\x04\x80\x01\x02\x0e\n\r\n\x05\x05\x04\
+    \x02\x03\x01\x12\x04\x80\x01\x02\t\n\r\n\x05\x05\x04\x02\x03\x02\x12\x04\
+    \x80\x01\x0c\r\n\x0c\n\x04\x05\x04\x02\x04\x12\x04\x81\x01\x02\x0e\n\r\n\
+    \x05\x05\x04\x02\x04\x01\x12\x04\x81\x01\x02\t\n\r\n\x05\x05\x04\x02\x04\
+    \x02\x12\x04\x81\x01\x0c\r\n\x0c\n\x04\x05\x04\x02\x05\x12\x04\x82\x01\
+    \x02\x0f\n\r\n\x05\x05\x04\x02\x05\x01\x12\x04\x82\x01\x02\n\n\r\n\x05\
+    \x05\x04\x02\x05\x02\x12\x04\x82\x01\r\x0e\n\x0c\n\x02\x04\r\x12\x06\x85\
+    \x01\0\x8b\x01\x01\n\x0b\n\x03\x04\r\x01\x12\x04\x85\x01\x08\x17\n\x0c\n\
+    \x04\x04\r\x02\0\x12\x04\x86\x01\x02\x1b\n\r\n\x05\x04\r\x02\0\x04\x12\
+    \x04\x86\x01\x02\n\n\r\n\x05\x04\r\x02\0\x05\x12\x04\x86\x01\x0b\x11\n\r\
+    \n\x05\x04\r\x02\0\x01\x12\x04\x86\x01\x12\x16\n\r\n\x05\x04\r\x02\0\x03\
+    \x12\x04\x86\x01\x19\x1a\n\x0c\n\x04\x04\r\x02\x01\x12\x04\x87\x01\x02\
+    \x1c\n\r\n\x05\x04\r\x02\x01\x04\x12\x04\x87\x01\x02\n\n\r\n\x05\x04\r\
+    \x02\x01\x05\x12\x04\x87\x01\x0b\x11\n\r\n\x05\x04\r\x02\x01\x01\x12\x04\
+    \x87\x01\x12\x17\n\r\n\x05\x04\r\x02\x01\x03\x12\x04\x87\x01\x1a\x1b\n\
+    \x0c\n\x04\x04\r\x02\x02\x12\x04\x88\x01\x02*\n\r\n\x05\x04\r\x02\x02\
+    \x04\x12\x04\x88\x01\x02\n\n\r\n\x05\x04\r\x02\x02\x06\x12\x04\x88\x01\
+    \x0b\x1f\n\r\n\x05\x04\r\x02\x02\x01\x12\x04\x88\x01\x20%\n\r\n\x05\x04\
+    \r\x02\x02\x03\x12\x04\x88\x01()\n\x0c\n\x04\x04\r\x02\x03\x12\x04\x89\
+    \x01\x02\x1d\n\r\n\x05\x04\r\x02\x03\x04\x12\x04\x89\x01\x02\n\n\r\n\x05\
+    \x04\r\x02\x03\x05\x12\x04\x89\x01\x0b\x11\n\r\n\x05\x04\r\x02\x03\x01\
+    \x12\x04\x89\x01\x12\x18\n\r\n\x05\x04\r\x02\x03\x03\x12\x04\x89\x01\x1b\
+    \x1c\n\x0c\n\x04\x04\r\x02\x04\x12\x04\x8a\x01\x02\x1d\n\r\n\x05\x04\r\
+    \x02\x04\x04\x12\x04\x8a\x01\x02\n\n\r\n\x05\x04\r\x02\x04\x05\x12\x04\
+    \x8a\x01\x0b\x11\n\r\n\x05\x04\r\x02\x04\x01\x12\x04\x8a\x01\x12\x18\n\r\
+    \n\x05\x04\r\x02\x04\x03\x12\x04\x8a\x01\x1b\x1c\n\x0c\n\x02\x05\x05\x12\
+    \x06\x8d\x01\0\x94\x01\x01\n\x0b\n\x03\x05\x05\x01\x12\x04\x8d\x01\x05\
+    \x12\n\x0c\n\x04\x05\x05\x02\0\x12\x04\x8e\x01\x02\x13\n\r\n\x05\x05\x05\
+    \x02\0\x01\x12\x04\x8e\x01\x02\x0e\n\r\n\x05\x05\x05\x02\0\x02\x12\x04\
+    \x8e\x01\x11\x12\n\x0c\n\x04\x05\x05\x02\x01\x12\x04\x8f\x01\x02\x17\n\r\
+    \n\x05\x05\x05\x02\x01\x01\x12\x04\x8f\x01\x02\x12\n\r\n\x05\x05\x05\x02\
+    \x01\x02\x12\x04\x8f\x01\x15\x16\n\x0c\n\x04\x05\x05\x02\x02\x12\x04\x90\
+    \x01\x02\x14\n\r\n\x05\x05\x05\x02\x02\x01\x12\x04\x90\x01\x02\x0f\n\r\n\
+    \x05\x05\x05\x02\x02\x02\x12\x04\x90\x01\x12\x13\n\x0c\n\x04\x05\x05\x02\
+    \x03\x12\x04\x91\x01\x02\x12\n\r\n\x05\x05\x05\x02\x03\x01\x12\x04\x91\
+    \x01\x02\r\n\r\n\x05\x05\x05\x02\x03\x02\x12\x04\x91\x01\x10\x11\n\x0c\n\
+    \x04\x05\x05\x02\x04\x12\x04\x92\x01\x02\x12\n\r\n\x05\x05\x05\x02\x04\
+    \x01\x12\x04\x92\x01\x02\r\n\r\n\x05\x05\x05\x02\x04\x02\x12\x04\x92\x01\
+    \x10\x11\n\x0c\n\x04\x05\x05\x02\x05\x12\x04\x93\x01\x02\x14\n\r\n\x05\
+    \x05\x05\x02\x05\x01\x12\x04\x93\x01\x02\x0f\n\r\n\x05\x05\x05\x02\x05\
+    \x02\x12\x04\x93\x01\x12\x13\n\x0c\n\x02\x04\x0e\x12\x06\x96\x01\0\x98\
+    \x01\x01\n\x0b\n\x03\x04\x0e\x01\x12\x04\x96\x01\x08\x15\n\x0c\n\x04\x04\
+    \x0e\x02\0\x12\x04\x97\x01\x02\x1f\n\r\n\x05\x04\x0e\x02\0\x04\x12\x04\
+    \x97\x01\x02\n\n\r\n\x05\x04\x0e\x02\0\x05\x12\x04\x97\x01\x0b\x11\n\r\n\
+    \x05\x04\x0e\x02\0\x01\x12\x04\x97\x01\x12\x1a\n\r\n\x05\x04\x0e\x02\0\
+    \x03\x12\x04\x97\x01\x1d\x1e\n\x0c\n\x02\x04\x0f\x12\x06\x9a\x01\0\x9c\
+    \x01\x01\n\x0b\n\x03\x04\x0f\x01\x12\x04\x9a\x01\x08\x16\n\x0c\n\x04\x04\
+    \x0f\x02\0\x12\x04\x9b\x01\x02\x1f\n\r\n\x05\x04\x0f\x02\0\x04\x12\x04\
+    \x9b\x01\x02\n\n\r\n\x05\x04\x0f\x02\0\x05\x12\x04\x9b\x01\x0b\x11\n\r\n\
+    \x05\x04\x0f\x02\0\x01\x12\x04\x9b\x01\x12\x1a\n\r\n\x05\x04\x0f\x02\0\
+    \x03\x12\x04\x9b\x01\x1d\x1e\n\x0c\n\x02\x04\x10\x12\x06\x9e\x01\0\xa0\
+    \x01\x01\n\x0b\n\x03\x04\x10\x01\x12\x04\x9e\x01\x08\x13\n\x0c\n\x04\x04\
+    \x10\x02\0\x12\x04\x9f\x01\x02\x1f\n\r\n\x05\x04\x10\x02\0\x04\x12\x04\
+    \x9f\x01\x02\n\n\r\n\x05\x04\x10\x02\0\x05\x12\x04\x9f\x01\x0b\x11\n\r\n\
+    \x05\x04\x10\x02\0\x01\x12\x04\x9f\x01\x12\x1a\n\r\n\x05\x04\x10\x02\0\
+    \x03\x12\x04\x9f\x01\x1d\x1e\n\x0c\n\x02\x04\x11\x12\x06\xa2\x01\0\xa4\
+    \x01\x01\n\x0b\n\x03\x04\x11\x01\x12\x04\xa2\x01\x08\x19\n\x0c\n\x04\x04\
+    \x11\x02\0\x12\x04\xa3\x01\x02\x1d\n\r\n\x05\x04\x11\x02\0\x04\x12\x04\
+    \xa3\x01\x02\n\n\r\n\x05\x04\x11\x02\0\x05\x12\x04\xa3\x01\x0b\x11\n\r\n\
+    \x05\x04\x11\x02\0\x01\x12\x04\xa3\x01\x12\x18\n\r\n\x05\x04\x11\x02\0\
+    \x03\x12\x04\xa3\x01\x1b\x1c\n\x0c\n\x02\x04\x12\x12\x06\xa6\x01\0\xa8\
+    \x01\x01\n\x0b\n\x03\x04\x12\x01\x12\x04\xa6\x01\x08\x1e\n\x0c\n\x04\x04\
+    \x12\x02\0\x12\x04\xa7\x01\x02#\n\r\n\x05\x04\x12\x02\0\x04\x12\x04\xa7\
+    \x01\x02\n\n\r\n\x05\x04\x12\x02\0\x06\x12\x04\xa7\x01\x0b\x13\n\r\n\x05\
+    \x04\x12\x02\0\x01\x12\x04\xa7\x01\x14\x1e\n\r\n\x05\x04\x12\x02\0\x03\
+    \x12\x04\xa7\x01!\"\n\x0c\n\x02\x04\x13\x12\x06\xaa\x01\0\xb0\x01\x01\n\
+    \x0b\n\x03\x04\x13\x01\x12\x04\xaa\x01\x08\x10\n\x0c\n\x04\x04\x13\x02\0\
+    \x12\x04\xab\x01\x02\x19\n\r\n\x05\x04\x13\x02\0\x04\x12\x04\xab\x01\x02\
+    \n\n\r\n\x05\x04\x13\x02\0\x05\x12\x04\xab\x01\x0b\x11\n\r\n\x05\x04\x13\
+    \x02\0\x01\x12\x04\xab\x01\x12\x14\n\r\n\x05\x04\x13\x02\0\x03\x12\x04\
+    \xab\x01\x17\x18\n\x0c\n\x04\x04\x13\x02\x01\x12\x04\xac\x01\x02#\n\r\n\
+    \x05\x04\x13\x02\x01\x04\x12\x04\xac\x01\x02\n\n\r\n\x05\x04\x13\x02\x01\
+    \x06\x12\x04\xac\x01\x0b\x18\n\r\n\x05\x04\x13\x02\x01\x01\x12\x04\xac\
+    \x01\x19\x1e\n\r\n\x05\x04\x13\x02\x01\x03\x12\x04\xac\x01!\"\n\x0c\n\
+    \x04\x04\x13\x02\x02\x12\x04\xad\x01\x02(\n\r\n\x05\x04\x13\x02\x02\x04\
+    \x12\x04\xad\x01\x02\n\n\r\n\x05\x04\x13\x02\x02\x06\x12\x04\xad\x01\x0b\
+    \x1a\n\r\n\x05\x04\x13\x02\x02\x01\x12\x04\xad\x01\x1b#\n\r\n\x05\x04\
+    \x13\x02\x02\x03\x12\x04\xad\x01&'\n\x0c\n\x04\x04\x13\x02\x03\x12\x04\
+    \xae\x01\x02!\n\r\n\x05\x04\x13\x02\x03\x04\x12\x04\xae\x01\x02\n\n\r\n\
+    \x05\x04\x13\x02\x03\x05\x12\x04\xae\x01\x0b\x11\n\r\n\x05\x04\x13\x02\
+    \x03\x01\x12\x04\xae\x01\x12\x1c\n\r\n\x05\x04\x13\x02\x03\x03\x12\x04\
+    \xae\x01\x1f\x20\n\x0c\n\x04\x04\x13\x02\x04\x12\x04\xaf\x01\x02#\n\r\n\
+    \x05\x04\x13\x02\x04\x04\x12\x04\xaf\x01\x02\n\n\r\n\x05\x04\x13\x02\x04\
+    \x05\x12\x04\xaf\x01\x0b\x11\n\r\n\x05\x04\x13\x02\x04\x01\x12\x04\xaf\
+    \x01\x12\x1e\n\r\n\x05\x04\x13\x02\x04\x03\x12\x04\xaf\x01!\"\n\x0c\n\
+    \x02\x04\x14\x12\x06\xb2\x01\0\xb6\x01\x01\n\x0b\n\x03\x04\x14\x01\x12\
+    \x04\xb2\x01\x08\x17\n\x0c\n\x04\x04\x14\x02\0\x12\x04\xb3\x01\x02\x1c\n\
+    \r\n\x05\x04\x14\x02\0\x04\x12\x04\xb3\x01\x02\n\n\r\n\x05\x04\x14\x02\0\
+    \x05\x12\x04\xb3\x01\x0b\x11\n\r\n\x05\x04\x14\x02\0\x01\x12\x04\xb3\x01\
+    \x12\x17\n\r\n\x05\x04\x14\x02\0\x03\x12\x04\xb3\x01\x1a\x1b\n\x0c\n\x04\
+    \x04\x14\x02\x01\x12\x04\xb4\x01\x02\x1b\n\r\n\x05\x04\x14\x02\x01\x04\
+    \x12\x04\xb4\x01\x02\n\n\r\n\x05\x04\x14\x02\x01\x05\x12\x04\xb4\x01\x0b\
+    \x11\n\r\n\x05\x04\x14\x02\x01\x01\x12\x04\xb4\x01\x12\x16\n\r\n\x05\x04\
+    \x14\x02\x01\x03\x12\x04\xb4\x01\x19\x1a\n\x0c\n\x04\x04\x14\x02\x02\x12\
+    \x04\xb5\x01\x02\x1d\n\r\n\x05\x04\x14\x02\x02\x04\x12\x04\xb5\x01\x02\n\
+    \n\r\n\x05\x04\x14\x02\x02\x05\x12\x04\xb5\x01\x0b\x11\n\r\n\x05\x04\x14\
+    \x02\x02\x01\x12\x04\xb5\x01\x12\x18\n\r\n\x05\x04\x14\x02\x02\x03\x12\
+    \x04\xb5\x01\x1b\x1c\n\x0c\n\x02\x04\x15\x12\x06\xb8\x01\0\xbc\x01\x01\n\
+    \x0b\n\x03\x04\x15\x01\x12\x04\xb8\x01\x08\x20\n\x0c\n\x04\x04\x15\x02\0\
+    \x12\x04\xb9\x01\x02\x1c\n\r\n\x05\x04\x15\x02\0\x04\x12\x04\xb9\x01\x02\
+    \n\n\r\n\x05\x04\x15\x02\0\x05\x12\x04\xb9\x01\x0b\x11\n\r\n\x05\x04\x15\
+    \x02\0\x01\x12\x04\xb9\x01\x12\x17\n\r\n\x05\x04\x15\x02\0\x03\x12\x04\
+    \xb9\x01\x1a\x1b\n\x0c\n\x04\x04\x15\x02\x01\x12\x04\xba\x01\x02\x1b\n\r\
+    \n\x05\x04\x15\x02\x01\x04\x12\x04\xba\x01\x02\n\n\r\n\x05\x04\x15\x02\
+    \x01\x05\x12\x04\xba\x01\x0b\x11\n\r\n\x05\x04\x15\x02\x01\x01\x12\x04\
+    \xba\x01\x12\x16\n\r\n\x05\x04\x15\x02\x01\x03\x12\x04\xba\x01\x19\x1a\n\
+    \x0c\n\x04\x04\x15\x02\x02\x12\x04\xbb\x01\x02\x1d\n\r\n\x05\x04\x15\x02\
+    \x02\x04\x12\x04\xbb\x01\x02\n\n\r\n\x05\x04\x15\x02\x02\x05\x12\x04\xbb\
+    \x01\x0b\x11\n\r\n\x05\x04\x15\x02\x02\x01\x12\x04\xbb\x01\x12\x18\n\r\n\
+    \x05\x04\x15\x02\x02\x03\x12\x04\xbb\x01\x1b\x1c\n\x0c\n\x02\x04\x16\x12\
+    \x06\xbe\x01\0\xc2\x01\x01\n\x0b\n\x03\x04\x16\x01\x12\x04\xbe\x01\x08\
+    \x1d\n\x0c\n\x04\x04\x16\x02\0\x12\x04\xbf\x01\x02\x1c\n\r\n\x05\x04\x16\
+    \x02\0\x04\x12\x04\xbf\x01\x02\n\n\r\n\x05\x04\x16\x02\0\x05\x12\x04\xbf\
+    \x01\x0b\x11\n\r\n\x05\x04\x16\x02\0\x01\x12\x04\xbf\x01\x12\x17\n\r\n\
+    \x05\x04\x16\x02\0\x03\x12\x04\xbf\x01\x1a\x1b\n\x0c\n\x04\x04\x16\x02\
+    \x01\x12\x04\xc0\x01\x02\x1b\n\r\n\x05\x04\x16\x02\x01\x04\x12\x04\xc0\
+    \x01\x02\n\n\r\n\x05\x04\x16\x02\x01\x05\x12\x04\xc0\x01\x0b\x11\n\r\n\
+    \x05\x04\x16\x02\x01\x01\x12\x04\xc0\x01\x12\x16\n\r\n\x05\x04\x16\x02\
+    \x01\x03\x12\x04\xc0\x01\x19\x1a\n\x0c\n\x04\x04\x16\x02\x02\x12\x04\xc1\
+    \x01\x02\x1d\n\r\n\x05\x04\x16\x02\x02\x04\x12\x04\xc1\x01\x02\n\n\r\n\
+    \x05\x04\x16\x02\x02\x05\x12\x04\xc1\x01\x0b\x11\n\r\n\x05\x04\x16\x02\
+    \x02\x01\x12\x04\xc1\x01\x12\x18\n\r\n\x05\x04\x16\x02\x02\x03\x12\x04\
+    \xc1\x01\x1b\x1c\n\x0c\n\x02\x04\x17\x12\x06\xc4\x01\0\xc8\x01\x01\n\x0b\
+    \n\x03\x04\x17\x01\x12\x04\xc4\x01\x08-\n\x0c\n\x04\x04\x17\x02\0\x12\
+    \x04\xc5\x01\x02\x1d\n\r\n\x05\x04\x17\x02\0\x04\x12\x04\xc5\x01\x02\n\n\
+    \r\n\x05\x04\x17\x02\0\x05\x12\x04\xc5\x01\x0b\x11\n\r\n\x05\x04\x17\x02\
+    \0\x01\x12\x04\xc5\x01\x12\x18\n\r\n\x05\x04\x17\x02\0\x03\x12\x04\xc5\
+    \x01\x1b\x1c\n\x0c\n\x04\x04\x17\x02\x01\x12\x04\xc6\x01\x02\x1b\n\r\n\
+    \x05\x04\x17\x02\x01\x04\x12\x04\xc6\x01\x02\n\n\r\n\x05\x04\x17\x02\x01\
+    \x05\x12\x04\xc6\x01\x0b\x11\n\r\n\x05\x04\x17\x02\x01\x01\x12\x04\xc6\
+    \x01\x12\x16\n\r\n\x05\x04\x17\x02\x01\x03\x12\x04\xc6\x01\x19\x1a\n\x0c\
+    \n\x04\x04\x17\x02\x02\x12\x04\xc7\x01\x02\x1d\n\r\n\x05\x04\x17\x02\x02\
+    \x04\x12\x04\xc7\x01\x02\n\n\r\n\x05\x04\x17\x02\x02\x05\x12\x04\xc7\x01\
+    \x0b\x11\n\r\n\x05\x04\x17\x02\x02\x01\x12\x04\xc7\x01\x12\x18\n\r\n\x05\
+    \x04\x17\x02\x02\x03\x12\x04\xc7\x01\x1b\x1c\n\x0c\n\x02\x04\x18\x12\x06\
+    \xca\x01\0\xce\x01\x01\n\x0b\n\x03\x04\x18\x01\x12\x04\xca\x01\x08*\n\
+    \x0c\n\x04\x04\x18\x02\0\x12\x04\xcb\x01\x02\x1d\n\r\n\x05\x04\x18\x02\0\
+    \x04\x12\x04\xcb\x01\x02\n\n\r\n\x05\x04\x18\x02\0\x05\x12\x04\xcb\x01\
+    \x0b\x11\n\r\n\x05\x04\x18\x02\0\x01\x12\x04\xcb\x01\x12\x18\n\r\n\x05\
+    \x04\x18\x02\0\x03\x12\x04\xcb\x01\x1b\x1c\n\x0c\n\x04\x04\x18\x02\x01\
+    \x12\x04\xcc\x01\x02\x1b\n\r\n\x05\x04\x18\x02\x01\x04\x12\x04\xcc\x01\
+    \x02\n\n\r\n\x05\x04\x18\x02\x01\x05\x12\x04\xcc\x01\x0b\x11\n\r\n\x05\
+    \x04\x18\x02\x01\x01\x12\x04\xcc\x01\x12\x16\n\r\n\x05\x04\x18\x02\x01\
+    \x03\x12\x04\xcc\x01\x19\x1a\n\x0c\n\x04\x04\x18\x02\x02\x12\x04\xcd\x01\
+    \x02\x1c\n\r\n\x05\x04\x18\x02\x02\x04\x12\x04\xcd\x01\x02\n\n\r\n\x05\
+    \x04\x18\x02\x02\x05\x12\x04\xcd\x01\x0b\x11\n\r\n\x05\x04\x18\x02\x02\
+    \x01\x12\x04\xcd\x01\x12\x17\n\r\n\x05\x04\x18\x02\x02\x03\x12\x04\xcd\
+    \x01\x1a\x1b\n\x0c\n\x02\x04\x19\x12\x06\xd0\x01\0\xd2\x01\x01\n\x0b\n\
+    \x03\x04\x19\x01\x12\x04\xd0\x01\x08\x1f\n\x0c\n\x04\x04\x19\x02\0\x12\
+    \x04\xd1\x01\x02\x1d\n\r\n\x05\x04\x19\x02\0\x04\x12\x04\xd1\x01\x02\n\n\
+    \r\n\x05\x04\x19\x02\0\x05\x12\x04\xd1\x01\x0b\x11\n\r\n\x05\x04\x19\x02\
+    \0\x01\x12\x04\xd1\x01\x12\x18\n\r\n\x05\x04\x19\x02\0\x03\x12\x04\xd1\
+    \x01\x1b\x1c\n\x0c\n\x02\x04\x1a\x12\x06\xd4\x01\0\xd8\x01\x01\n\x0b\n\
+    \x03\x04\x1a\x01\x12\x04\xd4\x01\x08\x1c\n\x0c\n\x04\x04\x1a\x02\0\x12\
+    \x04\xd5\x01\x02\x1c\n\r\n\x05\x04\x1a\x02\0\x04\x12\x04\xd5\x01\x02\n\n\
+    \r\n\x05\x04\x1a\x02\0\x05\x12\x04\xd5\x01\x0b\x11\n\r\n\x05\x04\x1a\x02\
+    \0\x01\x12\x04\xd5\x01\x12\x17\n\r\n\x05\x04\x1a\x02\0\x03\x12\x04\xd5\
+    \x01\x1a\x1b\n\x0c\n\x04\x04\x1a\x02\x01\x12\x04\xd6\x01\x02\x1d\n\r\n\
+    \x05\x04\x1a\x02\x01\x04\x12\x04\xd6\x01\x02\n\n\r\n\x05\x04\x1a\x02\x01\
+    \x05\x12\x04\xd6\x01\x0b\x11\n\r\n\x05\x04\x1a\x02\x01\x01\x12\x04\xd6\
+    \x01\x12\x18\n\r\n\x05\x04\x1a\x02\x01\x03\x12\x04\xd6\x01\x1b\x1c\n\x0c\
+    \n\x04\x04\x1a\x02\x02\x12\x04\xd7\x01\x02&\n\r\n\x05\x04\x1a\x02\x02\
+    \x04\x12\x04\xd7\x01\x02\n\n\r\n\x05\x04\x1a\x02\x02\x05\x12\x04\xd7\x01\
+    \x0b\x11\n\r\n\x05\x04\x1a\x02\x02\x01\x12\x04\xd7\x01\x12!\n\r\n\x05\
+    \x04\x1a\x02\x02\x03\x12\x04\xd7\x01$%\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -23,6 +23,7 @@ mod workspace;
 
 use std::path::PathBuf;
 use std::fs;
+use std::str::FromStr;
 use std::sync::{mpsc, Arc};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread::{self, JoinHandle};
@@ -35,6 +36,7 @@ use chrono::Utc;
 use depot_client;
 use hab_core::os::users;
 use hab_core::package::archive::PackageArchive;
+use hab_core::package::{PackageTarget, Target};
 use hab_core::util::perm;
 use hab_net::socket::DEFAULT_CONTEXT;
 use protocol::{message, jobsrv};
@@ -146,6 +148,26 @@ impl Runner {
             return Err(err);
         };
 
+        // Workers only build for their own target
+        if self.workspace.job.has_target() {
+            if let Some(err) = PackageTarget::from_str(self.workspace.job.get_target())
+                .and_then(|target| target.validate())
+                .err()
+            {
+                let msg = format!(
+                    "Cannot build {} on this worker, err={}",
+                    self.workspace.job.get_project().get_name(),
+                    err
+                );
+                debug!("{}", msg);
+                self.logger.log(&msg);
+
+                self.fail(net::err(ErrCode::BUILD, "wk:run:9"));
+                tx.send(self.job().clone()).map_err(Error::Mpsc)?;
+                return Err(Error::HabitatCore(err));
+            }
+        }
+
         Ok(())
     }
 
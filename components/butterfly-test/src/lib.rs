@@ -30,6 +30,7 @@ use std::str::FromStr;
 use time::SteadyTime;
 
 use habitat_butterfly::server::{Server, Suitability};
+use habitat_butterfly::server::federation::Federation;
 use habitat_butterfly::member::{Member, Health};
 use habitat_butterfly::server::timing::Timing;
 use habitat_butterfly::rumor::departure::Departure;
@@ -53,6 +54,19 @@ impl Suitability for NSuitability {
 }
 
 pub fn start_server(name: &str, ring_key: Option<SymKey>, suitability: u64) -> Server {
+    start_server_with_federation(name, ring_key, suitability, None)
+}
+
+pub fn start_gateway(name: &str, federation: Federation) -> Server {
+    start_server_with_federation(name, None, 0, Some(federation))
+}
+
+fn start_server_with_federation(
+    name: &str,
+    ring_key: Option<SymKey>,
+    suitability: u64,
+    federation: Option<Federation>,
+) -> Server {
     SERVER_PORT.compare_and_swap(0, 6666, Ordering::Relaxed);
     let swim_port = SERVER_PORT.fetch_add(1, Ordering::Relaxed);
     let gossip_port = SERVER_PORT.fetch_add(1, Ordering::Relaxed);
@@ -71,6 +85,9 @@ pub fn start_server(name: &str, ring_key: Option<SymKey>, suitability: u64) -> S
         None::<PathBuf>,
         Box::new(NSuitability(suitability)),
     ).unwrap();
+    if let Some(federation) = federation {
+        server.set_federation(federation);
+    }
     server.start(Timing::default()).expect(
         "Cannot start server",
     );
@@ -145,6 +162,13 @@ impl SwimNet {
         );
     }
 
+    pub fn add_gateway(&mut self, federation: Federation) {
+        let number = self.members.len() + 1;
+        self.members.push(
+            start_gateway(&format!("{}", number), federation),
+        );
+    }
+
     // Fully mesh the network
     pub fn mesh(&mut self) {
         trace_it!(TEST_NET: self, "Mesh");
@@ -16,7 +16,7 @@
 //!
 //! This will connect to a given butterfly members `Pull` thread, and inject a rumor.
 
-use habitat_core::crypto::SymKey;
+use habitat_core::crypto::{SigKeyPair, SymKey};
 use habitat_core::service::ServiceGroup;
 use zmq;
 
@@ -24,6 +24,8 @@ use ZMQ_CONTEXT;
 use message;
 use rumor::Rumor;
 use rumor::departure::Departure;
+use rumor::key_value::KeyValue;
+use rumor::ring_key::RingKey;
 use rumor::service_config::ServiceConfig;
 use rumor::service_file::ServiceFile;
 use error::{Result, Error};
@@ -32,6 +34,7 @@ use error::{Result, Error};
 pub struct Client {
     socket: zmq::Socket,
     ring_key: Option<SymKey>,
+    identity: Option<SigKeyPair>,
 }
 
 impl Client {
@@ -63,9 +66,16 @@ impl Client {
         Ok(Client {
             socket: socket,
             ring_key: ring_key,
+            identity: None,
         })
     }
 
+    /// Sign the traffic with the identity, so Supervisors which trust only certain identities
+    /// accept it.
+    pub fn set_identity(&mut self, identity: SigKeyPair) {
+        self.identity = Some(identity);
+    }
+
     /// Create a departure notification and send it to the server.
     pub fn send_departure<T>(&mut self, member_id: T) -> Result<()>
     where
@@ -104,10 +114,41 @@ impl Client {
         self.send(sf)
     }
 
+    /// Create a key/value pair for a service group and send it to the server.
+    pub fn send_key_value<S1, S2>(
+        &mut self,
+        service_group: ServiceGroup,
+        key: S1,
+        value: S2,
+        incarnation: u64,
+    ) -> Result<()>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let kv = KeyValue::new("butterflyclient", service_group, key, value, incarnation);
+        self.send(kv)
+    }
+
+    /// Create a ring key rotation, naming the revision of the ring key to encrypt with, and send
+    /// it to the server.
+    pub fn send_ring_key<S1, S2>(&mut self, name: S1, revision: S2, incarnation: u64) -> Result<()>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let ring_key = RingKey::new("butterflyclient", name, revision, incarnation);
+        self.send(ring_key)
+    }
+
     /// Send any `Rumor` to the server.
     pub fn send<T: Rumor>(&mut self, rumor: T) -> Result<()> {
-        let bytes = rumor.write_to_bytes()?;
-        let wire_msg = message::generate_wire(bytes, self.ring_key.as_ref())?;
+        let mut bytes = rumor.write_to_bytes()?;
+        if let Some(ref identity) = self.identity {
+            bytes = message::sign_rumor_bytes(bytes, identity)?;
+        }
+        let wire_msg =
+            message::generate_wire(bytes, self.ring_key.as_ref(), self.identity.as_ref())?;
         self.socket.send(&wire_msg, 0).map_err(Error::ZmqSendError)
     }
 }
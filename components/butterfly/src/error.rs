@@ -41,6 +41,9 @@ pub enum Error {
     SocketSetReadTimeout(io::Error),
     SocketSetWriteTimeout(io::Error),
     SocketCloneError,
+    UnknownRingKey(String),
+    UnsignedMessage,
+    UntrustedSigner(String),
     ZmqConnectError(zmq::Error),
     ZmqSendError(zmq::Error),
 }
@@ -93,6 +96,19 @@ impl fmt::Display for Error {
                 format!("Cannot set UDP socket write timeout: {}", err)
             }
             Error::SocketCloneError => format!("Cannot clone the underlying UDP socket"),
+            Error::UnknownRingKey(ref name_with_rev) => {
+                if name_with_rev.is_empty() {
+                    format!("Message was not encrypted with any of our ring keys")
+                } else {
+                    format!("Message was encrypted with an unknown ring key: {}", name_with_rev)
+                }
+            }
+            Error::UnsignedMessage => {
+                format!("Message was not signed, and only signed messages are trusted")
+            }
+            Error::UntrustedSigner(ref name_with_rev) => {
+                format!("Message was signed with an untrusted identity: {}", name_with_rev)
+            }
             Error::ZmqConnectError(ref err) => format!("Cannot connect ZMQ socket: {}", err),
             Error::ZmqSendError(ref err) => {
                 format!("Cannot send message through ZMQ socket: {}", err)
@@ -121,6 +137,9 @@ impl error::Error for Error {
             Error::SocketSetReadTimeout(_) => "Cannot set UDP socket read timeout",
             Error::SocketSetWriteTimeout(_) => "Cannot set UDP socket write timeout",
             Error::SocketCloneError => "Cannot clone the underlying UDP socket",
+            Error::UnknownRingKey(_) => "Message was encrypted with an unknown ring key",
+            Error::UnsignedMessage => "Message was not signed",
+            Error::UntrustedSigner(_) => "Message was signed with an untrusted identity",
             Error::ZmqConnectError(_) => "Cannot connect ZMQ socket",
             Error::ZmqSendError(_) => "Cannot send message through ZMQ socket",
         }
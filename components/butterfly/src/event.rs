@@ -0,0 +1,154 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A stream of the notable changes a `Server` sees in the ring: members changing health,
+//! elections starting and finishing, and service configuration and files arriving.
+//!
+//! Anything that wants to react to these changes, rather than poll for them, calls `subscribe`
+//! and reads events off the returned receiver. Subscribers which fall too far behind are
+//! disconnected rather than allowed to hold up gossip; they will see their receiver hang up, and
+//! can subscribe again.
+
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TrySendError};
+
+use member::Health;
+
+/// How many events may be waiting on a subscriber before it is disconnected.
+pub const SUBSCRIBER_BACKLOG: usize = 1024;
+
+/// The kind of election an election event is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ElectionKind {
+    Leader,
+    Update,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event")]
+pub enum Event {
+    /// A member was added to the member list, or its health changed.
+    MemberHealth { member_id: String, health: Health },
+    /// We saw a new election for a service group, or a new term of an existing one.
+    ElectionStarted {
+        kind: ElectionKind,
+        service_group: String,
+        term: u64,
+    },
+    /// An election for a service group finished, electing `member_id`.
+    ElectionFinished {
+        kind: ElectionKind,
+        service_group: String,
+        term: u64,
+        member_id: String,
+    },
+    /// A new incarnation of the configuration for a service group arrived.
+    ServiceConfig {
+        service_group: String,
+        incarnation: u64,
+    },
+    /// A new incarnation of a file for a service group arrived.
+    ServiceFile {
+        service_group: String,
+        filename: String,
+        incarnation: u64,
+    },
+}
+
+/// The subscribers to a stream of events. Clones share their subscribers.
+#[derive(Debug, Clone, Default)]
+pub struct EventStream {
+    subscribers: Arc<Mutex<Vec<SyncSender<Event>>>>,
+}
+
+impl EventStream {
+    /// Returns a receiver for every event published from now on.
+    pub fn subscribe(&self) -> Receiver<Event> {
+        let (tx, rx) = sync_channel(SUBSCRIBER_BACKLOG);
+        self.subscribers
+            .lock()
+            .expect("Event subscribers lock is poisoned")
+            .push(tx);
+        rx
+    }
+
+    /// Send an event to every subscriber, dropping those which have gone away or fallen behind.
+    pub fn publish(&self, event: Event) {
+        let mut subscribers = self.subscribers.lock().expect(
+            "Event subscribers lock is poisoned",
+        );
+        if subscribers.is_empty() {
+            return;
+        }
+        subscribers.retain(|tx| match tx.try_send(event.clone()) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_)) => {
+                warn!("Dropping an event subscriber that has fallen behind");
+                false
+            }
+            Err(TrySendError::Disconnected(_)) => false,
+        });
+    }
+
+    /// The number of current subscribers.
+    pub fn subscribers(&self) -> usize {
+        self.subscribers
+            .lock()
+            .expect("Event subscribers lock is poisoned")
+            .len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn health_event(member_id: &str) -> Event {
+        Event::MemberHealth {
+            member_id: String::from(member_id),
+            health: Health::Suspect,
+        }
+    }
+
+    #[test]
+    fn every_subscriber_sees_published_events() {
+        let events = EventStream::default();
+        let first = events.subscribe();
+        let second = events.clone().subscribe();
+        events.publish(health_event("a"));
+        assert_eq!(first.try_recv().unwrap(), health_event("a"));
+        assert_eq!(second.try_recv().unwrap(), health_event("a"));
+    }
+
+    #[test]
+    fn gone_subscribers_are_dropped() {
+        let events = EventStream::default();
+        let rx = events.subscribe();
+        events.subscribe();
+        drop(rx);
+        events.publish(health_event("a"));
+        assert_eq!(events.subscribers(), 0);
+    }
+
+    #[test]
+    fn lagging_subscribers_are_dropped() {
+        let events = EventStream::default();
+        let rx = events.subscribe();
+        for _ in 0..SUBSCRIBER_BACKLOG + 1 {
+            events.publish(health_event("a"));
+        }
+        assert_eq!(events.subscribers(), 0);
+        assert_eq!(rx.iter().count(), SUBSCRIBER_BACKLOG);
+    }
+}
@@ -23,6 +23,7 @@ use std::result;
 use std::str::FromStr;
 use std::sync::{Arc, RwLock};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::SystemTime;
 
 use protobuf::ProtobufEnum;
 use rand::{thread_rng, Rng};
@@ -32,6 +33,7 @@ use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
 
 use error::Error;
+use event::{Event, EventStream};
 use message::swim::{Member as ProtoMember, Membership as ProtoMembership,
                     Membership_Health as ProtoMembership_Health, Rumor_Type};
 use rumor::RumorKey;
@@ -211,8 +213,11 @@ pub struct MemberList {
     pub health: Arc<RwLock<HashMap<UuidSimple, Health>>>,
     suspect: Arc<RwLock<HashMap<UuidSimple, SteadyTime>>>,
     depart: Arc<RwLock<HashMap<UuidSimple, SteadyTime>>>,
+    // The incarnation of departed members which have expired, until when we remember it
+    tombstones: Arc<RwLock<HashMap<UuidSimple, (u64, SystemTime)>>>,
     initial_members: Arc<RwLock<Vec<Member>>>,
     update_counter: Arc<AtomicUsize>,
+    events: EventStream,
 }
 
 impl Serialize for MemberList {
@@ -240,13 +245,20 @@ impl Serialize for MemberList {
 impl MemberList {
     /// Creates a new, empty, MemberList.
     pub fn new() -> MemberList {
+        MemberList::with_events(EventStream::default())
+    }
+
+    /// Creates a new, empty, MemberList which publishes changes in health to `events`.
+    pub fn with_events(events: EventStream) -> MemberList {
         MemberList {
             members: Arc::new(RwLock::new(HashMap::new())),
             health: Arc::new(RwLock::new(HashMap::new())),
             suspect: Arc::new(RwLock::new(HashMap::new())),
             depart: Arc::new(RwLock::new(HashMap::new())),
+            tombstones: Arc::new(RwLock::new(HashMap::new())),
             initial_members: Arc::new(RwLock::new(Vec::new())),
             update_counter: Arc::new(AtomicUsize::new(0)),
+            events: events,
         }
     }
 
@@ -296,8 +308,19 @@ impl MemberList {
         }
     }
 
-    /// Inserts a member into the member list with the given health.
+    /// Inserts a member into the member list with the given health. An expired member is only
+    /// inserted again with a newer incarnation.
     pub fn insert(&self, member: Member, health: Health) -> bool {
+        {
+            let mut tombstones = self.tombstones.write().expect("Tombstone lock is poisoned");
+            match tombstones.get(member.get_id()).map(|&(incarnation, _)| incarnation) {
+                Some(incarnation) if member.get_incarnation() <= incarnation => return false,
+                Some(_) => {
+                    tombstones.remove(member.get_id());
+                }
+                None => {}
+            }
+        }
         let share_rumor: bool;
         let mut start_suspicion: bool = false;
         let mut stop_suspicion: bool = false;
@@ -377,10 +400,13 @@ impl MemberList {
 
         if share_rumor == true {
             self.increment_update_counter();
-            self.health
+            let previous = self.health
                 .write()
                 .expect("Health lock is poisoned")
                 .insert(String::from(member.get_id()), health);
+            if previous != Some(health) {
+                self.publish_health(member.get_id(), health);
+            }
             if start_suspicion == true {
                 self.suspect
                     .write()
@@ -498,9 +524,17 @@ impl MemberList {
             .expect("Health write lock is poisoned")
             .insert(String::from(member_id), health);
         self.increment_update_counter();
+        self.publish_health(member_id, health);
         true
     }
 
+    fn publish_health(&self, member_id: &str, health: Health) {
+        self.events.publish(Event::MemberHealth {
+            member_id: String::from(member_id),
+            health: health,
+        });
+    }
+
     /// The same as `insert_health_by_id`, but takes a member rather than an id.
     pub fn insert_health(&self, member: &Member, health: Health) -> bool {
         self.insert_health_by_id(member.get_id(), health)
@@ -680,6 +714,38 @@ impl MemberList {
         depart.remove(member_id);
     }
 
+    /// Removes a departed member from the list, remembering its incarnation until `expires` so
+    /// that gossip cannot bring it back. Returns true if the member was departed.
+    pub fn expire_departed(&self, member_id: &str, expires: SystemTime) -> bool {
+        if !self.check_health_of_by_id(member_id, Health::Departed) {
+            return false;
+        }
+        let member = self.members
+            .write()
+            .expect("Member list lock is poisoned")
+            .remove(member_id);
+        self.health.write().expect("Health lock is poisoned").remove(
+            member_id,
+        );
+        self.depart_remove(member_id);
+        if let Some(member) = member {
+            self.tombstones
+                .write()
+                .expect("Tombstone lock is poisoned")
+                .insert(member_id.to_string(), (member.get_incarnation(), expires));
+        }
+        self.increment_update_counter();
+        true
+    }
+
+    /// Forgets the expired members whose tombstones expired by `now`, returning how many.
+    pub fn purge_tombstones(&self, now: SystemTime) -> usize {
+        let mut tombstones = self.tombstones.write().expect("Tombstone lock is poisoned");
+        let before = tombstones.len();
+        tombstones.retain(|_, &mut (_, expires)| expires > now);
+        before - tombstones.len()
+    }
+
     pub fn contains_member(&self, member_id: &str) -> bool {
         self.members
             .read()
@@ -717,6 +783,9 @@ mod tests {
     }
 
     mod member_list {
+        use std::time::{Duration, SystemTime};
+
+        use event::{Event, EventStream};
         use member::{Member, MemberList, Health, PINGREQ_TARGETS};
 
         fn populated_member_list(size: u64) -> MemberList {
@@ -740,6 +809,52 @@ mod tests {
             assert_eq!(ml.len(), 4);
         }
 
+        #[test]
+        fn expire_departed_only_readmits_a_newer_incarnation() {
+            let ml = MemberList::new();
+            let member = Member::default();
+            let member_id = member.get_id().to_string();
+            let expires = SystemTime::now() + Duration::from_secs(60);
+            ml.insert(member.clone(), Health::Alive);
+            assert!(!ml.expire_departed(&member_id, expires));
+            ml.insert(member.clone(), Health::Departed);
+            assert!(ml.expire_departed(&member_id, expires));
+            assert!(!ml.contains_member(&member_id));
+            assert!(!ml.insert(member.clone(), Health::Departed));
+            let mut rejoined = member.clone();
+            rejoined.set_incarnation(member.get_incarnation() + 1);
+            assert!(ml.insert(rejoined, Health::Alive));
+            assert!(ml.check_health_of_by_id(&member_id, Health::Alive));
+        }
+
+        #[test]
+        fn health_changes_are_published() {
+            let events = EventStream::default();
+            let rx = events.subscribe();
+            let ml = MemberList::with_events(events);
+            let member = Member::default();
+            let member_id = member.get_id().to_string();
+            let mut newer = member.clone();
+            newer.set_incarnation(member.get_incarnation() + 1);
+            ml.insert(member.clone(), Health::Alive);
+            ml.insert(newer, Health::Alive);
+            ml.insert_health_by_id(&member_id, Health::Suspect);
+            let published: Vec<Event> = rx.try_iter().collect();
+            assert_eq!(
+                published,
+                vec![
+                    Event::MemberHealth {
+                        member_id: member_id.clone(),
+                        health: Health::Alive,
+                    },
+                    Event::MemberHealth {
+                        member_id: member_id.clone(),
+                        health: Health::Suspect,
+                    },
+                ]
+            );
+        }
+
         #[test]
         fn check_list() {
             let ml = populated_member_list(1000);
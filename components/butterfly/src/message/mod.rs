@@ -14,38 +14,257 @@
 
 pub mod swim;
 
+use std::collections::{BTreeMap, HashMap};
 use std::result;
 use std::str;
 
-use habitat_core::crypto::SymKey;
+use habitat_core::crypto::{SigKeyPair, SymKey};
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
 use toml;
 
-use error::Result;
-use message::swim::Wire;
+use error::{Error, Result};
+use message::swim::{Signature, Wire};
 use protobuf::{self, Message};
 
-pub fn generate_wire(payload: Vec<u8>, ring_key: Option<&SymKey>) -> Result<Vec<u8>> {
+/// The ring keys a server encrypts and decrypts its traffic with.
+///
+/// Traffic is always encrypted with the primary key, and decrypted with whichever of the keys the
+/// sender used. A server holding several revisions of its ring key can therefore talk to members
+/// which have moved to a new revision and to members which haven't yet, which lets a ring rotate
+/// its key without partitioning. A server with no keys sends and accepts plaintext.
+#[derive(Debug, Default)]
+pub struct RingKeys {
+    primary: Option<String>,
+    keys: Vec<SymKey>,
+}
+
+impl RingKeys {
+    pub fn new(primary: Option<SymKey>) -> RingKeys {
+        let mut ring_keys = RingKeys::default();
+        if let Some(key) = primary {
+            ring_keys.primary = Some(key.name_with_rev());
+            ring_keys.keys.push(key);
+        }
+        ring_keys
+    }
+
+    /// The key traffic is encrypted with.
+    pub fn primary(&self) -> Option<&SymKey> {
+        match self.primary {
+            Some(ref name_with_rev) => self.get(name_with_rev),
+            None => None,
+        }
+    }
+
+    pub fn get(&self, name_with_rev: &str) -> Option<&SymKey> {
+        self.keys.iter().find(|k| k.name_with_rev() == name_with_rev)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Accepts traffic encrypted with the given key, replacing any key of the same revision.
+    pub fn insert(&mut self, key: SymKey) {
+        let name_with_rev = key.name_with_rev();
+        self.keys.retain(|k| k.name_with_rev() != name_with_rev);
+        self.keys.push(key);
+    }
+
+    /// Replaces the accepted keys with the given keys. The primary key is always kept.
+    pub fn replace(&mut self, keys: Vec<SymKey>) {
+        let primary = self.primary().cloned();
+        self.keys = keys;
+        if let Some(key) = primary {
+            self.insert(key);
+        }
+    }
+
+    /// Makes the key with the given name and revision the primary key. Returns false if it is
+    /// already the primary, or if it isn't one of the accepted keys.
+    pub fn set_primary(&mut self, name_with_rev: &str) -> bool {
+        if self.primary.as_ref().map(|p| p.as_str()) == Some(name_with_rev) ||
+            self.get(name_with_rev).is_none()
+        {
+            return false;
+        }
+        self.primary = Some(name_with_rev.to_string());
+        true
+    }
+}
+
+/// The identities a server trusts the traffic of.
+///
+/// Every member signs its traffic with its own identity, a sig key pair named after its member
+/// id. A server with a list of trusted identities accepts only traffic signed by one of them, so
+/// holding the ring key is not enough to join the ring. Without one, the first identity seen
+/// under a name is pinned, and traffic signed under that name by any other key is refused; nor is
+/// unsigned traffic accepted any longer from a member which has signed before.
+#[derive(Debug, Default)]
+pub struct Identities {
+    trusted: Option<Vec<SigKeyPair>>,
+    pinned: HashMap<String, SigKeyPair>,
+}
+
+impl Identities {
+    /// Accepts traffic signed only by the given identities.
+    pub fn trust(&mut self, keys: Vec<SigKeyPair>) {
+        self.trusted = Some(keys);
+    }
+
+    /// Whether the identity is one of the trusted identities. Nothing is, when no identities
+    /// were given to trust.
+    pub fn is_trusted(&self, signer: &SigKeyPair) -> bool {
+        match self.trusted {
+            Some(ref trusted) => {
+                trusted.iter().any(|key| {
+                    key.name_with_rev() == signer.name_with_rev() && key.public == signer.public
+                })
+            }
+            None => false,
+        }
+    }
+
+    /// Checks the identity which signed a message from the given member is trusted. Unsigned
+    /// messages are passed as `None`.
+    pub fn check(&mut self, member_id: &str, signer: Option<&SigKeyPair>) -> Result<()> {
+        let signer = match signer {
+            Some(signer) => signer,
+            None => {
+                if self.trusted.is_some() || self.pinned.contains_key(member_id) {
+                    return Err(Error::UnsignedMessage);
+                }
+                return Ok(());
+            }
+        };
+        let same_key = |key: &SigKeyPair| {
+            key.name_with_rev() == signer.name_with_rev() && key.public == signer.public
+        };
+        if let Some(ref trusted) = self.trusted {
+            return if trusted.iter().any(|key| same_key(key)) {
+                Ok(())
+            } else {
+                Err(Error::UntrustedSigner(signer.name_with_rev()))
+            };
+        }
+        if let Some(pinned) = self.pinned.get(&signer.name) {
+            return if same_key(pinned) {
+                Ok(())
+            } else {
+                Err(Error::UntrustedSigner(signer.name_with_rev()))
+            };
+        }
+        self.pinned.insert(signer.name.clone(), signer.clone());
+        Ok(())
+    }
+}
+
+/// Wraps the payload for the wire, encrypting it with the ring key and signing it with the
+/// identity, if given.
+pub fn generate_wire(
+    payload: Vec<u8>,
+    ring_key: Option<&SymKey>,
+    identity: Option<&SigKeyPair>,
+) -> Result<Vec<u8>> {
     let mut wire = Wire::new();
     if let Some(ring_key) = ring_key {
         wire.set_encrypted(true);
+        wire.set_key_revision(ring_key.name_with_rev());
         let (nonce, encrypted_payload) = ring_key.encrypt(&payload)?;
         wire.set_nonce(nonce);
         wire.set_payload(encrypted_payload);
     } else {
         wire.set_payload(payload);
     }
+    if let Some(identity) = identity {
+        let mut signature = Signature::new();
+        signature.set_signer(identity.name_with_rev());
+        signature.set_public_key(identity.public()?[..].to_vec());
+        signature.set_signature(identity.sign(wire.get_payload())?);
+        wire.set_signature(signature);
+    }
     Ok(wire.write_to_bytes()?)
 }
 
-pub fn unwrap_wire(payload: &[u8], ring_key: Option<&SymKey>) -> Result<Vec<u8>> {
+/// Checks the signature of the payload, if it was signed, and decrypts the payload with the ring
+/// key it names. Payloads from members which don't name their key are tried against every key.
+///
+/// Returns the payload along with the identity which signed it. Whether that identity is trusted
+/// is up to the caller; see `Identities`.
+pub fn unwrap_wire(
+    payload: &[u8],
+    ring_keys: &RingKeys,
+) -> Result<(Vec<u8>, Option<SigKeyPair>)> {
     let mut wire: Wire = protobuf::parse_from_bytes(payload)?;
-    if let Some(ring_key) = ring_key {
-        Ok(ring_key.decrypt(wire.get_nonce(), wire.get_payload())?)
+    let signer = if wire.has_signature() {
+        let signature = wire.get_signature();
+        let signer = SigKeyPair::from_public_key_bytes(
+            signature.get_signer(),
+            signature.get_public_key(),
+        )?;
+        signer.verify(wire.get_payload(), signature.get_signature())?;
+        Some(signer)
     } else {
-        Ok(wire.take_payload())
+        None
+    };
+    if ring_keys.is_empty() {
+        return Ok((wire.take_payload(), signer));
+    }
+    if wire.has_key_revision() {
+        return match ring_keys.get(wire.get_key_revision()) {
+            Some(ring_key) => Ok((
+                ring_key.decrypt(wire.get_nonce(), wire.get_payload())?,
+                signer,
+            )),
+            None => Err(Error::UnknownRingKey(wire.take_key_revision())),
+        };
     }
+    let mut result = Err(Error::UnknownRingKey(String::new()));
+    for ring_key in ring_keys.keys.iter() {
+        result = ring_key.decrypt(wire.get_nonce(), wire.get_payload()).map_err(
+            Error::from,
+        );
+        if result.is_ok() {
+            break;
+        }
+    }
+    result.map(|payload| (payload, signer))
+}
+
+/// Signs the rumor as its origin. The signature covers the rumor with any previous origin
+/// signature cleared, and travels with the rumor as it is gossiped onward, so members which
+/// hear it second hand can tell who wrote it.
+pub fn sign_rumor(rumor: &mut swim::Rumor, identity: &SigKeyPair) -> Result<()> {
+    rumor.clear_origin();
+    let bytes = rumor.write_to_bytes()?;
+    let mut signature = Signature::new();
+    signature.set_signer(identity.name_with_rev());
+    signature.set_public_key(identity.public()?[..].to_vec());
+    signature.set_signature(identity.sign(&bytes)?);
+    rumor.set_origin(signature);
+    Ok(())
+}
+
+/// Like `sign_rumor`, for a rumor which has already been written out.
+pub fn sign_rumor_bytes(bytes: Vec<u8>, identity: &SigKeyPair) -> Result<Vec<u8>> {
+    let mut rumor: swim::Rumor = protobuf::parse_from_bytes(&bytes)?;
+    sign_rumor(&mut rumor, identity)?;
+    Ok(rumor.write_to_bytes()?)
+}
+
+/// Checks the origin signature of the rumor, if it has one, and returns the identity which made
+/// it. Whether that identity may speak for the rumor is up to the caller.
+pub fn verify_rumor(rumor: &swim::Rumor) -> Result<Option<SigKeyPair>> {
+    if !rumor.has_origin() {
+        return Ok(None);
+    }
+    let origin = rumor.get_origin();
+    let signer = SigKeyPair::from_public_key_bytes(origin.get_signer(), origin.get_public_key())?;
+    let mut unsigned = rumor.clone();
+    unsigned.clear_origin();
+    signer.verify(&unsigned.write_to_bytes()?, origin.get_signature())?;
+    Ok(Some(signer))
 }
 
 impl Serialize for swim::Election {
@@ -75,7 +294,7 @@ impl Serialize for swim::Member {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("member", 6)?;
+        let mut strukt = serializer.serialize_struct("member", 7)?;
         strukt.serialize_field("id", self.get_id())?;
         strukt.serialize_field(
             "incarnation",
@@ -88,6 +307,8 @@ impl Serialize for swim::Member {
             &self.get_gossip_port(),
         )?;
         strukt.serialize_field("persistent", &self.get_persistent())?;
+        let tags: BTreeMap<&String, &String> = self.get_tags().iter().collect();
+        strukt.serialize_field("tags", &tags)?;
         strukt.end()
     }
 }
@@ -104,6 +325,19 @@ impl Serialize for swim::Membership {
     }
 }
 
+impl Serialize for swim::FederatedMember {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("federated_member", 3)?;
+        strukt.serialize_field("ring", self.get_ring())?;
+        strukt.serialize_field("membership", self.get_membership())?;
+        strukt.serialize_field("relayed_at", &self.get_relayed_at())?;
+        strukt.end()
+    }
+}
+
 impl Serialize for swim::Rumor {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
     where
@@ -134,6 +368,12 @@ impl Serialize for swim::Rumor {
         if self.has_election() {
             strukt.serialize_field("election", self.get_election())?;
         }
+        if self.has_federated_member() {
+            strukt.serialize_field(
+                "federated_member",
+                self.get_federated_member(),
+            )?;
+        }
         strukt.end()
     }
 }
@@ -143,7 +383,7 @@ impl Serialize for swim::Service {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("service", 7)?;
+        let mut strukt = serializer.serialize_struct("service", 8)?;
         let cfg = toml::from_slice(self.get_cfg()).unwrap_or(toml::value::Table::default());
         strukt.serialize_field("member_id", self.get_member_id())?;
         strukt.serialize_field(
@@ -161,6 +401,7 @@ impl Serialize for swim::Service {
             "initialized",
             &self.get_initialized(),
         )?;
+        strukt.serialize_field("unloaded", &self.get_unloaded())?;
         strukt.end()
     }
 }
@@ -217,7 +458,7 @@ impl Serialize for swim::SysInfo {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("sys_info", 6)?;
+        let mut strukt = serializer.serialize_struct("sys_info", 7)?;
         strukt.serialize_field("ip", self.get_ip())?;
         strukt.serialize_field("hostname", self.get_hostname())?;
         strukt.serialize_field("gossip_ip", self.get_gossip_ip())?;
@@ -233,6 +474,12 @@ impl Serialize for swim::SysInfo {
             "http_gateway_port",
             &self.get_http_gateway_port(),
         )?;
+        let ring = if self.has_ring() {
+            Some(self.get_ring())
+        } else {
+            None
+        };
+        strukt.serialize_field("ring", &ring)?;
         strukt.end()
     }
 }
@@ -263,3 +510,121 @@ impl Serialize for swim::Rumor_Type {
         serializer.serialize_u8(*self as u8)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use habitat_core::crypto::SymKey;
+
+    use super::*;
+
+    #[test]
+    fn unwrap_wire_with_any_accepted_key() {
+        let old = SymKey::generate_pair_for_ring("wolverine").unwrap();
+        let mut new = old.clone();
+        new.rev = String::from("99991231235959");
+        new.secret = SymKey::generate_pair_for_ring("wolverine").unwrap().secret;
+        let mut ring_keys = RingKeys::new(Some(old.clone()));
+        ring_keys.insert(new.clone());
+
+        let from_old = generate_wire(b"hello".to_vec(), Some(&old), None).unwrap();
+        let from_new = generate_wire(b"hello".to_vec(), Some(&new), None).unwrap();
+        assert_eq!(unwrap_wire(&from_old, &ring_keys).unwrap().0, b"hello".to_vec());
+        assert_eq!(unwrap_wire(&from_new, &ring_keys).unwrap().0, b"hello".to_vec());
+
+        let mut unnamed: Wire = protobuf::parse_from_bytes(&from_new).unwrap();
+        unnamed.clear_key_revision();
+        let unnamed = unnamed.write_to_bytes().unwrap();
+        assert_eq!(unwrap_wire(&unnamed, &ring_keys).unwrap().0, b"hello".to_vec());
+
+        let only_old = RingKeys::new(Some(old));
+        assert!(unwrap_wire(&from_new, &only_old).is_err());
+    }
+
+    #[test]
+    fn unwrap_wire_checks_the_signature() {
+        let ring_key = SymKey::generate_pair_for_ring("wolverine").unwrap();
+        let ring_keys = RingKeys::new(Some(ring_key.clone()));
+        let identity = SigKeyPair::generate_pair_for_origin("logan").unwrap();
+
+        let signed = generate_wire(b"hello".to_vec(), Some(&ring_key), Some(&identity)).unwrap();
+        let (payload, signer) = unwrap_wire(&signed, &ring_keys).unwrap();
+        assert_eq!(payload, b"hello".to_vec());
+        assert_eq!(signer.unwrap().name_with_rev(), identity.name_with_rev());
+
+        let mut tampered: Wire = protobuf::parse_from_bytes(&signed).unwrap();
+        let (nonce, payload) = ring_key.encrypt(b"goodbye").unwrap();
+        tampered.set_nonce(nonce);
+        tampered.set_payload(payload);
+        let tampered = tampered.write_to_bytes().unwrap();
+        assert!(unwrap_wire(&tampered, &ring_keys).is_err());
+
+        let unsigned = generate_wire(b"hello".to_vec(), Some(&ring_key), None).unwrap();
+        assert!(unwrap_wire(&unsigned, &ring_keys).unwrap().1.is_none());
+    }
+
+    #[test]
+    fn verify_rumor_checks_the_origin() {
+        let identity = SigKeyPair::generate_pair_for_origin("logan").unwrap();
+        let mut rumor = swim::Rumor::new();
+        rumor.set_field_type(swim::Rumor_Type::Departure);
+        rumor.set_from_id(String::from("logan"));
+        rumor.mut_departure().set_member_id(String::from("logan"));
+        assert!(verify_rumor(&rumor).unwrap().is_none());
+
+        sign_rumor(&mut rumor, &identity).unwrap();
+        let signer = verify_rumor(&rumor).unwrap().unwrap();
+        assert_eq!(signer.name_with_rev(), identity.name_with_rev());
+
+        let bytes = sign_rumor_bytes(rumor.write_to_bytes().unwrap(), &identity).unwrap();
+        let resigned: swim::Rumor = protobuf::parse_from_bytes(&bytes).unwrap();
+        assert!(verify_rumor(&resigned).unwrap().is_some());
+
+        rumor.mut_departure().set_member_id(String::from("scott"));
+        assert!(verify_rumor(&rumor).is_err());
+    }
+
+    #[test]
+    fn identities_pin_the_first_key_seen() {
+        let mut identities = Identities::default();
+        let logan = SigKeyPair::generate_pair_for_origin("logan").unwrap();
+        let mut impostor = SigKeyPair::generate_pair_for_origin("logan").unwrap();
+        impostor.rev = logan.rev.clone();
+
+        identities.check("logan", None).unwrap();
+        identities.check("logan", Some(&logan)).unwrap();
+        identities.check("logan", Some(&logan)).unwrap();
+        assert!(identities.check("logan", Some(&impostor)).is_err());
+        assert!(identities.check("logan", None).is_err());
+        identities.check("ororo", None).unwrap();
+    }
+
+    #[test]
+    fn identities_only_accept_trusted_keys() {
+        let mut identities = Identities::default();
+        let logan = SigKeyPair::generate_pair_for_origin("logan").unwrap();
+        let ororo = SigKeyPair::generate_pair_for_origin("ororo").unwrap();
+        identities.trust(vec![logan.clone()]);
+
+        identities.check("logan", Some(&logan)).unwrap();
+        assert!(identities.check("ororo", Some(&ororo)).is_err());
+        assert!(identities.check("ororo", None).is_err());
+    }
+
+    #[test]
+    fn set_primary_requires_an_accepted_key() {
+        let old = SymKey::generate_pair_for_ring("wolverine").unwrap();
+        let mut new = old.clone();
+        new.rev = String::from("99991231235959");
+        let mut ring_keys = RingKeys::new(Some(old.clone()));
+        assert!(!ring_keys.set_primary(&new.name_with_rev()));
+        assert!(!ring_keys.set_primary(&old.name_with_rev()));
+
+        ring_keys.insert(new.clone());
+        assert!(ring_keys.set_primary(&new.name_with_rev()));
+        assert_eq!(ring_keys.primary().unwrap().name_with_rev(), new.name_with_rev());
+
+        ring_keys.replace(vec![old.clone()]);
+        assert_eq!(ring_keys.primary().unwrap().name_with_rev(), new.name_with_rev());
+        assert!(ring_keys.get(&old.name_with_rev()).is_some());
+    }
+}
@@ -31,6 +31,7 @@ pub struct Member {
     gossip_port: ::std::option::Option<i32>,
     persistent: ::std::option::Option<bool>,
     departed: ::std::option::Option<bool>,
+    pub tags: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -276,6 +277,39 @@ impl Member {
     fn mut_departed_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
         &mut self.departed
     }
+
+    // repeated .Member.TagsEntry tags = 8;
+
+    pub fn clear_tags(&mut self) {
+        self.tags.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tags(&mut self, v: ::std::collections::HashMap<::std::string::String, ::std::string::String>) {
+        self.tags = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tags(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &mut self.tags
+    }
+
+    // Take field
+    pub fn take_tags(&mut self) -> ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        ::std::mem::replace(&mut self.tags, ::std::collections::HashMap::new())
+    }
+
+    pub fn get_tags(&self) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &self.tags
+    }
+
+    fn get_tags_for_reflect(&self) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &self.tags
+    }
+
+    fn mut_tags_for_reflect(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &mut self.tags
+    }
 }
 
 impl ::protobuf::Message for Member {
@@ -328,6 +362,9 @@ impl ::protobuf::Message for Member {
                     let tmp = is.read_bool()?;
                     self.departed = ::std::option::Option::Some(tmp);
                 },
+                8 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.tags)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -361,6 +398,7 @@ impl ::protobuf::Message for Member {
         if let Some(v) = self.departed {
             my_size += 2;
         }
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(8, &self.tags);
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -388,6 +426,7 @@ impl ::protobuf::Message for Member {
         if let Some(v) = self.departed {
             os.write_bool(7, v)?;
         }
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(8, &self.tags, os)?;
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -467,6 +506,11 @@ impl ::protobuf::MessageStatic for Member {
                     Member::get_departed_for_reflect,
                     Member::mut_departed_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(
+                    "tags",
+                    Member::get_tags_for_reflect,
+                    Member::mut_tags_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Member>(
                     "Member",
                     fields,
@@ -486,6 +530,7 @@ impl ::protobuf::Clear for Member {
         self.clear_gossip_port();
         self.clear_persistent();
         self.clear_departed();
+        self.clear_tags();
         self.unknown_fields.clear();
     }
 }
@@ -2061,6 +2106,9 @@ pub struct Service {
     pkg: ::protobuf::SingularField<::std::string::String>,
     cfg: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     sys: ::protobuf::SingularPtrField<SysInfo>,
+    health: ::std::option::Option<Service_Health>,
+    rolled_back_from: ::protobuf::SingularField<::std::string::String>,
+    unloaded: ::std::option::Option<bool>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -2354,6 +2402,104 @@ impl Service {
     fn mut_sys_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<SysInfo> {
         &mut self.sys
     }
+
+    // optional .Service.Health health = 13;
+
+    pub fn clear_health(&mut self) {
+        self.health = ::std::option::Option::None;
+    }
+
+    pub fn has_health(&self) -> bool {
+        self.health.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_health(&mut self, v: Service_Health) {
+        self.health = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_health(&self) -> Service_Health {
+        self.health.unwrap_or(Service_Health::UNKNOWN)
+    }
+
+    fn get_health_for_reflect(&self) -> &::std::option::Option<Service_Health> {
+        &self.health
+    }
+
+    fn mut_health_for_reflect(&mut self) -> &mut ::std::option::Option<Service_Health> {
+        &mut self.health
+    }
+
+    // optional string rolled_back_from = 14;
+
+    pub fn clear_rolled_back_from(&mut self) {
+        self.rolled_back_from.clear();
+    }
+
+    pub fn has_rolled_back_from(&self) -> bool {
+        self.rolled_back_from.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rolled_back_from(&mut self, v: ::std::string::String) {
+        self.rolled_back_from = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rolled_back_from(&mut self) -> &mut ::std::string::String {
+        if self.rolled_back_from.is_none() {
+            self.rolled_back_from.set_default();
+        }
+        self.rolled_back_from.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rolled_back_from(&mut self) -> ::std::string::String {
+        self.rolled_back_from.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_rolled_back_from(&self) -> &str {
+        match self.rolled_back_from.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_rolled_back_from_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.rolled_back_from
+    }
+
+    fn mut_rolled_back_from_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.rolled_back_from
+    }
+
+    // optional bool unloaded = 15;
+
+    pub fn clear_unloaded(&mut self) {
+        self.unloaded = ::std::option::Option::None;
+    }
+
+    pub fn has_unloaded(&self) -> bool {
+        self.unloaded.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unloaded(&mut self, v: bool) {
+        self.unloaded = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_unloaded(&self) -> bool {
+        self.unloaded.unwrap_or(false)
+    }
+
+    fn get_unloaded_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.unloaded
+    }
+
+    fn mut_unloaded_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.unloaded
+    }
 }
 
 impl ::protobuf::Message for Service {
@@ -2399,6 +2545,23 @@ impl ::protobuf::Message for Service {
                 12 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.sys)?;
                 },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.health = ::std::option::Option::Some(tmp);
+                },
+                14 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.rolled_back_from)?;
+                },
+                15 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.unloaded = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2433,6 +2596,15 @@ impl ::protobuf::Message for Service {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if let Some(v) = self.health {
+            my_size += ::protobuf::rt::enum_size(13, v);
+        }
+        if let Some(ref v) = self.rolled_back_from.as_ref() {
+            my_size += ::protobuf::rt::string_size(14, &v);
+        }
+        if let Some(v) = self.unloaded {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2462,6 +2634,15 @@ impl ::protobuf::Message for Service {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if let Some(v) = self.health {
+            os.write_enum(13, v.value())?;
+        }
+        if let Some(ref v) = self.rolled_back_from.as_ref() {
+            os.write_string(14, &v)?;
+        }
+        if let Some(v) = self.unloaded {
+            os.write_bool(15, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2541,6 +2722,21 @@ impl ::protobuf::MessageStatic for Service {
                     Service::get_sys_for_reflect,
                     Service::mut_sys_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Service_Health>>(
+                    "health",
+                    Service::get_health_for_reflect,
+                    Service::mut_health_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "rolled_back_from",
+                    Service::get_rolled_back_from_for_reflect,
+                    Service::mut_rolled_back_from_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "unloaded",
+                    Service::get_unloaded_for_reflect,
+                    Service::mut_unloaded_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Service>(
                     "Service",
                     fields,
@@ -2560,6 +2756,9 @@ impl ::protobuf::Clear for Service {
         self.clear_pkg();
         self.clear_cfg();
         self.clear_sys();
+        self.clear_health();
+        self.clear_rolled_back_from();
+        self.clear_unloaded();
         self.unknown_fields.clear();
     }
 }
@@ -2576,6 +2775,61 @@ impl ::protobuf::reflect::ProtobufValue for Service {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Service_Health {
+    OK = 0,
+    WARNING = 1,
+    CRITICAL = 2,
+    UNKNOWN = 3,
+}
+
+impl ::protobuf::ProtobufEnum for Service_Health {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Service_Health> {
+        match value {
+            0 => ::std::option::Option::Some(Service_Health::OK),
+            1 => ::std::option::Option::Some(Service_Health::WARNING),
+            2 => ::std::option::Option::Some(Service_Health::CRITICAL),
+            3 => ::std::option::Option::Some(Service_Health::UNKNOWN),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Service_Health] = &[
+            Service_Health::OK,
+            Service_Health::WARNING,
+            Service_Health::CRITICAL,
+            Service_Health::UNKNOWN,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: ::std::option::Option<Service_Health>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("Service_Health", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Service_Health {
+}
+
+impl ::protobuf::reflect::ProtobufValue for Service_Health {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct ServiceConfig {
     // message fields
@@ -3325,6 +3579,7 @@ pub struct SysInfo {
     gossip_port: ::std::option::Option<u32>,
     http_gateway_ip: ::protobuf::SingularField<::std::string::String>,
     http_gateway_port: ::std::option::Option<u32>,
+    ring: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -3577,6 +3832,50 @@ impl SysInfo {
     fn mut_http_gateway_port_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
         &mut self.http_gateway_port
     }
+
+    // optional string ring = 7;
+
+    pub fn clear_ring(&mut self) {
+        self.ring.clear();
+    }
+
+    pub fn has_ring(&self) -> bool {
+        self.ring.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ring(&mut self, v: ::std::string::String) {
+        self.ring = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ring(&mut self) -> &mut ::std::string::String {
+        if self.ring.is_none() {
+            self.ring.set_default();
+        }
+        self.ring.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ring(&mut self) -> ::std::string::String {
+        self.ring.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_ring(&self) -> &str {
+        match self.ring.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_ring_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.ring
+    }
+
+    fn mut_ring_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.ring
+    }
 }
 
 impl ::protobuf::Message for SysInfo {
@@ -3614,6 +3913,9 @@ impl ::protobuf::Message for SysInfo {
                     let tmp = is.read_uint32()?;
                     self.http_gateway_port = ::std::option::Option::Some(tmp);
                 },
+                7 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.ring)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -3644,6 +3946,9 @@ impl ::protobuf::Message for SysInfo {
         if let Some(v) = self.http_gateway_port {
             my_size += ::protobuf::rt::value_size(6, v, ::protobuf::wire_format::WireTypeVarint);
         }
+        if let Some(ref v) = self.ring.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3668,6 +3973,9 @@ impl ::protobuf::Message for SysInfo {
         if let Some(v) = self.http_gateway_port {
             os.write_uint32(6, v)?;
         }
+        if let Some(ref v) = self.ring.as_ref() {
+            os.write_string(7, &v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3742,6 +4050,11 @@ impl ::protobuf::MessageStatic for SysInfo {
                     SysInfo::get_http_gateway_port_for_reflect,
                     SysInfo::mut_http_gateway_port_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "ring",
+                    SysInfo::get_ring_for_reflect,
+                    SysInfo::mut_ring_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<SysInfo>(
                     "SysInfo",
                     fields,
@@ -3760,6 +4073,7 @@ impl ::protobuf::Clear for SysInfo {
         self.clear_gossip_port();
         self.clear_http_gateway_ip();
         self.clear_http_gateway_port();
+        self.clear_ring();
         self.unknown_fields.clear();
     }
 }
@@ -3963,275 +4277,107 @@ impl ::protobuf::reflect::ProtobufValue for Departure {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Swim {
+pub struct ShardSlot {
     // message fields
-    field_type: ::std::option::Option<Swim_Type>,
-    membership: ::protobuf::RepeatedField<Membership>,
-    // message oneof groups
-    payload: ::std::option::Option<Swim_oneof_payload>,
+    member_id: ::protobuf::SingularField<::std::string::String>,
+    shard: ::std::option::Option<u32>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Swim {}
-
-#[derive(Clone,PartialEq)]
-pub enum Swim_oneof_payload {
-    ping(Ping),
-    ack(Ack),
-    pingreq(PingReq),
-}
+unsafe impl ::std::marker::Sync for ShardSlot {}
 
-impl Swim {
-    pub fn new() -> Swim {
+impl ShardSlot {
+    pub fn new() -> ShardSlot {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Swim {
-        static mut instance: ::protobuf::lazy::Lazy<Swim> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static ShardSlot {
+        static mut instance: ::protobuf::lazy::Lazy<ShardSlot> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Swim,
+            ptr: 0 as *const ShardSlot,
         };
         unsafe {
-            instance.get(Swim::new)
+            instance.get(ShardSlot::new)
         }
     }
 
-    // required .Swim.Type type = 1;
+    // optional string member_id = 1;
 
-    pub fn clear_field_type(&mut self) {
-        self.field_type = ::std::option::Option::None;
+    pub fn clear_member_id(&mut self) {
+        self.member_id.clear();
     }
 
-    pub fn has_field_type(&self) -> bool {
-        self.field_type.is_some()
+    pub fn has_member_id(&self) -> bool {
+        self.member_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_field_type(&mut self, v: Swim_Type) {
-        self.field_type = ::std::option::Option::Some(v);
+    pub fn set_member_id(&mut self, v: ::std::string::String) {
+        self.member_id = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_field_type(&self) -> Swim_Type {
-        self.field_type.unwrap_or(Swim_Type::PING)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_member_id(&mut self) -> &mut ::std::string::String {
+        if self.member_id.is_none() {
+            self.member_id.set_default();
+        }
+        self.member_id.as_mut().unwrap()
     }
 
-    fn get_field_type_for_reflect(&self) -> &::std::option::Option<Swim_Type> {
-        &self.field_type
+    // Take field
+    pub fn take_member_id(&mut self) -> ::std::string::String {
+        self.member_id.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    fn mut_field_type_for_reflect(&mut self) -> &mut ::std::option::Option<Swim_Type> {
-        &mut self.field_type
+    pub fn get_member_id(&self) -> &str {
+        match self.member_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    // optional .Ping ping = 2;
+    fn get_member_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.member_id
+    }
 
-    pub fn clear_ping(&mut self) {
-        self.payload = ::std::option::Option::None;
+    fn mut_member_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.member_id
     }
 
-    pub fn has_ping(&self) -> bool {
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::ping(..)) => true,
-            _ => false,
-        }
+    // optional uint32 shard = 2;
+
+    pub fn clear_shard(&mut self) {
+        self.shard = ::std::option::Option::None;
+    }
+
+    pub fn has_shard(&self) -> bool {
+        self.shard.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_ping(&mut self, v: Ping) {
-        self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(v))
+    pub fn set_shard(&mut self, v: u32) {
+        self.shard = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_ping(&mut self) -> &mut Ping {
-        if let ::std::option::Option::Some(Swim_oneof_payload::ping(_)) = self.payload {
-        } else {
-            self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(Ping::new()));
-        }
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::ping(ref mut v)) => v,
-            _ => panic!(),
-        }
-    }
-
-    // Take field
-    pub fn take_ping(&mut self) -> Ping {
-        if self.has_ping() {
-            match self.payload.take() {
-                ::std::option::Option::Some(Swim_oneof_payload::ping(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Ping::new()
-        }
-    }
-
-    pub fn get_ping(&self) -> &Ping {
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::ping(ref v)) => v,
-            _ => Ping::default_instance(),
-        }
-    }
-
-    // optional .Ack ack = 3;
-
-    pub fn clear_ack(&mut self) {
-        self.payload = ::std::option::Option::None;
-    }
-
-    pub fn has_ack(&self) -> bool {
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::ack(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_ack(&mut self, v: Ack) {
-        self.payload = ::std::option::Option::Some(Swim_oneof_payload::ack(v))
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_ack(&mut self) -> &mut Ack {
-        if let ::std::option::Option::Some(Swim_oneof_payload::ack(_)) = self.payload {
-        } else {
-            self.payload = ::std::option::Option::Some(Swim_oneof_payload::ack(Ack::new()));
-        }
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::ack(ref mut v)) => v,
-            _ => panic!(),
-        }
-    }
-
-    // Take field
-    pub fn take_ack(&mut self) -> Ack {
-        if self.has_ack() {
-            match self.payload.take() {
-                ::std::option::Option::Some(Swim_oneof_payload::ack(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Ack::new()
-        }
-    }
-
-    pub fn get_ack(&self) -> &Ack {
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::ack(ref v)) => v,
-            _ => Ack::default_instance(),
-        }
-    }
-
-    // optional .PingReq pingreq = 4;
-
-    pub fn clear_pingreq(&mut self) {
-        self.payload = ::std::option::Option::None;
-    }
-
-    pub fn has_pingreq(&self) -> bool {
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::pingreq(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_pingreq(&mut self, v: PingReq) {
-        self.payload = ::std::option::Option::Some(Swim_oneof_payload::pingreq(v))
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_pingreq(&mut self) -> &mut PingReq {
-        if let ::std::option::Option::Some(Swim_oneof_payload::pingreq(_)) = self.payload {
-        } else {
-            self.payload = ::std::option::Option::Some(Swim_oneof_payload::pingreq(PingReq::new()));
-        }
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::pingreq(ref mut v)) => v,
-            _ => panic!(),
-        }
-    }
-
-    // Take field
-    pub fn take_pingreq(&mut self) -> PingReq {
-        if self.has_pingreq() {
-            match self.payload.take() {
-                ::std::option::Option::Some(Swim_oneof_payload::pingreq(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            PingReq::new()
-        }
-    }
-
-    pub fn get_pingreq(&self) -> &PingReq {
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::pingreq(ref v)) => v,
-            _ => PingReq::default_instance(),
-        }
-    }
-
-    // repeated .Membership membership = 5;
-
-    pub fn clear_membership(&mut self) {
-        self.membership.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_membership(&mut self, v: ::protobuf::RepeatedField<Membership>) {
-        self.membership = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_membership(&mut self) -> &mut ::protobuf::RepeatedField<Membership> {
-        &mut self.membership
-    }
-
-    // Take field
-    pub fn take_membership(&mut self) -> ::protobuf::RepeatedField<Membership> {
-        ::std::mem::replace(&mut self.membership, ::protobuf::RepeatedField::new())
-    }
-
-    pub fn get_membership(&self) -> &[Membership] {
-        &self.membership
+    pub fn get_shard(&self) -> u32 {
+        self.shard.unwrap_or(0)
     }
 
-    fn get_membership_for_reflect(&self) -> &::protobuf::RepeatedField<Membership> {
-        &self.membership
+    fn get_shard_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.shard
     }
 
-    fn mut_membership_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Membership> {
-        &mut self.membership
+    fn mut_shard_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.shard
     }
 }
 
-impl ::protobuf::Message for Swim {
+impl ::protobuf::Message for ShardSlot {
     fn is_initialized(&self) -> bool {
-        if self.field_type.is_none() {
-            return false;
-        }
-        if let Some(Swim_oneof_payload::ping(ref v)) = self.payload {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Swim_oneof_payload::ack(ref v)) = self.payload {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Swim_oneof_payload::pingreq(ref v)) = self.payload {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        for v in &self.membership {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -4240,32 +4386,14 @@ impl ::protobuf::Message for Swim {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_enum()?;
-                    self.field_type = ::std::option::Option::Some(tmp);
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.member_id)?;
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(is.read_message()?));
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.payload = ::std::option::Option::Some(Swim_oneof_payload::ack(is.read_message()?));
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.payload = ::std::option::Option::Some(Swim_oneof_payload::pingreq(is.read_message()?));
-                },
-                5 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.membership)?;
+                    let tmp = is.read_uint32()?;
+                    self.shard = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -4279,28 +4407,11 @@ impl ::protobuf::Message for Swim {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.field_type {
-            my_size += ::protobuf::rt::enum_size(1, v);
+        if let Some(ref v) = self.member_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
         }
-        for value in &self.membership {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        if let ::std::option::Option::Some(ref v) = self.payload {
-            match v {
-                &Swim_oneof_payload::ping(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Swim_oneof_payload::ack(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Swim_oneof_payload::pingreq(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-            };
+        if let Some(v) = self.shard {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -4308,32 +4419,11 @@ impl ::protobuf::Message for Swim {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.field_type {
-            os.write_enum(1, v.value())?;
+        if let Some(ref v) = self.member_id.as_ref() {
+            os.write_string(1, &v)?;
         }
-        for v in &self.membership {
-            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        if let ::std::option::Option::Some(ref v) = self.payload {
-            match v {
-                &Swim_oneof_payload::ping(ref v) => {
-                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Swim_oneof_payload::ack(ref v) => {
-                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Swim_oneof_payload::pingreq(ref v) => {
-                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-            };
+        if let Some(v) = self.shard {
+            os.write_uint32(2, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -4366,12 +4456,12 @@ impl ::protobuf::Message for Swim {
     }
 }
 
-impl ::protobuf::MessageStatic for Swim {
-    fn new() -> Swim {
-        Swim::new()
+impl ::protobuf::MessageStatic for ShardSlot {
+    fn new() -> ShardSlot {
+        ShardSlot::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Swim>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<ShardSlot>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -4379,33 +4469,18 @@ impl ::protobuf::MessageStatic for Swim {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Swim_Type>>(
-                    "type",
-                    Swim::get_field_type_for_reflect,
-                    Swim::mut_field_type_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Ping>(
-                    "ping",
-                    Swim::has_ping,
-                    Swim::get_ping,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Ack>(
-                    "ack",
-                    Swim::has_ack,
-                    Swim::get_ack,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PingReq>(
-                    "pingreq",
-                    Swim::has_pingreq,
-                    Swim::get_pingreq,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "member_id",
+                    ShardSlot::get_member_id_for_reflect,
+                    ShardSlot::mut_member_id_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Membership>>(
-                    "membership",
-                    Swim::get_membership_for_reflect,
-                    Swim::mut_membership_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "shard",
+                    ShardSlot::get_shard_for_reflect,
+                    ShardSlot::mut_shard_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Swim>(
-                    "Swim",
+                ::protobuf::reflect::MessageDescriptor::new::<ShardSlot>(
+                    "ShardSlot",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4414,556 +4489,3431 @@ impl ::protobuf::MessageStatic for Swim {
     }
 }
 
-impl ::protobuf::Clear for Swim {
+impl ::protobuf::Clear for ShardSlot {
     fn clear(&mut self) {
-        self.clear_field_type();
-        self.clear_ping();
-        self.clear_ack();
-        self.clear_pingreq();
-        self.clear_membership();
+        self.clear_member_id();
+        self.clear_shard();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Swim {
+impl ::std::fmt::Debug for ShardSlot {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Swim {
+impl ::protobuf::reflect::ProtobufValue for ShardSlot {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
-#[derive(Clone,PartialEq,Eq,Debug,Hash)]
-pub enum Swim_Type {
-    PING = 1,
-    ACK = 2,
-    PINGREQ = 3,
-}
-
-impl ::protobuf::ProtobufEnum for Swim_Type {
-    fn value(&self) -> i32 {
-        *self as i32
-    }
-
-    fn from_i32(value: i32) -> ::std::option::Option<Swim_Type> {
-        match value {
-            1 => ::std::option::Option::Some(Swim_Type::PING),
-            2 => ::std::option::Option::Some(Swim_Type::ACK),
-            3 => ::std::option::Option::Some(Swim_Type::PINGREQ),
-            _ => ::std::option::Option::None
-        }
-    }
-
-    fn values() -> &'static [Self] {
-        static values: &'static [Swim_Type] = &[
-            Swim_Type::PING,
-            Swim_Type::ACK,
-            Swim_Type::PINGREQ,
-        ];
-        values
-    }
-
-    fn enum_descriptor_static(_: ::std::option::Option<Swim_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("Swim_Type", file_descriptor_proto())
-            })
-        }
-    }
-}
-
-impl ::std::marker::Copy for Swim_Type {
-}
-
-impl ::protobuf::reflect::ProtobufValue for Swim_Type {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
-    }
-}
-
 #[derive(PartialEq,Clone,Default)]
-pub struct Rumor {
+pub struct ShardMap {
     // message fields
-    field_type: ::std::option::Option<Rumor_Type>,
-    tag: ::protobuf::RepeatedField<::std::string::String>,
-    from_id: ::protobuf::SingularField<::std::string::String>,
-    // message oneof groups
-    payload: ::std::option::Option<Rumor_oneof_payload>,
+    member_id: ::protobuf::SingularField<::std::string::String>,
+    service_group: ::protobuf::SingularField<::std::string::String>,
+    incarnation: ::std::option::Option<u64>,
+    shard_count: ::std::option::Option<u32>,
+    multi_leader: ::std::option::Option<bool>,
+    slots: ::protobuf::RepeatedField<ShardSlot>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Rumor {}
-
-#[derive(Clone,PartialEq)]
-pub enum Rumor_oneof_payload {
-    member(Membership),
-    service(Service),
-    service_config(ServiceConfig),
-    service_file(ServiceFile),
-    election(Election),
-    departure(Departure),
-}
+unsafe impl ::std::marker::Sync for ShardMap {}
 
-impl Rumor {
-    pub fn new() -> Rumor {
+impl ShardMap {
+    pub fn new() -> ShardMap {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Rumor {
-        static mut instance: ::protobuf::lazy::Lazy<Rumor> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static ShardMap {
+        static mut instance: ::protobuf::lazy::Lazy<ShardMap> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Rumor,
+            ptr: 0 as *const ShardMap,
         };
         unsafe {
-            instance.get(Rumor::new)
+            instance.get(ShardMap::new)
         }
     }
 
-    // required .Rumor.Type type = 1;
+    // optional string member_id = 1;
 
-    pub fn clear_field_type(&mut self) {
-        self.field_type = ::std::option::Option::None;
+    pub fn clear_member_id(&mut self) {
+        self.member_id.clear();
     }
 
-    pub fn has_field_type(&self) -> bool {
-        self.field_type.is_some()
+    pub fn has_member_id(&self) -> bool {
+        self.member_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_field_type(&mut self, v: Rumor_Type) {
-        self.field_type = ::std::option::Option::Some(v);
+    pub fn set_member_id(&mut self, v: ::std::string::String) {
+        self.member_id = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_field_type(&self) -> Rumor_Type {
-        self.field_type.unwrap_or(Rumor_Type::Member)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_member_id(&mut self) -> &mut ::std::string::String {
+        if self.member_id.is_none() {
+            self.member_id.set_default();
+        }
+        self.member_id.as_mut().unwrap()
     }
 
-    fn get_field_type_for_reflect(&self) -> &::std::option::Option<Rumor_Type> {
-        &self.field_type
+    // Take field
+    pub fn take_member_id(&mut self) -> ::std::string::String {
+        self.member_id.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    fn mut_field_type_for_reflect(&mut self) -> &mut ::std::option::Option<Rumor_Type> {
-        &mut self.field_type
+    pub fn get_member_id(&self) -> &str {
+        match self.member_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    // repeated string tag = 2;
+    fn get_member_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.member_id
+    }
 
-    pub fn clear_tag(&mut self) {
-        self.tag.clear();
+    fn mut_member_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.member_id
+    }
+
+    // optional string service_group = 2;
+
+    pub fn clear_service_group(&mut self) {
+        self.service_group.clear();
+    }
+
+    pub fn has_service_group(&self) -> bool {
+        self.service_group.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_tag(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.tag = v;
+    pub fn set_service_group(&mut self, v: ::std::string::String) {
+        self.service_group = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_tag(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.tag
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service_group(&mut self) -> &mut ::std::string::String {
+        if self.service_group.is_none() {
+            self.service_group.set_default();
+        }
+        self.service_group.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_tag(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.tag, ::protobuf::RepeatedField::new())
+    pub fn take_service_group(&mut self) -> ::std::string::String {
+        self.service_group.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_tag(&self) -> &[::std::string::String] {
-        &self.tag
+    pub fn get_service_group(&self) -> &str {
+        match self.service_group.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    fn get_tag_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
-        &self.tag
+    fn get_service_group_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.service_group
     }
 
-    fn mut_tag_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.tag
+    fn mut_service_group_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.service_group
     }
 
-    // optional string from_id = 3;
+    // optional uint64 incarnation = 3;
 
-    pub fn clear_from_id(&mut self) {
-        self.from_id.clear();
+    pub fn clear_incarnation(&mut self) {
+        self.incarnation = ::std::option::Option::None;
     }
 
-    pub fn has_from_id(&self) -> bool {
-        self.from_id.is_some()
+    pub fn has_incarnation(&self) -> bool {
+        self.incarnation.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_from_id(&mut self, v: ::std::string::String) {
-        self.from_id = ::protobuf::SingularField::some(v);
+    pub fn set_incarnation(&mut self, v: u64) {
+        self.incarnation = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_from_id(&mut self) -> &mut ::std::string::String {
-        if self.from_id.is_none() {
-            self.from_id.set_default();
-        }
-        self.from_id.as_mut().unwrap()
+    pub fn get_incarnation(&self) -> u64 {
+        self.incarnation.unwrap_or(0)
     }
 
-    // Take field
-    pub fn take_from_id(&mut self) -> ::std::string::String {
-        self.from_id.take().unwrap_or_else(|| ::std::string::String::new())
+    fn get_incarnation_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.incarnation
     }
 
-    pub fn get_from_id(&self) -> &str {
-        match self.from_id.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    fn mut_incarnation_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.incarnation
     }
 
-    fn get_from_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.from_id
+    // optional uint32 shard_count = 4;
+
+    pub fn clear_shard_count(&mut self) {
+        self.shard_count = ::std::option::Option::None;
     }
 
-    fn mut_from_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.from_id
+    pub fn has_shard_count(&self) -> bool {
+        self.shard_count.is_some()
     }
 
-    // optional .Membership member = 4;
+    // Param is passed by value, moved
+    pub fn set_shard_count(&mut self, v: u32) {
+        self.shard_count = ::std::option::Option::Some(v);
+    }
 
-    pub fn clear_member(&mut self) {
-        self.payload = ::std::option::Option::None;
+    pub fn get_shard_count(&self) -> u32 {
+        self.shard_count.unwrap_or(0)
     }
 
-    pub fn has_member(&self) -> bool {
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::member(..)) => true,
-            _ => false,
-        }
+    fn get_shard_count_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.shard_count
+    }
+
+    fn mut_shard_count_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.shard_count
+    }
+
+    // optional bool multi_leader = 5;
+
+    pub fn clear_multi_leader(&mut self) {
+        self.multi_leader = ::std::option::Option::None;
+    }
+
+    pub fn has_multi_leader(&self) -> bool {
+        self.multi_leader.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_member(&mut self, v: Membership) {
-        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::member(v))
+    pub fn set_multi_leader(&mut self, v: bool) {
+        self.multi_leader = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_member(&mut self) -> &mut Membership {
-        if let ::std::option::Option::Some(Rumor_oneof_payload::member(_)) = self.payload {
-        } else {
-            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::member(Membership::new()));
-        }
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::member(ref mut v)) => v,
-            _ => panic!(),
-        }
+    pub fn get_multi_leader(&self) -> bool {
+        self.multi_leader.unwrap_or(false)
     }
 
-    // Take field
-    pub fn take_member(&mut self) -> Membership {
-        if self.has_member() {
-            match self.payload.take() {
-                ::std::option::Option::Some(Rumor_oneof_payload::member(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Membership::new()
-        }
+    fn get_multi_leader_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.multi_leader
     }
 
-    pub fn get_member(&self) -> &Membership {
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::member(ref v)) => v,
-            _ => Membership::default_instance(),
-        }
+    fn mut_multi_leader_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.multi_leader
     }
 
-    // optional .Service service = 5;
-
-    pub fn clear_service(&mut self) {
-        self.payload = ::std::option::Option::None;
-    }
+    // repeated .ShardSlot slots = 6;
 
-    pub fn has_service(&self) -> bool {
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service(..)) => true,
-            _ => false,
-        }
+    pub fn clear_slots(&mut self) {
+        self.slots.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_service(&mut self, v: Service) {
-        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service(v))
+    pub fn set_slots(&mut self, v: ::protobuf::RepeatedField<ShardSlot>) {
+        self.slots = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_service(&mut self) -> &mut Service {
-        if let ::std::option::Option::Some(Rumor_oneof_payload::service(_)) = self.payload {
-        } else {
-            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service(Service::new()));
-        }
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service(ref mut v)) => v,
-            _ => panic!(),
-        }
+    pub fn mut_slots(&mut self) -> &mut ::protobuf::RepeatedField<ShardSlot> {
+        &mut self.slots
     }
 
     // Take field
-    pub fn take_service(&mut self) -> Service {
-        if self.has_service() {
-            match self.payload.take() {
-                ::std::option::Option::Some(Rumor_oneof_payload::service(v)) => v,
-                _ => panic!(),
+    pub fn take_slots(&mut self) -> ::protobuf::RepeatedField<ShardSlot> {
+        ::std::mem::replace(&mut self.slots, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_slots(&self) -> &[ShardSlot] {
+        &self.slots
+    }
+
+    fn get_slots_for_reflect(&self) -> &::protobuf::RepeatedField<ShardSlot> {
+        &self.slots
+    }
+
+    fn mut_slots_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<ShardSlot> {
+        &mut self.slots
+    }
+}
+
+impl ::protobuf::Message for ShardMap {
+    fn is_initialized(&self) -> bool {
+        for v in &self.slots {
+            if !v.is_initialized() {
+                return false;
             }
-        } else {
-            Service::new()
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.member_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.service_group)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.incarnation = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.shard_count = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.multi_leader = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.slots)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
         }
+        ::std::result::Result::Ok(())
     }
 
-    pub fn get_service(&self) -> &Service {
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service(ref v)) => v,
-            _ => Service::default_instance(),
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.member_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.service_group.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.incarnation {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.shard_count {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.multi_leader {
+            my_size += 2;
+        }
+        for value in &self.slots {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.member_id.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.service_group.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(v) = self.incarnation {
+            os.write_uint64(3, v)?;
+        }
+        if let Some(v) = self.shard_count {
+            os.write_uint32(4, v)?;
+        }
+        if let Some(v) = self.multi_leader {
+            os.write_bool(5, v)?;
         }
+        for v in &self.slots {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // optional .ServiceConfig service_config = 6;
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
 
-    pub fn clear_service_config(&mut self) {
-        self.payload = ::std::option::Option::None;
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
     }
 
-    pub fn has_service_config(&self) -> bool {
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service_config(..)) => true,
-            _ => false,
-        }
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
 
-    // Param is passed by value, moved
-    pub fn set_service_config(&mut self, v: ServiceConfig) {
-        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_config(v))
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_service_config(&mut self) -> &mut ServiceConfig {
-        if let ::std::option::Option::Some(Rumor_oneof_payload::service_config(_)) = self.payload {
-        } else {
-            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_config(ServiceConfig::new()));
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ShardMap {
+    fn new() -> ShardMap {
+        ShardMap::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ShardMap>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "member_id",
+                    ShardMap::get_member_id_for_reflect,
+                    ShardMap::mut_member_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "service_group",
+                    ShardMap::get_service_group_for_reflect,
+                    ShardMap::mut_service_group_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "incarnation",
+                    ShardMap::get_incarnation_for_reflect,
+                    ShardMap::mut_incarnation_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "shard_count",
+                    ShardMap::get_shard_count_for_reflect,
+                    ShardMap::mut_shard_count_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "multi_leader",
+                    ShardMap::get_multi_leader_for_reflect,
+                    ShardMap::mut_multi_leader_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ShardSlot>>(
+                    "slots",
+                    ShardMap::get_slots_for_reflect,
+                    ShardMap::mut_slots_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ShardMap>(
+                    "ShardMap",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
         }
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service_config(ref mut v)) => v,
-            _ => panic!(),
+    }
+}
+
+impl ::protobuf::Clear for ShardMap {
+    fn clear(&mut self) {
+        self.clear_member_id();
+        self.clear_service_group();
+        self.clear_incarnation();
+        self.clear_shard_count();
+        self.clear_multi_leader();
+        self.clear_slots();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ShardMap {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShardMap {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RingKey {
+    // message fields
+    member_id: ::protobuf::SingularField<::std::string::String>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    revision: ::protobuf::SingularField<::std::string::String>,
+    incarnation: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for RingKey {}
+
+impl RingKey {
+    pub fn new() -> RingKey {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static RingKey {
+        static mut instance: ::protobuf::lazy::Lazy<RingKey> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RingKey,
+        };
+        unsafe {
+            instance.get(RingKey::new)
         }
     }
 
-    // Take field
-    pub fn take_service_config(&mut self) -> ServiceConfig {
-        if self.has_service_config() {
-            match self.payload.take() {
-                ::std::option::Option::Some(Rumor_oneof_payload::service_config(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            ServiceConfig::new()
-        }
+    // optional string member_id = 1;
+
+    pub fn clear_member_id(&mut self) {
+        self.member_id.clear();
+    }
+
+    pub fn has_member_id(&self) -> bool {
+        self.member_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_member_id(&mut self, v: ::std::string::String) {
+        self.member_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_member_id(&mut self) -> &mut ::std::string::String {
+        if self.member_id.is_none() {
+            self.member_id.set_default();
+        }
+        self.member_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_member_id(&mut self) -> ::std::string::String {
+        self.member_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_member_id(&self) -> &str {
+        match self.member_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_member_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.member_id
+    }
+
+    fn mut_member_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.member_id
+    }
+
+    // optional string name = 2;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
+    }
+
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
+    }
+
+    // optional string revision = 3;
+
+    pub fn clear_revision(&mut self) {
+        self.revision.clear();
+    }
+
+    pub fn has_revision(&self) -> bool {
+        self.revision.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision(&mut self, v: ::std::string::String) {
+        self.revision = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_revision(&mut self) -> &mut ::std::string::String {
+        if self.revision.is_none() {
+            self.revision.set_default();
+        }
+        self.revision.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_revision(&mut self) -> ::std::string::String {
+        self.revision.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_revision(&self) -> &str {
+        match self.revision.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_revision_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.revision
+    }
+
+    fn mut_revision_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.revision
+    }
+
+    // optional uint64 incarnation = 4;
+
+    pub fn clear_incarnation(&mut self) {
+        self.incarnation = ::std::option::Option::None;
+    }
+
+    pub fn has_incarnation(&self) -> bool {
+        self.incarnation.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_incarnation(&mut self, v: u64) {
+        self.incarnation = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_incarnation(&self) -> u64 {
+        self.incarnation.unwrap_or(0)
+    }
+
+    fn get_incarnation_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.incarnation
+    }
+
+    fn mut_incarnation_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.incarnation
+    }
+}
+
+impl ::protobuf::Message for RingKey {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.member_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.revision)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.incarnation = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.member_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.revision.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.incarnation {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.member_id.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.revision.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(v) = self.incarnation {
+            os.write_uint64(4, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for RingKey {
+    fn new() -> RingKey {
+        RingKey::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<RingKey>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "member_id",
+                    RingKey::get_member_id_for_reflect,
+                    RingKey::mut_member_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    RingKey::get_name_for_reflect,
+                    RingKey::mut_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "revision",
+                    RingKey::get_revision_for_reflect,
+                    RingKey::mut_revision_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "incarnation",
+                    RingKey::get_incarnation_for_reflect,
+                    RingKey::mut_incarnation_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RingKey>(
+                    "RingKey",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for RingKey {
+    fn clear(&mut self) {
+        self.clear_member_id();
+        self.clear_name();
+        self.clear_revision();
+        self.clear_incarnation();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RingKey {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RingKey {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KeyValue {
+    // message fields
+    member_id: ::protobuf::SingularField<::std::string::String>,
+    service_group: ::protobuf::SingularField<::std::string::String>,
+    key: ::protobuf::SingularField<::std::string::String>,
+    value: ::protobuf::SingularField<::std::string::String>,
+    incarnation: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for KeyValue {}
+
+impl KeyValue {
+    pub fn new() -> KeyValue {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static KeyValue {
+        static mut instance: ::protobuf::lazy::Lazy<KeyValue> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const KeyValue,
+        };
+        unsafe {
+            instance.get(KeyValue::new)
+        }
+    }
+
+    // optional string member_id = 1;
+
+    pub fn clear_member_id(&mut self) {
+        self.member_id.clear();
+    }
+
+    pub fn has_member_id(&self) -> bool {
+        self.member_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_member_id(&mut self, v: ::std::string::String) {
+        self.member_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_member_id(&mut self) -> &mut ::std::string::String {
+        if self.member_id.is_none() {
+            self.member_id.set_default();
+        }
+        self.member_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_member_id(&mut self) -> ::std::string::String {
+        self.member_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_member_id(&self) -> &str {
+        match self.member_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_member_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.member_id
+    }
+
+    fn mut_member_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.member_id
+    }
+
+    // optional string service_group = 2;
+
+    pub fn clear_service_group(&mut self) {
+        self.service_group.clear();
+    }
+
+    pub fn has_service_group(&self) -> bool {
+        self.service_group.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_group(&mut self, v: ::std::string::String) {
+        self.service_group = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service_group(&mut self) -> &mut ::std::string::String {
+        if self.service_group.is_none() {
+            self.service_group.set_default();
+        }
+        self.service_group.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_service_group(&mut self) -> ::std::string::String {
+        self.service_group.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_service_group(&self) -> &str {
+        match self.service_group.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_service_group_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.service_group
+    }
+
+    fn mut_service_group_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.service_group
+    }
+
+    // optional string key = 3;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        if self.key.is_none() {
+            self.key.set_default();
+        }
+        self.key.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        self.key.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_key(&self) -> &str {
+        match self.key.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_key_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.key
+    }
+
+    fn mut_key_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.key
+    }
+
+    // optional string value = 4;
+
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        if self.value.is_none() {
+            self.value.set_default();
+        }
+        self.value.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        self.value.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_value(&self) -> &str {
+        match self.value.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_value_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.value
+    }
+
+    fn mut_value_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.value
+    }
+
+    // optional uint64 incarnation = 5;
+
+    pub fn clear_incarnation(&mut self) {
+        self.incarnation = ::std::option::Option::None;
+    }
+
+    pub fn has_incarnation(&self) -> bool {
+        self.incarnation.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_incarnation(&mut self, v: u64) {
+        self.incarnation = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_incarnation(&self) -> u64 {
+        self.incarnation.unwrap_or(0)
+    }
+
+    fn get_incarnation_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.incarnation
+    }
+
+    fn mut_incarnation_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.incarnation
+    }
+}
+
+impl ::protobuf::Message for KeyValue {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.member_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.service_group)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.value)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.incarnation = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.member_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.service_group.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.key.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(v) = self.incarnation {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.member_id.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.service_group.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.key.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        if let Some(v) = self.incarnation {
+            os.write_uint64(5, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for KeyValue {
+    fn new() -> KeyValue {
+        KeyValue::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<KeyValue>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "member_id",
+                    KeyValue::get_member_id_for_reflect,
+                    KeyValue::mut_member_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "service_group",
+                    KeyValue::get_service_group_for_reflect,
+                    KeyValue::mut_service_group_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "key",
+                    KeyValue::get_key_for_reflect,
+                    KeyValue::mut_key_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "value",
+                    KeyValue::get_value_for_reflect,
+                    KeyValue::mut_value_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "incarnation",
+                    KeyValue::get_incarnation_for_reflect,
+                    KeyValue::mut_incarnation_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<KeyValue>(
+                    "KeyValue",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for KeyValue {
+    fn clear(&mut self) {
+        self.clear_member_id();
+        self.clear_service_group();
+        self.clear_key();
+        self.clear_value();
+        self.clear_incarnation();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KeyValue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KeyValue {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FederatedMember {
+    // message fields
+    ring: ::protobuf::SingularField<::std::string::String>,
+    membership: ::protobuf::SingularPtrField<Membership>,
+    relayed_at: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for FederatedMember {}
+
+impl FederatedMember {
+    pub fn new() -> FederatedMember {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static FederatedMember {
+        static mut instance: ::protobuf::lazy::Lazy<FederatedMember> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const FederatedMember,
+        };
+        unsafe {
+            instance.get(FederatedMember::new)
+        }
+    }
+
+    // optional string ring = 1;
+
+    pub fn clear_ring(&mut self) {
+        self.ring.clear();
+    }
+
+    pub fn has_ring(&self) -> bool {
+        self.ring.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ring(&mut self, v: ::std::string::String) {
+        self.ring = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ring(&mut self) -> &mut ::std::string::String {
+        if self.ring.is_none() {
+            self.ring.set_default();
+        }
+        self.ring.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ring(&mut self) -> ::std::string::String {
+        self.ring.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_ring(&self) -> &str {
+        match self.ring.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_ring_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.ring
+    }
+
+    fn mut_ring_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.ring
+    }
+
+    // optional .Membership membership = 2;
+
+    pub fn clear_membership(&mut self) {
+        self.membership.clear();
+    }
+
+    pub fn has_membership(&self) -> bool {
+        self.membership.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_membership(&mut self, v: Membership) {
+        self.membership = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_membership(&mut self) -> &mut Membership {
+        if self.membership.is_none() {
+            self.membership.set_default();
+        }
+        self.membership.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_membership(&mut self) -> Membership {
+        self.membership.take().unwrap_or_else(|| Membership::new())
+    }
+
+    pub fn get_membership(&self) -> &Membership {
+        self.membership.as_ref().unwrap_or_else(|| Membership::default_instance())
+    }
+
+    fn get_membership_for_reflect(&self) -> &::protobuf::SingularPtrField<Membership> {
+        &self.membership
+    }
+
+    fn mut_membership_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Membership> {
+        &mut self.membership
+    }
+
+    // optional uint64 relayed_at = 3;
+
+    pub fn clear_relayed_at(&mut self) {
+        self.relayed_at = ::std::option::Option::None;
+    }
+
+    pub fn has_relayed_at(&self) -> bool {
+        self.relayed_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_relayed_at(&mut self, v: u64) {
+        self.relayed_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_relayed_at(&self) -> u64 {
+        self.relayed_at.unwrap_or(0)
+    }
+
+    fn get_relayed_at_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.relayed_at
+    }
+
+    fn mut_relayed_at_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.relayed_at
+    }
+}
+
+impl ::protobuf::Message for FederatedMember {
+    fn is_initialized(&self) -> bool {
+        for v in &self.membership {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.ring)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.membership)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.relayed_at = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.ring.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.membership.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.relayed_at {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.ring.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.membership.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.relayed_at {
+            os.write_uint64(3, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for FederatedMember {
+    fn new() -> FederatedMember {
+        FederatedMember::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<FederatedMember>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "ring",
+                    FederatedMember::get_ring_for_reflect,
+                    FederatedMember::mut_ring_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Membership>>(
+                    "membership",
+                    FederatedMember::get_membership_for_reflect,
+                    FederatedMember::mut_membership_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "relayed_at",
+                    FederatedMember::get_relayed_at_for_reflect,
+                    FederatedMember::mut_relayed_at_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<FederatedMember>(
+                    "FederatedMember",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for FederatedMember {
+    fn clear(&mut self) {
+        self.clear_ring();
+        self.clear_membership();
+        self.clear_relayed_at();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FederatedMember {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FederatedMember {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Swim {
+    // message fields
+    field_type: ::std::option::Option<Swim_Type>,
+    membership: ::protobuf::RepeatedField<Membership>,
+    // message oneof groups
+    payload: ::std::option::Option<Swim_oneof_payload>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Swim {}
+
+#[derive(Clone,PartialEq)]
+pub enum Swim_oneof_payload {
+    ping(Ping),
+    ack(Ack),
+    pingreq(PingReq),
+}
+
+impl Swim {
+    pub fn new() -> Swim {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Swim {
+        static mut instance: ::protobuf::lazy::Lazy<Swim> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Swim,
+        };
+        unsafe {
+            instance.get(Swim::new)
+        }
+    }
+
+    // required .Swim.Type type = 1;
+
+    pub fn clear_field_type(&mut self) {
+        self.field_type = ::std::option::Option::None;
+    }
+
+    pub fn has_field_type(&self) -> bool {
+        self.field_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: Swim_Type) {
+        self.field_type = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_field_type(&self) -> Swim_Type {
+        self.field_type.unwrap_or(Swim_Type::PING)
+    }
+
+    fn get_field_type_for_reflect(&self) -> &::std::option::Option<Swim_Type> {
+        &self.field_type
+    }
+
+    fn mut_field_type_for_reflect(&mut self) -> &mut ::std::option::Option<Swim_Type> {
+        &mut self.field_type
+    }
+
+    // optional .Ping ping = 2;
+
+    pub fn clear_ping(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_ping(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::ping(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ping(&mut self, v: Ping) {
+        self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ping(&mut self) -> &mut Ping {
+        if let ::std::option::Option::Some(Swim_oneof_payload::ping(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(Ping::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::ping(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_ping(&mut self) -> Ping {
+        if self.has_ping() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Swim_oneof_payload::ping(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Ping::new()
+        }
+    }
+
+    pub fn get_ping(&self) -> &Ping {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::ping(ref v)) => v,
+            _ => Ping::default_instance(),
+        }
+    }
+
+    // optional .Ack ack = 3;
+
+    pub fn clear_ack(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_ack(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::ack(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ack(&mut self, v: Ack) {
+        self.payload = ::std::option::Option::Some(Swim_oneof_payload::ack(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ack(&mut self) -> &mut Ack {
+        if let ::std::option::Option::Some(Swim_oneof_payload::ack(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Swim_oneof_payload::ack(Ack::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::ack(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_ack(&mut self) -> Ack {
+        if self.has_ack() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Swim_oneof_payload::ack(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Ack::new()
+        }
+    }
+
+    pub fn get_ack(&self) -> &Ack {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::ack(ref v)) => v,
+            _ => Ack::default_instance(),
+        }
+    }
+
+    // optional .PingReq pingreq = 4;
+
+    pub fn clear_pingreq(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_pingreq(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::pingreq(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pingreq(&mut self, v: PingReq) {
+        self.payload = ::std::option::Option::Some(Swim_oneof_payload::pingreq(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_pingreq(&mut self) -> &mut PingReq {
+        if let ::std::option::Option::Some(Swim_oneof_payload::pingreq(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Swim_oneof_payload::pingreq(PingReq::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::pingreq(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_pingreq(&mut self) -> PingReq {
+        if self.has_pingreq() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Swim_oneof_payload::pingreq(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PingReq::new()
+        }
+    }
+
+    pub fn get_pingreq(&self) -> &PingReq {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::pingreq(ref v)) => v,
+            _ => PingReq::default_instance(),
+        }
+    }
+
+    // repeated .Membership membership = 5;
+
+    pub fn clear_membership(&mut self) {
+        self.membership.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_membership(&mut self, v: ::protobuf::RepeatedField<Membership>) {
+        self.membership = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_membership(&mut self) -> &mut ::protobuf::RepeatedField<Membership> {
+        &mut self.membership
+    }
+
+    // Take field
+    pub fn take_membership(&mut self) -> ::protobuf::RepeatedField<Membership> {
+        ::std::mem::replace(&mut self.membership, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_membership(&self) -> &[Membership] {
+        &self.membership
+    }
+
+    fn get_membership_for_reflect(&self) -> &::protobuf::RepeatedField<Membership> {
+        &self.membership
+    }
+
+    fn mut_membership_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Membership> {
+        &mut self.membership
+    }
+}
+
+impl ::protobuf::Message for Swim {
+    fn is_initialized(&self) -> bool {
+        if self.field_type.is_none() {
+            return false;
+        }
+        if let Some(Swim_oneof_payload::ping(ref v)) = self.payload {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Swim_oneof_payload::ack(ref v)) = self.payload {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Swim_oneof_payload::pingreq(ref v)) = self.payload {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        for v in &self.membership {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.field_type = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.payload = ::std::option::Option::Some(Swim_oneof_payload::ack(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.payload = ::std::option::Option::Some(Swim_oneof_payload::pingreq(is.read_message()?));
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.membership)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.field_type {
+            my_size += ::protobuf::rt::enum_size(1, v);
+        }
+        for value in &self.membership {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let ::std::option::Option::Some(ref v) = self.payload {
+            match v {
+                &Swim_oneof_payload::ping(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Swim_oneof_payload::ack(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Swim_oneof_payload::pingreq(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.field_type {
+            os.write_enum(1, v.value())?;
+        }
+        for v in &self.membership {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let ::std::option::Option::Some(ref v) = self.payload {
+            match v {
+                &Swim_oneof_payload::ping(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Swim_oneof_payload::ack(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Swim_oneof_payload::pingreq(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Swim {
+    fn new() -> Swim {
+        Swim::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Swim>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Swim_Type>>(
+                    "type",
+                    Swim::get_field_type_for_reflect,
+                    Swim::mut_field_type_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Ping>(
+                    "ping",
+                    Swim::has_ping,
+                    Swim::get_ping,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Ack>(
+                    "ack",
+                    Swim::has_ack,
+                    Swim::get_ack,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PingReq>(
+                    "pingreq",
+                    Swim::has_pingreq,
+                    Swim::get_pingreq,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Membership>>(
+                    "membership",
+                    Swim::get_membership_for_reflect,
+                    Swim::mut_membership_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Swim>(
+                    "Swim",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Swim {
+    fn clear(&mut self) {
+        self.clear_field_type();
+        self.clear_ping();
+        self.clear_ack();
+        self.clear_pingreq();
+        self.clear_membership();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Swim {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Swim {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Swim_Type {
+    PING = 1,
+    ACK = 2,
+    PINGREQ = 3,
+}
+
+impl ::protobuf::ProtobufEnum for Swim_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Swim_Type> {
+        match value {
+            1 => ::std::option::Option::Some(Swim_Type::PING),
+            2 => ::std::option::Option::Some(Swim_Type::ACK),
+            3 => ::std::option::Option::Some(Swim_Type::PINGREQ),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Swim_Type] = &[
+            Swim_Type::PING,
+            Swim_Type::ACK,
+            Swim_Type::PINGREQ,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: ::std::option::Option<Swim_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("Swim_Type", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Swim_Type {
+}
+
+impl ::protobuf::reflect::ProtobufValue for Swim_Type {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Rumor {
+    // message fields
+    field_type: ::std::option::Option<Rumor_Type>,
+    tag: ::protobuf::RepeatedField<::std::string::String>,
+    from_id: ::protobuf::SingularField<::std::string::String>,
+    origin: ::protobuf::SingularPtrField<Signature>,
+    // message oneof groups
+    payload: ::std::option::Option<Rumor_oneof_payload>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Rumor {}
+
+#[derive(Clone,PartialEq)]
+pub enum Rumor_oneof_payload {
+    member(Membership),
+    service(Service),
+    service_config(ServiceConfig),
+    service_file(ServiceFile),
+    election(Election),
+    departure(Departure),
+    shard_map(ShardMap),
+    ring_key(RingKey),
+    key_value(KeyValue),
+    federated_member(FederatedMember),
+}
+
+impl Rumor {
+    pub fn new() -> Rumor {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Rumor {
+        static mut instance: ::protobuf::lazy::Lazy<Rumor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Rumor,
+        };
+        unsafe {
+            instance.get(Rumor::new)
+        }
+    }
+
+    // required .Rumor.Type type = 1;
+
+    pub fn clear_field_type(&mut self) {
+        self.field_type = ::std::option::Option::None;
+    }
+
+    pub fn has_field_type(&self) -> bool {
+        self.field_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: Rumor_Type) {
+        self.field_type = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_field_type(&self) -> Rumor_Type {
+        self.field_type.unwrap_or(Rumor_Type::Member)
+    }
+
+    fn get_field_type_for_reflect(&self) -> &::std::option::Option<Rumor_Type> {
+        &self.field_type
+    }
+
+    fn mut_field_type_for_reflect(&mut self) -> &mut ::std::option::Option<Rumor_Type> {
+        &mut self.field_type
+    }
+
+    // repeated string tag = 2;
+
+    pub fn clear_tag(&mut self) {
+        self.tag.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tag(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tag = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tag(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tag
+    }
+
+    // Take field
+    pub fn take_tag(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tag, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_tag(&self) -> &[::std::string::String] {
+        &self.tag
+    }
+
+    fn get_tag_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.tag
+    }
+
+    fn mut_tag_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tag
+    }
+
+    // optional string from_id = 3;
+
+    pub fn clear_from_id(&mut self) {
+        self.from_id.clear();
+    }
+
+    pub fn has_from_id(&self) -> bool {
+        self.from_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from_id(&mut self, v: ::std::string::String) {
+        self.from_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_from_id(&mut self) -> &mut ::std::string::String {
+        if self.from_id.is_none() {
+            self.from_id.set_default();
+        }
+        self.from_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_from_id(&mut self) -> ::std::string::String {
+        self.from_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_from_id(&self) -> &str {
+        match self.from_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_from_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.from_id
+    }
+
+    fn mut_from_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.from_id
+    }
+
+    // optional .Membership member = 4;
+
+    pub fn clear_member(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_member(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::member(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_member(&mut self, v: Membership) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::member(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_member(&mut self) -> &mut Membership {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::member(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::member(Membership::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::member(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_member(&mut self) -> Membership {
+        if self.has_member() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::member(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Membership::new()
+        }
+    }
+
+    pub fn get_member(&self) -> &Membership {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::member(ref v)) => v,
+            _ => Membership::default_instance(),
+        }
+    }
+
+    // optional .Service service = 5;
+
+    pub fn clear_service(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_service(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service(&mut self, v: Service) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_service(&mut self) -> &mut Service {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::service(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service(Service::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_service(&mut self) -> Service {
+        if self.has_service() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::service(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Service::new()
+        }
+    }
+
+    pub fn get_service(&self) -> &Service {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service(ref v)) => v,
+            _ => Service::default_instance(),
+        }
+    }
+
+    // optional .ServiceConfig service_config = 6;
+
+    pub fn clear_service_config(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_service_config(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service_config(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_config(&mut self, v: ServiceConfig) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_config(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_service_config(&mut self) -> &mut ServiceConfig {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::service_config(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_config(ServiceConfig::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service_config(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_service_config(&mut self) -> ServiceConfig {
+        if self.has_service_config() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::service_config(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ServiceConfig::new()
+        }
+    }
+
+    pub fn get_service_config(&self) -> &ServiceConfig {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service_config(ref v)) => v,
+            _ => ServiceConfig::default_instance(),
+        }
+    }
+
+    // optional .ServiceFile service_file = 7;
+
+    pub fn clear_service_file(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_service_file(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service_file(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_file(&mut self, v: ServiceFile) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_file(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_service_file(&mut self) -> &mut ServiceFile {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::service_file(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_file(ServiceFile::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service_file(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_service_file(&mut self) -> ServiceFile {
+        if self.has_service_file() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::service_file(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ServiceFile::new()
+        }
+    }
+
+    pub fn get_service_file(&self) -> &ServiceFile {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service_file(ref v)) => v,
+            _ => ServiceFile::default_instance(),
+        }
+    }
+
+    // optional .Election election = 8;
+
+    pub fn clear_election(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_election(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::election(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_election(&mut self, v: Election) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::election(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_election(&mut self) -> &mut Election {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::election(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::election(Election::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::election(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_election(&mut self) -> Election {
+        if self.has_election() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::election(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Election::new()
+        }
+    }
+
+    pub fn get_election(&self) -> &Election {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::election(ref v)) => v,
+            _ => Election::default_instance(),
+        }
+    }
+
+    // optional .Departure departure = 9;
+
+    pub fn clear_departure(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_departure(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::departure(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_departure(&mut self, v: Departure) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::departure(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_departure(&mut self) -> &mut Departure {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::departure(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::departure(Departure::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::departure(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_departure(&mut self) -> Departure {
+        if self.has_departure() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::departure(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Departure::new()
+        }
+    }
+
+    pub fn get_departure(&self) -> &Departure {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::departure(ref v)) => v,
+            _ => Departure::default_instance(),
+        }
+    }
+
+    // optional .ShardMap shard_map = 10;
+
+    pub fn clear_shard_map(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_shard_map(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::shard_map(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shard_map(&mut self, v: ShardMap) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::shard_map(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_shard_map(&mut self) -> &mut ShardMap {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::shard_map(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::shard_map(ShardMap::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::shard_map(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_shard_map(&mut self) -> ShardMap {
+        if self.has_shard_map() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::shard_map(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ShardMap::new()
+        }
+    }
+
+    pub fn get_shard_map(&self) -> &ShardMap {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::shard_map(ref v)) => v,
+            _ => ShardMap::default_instance(),
+        }
+    }
+
+    // optional .RingKey ring_key = 11;
+
+    pub fn clear_ring_key(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_ring_key(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::ring_key(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ring_key(&mut self, v: RingKey) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::ring_key(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ring_key(&mut self) -> &mut RingKey {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::ring_key(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::ring_key(RingKey::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::ring_key(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_ring_key(&mut self) -> RingKey {
+        if self.has_ring_key() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::ring_key(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            RingKey::new()
+        }
+    }
+
+    pub fn get_ring_key(&self) -> &RingKey {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::ring_key(ref v)) => v,
+            _ => RingKey::default_instance(),
+        }
+    }
+
+    // optional .KeyValue key_value = 12;
+
+    pub fn clear_key_value(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_key_value(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::key_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_value(&mut self, v: KeyValue) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::key_value(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_key_value(&mut self) -> &mut KeyValue {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::key_value(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::key_value(KeyValue::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::key_value(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_key_value(&mut self) -> KeyValue {
+        if self.has_key_value() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::key_value(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            KeyValue::new()
+        }
+    }
+
+    pub fn get_key_value(&self) -> &KeyValue {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::key_value(ref v)) => v,
+            _ => KeyValue::default_instance(),
+        }
+    }
+
+    // optional .FederatedMember federated_member = 13;
+
+    pub fn clear_federated_member(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_federated_member(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::federated_member(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_federated_member(&mut self, v: FederatedMember) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::federated_member(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_federated_member(&mut self) -> &mut FederatedMember {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::federated_member(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::federated_member(FederatedMember::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::federated_member(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_federated_member(&mut self) -> FederatedMember {
+        if self.has_federated_member() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::federated_member(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FederatedMember::new()
+        }
+    }
+
+    pub fn get_federated_member(&self) -> &FederatedMember {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::federated_member(ref v)) => v,
+            _ => FederatedMember::default_instance(),
+        }
+    }
+
+    // optional .Signature origin = 14;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: Signature) {
+        self.origin = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut Signature {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> Signature {
+        self.origin.take().unwrap_or_else(|| Signature::new())
+    }
+
+    pub fn get_origin(&self) -> &Signature {
+        self.origin.as_ref().unwrap_or_else(|| Signature::default_instance())
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularPtrField<Signature> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Signature> {
+        &mut self.origin
+    }
+}
+
+impl ::protobuf::Message for Rumor {
+    fn is_initialized(&self) -> bool {
+        if self.field_type.is_none() {
+            return false;
+        }
+        if let Some(Rumor_oneof_payload::member(ref v)) = self.payload {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Rumor_oneof_payload::service(ref v)) = self.payload {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Rumor_oneof_payload::service_config(ref v)) = self.payload {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Rumor_oneof_payload::service_file(ref v)) = self.payload {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Rumor_oneof_payload::election(ref v)) = self.payload {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Rumor_oneof_payload::departure(ref v)) = self.payload {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Rumor_oneof_payload::shard_map(ref v)) = self.payload {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Rumor_oneof_payload::ring_key(ref v)) = self.payload {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Rumor_oneof_payload::key_value(ref v)) = self.payload {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Rumor_oneof_payload::federated_member(ref v)) = self.payload {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        for v in &self.origin {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.field_type = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tag)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.from_id)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::member(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_config(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_file(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::election(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::departure(is.read_message()?));
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::shard_map(is.read_message()?));
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::ring_key(is.read_message()?));
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::key_value(is.read_message()?));
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::federated_member(is.read_message()?));
+                },
+                14 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.field_type {
+            my_size += ::protobuf::rt::enum_size(1, v);
+        }
+        for value in &self.tag {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        if let Some(ref v) = self.from_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(ref v) = self.origin.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.payload {
+            match v {
+                &Rumor_oneof_payload::member(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::service(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::service_config(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::service_file(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::election(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::departure(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::shard_map(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::ring_key(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::key_value(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::federated_member(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.field_type {
+            os.write_enum(1, v.value())?;
+        }
+        for v in &self.tag {
+            os.write_string(2, &v)?;
+        };
+        if let Some(ref v) = self.from_id.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_tag(14, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.payload {
+            match v {
+                &Rumor_oneof_payload::member(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Rumor_oneof_payload::service(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Rumor_oneof_payload::service_config(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Rumor_oneof_payload::service_file(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Rumor_oneof_payload::election(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Rumor_oneof_payload::departure(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Rumor_oneof_payload::shard_map(ref v) => {
+                    os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Rumor_oneof_payload::ring_key(ref v) => {
+                    os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Rumor_oneof_payload::key_value(ref v) => {
+                    os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Rumor_oneof_payload::federated_member(ref v) => {
+                    os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Rumor {
+    fn new() -> Rumor {
+        Rumor::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Rumor>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Rumor_Type>>(
+                    "type",
+                    Rumor::get_field_type_for_reflect,
+                    Rumor::mut_field_type_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "tag",
+                    Rumor::get_tag_for_reflect,
+                    Rumor::mut_tag_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "from_id",
+                    Rumor::get_from_id_for_reflect,
+                    Rumor::mut_from_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Membership>(
+                    "member",
+                    Rumor::has_member,
+                    Rumor::get_member,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Service>(
+                    "service",
+                    Rumor::has_service,
+                    Rumor::get_service,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ServiceConfig>(
+                    "service_config",
+                    Rumor::has_service_config,
+                    Rumor::get_service_config,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ServiceFile>(
+                    "service_file",
+                    Rumor::has_service_file,
+                    Rumor::get_service_file,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Election>(
+                    "election",
+                    Rumor::has_election,
+                    Rumor::get_election,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Departure>(
+                    "departure",
+                    Rumor::has_departure,
+                    Rumor::get_departure,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ShardMap>(
+                    "shard_map",
+                    Rumor::has_shard_map,
+                    Rumor::get_shard_map,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, RingKey>(
+                    "ring_key",
+                    Rumor::has_ring_key,
+                    Rumor::get_ring_key,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, KeyValue>(
+                    "key_value",
+                    Rumor::has_key_value,
+                    Rumor::get_key_value,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FederatedMember>(
+                    "federated_member",
+                    Rumor::has_federated_member,
+                    Rumor::get_federated_member,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Signature>>(
+                    "origin",
+                    Rumor::get_origin_for_reflect,
+                    Rumor::mut_origin_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Rumor>(
+                    "Rumor",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Rumor {
+    fn clear(&mut self) {
+        self.clear_field_type();
+        self.clear_tag();
+        self.clear_from_id();
+        self.clear_member();
+        self.clear_service();
+        self.clear_service_config();
+        self.clear_service_file();
+        self.clear_election();
+        self.clear_departure();
+        self.clear_shard_map();
+        self.clear_ring_key();
+        self.clear_key_value();
+        self.clear_federated_member();
+        self.clear_origin();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Rumor {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Rumor {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Rumor_Type {
+    Member = 1,
+    Service = 2,
+    Election = 3,
+    ServiceConfig = 4,
+    ServiceFile = 5,
+    Fake = 6,
+    Fake2 = 7,
+    ElectionUpdate = 8,
+    Departure = 9,
+    ShardMap = 10,
+    RingKey = 11,
+    KeyValue = 12,
+    FederatedMember = 13,
+}
+
+impl ::protobuf::ProtobufEnum for Rumor_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Rumor_Type> {
+        match value {
+            1 => ::std::option::Option::Some(Rumor_Type::Member),
+            2 => ::std::option::Option::Some(Rumor_Type::Service),
+            3 => ::std::option::Option::Some(Rumor_Type::Election),
+            4 => ::std::option::Option::Some(Rumor_Type::ServiceConfig),
+            5 => ::std::option::Option::Some(Rumor_Type::ServiceFile),
+            6 => ::std::option::Option::Some(Rumor_Type::Fake),
+            7 => ::std::option::Option::Some(Rumor_Type::Fake2),
+            8 => ::std::option::Option::Some(Rumor_Type::ElectionUpdate),
+            9 => ::std::option::Option::Some(Rumor_Type::Departure),
+            10 => ::std::option::Option::Some(Rumor_Type::ShardMap),
+            11 => ::std::option::Option::Some(Rumor_Type::RingKey),
+            12 => ::std::option::Option::Some(Rumor_Type::KeyValue),
+            13 => ::std::option::Option::Some(Rumor_Type::FederatedMember),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Rumor_Type] = &[
+            Rumor_Type::Member,
+            Rumor_Type::Service,
+            Rumor_Type::Election,
+            Rumor_Type::ServiceConfig,
+            Rumor_Type::ServiceFile,
+            Rumor_Type::Fake,
+            Rumor_Type::Fake2,
+            Rumor_Type::ElectionUpdate,
+            Rumor_Type::Departure,
+            Rumor_Type::ShardMap,
+            Rumor_Type::RingKey,
+            Rumor_Type::KeyValue,
+            Rumor_Type::FederatedMember,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: ::std::option::Option<Rumor_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("Rumor_Type", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Rumor_Type {
+}
+
+impl ::protobuf::reflect::ProtobufValue for Rumor_Type {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Signature {
+    // message fields
+    signer: ::protobuf::SingularField<::std::string::String>,
+    public_key: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    signature: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Signature {}
+
+impl Signature {
+    pub fn new() -> Signature {
+        ::std::default::Default::default()
     }
 
-    pub fn get_service_config(&self) -> &ServiceConfig {
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service_config(ref v)) => v,
-            _ => ServiceConfig::default_instance(),
+    pub fn default_instance() -> &'static Signature {
+        static mut instance: ::protobuf::lazy::Lazy<Signature> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Signature,
+        };
+        unsafe {
+            instance.get(Signature::new)
         }
     }
 
-    // optional .ServiceFile service_file = 7;
+    // optional string signer = 1;
 
-    pub fn clear_service_file(&mut self) {
-        self.payload = ::std::option::Option::None;
+    pub fn clear_signer(&mut self) {
+        self.signer.clear();
     }
 
-    pub fn has_service_file(&self) -> bool {
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service_file(..)) => true,
-            _ => false,
-        }
+    pub fn has_signer(&self) -> bool {
+        self.signer.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_service_file(&mut self, v: ServiceFile) {
-        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_file(v))
+    pub fn set_signer(&mut self, v: ::std::string::String) {
+        self.signer = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_service_file(&mut self) -> &mut ServiceFile {
-        if let ::std::option::Option::Some(Rumor_oneof_payload::service_file(_)) = self.payload {
-        } else {
-            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_file(ServiceFile::new()));
-        }
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service_file(ref mut v)) => v,
-            _ => panic!(),
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signer(&mut self) -> &mut ::std::string::String {
+        if self.signer.is_none() {
+            self.signer.set_default();
         }
+        self.signer.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_service_file(&mut self) -> ServiceFile {
-        if self.has_service_file() {
-            match self.payload.take() {
-                ::std::option::Option::Some(Rumor_oneof_payload::service_file(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            ServiceFile::new()
-        }
+    pub fn take_signer(&mut self) -> ::std::string::String {
+        self.signer.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_service_file(&self) -> &ServiceFile {
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service_file(ref v)) => v,
-            _ => ServiceFile::default_instance(),
+    pub fn get_signer(&self) -> &str {
+        match self.signer.as_ref() {
+            Some(v) => &v,
+            None => "",
         }
     }
 
-    // optional .Election election = 8;
+    fn get_signer_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.signer
+    }
 
-    pub fn clear_election(&mut self) {
-        self.payload = ::std::option::Option::None;
+    fn mut_signer_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.signer
     }
 
-    pub fn has_election(&self) -> bool {
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::election(..)) => true,
-            _ => false,
-        }
+    // optional bytes public_key = 2;
+
+    pub fn clear_public_key(&mut self) {
+        self.public_key.clear();
+    }
+
+    pub fn has_public_key(&self) -> bool {
+        self.public_key.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_election(&mut self, v: Election) {
-        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::election(v))
+    pub fn set_public_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.public_key = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_election(&mut self) -> &mut Election {
-        if let ::std::option::Option::Some(Rumor_oneof_payload::election(_)) = self.payload {
-        } else {
-            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::election(Election::new()));
-        }
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::election(ref mut v)) => v,
-            _ => panic!(),
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_public_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.public_key.is_none() {
+            self.public_key.set_default();
         }
+        self.public_key.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_election(&mut self) -> Election {
-        if self.has_election() {
-            match self.payload.take() {
-                ::std::option::Option::Some(Rumor_oneof_payload::election(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Election::new()
-        }
+    pub fn take_public_key(&mut self) -> ::std::vec::Vec<u8> {
+        self.public_key.take().unwrap_or_else(|| ::std::vec::Vec::new())
     }
 
-    pub fn get_election(&self) -> &Election {
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::election(ref v)) => v,
-            _ => Election::default_instance(),
+    pub fn get_public_key(&self) -> &[u8] {
+        match self.public_key.as_ref() {
+            Some(v) => &v,
+            None => &[],
         }
     }
 
-    // optional .Departure departure = 9;
+    fn get_public_key_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.public_key
+    }
 
-    pub fn clear_departure(&mut self) {
-        self.payload = ::std::option::Option::None;
+    fn mut_public_key_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.public_key
     }
 
-    pub fn has_departure(&self) -> bool {
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::departure(..)) => true,
-            _ => false,
-        }
+    // optional bytes signature = 3;
+
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    pub fn has_signature(&self) -> bool {
+        self.signature.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_departure(&mut self, v: Departure) {
-        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::departure(v))
+    pub fn set_signature(&mut self, v: ::std::vec::Vec<u8>) {
+        self.signature = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_departure(&mut self) -> &mut Departure {
-        if let ::std::option::Option::Some(Rumor_oneof_payload::departure(_)) = self.payload {
-        } else {
-            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::departure(Departure::new()));
-        }
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::departure(ref mut v)) => v,
-            _ => panic!(),
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signature(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.signature.is_none() {
+            self.signature.set_default();
         }
+        self.signature.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_departure(&mut self) -> Departure {
-        if self.has_departure() {
-            match self.payload.take() {
-                ::std::option::Option::Some(Rumor_oneof_payload::departure(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Departure::new()
-        }
+    pub fn take_signature(&mut self) -> ::std::vec::Vec<u8> {
+        self.signature.take().unwrap_or_else(|| ::std::vec::Vec::new())
     }
 
-    pub fn get_departure(&self) -> &Departure {
-        match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::departure(ref v)) => v,
-            _ => Departure::default_instance(),
+    pub fn get_signature(&self) -> &[u8] {
+        match self.signature.as_ref() {
+            Some(v) => &v,
+            None => &[],
         }
     }
+
+    fn get_signature_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.signature
+    }
+
+    fn mut_signature_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.signature
+    }
 }
 
-impl ::protobuf::Message for Rumor {
+impl ::protobuf::Message for Signature {
     fn is_initialized(&self) -> bool {
-        if self.field_type.is_none() {
-            return false;
-        }
-        if let Some(Rumor_oneof_payload::member(ref v)) = self.payload {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Rumor_oneof_payload::service(ref v)) = self.payload {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Rumor_oneof_payload::service_config(ref v)) = self.payload {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Rumor_oneof_payload::service_file(ref v)) = self.payload {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Rumor_oneof_payload::election(ref v)) = self.payload {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Rumor_oneof_payload::departure(ref v)) = self.payload {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
         true
     }
 
@@ -4972,151 +7922,49 @@ impl ::protobuf::Message for Rumor {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_enum()?;
-                    self.field_type = ::std::option::Option::Some(tmp);
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.signer)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tag)?;
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.public_key)?;
                 },
                 3 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.from_id)?;
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::member(is.read_message()?));
-                },
-                5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service(is.read_message()?));
-                },
-                6 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_config(is.read_message()?));
-                },
-                7 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_file(is.read_message()?));
-                },
-                8 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::election(is.read_message()?));
-                },
-                9 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::departure(is.read_message()?));
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.signature)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
-    }
-
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if let Some(v) = self.field_type {
-            my_size += ::protobuf::rt::enum_size(1, v);
-        }
-        for value in &self.tag {
-            my_size += ::protobuf::rt::string_size(2, &value);
-        };
-        if let Some(ref v) = self.from_id.as_ref() {
-            my_size += ::protobuf::rt::string_size(3, &v);
-        }
-        if let ::std::option::Option::Some(ref v) = self.payload {
-            match v {
-                &Rumor_oneof_payload::member(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Rumor_oneof_payload::service(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Rumor_oneof_payload::service_config(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Rumor_oneof_payload::service_file(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Rumor_oneof_payload::election(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Rumor_oneof_payload::departure(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-            };
-        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.signer.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.public_key.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(2, &v);
+        }
+        if let Some(ref v) = self.signature.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(3, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.field_type {
-            os.write_enum(1, v.value())?;
+        if let Some(ref v) = self.signer.as_ref() {
+            os.write_string(1, &v)?;
         }
-        for v in &self.tag {
-            os.write_string(2, &v)?;
-        };
-        if let Some(ref v) = self.from_id.as_ref() {
-            os.write_string(3, &v)?;
+        if let Some(ref v) = self.public_key.as_ref() {
+            os.write_bytes(2, &v)?;
         }
-        if let ::std::option::Option::Some(ref v) = self.payload {
-            match v {
-                &Rumor_oneof_payload::member(ref v) => {
-                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Rumor_oneof_payload::service(ref v) => {
-                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Rumor_oneof_payload::service_config(ref v) => {
-                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Rumor_oneof_payload::service_file(ref v) => {
-                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Rumor_oneof_payload::election(ref v) => {
-                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Rumor_oneof_payload::departure(ref v) => {
-                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-            };
+        if let Some(ref v) = self.signature.as_ref() {
+            os.write_bytes(3, &v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -5149,12 +7997,12 @@ impl ::protobuf::Message for Rumor {
     }
 }
 
-impl ::protobuf::MessageStatic for Rumor {
-    fn new() -> Rumor {
-        Rumor::new()
+impl ::protobuf::MessageStatic for Signature {
+    fn new() -> Signature {
+        Signature::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Rumor>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<Signature>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -5162,53 +8010,23 @@ impl ::protobuf::MessageStatic for Rumor {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Rumor_Type>>(
-                    "type",
-                    Rumor::get_field_type_for_reflect,
-                    Rumor::mut_field_type_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "tag",
-                    Rumor::get_tag_for_reflect,
-                    Rumor::mut_tag_for_reflect,
-                ));
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "from_id",
-                    Rumor::get_from_id_for_reflect,
-                    Rumor::mut_from_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Membership>(
-                    "member",
-                    Rumor::has_member,
-                    Rumor::get_member,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Service>(
-                    "service",
-                    Rumor::has_service,
-                    Rumor::get_service,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ServiceConfig>(
-                    "service_config",
-                    Rumor::has_service_config,
-                    Rumor::get_service_config,
+                    "signer",
+                    Signature::get_signer_for_reflect,
+                    Signature::mut_signer_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ServiceFile>(
-                    "service_file",
-                    Rumor::has_service_file,
-                    Rumor::get_service_file,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Election>(
-                    "election",
-                    Rumor::has_election,
-                    Rumor::get_election,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "public_key",
+                    Signature::get_public_key_for_reflect,
+                    Signature::mut_public_key_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Departure>(
-                    "departure",
-                    Rumor::has_departure,
-                    Rumor::get_departure,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "signature",
+                    Signature::get_signature_for_reflect,
+                    Signature::mut_signature_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Rumor>(
-                    "Rumor",
+                ::protobuf::reflect::MessageDescriptor::new::<Signature>(
+                    "Signature",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5217,109 +8035,35 @@ impl ::protobuf::MessageStatic for Rumor {
     }
 }
 
-impl ::protobuf::Clear for Rumor {
+impl ::protobuf::Clear for Signature {
     fn clear(&mut self) {
-        self.clear_field_type();
-        self.clear_tag();
-        self.clear_from_id();
-        self.clear_member();
-        self.clear_service();
-        self.clear_service_config();
-        self.clear_service_file();
-        self.clear_election();
-        self.clear_departure();
+        self.clear_signer();
+        self.clear_public_key();
+        self.clear_signature();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Rumor {
+impl ::std::fmt::Debug for Signature {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Rumor {
+impl ::protobuf::reflect::ProtobufValue for Signature {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
-#[derive(Clone,PartialEq,Eq,Debug,Hash)]
-pub enum Rumor_Type {
-    Member = 1,
-    Service = 2,
-    Election = 3,
-    ServiceConfig = 4,
-    ServiceFile = 5,
-    Fake = 6,
-    Fake2 = 7,
-    ElectionUpdate = 8,
-    Departure = 9,
-}
-
-impl ::protobuf::ProtobufEnum for Rumor_Type {
-    fn value(&self) -> i32 {
-        *self as i32
-    }
-
-    fn from_i32(value: i32) -> ::std::option::Option<Rumor_Type> {
-        match value {
-            1 => ::std::option::Option::Some(Rumor_Type::Member),
-            2 => ::std::option::Option::Some(Rumor_Type::Service),
-            3 => ::std::option::Option::Some(Rumor_Type::Election),
-            4 => ::std::option::Option::Some(Rumor_Type::ServiceConfig),
-            5 => ::std::option::Option::Some(Rumor_Type::ServiceFile),
-            6 => ::std::option::Option::Some(Rumor_Type::Fake),
-            7 => ::std::option::Option::Some(Rumor_Type::Fake2),
-            8 => ::std::option::Option::Some(Rumor_Type::ElectionUpdate),
-            9 => ::std::option::Option::Some(Rumor_Type::Departure),
-            _ => ::std::option::Option::None
-        }
-    }
-
-    fn values() -> &'static [Self] {
-        static values: &'static [Rumor_Type] = &[
-            Rumor_Type::Member,
-            Rumor_Type::Service,
-            Rumor_Type::Election,
-            Rumor_Type::ServiceConfig,
-            Rumor_Type::ServiceFile,
-            Rumor_Type::Fake,
-            Rumor_Type::Fake2,
-            Rumor_Type::ElectionUpdate,
-            Rumor_Type::Departure,
-        ];
-        values
-    }
-
-    fn enum_descriptor_static(_: ::std::option::Option<Rumor_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("Rumor_Type", file_descriptor_proto())
-            })
-        }
-    }
-}
-
-impl ::std::marker::Copy for Rumor_Type {
-}
-
-impl ::protobuf::reflect::ProtobufValue for Rumor_Type {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
-    }
-}
-
 #[derive(PartialEq,Clone,Default)]
 pub struct Wire {
     // message fields
     encrypted: ::std::option::Option<bool>,
     nonce: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     payload: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    key_revision: ::protobuf::SingularField<::std::string::String>,
+    signature: ::protobuf::SingularPtrField<Signature>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -5457,10 +8201,100 @@ impl Wire {
     fn mut_payload_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
         &mut self.payload
     }
+
+    // optional string key_revision = 4;
+
+    pub fn clear_key_revision(&mut self) {
+        self.key_revision.clear();
+    }
+
+    pub fn has_key_revision(&self) -> bool {
+        self.key_revision.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_revision(&mut self, v: ::std::string::String) {
+        self.key_revision = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_revision(&mut self) -> &mut ::std::string::String {
+        if self.key_revision.is_none() {
+            self.key_revision.set_default();
+        }
+        self.key_revision.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key_revision(&mut self) -> ::std::string::String {
+        self.key_revision.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_key_revision(&self) -> &str {
+        match self.key_revision.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_key_revision_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.key_revision
+    }
+
+    fn mut_key_revision_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.key_revision
+    }
+
+    // optional .Signature signature = 5;
+
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    pub fn has_signature(&self) -> bool {
+        self.signature.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: Signature) {
+        self.signature = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signature(&mut self) -> &mut Signature {
+        if self.signature.is_none() {
+            self.signature.set_default();
+        }
+        self.signature.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> Signature {
+        self.signature.take().unwrap_or_else(|| Signature::new())
+    }
+
+    pub fn get_signature(&self) -> &Signature {
+        self.signature.as_ref().unwrap_or_else(|| Signature::default_instance())
+    }
+
+    fn get_signature_for_reflect(&self) -> &::protobuf::SingularPtrField<Signature> {
+        &self.signature
+    }
+
+    fn mut_signature_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Signature> {
+        &mut self.signature
+    }
 }
 
 impl ::protobuf::Message for Wire {
     fn is_initialized(&self) -> bool {
+        for v in &self.signature {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -5481,6 +8315,12 @@ impl ::protobuf::Message for Wire {
                 3 => {
                     ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.payload)?;
                 },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key_revision)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.signature)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -5502,6 +8342,13 @@ impl ::protobuf::Message for Wire {
         if let Some(ref v) = self.payload.as_ref() {
             my_size += ::protobuf::rt::bytes_size(3, &v);
         }
+        if let Some(ref v) = self.key_revision.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(ref v) = self.signature.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -5517,6 +8364,14 @@ impl ::protobuf::Message for Wire {
         if let Some(ref v) = self.payload.as_ref() {
             os.write_bytes(3, &v)?;
         }
+        if let Some(ref v) = self.key_revision.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        if let Some(ref v) = self.signature.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -5576,6 +8431,16 @@ impl ::protobuf::MessageStatic for Wire {
                     Wire::get_payload_for_reflect,
                     Wire::mut_payload_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "key_revision",
+                    Wire::get_key_revision_for_reflect,
+                    Wire::mut_key_revision_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Signature>>(
+                    "signature",
+                    Wire::get_signature_for_reflect,
+                    Wire::mut_signature_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Wire>(
                     "Wire",
                     fields,
@@ -5591,6 +8456,8 @@ impl ::protobuf::Clear for Wire {
         self.clear_encrypted();
         self.clear_nonce();
         self.clear_payload();
+        self.clear_key_revision();
+        self.clear_signature();
         self.unknown_fields.clear();
     }
 }
@@ -5608,55 +8475,80 @@ impl ::protobuf::reflect::ProtobufValue for Wire {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x14protocols/swim.proto\"\xdc\x01\n\x06Member\x12\x0e\n\x02id\x18\x01\
+    \n\x14protocols/swim.proto\"\xbc\x02\n\x06Member\x12\x0e\n\x02id\x18\x01\
     \x20\x01(\tR\x02id\x12\x20\n\x0bincarnation\x18\x02\x20\x01(\x04R\x0binc\
     arnation\x12\x18\n\x07address\x18\x03\x20\x01(\tR\x07address\x12\x1b\n\t\
     swim_port\x18\x04\x20\x01(\x05R\x08swimPort\x12\x1f\n\x0bgossip_port\x18\
     \x05\x20\x01(\x05R\ngossipPort\x12%\n\npersistent\x18\x06\x20\x01(\x08:\
     \x05falseR\npersistent\x12!\n\x08departed\x18\x07\x20\x01(\x08:\x05false\
-    R\x08departed\"K\n\x04Ping\x12\x1b\n\x04from\x18\x01\x20\x01(\x0b2\x07.M\
-    emberR\x04from\x12&\n\nforward_to\x18\x02\x20\x01(\x0b2\x07.MemberR\tfor\
-    wardTo\"J\n\x03Ack\x12\x1b\n\x04from\x18\x01\x20\x01(\x0b2\x07.MemberR\
-    \x04from\x12&\n\nforward_to\x18\x02\x20\x01(\x0b2\x07.MemberR\tforwardTo\
-    \"G\n\x07PingReq\x12\x1b\n\x04from\x18\x01\x20\x01(\x0b2\x07.MemberR\x04\
-    from\x12\x1f\n\x06target\x18\x02\x20\x01(\x0b2\x07.MemberR\x06target\"\
-    \x98\x01\n\nMembership\x12\x1f\n\x06member\x18\x01\x20\x01(\x0b2\x07.Mem\
-    berR\x06member\x12*\n\x06health\x18\x02\x20\x01(\x0e2\x12.Membership.Hea\
-    lthR\x06health\"=\n\x06Health\x12\t\n\x05ALIVE\x10\x01\x12\x0b\n\x07SUSP\
-    ECT\x10\x02\x12\r\n\tCONFIRMED\x10\x03\x12\x0c\n\x08DEPARTED\x10\x04\"\
-    \xf5\x01\n\x08Election\x12\x1b\n\tmember_id\x18\x01\x20\x01(\tR\x08membe\
-    rId\x12#\n\rservice_group\x18\x02\x20\x01(\tR\x0cserviceGroup\x12\x12\n\
-    \x04term\x18\x03\x20\x01(\x04R\x04term\x12\x20\n\x0bsuitability\x18\x04\
-    \x20\x01(\x04R\x0bsuitability\x12(\n\x06status\x18\x05\x20\x01(\x0e2\x10\
-    .Election.StatusR\x06status\x12\x14\n\x05votes\x18\x06\x20\x03(\tR\x05vo\
-    tes\"1\n\x06Status\x12\x0b\n\x07Running\x10\x01\x12\x0c\n\x08NoQuorum\
-    \x10\x02\x12\x0c\n\x08Finished\x10\x03\"\xcf\x01\n\x07Service\x12\x1b\n\
-    \tmember_id\x18\x01\x20\x01(\tR\x08memberId\x12#\n\rservice_group\x18\
-    \x02\x20\x01(\tR\x0cserviceGroup\x12\x20\n\x0bincarnation\x18\x03\x20\
-    \x01(\x04R\x0bincarnation\x12\x20\n\x0binitialized\x18\x08\x20\x01(\x08R\
-    \x0binitialized\x12\x10\n\x03pkg\x18\t\x20\x01(\tR\x03pkg\x12\x10\n\x03c\
-    fg\x18\n\x20\x01(\x0cR\x03cfg\x12\x1a\n\x03sys\x18\x0c\x20\x01(\x0b2\x08\
-    .SysInfoR\x03sys\"\x8c\x01\n\rServiceConfig\x12#\n\rservice_group\x18\
-    \x01\x20\x01(\tR\x0cserviceGroup\x12\x20\n\x0bincarnation\x18\x02\x20\
-    \x01(\x04R\x0bincarnation\x12\x1c\n\tencrypted\x18\x03\x20\x01(\x08R\ten\
-    crypted\x12\x16\n\x06config\x18\x04\x20\x01(\x0cR\x06config\"\xa2\x01\n\
-    \x0bServiceFile\x12#\n\rservice_group\x18\x01\x20\x01(\tR\x0cserviceGrou\
-    p\x12\x20\n\x0bincarnation\x18\x02\x20\x01(\x04R\x0bincarnation\x12\x1c\
-    \n\tencrypted\x18\x03\x20\x01(\x08R\tencrypted\x12\x1a\n\x08filename\x18\
-    \x04\x20\x01(\tR\x08filename\x12\x12\n\x04body\x18\x05\x20\x01(\x0cR\x04\
-    body\"\xf3\x01\n\x07SysInfo\x12\x19\n\x02ip\x18\x01\x20\x01(\t:\t127.0.0\
-    .1R\x02ip\x12%\n\x08hostname\x18\x02\x20\x01(\t:\tlocalhostR\x08hostname\
-    \x12&\n\tgossip_ip\x18\x03\x20\x01(\t:\t127.0.0.1R\x08gossipIp\x12\x1f\n\
-    \x0bgossip_port\x18\x04\x20\x01(\rR\ngossipPort\x121\n\x0fhttp_gateway_i\
-    p\x18\x05\x20\x01(\t:\t127.0.0.1R\rhttpGatewayIp\x12*\n\x11http_gateway_\
-    port\x18\x06\x20\x01(\rR\x0fhttpGatewayPort\"(\n\tDeparture\x12\x1b\n\tm\
-    ember_id\x18\x01\x20\x01(\tR\x08memberId\"\xe3\x01\n\x04Swim\x12\x1e\n\
+    R\x08departed\x12%\n\x04tags\x18\x08\x20\x03(\x0b2\x11.Member.TagsEntryR\
+    \x04tags\x1a7\n\tTagsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\
+    \x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01\"K\n\x04Ping\
+    \x12\x1b\n\x04from\x18\x01\x20\x01(\x0b2\x07.MemberR\x04from\x12&\n\nfor\
+    ward_to\x18\x02\x20\x01(\x0b2\x07.MemberR\tforwardTo\"J\n\x03Ack\x12\x1b\
+    \n\x04from\x18\x01\x20\x01(\x0b2\x07.MemberR\x04from\x12&\n\nforward_to\
+    \x18\x02\x20\x01(\x0b2\x07.MemberR\tforwardTo\"G\n\x07PingReq\x12\x1b\n\
+    \x04from\x18\x01\x20\x01(\x0b2\x07.MemberR\x04from\x12\x1f\n\x06target\
+    \x18\x02\x20\x01(\x0b2\x07.MemberR\x06target\"\x98\x01\n\nMembership\x12\
+    \x1f\n\x06member\x18\x01\x20\x01(\x0b2\x07.MemberR\x06member\x12*\n\x06h\
+    ealth\x18\x02\x20\x01(\x0e2\x12.Membership.HealthR\x06health\"=\n\x06Hea\
+    lth\x12\t\n\x05ALIVE\x10\x01\x12\x0b\n\x07SUSPECT\x10\x02\x12\r\n\tCONFI\
+    RMED\x10\x03\x12\x0c\n\x08DEPARTED\x10\x04\"\xf5\x01\n\x08Election\x12\
+    \x1b\n\tmember_id\x18\x01\x20\x01(\tR\x08memberId\x12#\n\rservice_group\
+    \x18\x02\x20\x01(\tR\x0cserviceGroup\x12\x12\n\x04term\x18\x03\x20\x01(\
+    \x04R\x04term\x12\x20\n\x0bsuitability\x18\x04\x20\x01(\x04R\x0bsuitabil\
+    ity\x12(\n\x06status\x18\x05\x20\x01(\x0e2\x10.Election.StatusR\x06statu\
+    s\x12\x14\n\x05votes\x18\x06\x20\x03(\tR\x05votes\"1\n\x06Status\x12\x0b\
+    \n\x07Running\x10\x01\x12\x0c\n\x08NoQuorum\x10\x02\x12\x0c\n\x08Finishe\
+    d\x10\x03\"\x81\x03\n\x07Service\x12\x1b\n\tmember_id\x18\x01\x20\x01(\t\
+    R\x08memberId\x12#\n\rservice_group\x18\x02\x20\x01(\tR\x0cserviceGroup\
+    \x12\x20\n\x0bincarnation\x18\x03\x20\x01(\x04R\x0bincarnation\x12\x20\n\
+    \x0binitialized\x18\x08\x20\x01(\x08R\x0binitialized\x12\x10\n\x03pkg\
+    \x18\t\x20\x01(\tR\x03pkg\x12\x10\n\x03cfg\x18\n\x20\x01(\x0cR\x03cfg\
+    \x12\x1a\n\x03sys\x18\x0c\x20\x01(\x0b2\x08.SysInfoR\x03sys\x120\n\x06he\
+    alth\x18\r\x20\x01(\x0e2\x0f.Service.Health:\x07UNKNOWNR\x06health\x12(\
+    \n\x10rolled_back_from\x18\x0e\x20\x01(\tR\x0erolledBackFrom\x12\x1a\n\
+    \x08unloaded\x18\x0f\x20\x01(\x08R\x08unloaded\"8\n\x06Health\x12\x06\n\
+    \x02OK\x10\0\x12\x0b\n\x07WARNING\x10\x01\x12\x0c\n\x08CRITICAL\x10\x02\
+    \x12\x0b\n\x07UNKNOWN\x10\x03\"\x8c\x01\n\rServiceConfig\x12#\n\rservice\
+    _group\x18\x01\x20\x01(\tR\x0cserviceGroup\x12\x20\n\x0bincarnation\x18\
+    \x02\x20\x01(\x04R\x0bincarnation\x12\x1c\n\tencrypted\x18\x03\x20\x01(\
+    \x08R\tencrypted\x12\x16\n\x06config\x18\x04\x20\x01(\x0cR\x06config\"\
+    \xa2\x01\n\x0bServiceFile\x12#\n\rservice_group\x18\x01\x20\x01(\tR\x0cs\
+    erviceGroup\x12\x20\n\x0bincarnation\x18\x02\x20\x01(\x04R\x0bincarnatio\
+    n\x12\x1c\n\tencrypted\x18\x03\x20\x01(\x08R\tencrypted\x12\x1a\n\x08fil\
+    ename\x18\x04\x20\x01(\tR\x08filename\x12\x12\n\x04body\x18\x05\x20\x01(\
+    \x0cR\x04body\"\x87\x02\n\x07SysInfo\x12\x19\n\x02ip\x18\x01\x20\x01(\t:\
+    \t127.0.0.1R\x02ip\x12%\n\x08hostname\x18\x02\x20\x01(\t:\tlocalhostR\
+    \x08hostname\x12&\n\tgossip_ip\x18\x03\x20\x01(\t:\t127.0.0.1R\x08gossip\
+    Ip\x12\x1f\n\x0bgossip_port\x18\x04\x20\x01(\rR\ngossipPort\x121\n\x0fht\
+    tp_gateway_ip\x18\x05\x20\x01(\t:\t127.0.0.1R\rhttpGatewayIp\x12*\n\x11h\
+    ttp_gateway_port\x18\x06\x20\x01(\rR\x0fhttpGatewayPort\x12\x12\n\x04rin\
+    g\x18\x07\x20\x01(\tR\x04ring\"(\n\tDeparture\x12\x1b\n\tmember_id\x18\
+    \x01\x20\x01(\tR\x08memberId\">\n\tShardSlot\x12\x1b\n\tmember_id\x18\
+    \x01\x20\x01(\tR\x08memberId\x12\x14\n\x05shard\x18\x02\x20\x01(\rR\x05s\
+    hard\"\xdb\x01\n\x08ShardMap\x12\x1b\n\tmember_id\x18\x01\x20\x01(\tR\
+    \x08memberId\x12#\n\rservice_group\x18\x02\x20\x01(\tR\x0cserviceGroup\
+    \x12\x20\n\x0bincarnation\x18\x03\x20\x01(\x04R\x0bincarnation\x12\x1f\n\
+    \x0bshard_count\x18\x04\x20\x01(\rR\nshardCount\x12(\n\x0cmulti_leader\
+    \x18\x05\x20\x01(\x08:\x05falseR\x0bmultiLeader\x12\x20\n\x05slots\x18\
+    \x06\x20\x03(\x0b2\n.ShardSlotR\x05slots\"x\n\x07RingKey\x12\x1b\n\tmemb\
+    er_id\x18\x01\x20\x01(\tR\x08memberId\x12\x12\n\x04name\x18\x02\x20\x01(\
+    \tR\x04name\x12\x1a\n\x08revision\x18\x03\x20\x01(\tR\x08revision\x12\
+    \x20\n\x0bincarnation\x18\x04\x20\x01(\x04R\x0bincarnation\"\x96\x01\n\
+    \x08KeyValue\x12\x1b\n\tmember_id\x18\x01\x20\x01(\tR\x08memberId\x12#\n\
+    \rservice_group\x18\x02\x20\x01(\tR\x0cserviceGroup\x12\x10\n\x03key\x18\
+    \x03\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x04\x20\x01(\tR\x05value\
+    \x12\x20\n\x0bincarnation\x18\x05\x20\x01(\x04R\x0bincarnation\"q\n\x0fF\
+    ederatedMember\x12\x12\n\x04ring\x18\x01\x20\x01(\tR\x04ring\x12+\n\nmem\
+    bership\x18\x02\x20\x01(\x0b2\x0b.MembershipR\nmembership\x12\x1d\n\nrel\
+    ayed_at\x18\x03\x20\x01(\x04R\trelayedAt\"\xe3\x01\n\x04Swim\x12\x1e\n\
     \x04type\x18\x01\x20\x02(\x0e2\n.Swim.TypeR\x04type\x12\x1b\n\x04ping\
     \x18\x02\x20\x01(\x0b2\x05.PingH\0R\x04ping\x12\x18\n\x03ack\x18\x03\x20\
     \x01(\x0b2\x04.AckH\0R\x03ack\x12$\n\x07pingreq\x18\x04\x20\x01(\x0b2\
     \x08.PingReqH\0R\x07pingreq\x12+\n\nmembership\x18\x05\x20\x03(\x0b2\x0b\
     .MembershipR\nmembership\"&\n\x04Type\x12\x08\n\x04PING\x10\x01\x12\x07\
-    \n\x03ACK\x10\x02\x12\x0b\n\x07PINGREQ\x10\x03B\t\n\x07payload\"\xf8\x03\
+    \n\x03ACK\x10\x02\x12\x0b\n\x07PINGREQ\x10\x03B\t\n\x07payload\"\x94\x06\
     \n\x05Rumor\x12\x1f\n\x04type\x18\x01\x20\x02(\x0e2\x0b.Rumor.TypeR\x04t\
     ype\x12\x10\n\x03tag\x18\x02\x20\x03(\tR\x03tag\x12\x17\n\x07from_id\x18\
     \x03\x20\x01(\tR\x06fromId\x12%\n\x06member\x18\x04\x20\x01(\x0b2\x0b.Me\
@@ -5665,16 +8557,27 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     viceConfigH\0R\rserviceConfig\x121\n\x0cservice_file\x18\x07\x20\x01(\
     \x0b2\x0c.ServiceFileH\0R\x0bserviceFile\x12'\n\x08election\x18\x08\x20\
     \x01(\x0b2\t.ElectionH\0R\x08election\x12*\n\tdeparture\x18\t\x20\x01(\
-    \x0b2\n.DepartureH\0R\tdeparture\"\x89\x01\n\x04Type\x12\n\n\x06Member\
-    \x10\x01\x12\x0b\n\x07Service\x10\x02\x12\x0c\n\x08Election\x10\x03\x12\
-    \x11\n\rServiceConfig\x10\x04\x12\x0f\n\x0bServiceFile\x10\x05\x12\x08\n\
-    \x04Fake\x10\x06\x12\t\n\x05Fake2\x10\x07\x12\x12\n\x0eElectionUpdate\
-    \x10\x08\x12\r\n\tDeparture\x10\tB\t\n\x07payload\"T\n\x04Wire\x12\x1c\n\
-    \tencrypted\x18\x01\x20\x01(\x08R\tencrypted\x12\x14\n\x05nonce\x18\x02\
-    \x20\x01(\x0cR\x05nonce\x12\x18\n\x07payload\x18\x03\x20\x01(\x0cR\x07pa\
-    yloadJ\xc7,\n\x06\x12\x04\0\0~\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\
-    \x02\x04\0\x12\x04\x02\0\n\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x0e\n\
-    \x0b\n\x04\x04\0\x02\0\x12\x03\x03\x02\x19\n\x0c\n\x05\x04\0\x02\0\x04\
+    \x0b2\n.DepartureH\0R\tdeparture\x12(\n\tshard_map\x18\n\x20\x01(\x0b2\t\
+    .ShardMapH\0R\x08shardMap\x12%\n\x08ring_key\x18\x0b\x20\x01(\x0b2\x08.R\
+    ingKeyH\0R\x07ringKey\x12(\n\tkey_value\x18\x0c\x20\x01(\x0b2\t.KeyValue\
+    H\0R\x08keyValue\x12=\n\x10federated_member\x18\r\x20\x01(\x0b2\x10.Fede\
+    ratedMemberH\0R\x0ffederatedMember\x12\"\n\x06origin\x18\x0e\x20\x01(\
+    \x0b2\n.SignatureR\x06origin\"\xc7\x01\n\x04Type\x12\n\n\x06Member\x10\
+    \x01\x12\x0b\n\x07Service\x10\x02\x12\x0c\n\x08Election\x10\x03\x12\x11\
+    \n\rServiceConfig\x10\x04\x12\x0f\n\x0bServiceFile\x10\x05\x12\x08\n\x04\
+    Fake\x10\x06\x12\t\n\x05Fake2\x10\x07\x12\x12\n\x0eElectionUpdate\x10\
+    \x08\x12\r\n\tDeparture\x10\t\x12\x0c\n\x08ShardMap\x10\n\x12\x0b\n\x07R\
+    ingKey\x10\x0b\x12\x0c\n\x08KeyValue\x10\x0c\x12\x13\n\x0fFederatedMembe\
+    r\x10\rB\t\n\x07payload\"`\n\tSignature\x12\x16\n\x06signer\x18\x01\x20\
+    \x01(\tR\x06signer\x12\x1d\n\npublic_key\x18\x02\x20\x01(\x0cR\tpublicKe\
+    y\x12\x1c\n\tsignature\x18\x03\x20\x01(\x0cR\tsignature\"\xa1\x01\n\x04W\
+    ire\x12\x1c\n\tencrypted\x18\x01\x20\x01(\x08R\tencrypted\x12\x14\n\x05n\
+    once\x18\x02\x20\x01(\x0cR\x05nonce\x12\x18\n\x07payload\x18\x03\x20\x01\
+    (\x0cR\x07payload\x12!\n\x0ckey_revision\x18\x04\x20\x01(\tR\x0bkeyRevis\
+    ion\x12(\n\tsignature\x18\x05\x20\x01(\x0b2\n.SignatureR\tsignatureJ\xd4\
+    F\n\x07\x12\x05\0\0\xbc\x01\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\
+    \x02\x04\0\x12\x04\x02\0\x0b\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x0e\
+    \n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x02\x19\n\x0c\n\x05\x04\0\x02\0\x04\
     \x12\x03\x03\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x0b\x11\n\x0c\
     \n\x05\x04\0\x02\0\x01\x12\x03\x03\x12\x14\n\x0c\n\x05\x04\0\x02\0\x03\
     \x12\x03\x03\x17\x18\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x02\"\n\x0c\n\
@@ -5700,248 +8603,408 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x04\0\x02\x06\x05\x12\x03\t\x0b\x0f\n\x0c\n\x05\x04\0\x02\x06\x01\x12\
     \x03\t\x10\x18\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03\t\x1b\x1c\n\x0c\n\
     \x05\x04\0\x02\x06\x08\x12\x03\t\x1d.\n\x0c\n\x05\x04\0\x02\x06\x07\x12\
-    \x03\t(-\n\n\n\x02\x04\x01\x12\x04\x0c\0\x0f\x01\n\n\n\x03\x04\x01\x01\
-    \x12\x03\x0c\x08\x0c\n\x0b\n\x04\x04\x01\x02\0\x12\x03\r\x02\x1b\n\x0c\n\
-    \x05\x04\x01\x02\0\x04\x12\x03\r\x02\n\n\x0c\n\x05\x04\x01\x02\0\x06\x12\
-    \x03\r\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\r\x12\x16\n\x0c\n\
-    \x05\x04\x01\x02\0\x03\x12\x03\r\x19\x1a\n\x0b\n\x04\x04\x01\x02\x01\x12\
-    \x03\x0e\x02!\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03\x0e\x02\n\n\x0c\n\
-    \x05\x04\x01\x02\x01\x06\x12\x03\x0e\x0b\x11\n\x0c\n\x05\x04\x01\x02\x01\
-    \x01\x12\x03\x0e\x12\x1c\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0e\x1f\
-    \x20\n\n\n\x02\x04\x02\x12\x04\x11\0\x14\x01\n\n\n\x03\x04\x02\x01\x12\
-    \x03\x11\x08\x0b\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x12\x02\x1b\n\x0c\n\
-    \x05\x04\x02\x02\0\x04\x12\x03\x12\x02\n\n\x0c\n\x05\x04\x02\x02\0\x06\
-    \x12\x03\x12\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x12\x12\x16\n\
-    \x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x12\x19\x1a\n\x0b\n\x04\x04\x02\x02\
-    \x01\x12\x03\x13\x02!\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\x03\x13\x02\n\
-    \n\x0c\n\x05\x04\x02\x02\x01\x06\x12\x03\x13\x0b\x11\n\x0c\n\x05\x04\x02\
-    \x02\x01\x01\x12\x03\x13\x12\x1c\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\
-    \x13\x1f\x20\n\n\n\x02\x04\x03\x12\x04\x16\0\x19\x01\n\n\n\x03\x04\x03\
-    \x01\x12\x03\x16\x08\x0f\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x17\x02\x1b\n\
-    \x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x17\x02\n\n\x0c\n\x05\x04\x03\x02\0\
-    \x06\x12\x03\x17\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x17\x12\
-    \x16\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x17\x19\x1a\n\x0b\n\x04\x04\
-    \x03\x02\x01\x12\x03\x18\x02\x1d\n\x0c\n\x05\x04\x03\x02\x01\x04\x12\x03\
-    \x18\x02\n\n\x0c\n\x05\x04\x03\x02\x01\x06\x12\x03\x18\x0b\x11\n\x0c\n\
-    \x05\x04\x03\x02\x01\x01\x12\x03\x18\x12\x18\n\x0c\n\x05\x04\x03\x02\x01\
-    \x03\x12\x03\x18\x1b\x1c\n\n\n\x02\x04\x04\x12\x04\x1b\0\x20\x01\n\n\n\
-    \x03\x04\x04\x01\x12\x03\x1b\x08\x12\n\x0b\n\x04\x04\x04\x04\0\x12\x03\
-    \x1c\x02F\n\x0c\n\x05\x04\x04\x04\0\x01\x12\x03\x1c\x07\r\n\r\n\x06\x04\
-    \x04\x04\0\x02\0\x12\x03\x1c\x10\x1a\n\x0e\n\x07\x04\x04\x04\0\x02\0\x01\
-    \x12\x03\x1c\x10\x15\n\x0e\n\x07\x04\x04\x04\0\x02\0\x02\x12\x03\x1c\x18\
-    \x19\n\r\n\x06\x04\x04\x04\0\x02\x01\x12\x03\x1c\x1b'\n\x0e\n\x07\x04\
-    \x04\x04\0\x02\x01\x01\x12\x03\x1c\x1b\"\n\x0e\n\x07\x04\x04\x04\0\x02\
-    \x01\x02\x12\x03\x1c%&\n\r\n\x06\x04\x04\x04\0\x02\x02\x12\x03\x1c(6\n\
-    \x0e\n\x07\x04\x04\x04\0\x02\x02\x01\x12\x03\x1c(1\n\x0e\n\x07\x04\x04\
-    \x04\0\x02\x02\x02\x12\x03\x1c45\n\r\n\x06\x04\x04\x04\0\x02\x03\x12\x03\
-    \x1c7D\n\x0e\n\x07\x04\x04\x04\0\x02\x03\x01\x12\x03\x1c7?\n\x0e\n\x07\
-    \x04\x04\x04\0\x02\x03\x02\x12\x03\x1cBC\n\x0b\n\x04\x04\x04\x02\0\x12\
-    \x03\x1e\x02\x1d\n\x0c\n\x05\x04\x04\x02\0\x04\x12\x03\x1e\x02\n\n\x0c\n\
-    \x05\x04\x04\x02\0\x06\x12\x03\x1e\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\
-    \x12\x03\x1e\x12\x18\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1e\x1b\x1c\n\
-    \x0b\n\x04\x04\x04\x02\x01\x12\x03\x1f\x02\x1d\n\x0c\n\x05\x04\x04\x02\
-    \x01\x04\x12\x03\x1f\x02\n\n\x0c\n\x05\x04\x04\x02\x01\x06\x12\x03\x1f\
-    \x0b\x11\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x1f\x12\x18\n\x0c\n\x05\
-    \x04\x04\x02\x01\x03\x12\x03\x1f\x1b\x1c\n\n\n\x02\x04\x05\x12\x04\"\0+\
-    \x01\n\n\n\x03\x04\x05\x01\x12\x03\"\x08\x10\n\x0b\n\x04\x04\x05\x04\0\
-    \x12\x03#\x02:\n\x0c\n\x05\x04\x05\x04\0\x01\x12\x03#\x07\r\n\r\n\x06\
-    \x04\x05\x04\0\x02\0\x12\x03#\x10\x1c\n\x0e\n\x07\x04\x05\x04\0\x02\0\
-    \x01\x12\x03#\x10\x17\n\x0e\n\x07\x04\x05\x04\0\x02\0\x02\x12\x03#\x1a\
-    \x1b\n\r\n\x06\x04\x05\x04\0\x02\x01\x12\x03#\x1d*\n\x0e\n\x07\x04\x05\
-    \x04\0\x02\x01\x01\x12\x03#\x1d%\n\x0e\n\x07\x04\x05\x04\0\x02\x01\x02\
-    \x12\x03#()\n\r\n\x06\x04\x05\x04\0\x02\x02\x12\x03#+8\n\x0e\n\x07\x04\
-    \x05\x04\0\x02\x02\x01\x12\x03#+3\n\x0e\n\x07\x04\x05\x04\0\x02\x02\x02\
-    \x12\x03#67\n\x0b\n\x04\x04\x05\x02\0\x12\x03%\x02\x20\n\x0c\n\x05\x04\
-    \x05\x02\0\x04\x12\x03%\x02\n\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03%\x0b\
-    \x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03%\x12\x1b\n\x0c\n\x05\x04\x05\
-    \x02\0\x03\x12\x03%\x1e\x1f\n\x0b\n\x04\x04\x05\x02\x01\x12\x03&\x02$\n\
-    \x0c\n\x05\x04\x05\x02\x01\x04\x12\x03&\x02\n\n\x0c\n\x05\x04\x05\x02\
-    \x01\x05\x12\x03&\x0b\x11\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03&\x12\
-    \x1f\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03&\"#\n\x0b\n\x04\x04\x05\x02\
-    \x02\x12\x03'\x02\x1b\n\x0c\n\x05\x04\x05\x02\x02\x04\x12\x03'\x02\n\n\
-    \x0c\n\x05\x04\x05\x02\x02\x05\x12\x03'\x0b\x11\n\x0c\n\x05\x04\x05\x02\
-    \x02\x01\x12\x03'\x12\x16\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x03'\x19\
-    \x1a\n\x0b\n\x04\x04\x05\x02\x03\x12\x03(\x02\"\n\x0c\n\x05\x04\x05\x02\
-    \x03\x04\x12\x03(\x02\n\n\x0c\n\x05\x04\x05\x02\x03\x05\x12\x03(\x0b\x11\
-    \n\x0c\n\x05\x04\x05\x02\x03\x01\x12\x03(\x12\x1d\n\x0c\n\x05\x04\x05\
-    \x02\x03\x03\x12\x03(\x20!\n\x0b\n\x04\x04\x05\x02\x04\x12\x03)\x02\x1d\
-    \n\x0c\n\x05\x04\x05\x02\x04\x04\x12\x03)\x02\n\n\x0c\n\x05\x04\x05\x02\
-    \x04\x06\x12\x03)\x0b\x11\n\x0c\n\x05\x04\x05\x02\x04\x01\x12\x03)\x12\
-    \x18\n\x0c\n\x05\x04\x05\x02\x04\x03\x12\x03)\x1b\x1c\n\x0b\n\x04\x04\
-    \x05\x02\x05\x12\x03*\x02\x1c\n\x0c\n\x05\x04\x05\x02\x05\x04\x12\x03*\
-    \x02\n\n\x0c\n\x05\x04\x05\x02\x05\x05\x12\x03*\x0b\x11\n\x0c\n\x05\x04\
-    \x05\x02\x05\x01\x12\x03*\x12\x17\n\x0c\n\x05\x04\x05\x02\x05\x03\x12\
-    \x03*\x1a\x1b\n\n\n\x02\x04\x06\x12\x04-\05\x01\n\n\n\x03\x04\x06\x01\
-    \x12\x03-\x08\x0f\n\x0b\n\x04\x04\x06\x02\0\x12\x03.\x02\x20\n\x0c\n\x05\
-    \x04\x06\x02\0\x04\x12\x03.\x02\n\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03.\
-    \x0b\x11\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03.\x12\x1b\n\x0c\n\x05\x04\
-    \x06\x02\0\x03\x12\x03.\x1e\x1f\n\x0b\n\x04\x04\x06\x02\x01\x12\x03/\x02\
-    $\n\x0c\n\x05\x04\x06\x02\x01\x04\x12\x03/\x02\n\n\x0c\n\x05\x04\x06\x02\
-    \x01\x05\x12\x03/\x0b\x11\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03/\x12\
-    \x1f\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03/\"#\n\x0b\n\x04\x04\x06\x02\
-    \x02\x12\x030\x02\"\n\x0c\n\x05\x04\x06\x02\x02\x04\x12\x030\x02\n\n\x0c\
-    \n\x05\x04\x06\x02\x02\x05\x12\x030\x0b\x11\n\x0c\n\x05\x04\x06\x02\x02\
-    \x01\x12\x030\x12\x1d\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x030\x20!\n\
-    \x0b\n\x04\x04\x06\x02\x03\x12\x031\x02\x20\n\x0c\n\x05\x04\x06\x02\x03\
-    \x04\x12\x031\x02\n\n\x0c\n\x05\x04\x06\x02\x03\x05\x12\x031\x0b\x0f\n\
-    \x0c\n\x05\x04\x06\x02\x03\x01\x12\x031\x10\x1b\n\x0c\n\x05\x04\x06\x02\
-    \x03\x03\x12\x031\x1e\x1f\n\x0b\n\x04\x04\x06\x02\x04\x12\x032\x02\x1a\n\
-    \x0c\n\x05\x04\x06\x02\x04\x04\x12\x032\x02\n\n\x0c\n\x05\x04\x06\x02\
-    \x04\x05\x12\x032\x0b\x11\n\x0c\n\x05\x04\x06\x02\x04\x01\x12\x032\x12\
-    \x15\n\x0c\n\x05\x04\x06\x02\x04\x03\x12\x032\x18\x19\n\x0b\n\x04\x04\
-    \x06\x02\x05\x12\x033\x02\x1a\n\x0c\n\x05\x04\x06\x02\x05\x04\x12\x033\
-    \x02\n\n\x0c\n\x05\x04\x06\x02\x05\x05\x12\x033\x0b\x10\n\x0c\n\x05\x04\
-    \x06\x02\x05\x01\x12\x033\x11\x14\n\x0c\n\x05\x04\x06\x02\x05\x03\x12\
-    \x033\x17\x19\n\x0b\n\x04\x04\x06\x02\x06\x12\x034\x02\x1c\n\x0c\n\x05\
-    \x04\x06\x02\x06\x04\x12\x034\x02\n\n\x0c\n\x05\x04\x06\x02\x06\x06\x12\
-    \x034\x0b\x12\n\x0c\n\x05\x04\x06\x02\x06\x01\x12\x034\x13\x16\n\x0c\n\
-    \x05\x04\x06\x02\x06\x03\x12\x034\x19\x1b\n\n\n\x02\x04\x07\x12\x047\0<\
-    \x01\n\n\n\x03\x04\x07\x01\x12\x037\x08\x15\n\x0b\n\x04\x04\x07\x02\0\
-    \x12\x038\x02$\n\x0c\n\x05\x04\x07\x02\0\x04\x12\x038\x02\n\n\x0c\n\x05\
-    \x04\x07\x02\0\x05\x12\x038\x0b\x11\n\x0c\n\x05\x04\x07\x02\0\x01\x12\
-    \x038\x12\x1f\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x038\"#\n\x0b\n\x04\x04\
-    \x07\x02\x01\x12\x039\x02\"\n\x0c\n\x05\x04\x07\x02\x01\x04\x12\x039\x02\
-    \n\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x039\x0b\x11\n\x0c\n\x05\x04\x07\
-    \x02\x01\x01\x12\x039\x12\x1d\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x039\
-    \x20!\n\x0b\n\x04\x04\x07\x02\x02\x12\x03:\x02\x1e\n\x0c\n\x05\x04\x07\
-    \x02\x02\x04\x12\x03:\x02\n\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\x03:\x0b\
-    \x0f\n\x0c\n\x05\x04\x07\x02\x02\x01\x12\x03:\x10\x19\n\x0c\n\x05\x04\
-    \x07\x02\x02\x03\x12\x03:\x1c\x1d\n\x0b\n\x04\x04\x07\x02\x03\x12\x03;\
-    \x02\x1c\n\x0c\n\x05\x04\x07\x02\x03\x04\x12\x03;\x02\n\n\x0c\n\x05\x04\
-    \x07\x02\x03\x05\x12\x03;\x0b\x10\n\x0c\n\x05\x04\x07\x02\x03\x01\x12\
-    \x03;\x11\x17\n\x0c\n\x05\x04\x07\x02\x03\x03\x12\x03;\x1a\x1b\n\n\n\x02\
-    \x04\x08\x12\x04>\0D\x01\n\n\n\x03\x04\x08\x01\x12\x03>\x08\x13\n\x0b\n\
-    \x04\x04\x08\x02\0\x12\x03?\x02$\n\x0c\n\x05\x04\x08\x02\0\x04\x12\x03?\
-    \x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03?\x0b\x11\n\x0c\n\x05\x04\
-    \x08\x02\0\x01\x12\x03?\x12\x1f\n\x0c\n\x05\x04\x08\x02\0\x03\x12\x03?\"\
-    #\n\x0b\n\x04\x04\x08\x02\x01\x12\x03@\x02\"\n\x0c\n\x05\x04\x08\x02\x01\
-    \x04\x12\x03@\x02\n\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x03@\x0b\x11\n\
-    \x0c\n\x05\x04\x08\x02\x01\x01\x12\x03@\x12\x1d\n\x0c\n\x05\x04\x08\x02\
-    \x01\x03\x12\x03@\x20!\n\x0b\n\x04\x04\x08\x02\x02\x12\x03A\x02\x1e\n\
-    \x0c\n\x05\x04\x08\x02\x02\x04\x12\x03A\x02\n\n\x0c\n\x05\x04\x08\x02\
-    \x02\x05\x12\x03A\x0b\x0f\n\x0c\n\x05\x04\x08\x02\x02\x01\x12\x03A\x10\
-    \x19\n\x0c\n\x05\x04\x08\x02\x02\x03\x12\x03A\x1c\x1d\n\x0b\n\x04\x04\
-    \x08\x02\x03\x12\x03B\x02\x1f\n\x0c\n\x05\x04\x08\x02\x03\x04\x12\x03B\
-    \x02\n\n\x0c\n\x05\x04\x08\x02\x03\x05\x12\x03B\x0b\x11\n\x0c\n\x05\x04\
-    \x08\x02\x03\x01\x12\x03B\x12\x1a\n\x0c\n\x05\x04\x08\x02\x03\x03\x12\
-    \x03B\x1d\x1e\n\x0b\n\x04\x04\x08\x02\x04\x12\x03C\x02\x1a\n\x0c\n\x05\
-    \x04\x08\x02\x04\x04\x12\x03C\x02\n\n\x0c\n\x05\x04\x08\x02\x04\x05\x12\
-    \x03C\x0b\x10\n\x0c\n\x05\x04\x08\x02\x04\x01\x12\x03C\x11\x15\n\x0c\n\
-    \x05\x04\x08\x02\x04\x03\x12\x03C\x18\x19\n\n\n\x02\x04\t\x12\x04F\0M\
-    \x01\n\n\n\x03\x04\t\x01\x12\x03F\x08\x0f\n\x0b\n\x04\x04\t\x02\0\x12\
-    \x03G\x021\n\x0c\n\x05\x04\t\x02\0\x04\x12\x03G\x02\n\n\x0c\n\x05\x04\t\
-    \x02\0\x05\x12\x03G\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03G\x12\x14\
-    \n\x0c\n\x05\x04\t\x02\0\x03\x12\x03G\x17\x18\n\x0c\n\x05\x04\t\x02\0\
-    \x08\x12\x03G\x190\n\x0c\n\x05\x04\t\x02\0\x07\x12\x03G$/\n\x0b\n\x04\
-    \x04\t\x02\x01\x12\x03H\x027\n\x0c\n\x05\x04\t\x02\x01\x04\x12\x03H\x02\
-    \n\n\x0c\n\x05\x04\t\x02\x01\x05\x12\x03H\x0b\x11\n\x0c\n\x05\x04\t\x02\
-    \x01\x01\x12\x03H\x12\x1a\n\x0c\n\x05\x04\t\x02\x01\x03\x12\x03H\x1d\x1e\
-    \n\x0c\n\x05\x04\t\x02\x01\x08\x12\x03H\x1f6\n\x0c\n\x05\x04\t\x02\x01\
-    \x07\x12\x03H*5\n\x0b\n\x04\x04\t\x02\x02\x12\x03I\x028\n\x0c\n\x05\x04\
-    \t\x02\x02\x04\x12\x03I\x02\n\n\x0c\n\x05\x04\t\x02\x02\x05\x12\x03I\x0b\
-    \x11\n\x0c\n\x05\x04\t\x02\x02\x01\x12\x03I\x12\x1b\n\x0c\n\x05\x04\t\
-    \x02\x02\x03\x12\x03I\x1e\x1f\n\x0c\n\x05\x04\t\x02\x02\x08\x12\x03I\x20\
-    7\n\x0c\n\x05\x04\t\x02\x02\x07\x12\x03I+6\n\x0b\n\x04\x04\t\x02\x03\x12\
-    \x03J\x02\"\n\x0c\n\x05\x04\t\x02\x03\x04\x12\x03J\x02\n\n\x0c\n\x05\x04\
-    \t\x02\x03\x05\x12\x03J\x0b\x11\n\x0c\n\x05\x04\t\x02\x03\x01\x12\x03J\
-    \x12\x1d\n\x0c\n\x05\x04\t\x02\x03\x03\x12\x03J\x20!\n\x0b\n\x04\x04\t\
-    \x02\x04\x12\x03K\x02>\n\x0c\n\x05\x04\t\x02\x04\x04\x12\x03K\x02\n\n\
-    \x0c\n\x05\x04\t\x02\x04\x05\x12\x03K\x0b\x11\n\x0c\n\x05\x04\t\x02\x04\
-    \x01\x12\x03K\x12!\n\x0c\n\x05\x04\t\x02\x04\x03\x12\x03K$%\n\x0c\n\x05\
-    \x04\t\x02\x04\x08\x12\x03K&=\n\x0c\n\x05\x04\t\x02\x04\x07\x12\x03K1<\n\
-    \x0b\n\x04\x04\t\x02\x05\x12\x03L\x02(\n\x0c\n\x05\x04\t\x02\x05\x04\x12\
-    \x03L\x02\n\n\x0c\n\x05\x04\t\x02\x05\x05\x12\x03L\x0b\x11\n\x0c\n\x05\
-    \x04\t\x02\x05\x01\x12\x03L\x12#\n\x0c\n\x05\x04\t\x02\x05\x03\x12\x03L&\
-    '\n\n\n\x02\x04\n\x12\x04O\0Q\x01\n\n\n\x03\x04\n\x01\x12\x03O\x08\x11\n\
-    \x0b\n\x04\x04\n\x02\0\x12\x03P\x02\x20\n\x0c\n\x05\x04\n\x02\0\x04\x12\
-    \x03P\x02\n\n\x0c\n\x05\x04\n\x02\0\x05\x12\x03P\x0b\x11\n\x0c\n\x05\x04\
-    \n\x02\0\x01\x12\x03P\x12\x1b\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03P\x1e\
-    \x1f\n\n\n\x02\x04\x0b\x12\x04S\0^\x01\n\n\n\x03\x04\x0b\x01\x12\x03S\
-    \x08\x0c\n\x0b\n\x04\x04\x0b\x04\0\x12\x03T\x02/\n\x0c\n\x05\x04\x0b\x04\
-    \0\x01\x12\x03T\x07\x0b\n\r\n\x06\x04\x0b\x04\0\x02\0\x12\x03T\x0e\x17\n\
-    \x0e\n\x07\x04\x0b\x04\0\x02\0\x01\x12\x03T\x0e\x12\n\x0e\n\x07\x04\x0b\
-    \x04\0\x02\0\x02\x12\x03T\x15\x16\n\r\n\x06\x04\x0b\x04\0\x02\x01\x12\
-    \x03T\x18\x20\n\x0e\n\x07\x04\x0b\x04\0\x02\x01\x01\x12\x03T\x18\x1b\n\
-    \x0e\n\x07\x04\x0b\x04\0\x02\x01\x02\x12\x03T\x1e\x1f\n\r\n\x06\x04\x0b\
-    \x04\0\x02\x02\x12\x03T!-\n\x0e\n\x07\x04\x0b\x04\0\x02\x02\x01\x12\x03T\
-    !(\n\x0e\n\x07\x04\x0b\x04\0\x02\x02\x02\x12\x03T+,\n3\n\x04\x04\x0b\x02\
-    \0\x12\x03W\x02\x19\x1a&\x20Identifies\x20which\x20field\x20is\x20filled\
-    \x20in.\n\n\x0c\n\x05\x04\x0b\x02\0\x04\x12\x03W\x02\n\n\x0c\n\x05\x04\
-    \x0b\x02\0\x06\x12\x03W\x0b\x0f\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03W\
-    \x10\x14\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03W\x17\x18\n\x0c\n\x04\x04\
-    \x0b\x08\0\x12\x04X\x02\\\x03\n\x0c\n\x05\x04\x0b\x08\0\x01\x12\x03X\x08\
-    \x0f\n\x0b\n\x04\x04\x0b\x02\x01\x12\x03Y\x04\x12\n\x0c\n\x05\x04\x0b\
-    \x02\x01\x06\x12\x03Y\x04\x08\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03Y\t\
-    \r\n\x0c\n\x05\x04\x0b\x02\x01\x03\x12\x03Y\x10\x11\n\x0b\n\x04\x04\x0b\
-    \x02\x02\x12\x03Z\x04\x10\n\x0c\n\x05\x04\x0b\x02\x02\x06\x12\x03Z\x04\
-    \x07\n\x0c\n\x05\x04\x0b\x02\x02\x01\x12\x03Z\x08\x0b\n\x0c\n\x05\x04\
-    \x0b\x02\x02\x03\x12\x03Z\x0e\x0f\n\x0b\n\x04\x04\x0b\x02\x03\x12\x03[\
-    \x04\x18\n\x0c\n\x05\x04\x0b\x02\x03\x06\x12\x03[\x04\x0b\n\x0c\n\x05\
-    \x04\x0b\x02\x03\x01\x12\x03[\x0c\x13\n\x0c\n\x05\x04\x0b\x02\x03\x03\
-    \x12\x03[\x16\x17\n\x0b\n\x04\x04\x0b\x02\x04\x12\x03]\x02%\n\x0c\n\x05\
-    \x04\x0b\x02\x04\x04\x12\x03]\x02\n\n\x0c\n\x05\x04\x0b\x02\x04\x06\x12\
-    \x03]\x0b\x15\n\x0c\n\x05\x04\x0b\x02\x04\x01\x12\x03]\x16\x20\n\x0c\n\
-    \x05\x04\x0b\x02\x04\x03\x12\x03]#$\n\n\n\x02\x04\x0c\x12\x04`\0x\x01\n\
-    \n\n\x03\x04\x0c\x01\x12\x03`\x08\r\n\x0c\n\x04\x04\x0c\x04\0\x12\x04a\
-    \x02k\x03\n\x0c\n\x05\x04\x0c\x04\0\x01\x12\x03a\x07\x0b\n\r\n\x06\x04\
-    \x0c\x04\0\x02\0\x12\x03b\x04\x0f\n\x0e\n\x07\x04\x0c\x04\0\x02\0\x01\
-    \x12\x03b\x04\n\n\x0e\n\x07\x04\x0c\x04\0\x02\0\x02\x12\x03b\r\x0e\n\r\n\
-    \x06\x04\x0c\x04\0\x02\x01\x12\x03c\x04\x10\n\x0e\n\x07\x04\x0c\x04\0\
-    \x02\x01\x01\x12\x03c\x04\x0b\n\x0e\n\x07\x04\x0c\x04\0\x02\x01\x02\x12\
-    \x03c\x0e\x0f\n\r\n\x06\x04\x0c\x04\0\x02\x02\x12\x03d\x04\x11\n\x0e\n\
-    \x07\x04\x0c\x04\0\x02\x02\x01\x12\x03d\x04\x0c\n\x0e\n\x07\x04\x0c\x04\
-    \0\x02\x02\x02\x12\x03d\x0f\x10\n\r\n\x06\x04\x0c\x04\0\x02\x03\x12\x03e\
-    \x04\x16\n\x0e\n\x07\x04\x0c\x04\0\x02\x03\x01\x12\x03e\x04\x11\n\x0e\n\
-    \x07\x04\x0c\x04\0\x02\x03\x02\x12\x03e\x14\x15\n\r\n\x06\x04\x0c\x04\0\
-    \x02\x04\x12\x03f\x04\x14\n\x0e\n\x07\x04\x0c\x04\0\x02\x04\x01\x12\x03f\
-    \x04\x0f\n\x0e\n\x07\x04\x0c\x04\0\x02\x04\x02\x12\x03f\x12\x13\n\r\n\
-    \x06\x04\x0c\x04\0\x02\x05\x12\x03g\x04\r\n\x0e\n\x07\x04\x0c\x04\0\x02\
-    \x05\x01\x12\x03g\x04\x08\n\x0e\n\x07\x04\x0c\x04\0\x02\x05\x02\x12\x03g\
-    \x0b\x0c\n\r\n\x06\x04\x0c\x04\0\x02\x06\x12\x03h\x04\x0e\n\x0e\n\x07\
-    \x04\x0c\x04\0\x02\x06\x01\x12\x03h\x04\t\n\x0e\n\x07\x04\x0c\x04\0\x02\
-    \x06\x02\x12\x03h\x0c\r\n\r\n\x06\x04\x0c\x04\0\x02\x07\x12\x03i\x04\x17\
-    \n\x0e\n\x07\x04\x0c\x04\0\x02\x07\x01\x12\x03i\x04\x12\n\x0e\n\x07\x04\
-    \x0c\x04\0\x02\x07\x02\x12\x03i\x15\x16\n\r\n\x06\x04\x0c\x04\0\x02\x08\
-    \x12\x03j\x04\x12\n\x0e\n\x07\x04\x0c\x04\0\x02\x08\x01\x12\x03j\x04\r\n\
-    \x0e\n\x07\x04\x0c\x04\0\x02\x08\x02\x12\x03j\x10\x11\n\x0b\n\x04\x04\
-    \x0c\x02\0\x12\x03m\x02\x19\n\x0c\n\x05\x04\x0c\x02\0\x04\x12\x03m\x02\n\
-    \n\x0c\n\x05\x04\x0c\x02\0\x06\x12\x03m\x0b\x0f\n\x0c\n\x05\x04\x0c\x02\
-    \0\x01\x12\x03m\x10\x14\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\x03m\x17\x18\n\
-    \x0b\n\x04\x04\x0c\x02\x01\x12\x03n\x02\x1a\n\x0c\n\x05\x04\x0c\x02\x01\
-    \x04\x12\x03n\x02\n\n\x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03n\x0b\x11\n\
-    \x0c\n\x05\x04\x0c\x02\x01\x01\x12\x03n\x12\x15\n\x0c\n\x05\x04\x0c\x02\
-    \x01\x03\x12\x03n\x18\x19\n\x0b\n\x04\x04\x0c\x02\x02\x12\x03o\x02\x1e\n\
-    \x0c\n\x05\x04\x0c\x02\x02\x04\x12\x03o\x02\n\n\x0c\n\x05\x04\x0c\x02\
-    \x02\x05\x12\x03o\x0b\x11\n\x0c\n\x05\x04\x0c\x02\x02\x01\x12\x03o\x12\
-    \x19\n\x0c\n\x05\x04\x0c\x02\x02\x03\x12\x03o\x1c\x1d\n\x0c\n\x04\x04\
-    \x0c\x08\0\x12\x04p\x02w\x03\n\x0c\n\x05\x04\x0c\x08\0\x01\x12\x03p\x08\
-    \x0f\n\x0b\n\x04\x04\x0c\x02\x03\x12\x03q\x04\x1a\n\x0c\n\x05\x04\x0c\
-    \x02\x03\x06\x12\x03q\x04\x0e\n\x0c\n\x05\x04\x0c\x02\x03\x01\x12\x03q\
-    \x0f\x15\n\x0c\n\x05\x04\x0c\x02\x03\x03\x12\x03q\x18\x19\n\x0b\n\x04\
-    \x04\x0c\x02\x04\x12\x03r\x04\x18\n\x0c\n\x05\x04\x0c\x02\x04\x06\x12\
-    \x03r\x04\x0b\n\x0c\n\x05\x04\x0c\x02\x04\x01\x12\x03r\x0c\x13\n\x0c\n\
-    \x05\x04\x0c\x02\x04\x03\x12\x03r\x16\x17\n\x0b\n\x04\x04\x0c\x02\x05\
-    \x12\x03s\x04%\n\x0c\n\x05\x04\x0c\x02\x05\x06\x12\x03s\x04\x11\n\x0c\n\
-    \x05\x04\x0c\x02\x05\x01\x12\x03s\x12\x20\n\x0c\n\x05\x04\x0c\x02\x05\
-    \x03\x12\x03s#$\n\x0b\n\x04\x04\x0c\x02\x06\x12\x03t\x04!\n\x0c\n\x05\
-    \x04\x0c\x02\x06\x06\x12\x03t\x04\x0f\n\x0c\n\x05\x04\x0c\x02\x06\x01\
-    \x12\x03t\x10\x1c\n\x0c\n\x05\x04\x0c\x02\x06\x03\x12\x03t\x1f\x20\n\x0b\
-    \n\x04\x04\x0c\x02\x07\x12\x03u\x04\x1a\n\x0c\n\x05\x04\x0c\x02\x07\x06\
-    \x12\x03u\x04\x0c\n\x0c\n\x05\x04\x0c\x02\x07\x01\x12\x03u\r\x15\n\x0c\n\
-    \x05\x04\x0c\x02\x07\x03\x12\x03u\x18\x19\n\x0b\n\x04\x04\x0c\x02\x08\
-    \x12\x03v\x04\x1c\n\x0c\n\x05\x04\x0c\x02\x08\x06\x12\x03v\x04\r\n\x0c\n\
-    \x05\x04\x0c\x02\x08\x01\x12\x03v\x0e\x17\n\x0c\n\x05\x04\x0c\x02\x08\
-    \x03\x12\x03v\x1a\x1b\n\n\n\x02\x04\r\x12\x04z\0~\x01\n\n\n\x03\x04\r\
-    \x01\x12\x03z\x08\x0c\n\x0b\n\x04\x04\r\x02\0\x12\x03{\x02\x1e\n\x0c\n\
-    \x05\x04\r\x02\0\x04\x12\x03{\x02\n\n\x0c\n\x05\x04\r\x02\0\x05\x12\x03{\
-    \x0b\x0f\n\x0c\n\x05\x04\r\x02\0\x01\x12\x03{\x10\x19\n\x0c\n\x05\x04\r\
-    \x02\0\x03\x12\x03{\x1c\x1d\n\x0b\n\x04\x04\r\x02\x01\x12\x03|\x02\x1b\n\
-    \x0c\n\x05\x04\r\x02\x01\x04\x12\x03|\x02\n\n\x0c\n\x05\x04\r\x02\x01\
-    \x05\x12\x03|\x0b\x10\n\x0c\n\x05\x04\r\x02\x01\x01\x12\x03|\x11\x16\n\
-    \x0c\n\x05\x04\r\x02\x01\x03\x12\x03|\x19\x1a\n\x0b\n\x04\x04\r\x02\x02\
-    \x12\x03}\x02\x1d\n\x0c\n\x05\x04\r\x02\x02\x04\x12\x03}\x02\n\n\x0c\n\
-    \x05\x04\r\x02\x02\x05\x12\x03}\x0b\x10\n\x0c\n\x05\x04\r\x02\x02\x01\
-    \x12\x03}\x11\x18\n\x0c\n\x05\x04\r\x02\x02\x03\x12\x03}\x1b\x1c\
+    \x03\t(-\n\x0b\n\x04\x04\0\x02\x07\x12\x03\n\x02\x1f\n\x0c\n\x05\x04\0\
+    \x02\x07\x06\x12\x03\n\x02\x15\n\x0c\n\x05\x04\0\x02\x07\x01\x12\x03\n\
+    \x16\x1a\n\x0c\n\x05\x04\0\x02\x07\x03\x12\x03\n\x1d\x1e\n\n\n\x02\x04\
+    \x01\x12\x04\r\0\x10\x01\n\n\n\x03\x04\x01\x01\x12\x03\r\x08\x0c\n\x0b\n\
+    \x04\x04\x01\x02\0\x12\x03\x0e\x02\x1b\n\x0c\n\x05\x04\x01\x02\0\x04\x12\
+    \x03\x0e\x02\n\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\x0e\x0b\x11\n\x0c\n\
+    \x05\x04\x01\x02\0\x01\x12\x03\x0e\x12\x16\n\x0c\n\x05\x04\x01\x02\0\x03\
+    \x12\x03\x0e\x19\x1a\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x0f\x02!\n\x0c\
+    \n\x05\x04\x01\x02\x01\x04\x12\x03\x0f\x02\n\n\x0c\n\x05\x04\x01\x02\x01\
+    \x06\x12\x03\x0f\x0b\x11\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x0f\x12\
+    \x1c\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0f\x1f\x20\n\n\n\x02\x04\
+    \x02\x12\x04\x12\0\x15\x01\n\n\n\x03\x04\x02\x01\x12\x03\x12\x08\x0b\n\
+    \x0b\n\x04\x04\x02\x02\0\x12\x03\x13\x02\x1b\n\x0c\n\x05\x04\x02\x02\0\
+    \x04\x12\x03\x13\x02\n\n\x0c\n\x05\x04\x02\x02\0\x06\x12\x03\x13\x0b\x11\
+    \n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x13\x12\x16\n\x0c\n\x05\x04\x02\
+    \x02\0\x03\x12\x03\x13\x19\x1a\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x14\
+    \x02!\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\x03\x14\x02\n\n\x0c\n\x05\x04\
+    \x02\x02\x01\x06\x12\x03\x14\x0b\x11\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\
+    \x03\x14\x12\x1c\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x14\x1f\x20\n\n\
+    \n\x02\x04\x03\x12\x04\x17\0\x1a\x01\n\n\n\x03\x04\x03\x01\x12\x03\x17\
+    \x08\x0f\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x18\x02\x1b\n\x0c\n\x05\x04\
+    \x03\x02\0\x04\x12\x03\x18\x02\n\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03\
+    \x18\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x18\x12\x16\n\x0c\n\
+    \x05\x04\x03\x02\0\x03\x12\x03\x18\x19\x1a\n\x0b\n\x04\x04\x03\x02\x01\
+    \x12\x03\x19\x02\x1d\n\x0c\n\x05\x04\x03\x02\x01\x04\x12\x03\x19\x02\n\n\
+    \x0c\n\x05\x04\x03\x02\x01\x06\x12\x03\x19\x0b\x11\n\x0c\n\x05\x04\x03\
+    \x02\x01\x01\x12\x03\x19\x12\x18\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\
+    \x19\x1b\x1c\n\n\n\x02\x04\x04\x12\x04\x1c\0!\x01\n\n\n\x03\x04\x04\x01\
+    \x12\x03\x1c\x08\x12\n\x0b\n\x04\x04\x04\x04\0\x12\x03\x1d\x02F\n\x0c\n\
+    \x05\x04\x04\x04\0\x01\x12\x03\x1d\x07\r\n\r\n\x06\x04\x04\x04\0\x02\0\
+    \x12\x03\x1d\x10\x1a\n\x0e\n\x07\x04\x04\x04\0\x02\0\x01\x12\x03\x1d\x10\
+    \x15\n\x0e\n\x07\x04\x04\x04\0\x02\0\x02\x12\x03\x1d\x18\x19\n\r\n\x06\
+    \x04\x04\x04\0\x02\x01\x12\x03\x1d\x1b'\n\x0e\n\x07\x04\x04\x04\0\x02\
+    \x01\x01\x12\x03\x1d\x1b\"\n\x0e\n\x07\x04\x04\x04\0\x02\x01\x02\x12\x03\
+    \x1d%&\n\r\n\x06\x04\x04\x04\0\x02\x02\x12\x03\x1d(6\n\x0e\n\x07\x04\x04\
+    \x04\0\x02\x02\x01\x12\x03\x1d(1\n\x0e\n\x07\x04\x04\x04\0\x02\x02\x02\
+    \x12\x03\x1d45\n\r\n\x06\x04\x04\x04\0\x02\x03\x12\x03\x1d7D\n\x0e\n\x07\
+    \x04\x04\x04\0\x02\x03\x01\x12\x03\x1d7?\n\x0e\n\x07\x04\x04\x04\0\x02\
+    \x03\x02\x12\x03\x1dBC\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1f\x02\x1d\n\
+    \x0c\n\x05\x04\x04\x02\0\x04\x12\x03\x1f\x02\n\n\x0c\n\x05\x04\x04\x02\0\
+    \x06\x12\x03\x1f\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x1f\x12\
+    \x18\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1f\x1b\x1c\n\x0b\n\x04\x04\
+    \x04\x02\x01\x12\x03\x20\x02\x1d\n\x0c\n\x05\x04\x04\x02\x01\x04\x12\x03\
+    \x20\x02\n\n\x0c\n\x05\x04\x04\x02\x01\x06\x12\x03\x20\x0b\x11\n\x0c\n\
+    \x05\x04\x04\x02\x01\x01\x12\x03\x20\x12\x18\n\x0c\n\x05\x04\x04\x02\x01\
+    \x03\x12\x03\x20\x1b\x1c\n\n\n\x02\x04\x05\x12\x04#\0,\x01\n\n\n\x03\x04\
+    \x05\x01\x12\x03#\x08\x10\n\x0b\n\x04\x04\x05\x04\0\x12\x03$\x02:\n\x0c\
+    \n\x05\x04\x05\x04\0\x01\x12\x03$\x07\r\n\r\n\x06\x04\x05\x04\0\x02\0\
+    \x12\x03$\x10\x1c\n\x0e\n\x07\x04\x05\x04\0\x02\0\x01\x12\x03$\x10\x17\n\
+    \x0e\n\x07\x04\x05\x04\0\x02\0\x02\x12\x03$\x1a\x1b\n\r\n\x06\x04\x05\
+    \x04\0\x02\x01\x12\x03$\x1d*\n\x0e\n\x07\x04\x05\x04\0\x02\x01\x01\x12\
+    \x03$\x1d%\n\x0e\n\x07\x04\x05\x04\0\x02\x01\x02\x12\x03$()\n\r\n\x06\
+    \x04\x05\x04\0\x02\x02\x12\x03$+8\n\x0e\n\x07\x04\x05\x04\0\x02\x02\x01\
+    \x12\x03$+3\n\x0e\n\x07\x04\x05\x04\0\x02\x02\x02\x12\x03$67\n\x0b\n\x04\
+    \x04\x05\x02\0\x12\x03&\x02\x20\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x03&\
+    \x02\n\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03&\x0b\x11\n\x0c\n\x05\x04\
+    \x05\x02\0\x01\x12\x03&\x12\x1b\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03&\
+    \x1e\x1f\n\x0b\n\x04\x04\x05\x02\x01\x12\x03'\x02$\n\x0c\n\x05\x04\x05\
+    \x02\x01\x04\x12\x03'\x02\n\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x03'\x0b\
+    \x11\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03'\x12\x1f\n\x0c\n\x05\x04\
+    \x05\x02\x01\x03\x12\x03'\"#\n\x0b\n\x04\x04\x05\x02\x02\x12\x03(\x02\
+    \x1b\n\x0c\n\x05\x04\x05\x02\x02\x04\x12\x03(\x02\n\n\x0c\n\x05\x04\x05\
+    \x02\x02\x05\x12\x03(\x0b\x11\n\x0c\n\x05\x04\x05\x02\x02\x01\x12\x03(\
+    \x12\x16\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x03(\x19\x1a\n\x0b\n\x04\
+    \x04\x05\x02\x03\x12\x03)\x02\"\n\x0c\n\x05\x04\x05\x02\x03\x04\x12\x03)\
+    \x02\n\n\x0c\n\x05\x04\x05\x02\x03\x05\x12\x03)\x0b\x11\n\x0c\n\x05\x04\
+    \x05\x02\x03\x01\x12\x03)\x12\x1d\n\x0c\n\x05\x04\x05\x02\x03\x03\x12\
+    \x03)\x20!\n\x0b\n\x04\x04\x05\x02\x04\x12\x03*\x02\x1d\n\x0c\n\x05\x04\
+    \x05\x02\x04\x04\x12\x03*\x02\n\n\x0c\n\x05\x04\x05\x02\x04\x06\x12\x03*\
+    \x0b\x11\n\x0c\n\x05\x04\x05\x02\x04\x01\x12\x03*\x12\x18\n\x0c\n\x05\
+    \x04\x05\x02\x04\x03\x12\x03*\x1b\x1c\n\x0b\n\x04\x04\x05\x02\x05\x12\
+    \x03+\x02\x1c\n\x0c\n\x05\x04\x05\x02\x05\x04\x12\x03+\x02\n\n\x0c\n\x05\
+    \x04\x05\x02\x05\x05\x12\x03+\x0b\x11\n\x0c\n\x05\x04\x05\x02\x05\x01\
+    \x12\x03+\x12\x17\n\x0c\n\x05\x04\x05\x02\x05\x03\x12\x03+\x1a\x1b\n\n\n\
+    \x02\x04\x06\x12\x04.\0;\x01\n\n\n\x03\x04\x06\x01\x12\x03.\x08\x0f\n\
+    \x0b\n\x04\x04\x06\x04\0\x12\x03/\x02A\n\x0c\n\x05\x04\x06\x04\0\x01\x12\
+    \x03/\x07\r\n\r\n\x06\x04\x06\x04\0\x02\0\x12\x03/\x10\x17\n\x0e\n\x07\
+    \x04\x06\x04\0\x02\0\x01\x12\x03/\x10\x12\n\x0e\n\x07\x04\x06\x04\0\x02\
+    \0\x02\x12\x03/\x15\x16\n\r\n\x06\x04\x06\x04\0\x02\x01\x12\x03/\x18$\n\
+    \x0e\n\x07\x04\x06\x04\0\x02\x01\x01\x12\x03/\x18\x1f\n\x0e\n\x07\x04\
+    \x06\x04\0\x02\x01\x02\x12\x03/\"#\n\r\n\x06\x04\x06\x04\0\x02\x02\x12\
+    \x03/%2\n\x0e\n\x07\x04\x06\x04\0\x02\x02\x01\x12\x03/%-\n\x0e\n\x07\x04\
+    \x06\x04\0\x02\x02\x02\x12\x03/01\n\r\n\x06\x04\x06\x04\0\x02\x03\x12\
+    \x03/3?\n\x0e\n\x07\x04\x06\x04\0\x02\x03\x01\x12\x03/3:\n\x0e\n\x07\x04\
+    \x06\x04\0\x02\x03\x02\x12\x03/=>\n\x0b\n\x04\x04\x06\x02\0\x12\x031\x02\
+    \x20\n\x0c\n\x05\x04\x06\x02\0\x04\x12\x031\x02\n\n\x0c\n\x05\x04\x06\
+    \x02\0\x05\x12\x031\x0b\x11\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x031\x12\
+    \x1b\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x031\x1e\x1f\n\x0b\n\x04\x04\x06\
+    \x02\x01\x12\x032\x02$\n\x0c\n\x05\x04\x06\x02\x01\x04\x12\x032\x02\n\n\
+    \x0c\n\x05\x04\x06\x02\x01\x05\x12\x032\x0b\x11\n\x0c\n\x05\x04\x06\x02\
+    \x01\x01\x12\x032\x12\x1f\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x032\"#\n\
+    \x0b\n\x04\x04\x06\x02\x02\x12\x033\x02\"\n\x0c\n\x05\x04\x06\x02\x02\
+    \x04\x12\x033\x02\n\n\x0c\n\x05\x04\x06\x02\x02\x05\x12\x033\x0b\x11\n\
+    \x0c\n\x05\x04\x06\x02\x02\x01\x12\x033\x12\x1d\n\x0c\n\x05\x04\x06\x02\
+    \x02\x03\x12\x033\x20!\n\x0b\n\x04\x04\x06\x02\x03\x12\x034\x02\x20\n\
+    \x0c\n\x05\x04\x06\x02\x03\x04\x12\x034\x02\n\n\x0c\n\x05\x04\x06\x02\
+    \x03\x05\x12\x034\x0b\x0f\n\x0c\n\x05\x04\x06\x02\x03\x01\x12\x034\x10\
+    \x1b\n\x0c\n\x05\x04\x06\x02\x03\x03\x12\x034\x1e\x1f\n\x0b\n\x04\x04\
+    \x06\x02\x04\x12\x035\x02\x1a\n\x0c\n\x05\x04\x06\x02\x04\x04\x12\x035\
+    \x02\n\n\x0c\n\x05\x04\x06\x02\x04\x05\x12\x035\x0b\x11\n\x0c\n\x05\x04\
+    \x06\x02\x04\x01\x12\x035\x12\x15\n\x0c\n\x05\x04\x06\x02\x04\x03\x12\
+    \x035\x18\x19\n\x0b\n\x04\x04\x06\x02\x05\x12\x036\x02\x1a\n\x0c\n\x05\
+    \x04\x06\x02\x05\x04\x12\x036\x02\n\n\x0c\n\x05\x04\x06\x02\x05\x05\x12\
+    \x036\x0b\x10\n\x0c\n\x05\x04\x06\x02\x05\x01\x12\x036\x11\x14\n\x0c\n\
+    \x05\x04\x06\x02\x05\x03\x12\x036\x17\x19\n\x0b\n\x04\x04\x06\x02\x06\
+    \x12\x037\x02\x1c\n\x0c\n\x05\x04\x06\x02\x06\x04\x12\x037\x02\n\n\x0c\n\
+    \x05\x04\x06\x02\x06\x06\x12\x037\x0b\x12\n\x0c\n\x05\x04\x06\x02\x06\
+    \x01\x12\x037\x13\x16\n\x0c\n\x05\x04\x06\x02\x06\x03\x12\x037\x19\x1b\n\
+    \x0b\n\x04\x04\x06\x02\x07\x12\x038\x022\n\x0c\n\x05\x04\x06\x02\x07\x04\
+    \x12\x038\x02\n\n\x0c\n\x05\x04\x06\x02\x07\x06\x12\x038\x0b\x11\n\x0c\n\
+    \x05\x04\x06\x02\x07\x01\x12\x038\x12\x18\n\x0c\n\x05\x04\x06\x02\x07\
+    \x03\x12\x038\x1b\x1d\n\x0c\n\x05\x04\x06\x02\x07\x08\x12\x038\x1e1\n\
+    \x0c\n\x05\x04\x06\x02\x07\x07\x12\x038)0\n\x0b\n\x04\x04\x06\x02\x08\
+    \x12\x039\x02(\n\x0c\n\x05\x04\x06\x02\x08\x04\x12\x039\x02\n\n\x0c\n\
+    \x05\x04\x06\x02\x08\x05\x12\x039\x0b\x11\n\x0c\n\x05\x04\x06\x02\x08\
+    \x01\x12\x039\x12\"\n\x0c\n\x05\x04\x06\x02\x08\x03\x12\x039%'\n\x0b\n\
+    \x04\x04\x06\x02\t\x12\x03:\x02\x1e\n\x0c\n\x05\x04\x06\x02\t\x04\x12\
+    \x03:\x02\n\n\x0c\n\x05\x04\x06\x02\t\x05\x12\x03:\x0b\x0f\n\x0c\n\x05\
+    \x04\x06\x02\t\x01\x12\x03:\x10\x18\n\x0c\n\x05\x04\x06\x02\t\x03\x12\
+    \x03:\x1b\x1d\n\n\n\x02\x04\x07\x12\x04=\0B\x01\n\n\n\x03\x04\x07\x01\
+    \x12\x03=\x08\x15\n\x0b\n\x04\x04\x07\x02\0\x12\x03>\x02$\n\x0c\n\x05\
+    \x04\x07\x02\0\x04\x12\x03>\x02\n\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03>\
+    \x0b\x11\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03>\x12\x1f\n\x0c\n\x05\x04\
+    \x07\x02\0\x03\x12\x03>\"#\n\x0b\n\x04\x04\x07\x02\x01\x12\x03?\x02\"\n\
+    \x0c\n\x05\x04\x07\x02\x01\x04\x12\x03?\x02\n\n\x0c\n\x05\x04\x07\x02\
+    \x01\x05\x12\x03?\x0b\x11\n\x0c\n\x05\x04\x07\x02\x01\x01\x12\x03?\x12\
+    \x1d\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x03?\x20!\n\x0b\n\x04\x04\x07\
+    \x02\x02\x12\x03@\x02\x1e\n\x0c\n\x05\x04\x07\x02\x02\x04\x12\x03@\x02\n\
+    \n\x0c\n\x05\x04\x07\x02\x02\x05\x12\x03@\x0b\x0f\n\x0c\n\x05\x04\x07\
+    \x02\x02\x01\x12\x03@\x10\x19\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\x03@\
+    \x1c\x1d\n\x0b\n\x04\x04\x07\x02\x03\x12\x03A\x02\x1c\n\x0c\n\x05\x04\
+    \x07\x02\x03\x04\x12\x03A\x02\n\n\x0c\n\x05\x04\x07\x02\x03\x05\x12\x03A\
+    \x0b\x10\n\x0c\n\x05\x04\x07\x02\x03\x01\x12\x03A\x11\x17\n\x0c\n\x05\
+    \x04\x07\x02\x03\x03\x12\x03A\x1a\x1b\n\n\n\x02\x04\x08\x12\x04D\0J\x01\
+    \n\n\n\x03\x04\x08\x01\x12\x03D\x08\x13\n\x0b\n\x04\x04\x08\x02\0\x12\
+    \x03E\x02$\n\x0c\n\x05\x04\x08\x02\0\x04\x12\x03E\x02\n\n\x0c\n\x05\x04\
+    \x08\x02\0\x05\x12\x03E\x0b\x11\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x03E\
+    \x12\x1f\n\x0c\n\x05\x04\x08\x02\0\x03\x12\x03E\"#\n\x0b\n\x04\x04\x08\
+    \x02\x01\x12\x03F\x02\"\n\x0c\n\x05\x04\x08\x02\x01\x04\x12\x03F\x02\n\n\
+    \x0c\n\x05\x04\x08\x02\x01\x05\x12\x03F\x0b\x11\n\x0c\n\x05\x04\x08\x02\
+    \x01\x01\x12\x03F\x12\x1d\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x03F\x20!\
+    \n\x0b\n\x04\x04\x08\x02\x02\x12\x03G\x02\x1e\n\x0c\n\x05\x04\x08\x02\
+    \x02\x04\x12\x03G\x02\n\n\x0c\n\x05\x04\x08\x02\x02\x05\x12\x03G\x0b\x0f\
+    \n\x0c\n\x05\x04\x08\x02\x02\x01\x12\x03G\x10\x19\n\x0c\n\x05\x04\x08\
+    \x02\x02\x03\x12\x03G\x1c\x1d\n\x0b\n\x04\x04\x08\x02\x03\x12\x03H\x02\
+    \x1f\n\x0c\n\x05\x04\x08\x02\x03\x04\x12\x03H\x02\n\n\x0c\n\x05\x04\x08\
+    \x02\x03\x05\x12\x03H\x0b\x11\n\x0c\n\x05\x04\x08\x02\x03\x01\x12\x03H\
+    \x12\x1a\n\x0c\n\x05\x04\x08\x02\x03\x03\x12\x03H\x1d\x1e\n\x0b\n\x04\
+    \x04\x08\x02\x04\x12\x03I\x02\x1a\n\x0c\n\x05\x04\x08\x02\x04\x04\x12\
+    \x03I\x02\n\n\x0c\n\x05\x04\x08\x02\x04\x05\x12\x03I\x0b\x10\n\x0c\n\x05\
+    \x04\x08\x02\x04\x01\x12\x03I\x11\x15\n\x0c\n\x05\x04\x08\x02\x04\x03\
+    \x12\x03I\x18\x19\n\n\n\x02\x04\t\x12\x04L\0T\x01\n\n\n\x03\x04\t\x01\
+    \x12\x03L\x08\x0f\n\x0b\n\x04\x04\t\x02\0\x12\x03M\x021\n\x0c\n\x05\x04\
+    \t\x02\0\x04\x12\x03M\x02\n\n\x0c\n\x05\x04\t\x02\0\x05\x12\x03M\x0b\x11\
+    \n\x0c\n\x05\x04\t\x02\0\x01\x12\x03M\x12\x14\n\x0c\n\x05\x04\t\x02\0\
+    \x03\x12\x03M\x17\x18\n\x0c\n\x05\x04\t\x02\0\x08\x12\x03M\x190\n\x0c\n\
+    \x05\x04\t\x02\0\x07\x12\x03M$/\n\x0b\n\x04\x04\t\x02\x01\x12\x03N\x027\
+    \n\x0c\n\x05\x04\t\x02\x01\x04\x12\x03N\x02\n\n\x0c\n\x05\x04\t\x02\x01\
+    \x05\x12\x03N\x0b\x11\n\x0c\n\x05\x04\t\x02\x01\x01\x12\x03N\x12\x1a\n\
+    \x0c\n\x05\x04\t\x02\x01\x03\x12\x03N\x1d\x1e\n\x0c\n\x05\x04\t\x02\x01\
+    \x08\x12\x03N\x1f6\n\x0c\n\x05\x04\t\x02\x01\x07\x12\x03N*5\n\x0b\n\x04\
+    \x04\t\x02\x02\x12\x03O\x028\n\x0c\n\x05\x04\t\x02\x02\x04\x12\x03O\x02\
+    \n\n\x0c\n\x05\x04\t\x02\x02\x05\x12\x03O\x0b\x11\n\x0c\n\x05\x04\t\x02\
+    \x02\x01\x12\x03O\x12\x1b\n\x0c\n\x05\x04\t\x02\x02\x03\x12\x03O\x1e\x1f\
+    \n\x0c\n\x05\x04\t\x02\x02\x08\x12\x03O\x207\n\x0c\n\x05\x04\t\x02\x02\
+    \x07\x12\x03O+6\n\x0b\n\x04\x04\t\x02\x03\x12\x03P\x02\"\n\x0c\n\x05\x04\
+    \t\x02\x03\x04\x12\x03P\x02\n\n\x0c\n\x05\x04\t\x02\x03\x05\x12\x03P\x0b\
+    \x11\n\x0c\n\x05\x04\t\x02\x03\x01\x12\x03P\x12\x1d\n\x0c\n\x05\x04\t\
+    \x02\x03\x03\x12\x03P\x20!\n\x0b\n\x04\x04\t\x02\x04\x12\x03Q\x02>\n\x0c\
+    \n\x05\x04\t\x02\x04\x04\x12\x03Q\x02\n\n\x0c\n\x05\x04\t\x02\x04\x05\
+    \x12\x03Q\x0b\x11\n\x0c\n\x05\x04\t\x02\x04\x01\x12\x03Q\x12!\n\x0c\n\
+    \x05\x04\t\x02\x04\x03\x12\x03Q$%\n\x0c\n\x05\x04\t\x02\x04\x08\x12\x03Q\
+    &=\n\x0c\n\x05\x04\t\x02\x04\x07\x12\x03Q1<\n\x0b\n\x04\x04\t\x02\x05\
+    \x12\x03R\x02(\n\x0c\n\x05\x04\t\x02\x05\x04\x12\x03R\x02\n\n\x0c\n\x05\
+    \x04\t\x02\x05\x05\x12\x03R\x0b\x11\n\x0c\n\x05\x04\t\x02\x05\x01\x12\
+    \x03R\x12#\n\x0c\n\x05\x04\t\x02\x05\x03\x12\x03R&'\n\x0b\n\x04\x04\t\
+    \x02\x06\x12\x03S\x02\x1b\n\x0c\n\x05\x04\t\x02\x06\x04\x12\x03S\x02\n\n\
+    \x0c\n\x05\x04\t\x02\x06\x05\x12\x03S\x0b\x11\n\x0c\n\x05\x04\t\x02\x06\
+    \x01\x12\x03S\x12\x16\n\x0c\n\x05\x04\t\x02\x06\x03\x12\x03S\x19\x1a\n\n\
+    \n\x02\x04\n\x12\x04V\0X\x01\n\n\n\x03\x04\n\x01\x12\x03V\x08\x11\n\x0b\
+    \n\x04\x04\n\x02\0\x12\x03W\x02\x20\n\x0c\n\x05\x04\n\x02\0\x04\x12\x03W\
+    \x02\n\n\x0c\n\x05\x04\n\x02\0\x05\x12\x03W\x0b\x11\n\x0c\n\x05\x04\n\
+    \x02\0\x01\x12\x03W\x12\x1b\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03W\x1e\x1f\
+    \n\n\n\x02\x04\x0b\x12\x04Z\0]\x01\n\n\n\x03\x04\x0b\x01\x12\x03Z\x08\
+    \x11\n\x0b\n\x04\x04\x0b\x02\0\x12\x03[\x02\x20\n\x0c\n\x05\x04\x0b\x02\
+    \0\x04\x12\x03[\x02\n\n\x0c\n\x05\x04\x0b\x02\0\x05\x12\x03[\x0b\x11\n\
+    \x0c\n\x05\x04\x0b\x02\0\x01\x12\x03[\x12\x1b\n\x0c\n\x05\x04\x0b\x02\0\
+    \x03\x12\x03[\x1e\x1f\n\x0b\n\x04\x04\x0b\x02\x01\x12\x03\\\x02\x1c\n\
+    \x0c\n\x05\x04\x0b\x02\x01\x04\x12\x03\\\x02\n\n\x0c\n\x05\x04\x0b\x02\
+    \x01\x05\x12\x03\\\x0b\x11\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03\\\x12\
+    \x17\n\x0c\n\x05\x04\x0b\x02\x01\x03\x12\x03\\\x1a\x1b\n\n\n\x02\x04\x0c\
+    \x12\x04_\0f\x01\n\n\n\x03\x04\x0c\x01\x12\x03_\x08\x10\n\x0b\n\x04\x04\
+    \x0c\x02\0\x12\x03`\x02\x20\n\x0c\n\x05\x04\x0c\x02\0\x04\x12\x03`\x02\n\
+    \n\x0c\n\x05\x04\x0c\x02\0\x05\x12\x03`\x0b\x11\n\x0c\n\x05\x04\x0c\x02\
+    \0\x01\x12\x03`\x12\x1b\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\x03`\x1e\x1f\n\
+    \x0b\n\x04\x04\x0c\x02\x01\x12\x03a\x02$\n\x0c\n\x05\x04\x0c\x02\x01\x04\
+    \x12\x03a\x02\n\n\x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03a\x0b\x11\n\x0c\n\
+    \x05\x04\x0c\x02\x01\x01\x12\x03a\x12\x1f\n\x0c\n\x05\x04\x0c\x02\x01\
+    \x03\x12\x03a\"#\n\x0b\n\x04\x04\x0c\x02\x02\x12\x03b\x02\"\n\x0c\n\x05\
+    \x04\x0c\x02\x02\x04\x12\x03b\x02\n\n\x0c\n\x05\x04\x0c\x02\x02\x05\x12\
+    \x03b\x0b\x11\n\x0c\n\x05\x04\x0c\x02\x02\x01\x12\x03b\x12\x1d\n\x0c\n\
+    \x05\x04\x0c\x02\x02\x03\x12\x03b\x20!\n\x0b\n\x04\x04\x0c\x02\x03\x12\
+    \x03c\x02\"\n\x0c\n\x05\x04\x0c\x02\x03\x04\x12\x03c\x02\n\n\x0c\n\x05\
+    \x04\x0c\x02\x03\x05\x12\x03c\x0b\x11\n\x0c\n\x05\x04\x0c\x02\x03\x01\
+    \x12\x03c\x12\x1d\n\x0c\n\x05\x04\x0c\x02\x03\x03\x12\x03c\x20!\n\x0b\n\
+    \x04\x04\x0c\x02\x04\x12\x03d\x023\n\x0c\n\x05\x04\x0c\x02\x04\x04\x12\
+    \x03d\x02\n\n\x0c\n\x05\x04\x0c\x02\x04\x05\x12\x03d\x0b\x0f\n\x0c\n\x05\
+    \x04\x0c\x02\x04\x01\x12\x03d\x10\x1c\n\x0c\n\x05\x04\x0c\x02\x04\x03\
+    \x12\x03d\x1f\x20\n\x0c\n\x05\x04\x0c\x02\x04\x08\x12\x03d!2\n\x0c\n\x05\
+    \x04\x0c\x02\x04\x07\x12\x03d,1\n\x0b\n\x04\x04\x0c\x02\x05\x12\x03e\x02\
+    \x1f\n\x0c\n\x05\x04\x0c\x02\x05\x04\x12\x03e\x02\n\n\x0c\n\x05\x04\x0c\
+    \x02\x05\x06\x12\x03e\x0b\x14\n\x0c\n\x05\x04\x0c\x02\x05\x01\x12\x03e\
+    \x15\x1a\n\x0c\n\x05\x04\x0c\x02\x05\x03\x12\x03e\x1d\x1e\n\n\n\x02\x04\
+    \r\x12\x04h\0m\x01\n\n\n\x03\x04\r\x01\x12\x03h\x08\x0f\n\x0b\n\x04\x04\
+    \r\x02\0\x12\x03i\x02\x20\n\x0c\n\x05\x04\r\x02\0\x04\x12\x03i\x02\n\n\
+    \x0c\n\x05\x04\r\x02\0\x05\x12\x03i\x0b\x11\n\x0c\n\x05\x04\r\x02\0\x01\
+    \x12\x03i\x12\x1b\n\x0c\n\x05\x04\r\x02\0\x03\x12\x03i\x1e\x1f\n\x0b\n\
+    \x04\x04\r\x02\x01\x12\x03j\x02\x1b\n\x0c\n\x05\x04\r\x02\x01\x04\x12\
+    \x03j\x02\n\n\x0c\n\x05\x04\r\x02\x01\x05\x12\x03j\x0b\x11\n\x0c\n\x05\
+    \x04\r\x02\x01\x01\x12\x03j\x12\x16\n\x0c\n\x05\x04\r\x02\x01\x03\x12\
+    \x03j\x19\x1a\n\x0b\n\x04\x04\r\x02\x02\x12\x03k\x02\x1f\n\x0c\n\x05\x04\
+    \r\x02\x02\x04\x12\x03k\x02\n\n\x0c\n\x05\x04\r\x02\x02\x05\x12\x03k\x0b\
+    \x11\n\x0c\n\x05\x04\r\x02\x02\x01\x12\x03k\x12\x1a\n\x0c\n\x05\x04\r\
+    \x02\x02\x03\x12\x03k\x1d\x1e\n\x0b\n\x04\x04\r\x02\x03\x12\x03l\x02\"\n\
+    \x0c\n\x05\x04\r\x02\x03\x04\x12\x03l\x02\n\n\x0c\n\x05\x04\r\x02\x03\
+    \x05\x12\x03l\x0b\x11\n\x0c\n\x05\x04\r\x02\x03\x01\x12\x03l\x12\x1d\n\
+    \x0c\n\x05\x04\r\x02\x03\x03\x12\x03l\x20!\n\n\n\x02\x04\x0e\x12\x04o\0u\
+    \x01\n\n\n\x03\x04\x0e\x01\x12\x03o\x08\x10\n\x0b\n\x04\x04\x0e\x02\0\
+    \x12\x03p\x02\x20\n\x0c\n\x05\x04\x0e\x02\0\x04\x12\x03p\x02\n\n\x0c\n\
+    \x05\x04\x0e\x02\0\x05\x12\x03p\x0b\x11\n\x0c\n\x05\x04\x0e\x02\0\x01\
+    \x12\x03p\x12\x1b\n\x0c\n\x05\x04\x0e\x02\0\x03\x12\x03p\x1e\x1f\n\x0b\n\
+    \x04\x04\x0e\x02\x01\x12\x03q\x02$\n\x0c\n\x05\x04\x0e\x02\x01\x04\x12\
+    \x03q\x02\n\n\x0c\n\x05\x04\x0e\x02\x01\x05\x12\x03q\x0b\x11\n\x0c\n\x05\
+    \x04\x0e\x02\x01\x01\x12\x03q\x12\x1f\n\x0c\n\x05\x04\x0e\x02\x01\x03\
+    \x12\x03q\"#\n\x0b\n\x04\x04\x0e\x02\x02\x12\x03r\x02\x1a\n\x0c\n\x05\
+    \x04\x0e\x02\x02\x04\x12\x03r\x02\n\n\x0c\n\x05\x04\x0e\x02\x02\x05\x12\
+    \x03r\x0b\x11\n\x0c\n\x05\x04\x0e\x02\x02\x01\x12\x03r\x12\x15\n\x0c\n\
+    \x05\x04\x0e\x02\x02\x03\x12\x03r\x18\x19\n\x0b\n\x04\x04\x0e\x02\x03\
+    \x12\x03s\x02\x1c\n\x0c\n\x05\x04\x0e\x02\x03\x04\x12\x03s\x02\n\n\x0c\n\
+    \x05\x04\x0e\x02\x03\x05\x12\x03s\x0b\x11\n\x0c\n\x05\x04\x0e\x02\x03\
+    \x01\x12\x03s\x12\x17\n\x0c\n\x05\x04\x0e\x02\x03\x03\x12\x03s\x1a\x1b\n\
+    \x0b\n\x04\x04\x0e\x02\x04\x12\x03t\x02\"\n\x0c\n\x05\x04\x0e\x02\x04\
+    \x04\x12\x03t\x02\n\n\x0c\n\x05\x04\x0e\x02\x04\x05\x12\x03t\x0b\x11\n\
+    \x0c\n\x05\x04\x0e\x02\x04\x01\x12\x03t\x12\x1d\n\x0c\n\x05\x04\x0e\x02\
+    \x04\x03\x12\x03t\x20!\n\n\n\x02\x04\x0f\x12\x04w\0|\x01\n\n\n\x03\x04\
+    \x0f\x01\x12\x03w\x08\x17\n\x0b\n\x04\x04\x0f\x02\0\x12\x03x\x02\x1b\n\
+    \x0c\n\x05\x04\x0f\x02\0\x04\x12\x03x\x02\n\n\x0c\n\x05\x04\x0f\x02\0\
+    \x05\x12\x03x\x0b\x11\n\x0c\n\x05\x04\x0f\x02\0\x01\x12\x03x\x12\x16\n\
+    \x0c\n\x05\x04\x0f\x02\0\x03\x12\x03x\x19\x1a\n\x0b\n\x04\x04\x0f\x02\
+    \x01\x12\x03y\x02%\n\x0c\n\x05\x04\x0f\x02\x01\x04\x12\x03y\x02\n\n\x0c\
+    \n\x05\x04\x0f\x02\x01\x06\x12\x03y\x0b\x15\n\x0c\n\x05\x04\x0f\x02\x01\
+    \x01\x12\x03y\x16\x20\n\x0c\n\x05\x04\x0f\x02\x01\x03\x12\x03y#$\nU\n\
+    \x04\x04\x0f\x02\x02\x12\x03{\x02!\x1aH\x20Seconds\x20since\x20the\x20ep\
+    och\x20when\x20a\x20gateway\x20of\x20the\x20member's\x20ring\x20relayed\
+    \x20it\n\n\x0c\n\x05\x04\x0f\x02\x02\x04\x12\x03{\x02\n\n\x0c\n\x05\x04\
+    \x0f\x02\x02\x05\x12\x03{\x0b\x11\n\x0c\n\x05\x04\x0f\x02\x02\x01\x12\
+    \x03{\x12\x1c\n\x0c\n\x05\x04\x0f\x02\x02\x03\x12\x03{\x1f\x20\n\x0b\n\
+    \x02\x04\x10\x12\x05~\0\x89\x01\x01\n\n\n\x03\x04\x10\x01\x12\x03~\x08\
+    \x0c\n\x0b\n\x04\x04\x10\x04\0\x12\x03\x7f\x02/\n\x0c\n\x05\x04\x10\x04\
+    \0\x01\x12\x03\x7f\x07\x0b\n\r\n\x06\x04\x10\x04\0\x02\0\x12\x03\x7f\x0e\
+    \x17\n\x0e\n\x07\x04\x10\x04\0\x02\0\x01\x12\x03\x7f\x0e\x12\n\x0e\n\x07\
+    \x04\x10\x04\0\x02\0\x02\x12\x03\x7f\x15\x16\n\r\n\x06\x04\x10\x04\0\x02\
+    \x01\x12\x03\x7f\x18\x20\n\x0e\n\x07\x04\x10\x04\0\x02\x01\x01\x12\x03\
+    \x7f\x18\x1b\n\x0e\n\x07\x04\x10\x04\0\x02\x01\x02\x12\x03\x7f\x1e\x1f\n\
+    \r\n\x06\x04\x10\x04\0\x02\x02\x12\x03\x7f!-\n\x0e\n\x07\x04\x10\x04\0\
+    \x02\x02\x01\x12\x03\x7f!(\n\x0e\n\x07\x04\x10\x04\0\x02\x02\x02\x12\x03\
+    \x7f+,\n4\n\x04\x04\x10\x02\0\x12\x04\x82\x01\x02\x19\x1a&\x20Identifies\
+    \x20which\x20field\x20is\x20filled\x20in.\n\n\r\n\x05\x04\x10\x02\0\x04\
+    \x12\x04\x82\x01\x02\n\n\r\n\x05\x04\x10\x02\0\x06\x12\x04\x82\x01\x0b\
+    \x0f\n\r\n\x05\x04\x10\x02\0\x01\x12\x04\x82\x01\x10\x14\n\r\n\x05\x04\
+    \x10\x02\0\x03\x12\x04\x82\x01\x17\x18\n\x0e\n\x04\x04\x10\x08\0\x12\x06\
+    \x83\x01\x02\x87\x01\x03\n\r\n\x05\x04\x10\x08\0\x01\x12\x04\x83\x01\x08\
+    \x0f\n\x0c\n\x04\x04\x10\x02\x01\x12\x04\x84\x01\x04\x12\n\r\n\x05\x04\
+    \x10\x02\x01\x06\x12\x04\x84\x01\x04\x08\n\r\n\x05\x04\x10\x02\x01\x01\
+    \x12\x04\x84\x01\t\r\n\r\n\x05\x04\x10\x02\x01\x03\x12\x04\x84\x01\x10\
+    \x11\n\x0c\n\x04\x04\x10\x02\x02\x12\x04\x85\x01\x04\x10\n\r\n\x05\x04\
+    \x10\x02\x02\x06\x12\x04\x85\x01\x04\x07\n\r\n\x05\x04\x10\x02\x02\x01\
+    \x12\x04\x85\x01\x08\x0b\n\r\n\x05\x04\x10\x02\x02\x03\x12\x04\x85\x01\
+    \x0e\x0f\n\x0c\n\x04\x04\x10\x02\x03\x12\x04\x86\x01\x04\x18\n\r\n\x05\
+    \x04\x10\x02\x03\x06\x12\x04\x86\x01\x04\x0b\n\r\n\x05\x04\x10\x02\x03\
+    \x01\x12\x04\x86\x01\x0c\x13\n\r\n\x05\x04\x10\x02\x03\x03\x12\x04\x86\
+    \x01\x16\x17\n\x0c\n\x04\x04\x10\x02\x04\x12\x04\x88\x01\x02%\n\r\n\x05\
+    \x04\x10\x02\x04\x04\x12\x04\x88\x01\x02\n\n\r\n\x05\x04\x10\x02\x04\x06\
+    \x12\x04\x88\x01\x0b\x15\n\r\n\x05\x04\x10\x02\x04\x01\x12\x04\x88\x01\
+    \x16\x20\n\r\n\x05\x04\x10\x02\x04\x03\x12\x04\x88\x01#$\n\x0c\n\x02\x04\
+    \x11\x12\x06\x8b\x01\0\xae\x01\x01\n\x0b\n\x03\x04\x11\x01\x12\x04\x8b\
+    \x01\x08\r\n\x0e\n\x04\x04\x11\x04\0\x12\x06\x8c\x01\x02\x9a\x01\x03\n\r\
+    \n\x05\x04\x11\x04\0\x01\x12\x04\x8c\x01\x07\x0b\n\x0e\n\x06\x04\x11\x04\
+    \0\x02\0\x12\x04\x8d\x01\x04\x0f\n\x0f\n\x07\x04\x11\x04\0\x02\0\x01\x12\
+    \x04\x8d\x01\x04\n\n\x0f\n\x07\x04\x11\x04\0\x02\0\x02\x12\x04\x8d\x01\r\
+    \x0e\n\x0e\n\x06\x04\x11\x04\0\x02\x01\x12\x04\x8e\x01\x04\x10\n\x0f\n\
+    \x07\x04\x11\x04\0\x02\x01\x01\x12\x04\x8e\x01\x04\x0b\n\x0f\n\x07\x04\
+    \x11\x04\0\x02\x01\x02\x12\x04\x8e\x01\x0e\x0f\n\x0e\n\x06\x04\x11\x04\0\
+    \x02\x02\x12\x04\x8f\x01\x04\x11\n\x0f\n\x07\x04\x11\x04\0\x02\x02\x01\
+    \x12\x04\x8f\x01\x04\x0c\n\x0f\n\x07\x04\x11\x04\0\x02\x02\x02\x12\x04\
+    \x8f\x01\x0f\x10\n\x0e\n\x06\x04\x11\x04\0\x02\x03\x12\x04\x90\x01\x04\
+    \x16\n\x0f\n\x07\x04\x11\x04\0\x02\x03\x01\x12\x04\x90\x01\x04\x11\n\x0f\
+    \n\x07\x04\x11\x04\0\x02\x03\x02\x12\x04\x90\x01\x14\x15\n\x0e\n\x06\x04\
+    \x11\x04\0\x02\x04\x12\x04\x91\x01\x04\x14\n\x0f\n\x07\x04\x11\x04\0\x02\
+    \x04\x01\x12\x04\x91\x01\x04\x0f\n\x0f\n\x07\x04\x11\x04\0\x02\x04\x02\
+    \x12\x04\x91\x01\x12\x13\n\x0e\n\x06\x04\x11\x04\0\x02\x05\x12\x04\x92\
+    \x01\x04\r\n\x0f\n\x07\x04\x11\x04\0\x02\x05\x01\x12\x04\x92\x01\x04\x08\
+    \n\x0f\n\x07\x04\x11\x04\0\x02\x05\x02\x12\x04\x92\x01\x0b\x0c\n\x0e\n\
+    \x06\x04\x11\x04\0\x02\x06\x12\x04\x93\x01\x04\x0e\n\x0f\n\x07\x04\x11\
+    \x04\0\x02\x06\x01\x12\x04\x93\x01\x04\t\n\x0f\n\x07\x04\x11\x04\0\x02\
+    \x06\x02\x12\x04\x93\x01\x0c\r\n\x0e\n\x06\x04\x11\x04\0\x02\x07\x12\x04\
+    \x94\x01\x04\x17\n\x0f\n\x07\x04\x11\x04\0\x02\x07\x01\x12\x04\x94\x01\
+    \x04\x12\n\x0f\n\x07\x04\x11\x04\0\x02\x07\x02\x12\x04\x94\x01\x15\x16\n\
+    \x0e\n\x06\x04\x11\x04\0\x02\x08\x12\x04\x95\x01\x04\x12\n\x0f\n\x07\x04\
+    \x11\x04\0\x02\x08\x01\x12\x04\x95\x01\x04\r\n\x0f\n\x07\x04\x11\x04\0\
+    \x02\x08\x02\x12\x04\x95\x01\x10\x11\n\x0e\n\x06\x04\x11\x04\0\x02\t\x12\
+    \x04\x96\x01\x04\x12\n\x0f\n\x07\x04\x11\x04\0\x02\t\x01\x12\x04\x96\x01\
+    \x04\x0c\n\x0f\n\x07\x04\x11\x04\0\x02\t\x02\x12\x04\x96\x01\x0f\x11\n\
+    \x0e\n\x06\x04\x11\x04\0\x02\n\x12\x04\x97\x01\x04\x11\n\x0f\n\x07\x04\
+    \x11\x04\0\x02\n\x01\x12\x04\x97\x01\x04\x0b\n\x0f\n\x07\x04\x11\x04\0\
+    \x02\n\x02\x12\x04\x97\x01\x0e\x10\n\x0e\n\x06\x04\x11\x04\0\x02\x0b\x12\
+    \x04\x98\x01\x04\x12\n\x0f\n\x07\x04\x11\x04\0\x02\x0b\x01\x12\x04\x98\
+    \x01\x04\x0c\n\x0f\n\x07\x04\x11\x04\0\x02\x0b\x02\x12\x04\x98\x01\x0f\
+    \x11\n\x0e\n\x06\x04\x11\x04\0\x02\x0c\x12\x04\x99\x01\x04\x19\n\x0f\n\
+    \x07\x04\x11\x04\0\x02\x0c\x01\x12\x04\x99\x01\x04\x13\n\x0f\n\x07\x04\
+    \x11\x04\0\x02\x0c\x02\x12\x04\x99\x01\x16\x18\n\x0c\n\x04\x04\x11\x02\0\
+    \x12\x04\x9c\x01\x02\x19\n\r\n\x05\x04\x11\x02\0\x04\x12\x04\x9c\x01\x02\
+    \n\n\r\n\x05\x04\x11\x02\0\x06\x12\x04\x9c\x01\x0b\x0f\n\r\n\x05\x04\x11\
+    \x02\0\x01\x12\x04\x9c\x01\x10\x14\n\r\n\x05\x04\x11\x02\0\x03\x12\x04\
+    \x9c\x01\x17\x18\n\x0c\n\x04\x04\x11\x02\x01\x12\x04\x9d\x01\x02\x1a\n\r\
+    \n\x05\x04\x11\x02\x01\x04\x12\x04\x9d\x01\x02\n\n\r\n\x05\x04\x11\x02\
+    \x01\x05\x12\x04\x9d\x01\x0b\x11\n\r\n\x05\x04\x11\x02\x01\x01\x12\x04\
+    \x9d\x01\x12\x15\n\r\n\x05\x04\x11\x02\x01\x03\x12\x04\x9d\x01\x18\x19\n\
+    \x0c\n\x04\x04\x11\x02\x02\x12\x04\x9e\x01\x02\x1e\n\r\n\x05\x04\x11\x02\
+    \x02\x04\x12\x04\x9e\x01\x02\n\n\r\n\x05\x04\x11\x02\x02\x05\x12\x04\x9e\
+    \x01\x0b\x11\n\r\n\x05\x04\x11\x02\x02\x01\x12\x04\x9e\x01\x12\x19\n\r\n\
+    \x05\x04\x11\x02\x02\x03\x12\x04\x9e\x01\x1c\x1d\n\x0e\n\x04\x04\x11\x08\
+    \0\x12\x06\x9f\x01\x02\xaa\x01\x03\n\r\n\x05\x04\x11\x08\0\x01\x12\x04\
+    \x9f\x01\x08\x0f\n\x0c\n\x04\x04\x11\x02\x03\x12\x04\xa0\x01\x04\x1a\n\r\
+    \n\x05\x04\x11\x02\x03\x06\x12\x04\xa0\x01\x04\x0e\n\r\n\x05\x04\x11\x02\
+    \x03\x01\x12\x04\xa0\x01\x0f\x15\n\r\n\x05\x04\x11\x02\x03\x03\x12\x04\
+    \xa0\x01\x18\x19\n\x0c\n\x04\x04\x11\x02\x04\x12\x04\xa1\x01\x04\x18\n\r\
+    \n\x05\x04\x11\x02\x04\x06\x12\x04\xa1\x01\x04\x0b\n\r\n\x05\x04\x11\x02\
+    \x04\x01\x12\x04\xa1\x01\x0c\x13\n\r\n\x05\x04\x11\x02\x04\x03\x12\x04\
+    \xa1\x01\x16\x17\n\x0c\n\x04\x04\x11\x02\x05\x12\x04\xa2\x01\x04%\n\r\n\
+    \x05\x04\x11\x02\x05\x06\x12\x04\xa2\x01\x04\x11\n\r\n\x05\x04\x11\x02\
+    \x05\x01\x12\x04\xa2\x01\x12\x20\n\r\n\x05\x04\x11\x02\x05\x03\x12\x04\
+    \xa2\x01#$\n\x0c\n\x04\x04\x11\x02\x06\x12\x04\xa3\x01\x04!\n\r\n\x05\
+    \x04\x11\x02\x06\x06\x12\x04\xa3\x01\x04\x0f\n\r\n\x05\x04\x11\x02\x06\
+    \x01\x12\x04\xa3\x01\x10\x1c\n\r\n\x05\x04\x11\x02\x06\x03\x12\x04\xa3\
+    \x01\x1f\x20\n\x0c\n\x04\x04\x11\x02\x07\x12\x04\xa4\x01\x04\x1a\n\r\n\
+    \x05\x04\x11\x02\x07\x06\x12\x04\xa4\x01\x04\x0c\n\r\n\x05\x04\x11\x02\
+    \x07\x01\x12\x04\xa4\x01\r\x15\n\r\n\x05\x04\x11\x02\x07\x03\x12\x04\xa4\
+    \x01\x18\x19\n\x0c\n\x04\x04\x11\x02\x08\x12\x04\xa5\x01\x04\x1c\n\r\n\
+    \x05\x04\x11\x02\x08\x06\x12\x04\xa5\x01\x04\r\n\r\n\x05\x04\x11\x02\x08\
+    \x01\x12\x04\xa5\x01\x0e\x17\n\r\n\x05\x04\x11\x02\x08\x03\x12\x04\xa5\
+    \x01\x1a\x1b\n\x0c\n\x04\x04\x11\x02\t\x12\x04\xa6\x01\x04\x1c\n\r\n\x05\
+    \x04\x11\x02\t\x06\x12\x04\xa6\x01\x04\x0c\n\r\n\x05\x04\x11\x02\t\x01\
+    \x12\x04\xa6\x01\r\x16\n\r\n\x05\x04\x11\x02\t\x03\x12\x04\xa6\x01\x19\
+    \x1b\n\x0c\n\x04\x04\x11\x02\n\x12\x04\xa7\x01\x04\x1a\n\r\n\x05\x04\x11\
+    \x02\n\x06\x12\x04\xa7\x01\x04\x0b\n\r\n\x05\x04\x11\x02\n\x01\x12\x04\
+    \xa7\x01\x0c\x14\n\r\n\x05\x04\x11\x02\n\x03\x12\x04\xa7\x01\x17\x19\n\
+    \x0c\n\x04\x04\x11\x02\x0b\x12\x04\xa8\x01\x04\x1c\n\r\n\x05\x04\x11\x02\
+    \x0b\x06\x12\x04\xa8\x01\x04\x0c\n\r\n\x05\x04\x11\x02\x0b\x01\x12\x04\
+    \xa8\x01\r\x16\n\r\n\x05\x04\x11\x02\x0b\x03\x12\x04\xa8\x01\x19\x1b\n\
+    \x0c\n\x04\x04\x11\x02\x0c\x12\x04\xa9\x01\x04*\n\r\n\x05\x04\x11\x02\
+    \x0c\x06\x12\x04\xa9\x01\x04\x13\n\r\n\x05\x04\x11\x02\x0c\x01\x12\x04\
+    \xa9\x01\x14$\n\r\n\x05\x04\x11\x02\x0c\x03\x12\x04\xa9\x01')\n\x95\x01\
+    \n\x04\x04\x11\x02\r\x12\x04\xad\x01\x02!\x1a\x86\x01\x20Signed\x20by\
+    \x20the\x20member\x20the\x20rumor\x20is\x20about,\x20or\x20the\x20operat\
+    or\x20which\x20sent\x20it,\x20and\x20carried\x20along\n\x20unchanged\x20\
+    as\x20the\x20rumor\x20is\x20gossiped\x20onward\n\n\r\n\x05\x04\x11\x02\r\
+    \x04\x12\x04\xad\x01\x02\n\n\r\n\x05\x04\x11\x02\r\x06\x12\x04\xad\x01\
+    \x0b\x14\n\r\n\x05\x04\x11\x02\r\x01\x12\x04\xad\x01\x15\x1b\n\r\n\x05\
+    \x04\x11\x02\r\x03\x12\x04\xad\x01\x1e\x20\n\x0c\n\x02\x04\x12\x12\x06\
+    \xb0\x01\0\xb4\x01\x01\n\x0b\n\x03\x04\x12\x01\x12\x04\xb0\x01\x08\x11\n\
+    \x0c\n\x04\x04\x12\x02\0\x12\x04\xb1\x01\x02\x1d\n\r\n\x05\x04\x12\x02\0\
+    \x04\x12\x04\xb1\x01\x02\n\n\r\n\x05\x04\x12\x02\0\x05\x12\x04\xb1\x01\
+    \x0b\x11\n\r\n\x05\x04\x12\x02\0\x01\x12\x04\xb1\x01\x12\x18\n\r\n\x05\
+    \x04\x12\x02\0\x03\x12\x04\xb1\x01\x1b\x1c\n\x0c\n\x04\x04\x12\x02\x01\
+    \x12\x04\xb2\x01\x02\x20\n\r\n\x05\x04\x12\x02\x01\x04\x12\x04\xb2\x01\
+    \x02\n\n\r\n\x05\x04\x12\x02\x01\x05\x12\x04\xb2\x01\x0b\x10\n\r\n\x05\
+    \x04\x12\x02\x01\x01\x12\x04\xb2\x01\x11\x1b\n\r\n\x05\x04\x12\x02\x01\
+    \x03\x12\x04\xb2\x01\x1e\x1f\n\x0c\n\x04\x04\x12\x02\x02\x12\x04\xb3\x01\
+    \x02\x1f\n\r\n\x05\x04\x12\x02\x02\x04\x12\x04\xb3\x01\x02\n\n\r\n\x05\
+    \x04\x12\x02\x02\x05\x12\x04\xb3\x01\x0b\x10\n\r\n\x05\x04\x12\x02\x02\
+    \x01\x12\x04\xb3\x01\x11\x1a\n\r\n\x05\x04\x12\x02\x02\x03\x12\x04\xb3\
+    \x01\x1d\x1e\n\x0c\n\x02\x04\x13\x12\x06\xb6\x01\0\xbc\x01\x01\n\x0b\n\
+    \x03\x04\x13\x01\x12\x04\xb6\x01\x08\x0c\n\x0c\n\x04\x04\x13\x02\0\x12\
+    \x04\xb7\x01\x02\x1e\n\r\n\x05\x04\x13\x02\0\x04\x12\x04\xb7\x01\x02\n\n\
+    \r\n\x05\x04\x13\x02\0\x05\x12\x04\xb7\x01\x0b\x0f\n\r\n\x05\x04\x13\x02\
+    \0\x01\x12\x04\xb7\x01\x10\x19\n\r\n\x05\x04\x13\x02\0\x03\x12\x04\xb7\
+    \x01\x1c\x1d\n\x0c\n\x04\x04\x13\x02\x01\x12\x04\xb8\x01\x02\x1b\n\r\n\
+    \x05\x04\x13\x02\x01\x04\x12\x04\xb8\x01\x02\n\n\r\n\x05\x04\x13\x02\x01\
+    \x05\x12\x04\xb8\x01\x0b\x10\n\r\n\x05\x04\x13\x02\x01\x01\x12\x04\xb8\
+    \x01\x11\x16\n\r\n\x05\x04\x13\x02\x01\x03\x12\x04\xb8\x01\x19\x1a\n\x0c\
+    \n\x04\x04\x13\x02\x02\x12\x04\xb9\x01\x02\x1d\n\r\n\x05\x04\x13\x02\x02\
+    \x04\x12\x04\xb9\x01\x02\n\n\r\n\x05\x04\x13\x02\x02\x05\x12\x04\xb9\x01\
+    \x0b\x10\n\r\n\x05\x04\x13\x02\x02\x01\x12\x04\xb9\x01\x11\x18\n\r\n\x05\
+    \x04\x13\x02\x02\x03\x12\x04\xb9\x01\x1b\x1c\n\x0c\n\x04\x04\x13\x02\x03\
+    \x12\x04\xba\x01\x02#\n\r\n\x05\x04\x13\x02\x03\x04\x12\x04\xba\x01\x02\
+    \n\n\r\n\x05\x04\x13\x02\x03\x05\x12\x04\xba\x01\x0b\x11\n\r\n\x05\x04\
+    \x13\x02\x03\x01\x12\x04\xba\x01\x12\x1e\n\r\n\x05\x04\x13\x02\x03\x03\
+    \x12\x04\xba\x01!\"\n\x0c\n\x04\x04\x13\x02\x04\x12\x04\xbb\x01\x02#\n\r\
+    \n\x05\x04\x13\x02\x04\x04\x12\x04\xbb\x01\x02\n\n\r\n\x05\x04\x13\x02\
+    \x04\x06\x12\x04\xbb\x01\x0b\x14\n\r\n\x05\x04\x13\x02\x04\x01\x12\x04\
+    \xbb\x01\x15\x1e\n\r\n\x05\x04\x13\x02\x04\x03\x12\x04\xbb\x01!\"\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -16,6 +16,7 @@ use std::fs::{self, File, OpenOptions};
 use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
 use std::mem;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, UNIX_EPOCH};
 
 use byteorder::{ByteOrder, LittleEndian};
 use protobuf::{self, Message};
@@ -23,12 +24,13 @@ use rand::{Rng, thread_rng};
 
 use error::{Result, Error};
 use member::{Health, Member, MemberList};
-use message::swim::Membership as ProtoMembership;
+use message::swim::{Membership as ProtoMembership, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
 use rumor::{Election, ElectionUpdate, Rumor, RumorStore, Service, ServiceConfig, ServiceFile,
-            Departure};
+            Departure, KeyValue, ShardMap};
 use server::Server;
 
-const HEADER_VERSION: u8 = 2;
+const HEADER_VERSION: u8 = 5;
 
 /// A versioned binary file containing rumors exchanged by the butterfly server which have
 /// been periodically persisted to disk.
@@ -39,6 +41,8 @@ const HEADER_VERSION: u8 = 2;
 /// * Header Version - 1 byte
 /// * Header Body - Variable bytes - see Header
 /// * Rumors - Variable bytes
+/// * Tombstones - Variable bytes; each is the time it expires, in seconds since the epoch,
+///   followed by the expired rumor
 #[derive(Debug)]
 pub struct DatFile {
     header: Header,
@@ -235,6 +239,113 @@ impl DatFile {
             }
         }
 
+        if version[0] >= 3 {
+            debug!(
+                "Reading shard map rumors list from {}",
+                self.path().display()
+            );
+            bytes_read = 0;
+            loop {
+                if bytes_read >= self.header.shard_map_len {
+                    break;
+                }
+                reader.read_exact(&mut size_buf).map_err(|err| {
+                    Error::DatFileIO(self.path.clone(), err)
+                })?;
+                let rumor_size = LittleEndian::read_u64(&size_buf);
+                rumor_buf.resize(rumor_size as usize, 0);
+                reader.read_exact(&mut rumor_buf).map_err(|err| {
+                    Error::DatFileIO(self.path.clone(), err)
+                })?;
+                let rumor = ShardMap::from_bytes(&rumor_buf)?;
+                server.insert_shard_map(rumor);
+                bytes_read += size_buf.len() as u64 + rumor_size;
+            }
+        }
+
+        if version[0] >= 4 {
+            debug!(
+                "Reading key/value rumors list from {}",
+                self.path().display()
+            );
+            bytes_read = 0;
+            loop {
+                if bytes_read >= self.header.key_value_len {
+                    break;
+                }
+                reader.read_exact(&mut size_buf).map_err(|err| {
+                    Error::DatFileIO(self.path.clone(), err)
+                })?;
+                let rumor_size = LittleEndian::read_u64(&size_buf);
+                rumor_buf.resize(rumor_size as usize, 0);
+                reader.read_exact(&mut rumor_buf).map_err(|err| {
+                    Error::DatFileIO(self.path.clone(), err)
+                })?;
+                let rumor = KeyValue::from_bytes(&rumor_buf)?;
+                server.insert_key_value(rumor);
+                bytes_read += size_buf.len() as u64 + rumor_size;
+            }
+        }
+
+        if version[0] >= 5 {
+            debug!("Reading tombstones from {}", self.path().display());
+            let mut expires_buf = [0; 8];
+            bytes_read = 0;
+            loop {
+                if bytes_read >= self.header.tombstone_len {
+                    break;
+                }
+                reader.read_exact(&mut expires_buf).map_err(|err| {
+                    Error::DatFileIO(self.path.clone(), err)
+                })?;
+                let expires = UNIX_EPOCH +
+                    Duration::from_secs(LittleEndian::read_u64(&expires_buf));
+                reader.read_exact(&mut size_buf).map_err(|err| {
+                    Error::DatFileIO(self.path.clone(), err)
+                })?;
+                let rumor_size = LittleEndian::read_u64(&size_buf);
+                rumor_buf.resize(rumor_size as usize, 0);
+                reader.read_exact(&mut rumor_buf).map_err(|err| {
+                    Error::DatFileIO(self.path.clone(), err)
+                })?;
+                let proto = protobuf::parse_from_bytes::<ProtoRumor>(&rumor_buf)?;
+                match proto.get_field_type() {
+                    ProtoRumor_Type::Service => {
+                        server.service_store.insert_tombstone(Service::from(proto), expires)
+                    }
+                    ProtoRumor_Type::ServiceConfig => {
+                        server.service_config_store.insert_tombstone(
+                            ServiceConfig::from(proto),
+                            expires,
+                        )
+                    }
+                    ProtoRumor_Type::ServiceFile => {
+                        server.service_file_store.insert_tombstone(
+                            ServiceFile::from(proto),
+                            expires,
+                        )
+                    }
+                    ProtoRumor_Type::Election => {
+                        server.election_store.insert_tombstone(Election::from(proto), expires)
+                    }
+                    ProtoRumor_Type::ElectionUpdate => {
+                        server.update_store.insert_tombstone(
+                            ElectionUpdate::from(proto),
+                            expires,
+                        )
+                    }
+                    ProtoRumor_Type::ShardMap => {
+                        server.shard_map_store.insert_tombstone(ShardMap::from(proto), expires)
+                    }
+                    ProtoRumor_Type::KeyValue => {
+                        server.key_value_store.insert_tombstone(KeyValue::from(proto), expires)
+                    }
+                    kind => debug!("Skipping tombstone of unexpected kind {:?}", kind),
+                }
+                bytes_read += expires_buf.len() as u64 + size_buf.len() as u64 + rumor_size;
+            }
+        }
+
         Ok(())
     }
 
@@ -268,6 +379,15 @@ impl DatFile {
             header.election_len = self.write_rumor_store(&mut writer, &server.election_store)?;
             header.update_len = self.write_rumor_store(&mut writer, &server.update_store)?;
             header.departure_len = self.write_rumor_store(&mut writer, &server.departure_store)?;
+            header.shard_map_len = self.write_rumor_store(&mut writer, &server.shard_map_store)?;
+            header.key_value_len = self.write_rumor_store(&mut writer, &server.key_value_store)?;
+            header.tombstone_len = self.write_tombstones(&mut writer, &server.service_store)? +
+                self.write_tombstones(&mut writer, &server.service_config_store)? +
+                self.write_tombstones(&mut writer, &server.service_file_store)? +
+                self.write_tombstones(&mut writer, &server.election_store)? +
+                self.write_tombstones(&mut writer, &server.update_store)? +
+                self.write_tombstones(&mut writer, &server.shard_map_store)? +
+                self.write_tombstones(&mut writer, &server.key_value_store)?;
             writer.seek(SeekFrom::Start(1)).map_err(|err| {
                 Error::DatFileIO(self.path.clone(), err)
             })?;
@@ -331,6 +451,21 @@ impl DatFile {
         self.update_offset() + self.header.update_len
     }
 
+    #[allow(dead_code)]
+    fn shard_map_offset(&self) -> u64 {
+        self.departure_offset() + self.header.departure_len
+    }
+
+    #[allow(dead_code)]
+    fn key_value_offset(&self) -> u64 {
+        self.shard_map_offset() + self.header.shard_map_len
+    }
+
+    #[allow(dead_code)]
+    fn tombstone_offset(&self) -> u64 {
+        self.key_value_offset() + self.header.key_value_len
+    }
+
     fn write_header<W>(&self, writer: &mut W, header: &Header) -> Result<usize>
     where
         W: Write,
@@ -394,6 +529,35 @@ impl DatFile {
         Ok(total)
     }
 
+    fn write_tombstones<T, W>(&self, writer: &mut W, store: &RumorStore<T>) -> Result<u64>
+    where
+        T: Rumor,
+        W: Write,
+    {
+        let mut total = 0;
+        let mut expires = [0; 8];
+        for member in store
+            .tombstones
+            .read()
+            .expect("Rumor store tombstones lock poisoned")
+            .values()
+        {
+            for tombstone in member.values() {
+                let secs = tombstone
+                    .expires
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or(Duration::from_secs(0))
+                    .as_secs();
+                LittleEndian::write_u64(&mut expires, secs);
+                total += writer.write(&expires).map_err(|err| {
+                    Error::DatFileIO(self.path.clone(), err)
+                })? as u64;
+                total += self.write_rumor(writer, &tombstone.rumor)?;
+            }
+        }
+        Ok(total)
+    }
+
     fn write_rumor<T, W>(&self, writer: &mut W, rumor: &T) -> Result<u64>
     where
         T: Rumor,
@@ -426,6 +590,9 @@ pub struct Header {
     pub election_len: u64,
     pub update_len: u64,
     pub departure_len: u64,
+    pub shard_map_len: u64,
+    pub key_value_len: u64,
+    pub tombstone_len: u64,
 }
 
 impl Header {
@@ -434,7 +601,10 @@ impl Header {
         R: Read,
     {
         let mut bytes = match version {
-            1 => vec![0; mem::size_of::<Self>()],
+            1 => vec![0; 48],
+            2 => vec![0; 64],
+            3 => vec![0; 72],
+            4 => vec![0; 80],
             _ => vec![0; mem::size_of::<Self>() + 8],
         };
         reader.read_exact(&mut bytes)?;
@@ -458,6 +628,60 @@ impl Header {
                         election_len: LittleEndian::read_u64(&bytes[32..40]),
                         update_len: LittleEndian::read_u64(&bytes[40..48]),
                         departure_len: 0,
+                        shard_map_len: 0,
+                        key_value_len: 0,
+                        tombstone_len: 0,
+                    },
+                )
+            }
+            2 => {
+                (
+                    LittleEndian::read_u64(&bytes[0..8]),
+                    Header {
+                        member_len: LittleEndian::read_u64(&bytes[8..16]),
+                        service_len: LittleEndian::read_u64(&bytes[16..24]),
+                        service_config_len: LittleEndian::read_u64(&bytes[24..32]),
+                        service_file_len: LittleEndian::read_u64(&bytes[32..40]),
+                        election_len: LittleEndian::read_u64(&bytes[40..48]),
+                        update_len: LittleEndian::read_u64(&bytes[48..56]),
+                        departure_len: LittleEndian::read_u64(&bytes[56..64]),
+                        shard_map_len: 0,
+                        key_value_len: 0,
+                        tombstone_len: 0,
+                    },
+                )
+            }
+            3 => {
+                (
+                    LittleEndian::read_u64(&bytes[0..8]),
+                    Header {
+                        member_len: LittleEndian::read_u64(&bytes[8..16]),
+                        service_len: LittleEndian::read_u64(&bytes[16..24]),
+                        service_config_len: LittleEndian::read_u64(&bytes[24..32]),
+                        service_file_len: LittleEndian::read_u64(&bytes[32..40]),
+                        election_len: LittleEndian::read_u64(&bytes[40..48]),
+                        update_len: LittleEndian::read_u64(&bytes[48..56]),
+                        departure_len: LittleEndian::read_u64(&bytes[56..64]),
+                        shard_map_len: LittleEndian::read_u64(&bytes[64..72]),
+                        key_value_len: 0,
+                        tombstone_len: 0,
+                    },
+                )
+            }
+            4 => {
+                (
+                    LittleEndian::read_u64(&bytes[0..8]),
+                    Header {
+                        member_len: LittleEndian::read_u64(&bytes[8..16]),
+                        service_len: LittleEndian::read_u64(&bytes[16..24]),
+                        service_config_len: LittleEndian::read_u64(&bytes[24..32]),
+                        service_file_len: LittleEndian::read_u64(&bytes[32..40]),
+                        election_len: LittleEndian::read_u64(&bytes[40..48]),
+                        update_len: LittleEndian::read_u64(&bytes[48..56]),
+                        departure_len: LittleEndian::read_u64(&bytes[56..64]),
+                        shard_map_len: LittleEndian::read_u64(&bytes[64..72]),
+                        key_value_len: LittleEndian::read_u64(&bytes[72..80]),
+                        tombstone_len: 0,
                     },
                 )
             }
@@ -480,6 +704,9 @@ impl Header {
                         election_len: LittleEndian::read_u64(&bytes[40..48]),
                         update_len: LittleEndian::read_u64(&bytes[48..56]),
                         departure_len: LittleEndian::read_u64(&bytes[56..64]),
+                        shard_map_len: LittleEndian::read_u64(&bytes[64..72]),
+                        key_value_len: LittleEndian::read_u64(&bytes[72..80]),
+                        tombstone_len: LittleEndian::read_u64(&bytes[80..88]),
                     },
                 )
             }
@@ -498,6 +725,9 @@ impl Header {
         LittleEndian::write_u64(&mut bytes[40..48], self.election_len);
         LittleEndian::write_u64(&mut bytes[48..56], self.update_len);
         LittleEndian::write_u64(&mut bytes[56..64], self.departure_len);
+        LittleEndian::write_u64(&mut bytes[64..72], self.shard_map_len);
+        LittleEndian::write_u64(&mut bytes[72..80], self.key_value_len);
+        LittleEndian::write_u64(&mut bytes[80..88], self.tombstone_len);
         Ok(bytes)
     }
 }
@@ -517,9 +747,52 @@ mod tests {
         original.service_file_len = rand::random::<u64>();
         original.election_len = rand::random::<u64>();
         original.update_len = rand::random::<u64>();
+        original.departure_len = rand::random::<u64>();
+        original.shard_map_len = rand::random::<u64>();
+        original.key_value_len = rand::random::<u64>();
+        original.tombstone_len = rand::random::<u64>();
         let bytes = original.write_to_bytes().unwrap();
         let (_size_of_header, restored) = Header::from_bytes(&bytes, HEADER_VERSION);
         assert_eq!(bytes.len(), mem::size_of::<Header>() + 8);
         assert_eq!(original, restored);
     }
+
+    #[test]
+    fn read_version_2_header() {
+        let mut original = Header::default();
+        original.member_len = rand::random::<u64>();
+        original.departure_len = rand::random::<u64>();
+        let mut bytes = original.write_to_bytes().unwrap();
+        bytes.truncate(64);
+        LittleEndian::write_u64(&mut bytes[0..8], 64);
+        let (size_of_header, restored) = Header::from_file(&mut &bytes[..], 2).unwrap();
+        assert_eq!(size_of_header, 64);
+        assert_eq!(original, restored);
+    }
+
+    #[test]
+    fn read_version_3_header() {
+        let mut original = Header::default();
+        original.member_len = rand::random::<u64>();
+        original.shard_map_len = rand::random::<u64>();
+        let mut bytes = original.write_to_bytes().unwrap();
+        bytes.truncate(72);
+        LittleEndian::write_u64(&mut bytes[0..8], 72);
+        let (size_of_header, restored) = Header::from_file(&mut &bytes[..], 3).unwrap();
+        assert_eq!(size_of_header, 72);
+        assert_eq!(original, restored);
+    }
+
+    #[test]
+    fn read_version_4_header() {
+        let mut original = Header::default();
+        original.member_len = rand::random::<u64>();
+        original.key_value_len = rand::random::<u64>();
+        let mut bytes = original.write_to_bytes().unwrap();
+        bytes.truncate(80);
+        LittleEndian::write_u64(&mut bytes[0..8], 80);
+        let (size_of_header, restored) = Header::from_file(&mut &bytes[..], 4).unwrap();
+        assert_eq!(size_of_header, 80);
+        assert_eq!(original, restored);
+    }
 }
@@ -0,0 +1,304 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The FederatedMember rumor.
+//!
+//! Carries the health of a member of another ring, as seen by that ring. Gateways relay these
+//! alongside the service rumors of federated service groups, so a ring knows which members of its
+//! peer rings are alive without ever probing them itself.
+//!
+//! The gateway of our ring receiving a relay stamps it with the time it arrived, by its own clock,
+//! and passes it on to the rest of our ring as its own rumor. Relays repeating the health we hold
+//! only refresh the stamp. A member whose health stops being relayed, because its ring or the
+//! gateways in between went away, is made suspect by that gateway after a suspicion window, the
+//! same way a member of our ring that stops answering probes would be, and departed after another.
+//! The rest of our ring follows the gateway.
+
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+
+use protobuf::{self, Message, ProtobufEnum};
+
+use error::Result;
+use member::{Health, Member};
+use message::swim::{FederatedMember as ProtoFederatedMember, Membership as ProtoMembership,
+                    Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FederatedMember(ProtoRumor);
+
+impl PartialOrd for FederatedMember {
+    fn partial_cmp(&self, other: &FederatedMember) -> Option<Ordering> {
+        if self.get_ring() != other.get_ring() || self.member_id() != other.member_id() {
+            None
+        } else {
+            let member = self.get_membership().get_member();
+            let other_member = other.get_membership().get_member();
+            let health = self.get_membership().get_health().value();
+            let other_health = other.get_membership().get_health().value();
+            // Only a change of health makes a newer relay a newer rumor
+            let relayed_at = if health == other_health {
+                Ordering::Equal
+            } else {
+                self.get_relayed_at().cmp(&other.get_relayed_at())
+            };
+            Some(
+                member
+                    .get_incarnation()
+                    .cmp(&other_member.get_incarnation())
+                    .then(relayed_at)
+                    .then(health.cmp(&other_health)),
+            )
+        }
+    }
+}
+
+impl PartialEq for FederatedMember {
+    fn eq(&self, other: &FederatedMember) -> bool {
+        self.get_ring() == other.get_ring() && self.get_membership() == other.get_membership()
+    }
+}
+
+impl From<ProtoRumor> for FederatedMember {
+    fn from(pr: ProtoRumor) -> FederatedMember {
+        FederatedMember(pr)
+    }
+}
+
+impl From<FederatedMember> for ProtoRumor {
+    fn from(federated_member: FederatedMember) -> ProtoRumor {
+        federated_member.0
+    }
+}
+
+impl Deref for FederatedMember {
+    type Target = ProtoFederatedMember;
+
+    fn deref(&self) -> &ProtoFederatedMember {
+        self.0.get_federated_member()
+    }
+}
+
+impl DerefMut for FederatedMember {
+    fn deref_mut(&mut self) -> &mut ProtoFederatedMember {
+        self.0.mut_federated_member()
+    }
+}
+
+impl FederatedMember {
+    /// Creates a new FederatedMember, relaying the given membership of a member of `ring`.
+    pub fn new<S1, S2>(from_id: S1, ring: S2, membership: ProtoMembership) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let mut rumor = ProtoRumor::new();
+        rumor.set_from_id(from_id.into());
+        rumor.set_field_type(ProtoRumor_Type::FederatedMember);
+
+        let mut proto = ProtoFederatedMember::new();
+        proto.set_ring(ring.into());
+        proto.set_membership(membership);
+
+        rumor.set_federated_member(proto);
+        FederatedMember(rumor)
+    }
+
+    /// The id of the member this rumor came from: the gateway of the peer ring relaying it, or
+    /// the gateway of our ring it arrived at.
+    pub fn from_id(&self) -> &str {
+        self.0.get_from_id()
+    }
+
+    /// The id of the member this rumor is about.
+    pub fn member_id(&self) -> &str {
+        self.get_membership().get_member().get_id()
+    }
+
+    pub fn member(&self) -> Member {
+        Member::from(self.get_membership().get_member())
+    }
+
+    pub fn health(&self) -> Health {
+        Health::from(self.get_membership().get_health())
+    }
+
+    pub fn set_health(&mut self, health: Health) {
+        self.mut_membership().set_health(health.into());
+    }
+
+    /// The health of the member at `now`, in seconds since the epoch by the clock the relay was
+    /// stamped with, given the relayed health and how long ago it was relayed. Unlike a
+    /// confirmation relayed by the member's own ring, which may be a partition within that ring,
+    /// a member whose relays stopped for long enough is as good as departed as far as we are
+    /// concerned. Unstamped rumors keep the relayed health.
+    pub fn health_at(&self, now: u64, suspicion_window: u64) -> Health {
+        let health = self.health();
+        if self.get_relayed_at() == 0 {
+            return health;
+        }
+        let silence = now.saturating_sub(self.get_relayed_at());
+        match health {
+            Health::Alive | Health::Suspect | Health::Confirmed
+                if silence > 2 * suspicion_window => Health::Departed,
+            Health::Alive if silence > suspicion_window => Health::Suspect,
+            health => health,
+        }
+    }
+}
+
+impl Rumor for FederatedMember {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let rumor = protobuf::parse_from_bytes::<ProtoRumor>(bytes)?;
+        Ok(FederatedMember::from(rumor))
+    }
+
+    /// Follows the member list: the newer incarnation wins, and within an incarnation, the newer
+    /// relay of a different health, or else the worse health, wins. A member refutes its own
+    /// suspicion or confirmation with a new incarnation. A relay of the same health only
+    /// refreshes the stamp, which isn't a change worth gossiping.
+    fn merge(&mut self, mut other: FederatedMember) -> bool {
+        if *self >= other {
+            if *self == other && other.get_relayed_at() > self.get_relayed_at() {
+                self.set_relayed_at(other.get_relayed_at());
+                self.0.set_from_id(other.0.take_from_id());
+            }
+            false
+        } else {
+            mem::swap(self, &mut other);
+            true
+        }
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::FederatedMember
+    }
+
+    fn id(&self) -> &str {
+        self.member_id()
+    }
+
+    fn key(&self) -> &str {
+        self.get_ring()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(self.0.write_to_bytes()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::FederatedMember;
+    use member::{Health, Member};
+    use message::swim::Membership as ProtoMembership;
+    use rumor::Rumor;
+
+    fn create_federated_member(ring: &str, incarnation: u64, health: Health) -> FederatedMember {
+        let mut member = Member::default();
+        member.set_id(String::from("neurosis"));
+        member.set_incarnation(incarnation);
+        let mut membership = ProtoMembership::new();
+        membership.set_member(member.proto);
+        membership.set_health(health.into());
+        FederatedMember::new("gateway", ring, membership)
+    }
+
+    fn relayed_at(mut federated_member: FederatedMember, relayed_at: u64) -> FederatedMember {
+        federated_member.set_relayed_at(relayed_at);
+        federated_member
+    }
+
+    #[test]
+    fn identical_federated_members_are_equal() {
+        let f1 = create_federated_member("us-east", 1, Health::Alive);
+        let f2 = create_federated_member("us-east", 1, Health::Alive);
+        assert_eq!(f1, f2);
+        assert_eq!(f1.partial_cmp(&f2), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn federated_members_of_different_rings_are_not_ordered() {
+        let f1 = create_federated_member("us-east", 1, Health::Alive);
+        let f2 = create_federated_member("eu-west", 1, Health::Alive);
+        assert_eq!(f1.partial_cmp(&f2), None);
+    }
+
+    #[test]
+    fn merge_prefers_worse_health_within_an_incarnation() {
+        let mut f1 = create_federated_member("us-east", 1, Health::Alive);
+        let f2 = create_federated_member("us-east", 1, Health::Confirmed);
+        assert_eq!(f1.merge(f2), true);
+        assert_eq!(f1.health(), Health::Confirmed);
+        assert_eq!(
+            f1.merge(create_federated_member("us-east", 1, Health::Suspect)),
+            false
+        );
+    }
+
+    #[test]
+    fn merge_prefers_a_newer_relay_within_an_incarnation() {
+        let mut f1 = relayed_at(create_federated_member("us-east", 1, Health::Suspect), 100);
+        let f2 = relayed_at(create_federated_member("us-east", 1, Health::Alive), 105);
+        assert_eq!(f1.merge(f2), true);
+        assert_eq!(f1.health(), Health::Alive);
+        assert_eq!(f1.get_relayed_at(), 105);
+    }
+
+    #[test]
+    fn merge_only_refreshes_the_stamp_of_a_relay_of_the_same_health() {
+        let mut f1 = relayed_at(create_federated_member("us-east", 1, Health::Alive), 100);
+        let f2 = relayed_at(create_federated_member("us-east", 1, Health::Alive), 105);
+        assert_eq!(f1, f2);
+        assert_eq!(f1.merge(f2), false);
+        assert_eq!(f1.get_relayed_at(), 105);
+        let f3 = relayed_at(create_federated_member("us-east", 1, Health::Alive), 101);
+        assert_eq!(f1.merge(f3), false);
+        assert_eq!(f1.get_relayed_at(), 105);
+    }
+
+    #[test]
+    fn health_expires_once_relays_stop() {
+        let f1 = relayed_at(create_federated_member("us-east", 1, Health::Alive), 100);
+        assert_eq!(f1.health_at(130, 30), Health::Alive);
+        assert_eq!(f1.health_at(131, 30), Health::Suspect);
+        assert_eq!(f1.health_at(161, 30), Health::Departed);
+
+        let confirmed = relayed_at(create_federated_member("us-east", 1, Health::Confirmed), 100);
+        assert_eq!(confirmed.health_at(131, 30), Health::Confirmed);
+        assert_eq!(confirmed.health_at(161, 30), Health::Departed);
+
+        let f2 = relayed_at(create_federated_member("us-east", 1, Health::Departed), 100);
+        assert_eq!(f2.health_at(1_000, 30), Health::Departed);
+    }
+
+    #[test]
+    fn health_of_unstamped_relays_does_not_expire() {
+        let f1 = create_federated_member("us-east", 1, Health::Alive);
+        assert_eq!(f1.health_at(1_000, 30), Health::Alive);
+    }
+
+    #[test]
+    fn merge_prefers_a_newer_incarnation() {
+        let mut f1 = create_federated_member("us-east", 1, Health::Confirmed);
+        let f2 = create_federated_member("us-east", 2, Health::Alive);
+        assert_eq!(f1.merge(f2), true);
+        assert_eq!(f1.health(), Health::Alive);
+        assert_eq!(f1.member().get_incarnation(), 2);
+    }
+}
@@ -0,0 +1,205 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The KeyValue rumor.
+//!
+//! Shares a small piece of application state, such as a feature flag or a maintenance-mode
+//! marker, with every member of the ring. Each key belongs to a service group, and the value
+//! with the highest incarnation wins.
+
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+
+use habitat_core::service::ServiceGroup;
+use protobuf::{self, Message};
+
+use error::Result;
+use message::swim::{KeyValue as ProtoKeyValue, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyValue(ProtoRumor);
+
+impl PartialOrd for KeyValue {
+    fn partial_cmp(&self, other: &KeyValue) -> Option<Ordering> {
+        if self.get_service_group() != other.get_service_group() ||
+            self.get_key() != other.get_key()
+        {
+            None
+        } else {
+            Some(
+                self.get_incarnation()
+                    .cmp(&other.get_incarnation())
+                    .then(self.get_value().cmp(other.get_value())),
+            )
+        }
+    }
+}
+
+impl PartialEq for KeyValue {
+    fn eq(&self, other: &KeyValue) -> bool {
+        self.get_service_group() == other.get_service_group() &&
+            self.get_key() == other.get_key() &&
+            self.get_value() == other.get_value() &&
+            self.get_incarnation() == other.get_incarnation()
+    }
+}
+
+impl From<ProtoRumor> for KeyValue {
+    fn from(pr: ProtoRumor) -> KeyValue {
+        KeyValue(pr)
+    }
+}
+
+impl From<KeyValue> for ProtoRumor {
+    fn from(key_value: KeyValue) -> ProtoRumor {
+        key_value.0
+    }
+}
+
+impl Deref for KeyValue {
+    type Target = ProtoKeyValue;
+
+    fn deref(&self) -> &ProtoKeyValue {
+        self.0.get_key_value()
+    }
+}
+
+impl DerefMut for KeyValue {
+    fn deref_mut(&mut self) -> &mut ProtoKeyValue {
+        self.0.mut_key_value()
+    }
+}
+
+impl KeyValue {
+    /// Creates a new KeyValue.
+    pub fn new<S1, S2, S3>(
+        member_id: S1,
+        service_group: ServiceGroup,
+        key: S2,
+        value: S3,
+        incarnation: u64,
+    ) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<String>,
+    {
+        let mut rumor = ProtoRumor::new();
+        let from_id = member_id.into();
+        rumor.set_from_id(from_id.clone());
+        rumor.set_field_type(ProtoRumor_Type::KeyValue);
+
+        let mut proto = ProtoKeyValue::new();
+        proto.set_member_id(from_id);
+        proto.set_service_group(format!("{}", service_group));
+        proto.set_key(key.into());
+        proto.set_value(value.into());
+        proto.set_incarnation(incarnation);
+
+        rumor.set_key_value(proto);
+        KeyValue(rumor)
+    }
+}
+
+impl Rumor for KeyValue {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let rumor = protobuf::parse_from_bytes::<ProtoRumor>(bytes)?;
+        Ok(KeyValue::from(rumor))
+    }
+
+    /// The last writer, by incarnation, wins. Should two writers use the same incarnation, the
+    /// greater value wins, so every member settles on the same value.
+    fn merge(&mut self, mut other: KeyValue) -> bool {
+        if *self >= other {
+            false
+        } else {
+            mem::swap(self, &mut other);
+            true
+        }
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::KeyValue
+    }
+
+    fn id(&self) -> &str {
+        self.get_key()
+    }
+
+    fn key(&self) -> &str {
+        self.get_service_group()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(self.0.write_to_bytes()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use habitat_core::service::ServiceGroup;
+
+    use super::KeyValue;
+    use rumor::Rumor;
+
+    fn create_key_value(key: &str, value: &str, incarnation: u64) -> KeyValue {
+        KeyValue::new(
+            "adam",
+            ServiceGroup::new(None, "neurosis", "production", None).unwrap(),
+            key,
+            value,
+            incarnation,
+        )
+    }
+
+    #[test]
+    fn identical_key_values_are_equal() {
+        let k1 = create_key_value("maintenance", "true", 1);
+        let k2 = create_key_value("maintenance", "true", 1);
+        assert_eq!(k1, k2);
+        assert_eq!(k1.partial_cmp(&k2), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn different_keys_are_not_ordered() {
+        let k1 = create_key_value("maintenance", "true", 1);
+        let k2 = create_key_value("feature", "true", 1);
+        assert_eq!(k1.partial_cmp(&k2), None);
+    }
+
+    #[test]
+    fn merge_chooses_the_higher_incarnation() {
+        let mut k1 = create_key_value("maintenance", "true", 2);
+        let k2 = create_key_value("maintenance", "false", 3);
+        let k2_check = k2.clone();
+        assert_eq!(k1.merge(k2), true);
+        assert_eq!(k1, k2_check);
+        assert_eq!(k1.merge(create_key_value("maintenance", "true", 2)), false);
+    }
+
+    #[test]
+    fn merge_breaks_ties_with_the_value() {
+        let mut k1 = create_key_value("maintenance", "false", 1);
+        let k2 = create_key_value("maintenance", "true", 1);
+        let k2_check = k2.clone();
+        assert_eq!(k1.merge(k2), true);
+        assert_eq!(k1, k2_check);
+        assert_eq!(k1.merge(create_key_value("maintenance", "false", 1)), false);
+    }
+}
@@ -19,19 +19,31 @@
 //!
 //! New rumors need to implement the `From` trait for `RumorKey`, and then can track the arrival of
 //! new rumors, and dispatch them according to their `kind`.
+//!
+//! Expired rumors leave a tombstone behind in their `RumorStore`. Until the tombstone expires in
+//! turn, the rumor is only accepted again if it is newer than the one that expired, so members
+//! which have yet to expire it cannot gossip it back.
 
 pub mod dat_file;
 pub mod departure;
+pub mod federated_member;
 pub mod heat;
+pub mod key_value;
+pub mod ring_key;
 pub mod election;
 pub mod service;
 pub mod service_config;
 pub mod service_file;
+pub mod shard_map;
 
 pub use self::election::{Election, ElectionUpdate};
+pub use self::federated_member::FederatedMember;
+pub use self::key_value::KeyValue;
+pub use self::ring_key::RingKey;
 pub use self::service::Service;
 pub use self::service_config::ServiceConfig;
 pub use self::service_file::ServiceFile;
+pub use self::shard_map::ShardMap;
 pub use self::departure::Departure;
 
 use std::collections::HashMap;
@@ -41,6 +53,7 @@ use std::ops::Deref;
 use std::result;
 use std::sync::{Arc, RwLock};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::SystemTime;
 
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
@@ -102,13 +115,22 @@ impl<'a, T: Rumor> From<&'a T> for RumorKey {
 #[derive(Debug, Clone)]
 pub struct RumorStore<T: Rumor> {
     pub list: Arc<RwLock<HashMap<String, HashMap<String, T>>>>,
+    pub tombstones: Arc<RwLock<HashMap<String, HashMap<String, Tombstone<T>>>>>,
     update_counter: Arc<AtomicUsize>,
 }
 
+/// An expired rumor, kept until `expires` so that it is not resurrected by gossip.
+#[derive(Debug, Clone)]
+pub struct Tombstone<T: Rumor> {
+    pub rumor: T,
+    pub expires: SystemTime,
+}
+
 impl<T: Rumor> Default for RumorStore<T> {
     fn default() -> RumorStore<T> {
         RumorStore {
             list: Arc::new(RwLock::new(HashMap::new())),
+            tombstones: Arc::new(RwLock::new(HashMap::new())),
             update_counter: Arc::new(AtomicUsize::new(0)),
         }
     }
@@ -150,10 +172,14 @@ impl<T: Rumor> RumorStore<T> {
         }
     }
 
-    /// Clear all rumors and reset update counter of RumorStore.
+    /// Clear all rumors and tombstones and reset update counter of RumorStore.
     pub fn clear(&self) -> usize {
         let mut list = self.list.write().expect("Rumor store lock poisoned");
         list.clear();
+        self.tombstones
+            .write()
+            .expect("Rumor store tombstones lock poisoned")
+            .clear();
         self.update_counter.swap(0, Ordering::Relaxed)
     }
 
@@ -179,11 +205,30 @@ impl<T: Rumor> RumorStore<T> {
 
     /// Insert a rumor into the Rumor Store. Returns true if the value didn't exist or if it was
     /// mutated; if nothing changed, returns false.
+    ///
+    /// A rumor which has expired is only inserted again if it is newer than its tombstone.
     pub fn insert(&self, rumor: T) -> bool {
         let mut list = self.list.write().expect("Rumor store lock poisoned");
-        let rumors = list.entry(String::from(rumor.key())).or_insert(
-            HashMap::new(),
-        );
+        let key = String::from(rumor.key());
+        let id = String::from(rumor.id());
+        let rumor = {
+            let mut tombstones = self.tombstones.write().expect(
+                "Rumor store tombstones lock poisoned",
+            );
+            let tombstone = tombstones.get_mut(&key).and_then(|t| t.remove(&id));
+            match tombstone {
+                Some(mut tombstone) => {
+                    if tombstone.rumor.merge(rumor) {
+                        tombstone.rumor
+                    } else {
+                        tombstones.get_mut(&key).unwrap().insert(id, tombstone);
+                        return false;
+                    }
+                }
+                None => rumor,
+            }
+        };
+        let rumors = list.entry(key).or_insert(HashMap::new());
         // Result reveals if there was a change so we can increment the counter if needed.
         let result = match rumors.entry(rumor.id().into()) {
             Entry::Occupied(mut entry) => entry.get_mut().merge(rumor),
@@ -203,6 +248,111 @@ impl<T: Rumor> RumorStore<T> {
         list.get_mut(key).and_then(|r| r.remove(id));
     }
 
+    /// Removes a rumor from the store, leaving a tombstone behind until `expires`. Returns true if
+    /// the rumor was in the store.
+    pub fn expire(&self, key: &str, id: &str, expires: SystemTime) -> bool {
+        let mut list = self.list.write().expect("Rumor store lock poisoned");
+        let rumor = match list.get_mut(key).and_then(|r| r.remove(id)) {
+            Some(rumor) => rumor,
+            None => return false,
+        };
+        if list.get(key).map_or(false, |r| r.is_empty()) {
+            list.remove(key);
+        }
+        self.insert_tombstone(rumor, expires);
+        self.increment_update_counter();
+        true
+    }
+
+    /// Expires every rumor under the given key, returning how many expired.
+    pub fn expire_all(&self, key: &str, expires: SystemTime) -> usize {
+        let mut ids = Vec::new();
+        self.with_rumors(key, |rumor| ids.push(String::from(rumor.id())));
+        ids.iter()
+            .filter(|id| self.expire(key, id, expires))
+            .count()
+    }
+
+    /// Adds a tombstone for an expired rumor, such as one read back from a DatFile.
+    pub fn insert_tombstone(&self, rumor: T, expires: SystemTime) {
+        let mut tombstones = self.tombstones.write().expect(
+            "Rumor store tombstones lock poisoned",
+        );
+        tombstones
+            .entry(String::from(rumor.key()))
+            .or_insert(HashMap::new())
+            .insert(
+                String::from(rumor.id()),
+                Tombstone {
+                    rumor: rumor,
+                    expires: expires,
+                },
+            );
+    }
+
+    /// Drops the tombstones which expired by `now`, returning how many were dropped.
+    pub fn purge_tombstones(&self, now: SystemTime) -> usize {
+        let mut tombstones = self.tombstones.write().expect(
+            "Rumor store tombstones lock poisoned",
+        );
+        let mut purged = 0;
+        for rumors in tombstones.values_mut() {
+            let before = rumors.len();
+            rumors.retain(|_, tombstone| tombstone.expires > now);
+            purged += before - rumors.len();
+        }
+        tombstones.retain(|_, rumors| !rumors.is_empty());
+        purged
+    }
+
+    /// Drops the tombstones of the rumors with the given id under any key, so the rumors of a
+    /// member which was seen alive again can be inserted anew. Returns how many were dropped.
+    pub fn remove_tombstones_by_id(&self, id: &str) -> usize {
+        let mut tombstones = self.tombstones.write().expect(
+            "Rumor store tombstones lock poisoned",
+        );
+        let mut removed = 0;
+        for rumors in tombstones.values_mut() {
+            if rumors.remove(id).is_some() {
+                removed += 1;
+            }
+        }
+        tombstones.retain(|_, rumors| !rumors.is_empty());
+        removed
+    }
+
+    /// Returns the count of all tombstones in this RumorStore.
+    pub fn len_tombstones(&self) -> usize {
+        self.tombstones
+            .read()
+            .expect("Rumor store tombstones lock poisoned")
+            .values()
+            .map(|rumors| rumors.len())
+            .sum()
+    }
+
+    pub fn with_tombstones<F>(&self, mut with_closure: F)
+    where
+        F: FnMut(&Tombstone<T>),
+    {
+        let tombstones = self.tombstones.read().expect(
+            "Rumor store tombstones lock poisoned",
+        );
+        for tombstone in tombstones.values().flat_map(|t| t.values()) {
+            with_closure(tombstone);
+        }
+    }
+
+    pub fn with_tombstone<F>(&self, key: &str, id: &str, mut with_closure: F)
+    where
+        F: FnMut(Option<&Tombstone<T>>),
+    {
+        let tombstones = self.tombstones.read().expect(
+            "Rumor store tombstones lock poisoned",
+        );
+        with_closure(tombstones.get(key).and_then(|t| t.get(id)));
+    }
+
     pub fn with_keys<F>(&self, mut with_closure: F)
     where
         F: FnMut((&String, &HashMap<String, T>)),
@@ -355,6 +505,7 @@ mod tests {
         use super::FakeRumor;
         use rumor::RumorStore;
         use rumor::Rumor;
+        use std::time::{Duration, SystemTime};
         use std::usize;
 
         fn create_rumor_store() -> RumorStore<FakeRumor> {
@@ -454,5 +605,44 @@ mod tests {
             let rs = create_rumor_store();
             rs.with_rumor("bar", "foo", |o| assert!(o.is_none()));
         }
+
+        #[test]
+        fn expire_leaves_a_tombstone() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let (key, id) = (f1.key.clone(), f1.id.clone());
+            rs.insert(f1.clone());
+            let expires = SystemTime::now() + Duration::from_secs(60);
+            assert!(rs.expire(&key, &id, expires));
+            assert_eq!(rs.len(), 0);
+            assert_eq!(rs.len_tombstones(), 1);
+            assert_eq!(rs.get_update_counter(), 2);
+            assert!(!rs.expire(&key, &id, expires));
+        }
+
+        #[test]
+        fn insert_does_not_resurrect_an_expired_rumor() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let (key, id) = (f1.key.clone(), f1.id.clone());
+            rs.insert(f1.clone());
+            rs.expire(&key, &id, SystemTime::now() + Duration::from_secs(60));
+            assert_eq!(rs.insert(f1), false);
+            assert!(!rs.contains_rumor(&key, &id));
+            assert_eq!(rs.len_tombstones(), 1);
+        }
+
+        #[test]
+        fn purge_tombstones_drops_expired_tombstones() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let f2 = FakeRumor::default();
+            let now = SystemTime::now();
+            rs.insert_tombstone(f1.clone(), now);
+            rs.insert_tombstone(f2, now + Duration::from_secs(60));
+            assert_eq!(rs.purge_tombstones(now), 1);
+            assert_eq!(rs.len_tombstones(), 1);
+            assert!(rs.insert(f1));
+        }
     }
 }
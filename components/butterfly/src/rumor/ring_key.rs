@@ -0,0 +1,195 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The RingKey rumor.
+//!
+//! Names the revision of the ring key every member should encrypt with. Members keep decrypting
+//! with any revision of the ring key they hold, so the ring can move to a new revision one member
+//! at a time: distribute the new revision first, then gossip that it is the primary.
+
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+
+use protobuf::{self, Message};
+
+use error::Result;
+use message::swim::{RingKey as ProtoRingKey, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RingKey(ProtoRumor);
+
+impl PartialOrd for RingKey {
+    fn partial_cmp(&self, other: &RingKey) -> Option<Ordering> {
+        if self.get_name() != other.get_name() {
+            None
+        } else {
+            Some(
+                self.get_incarnation()
+                    .cmp(&other.get_incarnation())
+                    .then(self.get_revision().cmp(other.get_revision())),
+            )
+        }
+    }
+}
+
+impl PartialEq for RingKey {
+    fn eq(&self, other: &RingKey) -> bool {
+        self.get_name() == other.get_name() && self.get_revision() == other.get_revision() &&
+            self.get_incarnation() == other.get_incarnation()
+    }
+}
+
+impl From<ProtoRumor> for RingKey {
+    fn from(pr: ProtoRumor) -> RingKey {
+        RingKey(pr)
+    }
+}
+
+impl From<RingKey> for ProtoRumor {
+    fn from(ring_key: RingKey) -> ProtoRumor {
+        ring_key.0
+    }
+}
+
+impl Deref for RingKey {
+    type Target = ProtoRingKey;
+
+    fn deref(&self) -> &ProtoRingKey {
+        self.0.get_ring_key()
+    }
+}
+
+impl DerefMut for RingKey {
+    fn deref_mut(&mut self) -> &mut ProtoRingKey {
+        self.0.mut_ring_key()
+    }
+}
+
+impl RingKey {
+    /// Creates a new RingKey, naming the given revision of the named ring key as the primary.
+    pub fn new<S1, S2, S3>(member_id: S1, name: S2, revision: S3, incarnation: u64) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<String>,
+    {
+        let mut rumor = ProtoRumor::new();
+        let from_id = member_id.into();
+        rumor.set_from_id(from_id.clone());
+        rumor.set_field_type(ProtoRumor_Type::RingKey);
+
+        let mut proto = ProtoRingKey::new();
+        proto.set_member_id(from_id);
+        proto.set_name(name.into());
+        proto.set_revision(revision.into());
+        proto.set_incarnation(incarnation);
+
+        rumor.set_ring_key(proto);
+        RingKey(rumor)
+    }
+
+    /// The name of the primary key with its revision, as `SymKey::name_with_rev` formats it.
+    pub fn name_with_rev(&self) -> String {
+        format!("{}-{}", self.get_name(), self.get_revision())
+    }
+}
+
+impl Rumor for RingKey {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let rumor = protobuf::parse_from_bytes::<ProtoRumor>(bytes)?;
+        Ok(RingKey::from(rumor))
+    }
+
+    /// The newer incarnation wins. Should two rotations be published with the same incarnation,
+    /// the later revision wins.
+    fn merge(&mut self, mut other: RingKey) -> bool {
+        if *self >= other {
+            false
+        } else {
+            mem::swap(self, &mut other);
+            true
+        }
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::RingKey
+    }
+
+    fn id(&self) -> &str {
+        "ring_key"
+    }
+
+    fn key(&self) -> &str {
+        self.get_name()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(self.0.write_to_bytes()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::RingKey;
+    use rumor::Rumor;
+
+    fn create_ring_key(revision: &str, incarnation: u64) -> RingKey {
+        RingKey::new("adam", "wolverine", revision, incarnation)
+    }
+
+    #[test]
+    fn identical_ring_keys_are_equal() {
+        let r1 = create_ring_key("20170101000000", 1);
+        let r2 = create_ring_key("20170101000000", 1);
+        assert_eq!(r1, r2);
+        assert_eq!(r1.partial_cmp(&r2), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn ring_keys_for_different_rings_are_not_ordered() {
+        let r1 = create_ring_key("20170101000000", 1);
+        let r2 = RingKey::new("adam", "hulk", "20170101000000", 1);
+        assert_eq!(r1.partial_cmp(&r2), None);
+    }
+
+    #[test]
+    fn merge_chooses_the_higher_incarnation() {
+        let mut r1 = create_ring_key("20170202000000", 1);
+        let r2 = create_ring_key("20170101000000", 2);
+        let r2_check = r2.clone();
+        assert_eq!(r1.merge(r2), true);
+        assert_eq!(r1, r2_check);
+        assert_eq!(r1.merge(create_ring_key("20170202000000", 1)), false);
+    }
+
+    #[test]
+    fn merge_breaks_ties_with_the_revision() {
+        let mut r1 = create_ring_key("20170101000000", 1);
+        let r2 = create_ring_key("20170202000000", 1);
+        let r2_check = r2.clone();
+        assert_eq!(r1.merge(r2), true);
+        assert_eq!(r1, r2_check);
+    }
+
+    #[test]
+    fn name_with_rev() {
+        let ring_key = create_ring_key("20170101000000", 1);
+        assert_eq!(ring_key.name_with_rev(), "wolverine-20170101000000");
+    }
+}
@@ -25,7 +25,7 @@ use habitat_core::package::Identifiable;
 use protobuf::{self, Message};
 use toml;
 
-pub use message::swim::SysInfo;
+pub use message::swim::{Service_Health, SysInfo};
 use error::Result;
 use message::swim::{Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type, Service as ProtoService};
 use rumor::Rumor;
@@ -0,0 +1,485 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The ShardMap rumor.
+//!
+//! Assigns the members of a sharded or multi-leader service group to shards. New members are
+//! placed on a consistent hash ring of the shards, and a member keeps its shard for as long as it
+//! stays alive, so assignments stay stable as members join and leave. The first member assigned
+//! to a shard leads it. When members are tagged with a zone, leaders are spread across zones.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+
+use habitat_core::service::ServiceGroup;
+use protobuf::{self, Message, RepeatedField};
+
+use error::Result;
+use message::swim::{ShardMap as ProtoShardMap, ShardSlot as ProtoShardSlot,
+                    Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+/// Number of points each shard gets on the hash ring. More points spread new members more evenly
+/// across the shards.
+const RING_POINTS_PER_SHARD: u32 = 64;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ShardMap(ProtoRumor);
+
+impl PartialOrd for ShardMap {
+    fn partial_cmp(&self, other: &ShardMap) -> Option<Ordering> {
+        if self.get_service_group() != other.get_service_group() {
+            None
+        } else {
+            Some(
+                self.get_incarnation()
+                    .cmp(&other.get_incarnation())
+                    .then(self.get_member_id().cmp(other.get_member_id())),
+            )
+        }
+    }
+}
+
+impl PartialEq for ShardMap {
+    fn eq(&self, other: &ShardMap) -> bool {
+        self.get_service_group() == other.get_service_group() &&
+            self.get_member_id() == other.get_member_id() &&
+            self.get_incarnation() == other.get_incarnation() &&
+            self.get_shard_count() == other.get_shard_count() &&
+            self.get_multi_leader() == other.get_multi_leader() &&
+            self.get_slots() == other.get_slots()
+    }
+}
+
+impl From<ProtoRumor> for ShardMap {
+    fn from(pr: ProtoRumor) -> ShardMap {
+        ShardMap(pr)
+    }
+}
+
+impl From<ShardMap> for ProtoRumor {
+    fn from(shard_map: ShardMap) -> ProtoRumor {
+        shard_map.0
+    }
+}
+
+impl Deref for ShardMap {
+    type Target = ProtoShardMap;
+
+    fn deref(&self) -> &ProtoShardMap {
+        self.0.get_shard_map()
+    }
+}
+
+impl DerefMut for ShardMap {
+    fn deref_mut(&mut self) -> &mut ProtoShardMap {
+        self.0.mut_shard_map()
+    }
+}
+
+impl ShardMap {
+    /// Creates a new, empty ShardMap.
+    pub fn new<S1>(member_id: S1, service_group: ServiceGroup, shard_count: u32) -> Self
+    where
+        S1: Into<String>,
+    {
+        let mut rumor = ProtoRumor::new();
+        let from_id = member_id.into();
+        rumor.set_from_id(from_id.clone());
+        rumor.set_field_type(ProtoRumor_Type::ShardMap);
+
+        let mut proto = ProtoShardMap::new();
+        proto.set_member_id(from_id);
+        proto.set_service_group(format!("{}", service_group));
+        proto.set_incarnation(0);
+        proto.set_shard_count(shard_count);
+
+        rumor.set_shard_map(proto);
+        ShardMap(rumor)
+    }
+
+    /// Returns the shard the given member is assigned to, if any.
+    pub fn shard_of(&self, member_id: &str) -> Option<u32> {
+        self.get_slots()
+            .iter()
+            .find(|slot| slot.get_member_id() == member_id)
+            .map(|slot| slot.get_shard())
+    }
+
+    /// Returns the member id of the leader of the given shard, if the shard has any members.
+    pub fn leader_of(&self, shard: u32) -> Option<&str> {
+        self.get_slots()
+            .iter()
+            .find(|slot| slot.get_shard() == shard)
+            .map(|slot| slot.get_member_id())
+    }
+
+    /// Recomputes the assignments for the given alive members. Members which are already assigned
+    /// keep their shard, members which are no longer alive are dropped, and new members are placed
+    /// on the hash ring. Shards left empty by departing members are then refilled from the
+    /// fullest shard.
+    ///
+    /// `zones` maps member ids to the zone each member is in. Shards are then led, where possible,
+    /// by members in zones that do not already lead another shard; a leader is only replaced if
+    /// its zone already leads a lower shard.
+    ///
+    /// Returns true, and bumps the incarnation, if the assignments changed.
+    pub fn assign<S>(
+        &mut self,
+        author: &str,
+        shard_count: u32,
+        multi_leader: bool,
+        members: &[S],
+        zones: &HashMap<String, String>,
+    ) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let shard_count = if shard_count == 0 { 1 } else { shard_count };
+        let mut alive: Vec<&str> = members.iter().map(|m| m.as_ref()).collect();
+        alive.sort();
+        alive.dedup();
+
+        let mut slots: Vec<(String, u32)> = Vec::new();
+        for slot in self.get_slots() {
+            if slot.get_shard() >= shard_count ||
+                alive.binary_search(&slot.get_member_id()).is_err() ||
+                slots.iter().any(|&(ref m, _)| m == slot.get_member_id())
+            {
+                continue;
+            }
+            slots.push((slot.get_member_id().to_string(), slot.get_shard()));
+        }
+
+        let mut load = vec![0u32; shard_count as usize];
+        for &(_, shard) in slots.iter() {
+            load[shard as usize] += 1;
+        }
+        let capacity = (alive.len() as u32 + shard_count - 1) / shard_count;
+        let ring = hash_ring(shard_count);
+        for member in alive {
+            if slots.iter().any(|&(ref m, _)| m == member) {
+                continue;
+            }
+            let shard = place(&ring, member, &load, capacity);
+            load[shard as usize] += 1;
+            slots.push((member.to_string(), shard));
+        }
+
+        while let Some(empty) = load.iter().position(|&l| l == 0) {
+            let fullest = (0..load.len()).rev().max_by_key(|&s| load[s]).unwrap();
+            if load[fullest] < 2 {
+                break;
+            }
+            let idx = slots.iter().rposition(|&(_, s)| s as usize == fullest).unwrap();
+            slots[idx].1 = empty as u32;
+            load[fullest] -= 1;
+            load[empty] += 1;
+        }
+
+        spread_leaders(&mut slots, shard_count, zones);
+
+        let unchanged = self.get_shard_count() == shard_count &&
+            self.get_multi_leader() == multi_leader &&
+            self.get_slots().len() == slots.len() &&
+            self.get_slots().iter().zip(slots.iter()).all(
+                |(old, &(ref m, s))| {
+                    old.get_member_id() == m && old.get_shard() == s
+                },
+            );
+        if unchanged {
+            return false;
+        }
+
+        let slots = slots
+            .into_iter()
+            .map(|(member_id, shard)| {
+                let mut slot = ProtoShardSlot::new();
+                slot.set_member_id(member_id);
+                slot.set_shard(shard);
+                slot
+            })
+            .collect();
+        let incarnation = self.get_incarnation() + 1;
+        self.0.set_from_id(author.to_string());
+        self.set_member_id(author.to_string());
+        self.set_incarnation(incarnation);
+        self.set_shard_count(shard_count);
+        self.set_multi_leader(multi_leader);
+        self.set_slots(RepeatedField::from_vec(slots));
+        true
+    }
+}
+
+impl Rumor for ShardMap {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let rumor = protobuf::parse_from_bytes::<ProtoRumor>(bytes)?;
+        Ok(ShardMap::from(rumor))
+    }
+
+    /// The newer incarnation wins. Should two members publish the same incarnation, such as while
+    /// the membership settles, the map published by the higher member id wins.
+    fn merge(&mut self, mut other: ShardMap) -> bool {
+        if *self >= other {
+            false
+        } else {
+            mem::swap(self, &mut other);
+            true
+        }
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::ShardMap
+    }
+
+    fn id(&self) -> &str {
+        "shard_map"
+    }
+
+    fn key(&self) -> &str {
+        self.get_service_group()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(self.0.write_to_bytes()?)
+    }
+}
+
+/// 64-bit FNV-1a. The ring must hash identically on every Supervisor, whatever it was built with,
+/// so we don't rely on the standard library's hasher.
+fn hash(value: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in value.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Moves, for each shard in turn, the first member whose zone does not yet lead a shard to the
+/// front of that shard's members. Members without a zone never conflict.
+fn spread_leaders(
+    slots: &mut Vec<(String, u32)>,
+    shard_count: u32,
+    zones: &HashMap<String, String>,
+) {
+    if zones.is_empty() {
+        return;
+    }
+    let mut leading: Vec<&str> = Vec::new();
+    for shard in 0..shard_count {
+        let candidates: Vec<usize> = (0..slots.len()).filter(|&i| slots[i].1 == shard).collect();
+        let first = match candidates.first() {
+            Some(&first) => first,
+            None => continue,
+        };
+        let pick = candidates
+            .iter()
+            .cloned()
+            .find(|&i| match zones.get(&slots[i].0) {
+                Some(zone) => !leading.contains(&zone.as_str()),
+                None => true,
+            })
+            .unwrap_or(first);
+        if pick != first {
+            let leader = slots.remove(pick);
+            slots.insert(first, leader);
+        }
+        if let Some(zone) = zones.get(&slots[first].0) {
+            leading.push(zone.as_str());
+        }
+    }
+}
+
+fn hash_ring(shard_count: u32) -> Vec<(u64, u32)> {
+    let mut ring = Vec::with_capacity((shard_count * RING_POINTS_PER_SHARD) as usize);
+    for shard in 0..shard_count {
+        for point in 0..RING_POINTS_PER_SHARD {
+            ring.push((hash(&format!("shard-{}-{}", shard, point)), shard));
+        }
+    }
+    ring.sort();
+    ring
+}
+
+/// Walks the ring clockwise from the member's hash and returns the first shard with room left.
+fn place(ring: &[(u64, u32)], member_id: &str, load: &[u32], capacity: u32) -> u32 {
+    let start = match ring.binary_search(&(hash(member_id), 0)) {
+        Ok(idx) | Err(idx) => idx,
+    };
+    for i in 0..ring.len() {
+        let (_, shard) = ring[(start + i) % ring.len()];
+        if load[shard as usize] < capacity {
+            return shard;
+        }
+    }
+    (0..load.len()).min_by_key(|&s| load[s]).unwrap() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use std::collections::HashMap;
+
+    use habitat_core::service::ServiceGroup;
+
+    use super::ShardMap;
+    use rumor::Rumor;
+
+    fn create_shard_map(member_id: &str) -> ShardMap {
+        ShardMap::new(
+            member_id,
+            ServiceGroup::new(None, "neurosis", "production", None).unwrap(),
+            3,
+        )
+    }
+
+    fn members(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("member-{}", i)).collect()
+    }
+
+    fn loads(shard_map: &ShardMap) -> Vec<usize> {
+        (0..shard_map.get_shard_count())
+            .map(|shard| {
+                shard_map
+                    .get_slots()
+                    .iter()
+                    .filter(|slot| slot.get_shard() == shard)
+                    .count()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn identical_shard_maps_are_equal() {
+        let s1 = create_shard_map("adam");
+        let s2 = create_shard_map("adam");
+        assert_eq!(s1, s2);
+        assert_eq!(s1.partial_cmp(&s2), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn merge_chooses_the_higher_incarnation() {
+        let mut s1 = create_shard_map("adam");
+        let mut s2 = create_shard_map("adam");
+        s2.set_incarnation(1);
+        let s2_check = s2.clone();
+        assert_eq!(s1.merge(s2), true);
+        assert_eq!(s1, s2_check);
+    }
+
+    #[test]
+    fn merge_breaks_ties_with_the_member_id() {
+        let mut s1 = create_shard_map("adam");
+        let s2 = create_shard_map("dave");
+        let s2_check = s2.clone();
+        assert_eq!(s1.merge(s2), true);
+        assert_eq!(s1, s2_check);
+        assert_eq!(s1.merge(create_shard_map("adam")), false);
+    }
+
+    #[test]
+    fn assign_places_every_member_evenly() {
+        let mut shard_map = create_shard_map("adam");
+        assert!(shard_map.assign("adam", 3, false, &members(6), &HashMap::new()));
+        assert_eq!(shard_map.get_incarnation(), 1);
+        assert_eq!(shard_map.get_slots().len(), 6);
+        assert_eq!(loads(&shard_map), vec![2, 2, 2]);
+    }
+
+    #[test]
+    fn assign_returns_false_if_nothing_changed() {
+        let mut shard_map = create_shard_map("adam");
+        assert!(shard_map.assign("adam", 3, false, &members(6), &HashMap::new()));
+        assert!(!shard_map.assign("adam", 3, false, &members(6), &HashMap::new()));
+        assert_eq!(shard_map.get_incarnation(), 1);
+    }
+
+    #[test]
+    fn assign_keeps_existing_members_on_their_shard() {
+        let mut shard_map = create_shard_map("adam");
+        shard_map.assign("adam", 3, false, &members(6), &HashMap::new());
+        let before = shard_map.clone();
+        assert!(shard_map.assign("adam", 3, false, &members(9), &HashMap::new()));
+        for member in members(6) {
+            assert_eq!(shard_map.shard_of(&member), before.shard_of(&member));
+        }
+        assert_eq!(shard_map.get_slots().len(), 9);
+
+        let mut survivors = members(9);
+        survivors.remove(4);
+        let before = shard_map.clone();
+        assert!(shard_map.assign("adam", 3, false, &survivors, &HashMap::new()));
+        assert_eq!(shard_map.shard_of("member-4"), None);
+        for member in survivors {
+            assert_eq!(shard_map.shard_of(&member), before.shard_of(&member));
+        }
+    }
+
+    #[test]
+    fn assign_refills_empty_shards() {
+        let mut shard_map = create_shard_map("adam");
+        shard_map.assign("adam", 3, false, &members(6), &HashMap::new());
+        let shard = shard_map.shard_of("member-0").unwrap();
+        let survivors: Vec<String> = members(6)
+            .into_iter()
+            .filter(|m| shard_map.shard_of(m) != Some(shard))
+            .collect();
+        assert_eq!(survivors.len(), 4);
+        assert!(shard_map.assign("adam", 3, false, &survivors, &HashMap::new()));
+        assert!(loads(&shard_map).iter().all(|load| *load > 0));
+        assert!(shard_map.leader_of(shard).is_some());
+    }
+
+    #[test]
+    fn leader_is_the_first_member_of_a_shard() {
+        let mut shard_map = create_shard_map("adam");
+        shard_map.assign("adam", 3, true, &members(3), &HashMap::new());
+        for member in members(3) {
+            let shard = shard_map.shard_of(&member).unwrap();
+            assert_eq!(shard_map.leader_of(shard), Some(member.as_str()));
+        }
+        let before = shard_map.clone();
+        shard_map.assign("adam", 3, true, &members(6), &HashMap::new());
+        for shard in 0..3 {
+            assert_eq!(shard_map.leader_of(shard), before.leader_of(shard));
+        }
+    }
+
+    #[test]
+    fn assign_moves_members_off_removed_shards() {
+        let mut shard_map = create_shard_map("adam");
+        shard_map.assign("adam", 3, false, &members(6), &HashMap::new());
+        assert!(shard_map.assign("adam", 2, false, &members(6), &HashMap::new()));
+        assert_eq!(shard_map.get_shard_count(), 2);
+        assert_eq!(loads(&shard_map), vec![3, 3]);
+    }
+
+    #[test]
+    fn assign_spreads_leaders_across_zones() {
+        let mut zones = HashMap::new();
+        for (i, member) in members(4).into_iter().enumerate() {
+            zones.insert(member, String::from(if i < 2 { "us-east-1a" } else { "us-east-1b" }));
+        }
+        let mut shard_map = create_shard_map("adam");
+        assert!(shard_map.assign("adam", 2, true, &members(4), &zones));
+        let leader_zones: Vec<&String> = (0..2)
+            .map(|shard| &zones[shard_map.leader_of(shard).unwrap()])
+            .collect();
+        assert_ne!(leader_zones[0], leader_zones[1]);
+        assert!(!shard_map.assign("adam", 2, true, &members(4), &zones));
+    }
+}
@@ -15,12 +15,14 @@
 //! Expire suspected members.
 //!
 //! This module keeps track of suspected members, and sets their status to confirmed if they remain
-//! suspect long enough.
+//! suspect long enough. It does the same for members of peer rings whose health stopped being
+//! relayed. Every `RUMOR_EXPIRY_INTERVAL_MS`, it also expires the rumors of gone members and
+//! unloaded services.
 
 use std::thread;
 use std::time::Duration;
 
-use time::SteadyTime;
+use time::{Duration as TimeDuration, SteadyTime};
 
 use message::swim::Rumor_Type;
 use member::Health;
@@ -29,6 +31,8 @@ use server::Server;
 use server::timing::Timing;
 use trace::TraceKind;
 
+const RUMOR_EXPIRY_INTERVAL_MS: i64 = 60_000;
+
 pub struct Expire {
     pub server: Server,
     pub timing: Timing,
@@ -45,6 +49,7 @@ impl Expire {
 
     /// Run the expire thread.
     pub fn run(&self) {
+        let mut next_rumor_expiry = SteadyTime::now();
         loop {
             let mut expired_list: Vec<String> = Vec::new();
             self.server.member_list.with_suspects(|(id, suspect)| {
@@ -106,6 +111,17 @@ impl Expire {
                 ));
             }
 
+            self.server.expire_federated_members();
+
+            if SteadyTime::now() >= next_rumor_expiry {
+                self.server.expire_rumors(
+                    self.timing.rumor_ttl(),
+                    self.timing.tombstone_ttl(),
+                );
+                next_rumor_expiry = SteadyTime::now() +
+                    TimeDuration::milliseconds(RUMOR_EXPIRY_INTERVAL_MS);
+            }
+
             thread::sleep(Duration::from_millis(500));
         }
     }
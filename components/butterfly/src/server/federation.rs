@@ -0,0 +1,221 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The federation thread.
+//!
+//! Each ring of a federation runs its own SWIM membership, so probes never cross between
+//! regions. Gateway members relay the service and service config rumors of the federated service
+//! groups, along with the health of the members behind them, to the gateways of peer rings, every
+//! `RELAY_INTERVAL_MS`. The peer rings gossip them onward like any other rumor.
+//!
+//! Only rumors about members of our own ring are relayed, so rumors a peer ring relayed to us
+//! never travel back.
+
+use std::net::SocketAddr;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use habitat_core::service::ServiceGroup;
+use zmq;
+
+use ZMQ_CONTEXT;
+use member::Health;
+use message::swim::Membership as ProtoMembership;
+use rumor::Rumor;
+use rumor::federated_member::FederatedMember;
+use server::Server;
+
+const RELAY_INTERVAL_MS: u64 = 5_000;
+/// Seconds without a relay of a member's health after which it becomes suspect, and after twice
+/// which it is departed. Allows for a few missed relays.
+pub const SUSPICION_WINDOW_SECS: u64 = 30;
+
+/// The federation a gateway member relays rumors for.
+#[derive(Clone, Debug)]
+pub struct Federation {
+    /// The name of our ring.
+    pub ring: String,
+    /// The gossip addresses of the gateways of peer rings.
+    pub gateways: Vec<SocketAddr>,
+    /// The service groups whose rumors are relayed.
+    pub service_groups: Vec<ServiceGroup>,
+}
+
+impl Federation {
+    pub fn new<S>(ring: S, gateways: Vec<SocketAddr>, service_groups: Vec<ServiceGroup>) -> Self
+    where
+        S: Into<String>,
+    {
+        Federation {
+            ring: ring.into(),
+            gateways: gateways,
+            service_groups: service_groups,
+        }
+    }
+}
+
+/// The federation relay.
+#[derive(Debug)]
+pub struct Relay {
+    pub server: Server,
+    pub federation: Federation,
+}
+
+impl Relay {
+    /// Creates a new Relay from a Server and the Federation it is a gateway of.
+    pub fn new(server: Server, federation: Federation) -> Relay {
+        Relay {
+            server: server,
+            federation: federation,
+        }
+    }
+
+    /// Executes the federation thread. Relays are idempotent; peer rings drop rumors they already
+    /// hold, so every round sends the whole set, and a peer ring that restarts catches up on the
+    /// next round.
+    pub fn run(&mut self) {
+        loop {
+            if self.server.pause.load(Ordering::Relaxed) {
+                thread::sleep(Duration::from_millis(100));
+                continue;
+            }
+            let rumors = self.collect_rumors();
+            if rumors.len() > 0 {
+                for gateway in self.federation.gateways.iter() {
+                    self.send_rumors(gateway, &rumors);
+                }
+            }
+            thread::sleep(Duration::from_millis(RELAY_INTERVAL_MS));
+        }
+    }
+
+    /// Gathers the rumors to relay, as bytes: for every federated service group, the service
+    /// rumors of the members of our ring with their health, and the group's service config.
+    fn collect_rumors(&self) -> Vec<Vec<u8>> {
+        let mut rumors = Vec::new();
+        for service_group in self.federation.service_groups.iter() {
+            let key = service_group.to_string();
+            self.server.service_store.with_rumors(&key, |service| {
+                let membership = match self.membership_for(service.get_member_id()) {
+                    Some(membership) => membership,
+                    None => return,
+                };
+                let federated_member = FederatedMember::new(
+                    self.server.member_id(),
+                    self.federation.ring.clone(),
+                    membership,
+                );
+                match (service.write_to_bytes(), federated_member.write_to_bytes()) {
+                    (Ok(service_bytes), Ok(member_bytes)) => {
+                        rumors.push(service_bytes);
+                        rumors.push(member_bytes);
+                    }
+                    (Err(e), _) | (_, Err(e)) => {
+                        println!("Could not write rumor to bytes; abandoning relay: {:?}", e)
+                    }
+                }
+            });
+            if let Ok(bytes) = self.server.service_config_store.write_to_bytes(
+                &key,
+                "service_config",
+            )
+            {
+                rumors.push(bytes);
+            }
+        }
+        rumors
+    }
+
+    /// The membership of a member of our ring, or `None` for a member we only know of through a
+    /// peer ring.
+    fn membership_for(&self, member_id: &str) -> Option<ProtoMembership> {
+        if member_id == self.server.member_id() {
+            let mut membership = ProtoMembership::new();
+            membership.set_member(
+                self.server
+                    .member
+                    .read()
+                    .expect("Member lock is poisoned")
+                    .proto
+                    .clone(),
+            );
+            membership.set_health(Health::Alive.into());
+            Some(membership)
+        } else {
+            self.server.member_list.membership_for(member_id)
+        }
+    }
+
+    /// Send the rumors to the gateway of a peer ring, over a short-lived push socket, the same way
+    /// the push thread talks to the members of our ring.
+    fn send_rumors(&self, gateway: &SocketAddr, rumors: &Vec<Vec<u8>>) {
+        let socket = (**ZMQ_CONTEXT).as_mut().socket(zmq::PUSH).expect(
+            "Failure to create the ZMQ push socket",
+        );
+        socket.set_linger(1000).expect(
+            "Failure to set the ZMQ push socket to not linger",
+        );
+        socket.set_tcp_keepalive(0).expect(
+            "Failure to set the ZMQ push socket to not use keepalive",
+        );
+        socket.set_immediate(true).expect(
+            "Failure to set the ZMQ push socket to immediate",
+        );
+        socket.set_sndhwm(1000).expect(
+            "Failure to set the ZMQ push socket hwm",
+        );
+        socket.set_sndtimeo(500).expect(
+            "Failure to set the ZMQ send timeout",
+        );
+        match socket.connect(&format!("tcp://{}", gateway)) {
+            Ok(()) => debug!("Connected federation socket to {}", gateway),
+            Err(e) => {
+                println!("Cannot connect federation socket to {}: {:?}", gateway, e);
+                return;
+            }
+        }
+        for rumor in rumors.iter() {
+            let rumor = match self.server.sign_rumor(rumor.clone()) {
+                Ok(rumor) => rumor,
+                Err(e) => {
+                    error!("Signing rumor failed: {}", e);
+                    continue;
+                }
+            };
+            let payload = match self.server.generate_wire(rumor) {
+                Ok(payload) => payload,
+                Err(e) => {
+                    error!("Generating protobuf failed: {}", e);
+                    continue;
+                }
+            };
+            if let Err(e) = socket.send(&payload, 0) {
+                println!(
+                    "Could not relay rumor to gateway {}; ZMQ said: {:?}",
+                    gateway,
+                    e
+                );
+            }
+        }
+    }
+}
+
+/// Seconds since the epoch, which relays are stamped with when they arrive.
+pub fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
@@ -50,9 +50,9 @@ impl Inbound {
         }
     }
 
-    /// Run the thread. Listens for messages up to 1k in size, and then processes them accordingly.
+    /// Run the thread. Listens for messages up to 2k in size, and then processes them accordingly.
     pub fn run(&self) {
-        let mut recv_buffer: Vec<u8> = vec![0; 1024];
+        let mut recv_buffer: Vec<u8> = vec![0; 2048];
         loop {
             if self.server.pause.load(Ordering::Relaxed) {
                 thread::sleep(Duration::from_millis(100));
@@ -60,8 +60,10 @@ impl Inbound {
             }
             match self.socket.recv_from(&mut recv_buffer[..]) {
                 Ok((length, addr)) => {
-                    let swim_payload = match self.server.unwrap_wire(&recv_buffer[0..length]) {
-                        Ok(swim_payload) => swim_payload,
+                    let (swim_payload, signer) = match self.server.unwrap_wire(
+                        &recv_buffer[0..length],
+                    ) {
+                        Ok(unwrapped) => unwrapped,
                         Err(e) => {
                             // NOTE: In the future, we might want to blacklist people who send us
                             // garbage all the time.
@@ -92,6 +94,14 @@ impl Inbound {
                                 );
                                 continue;
                             }
+                            if !self.server.check_signer(
+                                msg.get_ping().get_from().get_id(),
+                                signer.as_ref(),
+                                false,
+                            )
+                            {
+                                continue;
+                            }
                             self.process_ping(addr, msg);
                         }
                         Swim_Type::ACK => {
@@ -105,6 +115,19 @@ impl Inbound {
                                 );
                                 continue;
                             }
+                            // Acks forwarded to us were relayed by the member we sent the
+                            // pingreq to.
+                            let relayed = msg.get_ack().has_forward_to() &&
+                                msg.get_ack().get_forward_to().get_id() ==
+                                    self.server.member_id();
+                            if !self.server.check_signer(
+                                msg.get_ack().get_from().get_id(),
+                                signer.as_ref(),
+                                relayed,
+                            )
+                            {
+                                continue;
+                            }
                             self.process_ack(addr, msg);
                         }
                         Swim_Type::PINGREQ => {
@@ -118,6 +141,14 @@ impl Inbound {
                                 );
                                 continue;
                             }
+                            if !self.server.check_signer(
+                                msg.get_pingreq().get_from().get_id(),
+                                signer.as_ref(),
+                                false,
+                            )
+                            {
+                                continue;
+                            }
                             self.process_pingreq(addr, msg);
                         }
                     }
@@ -17,16 +17,18 @@
 //! Creates `Server` structs, that hold everything we need to run the SWIM and Gossip protocol.
 //! Winds up with 5 separate threads - inbound (incoming connections), outbound (the Probe
 //! protocol), expire (turning Suspect members into Confirmed members), push (the fan-out rumors),
-//! and pull (the inbound receipt of rumors.).
+//! and pull (the inbound receipt of rumors.). Gateway members of a federation run a sixth,
+//! federation, which relays rumors to peer rings.
 
 mod expire;
+pub mod federation;
 mod inbound;
 mod outbound;
 mod pull;
 mod push;
 pub mod timing;
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::ffi;
 use std::fmt::{self, Debug};
 use std::fs;
@@ -37,26 +39,35 @@ use std::result;
 use std::str::FromStr;
 use std::sync::{Arc, RwLock};
 use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
-use std::sync::mpsc::channel;
-use std::time::{Instant, Duration};
+use std::sync::mpsc::{channel, Receiver};
+use std::time::{Instant, Duration, SystemTime};
 use std::thread;
 
 use habitat_core::service::ServiceGroup;
-use habitat_core::crypto::SymKey;
+use habitat_core::crypto::{SigKeyPair, SymKey};
+use protobuf::{self, Message};
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
 
 use error::{Result, Error};
+use event::{ElectionKind, Event, EventStream};
 use member::{Member, Health, MemberList};
-use message;
+use message::{self, Identities, RingKeys};
+use message::swim::{Membership_Health as ProtoHealth, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
 use rumor::{Rumor, RumorKey, RumorStore};
 use rumor::heat::RumorHeat;
 use rumor::dat_file::DatFile;
 use rumor::departure::Departure;
+use rumor::federated_member::FederatedMember;
+use rumor::key_value::KeyValue;
 use rumor::service::Service;
 use rumor::service_config::ServiceConfig;
 use rumor::service_file::ServiceFile;
+use rumor::ring_key::RingKey;
+use rumor::shard_map::ShardMap;
 use rumor::election::{Election, ElectionUpdate};
+use self::federation::Federation;
 use trace::{Trace, TraceKind};
 
 pub trait Suitability: Debug + Send + Sync {
@@ -70,7 +81,10 @@ pub struct Server {
     member_id: Arc<String>,
     pub member: Arc<RwLock<Member>>,
     pub member_list: MemberList,
-    ring_key: Arc<Option<SymKey>>,
+    events: EventStream,
+    ring_keys: Arc<RwLock<RingKeys>>,
+    identity: Arc<Option<SigKeyPair>>,
+    identities: Arc<RwLock<Identities>>,
     rumor_heat: RumorHeat,
     pub service_store: RumorStore<Service>,
     pub service_config_store: RumorStore<ServiceConfig>,
@@ -78,11 +92,18 @@ pub struct Server {
     pub election_store: RumorStore<Election>,
     pub update_store: RumorStore<ElectionUpdate>,
     pub departure_store: RumorStore<Departure>,
+    pub shard_map_store: RumorStore<ShardMap>,
+    pub ring_key_store: RumorStore<RingKey>,
+    pub key_value_store: RumorStore<KeyValue>,
+    pub federated_member_store: RumorStore<FederatedMember>,
     swim_addr: Arc<RwLock<SocketAddr>>,
     gossip_addr: Arc<RwLock<SocketAddr>>,
     suitability_lookup: Arc<Box<Suitability>>,
     data_path: Arc<Option<PathBuf>>,
     dat_file: Arc<RwLock<Option<DatFile>>>,
+    federation: Arc<Option<Federation>>,
+    // The rumors which are due to expire, with when they were first found expirable
+    expiring: Arc<RwLock<HashMap<RumorKey, SystemTime>>>,
     socket: Option<UdpSocket>,
     departed: Arc<AtomicBool>,
     // These are all here for testing support
@@ -100,7 +121,10 @@ impl Clone for Server {
             member_id: self.member_id.clone(),
             member: self.member.clone(),
             member_list: self.member_list.clone(),
-            ring_key: self.ring_key.clone(),
+            events: self.events.clone(),
+            ring_keys: self.ring_keys.clone(),
+            identity: self.identity.clone(),
+            identities: self.identities.clone(),
             rumor_heat: self.rumor_heat.clone(),
             service_store: self.service_store.clone(),
             service_config_store: self.service_config_store.clone(),
@@ -108,11 +132,17 @@ impl Clone for Server {
             election_store: self.election_store.clone(),
             update_store: self.update_store.clone(),
             departure_store: self.departure_store.clone(),
+            shard_map_store: self.shard_map_store.clone(),
+            ring_key_store: self.ring_key_store.clone(),
+            key_value_store: self.key_value_store.clone(),
+            federated_member_store: self.federated_member_store.clone(),
             swim_addr: self.swim_addr.clone(),
             gossip_addr: self.gossip_addr.clone(),
             suitability_lookup: self.suitability_lookup.clone(),
             data_path: self.data_path.clone(),
             dat_file: self.dat_file.clone(),
+            federation: self.federation.clone(),
+            expiring: self.expiring.clone(),
             departed: self.departed.clone(),
             pause: self.pause.clone(),
             trace: self.trace.clone(),
@@ -127,6 +157,7 @@ impl Clone for Server {
 impl Server {
     /// Create a new server, bound to the `addr`, hosting a particular `member`, and with a
     /// `Trace` struct, a ring_key if you want encryption on the wire, and an optional server name.
+    /// The ring_key is the primary key; more revisions can be accepted with `accept_ring_keys`.
     pub fn new<T, U, P>(
         swim_addr: T,
         gossip_addr: U,
@@ -149,12 +180,16 @@ impl Server {
             (Ok(Some(swim_socket_addr)), Ok(Some(gossip_socket_addr))) => {
                 member.set_swim_port(swim_socket_addr.port() as i32);
                 member.set_gossip_port(gossip_socket_addr.port() as i32);
+                let events = EventStream::default();
                 Ok(Server {
                     name: Arc::new(name.unwrap_or(String::from(member.get_id()))),
                     member_id: Arc::new(String::from(member.get_id())),
                     member: Arc::new(RwLock::new(member)),
-                    member_list: MemberList::new(),
-                    ring_key: Arc::new(ring_key),
+                    member_list: MemberList::with_events(events.clone()),
+                    events: events,
+                    ring_keys: Arc::new(RwLock::new(RingKeys::new(ring_key))),
+                    identity: Arc::new(None),
+                    identities: Arc::new(RwLock::new(Identities::default())),
                     rumor_heat: RumorHeat::default(),
                     service_store: RumorStore::default(),
                     service_config_store: RumorStore::default(),
@@ -162,11 +197,17 @@ impl Server {
                     election_store: RumorStore::default(),
                     update_store: RumorStore::default(),
                     departure_store: RumorStore::default(),
+                    shard_map_store: RumorStore::default(),
+                    ring_key_store: RumorStore::default(),
+                    key_value_store: RumorStore::default(),
+                    federated_member_store: RumorStore::default(),
                     swim_addr: Arc::new(RwLock::new(swim_socket_addr)),
                     gossip_addr: Arc::new(RwLock::new(gossip_socket_addr)),
                     suitability_lookup: Arc::new(suitability_lookup),
                     data_path: Arc::new(data_path.as_ref().map(|p| p.into())),
                     dat_file: Arc::new(RwLock::new(None)),
+                    federation: Arc::new(None),
+                    expiring: Arc::new(RwLock::new(HashMap::new())),
                     departed: Arc::new(AtomicBool::new(false)),
                     pause: Arc::new(AtomicBool::new(false)),
                     trace: Arc::new(RwLock::new(trace)),
@@ -253,11 +294,17 @@ impl Server {
                 return Err(Error::BadDataPath(path.to_path_buf(), err));
             }
             let mut file = DatFile::new(&self.member_id, path);
-            if file.path().exists() {
+            let compacted = if file.path().exists() {
                 file.read_into(self)?;
+                self.compact(timing.tombstone_ttl())
+            } else {
+                0
+            };
+            *self.dat_file.write().expect("DatFile lock is poisoned") = Some(file);
+            if compacted > 0 {
+                debug!("Compacted {} rumors and tombstones out of the DatFile", compacted);
+                self.persist_data();
             }
-            let mut dat_file = self.dat_file.write().expect("DatFile lock is poisoned");
-            *dat_file = Some(file);
         }
 
         let socket = match UdpSocket::bind(*self.swim_addr.read().expect(
@@ -329,6 +376,17 @@ impl Server {
                 panic!("You should never, ever get here, liu");
             });
 
+        if let Some(ref federation) = *self.federation {
+            let server_g = self.clone();
+            let federation_g = federation.clone();
+            let _ = thread::Builder::new()
+                .name(format!("federation-{}", self.name()))
+                .spawn(move || {
+                    federation::Relay::new(server_g, federation_g).run();
+                    panic!("You should never, ever get here, hana");
+                });
+        }
+
         if self.dat_file
             .read()
             .expect("DatFile lock poisoned")
@@ -377,6 +435,101 @@ impl Server {
         blacklist.contains(member_id)
     }
 
+    /// Check that a message from the given member was signed by an identity we trust. Messages
+    /// the member sent on its own behalf must be signed with its own identity; messages it
+    /// relayed, such as forwarded acks and gossiped rumors, may be signed by any identity we
+    /// trust.
+    fn check_signer(&self, member_id: &str, signer: Option<&SigKeyPair>, relayed: bool) -> bool {
+        if let Some(signer) = signer {
+            if !relayed && signer.name != member_id {
+                warn!(
+                    "Not processing message from {} - it was signed by {}",
+                    member_id,
+                    signer.name_with_rev()
+                );
+                return false;
+            }
+        }
+        let sender = match signer {
+            Some(signer) if relayed => signer.name.as_str(),
+            _ => member_id,
+        };
+        match self.identities
+            .write()
+            .expect("Identities lock poisoned")
+            .check(sender, signer) {
+            Ok(()) => true,
+            Err(err) => {
+                warn!("Not processing message from {} - {}", member_id, err);
+                false
+            }
+        }
+    }
+
+    /// Check that a gossiped rumor was written by the member it speaks for. A departure speaks
+    /// for the member which is departing, a service rumor for the member running the service,
+    /// and a membership rumor declaring a member departed or confirmed dead for that member.
+    /// Every other rumor speaks for the member named in its `from_id`. The rumor must be signed
+    /// by that member, or by an operator identity which is one of the trusted identities and not
+    /// a member of the ring; a member may not sign a rumor on another member's behalf.
+    pub fn check_origin(&self, rumor: &ProtoRumor) -> bool {
+        let signer = match message::verify_rumor(rumor) {
+            Ok(signer) => signer,
+            Err(err) => {
+                warn!(
+                    "Not processing rumor from {} - bad origin signature, {}",
+                    rumor.get_from_id(),
+                    err
+                );
+                return false;
+            }
+        };
+        let bound = match rumor.get_field_type() {
+            ProtoRumor_Type::Departure => rumor.get_departure().get_member_id(),
+            ProtoRumor_Type::Service => rumor.get_service().get_member_id(),
+            ProtoRumor_Type::Member => {
+                match rumor.get_member().get_health() {
+                    ProtoHealth::CONFIRMED |
+                    ProtoHealth::DEPARTED => rumor.get_member().get_member().get_id(),
+                    _ => rumor.get_from_id(),
+                }
+            }
+            _ => rumor.get_from_id(),
+        };
+        let mut identities = self.identities.write().expect("Identities lock poisoned");
+        if let Some(ref signer) = signer {
+            if signer.name != bound {
+                if signer.name == self.member_id() ||
+                    self.member_list.contains_member(&signer.name)
+                {
+                    warn!(
+                        "Not processing rumor for {} - it was signed by member {}",
+                        bound,
+                        signer.name_with_rev()
+                    );
+                    return false;
+                }
+                if !identities.is_trusted(signer) {
+                    warn!(
+                        "Not processing rumor for {} - it was signed by {}, which is not a \
+                         trusted identity",
+                        bound,
+                        signer.name_with_rev()
+                    );
+                    return false;
+                }
+                return true;
+            }
+        }
+        match identities.check(bound, signer.as_ref()) {
+            Ok(()) => true,
+            Err(err) => {
+                warn!("Not processing rumor for {} - {}", bound, err);
+                false
+            }
+        }
+    }
+
     /// Stop the outbound and inbound threads from processing work.
     pub fn pause(&mut self) {
         self.pause.compare_and_swap(false, true, Ordering::Relaxed);
@@ -427,6 +580,37 @@ impl Server {
         &self.name
     }
 
+    /// Makes this server a gateway of a federation, relaying the rumors of the federated service
+    /// groups to the gateways of peer rings. Takes effect when the server starts.
+    pub fn set_federation(&mut self, federation: Federation) {
+        self.federation = Arc::new(Some(federation));
+    }
+
+    /// Signs everything this server sends with the given identity, a sig key pair named after its
+    /// member id. Takes effect when the server starts.
+    pub fn set_identity(&mut self, identity: SigKeyPair) {
+        self.identity = Arc::new(Some(identity));
+    }
+
+    /// Accepts traffic signed only by the given identities, rather than pinning the first
+    /// identity seen for each member.
+    pub fn trust_identities(&self, keys: Vec<SigKeyPair>) {
+        self.identities
+            .write()
+            .expect("Identities lock poisoned")
+            .trust(keys);
+    }
+
+    /// Subscribe to the changes this server sees in the ring. See the `event` module.
+    pub fn subscribe(&self) -> Receiver<Event> {
+        self.events.subscribe()
+    }
+
+    /// The stream this server publishes its events to, for subscribing from elsewhere.
+    pub fn events(&self) -> EventStream {
+        self.events.clone()
+    }
+
     /// Insert a member to the `MemberList`, and update its `RumorKey` appropriately.
     pub fn insert_member(&self, member: Member, health: Health) {
         let rk: RumorKey = RumorKey::from(&member);
@@ -437,6 +621,9 @@ impl Server {
         let trace_incarnation = member.get_incarnation();
         let trace_health = health.clone();
         if self.member_list.insert(member, health) {
+            if health == Health::Alive {
+                self.service_store.remove_tombstones_by_id(&trace_member_id);
+            }
             trace_it!(
                 MEMBERSHIP: self,
                 TraceKind::MemberUpdate,
@@ -458,6 +645,9 @@ impl Server {
         let trace_incarnation = member.get_incarnation();
         let trace_health = health.clone();
         if self.member_list.insert_health(member, health) {
+            if health == Health::Alive {
+                self.service_store.remove_tombstones_by_id(&trace_member_id);
+            }
             trace_it!(
                 MEMBERSHIP: self,
                 TraceKind::MemberUpdate,
@@ -528,6 +718,9 @@ impl Server {
         let trace_health = health.clone();
 
         if self.member_list.insert(member, health) || incremented_incarnation {
+            if health == Health::Alive {
+                self.service_store.remove_tombstones_by_id(&trace_member_id);
+            }
             trace_it!(
                 MEMBERSHIP: self,
                 TraceKind::MemberUpdate,
@@ -590,16 +783,27 @@ impl Server {
     /// Insert a service config rumor into the service store.
     pub fn insert_service_config(&self, service_config: ServiceConfig) {
         let rk = RumorKey::from(&service_config);
+        let event = Event::ServiceConfig {
+            service_group: String::from(service_config.get_service_group()),
+            incarnation: service_config.get_incarnation(),
+        };
         if self.service_config_store.insert(service_config) {
             self.rumor_heat.start_hot_rumor(rk);
+            self.events.publish(event);
         }
     }
 
     /// Insert a service file rumor into the service file store.
     pub fn insert_service_file(&self, service_file: ServiceFile) {
         let rk = RumorKey::from(&service_file);
+        let event = Event::ServiceFile {
+            service_group: String::from(service_file.get_service_group()),
+            filename: String::from(service_file.get_filename()),
+            incarnation: service_file.get_incarnation(),
+        };
         if self.service_file_store.insert(service_file) {
             self.rumor_heat.start_hot_rumor(rk);
+            self.events.publish(event);
         }
     }
 
@@ -630,12 +834,219 @@ impl Server {
         }
     }
 
+    /// Insert a shard map rumor into the shard map store.
+    pub fn insert_shard_map(&self, shard_map: ShardMap) {
+        let rk = RumorKey::from(&shard_map);
+        if self.shard_map_store.insert(shard_map) {
+            self.rumor_heat.start_hot_rumor(rk);
+        }
+    }
+
+    /// Insert a key/value rumor into the key/value store.
+    pub fn insert_key_value(&self, key_value: KeyValue) {
+        let rk = RumorKey::from(&key_value);
+        if self.key_value_store.insert(key_value) {
+            self.rumor_heat.start_hot_rumor(rk);
+        }
+    }
+
+    /// Insert a rumor about the health of a member of a peer ring. Rumors about members of our own
+    /// ring are dropped; the member list already knows better. A relay straight from the gateway
+    /// of a peer ring is stamped with the time it arrived, and becomes our own rumor, so that we
+    /// are the one to notice when relays about the member stop.
+    pub fn insert_federated_member(&self, mut federated_member: FederatedMember) {
+        if federated_member.member_id() == self.member_id() ||
+            self.member_list.contains_member(federated_member.member_id())
+        {
+            return;
+        }
+        if federated_member.from_id() != self.member_id() &&
+            !self.member_list.contains_member(federated_member.from_id())
+        {
+            let ring = String::from(federated_member.get_ring());
+            let membership = federated_member.get_membership().clone();
+            federated_member = FederatedMember::new(self.member_id(), ring, membership);
+            federated_member.set_relayed_at(federation::now_secs());
+        }
+        let rk = RumorKey::from(&federated_member);
+        let alive = federated_member.health() == Health::Alive;
+        if self.federated_member_store.insert(federated_member) {
+            if alive {
+                self.service_store.remove_tombstones_by_id(&rk.id);
+            }
+            self.rumor_heat.start_hot_rumor(rk);
+        }
+    }
+
+    /// Marks the members of peer rings whose health stopped being relayed to us as suspect, and as
+    /// departed once they stay silent for another suspicion window. Only the gateway the relays
+    /// used to arrive at judges this, by its own clock, and gossips the new health to the rest of
+    /// our ring.
+    pub fn expire_federated_members(&self) {
+        let now = federation::now_secs();
+        let mut stale = Vec::new();
+        self.federated_member_store.with_keys(
+            |(_, rumors)| for federated_member in rumors.values() {
+                if federated_member.from_id() != self.member_id() {
+                    continue;
+                }
+                let health = federated_member.health_at(now, federation::SUSPICION_WINDOW_SECS);
+                if health != federated_member.health() {
+                    let mut federated_member = federated_member.clone();
+                    federated_member.set_health(health);
+                    stale.push(federated_member);
+                }
+            },
+        );
+        for federated_member in stale {
+            let rk = RumorKey::from(&federated_member);
+            if self.federated_member_store.insert(federated_member) {
+                self.rumor_heat.start_hot_rumor(rk);
+            }
+        }
+    }
+
+    /// Insert a ring key rumor into the ring key store, and switch to the key it names if we hold
+    /// it.
+    pub fn insert_ring_key(&self, ring_key: RingKey) {
+        let rk = RumorKey::from(&ring_key);
+        if self.ring_key_store.insert(ring_key) {
+            self.rumor_heat.start_hot_rumor(rk);
+            self.apply_ring_key();
+        }
+    }
+
+    /// Gossip that the given revision of our ring key is now the primary key. The key must already
+    /// be accepted by this server.
+    pub fn rotate_ring_key(&self, revision: &str, incarnation: u64) -> Result<()> {
+        let name = match self.ring_keys.read().expect("Ring keys lock poisoned").primary() {
+            Some(key) => key.name.clone(),
+            None => return Err(Error::UnknownRingKey(revision.to_string())),
+        };
+        let ring_key = RingKey::new(self.member_id(), name, revision, incarnation);
+        if self.ring_keys
+            .read()
+            .expect("Ring keys lock poisoned")
+            .get(&ring_key.name_with_rev())
+            .is_none()
+        {
+            return Err(Error::UnknownRingKey(ring_key.name_with_rev()));
+        }
+        self.insert_ring_key(ring_key);
+        Ok(())
+    }
+
+    /// Accept traffic encrypted with any of the given keys, in addition to the primary key. Keys
+    /// accepted by an earlier call and left out of this one are no longer accepted.
+    ///
+    /// Does nothing if the ring isn't encrypted.
+    pub fn accept_ring_keys(&self, keys: Vec<SymKey>) {
+        {
+            let mut ring_keys = self.ring_keys.write().expect("Ring keys lock poisoned");
+            let name = match ring_keys.primary() {
+                Some(key) => key.name.clone(),
+                None => return,
+            };
+            ring_keys.replace(keys.into_iter().filter(|k| k.name == name).collect());
+        }
+        self.apply_ring_key();
+    }
+
+    /// The name and revision of the key we currently encrypt with, if the ring is encrypted.
+    pub fn primary_ring_key(&self) -> Option<String> {
+        self.ring_keys
+            .read()
+            .expect("Ring keys lock poisoned")
+            .primary()
+            .map(|k| k.name_with_rev())
+    }
+
+    /// Switches our primary key to the one named by the latest ring key rumor. If we don't hold
+    /// that key yet, we keep encrypting with the current one; the switch happens once the key is
+    /// accepted.
+    fn apply_ring_key(&self) {
+        let mut ring_keys = self.ring_keys.write().expect("Ring keys lock poisoned");
+        let name = match ring_keys.primary() {
+            Some(key) => key.name.clone(),
+            None => return,
+        };
+        self.ring_key_store.with_rumor(&name, "ring_key", |rumor| if let Some(rumor) = rumor {
+            let name_with_rev = rumor.name_with_rev();
+            if ring_keys.set_primary(&name_with_rev) {
+                debug!("Encrypting gossip with ring key {}", name_with_rev);
+            }
+        });
+    }
+
+    /// Reassign the shards of the given service group to the members currently alive in it.
+    ///
+    /// Only the alive member with the lowest member id publishes the shard map, so members never
+    /// race each other with competing assignments; when it dies, the next lowest takes over from
+    /// the assignments it left behind. With a `zone_tag`, shard leaders are spread across the
+    /// values members carry for that tag.
+    pub fn update_shard_map(
+        &self,
+        sg: &ServiceGroup,
+        shard_count: u32,
+        multi_leader: bool,
+        zone_tag: Option<&str>,
+    ) {
+        let key = sg.to_string();
+        let mut electorate = self.get_electorate(&key);
+        electorate.sort();
+        if electorate.first().map(|m| m.as_str()) != Some(self.member_id()) {
+            return;
+        }
+        let mut current = None;
+        self.shard_map_store.with_rumor(&key, "shard_map", |sm| {
+            current = sm.cloned()
+        });
+        let mut zones = HashMap::new();
+        if let Some(tag) = zone_tag {
+            for member_id in electorate.iter() {
+                if let Some(zone) = self.tag_of(member_id, tag) {
+                    zones.insert(member_id.clone(), zone);
+                }
+            }
+        }
+        let mut shard_map =
+            current.unwrap_or_else(|| ShardMap::new(self.member_id(), sg.clone(), shard_count));
+        if shard_map.assign(
+            self.member_id(),
+            shard_count,
+            multi_leader,
+            &electorate,
+            &zones,
+        )
+        {
+            self.insert_shard_map(shard_map);
+        }
+    }
+
+    /// The value of the given tag on a member of the ring, if the member carries it.
+    pub fn tag_of(&self, member_id: &str, tag: &str) -> Option<String> {
+        if member_id == self.member_id() {
+            return self.member
+                .read()
+                .expect("Member lock is poisoned")
+                .get_tags()
+                .get(tag)
+                .cloned();
+        }
+        let mut value = None;
+        self.member_list.with_member(member_id, |member| {
+            value = member.and_then(|m| m.get_tags().get(tag).cloned());
+        });
+        value
+    }
+
     /// Get all the Member ID's who are present in a given service group, and eligible to vote
     /// (alive)
     fn get_electorate(&self, key: &str) -> Vec<String> {
         let mut electorate = vec![];
         self.service_store.with_rumors(key, |s| {
-            if self.member_list.check_health_of_by_id(
+            if !s.get_unloaded() &&
+                self.member_list.check_health_of_by_id(
                 s.get_member_id(),
                 Health::Alive,
             )
@@ -650,9 +1061,10 @@ impl Server {
     pub fn get_total_population(&self, key: &str) -> usize {
         let mut total_pop = 0;
         self.service_store.with_rumors(key, |s| {
-            if self.member_list.check_in_voting_population_by_id(
-                s.get_member_id(),
-            )
+            if !s.get_unloaded() &&
+                self.member_list.check_in_voting_population_by_id(
+                    s.get_member_id(),
+                )
             {
                 total_pop += 1;
             }
@@ -691,7 +1103,7 @@ impl Server {
         if !self.check_quorum(e.key()) {
             e.no_quorum();
         }
-        self.election_store.insert(e);
+        self.store_election(e);
         self.rumor_heat.start_hot_rumor(ek);
     }
 
@@ -702,7 +1114,7 @@ impl Server {
         if !self.check_quorum(e.key()) {
             e.no_quorum();
         }
-        self.update_store.insert(e);
+        self.store_update_election(e);
         self.rumor_heat.start_hot_rumor(ek);
     }
 
@@ -925,7 +1337,7 @@ impl Server {
                 }
             }
         }
-        if self.election_store.insert(election) {
+        if self.store_election(election) {
             self.rumor_heat.start_hot_rumor(rk);
         }
     }
@@ -1011,17 +1423,108 @@ impl Server {
                 }
             }
         }
-        if self.update_store.insert(election) {
+        if self.store_update_election(election) {
             self.rumor_heat.start_hot_rumor(rk);
         }
     }
 
+    /// Insert an election into the election store, publishing any start or finish it brings.
+    fn store_election(&self, election: Election) -> bool {
+        let (key, id) = (String::from(election.key()), String::from(election.id()));
+        let mut before = None;
+        self.election_store.with_rumor(&key, &id, |e| before = e.map(election_state));
+        if !self.election_store.insert(election) {
+            return false;
+        }
+        let mut after = None;
+        self.election_store.with_rumor(&key, &id, |e| after = e.map(election_state));
+        self.publish_election(ElectionKind::Leader, &key, before, after);
+        true
+    }
+
+    /// Insert an update election into the update store, publishing any start or finish it brings.
+    fn store_update_election(&self, election: ElectionUpdate) -> bool {
+        let (key, id) = (String::from(election.key()), String::from(election.id()));
+        let mut before = None;
+        self.update_store.with_rumor(&key, &id, |e| before = e.map(|e| election_state(e)));
+        if !self.update_store.insert(election) {
+            return false;
+        }
+        let mut after = None;
+        self.update_store.with_rumor(&key, &id, |e| after = e.map(|e| election_state(e)));
+        self.publish_election(ElectionKind::Update, &key, before, after);
+        true
+    }
+
+    fn publish_election(
+        &self,
+        kind: ElectionKind,
+        service_group: &str,
+        before: Option<(u64, bool, String)>,
+        after: Option<(u64, bool, String)>,
+    ) {
+        let (term, finished, member_id) = match after {
+            Some(state) => state,
+            None => return,
+        };
+        let (started, was_finished) = match before {
+            Some((old_term, old_finished, _)) if old_term == term => (false, old_finished),
+            _ => (true, false),
+        };
+        if started {
+            self.events.publish(Event::ElectionStarted {
+                kind: kind,
+                service_group: String::from(service_group),
+                term: term,
+            });
+        }
+        if finished && !was_finished {
+            self.events.publish(Event::ElectionFinished {
+                kind: kind,
+                service_group: String::from(service_group),
+                term: term,
+                member_id: member_id,
+            });
+        }
+    }
+
     fn generate_wire(&self, payload: Vec<u8>) -> Result<Vec<u8>> {
-        message::generate_wire(payload, (*self.ring_key).as_ref())
+        message::generate_wire(
+            payload,
+            self.ring_keys.read().expect("Ring keys lock poisoned").primary(),
+            (*self.identity).as_ref(),
+        )
+    }
+
+    /// Signs the origin of a rumor we are about to gossip, if we wrote it. Elections merge the
+    /// votes of every member which has seen them, so the copy we hold is always our own. Rumors
+    /// written by anyone else are sent on unchanged, carrying their author's signature.
+    fn sign_rumor(&self, bytes: Vec<u8>) -> Result<Vec<u8>> {
+        let identity = match *self.identity {
+            Some(ref identity) => identity,
+            None => return Ok(bytes),
+        };
+        let mut rumor: ProtoRumor = protobuf::parse_from_bytes(&bytes)?;
+        match rumor.get_field_type() {
+            ProtoRumor_Type::Election |
+            ProtoRumor_Type::ElectionUpdate => {
+                rumor.set_from_id(String::from(self.member_id()));
+            }
+            _ => {
+                if rumor.get_from_id() != self.member_id() {
+                    return Ok(bytes);
+                }
+            }
+        }
+        message::sign_rumor(&mut rumor, identity)?;
+        Ok(rumor.write_to_bytes()?)
     }
 
-    fn unwrap_wire(&self, payload: &[u8]) -> Result<Vec<u8>> {
-        message::unwrap_wire(payload, (*self.ring_key).as_ref())
+    fn unwrap_wire(&self, payload: &[u8]) -> Result<(Vec<u8>, Option<SigKeyPair>)> {
+        message::unwrap_wire(
+            payload,
+            &*self.ring_keys.read().expect("Ring keys lock poisoned"),
+        )
     }
 
     fn persist_data(&self) {
@@ -1032,6 +1535,144 @@ impl Server {
         }
     }
 
+    /// Expires the rumors of members which have departed, and of services which have been
+    /// unloaded, once they have been so for longer than `rumor_ttl`. Departed members
+    /// leave the member list the same way. Expired rumors leave tombstones behind for
+    /// `tombstone_ttl`, and tombstones which have expired in turn are dropped.
+    pub fn expire_rumors(&self, rumor_ttl: Duration, tombstone_ttl: Duration) {
+        let now = SystemTime::now();
+        let mut expirable = HashSet::new();
+        self.service_store.with_keys(|(_, rumors)| for service in rumors.values() {
+            if service.get_unloaded() || self.is_gone(service.get_member_id()) {
+                expirable.insert(RumorKey::from(service));
+            }
+        });
+        self.federated_member_store.with_keys(
+            |(_, rumors)| for federated_member in rumors.values() {
+                if federated_member.health() == Health::Departed {
+                    expirable.insert(RumorKey::from(federated_member));
+                }
+            },
+        );
+        self.member_list.with_members(|member| if self.member_list
+            .check_health_of(member, Health::Departed)
+        {
+            expirable.insert(RumorKey::from(member));
+        });
+
+        let due: Vec<RumorKey> = {
+            let mut expiring = self.expiring.write().expect("Expiring lock is poisoned");
+            expiring.retain(|rk, _| expirable.contains(rk));
+            for rk in expirable.into_iter() {
+                expiring.entry(rk).or_insert(now);
+            }
+            let due: Vec<RumorKey> = expiring
+                .iter()
+                .filter(|&(_, since)| *since + rumor_ttl <= now)
+                .map(|(rk, _)| rk.clone())
+                .collect();
+            for rk in due.iter() {
+                expiring.remove(rk);
+            }
+            due
+        };
+        let expired = self.expire_due(due, now + tombstone_ttl) + self.purge_tombstones(now);
+        if expired > 0 {
+            debug!("Expired {} rumors and tombstones", expired);
+        }
+    }
+
+    /// Compacts the rumors read back from the DatFile at startup: members which had already
+    /// departed expire at once, along with their rumors, and expired tombstones are dropped.
+    /// Returns how many rumors and tombstones were dropped.
+    fn compact(&self, tombstone_ttl: Duration) -> usize {
+        let now = SystemTime::now();
+        let mut due = Vec::new();
+        self.member_list.with_members(|member| if self.member_list
+            .check_health_of(member, Health::Departed)
+        {
+            due.push(RumorKey::from(member));
+        });
+        self.service_store.with_keys(|(_, rumors)| for service in rumors.values() {
+            if self.member_list.check_health_of_by_id(
+                service.get_member_id(),
+                Health::Departed,
+            )
+            {
+                due.push(RumorKey::from(service));
+            }
+        });
+        self.expire_due(due, now + tombstone_ttl) + self.purge_tombstones(now)
+    }
+
+    /// Expires the given rumors, and the rumors of every service group left without services,
+    /// returning how many expired.
+    fn expire_due(&self, due: Vec<RumorKey>, expires: SystemTime) -> usize {
+        let mut expired = 0;
+        let mut groups = HashSet::new();
+        for rk in due.iter() {
+            let removed = match rk.kind {
+                message::swim::Rumor_Type::Service => {
+                    groups.insert(rk.key.clone());
+                    self.service_store.expire(&rk.key, &rk.id, expires)
+                }
+                message::swim::Rumor_Type::FederatedMember => {
+                    self.federated_member_store.expire(&rk.key, &rk.id, expires)
+                }
+                message::swim::Rumor_Type::Member => {
+                    self.member_list.expire_departed(&rk.id, expires)
+                }
+                _ => false,
+            };
+            if removed {
+                expired += 1;
+            }
+        }
+        for group in groups.iter().filter(
+            |group| self.service_store.len_for_key(group) == 0,
+        )
+        {
+            expired += self.service_config_store.expire_all(group, expires) +
+                self.service_file_store.expire_all(group, expires) +
+                self.election_store.expire_all(group, expires) +
+                self.update_store.expire_all(group, expires) +
+                self.shard_map_store.expire_all(group, expires) +
+                self.key_value_store.expire_all(group, expires);
+        }
+        expired
+    }
+
+    fn purge_tombstones(&self, now: SystemTime) -> usize {
+        self.member_list.purge_tombstones(now) + self.service_store.purge_tombstones(now) +
+            self.service_config_store.purge_tombstones(now) +
+            self.service_file_store.purge_tombstones(now) +
+            self.election_store.purge_tombstones(now) +
+            self.update_store.purge_tombstones(now) +
+            self.shard_map_store.purge_tombstones(now) +
+            self.key_value_store.purge_tombstones(now) +
+            self.federated_member_store.purge_tombstones(now)
+    }
+
+    /// Whether a member other than ourselves has departed, either from our ring or, for a member
+    /// of a peer ring, as relayed by its gateway or because relays about it stopped. Confirmed
+    /// members of our ring aren't gone: they may only be on the other side of a partition, and
+    /// they depart once they stay confirmed for long enough.
+    fn is_gone(&self, member_id: &str) -> bool {
+        if member_id == self.member_id() {
+            return false;
+        }
+        if let Some(health) = self.member_list.health_of_by_id(member_id) {
+            return health == Health::Departed;
+        }
+        let mut gone = false;
+        self.federated_member_store.with_keys(|(_, rumors)| {
+            if let Some(federated_member) = rumors.get(member_id) {
+                gone = federated_member.health() == Health::Departed;
+            }
+        });
+        gone
+    }
+
     #[allow(dead_code)]
     pub fn is_departed(&self) -> bool {
         self.departed.load(Ordering::Relaxed)
@@ -1043,7 +1684,7 @@ impl Serialize for Server {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("butterfly", 6)?;
+        let mut strukt = serializer.serialize_struct("butterfly", 12)?;
         strukt.serialize_field("member", &self.member_list)?;
         strukt.serialize_field("service", &self.service_store)?;
         strukt.serialize_field(
@@ -1060,6 +1701,13 @@ impl Serialize for Server {
             &self.update_store,
         )?;
         strukt.serialize_field("departure", &self.departure_store)?;
+        strukt.serialize_field("shard_map", &self.shard_map_store)?;
+        strukt.serialize_field("ring_key", &self.ring_key_store)?;
+        strukt.serialize_field("key_value", &self.key_value_store)?;
+        strukt.serialize_field(
+            "federated_member",
+            &self.federated_member_store,
+        )?;
         strukt.end()
     }
 }
@@ -1082,6 +1730,15 @@ impl Drop for Server {
     }
 }
 
+/// The term, whether it is finished, and the member voted for, of an election.
+fn election_state(election: &Election) -> (u64, bool, String) {
+    (
+        election.get_term(),
+        election.is_finished(),
+        String::from(election.get_member_id()),
+    )
+}
+
 fn persist_loop(server: Server) {
     loop {
         let next_check = Instant::now() + Duration::from_millis(30_000);
@@ -1094,13 +1751,26 @@ fn persist_loop(server: Server) {
 #[cfg(test)]
 mod tests {
     mod server {
+        use habitat_core::crypto::SigKeyPair;
+        use habitat_core::package::PackageIdent;
         use habitat_core::service::ServiceGroup;
+        use event::{ElectionKind, Event};
+        use message;
+        use message::swim::{Membership, Membership_Health as ProtoHealth, Rumor as ProtoRumor,
+                            Rumor_Type as ProtoRumor_Type};
         use server::{Server, Suitability};
         use server::timing::Timing;
-        use member::Member;
+        use member::{Health, Member};
+        use rumor::departure::Departure;
+        use rumor::election::Election;
+        use rumor::federated_member::FederatedMember;
+        use rumor::service::{Service, SysInfo};
+        use server::federation;
         use trace::Trace;
         use std::path::PathBuf;
+        use std::str::FromStr;
         use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+        use std::time::Duration;
 
         static SWIM_PORT: AtomicUsize = ATOMIC_USIZE_INIT;
         static GOSSIP_PORT: AtomicUsize = ATOMIC_USIZE_INIT;
@@ -1166,5 +1836,244 @@ mod tests {
                 "Server failed to start",
             );
         }
+
+        #[test]
+        fn elections_publish_their_start_and_finish() {
+            let server = start_server();
+            let events = server.subscribe();
+            let sg = ServiceGroup::from_str("witcher.prod").unwrap();
+            let mut election = Election::new(server.member_id(), sg, 0);
+            server.store_election(election.clone());
+            server.store_election(election.clone());
+            election.finish();
+            server.store_election(election);
+            let published: Vec<Event> = events.try_iter().collect();
+            assert_eq!(
+                published,
+                vec![
+                    Event::ElectionStarted {
+                        kind: ElectionKind::Leader,
+                        service_group: String::from("witcher.prod"),
+                        term: 0,
+                    },
+                    Event::ElectionFinished {
+                        kind: ElectionKind::Leader,
+                        service_group: String::from("witcher.prod"),
+                        term: 0,
+                        member_id: String::from(server.member_id()),
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn services_of_a_member_rejoining_after_a_partition_are_kept() {
+            let server = start_server();
+            let mut member = Member::default();
+            member.set_incarnation(1);
+            let member_id = String::from(member.get_id());
+            let sg = ServiceGroup::from_str("witcher.prod").unwrap();
+            let ident = PackageIdent::from_str("core/witcher/1.0.0/20170101000000").unwrap();
+            let service = Service::new(member_id.clone(), &ident, &sg, &SysInfo::default(), None);
+            server.insert_member(member.clone(), Health::Alive);
+            server.insert_service(service.clone());
+
+            // A partition gets the member confirmed, which doesn't expire its services
+            server.insert_member(member.clone(), Health::Confirmed);
+            server.expire_rumors(Duration::from_secs(0), Duration::from_secs(3600));
+            server.expire_rumors(Duration::from_secs(0), Duration::from_secs(3600));
+            assert!(server.service_store.contains_rumor(&sg.to_string(), &member_id));
+
+            // The member rejoins once the partition heals
+            member.set_incarnation(2);
+            server.insert_member(member.clone(), Health::Alive);
+            server.insert_service(service.clone());
+            assert!(server.service_store.contains_rumor(&sg.to_string(), &member_id));
+
+            // Once the member departed and its services expired, we may hear of it rejoining
+            // through gossip first, and only then from the member itself
+            server.insert_member(member.clone(), Health::Departed);
+            server.expire_rumors(Duration::from_secs(0), Duration::from_secs(3600));
+            server.expire_rumors(Duration::from_secs(0), Duration::from_secs(3600));
+            assert!(!server.service_store.contains_rumor(&sg.to_string(), &member_id));
+            member.set_incarnation(3);
+            server.insert_member_from_rumor(member.clone(), Health::Alive);
+            server.insert_member(member, Health::Alive);
+            server.insert_service(service);
+            assert!(server.service_store.contains_rumor(&sg.to_string(), &member_id));
+        }
+
+        #[test]
+        fn departures_are_only_accepted_from_the_departing_member_or_an_operator() {
+            let server = start_server();
+            let (departing, other) = (Member::default(), Member::default());
+            server.insert_member(departing.clone(), Health::Alive);
+            server.insert_member(other.clone(), Health::Alive);
+            let departing_identity = SigKeyPair::generate_pair_for_origin(departing.get_id())
+                .unwrap();
+            let operator = SigKeyPair::generate_pair_for_origin("operator").unwrap();
+            let sign = |identity: &SigKeyPair| {
+                let mut rumor: ProtoRumor = Departure::new(departing.get_id()).into();
+                message::sign_rumor(&mut rumor, identity).unwrap();
+                rumor
+            };
+            let sign_as = |signer: &str| {
+                sign(&SigKeyPair::generate_pair_for_origin(signer).unwrap())
+            };
+
+            // A member may not depart another member, even when the rumor reaches us second hand
+            assert!(!server.check_origin(&sign_as(other.get_id())));
+            assert!(server.check_origin(&sign(&departing_identity)));
+            // Nor may anyone else, without a list of trusted identities
+            assert!(!server.check_origin(&sign(&operator)));
+
+            // Once the member has signed, unsigned rumors about it are refused
+            let unsigned: ProtoRumor = Departure::new(departing.get_id()).into();
+            assert!(!server.check_origin(&unsigned));
+
+            // An operator may, once trusted, while other identities still may not
+            server.trust_identities(vec![departing_identity.clone(), operator.clone()]);
+            assert!(server.check_origin(&sign(&operator)));
+            assert!(server.check_origin(&sign(&departing_identity)));
+            assert!(!server.check_origin(&sign_as("operator")));
+            assert!(!server.check_origin(&sign_as("intruder")));
+        }
+
+        #[test]
+        fn relays_are_stamped_on_arrival_and_only_gossiped_when_health_changes() {
+            let server = start_server();
+            let member = Member::default();
+            let relay = |health: Health, relayed_at: u64| {
+                let mut membership = Membership::new();
+                membership.set_member(member.proto.clone());
+                membership.set_health(health.into());
+                let mut federated_member = FederatedMember::new("gateway", "us-east", membership);
+                federated_member.set_relayed_at(relayed_at);
+                federated_member
+            };
+            let stored = || {
+                let mut found = None;
+                server.federated_member_store.with_rumor(
+                    "us-east",
+                    member.get_id(),
+                    |federated_member| found = federated_member.cloned(),
+                );
+                found.unwrap()
+            };
+
+            // The clock of the peer ring's gateway doesn't matter
+            let before = federation::now_secs();
+            server.insert_federated_member(relay(Health::Alive, 1));
+            assert_eq!(stored().from_id(), server.member_id());
+            assert!(stored().get_relayed_at() >= before);
+
+            // Relays repeating the health we hold are no news
+            let counter = server.federated_member_store.get_update_counter();
+            server.insert_federated_member(relay(Health::Alive, 1));
+            assert_eq!(server.federated_member_store.get_update_counter(), counter);
+            server.insert_federated_member(relay(Health::Suspect, 1));
+            assert_eq!(stored().health(), Health::Suspect);
+            assert!(server.federated_member_store.get_update_counter() > counter);
+
+            // Once relays stop, we judge the member ourselves...
+            let mut silent = stored();
+            silent.set_health(Health::Alive);
+            silent.set_relayed_at(before - 2 * federation::SUSPICION_WINDOW_SECS - 1);
+            server.federated_member_store.remove("us-east", member.get_id());
+            server.federated_member_store.insert(silent.clone());
+            server.expire_federated_members();
+            assert_eq!(stored().health(), Health::Departed);
+
+            // ...which a member that only heard about it through our ring leaves to us
+            let other = start_server();
+            let mut gossiped: ProtoRumor = silent.into();
+            gossiped.set_from_id(String::from(server.member_id()));
+            other.insert_member(server.member.read().unwrap().clone(), Health::Alive);
+            other.insert_federated_member(gossiped.into());
+            other.expire_federated_members();
+            let mut health = None;
+            other.federated_member_store.with_rumor(
+                "us-east",
+                member.get_id(),
+                |federated_member| health = federated_member.map(|f| f.health()),
+            );
+            assert_eq!(health, Some(Health::Alive));
+        }
+
+        #[test]
+        fn members_are_only_declared_gone_by_themselves_or_an_operator() {
+            let server = start_server();
+            let (gone, other) = (Member::default(), Member::default());
+            server.insert_member(gone.clone(), Health::Alive);
+            server.insert_member(other.clone(), Health::Alive);
+            let rumor = |health: ProtoHealth, signer: &str| {
+                let mut membership = Membership::new();
+                membership.set_member(gone.proto.clone());
+                membership.set_health(health);
+                let mut rumor = ProtoRumor::new();
+                rumor.set_field_type(ProtoRumor_Type::Member);
+                rumor.set_member(membership);
+                rumor.set_from_id(String::from(signer));
+                let identity = SigKeyPair::generate_pair_for_origin(signer).unwrap();
+                message::sign_rumor(&mut rumor, &identity).unwrap();
+                rumor
+            };
+
+            // Members gossip each other's suspicion...
+            assert!(server.check_origin(&rumor(ProtoHealth::SUSPECT, other.get_id())));
+            // ...but may not declare each other departed or dead
+            assert!(!server.check_origin(&rumor(ProtoHealth::DEPARTED, other.get_id())));
+            assert!(!server.check_origin(&rumor(ProtoHealth::CONFIRMED, other.get_id())));
+            assert!(!server.check_origin(&rumor(ProtoHealth::DEPARTED, "operator")));
+            assert!(server.check_origin(&rumor(ProtoHealth::DEPARTED, gone.get_id())));
+        }
+
+        #[test]
+        fn service_rumors_are_bound_to_the_member_running_the_service() {
+            let server = start_server();
+            let (running, other) = (Member::default(), Member::default());
+            server.insert_member(running.clone(), Health::Alive);
+            server.insert_member(other.clone(), Health::Alive);
+            let sg = ServiceGroup::from_str("witcher.prod").unwrap();
+            let ident = PackageIdent::from_str("core/witcher/1.0.0/20170101000000").unwrap();
+            let rumor = |signer: &str| {
+                let service =
+                    Service::new(running.get_id(), &ident, &sg, &SysInfo::default(), None);
+                let mut rumor: ProtoRumor = service.into();
+                rumor.set_from_id(String::from(signer));
+                let identity = SigKeyPair::generate_pair_for_origin(signer).unwrap();
+                message::sign_rumor(&mut rumor, &identity).unwrap();
+                rumor
+            };
+
+            assert!(!server.check_origin(&rumor(other.get_id())));
+            assert!(!server.check_origin(&rumor("operator")));
+            assert!(server.check_origin(&rumor(running.get_id())));
+        }
+
+        #[test]
+        fn services_of_a_departed_member_come_back_when_it_rejoins() {
+            let server = start_server();
+            let mut member = Member::default();
+            member.set_incarnation(1);
+            let member_id = String::from(member.get_id());
+            let sg = ServiceGroup::from_str("witcher.prod").unwrap();
+            let ident = PackageIdent::from_str("core/witcher/1.0.0/20170101000000").unwrap();
+            let service = Service::new(member_id.clone(), &ident, &sg, &SysInfo::default(), None);
+            server.insert_member(member.clone(), Health::Alive);
+            server.insert_service(service.clone());
+
+            server.insert_member(member.clone(), Health::Departed);
+            server.expire_rumors(Duration::from_secs(0), Duration::from_secs(3600));
+            assert!(!server.service_store.contains_rumor(&sg.to_string(), &member_id));
+            // The tombstone keeps the rumor from being gossiped back in
+            server.insert_service(service.clone());
+            assert!(!server.service_store.contains_rumor(&sg.to_string(), &member_id));
+
+            member.set_incarnation(2);
+            server.insert_member(member, Health::Alive);
+            server.insert_service(service);
+            assert!(server.service_store.contains_rumor(&sg.to_string(), &member_id));
+        }
     }
 }
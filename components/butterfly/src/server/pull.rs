@@ -66,8 +66,8 @@ impl Pull {
                     continue 'recv;
                 }
             };
-            let payload = match self.server.unwrap_wire(&msg) {
-                Ok(payload) => payload,
+            let (payload, signer) = match self.server.unwrap_wire(&msg) {
+                Ok(unwrapped) => unwrapped,
                 Err(e) => {
                     // NOTE: In the future, we might want to blacklist people who send us
                     // garbage all the time.
@@ -89,6 +89,15 @@ impl Pull {
                 );
                 continue 'recv;
             }
+            // Rumors are relayed from member to member, so the member which pushed this one to us
+            // is not necessarily the one it came from.
+            if !self.server.check_signer(proto.get_from_id(), signer.as_ref(), true) {
+                continue 'recv;
+            }
+            // ...so the rumor itself must be signed by the member, or operator, which wrote it.
+            if !self.server.check_origin(&proto) {
+                continue 'recv;
+            }
             trace_it!(GOSSIP: &self.server, TraceKind::RecvRumor, proto.get_from_id(), &proto);
             match proto.get_field_type() {
                 Rumor_Type::Member => {
@@ -114,6 +123,18 @@ impl Pull {
                 Rumor_Type::Departure => {
                     self.server.insert_departure(proto.into());
                 }
+                Rumor_Type::ShardMap => {
+                    self.server.insert_shard_map(proto.into());
+                }
+                Rumor_Type::RingKey => {
+                    self.server.insert_ring_key(proto.into());
+                }
+                Rumor_Type::KeyValue => {
+                    self.server.insert_key_value(proto.into());
+                }
+                Rumor_Type::FederatedMember => {
+                    self.server.insert_federated_member(proto.into());
+                }
                 Rumor_Type::Fake |
                 Rumor_Type::Fake2 => debug!("Nothing to do for fake rumor types"),
             }
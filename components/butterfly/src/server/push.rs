@@ -277,6 +277,70 @@ impl PushWorker {
                         }
                     }
                 }
+                ProtoRumor_Type::ShardMap => {
+                    match self.server.shard_map_store.write_to_bytes(
+                        &rumor_key.key,
+                        &rumor_key.id,
+                    ) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            println!(
+                                "Could not write our own rumor to bytes; abandoning \
+                                            sending rumor: {:?}",
+                                e
+                            );
+                            continue 'rumorlist;
+                        }
+                    }
+                }
+                ProtoRumor_Type::RingKey => {
+                    match self.server.ring_key_store.write_to_bytes(
+                        &rumor_key.key,
+                        &rumor_key.id,
+                    ) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            println!(
+                                "Could not write our own rumor to bytes; abandoning \
+                                            sending rumor: {:?}",
+                                e
+                            );
+                            continue 'rumorlist;
+                        }
+                    }
+                }
+                ProtoRumor_Type::KeyValue => {
+                    match self.server.key_value_store.write_to_bytes(
+                        &rumor_key.key,
+                        &rumor_key.id,
+                    ) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            println!(
+                                "Could not write our own rumor to bytes; abandoning \
+                                            sending rumor: {:?}",
+                                e
+                            );
+                            continue 'rumorlist;
+                        }
+                    }
+                }
+                ProtoRumor_Type::FederatedMember => {
+                    match self.server.federated_member_store.write_to_bytes(
+                        &rumor_key.key,
+                        &rumor_key.id,
+                    ) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            println!(
+                                "Could not write our own rumor to bytes; abandoning \
+                                            sending rumor: {:?}",
+                                e
+                            );
+                            continue 'rumorlist;
+                        }
+                    }
+                }
                 ProtoRumor_Type::Election => {
                     // trace_it!(GOSSIP: &self.server,
                     //           TraceKind::SendRumor,
@@ -319,6 +383,13 @@ impl PushWorker {
                     continue 'rumorlist;
                 }
             };
+            let rumor_as_bytes = match self.server.sign_rumor(rumor_as_bytes) {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    error!("Signing rumor failed: {}", e);
+                    continue 'rumorlist;
+                }
+            };
             let payload = match self.server.generate_wire(rumor_as_bytes) {
                 Ok(payload) => payload,
                 Err(e) => {
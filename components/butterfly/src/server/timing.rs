@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use time::{SteadyTime, Duration as TimeDuration};
 
 /// How long to wait for an Ack after we ping
@@ -25,6 +27,12 @@ const GOSSIP_PERIOD_DEFAULT_MS: i64 = 1000;
 /// How long before we set a confirmed member to a departed member, removing them from quorums
 ///   just for your own sanity - this is 3 days.
 const DEPARTURE_TIMEOUT_DEFAULT_MS: i64 = 259200000;
+/// How long the rumors of a confirmed or departed member, or of an unloaded service, are kept
+///   before they expire - this is 1 day.
+const RUMOR_TTL_DEFAULT_MS: i64 = 86400000;
+/// How long the tombstone of an expired rumor is kept, so that members which have yet to expire
+///   the rumor cannot gossip it back - this is 7 days.
+const TOMBSTONE_TTL_DEFAULT_MS: i64 = 604800000;
 
 /// The timing of the outbound threads.
 #[derive(Debug, Clone)]
@@ -34,6 +42,8 @@ pub struct Timing {
     pub gossip_period_ms: i64,
     pub suspicion_timeout_protocol_periods: i64,
     pub departure_timeout_ms: i64,
+    pub rumor_ttl_ms: i64,
+    pub tombstone_ttl_ms: i64,
 }
 
 impl Default for Timing {
@@ -44,6 +54,8 @@ impl Default for Timing {
             gossip_period_ms: GOSSIP_PERIOD_DEFAULT_MS,
             suspicion_timeout_protocol_periods: SUSPICION_TIMEOUT_DEFAULT_PROTOCOL_PERIODS,
             departure_timeout_ms: DEPARTURE_TIMEOUT_DEFAULT_MS,
+            rumor_ttl_ms: RUMOR_TTL_DEFAULT_MS,
+            tombstone_ttl_ms: TOMBSTONE_TTL_DEFAULT_MS,
         }
     }
 }
@@ -63,6 +75,8 @@ impl Timing {
             gossip_period_ms: gossip_period_ms,
             suspicion_timeout_protocol_periods: suspicion_timeout_protocol_periods,
             departure_timeout_ms: departure_timeout_ms,
+            rumor_ttl_ms: RUMOR_TTL_DEFAULT_MS,
+            tombstone_ttl_ms: TOMBSTONE_TTL_DEFAULT_MS,
         }
     }
 
@@ -101,4 +115,16 @@ impl Timing {
     pub fn departure_timeout_duration(&self) -> TimeDuration {
         TimeDuration::milliseconds(self.departure_timeout_ms)
     }
+
+    /// How long before the rumors of a gone member or an unloaded service expire. A
+    /// `std::time::Duration`, as rumor expiry is measured in wall-clock time so that tombstones
+    /// can be persisted.
+    pub fn rumor_ttl(&self) -> Duration {
+        Duration::from_millis(self.rumor_ttl_ms as u64)
+    }
+
+    /// How long before the tombstone of an expired rumor expires.
+    pub fn tombstone_ttl(&self) -> Duration {
+        Duration::from_millis(self.tombstone_ttl_ms as u64)
+    }
 }
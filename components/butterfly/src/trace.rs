@@ -409,6 +409,35 @@ macro_rules! trace_it {
                     Rumor_Type::Departure => {
                         format!("{}", $payload.get_departure().get_member_id())
                     }
+                    Rumor_Type::ShardMap => {
+                        format!("{}-{}-{}-{}",
+                                $payload.get_shard_map().get_member_id(),
+                                $payload.get_shard_map().get_service_group(),
+                                $payload.get_shard_map().get_incarnation(),
+                                $payload.get_shard_map().get_shard_count())
+                    }
+                    Rumor_Type::RingKey => {
+                        format!("{}-{}-{}-{}",
+                                $payload.get_ring_key().get_member_id(),
+                                $payload.get_ring_key().get_name(),
+                                $payload.get_ring_key().get_revision(),
+                                $payload.get_ring_key().get_incarnation())
+                    }
+                    Rumor_Type::KeyValue => {
+                        format!("{}-{}-{}-{}",
+                                $payload.get_key_value().get_member_id(),
+                                $payload.get_key_value().get_service_group(),
+                                $payload.get_key_value().get_key(),
+                                $payload.get_key_value().get_incarnation())
+                    }
+                    Rumor_Type::FederatedMember => {
+                        let membership = $payload.get_federated_member().get_membership();
+                        format!("{}-{}-{}-{:?}",
+                                $payload.get_federated_member().get_ring(),
+                                membership.get_member().get_id(),
+                                membership.get_member().get_incarnation(),
+                                membership.get_health())
+                    }
                     Rumor_Type::Fake | Rumor_Type::Fake2 => format!("nothing-to-see"),
                 };
 
@@ -33,3 +33,37 @@ fn symmetric_encryption_of_wire_payloads() {
         |u| assert!(u.is_some()),
     );
 }
+
+#[test]
+fn rotating_the_ring_key_keeps_the_ring_together() {
+    let old_key = SymKey::generate_pair_for_ring("wolverine").expect(
+        "Failed to generate an in memory symkey",
+    );
+    let mut new_key = SymKey::generate_pair_for_ring("wolverine").expect(
+        "Failed to generate an in memory symkey",
+    );
+    new_key.rev = String::from("99991231235959");
+    let mut net = btest::SwimNet::new_ring_encryption(3, Some(old_key.clone()));
+    net.mesh();
+    assert_wait_for_health_of!(net, [0..3, 0..3], Health::Alive);
+
+    for server in net.iter() {
+        server.accept_ring_keys(vec![old_key.clone(), new_key.clone()]);
+        assert_eq!(server.primary_ring_key(), Some(old_key.name_with_rev()));
+    }
+    net[0].rotate_ring_key(&new_key.rev, 1).expect(
+        "Failed to rotate to an accepted key",
+    );
+    net.wait_for_gossip_rounds(2);
+    for server in net.iter() {
+        assert_eq!(server.primary_ring_key(), Some(new_key.name_with_rev()));
+    }
+    assert_wait_for_health_of!(net, [0..3, 0..3], Health::Alive);
+    net.add_service(2, "core/beast/1.2.3/20161208121212");
+    net.wait_for_gossip_rounds(2);
+    net[0].service_store.with_rumor(
+        "beast.prod",
+        net[2].member_id(),
+        |u| assert!(u.is_some()),
+    );
+}
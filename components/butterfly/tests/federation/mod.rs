@@ -0,0 +1,59 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::thread;
+use std::time::Duration;
+
+use habitat_butterfly::member::Health;
+use habitat_butterfly::server::federation::Federation;
+use habitat_core::service::ServiceGroup;
+use time::SteadyTime;
+
+use btest;
+
+#[test]
+fn gateways_relay_federated_service_groups_to_peer_rings() {
+    let mut west = btest::SwimNet::new(2);
+    west.mesh();
+    assert_wait_for_health_of!(west, [0..2, 0..2], Health::Alive);
+
+    let mut east = btest::SwimNet::new(2);
+    east.add_gateway(Federation::new(
+        "east",
+        vec![west[0].gossip_addr()],
+        vec![ServiceGroup::new(None, "beast", "prod", None).unwrap()],
+    ));
+    east.mesh();
+    assert_wait_for_health_of!(east, [0..3, 0..3], Health::Alive);
+    east.add_service(0, "core/beast/1.2.3/20161208121212");
+    east.add_service_config(0, "beast", "tcp-backlog = 128");
+
+    let member_id = String::from(east[0].member_id());
+    let timeout = SteadyTime::now() + ::time::Duration::seconds(30);
+    while !west[1].service_store.contains_rumor("beast.prod", &member_id) {
+        assert!(SteadyTime::now() < timeout, "Rumors were never relayed");
+        thread::sleep(Duration::from_millis(100));
+    }
+    west.wait_for_gossip_rounds(2);
+    for server in west.iter() {
+        assert!(server.service_config_store.contains_rumor(
+            "beast.prod",
+            "service_config",
+        ));
+        server.federated_member_store.with_rumor("east", &member_id, |f| {
+            assert_eq!(f.expect("No health for a relayed member").health(), Health::Alive)
+        });
+        assert!(!server.member_list.contains_member(&member_id));
+    }
+}
@@ -30,35 +30,51 @@
 //!
 //! # Internals
 //!
-//! * Download the artifact
+//! * Download the artifact, and any dependencies which aren't cached, several at a time
 //! * Verify it is un-altered
 //! * Unpack it
 //!
+//! Artifacts are fetched from the Supervisors listed in `HAB_ARTIFACT_PEERS` if they have them,
+//! and from Builder otherwise. Interrupted downloads pick up where they left off on the next
+//! attempt.
+//!
 
+use std::cmp;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::result::Result as StdResult;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
 
-use depot_client::{self, Client};
+use depot_client::{self, Client, DisplayProgress};
 use depot_client::Error::APIError;
 use hcore;
+use hcore::env as henv;
 use hcore::fs::{am_i_root, cache_key_path};
 use hcore::crypto::{artifact, SigKeyPair};
 use hcore::crypto::keys::parse_name_with_rev;
-use hcore::package::{Identifiable, PackageArchive, PackageIdent, Target, PackageInstall};
+use hcore::package::{Identifiable, PackageArchive, PackageIdent, PackageInstall, PackageTarget,
+                     Target};
 use hcore::package::metadata::PackageType;
 use hyper::status::StatusCode;
 
 use error::{Error, Result};
-use ui::{Status, UI};
+use ui::{ProgressBar, Status, UI};
 
 use retry::retry;
 
 pub const RETRIES: u64 = 5;
 pub const RETRY_WAIT: u64 = 3000;
 
+/// How many artifacts to download at once.
+pub const DOWNLOAD_CONCURRENCY_ENVVAR: &'static str = "HAB_DOWNLOAD_CONCURRENCY";
+pub const DEFAULT_DOWNLOAD_CONCURRENCY: usize = 4;
+/// A comma-separated list of Supervisor HTTP gateways (`host:port`) sharing their artifact
+/// caches, which are tried before Builder.
+pub const ARTIFACT_PEERS_ENVVAR: &'static str = "HAB_ARTIFACT_PEERS";
+
 /// Represents a locally-available `.hart` file for package
 /// installation purposes only.
 ///
@@ -222,7 +238,12 @@ where
 }
 
 struct InstallTask<'a> {
-    depot_client: Client,
+    depot_client: Arc<Client>,
+    /// Clients for the Supervisors sharing their artifact caches with us
+    peer_clients: Arc<Vec<Client>>,
+    download_concurrency: usize,
+    /// The target of the artifacts we install, which is always this system's own
+    target: PackageTarget,
     fs_root_path: &'a Path,
     /// The path to the local artifact cache (e.g., /hab/cache/artifacts)
     artifact_cache_path: &'a Path,
@@ -238,8 +259,31 @@ impl<'a> InstallTask<'a> {
         artifact_cache_path: &'a Path,
         key_cache_path: &'a Path,
     ) -> Result<Self> {
+        let mut peer_clients = Vec::new();
+        if let Ok(peers) = henv::var(ARTIFACT_PEERS_ENVVAR) {
+            for peer in peers.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+                let peer_url = if peer.contains("://") {
+                    peer.to_string()
+                } else {
+                    format!("http://{}", peer)
+                };
+                peer_clients.push(Client::new(
+                    peer_url.as_str(),
+                    product,
+                    version,
+                    Some(fs_root_path),
+                )?);
+            }
+        }
+        let download_concurrency = henv::var(DOWNLOAD_CONCURRENCY_ENVVAR)
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(DEFAULT_DOWNLOAD_CONCURRENCY);
         Ok(InstallTask {
-            depot_client: Client::new(url, product, version, Some(fs_root_path))?,
+            depot_client: Arc::new(Client::new(url, product, version, Some(fs_root_path))?),
+            peer_clients: Arc::new(peer_clients),
+            download_concurrency: cmp::max(download_concurrency, 1),
+            target: PackageTarget::default(),
             fs_root_path: fs_root_path,
             artifact_cache_path: artifact_cache_path,
             key_cache_path: key_cache_path,
@@ -423,13 +467,27 @@ impl<'a> InstallTask<'a> {
                 // original package itself, are cached locally.
                 let dependencies = artifact.tdeps()?;
                 let mut artifacts_to_install = Vec::with_capacity(dependencies.len() + 1);
+                let mut uninstalled = Vec::with_capacity(dependencies.len());
                 for dependency in dependencies.iter() {
                     if self.installed_package(dependency).is_some() {
                         ui.status(Status::Using, dependency)?;
                     } else {
-                        artifacts_to_install.push(self.get_cached_artifact(ui, dependency, token)?);
+                        uninstalled.push(dependency);
                     }
                 }
+                let (prefetched, unavailable) = self.prefetch_artifacts(ui, &uninstalled, token)?;
+                for dependency in uninstalled {
+                    let artifact = if unavailable.contains(dependency) {
+                        // The prefetch already retried it; doing so again would only repeat the
+                        // wait.
+                        return Err(download_failed(dependency));
+                    } else if prefetched.contains(dependency) {
+                        self.verify_cached_artifact(ui, dependency, true)?
+                    } else {
+                        self.get_cached_artifact(ui, dependency, token)?
+                    };
+                    artifacts_to_install.push(artifact);
+                }
                 // The package we're actually trying to install goes last; we
                 // want to ensure that its dependencies get installed before
                 // it does.
@@ -477,11 +535,12 @@ impl<'a> InstallTask<'a> {
         ident: &PackageIdent,
         token: Option<&str>,
     ) -> Result<PackageArchive> {
-        if self.is_artifact_cached(&ident)? {
+        let downloaded = if self.is_artifact_cached(&ident)? {
             debug!(
                 "Found {} in artifact cache, skipping remote download",
                 ident
             );
+            false
         } else {
             if retry(
                 RETRIES,
@@ -490,20 +549,119 @@ impl<'a> InstallTask<'a> {
                 |res| res.is_ok(),
             ).is_err()
             {
-                return Err(Error::from(depot_client::Error::DownloadFailed(format!(
-                    "We tried {} times but could not download {}. Giving up.",
-                    RETRIES,
-                    ident
-                ))));
+                return Err(download_failed(ident));
             }
-        }
+            true
+        };
+        self.verify_cached_artifact(ui, ident, downloaded)
+    }
 
+    /// Verifies the cached artifact, and returns a handle to it.
+    ///
+    /// An artifact we just downloaded which fails is removed from the cache. Otherwise a corrupt
+    /// download, such as one resumed onto a bad partial file, would fail every later install too.
+    fn verify_cached_artifact(
+        &self,
+        ui: &mut UI,
+        ident: &PackageIdent,
+        downloaded: bool,
+    ) -> Result<PackageArchive> {
         let mut artifact = PackageArchive::new(self.cached_artifact_path(ident)?);
-        ui.status(Status::Verifying, artifact.ident()?)?;
-        self.verify_artifact(ui, ident, &mut artifact)?;
+        let verified = match artifact.ident() {
+            Ok(artifact_ident) => {
+                ui.status(Status::Verifying, &artifact_ident)?;
+                self.verify_artifact(ui, ident, &mut artifact)
+            }
+            Err(e) => Err(Error::from(e)),
+        };
+        if let Err(e) = verified {
+            if downloaded {
+                debug!("Removing {} from the cache; {}", artifact.path.display(), e);
+                if let Err(err) = fs::remove_file(&artifact.path) {
+                    debug!("Could not remove {}: {}", artifact.path.display(), err);
+                }
+            }
+            return Err(e);
+        }
         Ok(artifact)
     }
 
+    /// Download the artifacts which aren't cached yet, several at a time.
+    ///
+    /// Returns the artifacts it downloaded, and those it gave up on after retrying. Anything else
+    /// is left for `get_cached_artifact` to download one at a time.
+    fn prefetch_artifacts(
+        &self,
+        ui: &mut UI,
+        idents: &[&PackageIdent],
+        token: Option<&str>,
+    ) -> Result<(Vec<PackageIdent>, Vec<PackageIdent>)> {
+        let mut missing = Vec::with_capacity(idents.len());
+        for ident in idents {
+            if !self.is_artifact_cached(ident)? {
+                missing.push((*ident).clone());
+            }
+        }
+        // With nothing to overlap, the one-at-a-time path does better; it can show progress.
+        if missing.len() < 2 || self.download_concurrency < 2 {
+            return Ok((Vec::new(), Vec::new()));
+        }
+        for ident in missing.iter() {
+            ui.status(Status::Downloading, ident)?;
+        }
+        let workers = cmp::min(self.download_concurrency, missing.len());
+        let queue = Arc::new(Mutex::new(missing));
+        let (tx, rx) = mpsc::channel();
+        for _ in 0..workers {
+            let queue = queue.clone();
+            let tx = tx.clone();
+            let depot_client = self.depot_client.clone();
+            let peer_clients = self.peer_clients.clone();
+            let target = self.target.clone();
+            let artifact_cache_path = self.artifact_cache_path.to_path_buf();
+            let token = token.map(String::from);
+            thread::Builder::new()
+                .name(String::from("download"))
+                .spawn(move || loop {
+                    let ident = match queue.lock().expect("Download queue lock poisoned").pop() {
+                        Some(ident) => ident,
+                        None => break,
+                    };
+                    let result = retry(
+                        RETRIES,
+                        RETRY_WAIT,
+                        || {
+                            fetch_from(
+                                &depot_client,
+                                &peer_clients,
+                                &ident,
+                                &target,
+                                token.as_ref().map(String::as_str),
+                                &artifact_cache_path,
+                                None::<ProgressBar>,
+                            )
+                        },
+                        |res| res.is_ok(),
+                    );
+                    if tx.send((ident, result.is_ok())).is_err() {
+                        break;
+                    }
+                })?;
+        }
+        drop(tx);
+        let (mut prefetched, mut unavailable) = (Vec::new(), Vec::new());
+        for (ident, downloaded) in rx.iter() {
+            if downloaded {
+                ui.status(Status::Cached, &ident)?;
+                prefetched.push(ident);
+            } else {
+                debug!("Could not prefetch {}", ident);
+                unavailable.push(ident);
+            }
+        }
+        Ok((prefetched, unavailable))
+    }
+
     /// Adapter function wrapping `PackageArchive::unpack`
     fn unpack_artifact(&self, ui: &mut UI, artifact: &mut PackageArchive) -> Result<()> {
         artifact.unpack(Some(self.fs_root_path))?;
@@ -527,7 +685,7 @@ impl<'a> InstallTask<'a> {
     /// the local package cache. It does not mean that the package is
     /// actually *in* the package cache, though.
     fn cached_artifact_path(&self, ident: &PackageIdent) -> Result<PathBuf> {
-        let name = fully_qualified_archive_name(ident)?;
+        let name = fully_qualified_archive_name(ident, &self.target)?;
         Ok(self.artifact_cache_path.join(name))
     }
 
@@ -539,7 +697,7 @@ impl<'a> InstallTask<'a> {
     ) -> Result<PackageIdent> {
         Ok(
             self.depot_client
-                .show_package(ident, channel, token)?
+                .show_package(ident, channel, &self.target, token)?
                 .into(),
         )
     }
@@ -548,8 +706,11 @@ impl<'a> InstallTask<'a> {
     /// the artifact is cached locally.
     fn fetch_artifact(&self, ui: &mut UI, ident: &PackageIdent, token: Option<&str>) -> Result<()> {
         ui.status(Status::Downloading, ident)?;
-        match self.depot_client.fetch_package(
+        match fetch_from(
+            &self.depot_client,
+            &self.peer_clients,
             ident,
+            &self.target,
             token,
             self.artifact_cache_path,
             ui.progress(),
@@ -630,11 +791,46 @@ impl<'a> InstallTask<'a> {
     }
 }
 
-/// Adapter function wrapping `PackageIdent::archive_name` that
+fn download_failed(ident: &PackageIdent) -> Error {
+    Error::from(depot_client::Error::DownloadFailed(format!(
+        "We tried {} times but could not download {}. Giving up.",
+        RETRIES,
+        ident
+    )))
+}
+
+/// Fetch an artifact into the cache from the first peer which has it, or else from Builder.
+///
+/// Peers are never sent the auth token. They all serve the same signed bytes as Builder, so a
+/// download which fails part way through can be resumed from any of them.
+fn fetch_from<D>(
+    depot_client: &Client,
+    peer_clients: &[Client],
+    ident: &PackageIdent,
+    target: &PackageTarget,
+    token: Option<&str>,
+    artifact_cache_path: &Path,
+    progress: Option<D>,
+) -> StdResult<(), depot_client::Error>
+where
+    D: DisplayProgress + Sized,
+{
+    for peer_client in peer_clients {
+        match peer_client.fetch_package(ident, target, None, artifact_cache_path, None::<D>) {
+            Ok(_) => return Ok(()),
+            Err(e) => debug!("Peer could not provide {}: {}", ident, e),
+        }
+    }
+    depot_client
+        .fetch_package(ident, target, token, artifact_cache_path, progress)
+        .map(|_| ())
+}
+
+/// Adapter function wrapping `PackageIdent::archive_name_with_target` that
 /// returns an error if the identifier is not fully-qualified
 /// (only fully-qualified identifiers can yield an archive name).
-fn fully_qualified_archive_name(ident: &PackageIdent) -> Result<String> {
-    ident.archive_name().ok_or(Error::HabitatCore(
+fn fully_qualified_archive_name(ident: &PackageIdent, target: &PackageTarget) -> Result<String> {
+    ident.archive_name_with_target(target).ok_or(Error::HabitatCore(
         hcore::Error::FullyQualifiedPackageIdentRequired(
             ident.to_string(),
         ),
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -30,6 +31,12 @@ use super::super::{PUBLIC_KEY_SUFFIX, PUBLIC_SIG_KEY_VERSION, SECRET_SIG_KEY_SUF
 
 pub type SigKeyPair = KeyPair<SigPublicKey, SigSecretKey>;
 
+impl fmt::Debug for SigKeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SigKeyPair {}", self.name_with_rev())
+    }
+}
+
 impl SigKeyPair {
     pub fn generate_pair_for_origin(name: &str) -> Result<Self> {
         let revision = mk_revision_string()?;
@@ -37,6 +44,53 @@ impl SigKeyPair {
         Ok(Self::new(name.to_string(), revision, Some(pk), Some(sk)))
     }
 
+    /// Builds a pair holding only the public key, from the raw bytes of the key.
+    ///
+    /// # Errors
+    ///
+    /// * If the name with revision cannot be parsed
+    /// * If the bytes are not a sig public key
+    pub fn from_public_key_bytes(name_with_rev: &str, bytes: &[u8]) -> Result<Self> {
+        let (name, rev) = parse_name_with_rev(name_with_rev)?;
+        match SigPublicKey::from_slice(bytes) {
+            Some(pk) => Ok(Self::new(name, rev, Some(pk), None)),
+            None => Err(Error::CryptoError(
+                format!("Can't read sig public key for {}", name_with_rev),
+            )),
+        }
+    }
+
+    /// Signs a byte slice with the secret key, returning the detached signature.
+    ///
+    /// # Errors
+    ///
+    /// * If the secret key component of the `SigKeyPair` is not present
+    pub fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+        Ok(sign::sign_detached(data, self.secret()?).0.to_vec())
+    }
+
+    /// Verifies a detached signature of a byte slice with the public key.
+    ///
+    /// # Errors
+    ///
+    /// * If the public key component of the `SigKeyPair` is not present
+    /// * If the signature is malformed, or was not made by this pair's secret key
+    pub fn verify(&self, data: &[u8], signature: &[u8]) -> Result<()> {
+        let pk = self.public()?;
+        let signature = match sign::Signature::from_slice(signature) {
+            Some(signature) => signature,
+            None => return Err(Error::CryptoError("Invalid size of signature".to_string())),
+        };
+        if sign::verify_detached(&signature, data, pk) {
+            Ok(())
+        } else {
+            Err(Error::CryptoError(format!(
+                "Signature does not match {}",
+                self.name_with_rev()
+            )))
+        }
+    }
+
     /// Return a Vec of origin keys with a given name.
     /// The newest key is listed first in the Vec.
     pub fn get_pairs_for<P: AsRef<Path> + ?Sized>(
@@ -530,6 +584,24 @@ mod test {
         );
     }
 
+    #[test]
+    fn sign_and_verify() {
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn").unwrap();
+        let signature = pair.sign(b"a rumor").unwrap();
+        pair.verify(b"a rumor", &signature).unwrap();
+        assert!(pair.verify(b"another rumor", &signature).is_err());
+
+        let public = SigKeyPair::from_public_key_bytes(
+            &pair.name_with_rev(),
+            &pair.public().unwrap()[..],
+        ).unwrap();
+        assert!(public.sign(b"a rumor").is_err());
+        public.verify(b"a rumor", &signature).unwrap();
+
+        let other = SigKeyPair::generate_pair_for_origin("unicorn").unwrap();
+        assert!(other.verify(b"a rumor", &signature).is_err());
+    }
+
     #[test]
     fn get_pairs_for() {
         let cache = TempDir::new("key_cache").unwrap();
@@ -39,6 +39,7 @@ pub struct Uname {
 #[derive(Debug, Hash, Clone, Serialize, Deserialize, Eq, PartialEq)]
 pub enum Architecture {
     X86_64,
+    Aarch64,
 }
 
 impl fmt::Display for Architecture {
@@ -51,14 +52,22 @@ impl fmt::Display for Architecture {
 #[derive(Debug, Hash, Clone, Serialize, Deserialize, Eq, PartialEq)]
 pub enum Platform {
     Linux,
+    /// Linux hosts still running a 2.x kernel, which need packages built against older kernel
+    /// headers.
+    LinuxKernel2,
     Windows,
     Darwin,
 }
 
 impl fmt::Display for Platform {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let platform_string = format!("{:?}", self);
-        write!(f, "{}", platform_string.to_lowercase())
+        match *self {
+            Platform::LinuxKernel2 => write!(f, "linux-kernel2"),
+            _ => {
+                let platform_string = format!("{:?}", self);
+                write!(f, "{}", platform_string.to_lowercase())
+            }
+        }
     }
 }
 
@@ -69,6 +78,7 @@ impl FromStr for Architecture {
         let architecture = value.trim().to_lowercase();
         match architecture.as_ref() {
             "x86_64" => Ok(Architecture::X86_64),
+            "aarch64" => Ok(Architecture::Aarch64),
             _ => return Err(Error::InvalidArchitecture(value.to_string())),
         }
     }
@@ -81,6 +91,7 @@ impl FromStr for Platform {
         let platform = value.trim().to_lowercase();
         match platform.as_ref() {
             "linux" => Ok(Platform::Linux),
+            "linux-kernel2" => Ok(Platform::LinuxKernel2),
             "windows" => Ok(Platform::Windows),
             "darwin" => Ok(Platform::Darwin),
             _ => return Err(Error::InvalidPlatform(value.to_string())),
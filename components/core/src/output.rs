@@ -20,15 +20,18 @@
 //! where the output was generated within the Supervisor. Also supported is a `preamble`, which is
 //! used to denote when output comes from a running service rather than the Supervisor itself.
 //!
-//! The `StructuredOutput` struct supports two global options - verbosity and coloring. If verbose
-//! is turned on, then every line printed is annotated with its preamble, logkey, and precise
-//! location. Without verbose, it prints simply the preamble and logkey. Coloring does what it says
-//! on the tin :)
+//! The `StructuredOutput` struct supports three global options - verbosity, coloring and JSON. If
+//! verbose is turned on, then every line printed is annotated with its preamble, logkey, and
+//! precise location. Without verbose, it prints simply the preamble and logkey. Coloring does what
+//! it says on the tin :) With JSON turned on, every line is instead a JSON object carrying a
+//! timestamp, level, service group, source and message, for log shippers to consume.
 
 use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
 use std::fmt;
 
 use ansi_term::Colour::{White, Cyan, Green};
+use serde_json;
+use time;
 
 use PROGRAM_NAME;
 
@@ -36,6 +39,7 @@ static mut VERBOSE: AtomicBool = ATOMIC_BOOL_INIT;
 // I am sorry this isn't named the other way; I can't get an atomic initializer that defaults to
 // true. Them's the breaks.
 static mut NO_COLOR: AtomicBool = ATOMIC_BOOL_INIT;
+static mut JSON: AtomicBool = ATOMIC_BOOL_INIT;
 
 /// True if verbose output is on.
 pub fn is_verbose() -> bool {
@@ -67,6 +71,53 @@ pub fn set_no_color(booly: bool) {
     }
 }
 
+/// True if output is formatted as JSON.
+pub fn is_json() -> bool {
+    unsafe { JSON.load(Ordering::Relaxed) }
+}
+
+/// Set to true if you want every line of output to be a JSON object.
+pub fn set_json(booly: bool) {
+    unsafe {
+        JSON.store(booly, Ordering::Relaxed);
+    }
+}
+
+/// How severe a line of output is. Only JSON output carries the level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Level {
+    Info,
+    Error,
+}
+
+impl Level {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Level::Info => "info",
+            Level::Error => "error",
+        }
+    }
+}
+
+impl Default for Level {
+    fn default() -> Level {
+        Level::Info
+    }
+}
+
+/// A line of output, as serialized in JSON.
+#[derive(Serialize)]
+struct JsonLine<'a> {
+    timestamp: String,
+    level: &'a str,
+    service_group: Option<&'a str>,
+    source: &'a str,
+    logkey: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+    message: &'a str,
+}
+
 /// Adds structure to printed output. Stores a preamble, a logkey, line, file, column, and content
 /// to print.
 pub struct StructuredOutput<'a> {
@@ -76,8 +127,12 @@ pub struct StructuredOutput<'a> {
     file: &'static str,
     column: u32,
     content: &'a str,
+    /// The hook or process the content came from, if it didn't come from the Supervisor itself.
+    pub source: Option<&'a str>,
+    pub level: Level,
     pub verbose: Option<bool>,
     pub color: Option<bool>,
+    pub json: Option<bool>,
 }
 
 impl<'a> StructuredOutput<'a> {
@@ -97,8 +152,35 @@ impl<'a> StructuredOutput<'a> {
             file: file,
             column: column,
             content: content,
+            source: None,
+            level: Level::default(),
             verbose: None,
             color: None,
+            json: None,
+        }
+    }
+
+    fn fmt_json(&self, f: &mut fmt::Formatter, verbose: bool) -> fmt::Result {
+        let line = JsonLine {
+            timestamp: format!("{}", time::now_utc().rfc3339()),
+            level: self.level.as_str(),
+            service_group: if self.preamble == PROGRAM_NAME.as_str() {
+                None
+            } else {
+                Some(self.preamble)
+            },
+            source: self.source.unwrap_or("supervisor"),
+            logkey: self.logkey,
+            location: if verbose {
+                Some(format!("{}:{}:{}", self.file, self.line, self.column))
+            } else {
+                None
+            },
+            message: self.content.trim_right_matches(|c| c == '\n' || c == '\r'),
+        };
+        match serde_json::to_string(&line) {
+            Ok(json) => write!(f, "{}", json),
+            Err(_) => Err(fmt::Error),
         }
     }
 }
@@ -109,6 +191,9 @@ impl<'a> StructuredOutput<'a> {
 impl<'a> fmt::Display for StructuredOutput<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let verbose = self.verbose.unwrap_or(is_verbose());
+        if self.json.unwrap_or(is_json()) {
+            return self.fmt_json(f, verbose);
+        }
         let color = self.color.unwrap_or(is_color());
         let preamble_color = if self.preamble == PROGRAM_NAME.as_str() {
             Cyan
@@ -351,8 +436,9 @@ macro_rules! output_format {
 
 #[cfg(test)]
 mod tests {
-    use super::StructuredOutput;
+    use super::{Level, StructuredOutput};
     use ansi_term::Colour::{White, Cyan};
+    use serde_json::{self, Value};
 
     use PROGRAM_NAME;
 
@@ -378,6 +464,35 @@ mod tests {
         assert_eq!(format!("{}", so), "soup(SOT): opeth is amazing");
     }
 
+    #[test]
+    fn format_json() {
+        let mut so = so("redis.default", "opeth is amazing\n");
+        so.verbose = Some(false);
+        so.json = Some(true);
+        so.source = Some("hook:init");
+        so.level = Level::Error;
+        let line: Value = serde_json::from_str(&format!("{}", so)).unwrap();
+        assert_eq!(line["level"], "error");
+        assert_eq!(line["service_group"], "redis.default");
+        assert_eq!(line["source"], "hook:init");
+        assert_eq!(line["logkey"], "SOT");
+        assert_eq!(line["message"], "opeth is amazing");
+        assert!(line["timestamp"].is_string());
+        assert!(line.get("location").is_none());
+    }
+
+    #[test]
+    fn format_json_from_supervisor() {
+        let mut so = so(PROGRAM_NAME.as_str(), "opeth is amazing");
+        so.verbose = Some(true);
+        so.json = Some(true);
+        let line: Value = serde_json::from_str(&format!("{}", so)).unwrap();
+        assert_eq!(line["level"], "info");
+        assert!(line["service_group"].is_null());
+        assert_eq!(line["source"], "supervisor");
+        assert_eq!(line["location"], format!("{}:1:2", file!()));
+    }
+
     #[test]
     fn format_color() {
         let progname = PROGRAM_NAME.as_str();
@@ -100,13 +100,12 @@ impl PackageIdent {
     fn archive_name_impl(&self, ref target: &PackageTarget) -> Option<String> {
         if self.fully_qualified() {
             Some(format!(
-                "{}-{}-{}-{}-{}-{}.hart",
+                "{}-{}-{}-{}-{}.hart",
                 self.origin,
                 self.name,
                 self.version.as_ref().unwrap(),
                 self.release.as_ref().unwrap(),
-                target.architecture,
-                target.platform
+                target
             ))
         } else {
             None
@@ -385,6 +384,22 @@ mod tests {
     use super::split_version;
     use std::cmp::Ordering;
     use std::cmp::PartialOrd;
+    use std::str::FromStr;
+
+    #[test]
+    fn archive_name_with_target() {
+        let ident = PackageIdent::from_str("acme/redis/3.0.7/21120102031201").unwrap();
+        let aarch64 = PackageTarget::from_str("aarch64-linux").unwrap();
+        let kernel2 = PackageTarget::from_str("x86_64-linux-kernel2").unwrap();
+        assert_eq!(
+            ident.archive_name_with_target(&aarch64).unwrap(),
+            "acme-redis-3.0.7-21120102031201-aarch64-linux.hart"
+        );
+        assert_eq!(
+            ident.archive_name_with_target(&kernel2).unwrap(),
+            "acme-redis-3.0.7-21120102031201-x86_64-linux-kernel2.hart"
+        );
+    }
 
     #[test]
     fn package_ident_partial_eq() {
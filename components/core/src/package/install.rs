@@ -84,6 +84,18 @@ impl PackageInstall {
         }
     }
 
+    /// Returns the identifiers of every installed release of every package.
+    ///
+    /// An optional `fs_root` path may be provided to search for packages that are mounted on a
+    /// filesystem not currently rooted at `/`.
+    pub fn all(fs_root_path: Option<&Path>) -> Result<Vec<PackageIdent>> {
+        let package_root_path = fs::pkg_root_path(fs_root_path);
+        if !package_root_path.exists() {
+            return Ok(vec![]);
+        }
+        Self::package_list(&package_root_path)
+    }
+
     fn resolve_package_install<T>(
         ident: &PackageIdent,
         fs_root_path: Option<T>,
@@ -730,7 +742,7 @@ impl fmt::Display for PackageInstall {
 mod test {
     use package::metadata::{BindMapping, MetaFile};
     use std::collections::HashMap;
-    use std::fs::File;
+    use std::fs::{self, File};
     use std::io::Write;
     use std::path::PathBuf;
     use std::str::FromStr;
@@ -857,5 +869,26 @@ core/bar=pub:core/publish sub:core/subscribe
 
     }
 
+    #[test]
+    fn all_lists_every_installed_release() {
+        let fs_root = TempDir::new("all_installed").expect(
+            "Could not create fs_root temporary directory",
+        );
+        assert!(PackageInstall::all(Some(fs_root.path())).unwrap().is_empty());
+        for ident in &["core/foo/1.0.0/20170101000000", "core/foo/1.0.0/20170202000000"] {
+            let ident = PackageIdent::from_str(ident).unwrap();
+            fs::create_dir_all(::fs::pkg_install_path(&ident, Some(fs_root.path()))).unwrap();
+        }
+        let mut all = PackageInstall::all(Some(fs_root.path())).unwrap();
+        all.sort();
+        assert_eq!(
+            all,
+            vec![
+                PackageIdent::from_str("core/foo/1.0.0/20170101000000").unwrap(),
+                PackageIdent::from_str("core/foo/1.0.0/20170202000000").unwrap(),
+            ]
+        );
+    }
+
 
 }
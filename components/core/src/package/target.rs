@@ -16,10 +16,16 @@ use std::fmt;
 use std::result;
 use std::str::FromStr;
 
+use env;
 use error::{Error, Result};
 use os::system::{Architecture, Platform};
 use util::{deserialize_using_from_str, serialize_using_to_string};
 
+/// Set to `true` on a Linux host to use packages for the `linux-kernel2` platform, which are built
+/// against older kernel headers for hosts still running a 2.x kernel. Nothing else is ever given
+/// that platform, so a host must opt in to it.
+pub const LINUX_KERNEL2_ENVVAR: &'static str = "HAB_LINUX_KERNEL2";
+
 pub trait Target: fmt::Display + Into<PackageTarget> {
     fn validate(&self) -> Result<()>;
 }
@@ -53,7 +59,7 @@ impl PackageTarget {
         if cfg!(target_os = "windows") {
             Platform::Windows
         } else if cfg!(target_os = "linux") {
-            Platform::Linux
+            linux_platform(env::var(LINUX_KERNEL2_ENVVAR).ok().as_ref().map(String::as_str))
         } else if cfg!(target_os = "macos") {
             Platform::Darwin
         } else {
@@ -64,6 +70,8 @@ impl PackageTarget {
     pub fn current_architecture() -> Architecture {
         if cfg!(target_arch = "x86_64") {
             Architecture::X86_64
+        } else if cfg!(target_arch = "aarch64") {
+            Architecture::Aarch64
         } else {
             unreachable!("binary built for an unknown architecture")
         }
@@ -103,11 +111,20 @@ impl fmt::Display for PackageTarget {
     }
 }
 
+/// The Linux platform to use, given the value of `LINUX_KERNEL2_ENVVAR`.
+fn linux_platform(kernel2: Option<&str>) -> Platform {
+    match kernel2 {
+        Some("true") => Platform::LinuxKernel2,
+        _ => Platform::Linux,
+    }
+}
+
 impl FromStr for PackageTarget {
     type Err = Error;
 
     fn from_str(value: &str) -> result::Result<Self, Self::Err> {
-        let items: Vec<&str> = value.split("-").collect();
+        // Architectures never contain a dash, but platforms may (ex: `x86_64-linux-kernel2`)
+        let items: Vec<&str> = value.splitn(2, "-").collect();
         let (architecture, platform) = match items.len() {
             2 => {
                 (
@@ -133,7 +150,9 @@ mod tests {
         if cfg!(target_os = "windows") {
             assert_eq!(target.platform, Platform::Windows);
         } else if cfg!(target_os = "linux") {
-            assert_eq!(target.platform, Platform::Linux);
+            assert!(
+                target.platform == Platform::Linux || target.platform == Platform::LinuxKernel2
+            );
         } else if cfg!(target_os = "macos") {
             assert_eq!(target.platform, Platform::Darwin);
         } else {
@@ -142,7 +161,11 @@ mod tests {
                           compilation to PackageTarget::current_platform()"
             );
         }
-        assert_eq!(target.architecture, Architecture::X86_64);
+        if cfg!(target_arch = "aarch64") {
+            assert_eq!(target.architecture, Architecture::Aarch64);
+        } else {
+            assert_eq!(target.architecture, Architecture::X86_64);
+        }
     }
 
     #[test]
@@ -152,6 +175,29 @@ mod tests {
         assert_eq!(target.architecture, Architecture::X86_64);
     }
 
+    #[test]
+    fn package_target_from_string_with_dashed_platform() {
+        let target = PackageTarget::from_str("x86_64-linux-kernel2").unwrap();
+        assert_eq!(target.platform, Platform::LinuxKernel2);
+        assert_eq!(target.architecture, Architecture::X86_64);
+        assert_eq!(target.to_string(), "x86_64-linux-kernel2");
+    }
+
+    #[test]
+    fn linux_kernel2_is_opt_in() {
+        assert_eq!(linux_platform(None), Platform::Linux);
+        assert_eq!(linux_platform(Some("false")), Platform::Linux);
+        assert_eq!(linux_platform(Some("true")), Platform::LinuxKernel2);
+    }
+
+    #[test]
+    fn package_target_from_string_for_aarch64() {
+        let target = PackageTarget::from_str("aarch64-linux").unwrap();
+        assert_eq!(target.platform, Platform::Linux);
+        assert_eq!(target.architecture, Architecture::Aarch64);
+        assert_eq!(target.to_string(), "aarch64-linux");
+    }
+
     #[test]
     #[should_panic]
     fn package_target_with_reversed_target_string() {
@@ -182,12 +228,14 @@ mod tests {
     }
 
     fn current_platform_target() -> PackageTarget {
+        let arch = PackageTarget::current_architecture();
         if cfg!(target_os = "windows") {
-            return PackageTarget::from_str("x86_64-windows").unwrap();
+            return PackageTarget::from_str(&format!("{}-windows", arch)).unwrap();
         } else if cfg!(target_os = "linux") {
-            PackageTarget::from_str("x86_64-linux").unwrap()
+            PackageTarget::from_str(&format!("{}-{}", arch, PackageTarget::current_platform()))
+                .unwrap()
         } else if cfg!(target_os = "macos") {
-            PackageTarget::from_str("x86_64-darwin").unwrap()
+            PackageTarget::from_str(&format!("{}-darwin", arch)).unwrap()
         } else {
             unreachable!(
                 "Test case not defined for target_os! Fix this by adding a conditional \
@@ -41,6 +41,8 @@ pub fn get() -> App<'static, 'static> {
                     (default: 127.0.0.1:9638)")
             (@arg RING: -r --ring +takes_value
              "Ring key name, which will encrypt communication messages")
+            (@arg IDENTITY: --identity +takes_value
+             "Identity key name, which will sign communication messages")
         )
         (@subcommand file =>
             (about: "Commands relating to Habitat files")
@@ -61,8 +63,62 @@ pub fn get() -> App<'static, 'static> {
                     (default: 127.0.0.1:9638)")
                 (@arg RING: -r --ring +takes_value
                     "Ring key name, which will encrypt communication messages")
+                (@arg IDENTITY: --identity +takes_value
+                    "Identity key name, which will sign communication messages")
             )
         )
+        (@subcommand kv =>
+            (about: "Commands relating to key/value pairs gossiped to Habitat service groups")
+            (@setting ArgRequiredElseHelp)
+            (subcommand: sub_kv_set())
+        )
+        (@subcommand ring =>
+            (about: "Commands relating to Habitat rings")
+            (aliases: &["r", "ri", "rin"])
+            (@setting ArgRequiredElseHelp)
+            (@subcommand key =>
+                (about: "Commands relating to Habitat ring keys")
+                (aliases: &["k", "ke"])
+                (@setting ArgRequiredElseHelp)
+                (subcommand: sub_ring_key_rotate())
+            )
+        )
+    )
+}
+
+fn sub_ring_key_rotate() -> App<'static, 'static> {
+    clap_app!(@subcommand rotate =>
+        (about: "Switches a Supervisor ring to encrypting with another revision of its ring key")
+        (@arg RING: +required +takes_value "Ring key name")
+        (@arg REVISION: +takes_value
+            "Revision of the ring key to encrypt with, which every Supervisor in the ring \
+            must already have (default: the latest revision)")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg IDENTITY: --identity +takes_value
+            "Identity key name, which will sign communication messages")
+    )
+}
+
+fn sub_kv_set() -> App<'static, 'static> {
+    clap_app!(@subcommand set =>
+        (about: "Sets a key/value pair for a group of Habitat Supervisors")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg IDENTITY: --identity +takes_value
+            "Identity key name, which will sign communication messages")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg KEY: +required {valid_kv_key}
+            "The key, made of letters, numbers, dashes and underscores (ex: maintenance)")
+        (@arg VALUE: +required "The value (ex: true)")
+        (@arg VERSION_NUMBER: +required
+            "A version number (positive integer) for this value; the highest version wins (ex: 42)")
+        (@arg ORG: --org +takes_value "Name of service organization")
     )
 }
 
@@ -74,6 +130,8 @@ fn sub_config_apply() -> App<'static, 'static> {
             (default: 127.0.0.1:9638)")
         (@arg RING: -r --ring +takes_value
             "Ring key name, which will encrypt communication messages")
+        (@arg IDENTITY: --identity +takes_value
+            "Identity key name, which will sign communication messages")
         (@arg SERVICE_GROUP: +required {valid_service_group}
             "Target service group (ex: redis.default)")
         (@arg VERSION_NUMBER: +required
@@ -97,6 +155,21 @@ fn file_exists_or_stdin(val: String) -> result::Result<(), String> {
     if val == "-" { Ok(()) } else { file_exists(val) }
 }
 
+fn valid_kv_key(val: String) -> result::Result<(), String> {
+    let valid_char = |c: char| match c {
+        'a'...'z' | 'A'...'Z' | '0'...'9' | '_' | '-' => true,
+        _ => false,
+    };
+    if !val.is_empty() && val.chars().all(valid_char) {
+        Ok(())
+    } else {
+        Err(format!(
+            "'{}' is not valid. Keys are made of letters, numbers, dashes and underscores",
+            &val
+        ))
+    }
+}
+
 fn valid_service_group(val: String) -> result::Result<(), String> {
     match ServiceGroup::validate(&val) {
         Ok(()) => Ok(()),
@@ -21,7 +21,7 @@ pub mod apply {
 
     use butterfly::client::Client;
     use common::ui::{Status, UI};
-    use hcore::crypto::{SymKey, BoxKeyPair};
+    use hcore::crypto::{SigKeyPair, SymKey, BoxKeyPair};
     use hcore::service::ServiceGroup;
     use toml;
 
@@ -34,6 +34,7 @@ pub mod apply {
         file_path: Option<&Path>,
         peers: &Vec<String>,
         ring_key: Option<&SymKey>,
+        identity: Option<&SigKeyPair>,
         user_pair: Option<&BoxKeyPair>,
         service_pair: Option<&BoxKeyPair>,
     ) -> Result<()> {
@@ -98,6 +99,9 @@ pub mod apply {
                     Error::ButterflyError(format!("{}", e))
                 },
             )?;
+            if let Some(identity) = identity {
+                client.set_identity(identity.clone());
+            }
             client
                 .send_service_config(sg.clone(), number, body.clone(), encrypted)
                 .map_err(|e| Error::ButterflyError(format!("{}", e)))?;
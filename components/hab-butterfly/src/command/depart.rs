@@ -17,7 +17,7 @@ use std::time;
 
 use butterfly::client::Client;
 use common::ui::{Status, UI};
-use hcore::crypto::SymKey;
+use hcore::crypto::{SigKeyPair, SymKey};
 
 use error::{Error, Result};
 
@@ -26,6 +26,7 @@ pub fn run(
     member_id: &str,
     peers: Vec<String>,
     ring_key: Option<SymKey>,
+    identity: Option<SigKeyPair>,
 ) -> Result<()> {
     ui.begin(
         format!("Permanently marking {} as departed", member_id),
@@ -39,6 +40,9 @@ pub fn run(
         let mut client = Client::new(peer, ring_key.clone()).map_err(|e| {
             Error::ButterflyError(e.to_string())
         })?;
+        if let Some(ref identity) = identity {
+            client.set_identity(identity.clone());
+        }
         client.send_departure(member_id).map_err(|e| {
             Error::ButterflyError(e.to_string())
         })?;
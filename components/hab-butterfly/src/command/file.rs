@@ -21,7 +21,7 @@ pub mod upload {
 
     use butterfly::client::Client;
     use common::ui::{Status, UI};
-    use hcore::crypto::{SymKey, BoxKeyPair};
+    use hcore::crypto::{SigKeyPair, SymKey, BoxKeyPair};
     use hcore::service::ServiceGroup;
 
     use error::{Error, Result};
@@ -33,6 +33,7 @@ pub mod upload {
         file_path: &Path,
         peers: &Vec<String>,
         ring_key: Option<&SymKey>,
+        identity: Option<&SigKeyPair>,
         user_pair: Option<&BoxKeyPair>,
         service_pair: Option<&BoxKeyPair>,
     ) -> Result<()> {
@@ -79,6 +80,9 @@ pub mod upload {
                     Error::ButterflyError(format!("{}", e))
                 },
             )?;
+            if let Some(identity) = identity {
+                client.set_identity(identity.clone());
+            }
             client
                 .send_service_file(
                     sg.clone(),
@@ -15,3 +15,5 @@
 pub mod config;
 pub mod depart;
 pub mod file;
+pub mod kv;
+pub mod ring;
@@ -0,0 +1,67 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod key {
+    pub mod rotate {
+        use std::thread;
+        use std::time::{self, SystemTime, UNIX_EPOCH};
+
+        use butterfly::client::Client;
+        use common::ui::{Status, UI};
+        use hcore::crypto::{SigKeyPair, SymKey};
+
+        use error::{Error, Result};
+
+        /// Gossips that `ring_key` is now the primary key of its ring. Supervisors holding the
+        /// key switch to encrypting with it; every other revision they hold keeps being
+        /// accepted, so members which haven't switched yet can still be heard.
+        pub fn start(
+            ui: &mut UI,
+            ring_key: &SymKey,
+            identity: Option<&SigKeyPair>,
+            peers: &Vec<String>,
+        ) -> Result<()> {
+            ui.begin(format!(
+                "Rotating ring {} to key {}",
+                ring_key.name,
+                ring_key.name_with_rev()
+            ))?;
+            // The wall clock orders rotations, so the latest rotation wins wherever it's sent.
+            let incarnation = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            for peer in peers.into_iter() {
+                ui.status(Status::Applying, format!("to peer {}", peer))?;
+                // Sent encrypted with the new key, which every member must hold before rotating.
+                let mut client = Client::new(peer, Some(ring_key.clone())).map_err(|e| {
+                    Error::ButterflyError(e.to_string())
+                })?;
+                if let Some(identity) = identity {
+                    client.set_identity(identity.clone());
+                }
+                client
+                    .send_ring_key(ring_key.name.clone(), ring_key.rev.clone(), incarnation)
+                    .map_err(|e| Error::ButterflyError(e.to_string()))?;
+                // please take a moment to weep over the following line
+                // of code. We must sleep to allow messages to be sent
+                // before freeing the socket to prevent loss.
+                // see https://github.com/zeromq/libzmq/issues/1264
+                thread::sleep(time::Duration::from_millis(100));
+            }
+            ui.end("Ring key rotation recorded.")?;
+            Ok(())
+        }
+    }
+}
@@ -37,7 +37,8 @@ use clap::ArgMatches;
 
 use common::ui::UI;
 use hcore::env as henv;
-use hcore::crypto::{init, default_cache_key_path, BoxKeyPair, SymKey};
+use hcore::crypto::{init, default_cache_key_path, BoxKeyPair, SigKeyPair, SymKey};
+use hcore::crypto::keys::PairType;
 use hcore::service::ServiceGroup;
 
 use hab_butterfly::{analytics, cli, command};
@@ -100,6 +101,23 @@ fn start(ui: &mut UI) -> Result<()> {
                 _ => unreachable!(),
             }
         }
+        ("kv", Some(matches)) => {
+            match matches.subcommand() {
+                ("set", Some(m)) => sub_kv_set(ui, m)?,
+                _ => unreachable!(),
+            }
+        }
+        ("ring", Some(matches)) => {
+            match matches.subcommand() {
+                ("key", Some(m)) => {
+                    match m.subcommand() {
+                        ("rotate", Some(sc)) => sub_ring_key_rotate(ui, sc)?,
+                        _ => unreachable!(),
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
         _ => unreachable!(),
     };
     Ok(())
@@ -122,7 +140,66 @@ fn sub_depart(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         Some(name) => Some(SymKey::get_latest_pair_for(&name, &cache)?),
         None => None,
     };
-    command::depart::run(ui, member_id, peers, ring_key)
+    let identity = identity_param(&m, &cache)?;
+    command::depart::run(ui, member_id, peers, ring_key, identity)
+}
+
+fn sub_kv_set(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    let number = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
+    let key = m.value_of("KEY").unwrap(); // Required via clap
+    let value = m.value_of("VALUE").unwrap(); // Required via clap
+
+    init();
+    let cache = default_cache_key_path(Some(&*FS_ROOT));
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(SymKey::get_latest_pair_for(&name, &cache)?),
+        None => None,
+    };
+
+    let mut sg = ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap())?;
+    if let Some(org) = org_param_or_env(&m) {
+        sg.set_org(org);
+    }
+    let identity = identity_param(&m, &cache)?;
+    command::kv::set::start(
+        ui,
+        &sg,
+        key,
+        value,
+        number,
+        &peers,
+        ring_key.as_ref(),
+        identity.as_ref(),
+    )
+}
+
+fn sub_ring_key_rotate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    let ring = m.value_of("RING").unwrap(); // Required via clap
+
+    init();
+    let cache = default_cache_key_path(Some(&*FS_ROOT));
+    let ring_key = match m.value_of("REVISION") {
+        Some(rev) => SymKey::get_pair_for(&format!("{}-{}", ring, rev), &cache)?,
+        None => SymKey::get_latest_pair_for(&ring, &cache)?,
+    };
+    let identity = identity_param(&m, &cache)?;
+    command::ring::key::rotate::start(ui, &ring_key, identity.as_ref(), &peers)
 }
 
 fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
@@ -160,6 +237,7 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         Some(username) => Some(BoxKeyPair::get_latest_pair_for(username, &cache)?),
         None => None,
     };
+    let identity = identity_param(&m, &cache)?;
     command::config::apply::start(
         ui,
         &sg,
@@ -167,6 +245,7 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         file_path,
         &peers,
         ring_key.as_ref(),
+        identity.as_ref(),
         user_pair.as_ref(),
         service_pair.as_ref(),
     )
@@ -220,6 +299,7 @@ fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         Some(username) => Some(BoxKeyPair::get_latest_pair_for(username, &cache)?),
         None => None,
     };
+    let identity = identity_param(&m, &cache)?;
     command::file::upload::start(
         ui,
         &sg,
@@ -227,6 +307,7 @@ fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         file_path,
         &peers,
         ring_key.as_ref(),
+        identity.as_ref(),
         user_pair.as_ref(),
         service_pair.as_ref(),
     )
@@ -285,3 +366,13 @@ fn user_param_or_env(m: &ArgMatches) -> Option<String> {
         }
     }
 }
+
+/// Load the identity named by the IDENTITY param, if any, to sign the traffic with.
+fn identity_param(m: &ArgMatches, cache: &Path) -> Result<Option<SigKeyPair>> {
+    match m.value_of("IDENTITY") {
+        Some(name) => Ok(Some(
+            SigKeyPair::get_latest_pair_for(name, cache, Some(&PairType::Secret))?,
+        )),
+        None => Ok(None),
+    }
+}
@@ -18,6 +18,7 @@ use std::str::FromStr;
 
 use clap::{App, AppSettings, Arg};
 use hcore::crypto::keys::PairType;
+use hcore::package::PackageTarget;
 use regex::Regex;
 use url::Url;
 
@@ -54,6 +55,11 @@ pub fn get() -> App<'static, 'static> {
             (@setting ArgRequiredElseHelp)
             (subcommand: sub_config_apply().aliases(&["a", "ap", "app", "appl"]))
         )
+        (@subcommand kv =>
+            (about: "Commands relating to key/value pairs gossiped to Habitat service groups")
+            (@setting ArgRequiredElseHelp)
+            (subcommand: sub_kv_set())
+        )
         (@subcommand file =>
             (about: "Commands relating to Habitat files")
             (aliases: &["f", "fi", "fil"])
@@ -73,6 +79,8 @@ pub fn get() -> App<'static, 'static> {
                     (default: 127.0.0.1:9638)")
                 (@arg RING: -r --ring +takes_value
                     "Ring key name, which will encrypt communication messages")
+                (@arg IDENTITY: --identity +takes_value
+                    "Identity key name, which will sign communication messages")
             )
         )
         (@subcommand bldr =>
@@ -95,6 +103,9 @@ pub fn get() -> App<'static, 'static> {
                     (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
                     (@arg GROUP: -g --group "Schedule jobs for this package and all of its reverse \
                         dependencies")
+                    (@arg TARGET: -t --target +takes_value {valid_target}
+                        "The package target to build for (ex: x86_64-linux, aarch64-linux, \
+                        x86_64-linux-kernel2) (default: x86_64-linux)")
                 )
                 (@subcommand cancel =>
                     (about: "Cancel a build job group and any in-progress builds")
@@ -320,6 +331,12 @@ pub fn get() -> App<'static, 'static> {
                     "Retrieve the container's package from the specified release channel \
                     (default: stable)")
             )
+            (@subcommand gc =>
+                (about: "Removes old releases of installed packages, and cached artifacts which \
+                    are no longer needed")
+                (@arg KEEP: --keep +takes_value {valid_release_count}
+                    "The number of releases of each package to keep (default: 1)")
+            )
             (@subcommand hash =>
                 (about: "Generates a blake2b hashsum from a target at any given filepath")
                 (aliases: &["ha", "has"])
@@ -362,6 +379,12 @@ pub fn get() -> App<'static, 'static> {
                     "The destination path to the signed Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
             )
+            (@subcommand uninstall =>
+                (about: "Removes an installed package, unless another installed package or a \
+                    loaded service still needs it")
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+            )
             (@subcommand upload =>
                 (about: "Uploads a local Habitat Artifact to Builder")
                 (aliases: &["u", "up", "upl", "uplo", "uploa"])
@@ -476,6 +499,7 @@ pub fn get() -> App<'static, 'static> {
                     (aliases: &["g", "ge", "gen", "gene", "gener", "genera", "generat"])
                     (@arg RING: +required +takes_value "Ring key name")
                 )
+                (subcommand: sub_ring_key_rotate())
             )
         )
         (@subcommand svc =>
@@ -628,6 +652,42 @@ fn sub_cli_completers() -> App<'static, 'static> {
     )
 }
 
+fn sub_ring_key_rotate() -> App<'static, 'static> {
+    clap_app!(@subcommand rotate =>
+        (about: "Switches a Supervisor ring to encrypting with another revision of its ring key")
+        (@arg RING: +required +takes_value "Ring key name")
+        (@arg REVISION: +takes_value
+            "Revision of the ring key to encrypt with, which every Supervisor in the ring \
+            must already have (default: the latest revision)")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg IDENTITY: --identity +takes_value
+            "Identity key name, which will sign communication messages")
+    )
+}
+
+fn sub_kv_set() -> App<'static, 'static> {
+    clap_app!(@subcommand set =>
+        (about: "Sets a key/value pair for a group of Habitat Supervisors")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg IDENTITY: --identity +takes_value
+            "Identity key name, which will sign communication messages")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg KEY: +required {valid_kv_key}
+            "The key, made of letters, numbers, dashes and underscores (ex: maintenance)")
+        (@arg VALUE: +required "The value (ex: true)")
+        (@arg VERSION_NUMBER: +required
+            "A version number (positive integer) for this value; the highest version wins (ex: 42)")
+        (@arg ORG: --org +takes_value "Name of service organization")
+    )
+}
+
 fn sub_config_apply() -> App<'static, 'static> {
     clap_app!(@subcommand apply =>
         (about: "Applies a configuration to a group of Habitat Supervisors")
@@ -636,6 +696,8 @@ fn sub_config_apply() -> App<'static, 'static> {
             (default: 127.0.0.1:9638)")
         (@arg RING: -r --ring +takes_value
             "Ring key name, which will encrypt communication messages")
+        (@arg IDENTITY: --identity +takes_value
+            "Identity key name, which will sign communication messages")
         (@arg SERVICE_GROUP: +required {valid_service_group}
             "Target service group (ex: redis.default)")
         (@arg VERSION_NUMBER: +required
@@ -733,6 +795,28 @@ fn valid_pair_type(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_kv_key(val: String) -> result::Result<(), String> {
+    let valid_char = |c: char| match c {
+        'a'...'z' | 'A'...'Z' | '0'...'9' | '_' | '-' => true,
+        _ => false,
+    };
+    if !val.is_empty() && val.chars().all(valid_char) {
+        Ok(())
+    } else {
+        Err(format!(
+            "'{}' is not valid. Keys are made of letters, numbers, dashes and underscores",
+            &val
+        ))
+    }
+}
+
+fn valid_release_count(val: String) -> result::Result<(), String> {
+    match val.parse::<usize>() {
+        Ok(count) if count > 0 => Ok(()),
+        _ => Err(format!("'{}' is not valid, must be a number greater than 0", &val)),
+    }
+}
+
 fn valid_service_group(val: String) -> result::Result<(), String> {
     let regex = Regex::new(r"([A-Za-z_0-9]+)\.([A-Za-z_0-9]+)").unwrap();
     if regex.is_match(&val) {
@@ -742,6 +826,13 @@ fn valid_service_group(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_target(val: String) -> result::Result<(), String> {
+    match PackageTarget::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("TARGET: '{}' is not a valid package target", &val)),
+    }
+}
+
 fn valid_url(val: String) -> result::Result<(), String> {
     match Url::parse(&val) {
         Ok(_) => Ok(()),
@@ -15,7 +15,7 @@
 use api_client::Client as ApiClient;
 use depot_client::Client as DepotClient;
 use common::ui::{Status, UI};
-use hcore::package::PackageIdent;
+use hcore::package::{PackageIdent, PackageTarget};
 
 use {PRODUCT, VERSION};
 use error::{Error, Result};
@@ -24,6 +24,7 @@ pub fn start(
     ui: &mut UI,
     bldr_url: &str,
     ident: &PackageIdent,
+    target: &PackageTarget,
     token: &str,
     group: bool,
 ) -> Result<()> {
@@ -56,10 +57,10 @@ pub fn start(
 
     ui.status(
         Status::Creating,
-        format!("build job for {}", ident),
+        format!("build job for {} ({})", ident, target),
     )?;
 
-    let id = depot_client.schedule_job(ident, !group, token).map_err(
+    let id = depot_client.schedule_job(ident, target, !group, token).map_err(
         Error::DepotClient,
     )?;
 
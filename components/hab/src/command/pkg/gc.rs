@@ -0,0 +1,232 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Removes old releases of installed packages, and cached artifacts nothing needs any more.
+//!
+//! The newest `keep` releases of every installed package are kept, along with everything they
+//! depend on and everything the services loaded into, or running under, this host's Supervisors
+//! need. Cached artifacts are kept for whatever is still installed, and for loaded services whose
+//! packages haven't been installed yet.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use common::ui::{Status, UI};
+use hcore::package::{Identifiable, PackageArchive, PackageIdent, PackageInstall};
+
+use command::pkg::uninstall::{loaded_services, needed_by, remove, running_services};
+use error::Result;
+
+const ARTIFACT_EXT: &'static str = "hart";
+
+pub fn start(
+    ui: &mut UI,
+    keep: usize,
+    fs_root_path: &Path,
+    artifact_cache_path: &Path,
+) -> Result<()> {
+    ui.begin(format!(
+        "Removing all but the newest {} release(s) of each package",
+        keep
+    ))?;
+    let mut releases: BTreeMap<(String, String), Vec<PackageIdent>> = BTreeMap::new();
+    for ident in PackageInstall::all(Some(fs_root_path))? {
+        releases
+            .entry((ident.origin.clone(), ident.name.clone()))
+            .or_insert(Vec::new())
+            .push(ident);
+    }
+    let mut needed = HashSet::new();
+    let mut candidates = Vec::new();
+    for (_, mut idents) in releases {
+        idents.sort_by(|a, b| b.cmp(a));
+        for (i, ident) in idents.into_iter().enumerate() {
+            if i < keep {
+                if let Ok(pkg_install) = PackageInstall::load(&ident, Some(fs_root_path)) {
+                    needed.extend(pkg_install.tdeps()?);
+                }
+                needed.insert(ident);
+            } else {
+                candidates.push(ident);
+            }
+        }
+    }
+    // Services whose packages aren't installed yet only need what's in the artifact cache
+    let mut pending = Vec::new();
+    for service in loaded_services(fs_root_path)? {
+        let service_needs = needed_by(&service, fs_root_path)?;
+        if service_needs.is_empty() {
+            pending.push(service);
+        }
+        needed.extend(service_needs);
+    }
+    for service in running_services(fs_root_path)? {
+        needed.extend(needed_by(&service, fs_root_path)?);
+    }
+
+    let mut removed_pkgs = 0;
+    for ident in candidates {
+        if needed.contains(&ident) {
+            ui.status(Status::Using, format!("{}, which is still needed", &ident))?;
+            continue;
+        }
+        remove(ui, &ident, fs_root_path)?;
+        removed_pkgs += 1;
+    }
+
+    let removed_artifacts = prune_artifacts(ui, fs_root_path, artifact_cache_path, &pending)?;
+    ui.end(format!(
+        "Removed {} package release(s) and {} cached artifact(s)",
+        removed_pkgs,
+        removed_artifacts
+    ))?;
+    Ok(())
+}
+
+fn prune_artifacts(
+    ui: &mut UI,
+    fs_root_path: &Path,
+    artifact_cache_path: &Path,
+    pending: &[PackageIdent],
+) -> Result<usize> {
+    if !artifact_cache_path.is_dir() {
+        return Ok(0);
+    }
+    let mut needed: HashSet<PackageIdent> = PackageInstall::all(Some(fs_root_path))?
+        .into_iter()
+        .collect();
+    let mut artifacts = Vec::new();
+    for entry in fs::read_dir(artifact_cache_path)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some(ARTIFACT_EXT) {
+            continue;
+        }
+        let mut archive = PackageArchive::new(path.clone());
+        let ident = match archive.ident() {
+            Ok(ident) => ident,
+            Err(err) => {
+                debug!("Skipping artifact {}, {}", path.display(), err);
+                continue;
+            }
+        };
+        if pending.iter().any(|service| ident.satisfies(service)) {
+            needed.extend(archive.tdeps()?);
+            needed.insert(ident.clone());
+        }
+        artifacts.push((path, ident));
+    }
+    let mut removed = 0;
+    for (path, ident) in artifacts {
+        if needed.contains(&ident) {
+            continue;
+        }
+        ui.status(Status::Deleting, path.display())?;
+        fs::remove_file(&path)?;
+        removed += 1;
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use hcore;
+    use hcore::package::{PackageArchive, PackageIdent};
+    use tempdir::TempDir;
+
+    use command::pkg::test_support::{fake_pkg_install, ui, write_file};
+    use super::start;
+
+    #[test]
+    fn keeps_newest_releases_and_their_dependencies() {
+        let rootfs = TempDir::new("rootfs").unwrap();
+        let cache = rootfs.path().join("hab/cache/artifacts");
+        let old_lib = fake_pkg_install("acme/lib/1.0.0/20170101000000", &[], rootfs.path());
+        let new_lib = fake_pkg_install("acme/lib/1.1.0/20170101000000", &[], rootfs.path());
+        let old_app = fake_pkg_install("acme/app/1.0.0/20170101000000", &[], rootfs.path());
+        let new_app =
+            fake_pkg_install("acme/app/1.0.0/20170202000000", &[&old_lib], rootfs.path());
+
+        start(&mut ui(), 1, rootfs.path(), &cache).unwrap();
+
+        assert!(installed(&old_lib, rootfs.path()));
+        assert!(installed(&new_lib, rootfs.path()));
+        assert!(!installed(&old_app, rootfs.path()));
+        assert!(installed(&new_app, rootfs.path()));
+    }
+
+    #[test]
+    fn keeps_releases_running_as_services() {
+        let rootfs = TempDir::new("rootfs").unwrap();
+        let cache = rootfs.path().join("hab/cache/artifacts");
+        let old_app = fake_pkg_install("acme/app/1.0.0/20170101000000", &[], rootfs.path());
+        fake_pkg_install("acme/app/1.0.0/20170202000000", &[], rootfs.path());
+        write_file(
+            rootfs.path().join("hab/sup/default/data/services.dat"),
+            &format!(r#"[{{"pkg": {{"ident": "{}"}}}}]"#, old_app),
+        );
+
+        start(&mut ui(), 1, rootfs.path(), &cache).unwrap();
+
+        assert!(installed(&old_app, rootfs.path()));
+    }
+
+    #[test]
+    fn prunes_artifacts_nothing_needs() {
+        let rootfs = TempDir::new("rootfs").unwrap();
+        let cache = rootfs.path().join("hab/cache/artifacts");
+        let artifact = cache_artifact(&cache);
+        let ident = PackageArchive::new(artifact.clone()).ident().unwrap();
+        write_file(cache.join("notes.txt"), "not an artifact");
+
+        // Kept for a loaded service which hasn't been installed yet
+        let spec = rootfs.path().join("hab/sup/default/specs/possums.spec");
+        write_file(
+            &spec,
+            &format!("ident = \"{}/{}\"\n", ident.origin, ident.name),
+        );
+        start(&mut ui(), 1, rootfs.path(), &cache).unwrap();
+        assert!(artifact.is_file());
+
+        // Kept while installed
+        fs::remove_file(&spec).unwrap();
+        fake_pkg_install(&ident.to_string(), &[], rootfs.path());
+        start(&mut ui(), 1, rootfs.path(), &cache).unwrap();
+        assert!(artifact.is_file());
+
+        // Pruned once nothing needs it
+        fs::remove_dir_all(hcore::fs::pkg_root_path(Some(rootfs.path()))).unwrap();
+        start(&mut ui(), 1, rootfs.path(), &cache).unwrap();
+        assert!(!artifact.exists());
+        assert!(cache.join("notes.txt").is_file());
+    }
+
+    fn installed(ident: &PackageIdent, rootfs: &Path) -> bool {
+        hcore::fs::pkg_install_path(ident, Some(rootfs)).is_dir()
+    }
+
+    /// Copies a real artifact, which `PackageArchive` can read, into the cache.
+    fn cache_artifact(cache: &Path) -> PathBuf {
+        let name = "happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart";
+        let fixture = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../core/tests/fixtures")
+            .join(name);
+        fs::create_dir_all(cache).unwrap();
+        fs::copy(&fixture, cache.join(name)).unwrap();
+        cache.join(name)
+    }
+}
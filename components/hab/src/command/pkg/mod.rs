@@ -19,6 +19,7 @@ pub mod demote;
 pub mod env;
 pub mod exec;
 pub mod export;
+pub mod gc;
 pub mod hash;
 pub mod header;
 pub mod path;
@@ -26,5 +27,45 @@ pub mod promote;
 pub mod provides;
 pub mod search;
 pub mod sign;
+pub mod uninstall;
 pub mod upload;
 pub mod verify;
+
+#[cfg(test)]
+pub mod test_support {
+    use std::fs::{self, File};
+    use std::io::{self, Write};
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use common::ui::{Coloring, UI};
+    use hcore;
+    use hcore::package::{PackageIdent, PackageTarget};
+
+    pub fn ui() -> UI {
+        UI::with_streams(
+            Box::new(io::empty()),
+            || Box::new(io::sink()),
+            || Box::new(io::sink()),
+            Coloring::Never,
+            false,
+        )
+    }
+
+    /// Installs just enough of a package under `rootfs` for `PackageInstall` to load it.
+    pub fn fake_pkg_install(ident: &str, tdeps: &[&PackageIdent], rootfs: &Path) -> PackageIdent {
+        let ident = PackageIdent::from_str(ident).unwrap();
+        let prefix = hcore::fs::pkg_install_path(&ident, Some(rootfs));
+        let tdeps: Vec<String> = tdeps.iter().map(|d| d.to_string()).collect();
+        write_file(prefix.join("IDENT"), &ident.to_string());
+        write_file(prefix.join("TARGET"), &PackageTarget::default().to_string());
+        write_file(prefix.join("TDEPS"), &tdeps.join("\n"));
+        ident
+    }
+
+    pub fn write_file<P: AsRef<Path>>(file: P, content: &str) {
+        fs::create_dir_all(file.as_ref().parent().unwrap()).unwrap();
+        let mut f = File::create(file).unwrap();
+        f.write_all(content.as_bytes()).unwrap();
+    }
+}
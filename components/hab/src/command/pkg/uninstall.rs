@@ -0,0 +1,277 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Removes an installed package.
+//!
+//! A package is only removed when nothing still needs it: it may not be in the transitive
+//! dependencies of any other installed package, nor be run or depended on by a service loaded
+//! into, or running under, one of this host's Supervisors. Supervisors started with a custom
+//! `--state-path` keep their specs and state somewhere we can't find, so their services aren't
+//! taken into account.
+
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use common::ui::{Status, UI};
+use hcore::fs as hfs;
+use hcore::package::{PackageIdent, PackageInstall};
+use serde_json;
+use toml;
+
+use error::{Error, Result};
+
+/// The directory, relative to the filesystem root, holding the state of every named Supervisor.
+const SUP_STATE_PATH: &'static str = "hab/sup";
+const SPEC_FILE_EXT: &'static str = "spec";
+/// The file, in a Supervisor's data directory, recording the services it is running.
+const SERVICES_DATA_FILE: &'static str = "services.dat";
+
+/// The one part of a Supervisor's service spec we care about.
+#[derive(Deserialize)]
+struct LoadedSpec {
+    ident: String,
+}
+
+/// The one part of a Supervisor's record of a running service we care about.
+#[derive(Deserialize)]
+struct RunningService {
+    pkg: RunningPkg,
+}
+
+#[derive(Deserialize)]
+struct RunningPkg {
+    ident: String,
+}
+
+pub fn start(ui: &mut UI, ident: &PackageIdent, fs_root_path: &Path) -> Result<()> {
+    let pkg_install = PackageInstall::load(ident, Some(fs_root_path))?;
+    let ident = pkg_install.ident().clone();
+    ui.begin(format!("Uninstalling {}", &ident))?;
+    let mut users = Vec::new();
+    for installed in PackageInstall::all(Some(fs_root_path))? {
+        if installed == ident {
+            continue;
+        }
+        // Packages we can't load, such as those for another target, can't be run here either
+        if let Ok(other) = PackageInstall::load(&installed, Some(fs_root_path)) {
+            if other.tdeps()?.contains(&ident) {
+                users.push(installed.to_string());
+            }
+        }
+    }
+    for service in loaded_services(fs_root_path)? {
+        if needed_by(&service, fs_root_path)?.contains(&ident) {
+            users.push(format!("the loaded {} service", service));
+        }
+    }
+    for service in running_services(fs_root_path)? {
+        if needed_by(&service, fs_root_path)?.contains(&ident) {
+            users.push(format!("the running {} service", service));
+        }
+    }
+    if !users.is_empty() {
+        return Err(Error::PackageInUse(ident.to_string(), users));
+    }
+    remove(ui, &ident, fs_root_path)?;
+    ui.end(format!("Uninstall of {} complete", &ident))?;
+    Ok(())
+}
+
+/// Removes an installed package, along with the version, name and origin directories above it
+/// if it was the last thing in them.
+pub fn remove(ui: &mut UI, ident: &PackageIdent, fs_root_path: &Path) -> Result<()> {
+    let pkg_root = hfs::pkg_root_path(Some(fs_root_path));
+    let installed_path = hfs::pkg_install_path(ident, Some(fs_root_path));
+    ui.status(Status::Deleting, ident)?;
+    fs::remove_dir_all(&installed_path)?;
+    let mut dir = installed_path.parent();
+    while let Some(path) = dir {
+        // `remove_dir` refuses to remove a directory which still has something in it
+        if path == pkg_root.as_path() || !path.starts_with(&pkg_root) ||
+            fs::remove_dir(path).is_err()
+        {
+            break;
+        }
+        dir = path.parent();
+    }
+    ui.status(Status::Deleted, ident)?;
+    Ok(())
+}
+
+/// Returns the package identifiers of the services loaded into every Supervisor on this host,
+/// as they were given when the services were loaded.
+pub fn loaded_services(fs_root_path: &Path) -> Result<Vec<PackageIdent>> {
+    let mut idents = Vec::new();
+    for sup_path in sup_state_paths(fs_root_path)? {
+        let specs_path = sup_path.join("specs");
+        if !specs_path.is_dir() {
+            continue;
+        }
+        for spec in fs::read_dir(&specs_path)? {
+            let spec_path = spec?.path();
+            if spec_path.extension().and_then(|e| e.to_str()) != Some(SPEC_FILE_EXT) {
+                continue;
+            }
+            match read_spec_ident(&spec_path) {
+                Ok(ident) => idents.push(ident),
+                Err(err) => debug!("Skipping spec {}, {}", spec_path.display(), err),
+            }
+        }
+    }
+    Ok(idents)
+}
+
+/// Returns the fully qualified package identifiers of the services every Supervisor on this host
+/// is running. A loaded service's spec may name only `origin/name`, and the release it runs
+/// needn't be the newest installed, so only this says which release is in use.
+pub fn running_services(fs_root_path: &Path) -> Result<Vec<PackageIdent>> {
+    let mut idents = Vec::new();
+    for sup_path in sup_state_paths(fs_root_path)? {
+        let data_path = sup_path.join("data").join(SERVICES_DATA_FILE);
+        if !data_path.is_file() {
+            continue;
+        }
+        match read_running_idents(&data_path) {
+            Ok(running) => idents.extend(running),
+            Err(err) => debug!("Skipping {}, {}", data_path.display(), err),
+        }
+    }
+    Ok(idents)
+}
+
+/// Returns the installed package which a loaded service runs, along with its transitive
+/// dependencies. Nothing is needed by a service whose package isn't installed yet.
+pub fn needed_by(service: &PackageIdent, fs_root_path: &Path) -> Result<Vec<PackageIdent>> {
+    match PackageInstall::load(service, Some(fs_root_path)) {
+        Ok(pkg_install) => {
+            let mut needed = pkg_install.tdeps()?;
+            needed.push(pkg_install.ident().clone());
+            Ok(needed)
+        }
+        Err(_) => Ok(Vec::new()),
+    }
+}
+
+/// Returns the state directory of every named Supervisor on this host.
+fn sup_state_paths(fs_root_path: &Path) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    let sup_state_path = fs_root_path.join(SUP_STATE_PATH);
+    if !sup_state_path.is_dir() {
+        return Ok(paths);
+    }
+    for sup in fs::read_dir(&sup_state_path)? {
+        paths.push(sup?.path());
+    }
+    Ok(paths)
+}
+
+fn read_running_idents(data_path: &Path) -> Result<Vec<PackageIdent>> {
+    let file = File::open(data_path)?;
+    let services: Vec<RunningService> = serde_json::from_reader(file)?;
+    let mut idents = Vec::with_capacity(services.len());
+    for service in services {
+        idents.push(PackageIdent::from_str(&service.pkg.ident)?);
+    }
+    Ok(idents)
+}
+
+fn read_spec_ident(spec_path: &Path) -> Result<PackageIdent> {
+    let mut file = File::open(spec_path)?;
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)?;
+    let spec: LoadedSpec = toml::from_str(&contents)?;
+    Ok(PackageIdent::from_str(&spec.ident)?)
+}
+
+#[cfg(test)]
+mod test {
+    use tempdir::TempDir;
+
+    use command::pkg::test_support::{fake_pkg_install, ui, write_file};
+    use error::Error;
+    use hcore;
+    use super::start;
+
+    #[test]
+    fn packages_still_needed_are_not_uninstalled() {
+        let rootfs = TempDir::new("rootfs").unwrap();
+        let lib = fake_pkg_install("acme/lib/1.0.0/20170101000000", &[], rootfs.path());
+        let app = fake_pkg_install("acme/app/1.0.0/20170101000000", &[&lib], rootfs.path());
+
+        match start(&mut ui(), &lib, rootfs.path()) {
+            Err(Error::PackageInUse(_, users)) => assert_eq!(users, vec![app.to_string()]),
+            other => panic!("Expected PackageInUse, got {:?}", other),
+        }
+        assert!(hcore::fs::pkg_install_path(&lib, Some(rootfs.path())).is_dir());
+
+        start(&mut ui(), &app, rootfs.path()).unwrap();
+        assert!(!hcore::fs::pkg_root_path(Some(rootfs.path()))
+            .join("acme/app")
+            .exists());
+        start(&mut ui(), &lib, rootfs.path()).unwrap();
+        assert!(!hcore::fs::pkg_root_path(Some(rootfs.path()))
+            .join("acme")
+            .exists());
+    }
+
+    #[test]
+    fn packages_loaded_as_services_are_not_uninstalled() {
+        let rootfs = TempDir::new("rootfs").unwrap();
+        let lib = fake_pkg_install("acme/lib/1.0.0/20170101000000", &[], rootfs.path());
+        fake_pkg_install("acme/app/1.0.0/20170101000000", &[&lib], rootfs.path());
+        write_file(
+            rootfs.path().join("hab/sup/default/specs/app.spec"),
+            "ident = \"acme/app\"\n",
+        );
+
+        match start(&mut ui(), &lib, rootfs.path()) {
+            Err(Error::PackageInUse(_, users)) => assert_eq!(users.len(), 2),
+            other => panic!("Expected PackageInUse, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn releases_running_as_services_are_not_uninstalled() {
+        let rootfs = TempDir::new("rootfs").unwrap();
+        let old_lib = fake_pkg_install("acme/lib/1.0.0/20170101000000", &[], rootfs.path());
+        let new_lib = fake_pkg_install("acme/lib/1.1.0/20170101000000", &[], rootfs.path());
+        let old_app =
+            fake_pkg_install("acme/app/1.0.0/20170101000000", &[&old_lib], rootfs.path());
+        fake_pkg_install("acme/app/1.0.0/20170202000000", &[&new_lib], rootfs.path());
+        // The spec names only the package, whose newest release the Supervisor hasn't started
+        write_file(
+            rootfs.path().join("hab/sup/default/specs/app.spec"),
+            "ident = \"acme/app\"\n",
+        );
+        write_file(
+            rootfs.path().join("hab/sup/default/data/services.dat"),
+            &format!(r#"[{{"pkg": {{"ident": "{}", "name": "app"}}}}]"#, old_app),
+        );
+
+        match start(&mut ui(), &old_lib, rootfs.path()) {
+            Err(Error::PackageInUse(_, users)) => {
+                assert!(users.contains(&format!("the running {} service", old_app)))
+            }
+            other => panic!("Expected PackageInUse, got {:?}", other),
+        }
+        match start(&mut ui(), &old_app, rootfs.path()) {
+            Err(Error::PackageInUse(_, users)) => {
+                assert_eq!(users, vec![format!("the running {} service", old_app)])
+            }
+            other => panic!("Expected PackageInUse, got {:?}", other),
+        }
+    }
+}
@@ -109,14 +109,14 @@ where
     let tdeps = archive.tdeps()?;
     let ident = archive.ident()?;
     let target = archive.target()?;
-    match depot_client.show_package(&ident, None, Some(token)) {
+    match depot_client.show_package(&ident, None, &target, Some(token)) {
         Ok(_) => {
             ui.status(Status::Using, format!("existing {}", &ident))?;
             Ok(())
         }
         Err(depot_client::Error::APIError(StatusCode::NotFound, _)) => {
             for dep in tdeps.into_iter() {
-                match depot_client.show_package(&dep, None, Some(token)) {
+                match depot_client.show_package(&dep, None, &target, Some(token)) {
                     Ok(_) => ui.status(Status::Using, format!("existing {}", &dep))?,
                     Err(depot_client::Error::APIError(StatusCode::NotFound, _)) => {
                         let candidate_path = match archive_path.as_ref().parent() {
@@ -263,10 +263,11 @@ fn attempt_upload_dep(
     additional_release_channel: Option<&str>,
     archives_dir: &PathBuf,
 ) -> Result<()> {
-    let candidate_path = match target {
-        Some(t) => archives_dir.join(ident.archive_name_with_target(t).unwrap()),
-        None => archives_dir.join(ident.archive_name().unwrap()),
+    let archive_name = match target {
+        Some(t) => ident.archive_name_with_target(t).unwrap(),
+        None => ident.archive_name().unwrap(),
     };
+    let candidate_path = archives_dir.join(&archive_name);
     if candidate_path.is_file() {
         let mut archive = PackageArchive::new(candidate_path);
         upload_into_depot(
@@ -282,7 +283,7 @@ fn attempt_upload_dep(
             Status::Missing,
             format!(
                 "artifact for {} was not found in {}",
-                ident.archive_name().unwrap(),
+                archive_name,
                 archives_dir.display()
             ),
         )?;
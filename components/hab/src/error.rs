@@ -26,6 +26,7 @@ use depot_client;
 use common;
 use hcore;
 use handlebars;
+use serde_json;
 use toml;
 
 pub type Result<T> = result::Result<T, Error>;
@@ -52,10 +53,12 @@ pub enum Error {
     HabitatCore(hcore::Error),
     HandlebarsRenderError(handlebars::TemplateRenderError),
     IO(io::Error),
+    JsonDeserializeError(serde_json::Error),
     JobGroupPromoteOrDemote(api_client::Error, bool /* promote */),
     JobGroupCancel(api_client::Error),
     JobGroupPromoteOrDemoteUnprocessable(bool /* promote */),
     PackageArchiveMalformed(String),
+    PackageInUse(String, Vec<String>),
     ParseIntError(num::ParseIntError),
     PathPrefixError(path::StripPrefixError),
     ProvidesError(String),
@@ -148,6 +151,7 @@ impl fmt::Display for Error {
                 )
             }
             Error::JobGroupCancel(ref e) => format!("Failed to cancel job group: {:?}", e),
+            Error::JsonDeserializeError(ref e) => format!("Can't deserialize JSON: {}", e),
             Error::PackageArchiveMalformed(ref e) => {
                 format!(
                     "Package archive was unreadable or contained unexpected contents: {:?}",
@@ -156,6 +160,13 @@ impl fmt::Display for Error {
             }
             Error::ParseIntError(ref err) => format!("{}", err),
             Error::PathPrefixError(ref err) => format!("{}", err),
+            Error::PackageInUse(ref ident, ref users) => {
+                format!(
+                    "Cannot uninstall {}, it is still needed by: {}",
+                    ident,
+                    users.join(", ")
+                )
+            }
             Error::ProvidesError(ref err) => format!("Can't find {}", err),
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation".to_string()
@@ -207,11 +218,15 @@ impl error::Error for Error {
             }
             Error::JobGroupPromoteOrDemote(ref err, _) => err.description(),
             Error::JobGroupCancel(ref err) => err.description(),
+            Error::JsonDeserializeError(_) => "Can't deserialize JSON",
             Error::PackageArchiveMalformed(_) => {
                 "Package archive was unreadable or had unexpected contents"
             }
             Error::ParseIntError(ref err) => err.description(),
             Error::PathPrefixError(ref err) => err.description(),
+            Error::PackageInUse(_, _) => {
+                "Package is still needed by another package or a loaded service"
+            }
             Error::ProvidesError(_) => {
                 "Can't find a package that provides the given search parameter"
             }
@@ -265,6 +280,12 @@ impl From<io::Error> for Error {
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::JsonDeserializeError(err)
+    }
+}
+
 impl From<path::StripPrefixError> for Error {
     fn from(err: path::StripPrefixError) -> Error {
         Error::PathPrefixError(err)
@@ -45,7 +45,7 @@ use hcore::crypto::{init, default_cache_key_path, SigKeyPair};
 use hcore::crypto::keys::PairType;
 use hcore::env as henv;
 use hcore::fs::{cache_artifact_path, cache_analytics_path, cache_key_path};
-use hcore::package::PackageIdent;
+use hcore::package::{PackageIdent, PackageTarget};
 use hcore::service::ServiceGroup;
 use hcore::url::default_bldr_url;
 use hcore::binlink::default_binlink_dir;
@@ -56,6 +56,8 @@ use hab::error::{Error, Result};
 
 /// Makes the --org CLI param optional when this env var is set
 const HABITAT_ORG_ENVVAR: &'static str = "HAB_ORG";
+/// The target Builder builds for when a job doesn't say
+const DEFAULT_BUILD_TARGET: &'static str = "x86_64-linux";
 
 lazy_static! {
     /// The default filesystem root path to base all commands from. This is lazily generated on
@@ -163,12 +165,14 @@ fn start(ui: &mut UI) -> Result<()> {
                 ("env", Some(m)) => sub_pkg_env(m)?,
                 ("exec", Some(m)) => sub_pkg_exec(m, remaining_args)?,
                 ("export", Some(m)) => sub_pkg_export(ui, m)?,
+                ("gc", Some(m)) => sub_pkg_gc(ui, m)?,
                 ("hash", Some(m)) => sub_pkg_hash(m)?,
                 ("install", Some(m)) => sub_pkg_install(ui, m)?,
                 ("path", Some(m)) => sub_pkg_path(m)?,
                 ("provides", Some(m)) => sub_pkg_provides(m)?,
                 ("search", Some(m)) => sub_pkg_search(m)?,
                 ("sign", Some(m)) => sub_pkg_sign(ui, m)?,
+                ("uninstall", Some(m)) => sub_pkg_uninstall(ui, m)?,
                 ("upload", Some(m)) => sub_pkg_upload(ui, m)?,
                 ("verify", Some(m)) => sub_pkg_verify(ui, m)?,
                 ("header", Some(m)) => sub_pkg_header(ui, m)?,
@@ -394,6 +398,15 @@ fn sub_pkg_export(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::pkg::export::start(ui, &url, &channel, &ident, &export_fmt)
 }
 
+fn sub_pkg_gc(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let keep = m.value_of("KEEP")
+        .map(|k| k.parse().unwrap()) // Validated via clap
+        .unwrap_or(1);
+    init();
+
+    command::pkg::gc::start(ui, keep, &*FS_ROOT, &cache_artifact_path(Some(&*FS_ROOT)))
+}
+
 fn sub_pkg_hash(m: &ArgMatches) -> Result<()> {
     init();
     match m.value_of("SOURCE") {
@@ -448,9 +461,10 @@ fn sub_bldr_channel_list(ui: &mut UI, m: &ArgMatches) -> Result<()> {
 fn sub_bldr_job_start(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?; // Required via clap
     let url = bldr_url_from_matches(m);
+    let target = PackageTarget::from_str(m.value_of("TARGET").unwrap_or(DEFAULT_BUILD_TARGET))?;
     let group = m.is_present("GROUP");
     let token = auth_token_param_or_env(&m)?;
-    command::bldr::job::start::start(ui, &url, &ident, &token, group)
+    command::bldr::job::start::start(ui, &url, &ident, &target, &token, group)
 }
 
 fn sub_bldr_job_cancel(ui: &mut UI, m: &ArgMatches) -> Result<()> {
@@ -549,6 +563,13 @@ fn sub_pkg_path(m: &ArgMatches) -> Result<()> {
     command::pkg::path::start(&ident, &*FS_ROOT)
 }
 
+fn sub_pkg_uninstall(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?; // Required via clap
+    init();
+
+    command::pkg::uninstall::start(ui, &ident, &*FS_ROOT)
+}
+
 fn sub_pkg_provides(m: &ArgMatches) -> Result<()> {
     let filename = m.value_of("FILE").unwrap(); // Required via clap
 
@@ -695,7 +716,10 @@ fn exec_subcommand_if_called(ui: &mut UI) -> Result<()> {
             args.insert(0, OsString::from("config"));
             command::butterfly::start(ui, args)
         }
-        ("config", _, _) | ("file", _, _) => {
+        ("config", _, _) |
+        ("file", _, _) |
+        ("kv", _, _) |
+        ("ring", "key", "rotate") => {
             command::butterfly::start(ui, env::args_os().skip(1).collect())
         }
         ("pkg", "export", "docker") => {
@@ -711,6 +735,9 @@ fn exec_subcommand_if_called(ui: &mut UI) -> Result<()> {
         ("pkg", "export", "kubernetes") => {
             command::pkg::export::kubernetes::start(ui, env::args_os().skip(4).collect())
         }
+        ("pkg", "export", "tar") => {
+            command::pkg::export::tar::start(ui, env::args_os().skip(4).collect())
+        }
         ("run", _, _) => command::launcher::start(ui, env::args_os().skip(1).collect()),
         ("stu", _, _) | ("stud", _, _) | ("studi", _, _) | ("studio", _, _) => {
             command::studio::enter::start(ui, env::args_os().skip(2).collect())
@@ -15,6 +15,9 @@
 use std::collections::HashMap;
 use std::io;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use core::os::process::Pid;
 use ipc_channel::ipc::{IpcOneShotServer, IpcReceiver, IpcSender};
@@ -26,9 +29,42 @@ use error::{Error, Result};
 type Env = HashMap<String, String>;
 type IpcServer = IpcOneShotServer<Vec<u8>>;
 
+/// How long to wait for the Launcher to reply to messages which Launchers older than the
+/// Supervisor don't know, and so never reply to.
+const REPLY_TIMEOUT_MS: u64 = 2_000;
+
+/// Resource limits the Launcher applies to a spawned process. Unset limits are not enforced.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ResourceLimits {
+    /// Relative CPU weight, in cgroup `cpu.shares` units
+    pub cpu_shares: Option<u64>,
+    /// Hard CPU limit, in thousandths of a CPU
+    pub cpu_quota: Option<u64>,
+    /// Memory limit, in bytes
+    pub memory_limit: Option<u64>,
+    /// Maximum number of processes
+    pub pids_limit: Option<u64>,
+    /// Maximum number of open file descriptors
+    pub nofile_limit: Option<u64>,
+}
+
+/// Resource usage of a spawned process and its descendants. A counter is `None` if the
+/// Launcher couldn't read it, for example because the process doesn't run in a cgroup.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ResourceUsage {
+    /// Total CPU time consumed, in microseconds
+    pub cpu_usage_usec: Option<u64>,
+    /// Current memory usage, in bytes
+    pub memory_usage: Option<u64>,
+    /// Number of processes
+    pub pids: Option<u64>,
+}
+
 pub struct LauncherCli {
     tx: IpcSender<Vec<u8>>,
     rx: IpcReceiver<Vec<u8>>,
+    /// Whether the Launcher applies resource limits and reports resource usage
+    resources: AtomicBool,
 }
 
 impl LauncherCli {
@@ -40,7 +76,27 @@ impl LauncherCli {
         Self::send(&tx, &cmd)?;
         let (rx, raw) = ipc_srv.accept().map_err(|_| Error::AcceptConn)?;
         Self::read::<protocol::NetOk>(&raw)?;
-        Ok(LauncherCli { tx: tx, rx: rx })
+        let resources = Self::probe_resources(&tx, &rx)?;
+        Ok(LauncherCli {
+            tx: tx,
+            rx: rx,
+            resources: AtomicBool::new(resources),
+        })
+    }
+
+    /// Find out whether the Launcher handles resource limits by asking it for the resource
+    /// usage of a process it can't have spawned. A Launcher which handles them replies with an
+    /// error, while an older one doesn't reply at all.
+    fn probe_resources(tx: &IpcSender<Vec<u8>>, rx: &IpcReceiver<Vec<u8>>) -> Result<bool> {
+        let mut msg = protocol::ResourceUsage::new();
+        msg.set_pid(0);
+        Self::send(tx, &msg)?;
+        match Self::recv_timeout::<protocol::ResourceUsageOk>(rx) {
+            Ok(Some(_)) |
+            Err(Error::Protocol(_)) => Ok(true),
+            Ok(None) => Ok(false),
+            Err(err) => Err(err),
+        }
     }
 
     /// Read a launcher protocol message from a byte array
@@ -81,6 +137,25 @@ impl LauncherCli {
         Ok(())
     }
 
+    /// Receive and read protocol message from an IpcReceiver, giving up after
+    /// `REPLY_TIMEOUT_MS`
+    fn recv_timeout<T>(rx: &IpcReceiver<Vec<u8>>) -> Result<Option<T>>
+    where
+        T: protobuf::MessageStatic,
+    {
+        let timeout = Duration::from_millis(REPLY_TIMEOUT_MS);
+        let start = Instant::now();
+        loop {
+            if let Some(msg) = Self::try_recv::<T>(rx)? {
+                return Ok(Some(msg));
+            }
+            if start.elapsed() >= timeout {
+                return Ok(None);
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
     /// Receive and read protocol message from an IpcReceiver
     fn try_recv<T>(rx: &IpcReceiver<Vec<u8>>) -> Result<Option<T>>
     where
@@ -105,6 +180,62 @@ impl LauncherCli {
         }
     }
 
+    /// Retrieve the exit code of a process which exited on its own. Returns `None` if the
+    /// process was terminated by a signal.
+    pub fn exit_code(&self, pid: Pid) -> Result<Option<i32>> {
+        let mut msg = protocol::ExitCode::new();
+        msg.set_pid(pid.into());
+        Self::send(&self.tx, &msg)?;
+        let reply = Self::recv::<protocol::ExitCodeOk>(&self.rx)?;
+        if reply.has_exit_code() {
+            Ok(Some(reply.get_exit_code()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Whether the Launcher applies resource limits to the processes it spawns, and reports
+    /// their resource usage. Launchers older than the Supervisor don't.
+    pub fn handles_resources(&self) -> bool {
+        self.resources.load(Ordering::Relaxed)
+    }
+
+    /// Retrieve the resource usage of a running process. Nothing is reported by a Launcher which
+    /// doesn't handle resources.
+    pub fn resource_usage(&self, pid: Pid) -> Result<ResourceUsage> {
+        if !self.handles_resources() {
+            return Ok(ResourceUsage::default());
+        }
+        let mut msg = protocol::ResourceUsage::new();
+        msg.set_pid(pid.into());
+        Self::send(&self.tx, &msg)?;
+        let reply = match Self::recv_timeout::<protocol::ResourceUsageOk>(&self.rx)? {
+            Some(reply) => reply,
+            None => {
+                // Don't keep the Supervisor waiting on every refresh
+                self.resources.store(false, Ordering::Relaxed);
+                return Err(Error::Timeout);
+            }
+        };
+        Ok(ResourceUsage {
+            cpu_usage_usec: if reply.has_cpu_usage_usec() {
+                Some(reply.get_cpu_usage_usec())
+            } else {
+                None
+            },
+            memory_usage: if reply.has_memory_usage() {
+                Some(reply.get_memory_usage())
+            } else {
+                None
+            },
+            pids: if reply.has_pids() {
+                Some(reply.get_pids())
+            } else {
+                None
+            },
+        })
+    }
+
     /// Restart a running process with the same arguments
     pub fn restart(&self, pid: Pid) -> Result<Pid> {
         let mut msg = protocol::Restart::new();
@@ -114,7 +245,8 @@ impl LauncherCli {
         Ok(reply.get_pid() as Pid)
     }
 
-    /// Send a process spawn command to the connected Launcher
+    /// Send a process spawn command to the connected Launcher. Resource limits are left out for
+    /// a Launcher which doesn't handle them.
     pub fn spawn<I, B, U, G, P>(
         &self,
         id: I,
@@ -123,6 +255,7 @@ impl LauncherCli {
         group: G,
         password: Option<P>,
         env: Env,
+        limits: &ResourceLimits,
     ) -> Result<Pid>
     where
         I: ToString,
@@ -140,11 +273,32 @@ impl LauncherCli {
         }
         msg.set_env(env);
         msg.set_id(id.to_string());
+        if self.handles_resources() {
+            Self::set_limits(&mut msg, limits);
+        }
         Self::send(&self.tx, &msg)?;
         let reply = Self::recv::<protocol::SpawnOk>(&self.rx)?;
         Ok(reply.get_pid() as Pid)
     }
 
+    fn set_limits(msg: &mut protocol::Spawn, limits: &ResourceLimits) {
+        if let Some(shares) = limits.cpu_shares {
+            msg.set_cpu_shares(shares);
+        }
+        if let Some(quota) = limits.cpu_quota {
+            msg.set_cpu_quota(quota);
+        }
+        if let Some(memory) = limits.memory_limit {
+            msg.set_memory_limit(memory);
+        }
+        if let Some(pids) = limits.pids_limit {
+            msg.set_pids_limit(pids);
+        }
+        if let Some(nofile) = limits.nofile_limit {
+            msg.set_nofile_limit(nofile);
+        }
+    }
+
     pub fn terminate(&self, pid: Pid) -> Result<i32> {
         let mut msg = protocol::Terminate::new();
         msg.set_pid(pid.into());
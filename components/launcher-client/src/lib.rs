@@ -23,7 +23,7 @@ mod client;
 pub use protocol::{LAUNCHER_LOCK_CLEAN_ENV, LAUNCHER_PID_ENV, ERR_NO_RETRY_EXCODE,
                    OK_NO_RETRY_EXCODE};
 
-pub use client::LauncherCli;
+pub use client::{LauncherCli, ResourceLimits, ResourceUsage};
 pub use error::Error;
 
 pub fn env_pipe() -> Option<String> {
@@ -207,6 +207,792 @@ impl ::protobuf::reflect::ProtobufValue for Register {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct ExitCode {
+    // message fields
+    pid: ::std::option::Option<i64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ExitCode {}
+
+impl ExitCode {
+    pub fn new() -> ExitCode {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ExitCode {
+        static mut instance: ::protobuf::lazy::Lazy<ExitCode> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ExitCode,
+        };
+        unsafe {
+            instance.get(ExitCode::new)
+        }
+    }
+
+    // optional int64 pid = 1;
+
+    pub fn clear_pid(&mut self) {
+        self.pid = ::std::option::Option::None;
+    }
+
+    pub fn has_pid(&self) -> bool {
+        self.pid.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pid(&mut self, v: i64) {
+        self.pid = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_pid(&self) -> i64 {
+        self.pid.unwrap_or(0)
+    }
+
+    fn get_pid_for_reflect(&self) -> &::std::option::Option<i64> {
+        &self.pid
+    }
+
+    fn mut_pid_for_reflect(&mut self) -> &mut ::std::option::Option<i64> {
+        &mut self.pid
+    }
+}
+
+impl ::protobuf::Message for ExitCode {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pid = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.pid {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.pid {
+            os.write_int64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ExitCode {
+    fn new() -> ExitCode {
+        ExitCode::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ExitCode>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "pid",
+                    ExitCode::get_pid_for_reflect,
+                    ExitCode::mut_pid_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ExitCode>(
+                    "ExitCode",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ExitCode {
+    fn clear(&mut self) {
+        self.clear_pid();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExitCode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExitCode {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExitCodeOk {
+    // message fields
+    exit_code: ::std::option::Option<i32>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ExitCodeOk {}
+
+impl ExitCodeOk {
+    pub fn new() -> ExitCodeOk {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ExitCodeOk {
+        static mut instance: ::protobuf::lazy::Lazy<ExitCodeOk> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ExitCodeOk,
+        };
+        unsafe {
+            instance.get(ExitCodeOk::new)
+        }
+    }
+
+    // optional int32 exit_code = 1;
+
+    pub fn clear_exit_code(&mut self) {
+        self.exit_code = ::std::option::Option::None;
+    }
+
+    pub fn has_exit_code(&self) -> bool {
+        self.exit_code.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exit_code(&mut self, v: i32) {
+        self.exit_code = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_exit_code(&self) -> i32 {
+        self.exit_code.unwrap_or(0)
+    }
+
+    fn get_exit_code_for_reflect(&self) -> &::std::option::Option<i32> {
+        &self.exit_code
+    }
+
+    fn mut_exit_code_for_reflect(&mut self) -> &mut ::std::option::Option<i32> {
+        &mut self.exit_code
+    }
+}
+
+impl ::protobuf::Message for ExitCodeOk {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.exit_code = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.exit_code {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.exit_code {
+            os.write_int32(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ExitCodeOk {
+    fn new() -> ExitCodeOk {
+        ExitCodeOk::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ExitCodeOk>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "exit_code",
+                    ExitCodeOk::get_exit_code_for_reflect,
+                    ExitCodeOk::mut_exit_code_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ExitCodeOk>(
+                    "ExitCodeOk",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ExitCodeOk {
+    fn clear(&mut self) {
+        self.clear_exit_code();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExitCodeOk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExitCodeOk {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResourceUsage {
+    // message fields
+    pid: ::std::option::Option<i64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ResourceUsage {}
+
+impl ResourceUsage {
+    pub fn new() -> ResourceUsage {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ResourceUsage {
+        static mut instance: ::protobuf::lazy::Lazy<ResourceUsage> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ResourceUsage,
+        };
+        unsafe {
+            instance.get(ResourceUsage::new)
+        }
+    }
+
+    // optional int64 pid = 1;
+
+    pub fn clear_pid(&mut self) {
+        self.pid = ::std::option::Option::None;
+    }
+
+    pub fn has_pid(&self) -> bool {
+        self.pid.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pid(&mut self, v: i64) {
+        self.pid = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_pid(&self) -> i64 {
+        self.pid.unwrap_or(0)
+    }
+
+    fn get_pid_for_reflect(&self) -> &::std::option::Option<i64> {
+        &self.pid
+    }
+
+    fn mut_pid_for_reflect(&mut self) -> &mut ::std::option::Option<i64> {
+        &mut self.pid
+    }
+}
+
+impl ::protobuf::Message for ResourceUsage {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pid = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.pid {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.pid {
+            os.write_int64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ResourceUsage {
+    fn new() -> ResourceUsage {
+        ResourceUsage::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ResourceUsage>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "pid",
+                    ResourceUsage::get_pid_for_reflect,
+                    ResourceUsage::mut_pid_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ResourceUsage>(
+                    "ResourceUsage",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ResourceUsage {
+    fn clear(&mut self) {
+        self.clear_pid();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResourceUsage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResourceUsage {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResourceUsageOk {
+    // message fields
+    cpu_usage_usec: ::std::option::Option<u64>,
+    memory_usage: ::std::option::Option<u64>,
+    pids: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ResourceUsageOk {}
+
+impl ResourceUsageOk {
+    pub fn new() -> ResourceUsageOk {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ResourceUsageOk {
+        static mut instance: ::protobuf::lazy::Lazy<ResourceUsageOk> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ResourceUsageOk,
+        };
+        unsafe {
+            instance.get(ResourceUsageOk::new)
+        }
+    }
+
+    // optional uint64 cpu_usage_usec = 1;
+
+    pub fn clear_cpu_usage_usec(&mut self) {
+        self.cpu_usage_usec = ::std::option::Option::None;
+    }
+
+    pub fn has_cpu_usage_usec(&self) -> bool {
+        self.cpu_usage_usec.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_usage_usec(&mut self, v: u64) {
+        self.cpu_usage_usec = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_cpu_usage_usec(&self) -> u64 {
+        self.cpu_usage_usec.unwrap_or(0)
+    }
+
+    fn get_cpu_usage_usec_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.cpu_usage_usec
+    }
+
+    fn mut_cpu_usage_usec_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.cpu_usage_usec
+    }
+
+    // optional uint64 memory_usage = 2;
+
+    pub fn clear_memory_usage(&mut self) {
+        self.memory_usage = ::std::option::Option::None;
+    }
+
+    pub fn has_memory_usage(&self) -> bool {
+        self.memory_usage.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_memory_usage(&mut self, v: u64) {
+        self.memory_usage = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_memory_usage(&self) -> u64 {
+        self.memory_usage.unwrap_or(0)
+    }
+
+    fn get_memory_usage_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.memory_usage
+    }
+
+    fn mut_memory_usage_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.memory_usage
+    }
+
+    // optional uint64 pids = 3;
+
+    pub fn clear_pids(&mut self) {
+        self.pids = ::std::option::Option::None;
+    }
+
+    pub fn has_pids(&self) -> bool {
+        self.pids.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pids(&mut self, v: u64) {
+        self.pids = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_pids(&self) -> u64 {
+        self.pids.unwrap_or(0)
+    }
+
+    fn get_pids_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.pids
+    }
+
+    fn mut_pids_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.pids
+    }
+}
+
+impl ::protobuf::Message for ResourceUsageOk {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.cpu_usage_usec = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.memory_usage = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.pids = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.cpu_usage_usec {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.memory_usage {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.pids {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.cpu_usage_usec {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.memory_usage {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.pids {
+            os.write_uint64(3, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ResourceUsageOk {
+    fn new() -> ResourceUsageOk {
+        ResourceUsageOk::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ResourceUsageOk>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "cpu_usage_usec",
+                    ResourceUsageOk::get_cpu_usage_usec_for_reflect,
+                    ResourceUsageOk::mut_cpu_usage_usec_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "memory_usage",
+                    ResourceUsageOk::get_memory_usage_for_reflect,
+                    ResourceUsageOk::mut_memory_usage_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "pids",
+                    ResourceUsageOk::get_pids_for_reflect,
+                    ResourceUsageOk::mut_pids_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ResourceUsageOk>(
+                    "ResourceUsageOk",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ResourceUsageOk {
+    fn clear(&mut self) {
+        self.clear_cpu_usage_usec();
+        self.clear_memory_usage();
+        self.clear_pids();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResourceUsageOk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResourceUsageOk {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct Restart {
     // message fields
@@ -389,6 +1175,11 @@ pub struct Spawn {
     svc_group: ::protobuf::SingularField<::std::string::String>,
     svc_password: ::protobuf::SingularField<::std::string::String>,
     pub env: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    cpu_shares: ::std::option::Option<u64>,
+    cpu_quota: ::std::option::Option<u64>,
+    memory_limit: ::std::option::Option<u64>,
+    pids_limit: ::std::option::Option<u64>,
+    nofile_limit: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -664,6 +1455,141 @@ impl Spawn {
     fn mut_env_for_reflect(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
         &mut self.env
     }
+
+    // optional uint64 cpu_shares = 7;
+
+    pub fn clear_cpu_shares(&mut self) {
+        self.cpu_shares = ::std::option::Option::None;
+    }
+
+    pub fn has_cpu_shares(&self) -> bool {
+        self.cpu_shares.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_shares(&mut self, v: u64) {
+        self.cpu_shares = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_cpu_shares(&self) -> u64 {
+        self.cpu_shares.unwrap_or(0)
+    }
+
+    fn get_cpu_shares_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.cpu_shares
+    }
+
+    fn mut_cpu_shares_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.cpu_shares
+    }
+
+    // optional uint64 cpu_quota = 8;
+
+    pub fn clear_cpu_quota(&mut self) {
+        self.cpu_quota = ::std::option::Option::None;
+    }
+
+    pub fn has_cpu_quota(&self) -> bool {
+        self.cpu_quota.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_quota(&mut self, v: u64) {
+        self.cpu_quota = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_cpu_quota(&self) -> u64 {
+        self.cpu_quota.unwrap_or(0)
+    }
+
+    fn get_cpu_quota_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.cpu_quota
+    }
+
+    fn mut_cpu_quota_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.cpu_quota
+    }
+
+    // optional uint64 memory_limit = 9;
+
+    pub fn clear_memory_limit(&mut self) {
+        self.memory_limit = ::std::option::Option::None;
+    }
+
+    pub fn has_memory_limit(&self) -> bool {
+        self.memory_limit.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_memory_limit(&mut self, v: u64) {
+        self.memory_limit = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_memory_limit(&self) -> u64 {
+        self.memory_limit.unwrap_or(0)
+    }
+
+    fn get_memory_limit_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.memory_limit
+    }
+
+    fn mut_memory_limit_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.memory_limit
+    }
+
+    // optional uint64 pids_limit = 10;
+
+    pub fn clear_pids_limit(&mut self) {
+        self.pids_limit = ::std::option::Option::None;
+    }
+
+    pub fn has_pids_limit(&self) -> bool {
+        self.pids_limit.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pids_limit(&mut self, v: u64) {
+        self.pids_limit = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_pids_limit(&self) -> u64 {
+        self.pids_limit.unwrap_or(0)
+    }
+
+    fn get_pids_limit_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.pids_limit
+    }
+
+    fn mut_pids_limit_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.pids_limit
+    }
+
+    // optional uint64 nofile_limit = 11;
+
+    pub fn clear_nofile_limit(&mut self) {
+        self.nofile_limit = ::std::option::Option::None;
+    }
+
+    pub fn has_nofile_limit(&self) -> bool {
+        self.nofile_limit.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nofile_limit(&mut self, v: u64) {
+        self.nofile_limit = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_nofile_limit(&self) -> u64 {
+        self.nofile_limit.unwrap_or(0)
+    }
+
+    fn get_nofile_limit_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.nofile_limit
+    }
+
+    fn mut_nofile_limit_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.nofile_limit
+    }
 }
 
 impl ::protobuf::Message for Spawn {
@@ -693,6 +1619,41 @@ impl ::protobuf::Message for Spawn {
                 6 => {
                     ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.env)?;
                 },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.cpu_shares = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.cpu_quota = ::std::option::Option::Some(tmp);
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.memory_limit = ::std::option::Option::Some(tmp);
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.pids_limit = ::std::option::Option::Some(tmp);
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.nofile_limit = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -721,6 +1682,21 @@ impl ::protobuf::Message for Spawn {
             my_size += ::protobuf::rt::string_size(5, &v);
         }
         my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(6, &self.env);
+        if let Some(v) = self.cpu_shares {
+            my_size += ::protobuf::rt::value_size(7, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.cpu_quota {
+            my_size += ::protobuf::rt::value_size(8, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.memory_limit {
+            my_size += ::protobuf::rt::value_size(9, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.pids_limit {
+            my_size += ::protobuf::rt::value_size(10, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.nofile_limit {
+            my_size += ::protobuf::rt::value_size(11, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -743,6 +1719,21 @@ impl ::protobuf::Message for Spawn {
             os.write_string(5, &v)?;
         }
         ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(6, &self.env, os)?;
+        if let Some(v) = self.cpu_shares {
+            os.write_uint64(7, v)?;
+        }
+        if let Some(v) = self.cpu_quota {
+            os.write_uint64(8, v)?;
+        }
+        if let Some(v) = self.memory_limit {
+            os.write_uint64(9, v)?;
+        }
+        if let Some(v) = self.pids_limit {
+            os.write_uint64(10, v)?;
+        }
+        if let Some(v) = self.nofile_limit {
+            os.write_uint64(11, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -817,6 +1808,31 @@ impl ::protobuf::MessageStatic for Spawn {
                     Spawn::get_env_for_reflect,
                     Spawn::mut_env_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "cpu_shares",
+                    Spawn::get_cpu_shares_for_reflect,
+                    Spawn::mut_cpu_shares_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "cpu_quota",
+                    Spawn::get_cpu_quota_for_reflect,
+                    Spawn::mut_cpu_quota_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "memory_limit",
+                    Spawn::get_memory_limit_for_reflect,
+                    Spawn::mut_memory_limit_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "pids_limit",
+                    Spawn::get_pids_limit_for_reflect,
+                    Spawn::mut_pids_limit_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "nofile_limit",
+                    Spawn::get_nofile_limit_for_reflect,
+                    Spawn::mut_nofile_limit_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Spawn>(
                     "Spawn",
                     fields,
@@ -835,6 +1851,11 @@ impl ::protobuf::Clear for Spawn {
         self.clear_svc_group();
         self.clear_svc_password();
         self.clear_env();
+        self.clear_cpu_shares();
+        self.clear_cpu_quota();
+        self.clear_memory_limit();
+        self.clear_pids_limit();
+        self.clear_nofile_limit();
         self.unknown_fields.clear();
     }
 }
@@ -1471,77 +2492,135 @@ impl ::protobuf::reflect::ProtobufValue for ShutdownMethod {
 
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x18protocols/launcher.proto\x12\x08launcher\"\x1e\n\x08Register\x12\
-    \x12\n\x04pipe\x18\x01\x20\x01(\tR\x04pipe\"\x1b\n\x07Restart\x12\x10\n\
-    \x03pid\x18\x01\x20\x01(\x03R\x03pid\"\xee\x01\n\x05Spawn\x12\x0e\n\x02i\
-    d\x18\x01\x20\x01(\tR\x02id\x12\x16\n\x06binary\x18\x02\x20\x01(\tR\x06b\
-    inary\x12\x19\n\x08svc_user\x18\x03\x20\x01(\tR\x07svcUser\x12\x1b\n\tsv\
-    c_group\x18\x04\x20\x01(\tR\x08svcGroup\x12!\n\x0csvc_password\x18\x05\
-    \x20\x01(\tR\x0bsvcPassword\x12*\n\x03env\x18\x06\x20\x03(\x0b2\x18.laun\
-    cher.Spawn.EnvEntryR\x03env\x1a6\n\x08EnvEntry\x12\x10\n\x03key\x18\x01\
-    \x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x02\
-    8\x01\"\x1b\n\x07SpawnOk\x12\x10\n\x03pid\x18\x01\x20\x01(\x03R\x03pid\"\
-    \x1d\n\tTerminate\x12\x10\n\x03pid\x18\x01\x20\x01(\x03R\x03pid\"m\n\x0b\
-    TerminateOk\x12\x1b\n\texit_code\x18\x01\x20\x01(\x05R\x08exitCode\x12A\
-    \n\x0fshutdown_method\x18\x02\x20\x01(\x0e2\x18.launcher.ShutdownMethodR\
-    \x0eshutdownMethod*H\n\x0eShutdownMethod\x12\x11\n\rAlreadyExited\x10\0\
-    \x12\x17\n\x13GracefulTermination\x10\x01\x12\n\n\x06Killed\x10\x02J\xfc\
-    \x08\n\x06\x12\x04\0\0&\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\
-    \x02\x12\x03\x02\x08\x10\n\n\n\x02\x04\0\x12\x04\x04\0\x06\x01\n\n\n\x03\
-    \x04\0\x01\x12\x03\x04\x08\x10\n\x0b\n\x04\x04\0\x02\0\x12\x03\x05\x02\
-    \x1b\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\x05\x02\n\n\x0c\n\x05\x04\0\x02\
-    \0\x05\x12\x03\x05\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x05\x12\
-    \x16\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x05\x19\x1a\n\n\n\x02\x04\x01\
-    \x12\x04\x08\0\n\x01\n\n\n\x03\x04\x01\x01\x12\x03\x08\x08\x0f\n\x0b\n\
-    \x04\x04\x01\x02\0\x12\x03\t\x02\x19\n\x0c\n\x05\x04\x01\x02\0\x04\x12\
-    \x03\t\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\t\x0b\x10\n\x0c\n\x05\
-    \x04\x01\x02\0\x01\x12\x03\t\x11\x14\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
-    \x03\t\x17\x18\n\n\n\x02\x04\x02\x12\x04\x0c\0\x13\x01\n\n\n\x03\x04\x02\
-    \x01\x12\x03\x0c\x08\r\n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\x02\x19\n\x0c\
-    \n\x05\x04\x02\x02\0\x04\x12\x03\r\x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\
-    \x12\x03\r\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\r\x12\x14\n\x0c\
-    \n\x05\x04\x02\x02\0\x03\x12\x03\r\x17\x18\n\x0b\n\x04\x04\x02\x02\x01\
-    \x12\x03\x0e\x02\x1d\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\x03\x0e\x02\n\n\
-    \x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x0e\x0b\x11\n\x0c\n\x05\x04\x02\
-    \x02\x01\x01\x12\x03\x0e\x12\x18\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\
-    \x0e\x1b\x1c\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x0f\x02\x1f\n\x0c\n\x05\
-    \x04\x02\x02\x02\x04\x12\x03\x0f\x02\n\n\x0c\n\x05\x04\x02\x02\x02\x05\
-    \x12\x03\x0f\x0b\x11\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x0f\x12\x1a\
-    \n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x0f\x1d\x1e\n\x0b\n\x04\x04\x02\
-    \x02\x03\x12\x03\x10\x02\x20\n\x0c\n\x05\x04\x02\x02\x03\x04\x12\x03\x10\
-    \x02\n\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x10\x0b\x11\n\x0c\n\x05\
-    \x04\x02\x02\x03\x01\x12\x03\x10\x12\x1b\n\x0c\n\x05\x04\x02\x02\x03\x03\
-    \x12\x03\x10\x1e\x1f\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x11\x02#\n\x0c\
-    \n\x05\x04\x02\x02\x04\x04\x12\x03\x11\x02\n\n\x0c\n\x05\x04\x02\x02\x04\
-    \x05\x12\x03\x11\x0b\x11\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x11\x12\
-    \x1e\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\x11!\"\n\x0b\n\x04\x04\x02\
-    \x02\x05\x12\x03\x12\x02\x1e\n\r\n\x05\x04\x02\x02\x05\x04\x12\x04\x12\
-    \x02\x11#\n\x0c\n\x05\x04\x02\x02\x05\x06\x12\x03\x12\x02\x15\n\x0c\n\
-    \x05\x04\x02\x02\x05\x01\x12\x03\x12\x16\x19\n\x0c\n\x05\x04\x02\x02\x05\
-    \x03\x12\x03\x12\x1c\x1d\n\n\n\x02\x04\x03\x12\x04\x15\0\x17\x01\n\n\n\
-    \x03\x04\x03\x01\x12\x03\x15\x08\x0f\n\x0b\n\x04\x04\x03\x02\0\x12\x03\
-    \x16\x02\x19\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x16\x02\n\n\x0c\n\x05\
-    \x04\x03\x02\0\x05\x12\x03\x16\x0b\x10\n\x0c\n\x05\x04\x03\x02\0\x01\x12\
-    \x03\x16\x11\x14\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x16\x17\x18\n\n\n\
-    \x02\x04\x04\x12\x04\x19\0\x1b\x01\n\n\n\x03\x04\x04\x01\x12\x03\x19\x08\
-    \x11\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1a\x02\x19\n\x0c\n\x05\x04\x04\
-    \x02\0\x04\x12\x03\x1a\x02\n\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x1a\
-    \x0b\x10\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x1a\x11\x14\n\x0c\n\x05\
-    \x04\x04\x02\0\x03\x12\x03\x1a\x17\x18\n\n\n\x02\x04\x05\x12\x04\x1d\0\
-    \x20\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1d\x08\x13\n\x0b\n\x04\x04\x05\
-    \x02\0\x12\x03\x1e\x02\x1f\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x03\x1e\x02\
-    \n\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03\x1e\x0b\x10\n\x0c\n\x05\x04\x05\
-    \x02\0\x01\x12\x03\x1e\x11\x1a\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\x1e\
-    \x1d\x1e\n\x0b\n\x04\x04\x05\x02\x01\x12\x03\x1f\x02.\n\x0c\n\x05\x04\
-    \x05\x02\x01\x04\x12\x03\x1f\x02\n\n\x0c\n\x05\x04\x05\x02\x01\x06\x12\
-    \x03\x1f\x0b\x19\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03\x1f\x1a)\n\x0c\
-    \n\x05\x04\x05\x02\x01\x03\x12\x03\x1f,-\n\n\n\x02\x05\0\x12\x04\"\0&\
-    \x01\n\n\n\x03\x05\0\x01\x12\x03\"\x05\x13\n\x0b\n\x04\x05\0\x02\0\x12\
-    \x03#\x02\x14\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03#\x02\x0f\n\x0c\n\x05\
-    \x05\0\x02\0\x02\x12\x03#\x12\x13\n\x0b\n\x04\x05\0\x02\x01\x12\x03$\x02\
-    \x1a\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03$\x02\x15\n\x0c\n\x05\x05\0\
-    \x02\x01\x02\x12\x03$\x18\x19\n\x0b\n\x04\x05\0\x02\x02\x12\x03%\x02\r\n\
-    \x0c\n\x05\x05\0\x02\x02\x01\x12\x03%\x02\x08\n\x0c\n\x05\x05\0\x02\x02\
-    \x02\x12\x03%\x0b\x0c\
+    \x12\n\x04pipe\x18\x01\x20\x01(\tR\x04pipe\"\x1c\n\x08ExitCode\x12\x10\n\
+    \x03pid\x18\x01\x20\x01(\x03R\x03pid\")\n\nExitCodeOk\x12\x1b\n\texit_co\
+    de\x18\x01\x20\x01(\x05R\x08exitCode\"!\n\rResourceUsage\x12\x10\n\x03pi\
+    d\x18\x01\x20\x01(\x03R\x03pid\"n\n\x0fResourceUsageOk\x12$\n\x0ecpu_usa\
+    ge_usec\x18\x01\x20\x01(\x04R\x0ccpuUsageUsec\x12!\n\x0cmemory_usage\x18\
+    \x02\x20\x01(\x04R\x0bmemoryUsage\x12\x12\n\x04pids\x18\x03\x20\x01(\x04\
+    R\x04pids\"\x1b\n\x07Restart\x12\x10\n\x03pid\x18\x01\x20\x01(\x03R\x03p\
+    id\"\x8f\x03\n\x05Spawn\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\
+    \x16\n\x06binary\x18\x02\x20\x01(\tR\x06binary\x12\x19\n\x08svc_user\x18\
+    \x03\x20\x01(\tR\x07svcUser\x12\x1b\n\tsvc_group\x18\x04\x20\x01(\tR\x08\
+    svcGroup\x12!\n\x0csvc_password\x18\x05\x20\x01(\tR\x0bsvcPassword\x12*\
+    \n\x03env\x18\x06\x20\x03(\x0b2\x18.launcher.Spawn.EnvEntryR\x03env\x12\
+    \x1d\n\ncpu_shares\x18\x07\x20\x01(\x04R\tcpuShares\x12\x1b\n\tcpu_quota\
+    \x18\x08\x20\x01(\x04R\x08cpuQuota\x12!\n\x0cmemory_limit\x18\t\x20\x01(\
+    \x04R\x0bmemoryLimit\x12\x1d\n\npids_limit\x18\n\x20\x01(\x04R\tpidsLimi\
+    t\x12!\n\x0cnofile_limit\x18\x0b\x20\x01(\x04R\x0bnofileLimit\x1a6\n\x08\
+    EnvEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\
+    \x18\x02\x20\x01(\tR\x05value:\x028\x01\"\x1b\n\x07SpawnOk\x12\x10\n\x03\
+    pid\x18\x01\x20\x01(\x03R\x03pid\"\x1d\n\tTerminate\x12\x10\n\x03pid\x18\
+    \x01\x20\x01(\x03R\x03pid\"m\n\x0bTerminateOk\x12\x1b\n\texit_code\x18\
+    \x01\x20\x01(\x05R\x08exitCode\x12A\n\x0fshutdown_method\x18\x02\x20\x01\
+    (\x0e2\x18.launcher.ShutdownMethodR\x0eshutdownMethod*H\n\x0eShutdownMet\
+    hod\x12\x11\n\rAlreadyExited\x10\0\x12\x17\n\x13GracefulTermination\x10\
+    \x01\x12\n\n\x06Killed\x10\x02J\xa7\x13\n\x06\x12\x04\0\0F\x01\n\x08\n\
+    \x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x02\0\x11\n\n\n\x02\x04\
+    \0\x12\x04\x04\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x04\x08\x10\n\x0b\n\
+    \x04\x04\0\x02\0\x12\x03\x05\x02\x1b\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\
+    \x05\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x05\x0b\x11\n\x0c\n\x05\
+    \x04\0\x02\0\x01\x12\x03\x05\x12\x16\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\
+    \x05\x19\x1a\n\n\n\x02\x04\x01\x12\x04\x08\0\n\x01\n\n\n\x03\x04\x01\x01\
+    \x12\x03\x08\x08\x10\n\x0b\n\x04\x04\x01\x02\0\x12\x03\t\x02\x19\n\x0c\n\
+    \x05\x04\x01\x02\0\x04\x12\x03\t\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\
+    \x03\t\x0b\x10\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\t\x11\x14\n\x0c\n\
+    \x05\x04\x01\x02\0\x03\x12\x03\t\x17\x18\n\n\n\x02\x04\x02\x12\x04\x0c\0\
+    \x0f\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0c\x08\x12\n>\n\x04\x04\x02\x02\
+    \0\x12\x03\x0e\x02\x1f\x1a1\x20Unset\x20if\x20the\x20process\x20was\x20t\
+    erminated\x20by\x20a\x20signal\n\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03\
+    \x0e\x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0e\x0b\x10\n\x0c\n\x05\
+    \x04\x02\x02\0\x01\x12\x03\x0e\x11\x1a\n\x0c\n\x05\x04\x02\x02\0\x03\x12\
+    \x03\x0e\x1d\x1e\n\n\n\x02\x04\x03\x12\x04\x11\0\x13\x01\n\n\n\x03\x04\
+    \x03\x01\x12\x03\x11\x08\x15\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x12\x02\
+    \x19\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x12\x02\n\n\x0c\n\x05\x04\x03\
+    \x02\0\x05\x12\x03\x12\x0b\x10\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x12\
+    \x11\x14\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x12\x17\x18\n\n\n\x02\x04\
+    \x04\x12\x04\x15\0\x1c\x01\n\n\n\x03\x04\x04\x01\x12\x03\x15\x08\x17\nO\
+    \n\x04\x04\x04\x02\0\x12\x03\x17\x02%\x1aB\x20Total\x20CPU\x20time\x20co\
+    nsumed\x20by\x20the\x20service's\x20cgroup,\x20in\x20microseconds\n\n\
+    \x0c\n\x05\x04\x04\x02\0\x04\x12\x03\x17\x02\n\n\x0c\n\x05\x04\x04\x02\0\
+    \x05\x12\x03\x17\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x17\x12\
+    \x20\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x17#$\nE\n\x04\x04\x04\x02\
+    \x01\x12\x03\x19\x02#\x1a8\x20Current\x20memory\x20usage\x20of\x20the\
+    \x20service's\x20cgroup,\x20in\x20bytes\n\n\x0c\n\x05\x04\x04\x02\x01\
+    \x04\x12\x03\x19\x02\n\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03\x19\x0b\
+    \x11\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x19\x12\x1e\n\x0c\n\x05\x04\
+    \x04\x02\x01\x03\x12\x03\x19!\"\nD\n\x04\x04\x04\x02\x02\x12\x03\x1b\x02\
+    \x1b\x1a7\x20Number\x20of\x20processes\x20currently\x20in\x20the\x20serv\
+    ice's\x20cgroup\n\n\x0c\n\x05\x04\x04\x02\x02\x04\x12\x03\x1b\x02\n\n\
+    \x0c\n\x05\x04\x04\x02\x02\x05\x12\x03\x1b\x0b\x11\n\x0c\n\x05\x04\x04\
+    \x02\x02\x01\x12\x03\x1b\x12\x16\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03\
+    \x1b\x19\x1a\n\n\n\x02\x04\x05\x12\x04\x1e\0\x20\x01\n\n\n\x03\x04\x05\
+    \x01\x12\x03\x1e\x08\x0f\n\x0b\n\x04\x04\x05\x02\0\x12\x03\x1f\x02\x19\n\
+    \x0c\n\x05\x04\x05\x02\0\x04\x12\x03\x1f\x02\n\n\x0c\n\x05\x04\x05\x02\0\
+    \x05\x12\x03\x1f\x0b\x10\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\x1f\x11\
+    \x14\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\x1f\x17\x18\n\n\n\x02\x04\x06\
+    \x12\x04\"\03\x01\n\n\n\x03\x04\x06\x01\x12\x03\"\x08\r\n\x0b\n\x04\x04\
+    \x06\x02\0\x12\x03#\x02\x19\n\x0c\n\x05\x04\x06\x02\0\x04\x12\x03#\x02\n\
+    \n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03#\x0b\x11\n\x0c\n\x05\x04\x06\x02\
+    \0\x01\x12\x03#\x12\x14\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03#\x17\x18\n\
+    \x0b\n\x04\x04\x06\x02\x01\x12\x03$\x02\x1d\n\x0c\n\x05\x04\x06\x02\x01\
+    \x04\x12\x03$\x02\n\n\x0c\n\x05\x04\x06\x02\x01\x05\x12\x03$\x0b\x11\n\
+    \x0c\n\x05\x04\x06\x02\x01\x01\x12\x03$\x12\x18\n\x0c\n\x05\x04\x06\x02\
+    \x01\x03\x12\x03$\x1b\x1c\n\x0b\n\x04\x04\x06\x02\x02\x12\x03%\x02\x1f\n\
+    \x0c\n\x05\x04\x06\x02\x02\x04\x12\x03%\x02\n\n\x0c\n\x05\x04\x06\x02\
+    \x02\x05\x12\x03%\x0b\x11\n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x03%\x12\
+    \x1a\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x03%\x1d\x1e\n\x0b\n\x04\x04\
+    \x06\x02\x03\x12\x03&\x02\x20\n\x0c\n\x05\x04\x06\x02\x03\x04\x12\x03&\
+    \x02\n\n\x0c\n\x05\x04\x06\x02\x03\x05\x12\x03&\x0b\x11\n\x0c\n\x05\x04\
+    \x06\x02\x03\x01\x12\x03&\x12\x1b\n\x0c\n\x05\x04\x06\x02\x03\x03\x12\
+    \x03&\x1e\x1f\n\x0b\n\x04\x04\x06\x02\x04\x12\x03'\x02#\n\x0c\n\x05\x04\
+    \x06\x02\x04\x04\x12\x03'\x02\n\n\x0c\n\x05\x04\x06\x02\x04\x05\x12\x03'\
+    \x0b\x11\n\x0c\n\x05\x04\x06\x02\x04\x01\x12\x03'\x12\x1e\n\x0c\n\x05\
+    \x04\x06\x02\x04\x03\x12\x03'!\"\n\x0b\n\x04\x04\x06\x02\x05\x12\x03(\
+    \x02\x1e\n\x0c\n\x05\x04\x06\x02\x05\x06\x12\x03(\x02\x15\n\x0c\n\x05\
+    \x04\x06\x02\x05\x01\x12\x03(\x16\x19\n\x0c\n\x05\x04\x06\x02\x05\x03\
+    \x12\x03(\x1c\x1d\nM\n\x04\x04\x06\x02\x06\x12\x03*\x02!\x1a@\x20Relativ\
+    e\x20CPU\x20weight,\x20expressed\x20in\x20cgroup\x20v1\x20`cpu.shares`\
+    \x20units\n\n\x0c\n\x05\x04\x06\x02\x06\x04\x12\x03*\x02\n\n\x0c\n\x05\
+    \x04\x06\x02\x06\x05\x12\x03*\x0b\x11\n\x0c\n\x05\x04\x06\x02\x06\x01\
+    \x12\x03*\x12\x1c\n\x0c\n\x05\x04\x06\x02\x06\x03\x12\x03*\x1f\x20\n6\n\
+    \x04\x04\x06\x02\x07\x12\x03,\x02\x20\x1a)\x20Hard\x20CPU\x20limit,\x20i\
+    n\x20thousandths\x20of\x20a\x20CPU\n\n\x0c\n\x05\x04\x06\x02\x07\x04\x12\
+    \x03,\x02\n\n\x0c\n\x05\x04\x06\x02\x07\x05\x12\x03,\x0b\x11\n\x0c\n\x05\
+    \x04\x06\x02\x07\x01\x12\x03,\x12\x1b\n\x0c\n\x05\x04\x06\x02\x07\x03\
+    \x12\x03,\x1e\x1f\n%\n\x04\x04\x06\x02\x08\x12\x03.\x02#\x1a\x18\x20Memo\
+    ry\x20limit,\x20in\x20bytes\n\n\x0c\n\x05\x04\x06\x02\x08\x04\x12\x03.\
+    \x02\n\n\x0c\n\x05\x04\x06\x02\x08\x05\x12\x03.\x0b\x11\n\x0c\n\x05\x04\
+    \x06\x02\x08\x01\x12\x03.\x12\x1e\n\x0c\n\x05\x04\x06\x02\x08\x03\x12\
+    \x03.!\"\nB\n\x04\x04\x06\x02\t\x12\x030\x02\"\x1a5\x20Maximum\x20number\
+    \x20of\x20processes\x20in\x20the\x20service's\x20cgroup\n\n\x0c\n\x05\
+    \x04\x06\x02\t\x04\x12\x030\x02\n\n\x0c\n\x05\x04\x06\x02\t\x05\x12\x030\
+    \x0b\x11\n\x0c\n\x05\x04\x06\x02\t\x01\x12\x030\x12\x1c\n\x0c\n\x05\x04\
+    \x06\x02\t\x03\x12\x030\x1f!\nF\n\x04\x04\x06\x02\n\x12\x032\x02$\x1a9\
+    \x20Maximum\x20number\x20of\x20open\x20file\x20descriptors\x20(RLIMIT_NO\
+    FILE)\n\n\x0c\n\x05\x04\x06\x02\n\x04\x12\x032\x02\n\n\x0c\n\x05\x04\x06\
+    \x02\n\x05\x12\x032\x0b\x11\n\x0c\n\x05\x04\x06\x02\n\x01\x12\x032\x12\
+    \x1e\n\x0c\n\x05\x04\x06\x02\n\x03\x12\x032!#\n\n\n\x02\x04\x07\x12\x045\
+    \07\x01\n\n\n\x03\x04\x07\x01\x12\x035\x08\x0f\n\x0b\n\x04\x04\x07\x02\0\
+    \x12\x036\x02\x19\n\x0c\n\x05\x04\x07\x02\0\x04\x12\x036\x02\n\n\x0c\n\
+    \x05\x04\x07\x02\0\x05\x12\x036\x0b\x10\n\x0c\n\x05\x04\x07\x02\0\x01\
+    \x12\x036\x11\x14\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x036\x17\x18\n\n\n\
+    \x02\x04\x08\x12\x049\0;\x01\n\n\n\x03\x04\x08\x01\x12\x039\x08\x11\n\
+    \x0b\n\x04\x04\x08\x02\0\x12\x03:\x02\x19\n\x0c\n\x05\x04\x08\x02\0\x04\
+    \x12\x03:\x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03:\x0b\x10\n\x0c\n\
+    \x05\x04\x08\x02\0\x01\x12\x03:\x11\x14\n\x0c\n\x05\x04\x08\x02\0\x03\
+    \x12\x03:\x17\x18\n\n\n\x02\x04\t\x12\x04=\0@\x01\n\n\n\x03\x04\t\x01\
+    \x12\x03=\x08\x13\n\x0b\n\x04\x04\t\x02\0\x12\x03>\x02\x1f\n\x0c\n\x05\
+    \x04\t\x02\0\x04\x12\x03>\x02\n\n\x0c\n\x05\x04\t\x02\0\x05\x12\x03>\x0b\
+    \x10\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03>\x11\x1a\n\x0c\n\x05\x04\t\x02\
+    \0\x03\x12\x03>\x1d\x1e\n\x0b\n\x04\x04\t\x02\x01\x12\x03?\x02.\n\x0c\n\
+    \x05\x04\t\x02\x01\x04\x12\x03?\x02\n\n\x0c\n\x05\x04\t\x02\x01\x06\x12\
+    \x03?\x0b\x19\n\x0c\n\x05\x04\t\x02\x01\x01\x12\x03?\x1a)\n\x0c\n\x05\
+    \x04\t\x02\x01\x03\x12\x03?,-\n\n\n\x02\x05\0\x12\x04B\0F\x01\n\n\n\x03\
+    \x05\0\x01\x12\x03B\x05\x13\n\x0b\n\x04\x05\0\x02\0\x12\x03C\x02\x14\n\
+    \x0c\n\x05\x05\0\x02\0\x01\x12\x03C\x02\x0f\n\x0c\n\x05\x05\0\x02\0\x02\
+    \x12\x03C\x12\x13\n\x0b\n\x04\x05\0\x02\x01\x12\x03D\x02\x1a\n\x0c\n\x05\
+    \x05\0\x02\x01\x01\x12\x03D\x02\x15\n\x0c\n\x05\x05\0\x02\x01\x02\x12\
+    \x03D\x18\x19\n\x0b\n\x04\x05\0\x02\x02\x12\x03E\x02\r\n\x0c\n\x05\x05\0\
+    \x02\x02\x01\x12\x03E\x02\x08\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03E\x0b\
+    \x0c\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
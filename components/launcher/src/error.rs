@@ -26,6 +26,7 @@ use {SUP_CMD, SUP_PACKAGE_IDENT};
 #[derive(Debug)]
 pub enum Error {
     AcceptConn,
+    Cgroup(io::Error),
     Connect(io::Error),
     Deserialize(protobuf::ProtobufError),
     ExecWait(io::Error),
@@ -47,6 +48,7 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
             Error::AcceptConn => format!("Unable to accept connection from Supervisor"),
+            Error::Cgroup(ref e) => format!("Unable to apply resource limits, {}", e),
             Error::Connect(ref e) => {
                 format!("Unable to connect to Supervisor's comm channel, {}", e)
             }
@@ -77,6 +79,7 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::AcceptConn => "Unable to accept connection from Supervisor",
+            Error::Cgroup(_) => "Unable to apply resource limits",
             Error::Connect(_) => "Unable to connect to Supervisor's pipe",
             Error::Deserialize(_) => "Unable to deserialize message from Supervisor",
             Error::GroupNotFound(_) => "No matching GID for group found",
@@ -38,6 +38,12 @@ fn main() {
     if args.contains(&String::from("--no-color")) {
         core::output::set_no_color(true);
     }
+    // The same goes for `--log-format json`, which also wraps each line of the services' output.
+    if args.windows(2).any(|w| w[0] == "--log-format" && w[1] == "json") ||
+        args.contains(&String::from("--log-format=json"))
+    {
+        core::output::set_json(true);
+    }
 
     if let Err(err) = server::run(args) {
         println!("{}", err);
@@ -24,8 +24,9 @@ impl Handler for TerminateHandler {
     type Reply = protocol::TerminateOk;
 
     fn handle(msg: Self::Message, services: &mut ServiceTable) -> HandleResult<Self::Reply> {
-        match services.get_mut(msg.get_pid() as Pid) {
-            Some(service) => {
+        // Removing the service keeps its termination from being recorded as an exit of its own.
+        match services.remove(msg.get_pid() as Pid) {
+            Some(mut service) => {
                 debug!("Terminating: {}", service.id());
                 let shutdown_method = service.kill();
                 match service.wait() {
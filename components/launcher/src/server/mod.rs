@@ -19,7 +19,7 @@ use std::path::PathBuf;
 use std::process::{Child, Command, Stdio};
 use std::str::FromStr;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use core;
 use core::package::{PackageIdent, PackageInstall};
@@ -36,6 +36,8 @@ use service::Service;
 
 const SUP_CMD_ENVVAR: &'static str = "HAB_SUP_BINARY";
 static LOGKEY: &'static str = "SV";
+/// How long the exit code of a service is kept for a Supervisor which never asks for it.
+const EXIT_CODE_TTL_SECS: u64 = 300;
 
 type Receiver = IpcReceiver<Vec<u8>>;
 type Sender = IpcSender<Vec<u8>>;
@@ -167,27 +169,39 @@ impl Server {
 }
 
 #[derive(Debug, Default)]
-pub struct ServiceTable(HashMap<Pid, Service>);
+pub struct ServiceTable {
+    services: HashMap<Pid, Service>,
+    // Exit codes of services which exited on their own, kept until the Supervisor asks for them.
+    // A code of `None` means the service was terminated by a signal.
+    exits: HashMap<Pid, (Option<i32>, Instant)>,
+}
 
 impl ServiceTable {
     pub fn get(&self, pid: Pid) -> Option<&Service> {
-        self.0.get(&pid)
+        self.services.get(&pid)
     }
 
     pub fn get_mut(&mut self, pid: Pid) -> Option<&mut Service> {
-        self.0.get_mut(&pid)
+        self.services.get_mut(&pid)
     }
 
     pub fn insert(&mut self, service: Service) {
-        self.0.insert(service.id(), service);
+        // The pid was reused, so a code left for a former service of that pid is stale.
+        self.exits.remove(&service.id());
+        self.services.insert(service.id(), service);
     }
 
     pub fn remove(&mut self, pid: Pid) -> Option<Service> {
-        self.0.remove(&pid)
+        self.services.remove(&pid)
+    }
+
+    /// Returns the exit code of a service which exited on its own and forgets it.
+    pub fn take_exit_code(&mut self, pid: Pid) -> Option<Option<i32>> {
+        self.exits.remove(&pid).map(|(code, _)| code)
     }
 
     fn kill_all(&mut self) {
-        for service in self.0.values_mut() {
+        for service in self.services.values_mut() {
             outputln!(preamble service.name(), "Stopping...");
             let shutdown_method = service.kill();
             outputln!(preamble service.name(), "Shutdown OK: {}", shutdown_method);
@@ -196,7 +210,7 @@ impl ServiceTable {
 
     fn reap_zombies(&mut self) {
         let mut dead: Vec<Pid> = vec![];
-        for service in self.0.values_mut() {
+        for service in self.services.values_mut() {
             match service.try_wait() {
                 Ok(None) => (),
                 Ok(Some(code)) => {
@@ -206,6 +220,7 @@ impl ServiceTable {
                         service.id(),
                         code
                     );
+                    self.exits.insert(service.id(), (code.code(), Instant::now()));
                     dead.push(service.id());
                 }
                 Err(err) => {
@@ -215,8 +230,10 @@ impl ServiceTable {
             }
         }
         for pid in dead {
-            self.0.remove(&pid);
+            self.services.remove(&pid);
         }
+        let ttl = Duration::from_secs(EXIT_CODE_TTL_SECS);
+        self.exits.retain(|_, &mut (_, exited)| exited.elapsed() < ttl);
     }
 }
 
@@ -279,6 +296,8 @@ fn dispatch(tx: &Sender, bytes: &[u8], services: &mut ServiceTable) {
         }
     };
     let func = match msg.message_id() {
+        "ExitCode" => handlers::ExitCodeHandler::run,
+        "ResourceUsage" => handlers::ResourceUsageHandler::run,
         "Restart" => handlers::RestartHandler::run,
         "Spawn" => handlers::SpawnHandler::run,
         "Terminate" => handlers::TerminateHandler::run,
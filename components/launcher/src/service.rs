@@ -21,6 +21,7 @@ use std::thread;
 #[cfg(windows)]
 use core::os::process::windows_child::{ChildStderr, ChildStdout, ExitStatus};
 use core::os::process::Pid;
+use core::output::{self, Level, StructuredOutput};
 use protocol;
 
 pub use sys::service::*;
@@ -78,6 +79,11 @@ impl Service {
         self.args.get_id()
     }
 
+    /// Resource usage of the service's cgroup.
+    pub fn resource_usage(&self) -> protocol::ResourceUsageOk {
+        self.process.usage()
+    }
+
     pub fn take_args(self) -> protocol::Spawn {
         self.args
     }
@@ -110,8 +116,7 @@ where
     let mut reader = BufReader::new(out);
     let mut buffer = String::new();
     while reader.read_line(&mut buffer).unwrap() > 0 {
-        let mut line = output_format!(preamble &id, logkey "O");
-        line.push_str(&buffer);
+        let line = format_line(&id, "O", Level::Info, &buffer);
         write!(&mut io::stdout(), "{}", line).expect("unable to write to stdout");
         buffer.clear();
     }
@@ -125,9 +130,23 @@ where
     let mut reader = BufReader::new(err);
     let mut buffer = String::new();
     while reader.read_line(&mut buffer).unwrap() > 0 {
-        let mut line = output_format!(preamble &id, logkey "E");
-        line.push_str(&buffer);
+        let line = format_line(&id, "E", Level::Error, &buffer);
         write!(&mut io::stderr(), "{}", line).expect("unable to write to stderr");
         buffer.clear();
     }
 }
+
+/// Prefixes a line of a child process's output with the service's preamble, or wraps it in a JSON
+/// object when the Supervisor logs JSON.
+fn format_line(id: &str, logkey: &'static str, level: Level, buffer: &str) -> String {
+    if output::is_json() {
+        let mut so = StructuredOutput::new(id, logkey, line!(), file!(), column!(), buffer);
+        so.source = Some("process");
+        so.level = level;
+        format!("{}\n", so)
+    } else {
+        let mut line = output_format!(preamble id, logkey logkey);
+        line.push_str(buffer);
+        line
+    }
+}
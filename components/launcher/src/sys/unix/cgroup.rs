@@ -0,0 +1,595 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Control groups for spawned services.
+//!
+//! Every service is placed in its own cgroup, `hab/<service-id>`, below the Launcher's own
+//! cgroup as given by `/proc/self/cgroup`, or the cgroup above its `launcher` leaf once it was
+//! moved there, so that services stay within whatever the Launcher itself was confined to, such
+//! as its systemd unit. Both the unified (v2) hierarchy and the legacy (v1) per-controller
+//! hierarchies are supported. The cgroup carries the service's CPU, memory and process limits
+//! and is the source of the resource usage the Supervisor reports for the service.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::path::{Path, PathBuf};
+
+use protocol;
+
+const CGROUP_ROOT: &'static str = "/sys/fs/cgroup";
+/// The file listing the cgroups the calling process belongs to.
+const PROC_SELF_CGROUP: &'static str = "/proc/self/cgroup";
+/// Name of the cgroup, below the Launcher's own cgroup, holding the cgroups of all services.
+const HAB_CGROUP: &'static str = "hab";
+/// Name of the cgroup the processes in the Launcher's own cgroup are moved into on the unified
+/// hierarchy, where a cgroup holding processes can't hand controllers down to its children.
+const LAUNCHER_CGROUP: &'static str = "launcher";
+/// Controllers delegated to the service cgroups in the unified hierarchy.
+const UNIFIED_CONTROLLERS: &'static [&'static str] = &["cpu", "memory", "pids"];
+/// Controllers a service cgroup is created in on the legacy hierarchies.
+const LEGACY_CONTROLLERS: &'static [&'static str] = &["cpu", "cpuacct", "memory", "pids"];
+/// CFS period used when enforcing a CPU quota, in microseconds.
+const CPU_PERIOD_USEC: u64 = 100_000;
+const DEFAULT_CPU_SHARES: u64 = 1024;
+
+/// Resource limits requested for a service.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Limits {
+    /// Relative CPU weight, in `cpu.shares` units.
+    pub cpu_shares: Option<u64>,
+    /// Hard CPU limit, in thousandths of a CPU.
+    pub cpu_quota: Option<u64>,
+    /// Memory limit, in bytes.
+    pub memory_limit: Option<u64>,
+    /// Maximum number of processes.
+    pub pids_limit: Option<u64>,
+}
+
+impl Limits {
+    pub fn from_spawn(msg: &protocol::Spawn) -> Self {
+        Limits {
+            cpu_shares: if msg.has_cpu_shares() {
+                Some(msg.get_cpu_shares())
+            } else {
+                None
+            },
+            cpu_quota: if msg.has_cpu_quota() {
+                Some(msg.get_cpu_quota())
+            } else {
+                None
+            },
+            memory_limit: if msg.has_memory_limit() {
+                Some(msg.get_memory_limit())
+            } else {
+                None
+            },
+            pids_limit: if msg.has_pids_limit() {
+                Some(msg.get_pids_limit())
+            } else {
+                None
+            },
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Limits::default()
+    }
+}
+
+#[derive(Debug)]
+enum Hierarchy {
+    Unified(PathBuf),
+    Legacy(Vec<(&'static str, PathBuf)>),
+}
+
+/// The cgroup of a single service.
+///
+/// The cgroup directories are removed again when the value is dropped, which happens once the
+/// service's process has been reaped.
+#[derive(Debug)]
+pub struct Cgroup {
+    hierarchy: Hierarchy,
+    procs: Vec<File>,
+}
+
+impl Cgroup {
+    /// Creates, or reuses, the cgroup of the service with the given id and applies the limits
+    /// to it.
+    pub fn create(id: &str, limits: &Limits) -> io::Result<Cgroup> {
+        let root = Path::new(CGROUP_ROOT);
+        let name = cgroup_name(id);
+        let proc_cgroup = read_file(Path::new(PROC_SELF_CGROUP)).unwrap_or_default();
+        if root.join("cgroup.controllers").is_file() {
+            Self::create_unified(root, &proc_cgroup, &name, limits)
+        } else {
+            Self::create_legacy(root, &proc_cgroup, &name, limits)
+        }
+    }
+
+    fn create_unified(
+        root: &Path,
+        proc_cgroup: &str,
+        name: &str,
+        limits: &Limits,
+    ) -> io::Result<Cgroup> {
+        let own = &root.join(unified_base(proc_cgroup));
+        let parent = own.join(HAB_CGROUP);
+        fs::create_dir_all(&parent)?;
+        // Controllers have to be enabled on every level between the Launcher's cgroup and the
+        // service cgroup; the levels above are up to whoever delegated the Launcher's cgroup.
+        if enable_controllers(own).is_err() {
+            let leaf = own.join(LAUNCHER_CGROUP);
+            fs::create_dir_all(&leaf)?;
+            for pid in read_file(&own.join("cgroup.procs"))
+                .unwrap_or_default()
+                .lines()
+            {
+                // Processes may well have exited in the meantime
+                write_file(&leaf.join("cgroup.procs"), pid).ok();
+            }
+            enable_controllers(own)?;
+        }
+        enable_controllers(&parent)?;
+        let dir = parent.join(name);
+        fs::create_dir_all(&dir)?;
+        let weight = limits.cpu_shares.map(shares_to_weight);
+        set(&dir.join("cpu.weight"), weight, "100")?;
+        let quota = limits.cpu_quota.map(|quota| {
+            format!("{} {}", quota_to_usec(quota), CPU_PERIOD_USEC)
+        });
+        set(
+            &dir.join("cpu.max"),
+            quota,
+            &format!("max {}", CPU_PERIOD_USEC),
+        )?;
+        set(&dir.join("memory.max"), limits.memory_limit, "max")?;
+        set(&dir.join("pids.max"), limits.pids_limit, "max")?;
+        let procs = vec![open_procs(&dir)?];
+        Ok(Cgroup {
+            hierarchy: Hierarchy::Unified(dir),
+            procs: procs,
+        })
+    }
+
+    fn create_legacy(
+        root: &Path,
+        proc_cgroup: &str,
+        name: &str,
+        limits: &Limits,
+    ) -> io::Result<Cgroup> {
+        let mut dirs = vec![];
+        let mut procs = vec![];
+        for controller in LEGACY_CONTROLLERS {
+            let mount = root.join(controller);
+            if !mount.is_dir() {
+                continue;
+            }
+            let own = own_cgroup(proc_cgroup, Some(controller)).unwrap_or_default();
+            let dir = mount.join(own).join(HAB_CGROUP).join(name);
+            fs::create_dir_all(&dir)?;
+            procs.push(open_procs(&dir)?);
+            dirs.push((*controller, dir));
+        }
+        let cgroup = Cgroup {
+            hierarchy: Hierarchy::Legacy(dirs),
+            procs: procs,
+        };
+        if cgroup.procs.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no cgroup hierarchy found at {}", root.display()),
+            ));
+        }
+        let period = limits.cpu_quota.map(|_| CPU_PERIOD_USEC);
+        let quota = limits.cpu_quota.map(quota_to_usec);
+        cgroup.set_legacy(
+            "cpu",
+            "cpu.shares",
+            limits.cpu_shares,
+            &DEFAULT_CPU_SHARES.to_string(),
+        )?;
+        cgroup.set_legacy("cpu", "cpu.cfs_period_us", period, "")?;
+        cgroup.set_legacy("cpu", "cpu.cfs_quota_us", quota, "-1")?;
+        cgroup.set_legacy(
+            "memory",
+            "memory.limit_in_bytes",
+            limits.memory_limit,
+            "-1",
+        )?;
+        cgroup.set_legacy("pids", "pids.max", limits.pids_limit, "max")?;
+        Ok(cgroup)
+    }
+
+    /// File descriptors of the `cgroup.procs` files a process has to write itself into to join
+    /// this cgroup.
+    pub fn procs_fds(&self) -> Vec<RawFd> {
+        self.procs.iter().map(|f| f.as_raw_fd()).collect()
+    }
+
+    /// Reads the current resource usage of all processes in this cgroup. Counters which can't be
+    /// read are left unset.
+    pub fn usage(&self) -> protocol::ResourceUsageOk {
+        let mut usage = protocol::ResourceUsageOk::new();
+        match self.hierarchy {
+            Hierarchy::Unified(ref dir) => {
+                if let Some(cpu) = read_stat(&dir.join("cpu.stat"), "usage_usec") {
+                    usage.set_cpu_usage_usec(cpu);
+                }
+                if let Some(memory) = read_u64(&dir.join("memory.current")) {
+                    usage.set_memory_usage(memory);
+                }
+                if let Some(pids) = read_u64(&dir.join("pids.current")) {
+                    usage.set_pids(pids);
+                }
+            }
+            Hierarchy::Legacy(_) => {
+                let cpu = self.legacy_dir("cpuacct").and_then(|dir| {
+                    read_u64(&dir.join("cpuacct.usage"))
+                });
+                if let Some(cpu) = cpu {
+                    usage.set_cpu_usage_usec(cpu / 1_000);
+                }
+                let memory = self.legacy_dir("memory").and_then(|dir| {
+                    read_u64(&dir.join("memory.usage_in_bytes"))
+                });
+                if let Some(memory) = memory {
+                    usage.set_memory_usage(memory);
+                }
+                let pids = self.legacy_dir("pids").and_then(
+                    |dir| read_u64(&dir.join("pids.current")),
+                );
+                if let Some(pids) = pids {
+                    usage.set_pids(pids);
+                }
+            }
+        }
+        usage
+    }
+
+    fn legacy_dir(&self, controller: &str) -> Option<&Path> {
+        match self.hierarchy {
+            Hierarchy::Legacy(ref dirs) => {
+                dirs.iter().find(|&&(c, _)| c == controller).map(
+                    |&(_, ref dir)| {
+                        dir.as_path()
+                    },
+                )
+            }
+            Hierarchy::Unified(_) => None,
+        }
+    }
+
+    fn set_legacy<T>(
+        &self,
+        controller: &str,
+        file: &str,
+        value: Option<T>,
+        unset: &str,
+    ) -> io::Result<()>
+    where
+        T: ToString,
+    {
+        match self.legacy_dir(controller) {
+            Some(dir) => set(&dir.join(file), value, unset),
+            None if value.is_some() => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("the {} cgroup controller is not available", controller),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Cgroup {
+    fn drop(&mut self) {
+        let dirs: Vec<&Path> = match self.hierarchy {
+            Hierarchy::Unified(ref dir) => vec![dir.as_path()],
+            Hierarchy::Legacy(ref dirs) => dirs.iter().map(|&(_, ref dir)| dir.as_path()).collect(),
+        };
+        // Removing a cgroup fails while processes are left in it, in which case it is reused by
+        // the service's next process.
+        for dir in dirs {
+            if let Err(err) = fs::remove_dir(dir) {
+                debug!("Unable to remove cgroup {}, {}", dir.display(), err);
+            }
+        }
+    }
+}
+
+/// Moves the calling process into the cgroups behind the given `cgroup.procs` file descriptors.
+///
+/// Only async-signal-safe functions are used, as this runs in a freshly forked child.
+pub fn join(fds: &[RawFd]) -> io::Result<()> {
+    for fd in fds {
+        // Writing "0" moves the writing process itself.
+        let ret = unsafe { ::libc::write(*fd, b"0".as_ptr() as *const ::libc::c_void, 1) };
+        if ret == -1 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+/// Returns the path, relative to the root of its hierarchy, of the cgroup a process belongs to
+/// according to its `/proc/<pid>/cgroup` file. The cgroup in the unified hierarchy is returned
+/// when no controller is given.
+fn own_cgroup(proc_cgroup: &str, controller: Option<&str>) -> Option<PathBuf> {
+    proc_cgroup
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, ':');
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(_), Some(controllers), Some(path)) => Some((controllers, path)),
+                _ => None,
+            }
+        })
+        .find(|&(controllers, _)| match controller {
+            Some(controller) => controllers.split(',').any(|c| c == controller),
+            None => controllers.is_empty(),
+        })
+        .map(|(_, path)| PathBuf::from(path.trim_left_matches('/')))
+}
+
+/// Returns the cgroup in the unified hierarchy the service cgroups are created below. This is
+/// the Launcher's own cgroup, unless the Launcher was already moved into its `launcher` leaf by
+/// an earlier spawn, in which case it's the cgroup that leaf was created in.
+fn unified_base(proc_cgroup: &str) -> PathBuf {
+    let own = own_cgroup(proc_cgroup, None).unwrap_or_default();
+    if own.file_name().map_or(false, |leaf| leaf == LAUNCHER_CGROUP) {
+        own.parent().map(Path::to_path_buf).unwrap_or_default()
+    } else {
+        own
+    }
+}
+
+/// Enables the controllers services are limited with for the children of a cgroup. A
+/// controller the kernel doesn't offer simply stays disabled, and writing a limit for it below
+/// fails, but a cgroup which still holds processes refuses to hand any controller down.
+fn enable_controllers(dir: &Path) -> io::Result<()> {
+    let subtree_control = dir.join("cgroup.subtree_control");
+    for controller in UNIFIED_CONTROLLERS {
+        match write_file(&subtree_control, &format!("+{}", controller)) {
+            Err(ref err) if err.raw_os_error() == Some(::libc::EBUSY) => {
+                return Err(io::Error::new(
+                    err.kind(),
+                    format!("{}: {}", dir.display(), err),
+                ));
+            }
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+/// Turns a service id into a name usable as a cgroup directory.
+fn cgroup_name(id: &str) -> String {
+    id.chars()
+        .map(|c| match c {
+            'a'...'z' | 'A'...'Z' | '0'...'9' | '.' | '-' | '_' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+/// Converts `cpu.shares` (2-262144, default 1024) to `cpu.weight` (1-10000, default 100) the way
+/// the kernel and systemd do.
+fn shares_to_weight(shares: u64) -> u64 {
+    let shares = if shares < 2 {
+        2
+    } else if shares > 262_144 {
+        262_144
+    } else {
+        shares
+    };
+    1 + ((shares - 2) * 9_999) / 262_142
+}
+
+/// Converts a CPU quota in thousandths of a CPU to microseconds per `CPU_PERIOD_USEC`.
+fn quota_to_usec(quota: u64) -> u64 {
+    // The kernel refuses quotas below 1ms.
+    let usec = quota * CPU_PERIOD_USEC / 1_000;
+    if usec < 1_000 { 1_000 } else { usec }
+}
+
+fn open_procs(dir: &Path) -> io::Result<File> {
+    OpenOptions::new().write(true).open(dir.join("cgroup.procs"))
+}
+
+/// Writes a limit to a cgroup file. When no limit is requested the file is reset to `unset`,
+/// since the cgroup may be left over from an earlier process of the service with other limits.
+/// Failing to reset a file is ignored, as its controller may simply not be enabled.
+fn set<T>(path: &Path, value: Option<T>, unset: &str) -> io::Result<()>
+where
+    T: ToString,
+{
+    match value {
+        Some(value) => {
+            write_file(path, &value.to_string()).map_err(|err| {
+                io::Error::new(err.kind(), format!("{}: {}", path.display(), err))
+            })
+        }
+        None => {
+            if !unset.is_empty() {
+                write_file(path, unset).ok();
+            }
+            Ok(())
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Option<String> {
+    let mut content = String::new();
+    match File::open(path).and_then(|mut f| f.read_to_string(&mut content)) {
+        Ok(_) => Some(content),
+        Err(_) => None,
+    }
+}
+
+fn write_file(path: &Path, value: &str) -> io::Result<()> {
+    let mut file = OpenOptions::new().write(true).open(path)?;
+    file.write_all(value.as_bytes())
+}
+
+fn read_u64(path: &Path) -> Option<u64> {
+    read_file(path).and_then(|content| content.trim().parse().ok())
+}
+
+/// Reads a single counter from a flat keyed file such as `cpu.stat`.
+fn read_stat(path: &Path, key: &str) -> Option<u64> {
+    let content = match read_file(path) {
+        Some(content) => content,
+        None => return None,
+    };
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            match (parts.next(), parts.next()) {
+                (Some(k), Some(v)) if k == key => v.parse().ok(),
+                _ => None,
+            }
+        })
+        .next()
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use super::*;
+
+    const UNIFIED_PROC_CGROUP: &'static str = "0::/system.slice/hab-launcher.service\n";
+    const LEGACY_PROC_CGROUP: &'static str = "12:pids:/system.slice/hab-launcher.service
+11:cpu,cpuacct:/system.slice/hab-launcher.service
+4:memory:/system.slice
+1:name=systemd:/system.slice/hab-launcher.service
+";
+
+    #[test]
+    fn own_cgroup_in_the_unified_hierarchy() {
+        assert_eq!(
+            own_cgroup(UNIFIED_PROC_CGROUP, None),
+            Some(PathBuf::from("system.slice/hab-launcher.service"))
+        );
+        assert_eq!(own_cgroup("0::/\n", None), Some(PathBuf::from("")));
+        assert_eq!(own_cgroup(LEGACY_PROC_CGROUP, None), None);
+    }
+
+    #[test]
+    fn own_cgroup_in_the_legacy_hierarchies() {
+        assert_eq!(
+            own_cgroup(LEGACY_PROC_CGROUP, Some("cpuacct")),
+            Some(PathBuf::from("system.slice/hab-launcher.service"))
+        );
+        assert_eq!(
+            own_cgroup(LEGACY_PROC_CGROUP, Some("memory")),
+            Some(PathBuf::from("system.slice"))
+        );
+        assert_eq!(own_cgroup(LEGACY_PROC_CGROUP, Some("blkio")), None);
+        assert_eq!(own_cgroup(LEGACY_PROC_CGROUP, Some("systemd")), None);
+        assert_eq!(own_cgroup(UNIFIED_PROC_CGROUP, Some("cpu")), None);
+    }
+
+    #[test]
+    fn unified_base_skips_the_launcher_leaf() {
+        assert_eq!(
+            unified_base(UNIFIED_PROC_CGROUP),
+            PathBuf::from("system.slice/hab-launcher.service")
+        );
+        assert_eq!(
+            unified_base("0::/system.slice/hab-launcher.service/launcher\n"),
+            PathBuf::from("system.slice/hab-launcher.service")
+        );
+        assert_eq!(unified_base("0::/launcher\n"), PathBuf::from(""));
+        assert_eq!(unified_base("0::/\n"), PathBuf::from(""));
+    }
+
+    #[test]
+    fn unified_cgroups_share_the_hab_parent() {
+        let pid = unsafe { ::libc::getpid() };
+        let root = env::temp_dir().join(format!("hab-launcher-cgroup-unified-{}", pid));
+        let parent = root.join("hab-launcher.service").join(HAB_CGROUP);
+        for name in &["redis.default", "nginx.default"] {
+            fs::create_dir_all(parent.join(name)).unwrap();
+            File::create(parent.join(name).join("cgroup.procs")).unwrap();
+        }
+        let limits = Limits::default();
+
+        // Before and after the Launcher was moved into its leaf by the first spawn
+        let first = Cgroup::create_unified(
+            &root,
+            "0::/hab-launcher.service\n",
+            "redis.default",
+            &limits,
+        ).unwrap();
+        let second = Cgroup::create_unified(
+            &root,
+            "0::/hab-launcher.service/launcher\n",
+            "nginx.default",
+            &limits,
+        ).unwrap();
+        for cgroup in &[&first, &second] {
+            match cgroup.hierarchy {
+                Hierarchy::Unified(ref dir) => assert_eq!(dir.parent(), Some(parent.as_path())),
+                Hierarchy::Legacy(_) => panic!("expected a unified cgroup"),
+            }
+        }
+        drop(first);
+        drop(second);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn cgroup_name_replaces_separators() {
+        assert_eq!(cgroup_name("redis.default"), "redis.default");
+        assert_eq!(cgroup_name("redis.prod@acme-1_2"), "redis.prod_acme-1_2");
+        assert_eq!(cgroup_name("../../etc"), ".._.._etc");
+    }
+
+    #[test]
+    fn shares_to_weight_matches_the_kernel() {
+        assert_eq!(shares_to_weight(2), 1);
+        assert_eq!(shares_to_weight(1024), 39);
+        assert_eq!(shares_to_weight(262_144), 10_000);
+        assert_eq!(shares_to_weight(0), 1);
+        assert_eq!(shares_to_weight(1_000_000), 10_000);
+    }
+
+    #[test]
+    fn quota_to_usec_is_per_period() {
+        assert_eq!(quota_to_usec(1_000), CPU_PERIOD_USEC);
+        assert_eq!(quota_to_usec(500), CPU_PERIOD_USEC / 2);
+        assert_eq!(quota_to_usec(2_500), CPU_PERIOD_USEC * 5 / 2);
+        assert_eq!(quota_to_usec(1), 1_000);
+    }
+
+    #[test]
+    fn read_stat_finds_the_key() {
+        let pid = unsafe { ::libc::getpid() };
+        let dir = env::temp_dir().join(format!("hab-launcher-cgroup-{}", pid));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("cpu.stat");
+        write!(
+            File::create(&path).unwrap(),
+            "usage_usec 12345\nuser_usec 10000\nsystem_usec 2345\n"
+        ).unwrap();
+
+        assert_eq!(read_stat(&path, "usage_usec"), Some(12345));
+        assert_eq!(read_stat(&path, "system_usec"), Some(2345));
+        assert_eq!(read_stat(&path, "nr_periods"), None);
+        assert_eq!(read_stat(&dir.join("missing"), "usage_usec"), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -16,27 +16,31 @@ use std::io;
 use std::ops::Neg;
 use std::os::unix::process::{CommandExt, ExitStatusExt};
 use std::process::{Command, ExitStatus, Stdio};
+use std::ptr;
 use std::result;
 
 use core::os;
 use core::os::process::{Pid, signal, Signal};
-use libc::{self, c_int, pid_t};
+use libc::{self, c_int, gid_t, pid_t, uid_t};
 use protocol::{self, ShutdownMethod};
 use time::{Duration, SteadyTime};
 
 use error::{Error, Result};
 use service::Service;
+use super::cgroup::{self, Cgroup, Limits};
 
 pub struct Process {
     pid: pid_t,
     status: Option<ExitStatus>,
+    cgroup: Option<Cgroup>,
 }
 
 impl Process {
-    fn new(pid: u32) -> Self {
+    fn new(pid: u32, cgroup: Option<Cgroup>) -> Self {
         Process {
             pid: pid as pid_t,
             status: None,
+            cgroup: cgroup,
         }
     }
 
@@ -44,6 +48,15 @@ impl Process {
         self.pid
     }
 
+    /// Resource usage of the process's cgroup. All counters are unset if the process isn't
+    /// running in a cgroup of its own.
+    pub fn usage(&self) -> protocol::ResourceUsageOk {
+        match self.cgroup {
+            Some(ref cgroup) => cgroup.usage(),
+            None => protocol::ResourceUsageOk::new(),
+        }
+    }
+
     /// Attempt to gracefully terminate a proccess and then forcefully kill it after
     /// 8 seconds if it has not terminated.
     pub fn kill(&mut self) -> ShutdownMethod {
@@ -124,17 +137,44 @@ pub fn run(msg: protocol::Spawn) -> Result<Service> {
     let gid = os::users::get_gid_by_name(msg.get_svc_group()).ok_or(
         Error::GroupNotFound(msg.get_svc_group().to_string()),
     )?;
-    cmd.before_exec(owned_pgid);
+    let limits = Limits::from_spawn(&msg);
+    // Without requested limits the cgroup only serves usage reporting, so a host without
+    // (writable) cgroups doesn't keep the service from starting.
+    let cgroup = match Cgroup::create(msg.get_id(), &limits) {
+        Ok(cgroup) => Some(cgroup),
+        Err(err) => {
+            if !limits.is_empty() {
+                return Err(Error::Cgroup(err));
+            }
+            debug!("Not placing {} in a cgroup, {}", msg.get_id(), err);
+            None
+        }
+    };
+    let procs = cgroup.as_ref().map(|c| c.procs_fds()).unwrap_or_default();
+    let nofile = if msg.has_nofile_limit() {
+        Some(msg.get_nofile_limit())
+    } else {
+        None
+    };
+    // The cgroup and rlimits have to be applied while the child still runs with the Launcher's
+    // privileges, so the service user and group are switched to here instead of with
+    // `Command::uid` and `Command::gid`, which take effect before these callbacks run.
+    cmd.before_exec(move || {
+        owned_pgid()?;
+        cgroup::join(&procs)?;
+        if let Some(nofile) = nofile {
+            set_nofile_limit(nofile)?;
+        }
+        drop_privileges(uid, gid)
+    });
     cmd.stdin(Stdio::null())
         .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .uid(uid)
-        .gid(gid);
+        .stderr(Stdio::piped());
     for (key, val) in msg.get_env().iter() {
         cmd.env(key, val);
     }
     let child = cmd.spawn().map_err(Error::Spawn)?;
-    let process = Process::new(child.id());
+    let process = Process::new(child.id(), cgroup);
     Ok(Service::new(msg, process, child.stdout, child.stderr))
 }
 
@@ -147,3 +187,31 @@ fn owned_pgid() -> result::Result<(), io::Error> {
     }
     Ok(())
 }
+
+fn set_nofile_limit(nofile: u64) -> result::Result<(), io::Error> {
+    let limit = libc::rlimit {
+        rlim_cur: nofile as libc::rlim_t,
+        rlim_max: nofile as libc::rlim_t,
+    };
+    if unsafe { libc::setrlimit(libc::RLIMIT_NOFILE, &limit) } == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+// Mirrors what `Command::uid` and `Command::gid` do, including dropping the supplementary groups
+// of the Launcher when it runs as root.
+fn drop_privileges(uid: uid_t, gid: gid_t) -> result::Result<(), io::Error> {
+    unsafe {
+        if libc::setgid(gid) == -1 {
+            return Err(io::Error::last_os_error());
+        }
+        if libc::getuid() == 0 {
+            libc::setgroups(0, ptr::null());
+        }
+        if libc::setuid(uid) == -1 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
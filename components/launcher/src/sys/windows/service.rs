@@ -46,6 +46,11 @@ impl Process {
         unsafe { kernel32::GetProcessId(self.handle.raw()) as u32 }
     }
 
+    /// Resource usage reporting relies on cgroups and isn't available on Windows.
+    pub fn usage(&self) -> protocol::ResourceUsageOk {
+        protocol::ResourceUsageOk::new()
+    }
+
     pub fn kill(&mut self) -> ShutdownMethod {
         if self.status().is_some() {
             return ShutdownMethod::AlreadyExited;
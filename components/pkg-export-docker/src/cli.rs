@@ -268,6 +268,15 @@ impl<'a, 'b> Cli<'a, 'b> {
             .arg(Arg::with_name("RM_IMAGE")
                  .long("rm-image")
                  .help("Remove local image from engine after build and/or push (default: no)"));
+        let app = app.arg(
+            Arg::with_name("DAEMONLESS")
+                .long("daemonless")
+                .conflicts_with("RM_IMAGE")
+                .help(
+                    "Write an OCI image tarball to ./results, and push it to a remote registry, \
+                    without a Docker daemon (default: no)",
+                ),
+        );
 
         Cli { app: app }
     }
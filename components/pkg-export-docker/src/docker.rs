@@ -26,8 +26,9 @@ use hcore::package::PackageIdent;
 use handlebars::Handlebars;
 
 use super::{Credentials, Naming};
-use build::BuildRoot;
+use build::{BuildRoot, BuildRootContext};
 use error::{Error, Result};
+use layers;
 use serde_json;
 use util;
 
@@ -238,29 +239,7 @@ impl<'a> DockerImage {
     /// * If the destination directory cannot be created
     /// * If the report file cannot be written
     pub fn create_report<P: AsRef<Path>>(&self, ui: &mut UI, dst: P) -> Result<()> {
-        let report = dst.as_ref().join("last_docker_export.env");
-        ui.status(
-            Status::Creating,
-            format!("build report {}", report.display()),
-        )?;
-        fs::create_dir_all(&dst)?;
-        let name_tags: Vec<_> = self.tags
-            .iter()
-            .map(|t| format!("{}:{}", &self.name, t))
-            .collect();
-        let json = json!({
-            "id": &self.id,
-            "name": &self.name,
-            "tags": self.tags.join(","),
-            "name_tags": name_tags.join(","),
-        });
-        util::write_file(
-            &report,
-            &Handlebars::new()
-                .template_render(BUILD_REPORT, &json)
-                .map_err(SyncFailure::new)?,
-        )?;
-        Ok(())
+        write_report(ui, dst, &self.id, &self.name, &self.tags)
     }
 
     pub fn create_docker_config_file(
@@ -391,6 +370,68 @@ impl DockerBuildRoot {
         self.build_docker_image(ui, naming)
     }
 
+    /// Returns the temporary work directory which hosts the root file system.
+    pub fn workdir(&self) -> &Path {
+        self.0.workdir()
+    }
+
+    /// Returns the `BuildRootContext` for the build root.
+    pub fn ctx(&self) -> &BuildRootContext {
+        self.0.ctx()
+    }
+
+    /// Returns the name of the image, and the tags it gets, from the provided naming policy.
+    ///
+    /// # Errors
+    ///
+    /// * If the primary service package cannot be loaded
+    /// * If a custom image name or tag is not a valid template
+    pub fn image_name_and_tags(&self, naming: &Naming) -> Result<(String, Vec<String>)> {
+        let ident = self.0.ctx().installed_primary_svc_ident()?;
+        let version = &ident.version.expect("version exists");
+        let release = &ident.release.expect("release exists");
+        let json = json!({
+            "pkg_origin": ident.origin,
+            "pkg_name": ident.name,
+            "pkg_version": &version,
+            "pkg_release": &release,
+            "channel": self.0.ctx().channel(),
+        });
+        let image_name = match naming.custom_image_name {
+            Some(ref custom) => {
+                Handlebars::new().template_render(custom, &json).map_err(
+                    SyncFailure::new,
+                )?
+            }
+            None => format!("{}/{}", ident.origin, ident.name),
+        }.to_lowercase();
+
+        let image_name = match naming.registry_url {
+            Some(ref url) => format!("{}/{}", url, image_name),
+            None => image_name,
+        }.to_lowercase();
+
+        let mut tags = Vec::new();
+        if naming.version_release_tag {
+            tags.push(format!("{}-{}", &version, &release));
+        }
+        if naming.version_tag {
+            tags.push(version.clone());
+        }
+        if naming.latest_tag {
+            tags.push("latest".to_string());
+        }
+        if let Some(ref custom) = naming.custom_tag {
+            tags.push(
+                Handlebars::new()
+                    .template_render(custom, &json)
+                    .map_err(SyncFailure::new)?
+                    .to_lowercase(),
+            );
+        }
+        Ok((image_name, tags))
+    }
+
     fn add_users_and_groups(&self, ui: &mut UI) -> Result<()> {
         let ctx = self.0.ctx();
         let (users, groups) = ctx.svc_users_and_groups()?;
@@ -458,6 +499,9 @@ impl DockerBuildRoot {
                 ctx.rootfs())?.join("bin/hab")
                 .to_string_lossy()
                 .replace("\\", "/"),
+            "layers": (0..layers::pkg_layers(ctx.rootfs())?.len())
+                .map(pkg_layer_dir)
+                .collect::<Vec<_>>(),
             "volumes": ctx.svc_volumes(),
             "exposes": ctx.svc_exposes().join(" "),
             "primary_svc_ident": ctx.primary_svc_ident().to_string(),
@@ -473,54 +517,78 @@ impl DockerBuildRoot {
         Ok(())
     }
 
-    fn build_docker_image(&self, ui: &mut UI, naming: &Naming) -> Result<DockerImage> {
-        ui.status(Status::Creating, "Docker image")?;
-        let ident = self.0.ctx().installed_primary_svc_ident()?;
-        let version = &ident.version.expect("version exists");
-        let release = &ident.release.expect("release exists");
-        let json = json!({
-            "pkg_origin": ident.origin,
-            "pkg_name": ident.name,
-            "pkg_version": &version,
-            "pkg_release": &release,
-            "channel": self.0.ctx().channel(),
-        });
-        let image_name = match naming.custom_image_name {
-            Some(ref custom) => {
-                Handlebars::new().template_render(custom, &json).map_err(
-                    SyncFailure::new,
-                )?
+    /// Moves each layer's worth of installed packages out of the root file system and into a
+    /// directory of its own, which the `Dockerfile` copies into the image as one layer.
+    fn stage_pkg_layers(&self, ui: &mut UI) -> Result<()> {
+        let ctx = self.0.ctx();
+        for (i, idents) in layers::pkg_layers(ctx.rootfs())?.into_iter().enumerate() {
+            let layer_root = self.0.workdir().join(pkg_layer_dir(i));
+            for ident in idents {
+                ui.status(
+                    Status::Creating,
+                    format!("image layer {} for {}", i, &ident),
+                )?;
+                let path = layers::pkg_path(&ident);
+                let dst = layer_root.join(&path);
+                fs::create_dir_all(dst.parent().expect("parent exists"))?;
+                fs::rename(ctx.rootfs().join(&path), &dst)?;
             }
-            None => format!("{}/{}", ident.origin, ident.name),
-        }.to_lowercase();
-
-        let image_name = match naming.registry_url {
-            Some(ref url) => format!("{}/{}", url, image_name),
-            None => image_name,
-        }.to_lowercase();
+        }
+        Ok(())
+    }
 
+    fn build_docker_image(&self, ui: &mut UI, naming: &Naming) -> Result<DockerImage> {
+        self.stage_pkg_layers(ui)?;
+        ui.status(Status::Creating, "Docker image")?;
+        let (image_name, tags) = self.image_name_and_tags(naming)?;
         let mut image = DockerImage::new(self.0.workdir(), image_name);
-        if naming.version_release_tag {
-            image = image.tag(format!("{}-{}", &version, &release));
-        }
-        if naming.version_tag {
-            image = image.tag(version.clone());
-        }
-        if naming.latest_tag {
-            image = image.tag("latest".to_string());
-        }
-        if let Some(ref custom) = naming.custom_tag {
-            image = image.tag(
-                Handlebars::new()
-                    .template_render(custom, &json)
-                    .map_err(SyncFailure::new)?
-                    .to_lowercase(),
-            );
+        for tag in tags {
+            image = image.tag(tag);
         }
         image.build()
     }
 }
 
+/// Returns the directory, relative to the work directory, that a package layer is staged in.
+fn pkg_layer_dir(layer: usize) -> String {
+    format!("layers/{}", layer)
+}
+
+/// Writes a build report with image metadata in the given path.
+///
+/// # Errors
+///
+/// * If the destination directory cannot be created
+/// * If the report file cannot be written
+pub fn write_report<P: AsRef<Path>>(
+    ui: &mut UI,
+    dst: P,
+    id: &str,
+    name: &str,
+    tags: &[String],
+) -> Result<()> {
+    let report = dst.as_ref().join("last_docker_export.env");
+    ui.status(
+        Status::Creating,
+        format!("build report {}", report.display()),
+    )?;
+    fs::create_dir_all(&dst)?;
+    let name_tags: Vec<_> = tags.iter().map(|t| format!("{}:{}", name, t)).collect();
+    let json = json!({
+        "id": id,
+        "name": name,
+        "tags": tags.join(","),
+        "name_tags": name_tags.join(","),
+    });
+    util::write_file(
+        &report,
+        &Handlebars::new()
+            .template_render(BUILD_REPORT, &json)
+            .map_err(SyncFailure::new)?,
+    )?;
+    Ok(())
+}
+
 /// Returns a `Command` for the Docker program.
 fn docker_cmd() -> Command {
     Command::new(&*DOCKER_PROGRAM)
@@ -14,6 +14,7 @@
 
 use base64::DecodeError;
 use std::process::ExitStatus;
+use hyper::status::StatusCode;
 use std::result;
 use std::string::FromUtf8Error;
 use rusoto_ecr::GetAuthorizationTokenError;
@@ -28,6 +29,10 @@ pub enum Error {
     Base64DecodeError(DecodeError),
     #[fail(display = "Docker build failed with exit code: {}", _0)]
     BuildFailed(ExitStatus),
+        #[fail(display = "Exporting images without a Docker daemon is only supported for Linux, \
+                     not: {}",
+           _0)]
+    DaemonlessUnsupported(String),
     #[fail(display = "Could not determine Docker image ID for image: {}", _0)]
     DockerImageIdNotFound(String),
     #[fail(display = "Switch to Windows containers to export Docker images on Windows. \
@@ -50,6 +55,10 @@ pub enum Error {
     PrimaryServicePackageNotFound(Vec<String>),
     #[fail(display = "Docker image push failed with exit code: {}", _0)]
     PushImageFailed(ExitStatus),
+        #[fail(display = "Registry request to {} failed with status: {}", _0, _1)]
+    RegistryRequestFailed(String, StatusCode),
+        #[fail(display = "No token returned by registry token service: {}", _0)]
+    RegistryTokenNotReturned(String),
     #[fail(display = "Removing Docker local images failed with exit code: {}", _0)]
     RemoveImageFailed(ExitStatus),
 }
@@ -0,0 +1,141 @@
+// Copyright (c) 2018 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Splits the packages installed in a root file system into image layers.
+//!
+//! Every package gets a layer of its own, ordered from the most stable package to the least
+//! stable, so that images built from the same base packages share as many layers as possible.
+//! A package is considered more stable the more of the other installed packages depend on it,
+//! which also puts every package after everything it depends on. When there are more packages
+//! than layers to spare, the most stable packages are grouped into the first layer.
+
+use std::path::{Path, PathBuf};
+
+use hcore::fs as hfs;
+use hcore::package::{PackageIdent, PackageInstall};
+
+use error::Result;
+
+/// The most layers that packages are split into, leaving room under the layer limits of common
+/// storage drivers for the rest of the root file system and anything built on top of the image.
+pub const MAX_PKG_LAYERS: usize = 100;
+
+/// Returns the packages installed in a root file system grouped into layers, from the most
+/// stable layer to the least stable.
+///
+/// # Errors
+///
+/// * If the installed packages or their dependencies cannot be read
+pub fn pkg_layers(rootfs: &Path) -> Result<Vec<Vec<PackageIdent>>> {
+    let mut pkgs = Vec::new();
+    for ident in PackageInstall::all(Some(rootfs))? {
+        let tdeps = PackageInstall::load(&ident, Some(rootfs))?.tdeps()?;
+        pkgs.push((ident, tdeps));
+    }
+    Ok(group(order_by_stability(pkgs), MAX_PKG_LAYERS))
+}
+
+/// Returns the path of an installed package, relative to the root file system.
+pub fn pkg_path(ident: &PackageIdent) -> PathBuf {
+    hfs::pkg_install_path(ident, Some(""))
+}
+
+/// Orders packages, given along with their transitive dependencies, from the one with the most
+/// dependents to the one with the least. Packages with as many dependents are ordered by ident, so
+/// the same packages are always ordered the same way.
+fn order_by_stability(pkgs: Vec<(PackageIdent, Vec<PackageIdent>)>) -> Vec<PackageIdent> {
+    let mut ranked: Vec<(usize, PackageIdent)> = pkgs.iter()
+        .map(|&(ref ident, _)| {
+            let dependents = pkgs.iter()
+                .filter(|&&(_, ref tdeps)| tdeps.contains(ident))
+                .count();
+            (dependents, ident.clone())
+        })
+        .collect();
+    ranked.sort_by(|&(a_deps, ref a), &(b_deps, ref b)| {
+        b_deps.cmp(&a_deps).then_with(|| a.to_string().cmp(&b.to_string()))
+    });
+    ranked.into_iter().map(|(_, ident)| ident).collect()
+}
+
+/// Groups ordered packages into at most `max` layers.
+fn group(pkgs: Vec<PackageIdent>, max: usize) -> Vec<Vec<PackageIdent>> {
+    let first = if pkgs.len() > max {
+        pkgs.len() - max + 1
+    } else {
+        1
+    };
+    let mut layers = Vec::new();
+    let mut pkgs = pkgs.into_iter();
+    let head: Vec<PackageIdent> = pkgs.by_ref().take(first).collect();
+    if !head.is_empty() {
+        layers.push(head);
+    }
+    layers.extend(pkgs.map(|ident| vec![ident]));
+    layers
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+
+    use super::*;
+
+    fn ident(s: &str) -> PackageIdent {
+        PackageIdent::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn dependencies_come_before_their_dependents() {
+        let glibc = ident("core/glibc/2.22/20170513201042");
+        let openssl = ident("core/openssl/1.0.2l/20171014213633");
+        let app = ident("acme/app/1.0.0/20180101000000");
+        let busybox = ident("core/busybox-static/1.24.2/20170513215502");
+        let ordered = order_by_stability(vec![
+            (app.clone(), vec![openssl.clone(), glibc.clone()]),
+            (busybox.clone(), vec![]),
+            (openssl.clone(), vec![glibc.clone()]),
+            (glibc.clone(), vec![]),
+        ]);
+        assert_eq!(ordered, vec![glibc, openssl, app, busybox]);
+    }
+
+    #[test]
+    fn most_stable_packages_are_grouped_when_over_the_limit() {
+        let pkgs: Vec<PackageIdent> = (0..5)
+            .map(|i| ident(&format!("core/pkg{}/1.0.0/20180101000000", i)))
+            .collect();
+        let layers = group(pkgs.clone(), 3);
+        assert_eq!(
+            layers,
+            vec![
+                vec![pkgs[0].clone(), pkgs[1].clone(), pkgs[2].clone()],
+                vec![pkgs[3].clone()],
+                vec![pkgs[4].clone()],
+            ]
+        );
+        assert_eq!(group(pkgs.clone(), 5).len(), 5);
+        assert!(group(Vec::new(), 5).is_empty());
+    }
+
+    #[test]
+    fn pkg_path_is_relative_to_the_root_file_system() {
+        assert_eq!(
+            pkg_path(&ident("core/glibc/2.22/20170513201042")),
+            Path::new("hab/pkgs/core/glibc/2.22/20170513201042")
+        );
+    }
+}
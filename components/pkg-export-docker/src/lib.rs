@@ -17,10 +17,14 @@
 
 #[macro_use]
 extern crate clap;
+extern crate chrono;
+extern crate flate2;
 extern crate hab;
 extern crate habitat_core as hcore;
 extern crate habitat_common as common;
+extern crate habitat_http_client as hab_http;
 extern crate handlebars;
+extern crate hyper;
 extern crate rusoto_core;
 extern crate rusoto_ecr;
 extern crate rusoto_credential as aws_creds;
@@ -30,6 +34,8 @@ extern crate lazy_static;
 extern crate log;
 #[macro_use]
 extern crate serde_json;
+extern crate sha2;
+extern crate tar;
 extern crate tempdir;
 extern crate base64;
 extern crate url;
@@ -44,10 +50,14 @@ pub mod cli;
 mod docker;
 mod error;
 mod fs;
+mod layers;
+mod oci;
+mod registry;
 pub mod rootfs;
 mod util;
 
 use std::env;
+use std::path::Path;
 
 use common::ui::UI;
 use hcore::{channel, PROGRAM_NAME};
@@ -60,9 +70,10 @@ use rusoto_core::request::*;
 use rusoto_ecr::{Ecr, EcrClient, GetAuthorizationTokenRequest};
 
 pub use cli::{Cli, PkgIdentArgOptions, RegistryType};
-pub use build::BuildSpec;
+pub use build::{BuildRoot, BuildRootContext, BuildSpec};
 pub use docker::{DockerImage, DockerBuildRoot};
 pub use error::{Error, Result};
+pub use oci::OciImage;
 
 /// The version of this library and program when built.
 pub const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
@@ -187,8 +198,68 @@ pub fn export(ui: &mut UI, build_spec: BuildSpec, naming: &Naming) -> Result<Doc
     Ok(image)
 }
 
+/// Exports an OCI image, without a Docker engine, from a build specification and naming policy,
+/// archiving its image layout into a tarball in the given directory.
+///
+/// # Errors
+///
+/// * If a generic and temporary build root directory cannot be created containing a root
+/// file system
+/// * If the image layout cannot be written, or archived
+/// * If destroying the temporary build root directory fails
+pub fn export_oci<P: AsRef<Path>>(
+    ui: &mut UI,
+    build_spec: BuildSpec,
+    naming: &Naming,
+    dst: P,
+) -> Result<OciImage> {
+    ui.begin(format!(
+        "Building a runnable OCI image with: {}",
+        build_spec.idents_or_archives.join(", ")
+    ))?;
+    let build_root = DockerBuildRoot::from_build_root(build_spec.create(ui)?, ui)?;
+    let image = oci::export(ui, &build_root, naming)?;
+    let archive = image.archive(ui, dst)?;
+    build_root.destroy(ui)?;
+    ui.end(format!(
+        "OCI image '{}' created in {} with tags: {}",
+        image.name(),
+        archive.display(),
+        image.tags().join(", ")
+    ))?;
+
+    Ok(image)
+}
+
+/// Exports an OCI image from Cli arguments into the given directory, pushing it to a remote
+/// registry when asked to.
+fn export_oci_for_cli_matches(
+    ui: &mut UI,
+    spec: BuildSpec,
+    naming: &Naming,
+    results: &Path,
+    matches: &clap::ArgMatches,
+) -> Result<()> {
+    let oci_image = export_oci(ui, spec, naming, results)?;
+    oci_image.create_report(ui, results)?;
+    if matches.is_present("PUSH_IMAGE") {
+        let credentials = Credentials::new(
+            naming.registry_type,
+            matches.value_of("REGISTRY_USERNAME").expect(
+                "Username not specified",
+            ),
+            matches.value_of("REGISTRY_PASSWORD").expect(
+                "Password not specified",
+            ),
+        )?;
+        oci_image.push(ui, &credentials, naming.registry_url)?;
+    }
+
+    Ok(())
+}
+
 /// Creates a build specification and naming policy from Cli arguments, and then exports a Docker
-/// image to a Docker engine from them.
+/// image to a Docker engine from them, or an OCI image without one.
 ///
 /// # Errors
 ///
@@ -203,12 +274,14 @@ pub fn export_for_cli_matches(ui: &mut UI, matches: &clap::ArgMatches) -> Result
     let default_url = hurl::default_bldr_url();
     let spec = BuildSpec::new_from_cli_matches(&matches, &default_channel, &default_url);
     let naming = Naming::new_from_cli_matches(&matches);
+    let results = env::current_dir()?.join("results");
+
+        if matches.is_present("DAEMONLESS") {
+        return export_oci_for_cli_matches(ui, spec, &naming, &results, matches);
+    }
 
     let docker_image = export(ui, spec, &naming)?;
-    docker_image.create_report(
-        ui,
-        env::current_dir()?.join("results"),
-    )?;
+    docker_image.create_report(ui, &results)?;
 
     if matches.is_present("PUSH_IMAGE") {
         let credentials = Credentials::new(
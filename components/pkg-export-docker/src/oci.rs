@@ -0,0 +1,710 @@
+// Copyright (c) 2018 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Writes a root file system straight into an [OCI image
+//! layout](https://github.com/opencontainers/image-spec/blob/master/image-layout.md), without a
+//! Docker daemon.
+//!
+//! Every installed package becomes a gzipped layer of its own, as grouped by the `layers`
+//! module, and the rest of the root file system becomes one last layer. Layers, along with the
+//! image config and manifest, are stored by the SHA-256 digest of their contents. Package layers
+//! are written the same way every time, so images sharing packages share those layers.
+//!
+//! The layout directory is then archived into one tarball which `skopeo` and friends understand
+//! (unlike `docker load`), and which can be pushed to a registry with the `registry` module.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use common::ui::{UI, Status};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use hcore::os::system::{Architecture, Platform};
+use hcore::package::PackageTarget;
+use serde_json::{self, Value};
+use sha2::{Digest, Sha256};
+use tar;
+
+use super::{Credentials, Naming};
+use docker::{self, DockerBuildRoot};
+use error::{Error, Result};
+use layers;
+use registry::Registry;
+
+pub const MANIFEST_MEDIA_TYPE: &'static str = "application/vnd.oci.image.manifest.v1+json";
+pub const CONFIG_MEDIA_TYPE: &'static str = "application/vnd.oci.image.config.v1+json";
+pub const LAYER_MEDIA_TYPE: &'static str = "application/vnd.oci.image.layer.v1.tar+gzip";
+/// The annotation naming the tag of each manifest in an image index.
+const REF_NAME_ANNOTATION: &'static str = "org.opencontainers.image.ref.name";
+/// The port the Supervisor's HTTP gateway listens on, which every image exposes.
+const SUP_HTTP_PORT: &'static str = "9631";
+
+/// A blob stored in an image layout by the digest of its contents.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Descriptor {
+    pub media_type: &'static str,
+    /// The digest of the blob's contents, in the form `sha256:<hex>`.
+    pub digest: String,
+    pub size: u64,
+}
+
+impl Descriptor {
+    fn to_json(&self) -> Value {
+        json!({
+            "mediaType": self.media_type,
+            "digest": &self.digest,
+            "size": self.size,
+        })
+    }
+}
+
+/// An image written into an OCI image layout, which exists only on disk.
+pub struct OciImage {
+    /// The name of this image.
+    name: String,
+    /// The list of tags for this image.
+    tags: Vec<String>,
+    /// The directory holding the image layout.
+    layout: PathBuf,
+    /// The image manifest.
+    manifest: Descriptor,
+    /// The image config.
+    config: Descriptor,
+    /// The layers holding the root file system, from the most stable package to the rest of the
+    /// root file system.
+    layers: Vec<Descriptor>,
+}
+
+impl OciImage {
+    /// Writes the root file system of a build root into an image layout under its work
+    /// directory.
+    ///
+    /// Anything a `Dockerfile` would otherwise do on top of the root file system, such as
+    /// handing `/hab` to a non-root user or creating volume mount points, is done while writing
+    /// the layer.
+    ///
+    /// # Errors
+    ///
+    /// * If the build root is for a platform other than Linux
+    /// * If the layer, config or manifest cannot be written
+    pub fn write(
+        ui: &mut UI,
+        build_root: &DockerBuildRoot,
+        name: String,
+        tags: Vec<String>,
+    ) -> Result<Self> {
+        ui.status(Status::Creating, "OCI image layout")?;
+        let ctx = build_root.ctx();
+        let target = PackageTarget::default();
+        let architecture = match (target.platform, target.architecture) {
+            (Platform::Linux, Architecture::X86_64) |
+            (Platform::LinuxKernel2, Architecture::X86_64) => "amd64",
+            (Platform::Linux, Architecture::Aarch64) |
+            (Platform::LinuxKernel2, Architecture::Aarch64) => "arm64",
+            _ => return Err(Error::DaemonlessUnsupported(target.to_string()))?,
+        };
+        let layout = build_root.workdir().join("oci");
+        fs::create_dir_all(blobs_path(&layout))?;
+
+        for volume in ctx.svc_volumes() {
+            fs::create_dir_all(ctx.rootfs().join(volume.trim_left_matches('/')))?;
+        }
+        let owner = Owner {
+            uid: ctx.primary_user_id(),
+            gid: ctx.primary_group_id(),
+        };
+        let created = Utc::now().to_rfc3339();
+        let created_by = format!("hab-pkg-export-docker {}", super::VERSION.trim());
+        let mut layers = Vec::new();
+        let mut diff_ids = Vec::new();
+        let mut history = Vec::new();
+        let mut pkg_paths = HashSet::new();
+        for idents in layers::pkg_layers(ctx.rootfs())? {
+            let names: Vec<String> = idents.iter().map(|i| i.to_string()).collect();
+            ui.status(
+                Status::Creating,
+                format!("image layer for {}", names.join(", ")),
+            )?;
+            let paths: Vec<PathBuf> = idents.iter().map(layers::pkg_path).collect();
+            let (layer, diff_id) = write_pkg_layer(&layout, ctx.rootfs(), &paths, &owner)?;
+            layers.push(layer);
+            diff_ids.push(diff_id);
+            history.push(json!({
+                "created": &created,
+                "created_by": format!("{}: {}", &created_by, names.join(" ")),
+            }));
+            pkg_paths.extend(paths);
+        }
+        ui.status(Status::Creating, "image layer from the rest of the root file system")?;
+        let (layer, diff_id) = write_rootfs_layer(&layout, ctx.rootfs(), &pkg_paths, &owner)?;
+        layers.push(layer);
+        diff_ids.push(diff_id);
+        history.push(json!({
+            "created": &created,
+            "created_by": created_by,
+        }));
+
+        let mut env = vec![format!("PATH={}", ctx.env_path())];
+        if ctx.primary_user_id() != 0 {
+            // Disables the Supervisor's checks around installing packages, as the non-root
+            // `Dockerfile` does
+            env.push(String::from("HAB_NON_ROOT=1"));
+        }
+        let mut exposed_ports = BTreeMap::new();
+        for port in Some(SUP_HTTP_PORT).into_iter().chain(ctx.svc_exposes()) {
+            let port = if port.contains('/') {
+                port.to_string()
+            } else {
+                format!("{}/tcp", port)
+            };
+            exposed_ports.insert(port, json!({}));
+        }
+        let mut volumes = BTreeMap::new();
+        for volume in ctx.svc_volumes() {
+            volumes.insert(volume, json!({}));
+        }
+        let config = json!({
+            "created": &created,
+            "architecture": architecture,
+            "os": "linux",
+            "config": {
+                "User": format!("{}:{}", ctx.primary_user_id(), ctx.primary_group_id()),
+                "Env": env,
+                "ExposedPorts": exposed_ports,
+                "Volumes": volumes,
+                "Entrypoint": ["/init.sh"],
+                "Cmd": ["start", ctx.primary_svc_ident().to_string()],
+            },
+            "rootfs": {
+                "type": "layers",
+                "diff_ids": diff_ids,
+            },
+            "history": history,
+        });
+        let config = write_blob(&layout, CONFIG_MEDIA_TYPE, &serde_json::to_vec(&config)?)?;
+
+        let manifest = json!({
+            "schemaVersion": 2,
+            "mediaType": MANIFEST_MEDIA_TYPE,
+            "config": config.to_json(),
+            "layers": layers.iter().map(|l| l.to_json()).collect::<Vec<_>>(),
+        });
+        let manifest = write_blob(&layout, MANIFEST_MEDIA_TYPE, &serde_json::to_vec(&manifest)?)?;
+
+        let manifests: Vec<Value> = if tags.is_empty() {
+            vec![manifest.to_json()]
+        } else {
+            tags.iter()
+                .map(|tag| {
+                    let mut entry = manifest.to_json();
+                    entry["annotations"] = json!({ REF_NAME_ANNOTATION: tag });
+                    entry
+                })
+                .collect()
+        };
+        let index = json!({
+            "schemaVersion": 2,
+            "manifests": manifests,
+        });
+        File::create(layout.join("index.json"))?.write_all(
+            &serde_json::to_vec(&index)?,
+        )?;
+        File::create(layout.join("oci-layout"))?.write_all(
+            br#"{"imageLayoutVersion":"1.0.0"}"#,
+        )?;
+
+        Ok(OciImage {
+            name: name,
+            tags: tags,
+            layout: layout,
+            manifest: manifest,
+            config: config,
+            layers: layers,
+        })
+    }
+
+    /// Archives the image layout into a tarball in the given directory, returning its path.
+    ///
+    /// # Errors
+    ///
+    /// * If the destination directory cannot be created
+    /// * If the tarball cannot be written
+    pub fn archive<P: AsRef<Path>>(&self, ui: &mut UI, dst: P) -> Result<PathBuf> {
+        fs::create_dir_all(&dst)?;
+        let tarball = dst.as_ref().join(format!(
+            "{}.oci.tar",
+            self.name.replace("/", "-").replace(":", "-")
+        ));
+        ui.status(
+            Status::Creating,
+            format!("image archive {}", tarball.display()),
+        )?;
+        let mut builder = tar::Builder::new(File::create(&tarball)?);
+        builder.append_dir_all(".", &self.layout)?;
+        builder.into_inner()?.flush()?;
+        Ok(tarball)
+    }
+
+    /// Pushes the image, with all tags, to a remote registry using the provided `Credentials`.
+    ///
+    /// Blobs the registry already has are not uploaded again.
+    ///
+    /// # Errors
+    ///
+    /// * If the registry cannot be authenticated with
+    /// * If uploading a blob or a manifest fails
+    pub fn push(
+        &self,
+        ui: &mut UI,
+        credentials: &Credentials,
+        registry_url: Option<&str>,
+    ) -> Result<()> {
+        ui.begin(format!(
+            "Pushing OCI image '{}' with all tags to remote registry",
+            self.name()
+        ))?;
+        let registry = Registry::connect(&self.name, credentials, registry_url)?;
+        for blob in self.layers.iter().chain(Some(&self.config)) {
+            if registry.has_blob(blob)? {
+                ui.status(Status::Using, format!("existing blob {}", &blob.digest))?;
+                continue;
+            }
+            ui.status(Status::Uploading, format!("blob {}", &blob.digest))?;
+            registry.upload_blob(blob, &blob_path(&self.layout, blob))?;
+        }
+        let tags = if self.tags.is_empty() {
+            vec![String::from("latest")]
+        } else {
+            self.tags.clone()
+        };
+        for tag in tags {
+            let image_tag = format!("{}:{}", &self.name, &tag);
+            ui.status(
+                Status::Uploading,
+                format!("image '{}' to remote registry", &image_tag),
+            )?;
+            registry.put_manifest(
+                &tag,
+                &self.manifest,
+                &blob_path(&self.layout, &self.manifest),
+            )?;
+            ui.status(Status::Uploaded, format!("image '{}'", &image_tag))?;
+        }
+        ui.end(format!(
+            "OCI image '{}' published with tags: {}",
+            self.name(),
+            self.tags().join(", "),
+        ))?;
+
+        Ok(())
+    }
+
+    /// Create a build report with image metadata in the given path.
+    ///
+    /// # Errors
+    ///
+    /// * If the destination directory cannot be created
+    /// * If the report file cannot be written
+    pub fn create_report<P: AsRef<Path>>(&self, ui: &mut UI, dst: P) -> Result<()> {
+        docker::write_report(ui, dst, self.id(), &self.name, &self.tags)
+    }
+
+    /// Returns the digest of this image's manifest, which identifies it in a registry.
+    pub fn id(&self) -> &str {
+        self.manifest.digest.as_str()
+    }
+
+    /// Returns the name of this image.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the list of tags for this image.
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+}
+
+/// Exports an OCI image from a build root using the provided naming policy.
+pub fn export(ui: &mut UI, build_root: &DockerBuildRoot, naming: &Naming) -> Result<OciImage> {
+    let (name, tags) = build_root.image_name_and_tags(naming)?;
+    OciImage::write(ui, build_root, name, tags)
+}
+
+fn blobs_path(layout: &Path) -> PathBuf {
+    layout.join("blobs").join("sha256")
+}
+
+fn blob_path(layout: &Path, blob: &Descriptor) -> PathBuf {
+    blobs_path(layout).join(blob.digest.trim_left_matches("sha256:"))
+}
+
+fn write_blob(layout: &Path, media_type: &'static str, content: &[u8]) -> Result<Descriptor> {
+    let mut hasher = Sha256::default();
+    hasher.input(content);
+    let blob = Descriptor {
+        media_type: media_type,
+        digest: format!("sha256:{:x}", hasher.result()),
+        size: content.len() as u64,
+    };
+    File::create(blob_path(layout, &blob))?.write_all(content)?;
+    Ok(blob)
+}
+
+/// The writer a layer is built with, which yields the digest of both the uncompressed and the
+/// compressed layer.
+type LayerWriter = HashingWriter<GzEncoder<HashingWriter<File>>>;
+
+/// Who owns the files in a layer: everything is owned by root, other than `/hab` which is owned
+/// by the primary user and group.
+struct Owner {
+    uid: u32,
+    gid: u32,
+}
+
+impl Owner {
+    fn set(&self, header: &mut tar::Header, path: &Path) {
+        if path.starts_with("hab") {
+            header.set_uid(self.uid as u64);
+            header.set_gid(self.gid as u64);
+        } else {
+            header.set_uid(0);
+            header.set_gid(0);
+        }
+    }
+}
+
+/// Writes a gzipped layer, returning the layer's descriptor along with the digest of its
+/// uncompressed contents (its "diff ID").
+fn write_layer<F>(layout: &Path, append: F) -> Result<(Descriptor, String)>
+where
+    F: FnOnce(&mut tar::Builder<LayerWriter>) -> Result<()>,
+{
+    let partial = layout.join("layer.tar.gz.partial");
+    let compressed = HashingWriter::new(File::create(&partial)?);
+    let uncompressed = HashingWriter::new(GzEncoder::new(compressed, Compression::default()));
+    let mut builder = tar::Builder::new(uncompressed);
+    append(&mut builder)?;
+    let uncompressed = builder.into_inner()?;
+    let diff_id = format!("sha256:{:x}", uncompressed.hasher.result());
+    let compressed = uncompressed.inner.finish()?;
+    let layer = Descriptor {
+        media_type: LAYER_MEDIA_TYPE,
+        digest: format!("sha256:{:x}", compressed.hasher.result()),
+        size: compressed.written,
+    };
+    fs::rename(&partial, blob_path(layout, &layer))?;
+    Ok((layer, diff_id))
+}
+
+/// Writes a layer holding installed packages, given by their paths in the root file system.
+///
+/// The directories above each package are made up rather than copied from the root file system,
+/// so that a package's layer doesn't change with when or alongside what it was installed.
+fn write_pkg_layer(
+    layout: &Path,
+    rootfs: &Path,
+    pkg_paths: &[PathBuf],
+    owner: &Owner,
+) -> Result<(Descriptor, String)> {
+    write_layer(layout, |builder| {
+        let mut parents = BTreeSet::new();
+        for pkg_path in pkg_paths {
+            let mut parent = pkg_path.parent();
+            while let Some(path) = parent {
+                if path == Path::new("") {
+                    break;
+                }
+                parents.insert(path.to_path_buf());
+                parent = path.parent();
+            }
+        }
+        for parent in parents {
+            let mut header = tar::Header::new_gnu();
+            header.set_entry_type(tar::EntryType::Directory);
+            header.set_mode(0o755);
+            header.set_mtime(0);
+            header.set_size(0);
+            owner.set(&mut header, &parent);
+            builder.append_data(&mut header, &parent, io::empty())?;
+        }
+        for pkg_path in pkg_paths {
+            append_path(builder, rootfs, pkg_path, owner, &HashSet::new())?;
+        }
+        Ok(())
+    })
+}
+
+/// Writes a layer holding everything in the root file system other than the given paths.
+fn write_rootfs_layer(
+    layout: &Path,
+    rootfs: &Path,
+    exclude: &HashSet<PathBuf>,
+    owner: &Owner,
+) -> Result<(Descriptor, String)> {
+    write_layer(layout, |builder| {
+        append_children(builder, rootfs, Path::new(""), owner, exclude)
+    })
+}
+
+fn append_children<W: Write>(
+    builder: &mut tar::Builder<W>,
+    rootfs: &Path,
+    dir: &Path,
+    owner: &Owner,
+    exclude: &HashSet<PathBuf>,
+) -> Result<()> {
+    // Entries are sorted so that the same root file system always makes the same layer
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(rootfs.join(dir))? {
+        entries.push(entry?.file_name());
+    }
+    entries.sort();
+    for file_name in entries {
+        append_path(builder, rootfs, &dir.join(&file_name), owner, exclude)?;
+    }
+    Ok(())
+}
+
+fn append_path<W: Write>(
+    builder: &mut tar::Builder<W>,
+    rootfs: &Path,
+    path: &Path,
+    owner: &Owner,
+    exclude: &HashSet<PathBuf>,
+) -> Result<()> {
+    if exclude.contains(path) {
+        return Ok(());
+    }
+    let full_path = rootfs.join(path);
+    let metadata = fs::symlink_metadata(&full_path)?;
+    // Only the mode is taken from the file itself, so that a layer doesn't change with when, or
+    // by whom, its files were written
+    let mut header = tar::Header::new_gnu();
+    header.set_mode(mode(&metadata));
+    header.set_mtime(0);
+    header.set_size(0);
+    owner.set(&mut header, path);
+    let file_type = metadata.file_type();
+    if file_type.is_symlink() {
+        header.set_entry_type(tar::EntryType::Symlink);
+        header.set_link_name(fs::read_link(&full_path)?)?;
+        builder.append_data(&mut header, path, io::empty())?;
+    } else if file_type.is_dir() {
+        header.set_entry_type(tar::EntryType::Directory);
+        builder.append_data(&mut header, path, io::empty())?;
+        append_children(builder, rootfs, path, owner, exclude)?;
+    } else if file_type.is_file() {
+        header.set_entry_type(tar::EntryType::Regular);
+        header.set_size(metadata.len());
+        builder.append_data(&mut header, path, File::open(&full_path)?)?;
+    } else {
+        debug!("Skipping special file {}", full_path.display());
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn mode(metadata: &fs::Metadata) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+
+    metadata.permissions().mode() & 0o7777
+}
+
+#[cfg(not(unix))]
+fn mode(metadata: &fs::Metadata) -> u32 {
+    if metadata.is_dir() {
+        0o755
+    } else {
+        0o644
+    }
+}
+
+/// A writer which hashes and counts everything written through it.
+struct HashingWriter<W> {
+    inner: W,
+    hasher: Sha256,
+    written: u64,
+}
+
+impl<W: Write> HashingWriter<W> {
+    fn new(inner: W) -> Self {
+        HashingWriter {
+            inner: inner,
+            hasher: Sha256::default(),
+            written: 0,
+        }
+    }
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.hasher.input(&buf[..n]);
+        self.written += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::{self, File};
+    use std::io::{Read, Write};
+    use std::thread;
+    use std::time::Duration;
+
+    use flate2::read::GzDecoder;
+    use sha2::{Digest, Sha256};
+    use tar;
+    use tempdir::TempDir;
+
+    use super::*;
+
+    const GLIBC: &'static str = "hab/pkgs/core/glibc/2.22/20170513201042";
+
+    fn owner() -> Owner {
+        Owner { uid: 42, gid: 43 }
+    }
+
+    fn write_file(rootfs: &Path, path: &str, content: &[u8]) {
+        let path = rootfs.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        File::create(path).unwrap().write_all(content).unwrap();
+    }
+
+    fn fake_rootfs() -> TempDir {
+        let rootfs = TempDir::new("rootfs").unwrap();
+        fs::create_dir_all(rootfs.path().join("hab/svc/redis")).unwrap();
+        write_file(rootfs.path(), "etc/passwd", b"root:x:0:0::/:/bin/sh\n");
+        write_file(rootfs.path(), &format!("{}/lib/libc.so", GLIBC), b"libc");
+        rootfs
+    }
+
+    fn fake_layout() -> TempDir {
+        let layout = TempDir::new("layout").unwrap();
+        fs::create_dir_all(blobs_path(layout.path())).unwrap();
+        layout
+    }
+
+    /// Returns the path and owner of every entry in a layer, checking the layer's digests on the
+    /// way.
+    fn layer_entries(layout: &Path, layer: &Descriptor, diff_id: &str) -> Vec<(String, u64)> {
+        let mut compressed = Vec::new();
+        File::open(blob_path(layout, layer))
+            .unwrap()
+            .read_to_end(&mut compressed)
+            .unwrap();
+        let mut hasher = Sha256::default();
+        hasher.input(&compressed);
+        assert_eq!(layer.digest, format!("sha256:{:x}", hasher.result()));
+        assert_eq!(layer.size, compressed.len() as u64);
+
+        let mut uncompressed = Vec::new();
+        GzDecoder::new(&compressed[..])
+            .read_to_end(&mut uncompressed)
+            .unwrap();
+        let mut hasher = Sha256::default();
+        hasher.input(&uncompressed);
+        assert_eq!(diff_id, format!("sha256:{:x}", hasher.result()));
+
+        let mut archive = tar::Archive::new(&uncompressed[..]);
+        let entries = archive.entries().unwrap();
+        entries
+            .map(|e| {
+                let e = e.unwrap();
+                let path = e.path().unwrap().to_string_lossy().into_owned();
+                (path.trim_right_matches('/').to_string(), e.header().uid().unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rootfs_layer_skips_packages_and_hands_hab_to_the_user() {
+        let rootfs = fake_rootfs();
+        let layout = fake_layout();
+        let mut exclude = HashSet::new();
+        exclude.insert(PathBuf::from(GLIBC));
+
+        let (layer, diff_id) =
+            write_rootfs_layer(layout.path(), rootfs.path(), &exclude, &owner()).unwrap();
+
+        assert_eq!(
+            layer_entries(layout.path(), &layer, &diff_id),
+            vec![
+                (String::from("etc"), 0),
+                (String::from("etc/passwd"), 0),
+                (String::from("hab"), 42),
+                (String::from("hab/pkgs"), 42),
+                (String::from("hab/pkgs/core"), 42),
+                (String::from("hab/pkgs/core/glibc"), 42),
+                (String::from("hab/pkgs/core/glibc/2.22"), 42),
+                (String::from("hab/svc"), 42),
+                (String::from("hab/svc/redis"), 42),
+            ]
+        );
+    }
+
+    #[test]
+    fn pkg_layers_hold_only_their_packages_and_are_reproducible() {
+        let rootfs = fake_rootfs();
+        let layout = fake_layout();
+        let pkgs = vec![PathBuf::from(GLIBC)];
+
+        let (layer, diff_id) =
+            write_pkg_layer(layout.path(), rootfs.path(), &pkgs, &owner()).unwrap();
+
+        assert_eq!(
+            layer_entries(layout.path(), &layer, &diff_id),
+            vec![
+                (String::from("hab"), 42),
+                (String::from("hab/pkgs"), 42),
+                (String::from("hab/pkgs/core"), 42),
+                (String::from("hab/pkgs/core/glibc"), 42),
+                (String::from("hab/pkgs/core/glibc/2.22"), 42),
+                (String::from(GLIBC), 42),
+                (format!("{}/lib", GLIBC), 42),
+                (format!("{}/lib/libc.so", GLIBC), 42),
+            ]
+        );
+        // Installing the same package later, alongside others, makes the very same layer
+        thread::sleep(Duration::from_millis(1100));
+        let other_rootfs = fake_rootfs();
+        fs::create_dir_all(other_rootfs.path().join("hab/pkgs/core/zlib")).unwrap();
+        let other_layout = fake_layout();
+        let (other_layer, other_diff_id) =
+            write_pkg_layer(other_layout.path(), other_rootfs.path(), &pkgs, &owner()).unwrap();
+        assert_eq!(other_layer, layer);
+        assert_eq!(other_diff_id, diff_id);
+    }
+
+    #[test]
+    fn blobs_are_named_by_their_digest() {
+        let layout = TempDir::new("layout").unwrap();
+        fs::create_dir_all(blobs_path(layout.path())).unwrap();
+        let blob = write_blob(layout.path(), CONFIG_MEDIA_TYPE, b"{}").unwrap();
+        assert_eq!(
+            blob.digest,
+            "sha256:44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a"
+        );
+        assert_eq!(blob.size, 2);
+        assert!(blob_path(layout.path(), &blob).is_file());
+    }
+}
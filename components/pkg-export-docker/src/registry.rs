@@ -0,0 +1,298 @@
+// Copyright (c) 2018 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A minimal client for the [Docker Registry HTTP API
+//! v2](https://docs.docker.com/registry/spec/api/), which is just enough to push an image
+//! without a Docker daemon.
+//!
+//! Registries which hand out bearer tokens, such as Docker Hub, are asked for a token to push to
+//! the image's repository using the registry credentials. Every other registry is sent the
+//! credentials directly using basic authentication.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::path::Path;
+
+use hab_http::ApiClient;
+use hyper::client::{Body, Response};
+use hyper::header::{Authorization, ContentType, Location};
+use hyper::mime::Mime;
+use hyper::status::StatusCode;
+use hyper::Url;
+use serde_json::{self, Value};
+
+use super::{Credentials, VERSION};
+use error::{Error, Result};
+use oci::Descriptor;
+
+/// The registry images are pushed to when no registry URL is given.
+const DEFAULT_REGISTRY_URL: &'static str = "https://registry-1.docker.io";
+const PRODUCT: &'static str = "hab-pkg-export-docker";
+
+/// A connection to one repository in a registry.
+pub struct Registry {
+    client: ApiClient,
+    /// The base URL of the registry.
+    endpoint: Url,
+    /// The repository being pushed to, such as `core/redis`.
+    repository: String,
+    /// The `Authorization` header value sent with every request.
+    authorization: String,
+}
+
+impl Registry {
+    /// Connects to the registry hosting an image, authenticating with the given credentials.
+    ///
+    /// # Errors
+    ///
+    /// * If the registry cannot be reached
+    /// * If the registry hands out tokens, and won't give us one for the image's repository
+    pub fn connect(
+        image_name: &str,
+        credentials: &Credentials,
+        registry_url: Option<&str>,
+    ) -> Result<Self> {
+        let (endpoint, repository) = endpoint_and_repository(image_name, registry_url);
+        let endpoint = Url::parse(&endpoint)?;
+        let client = ApiClient::new(endpoint.clone(), PRODUCT, VERSION, None)?;
+        let basic = format!("Basic {}", credentials.token);
+        let res = client.get("v2/").send()?;
+        let challenge = match res.status {
+            StatusCode::Unauthorized => www_authenticate(&res).and_then(|c| parse_challenge(&c)),
+            _ => None,
+        };
+        let authorization = match challenge {
+            Some(ref params) if params.contains_key("realm") => {
+                format!("Bearer {}", bearer_token(params, &repository, &basic)?)
+            }
+            _ => basic,
+        };
+
+        Ok(Registry {
+            client: client,
+            endpoint: endpoint,
+            repository: repository,
+            authorization: authorization,
+        })
+    }
+
+    /// Returns whether the registry already has a blob in the repository.
+    pub fn has_blob(&self, blob: &Descriptor) -> Result<bool> {
+        let path = format!("v2/{}/blobs/{}", &self.repository, &blob.digest);
+        let res = self.client
+            .head(&path)
+            .header(Authorization(self.authorization.clone()))
+            .send()?;
+        match res.status {
+            StatusCode::Ok => Ok(true),
+            StatusCode::NotFound => Ok(false),
+            status => Err(Error::RegistryRequestFailed(path, status))?,
+        }
+    }
+
+    /// Uploads a blob to the repository in one request.
+    pub fn upload_blob(&self, blob: &Descriptor, src: &Path) -> Result<()> {
+        let path = format!("v2/{}/blobs/uploads/", &self.repository);
+        let res = self.client
+            .post(&path)
+            .header(Authorization(self.authorization.clone()))
+            .send()?;
+        if res.status != StatusCode::Accepted {
+            return Err(Error::RegistryRequestFailed(path, res.status))?;
+        }
+        // The upload location may be relative to the registry, or somewhere else entirely
+        let location = match res.headers.get::<Location>() {
+            Some(&Location(ref location)) => location.clone(),
+            None => return Err(Error::RegistryRequestFailed(path, res.status))?,
+        };
+        let mut upload_url = self.endpoint.join(&location)?;
+        upload_url.query_pairs_mut().append_pair(
+            "digest",
+            &blob.digest,
+        );
+
+        let mut file = File::open(src)?;
+        let res = self.client
+            .put_with_custom_url("", |url| *url = upload_url.clone())
+            .header(Authorization(self.authorization.clone()))
+            .header(ContentType(mime("application/octet-stream")))
+            .body(Body::SizedBody(&mut file, blob.size))
+            .send()?;
+        match res.status {
+            StatusCode::Created => Ok(()),
+            status => Err(Error::RegistryRequestFailed(upload_url.to_string(), status))?,
+        }
+    }
+
+    /// Uploads an image manifest to the repository under the given tag.
+    pub fn put_manifest(&self, tag: &str, manifest: &Descriptor, src: &Path) -> Result<()> {
+        let path = format!("v2/{}/manifests/{}", &self.repository, tag);
+        let mut file = File::open(src)?;
+        let res = self.client
+            .put(&path)
+            .header(Authorization(self.authorization.clone()))
+            .header(ContentType(mime(manifest.media_type)))
+            .body(Body::SizedBody(&mut file, manifest.size))
+            .send()?;
+        match res.status {
+            StatusCode::Created => Ok(()),
+            status => Err(Error::RegistryRequestFailed(path, status))?,
+        }
+    }
+}
+
+/// Splits an image name into the URL of the registry hosting it and its repository in that
+/// registry. Images are named after their registry's host, unless they live on Docker Hub.
+fn endpoint_and_repository(image_name: &str, registry_url: Option<&str>) -> (String, String) {
+    let registry_url = match registry_url {
+        Some(url) => url,
+        None => return (DEFAULT_REGISTRY_URL.to_string(), image_name.to_string()),
+    };
+    let scheme = if registry_url.starts_with("http://") {
+        "http"
+    } else {
+        "https"
+    };
+    let name = image_name
+        .trim_left_matches("http://")
+        .trim_left_matches("https://");
+    let mut parts = name.splitn(2, '/');
+    let host = parts.next().unwrap_or("");
+    let repository = parts.next().unwrap_or("");
+    (format!("{}://{}", scheme, host), repository.to_string())
+}
+
+/// Asks a registry's token service for a token to push to a repository.
+fn bearer_token(
+    challenge: &BTreeMap<String, String>,
+    repository: &str,
+    basic: &str,
+) -> Result<String> {
+    let realm = &challenge["realm"];
+    let client = ApiClient::new(realm.as_str(), PRODUCT, VERSION, None)?;
+    let scope = format!("repository:{}:pull,push", repository);
+    let res = client
+        .get_with_custom_url("", |url| {
+            if let Some(service) = challenge.get("service") {
+                url.query_pairs_mut().append_pair("service", service);
+            }
+            url.query_pairs_mut().append_pair("scope", &scope);
+        })
+        .header(Authorization(basic.to_string()))
+        .send()?;
+    if res.status != StatusCode::Ok {
+        return Err(Error::RegistryRequestFailed(realm.clone(), res.status))?;
+    }
+    let body: Value = serde_json::from_reader(res)?;
+    // Token services may answer with either field, per the token authentication spec
+    match body["token"].as_str().or(body["access_token"].as_str()) {
+        Some(token) => Ok(token.to_string()),
+        None => Err(Error::RegistryTokenNotReturned(realm.clone()))?,
+    }
+}
+
+fn www_authenticate(res: &Response) -> Option<String> {
+    res.headers.get_raw("WWW-Authenticate").and_then(|values| {
+        values.first().map(
+            |v| String::from_utf8_lossy(v).into_owned(),
+        )
+    })
+}
+
+/// Parses a `Bearer` challenge from a `WWW-Authenticate` header, such as
+/// `Bearer realm="https://auth.docker.io/token",service="registry.docker.io"`, into its
+/// parameters. Challenges for any other scheme are ignored.
+fn parse_challenge(header: &str) -> Option<BTreeMap<String, String>> {
+    let header = header.trim();
+    if !header.starts_with("Bearer ") {
+        return None;
+    }
+    let mut params = BTreeMap::new();
+    let mut chars = header["Bearer ".len()..].chars().peekable();
+    loop {
+        let key: String = chars
+            .by_ref()
+            .skip_while(|c| *c == ',' || c.is_whitespace())
+            .take_while(|c| *c != '=')
+            .collect();
+        if key.is_empty() {
+            break;
+        }
+        let mut value = String::new();
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' => break,
+                    '\\' => value.extend(chars.next()),
+                    c => value.push(c),
+                }
+            }
+        } else {
+            value.extend(chars.by_ref().take_while(|c| *c != ','));
+        }
+        params.insert(key.trim().to_string(), value);
+    }
+    Some(params)
+}
+
+fn mime(media_type: &str) -> Mime {
+    media_type.parse().expect("Media types are valid MIME types")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_bearer_challenge() {
+        let params = parse_challenge(
+            "Bearer realm=\"https://auth.docker.io/token\",service=\"registry.docker.io\",\
+             scope=\"repository:core/redis:pull,push\"",
+        ).unwrap();
+        assert_eq!(params["realm"], "https://auth.docker.io/token");
+        assert_eq!(params["service"], "registry.docker.io");
+        assert_eq!(params["scope"], "repository:core/redis:pull,push");
+    }
+
+    #[test]
+    fn parse_basic_challenge() {
+        assert_eq!(parse_challenge("Basic realm=\"Registry\""), None);
+    }
+
+    #[test]
+    fn endpoint_and_repository_default_to_docker_hub() {
+        assert_eq!(
+            endpoint_and_repository("core/redis", None),
+            (
+                String::from("https://registry-1.docker.io"),
+                String::from("core/redis"),
+            )
+        );
+    }
+
+    #[test]
+    fn endpoint_and_repository_for_custom_registry() {
+        assert_eq!(
+            endpoint_and_repository(
+                "registry.acme.com:5000/team/core/redis",
+                Some("registry.acme.com:5000/team"),
+            ),
+            (
+                String::from("https://registry.acme.com:5000"),
+                String::from("team/core/redis"),
+            )
+        );
+    }
+}
@@ -20,9 +20,16 @@ use common::ui::{UI, Status};
 use export_docker;
 use export_docker::Result;
 use export_k8s::{Manifest, ManifestJson};
+use serde_json;
+use toml;
 
 use chartfile::ChartFile;
-use values::Values;
+use requirements::Requirements;
+use values::{self, Values};
+
+// Template of the secret holding the service's `user.toml`, rendered by Helm from the `config`
+// values
+const USER_TOML_SECRET: &'static str = include_str!("../defaults/HelmUserTomlSecret.yaml");
 
 pub struct Chart<'a> {
     name: String,
@@ -30,6 +37,9 @@ pub struct Chart<'a> {
     chartfile: ChartFile,
     manifest_template: ManifestJson,
     values: Values,
+    requirements: Requirements,
+    /// Whether the chart generates the service's `user.toml` from its values.
+    user_toml: bool,
     ui: &'a mut UI,
 }
 
@@ -38,7 +48,14 @@ impl<'a> Chart<'a> {
         if !matches.is_present("NO_DOCKER_IMAGE") {
             export_docker::export_for_cli_matches(ui, &matches)?;
         }
-        let manifest = Manifest::new_from_cli_matches(ui, &matches)?;
+        let mut manifest = Manifest::new_from_cli_matches(ui, &matches)?;
+        // A secret given by name holds the whole configuration, so there is nothing to
+        // parameterize
+        let config = if manifest.config_secret_name.is_none() {
+            default_config(ui, &mut manifest, &matches)?
+        } else {
+            None
+        };
         let name = matches
             .value_of("CHART")
             .unwrap_or(&manifest.habitat_name)
@@ -46,16 +63,32 @@ impl<'a> Chart<'a> {
         let version = matches.value_of("VERSION");
         let description = matches.value_of("DESCRIPTION");
         let chartfile = ChartFile::new(&name, version, description);
+        let requirements =
+            Requirements::new_for_binds(&manifest.binds, matches.value_of("DEPENDENCY_REPO"));
 
-        Ok(Self::new_for_manifest(manifest, name, chartfile, ui))
+        Ok(Self::new_for_manifest(
+            manifest,
+            config,
+            name,
+            chartfile,
+            requirements,
+            ui,
+        ))
     }
 
     fn new_for_manifest(
         manifest: Manifest,
+        config: Option<toml::Value>,
         name: String,
         chartfile: ChartFile,
+        requirements: Requirements,
         ui: &'a mut UI,
     ) -> Self {
+        let config_secret_name = match (&manifest.config_secret_name, &config) {
+            (&Some(_), _) => Some("{{.Values.configSecretName}}"),
+            (&None, &Some(_)) => Some("{{.Values.metadataName}}-user-toml"),
+            (&None, &None) => None,
+        };
         let main = json!({
             "metadata_name": "{{.Values.metadataName}}",
             "habitat_name": "{{.Values.habitatName}}",
@@ -63,9 +96,7 @@ impl<'a> Chart<'a> {
             "count": "{{.Values.instanceCount}}",
             "service_topology": "{{.Values.serviceTopology}}",
             "service_group": manifest.service_group.clone().map(|_| "{{.Values.serviceGroup}}"),
-            "config_secret_name": manifest.config_secret_name
-                .clone()
-                .map(|_| "{{.Values.configSecretName}}"),
+            "config_secret_name": config_secret_name,
             "ring_secret_name": manifest.ring_secret_name
                 .clone()
                 .map(|_| "{{.Values.ringSecretName}}"),
@@ -109,9 +140,14 @@ impl<'a> Chart<'a> {
             binds.push(json);
         }
 
+        if let Some(ref config) = config {
+            values.add_config(config);
+        }
+
         let manifest_template = ManifestJson {
             main: main,
             binds: binds,
+            operator: true,
         };
         let habitat_name = manifest.habitat_name;
 
@@ -121,6 +157,8 @@ impl<'a> Chart<'a> {
             chartfile,
             manifest_template,
             values,
+            requirements,
+            user_toml: config.is_some(),
             ui,
         }
     }
@@ -133,6 +171,9 @@ impl<'a> Chart<'a> {
         fs::create_dir_all(&self.name)?;
 
         self.generate_chartfile()?;
+        if !self.requirements.is_empty() {
+            self.generate_requirements()?;
+        }
 
         let template_path = format!("{}/{}", self.name, "templates");
         self.ui.status(
@@ -141,8 +182,12 @@ impl<'a> Chart<'a> {
         )?;
         fs::create_dir_all(&template_path)?;
         self.generate_manifest_template(&template_path)?;
+        if self.user_toml {
+            self.generate_user_toml_template(&template_path)?;
+        }
 
-        self.generate_values()
+        self.generate_values()?;
+        self.generate_values_schema()
     }
 
     pub fn generate_chartfile(&mut self) -> Result<()> {
@@ -159,6 +204,20 @@ impl<'a> Chart<'a> {
         Ok(())
     }
 
+    pub fn generate_requirements(&mut self) -> Result<()> {
+        let path = format!("{}/requirements.yaml", self.name);
+        self.ui.status(
+            Status::Creating,
+            format!("requirements file `{}`", path),
+        )?;
+        let mut write = fs::File::create(path)?;
+        let out = self.requirements.into_string()?;
+
+        write.write(out.as_bytes())?;
+
+        Ok(())
+    }
+
     pub fn generate_manifest_template(&mut self, template_path: &str) -> Result<()> {
         let manifest_path = format!("{}/{}.yaml", template_path, self.habitat_name);
         self.ui.status(
@@ -173,6 +232,19 @@ impl<'a> Chart<'a> {
         Ok(())
     }
 
+    pub fn generate_user_toml_template(&mut self, template_path: &str) -> Result<()> {
+        let path = format!("{}/user-toml-secret.yaml", template_path);
+        self.ui.status(
+            Status::Creating,
+            format!("user.toml template `{}`", path),
+        )?;
+        let mut write = fs::File::create(path)?;
+
+        write.write(USER_TOML_SECRET.as_bytes())?;
+
+        Ok(())
+    }
+
     pub fn generate_values(&mut self) -> Result<()> {
         let path = format!("{}/values.yaml", self.name);
         self.ui.status(
@@ -185,4 +257,46 @@ impl<'a> Chart<'a> {
 
         Ok(())
     }
+
+    pub fn generate_values_schema(&mut self) -> Result<()> {
+        let path = format!("{}/values.schema.json", self.name);
+        self.ui.status(
+            Status::Creating,
+            format!("values schema `{}`", path),
+        )?;
+        let mut write = fs::File::create(path)?;
+
+        serde_json::to_writer_pretty(&mut write, &self.values.schema())?;
+
+        Ok(())
+    }
+}
+
+/// Returns the package's default configuration, which the chart's `config` values start from.
+/// Charts can be generated for packages which aren't around to read, just without
+/// parameterizing their configuration.
+fn default_config(
+    ui: &mut UI,
+    manifest: &mut Manifest,
+    matches: &clap::ArgMatches,
+) -> Result<Option<toml::Value>> {
+    if let Err(e) = manifest.load_pkg_metadata(matches) {
+        ui.warn(format!(
+            "Not generating configuration values for the chart: {}",
+            e
+        ))?;
+        return Ok(None);
+    }
+    let mut config: toml::Value = match manifest.default_config {
+        Some(ref raw) => raw.parse()?,
+        None => return Ok(None),
+    };
+    for key in values::remove_datetimes(&mut config) {
+        ui.warn(format!(
+            "Leaving the datetime `{}` out of the chart's configuration values, as Helm \
+             can't keep it one",
+            key
+        ))?;
+    }
+    Ok(Some(config))
 }
@@ -23,11 +23,13 @@ extern crate handlebars;
 extern crate log;
 #[macro_use]
 extern crate serde_json;
+extern crate toml;
 
 extern crate failure;
 
 mod chart;
 mod chartfile;
+mod requirements;
 mod values;
 
 use std::result;
@@ -95,6 +97,15 @@ fn cli<'a, 'b>() -> clap::App<'a, 'b> {
                 .long("desc")
                 .help("A single-sentence description"),
         )
+        .arg(
+            Arg::with_name("DEPENDENCY_REPO")
+                .value_name("URL")
+                .long("dependency-repo")
+                .help(
+                    "Repository to fetch the charts of bound services from \
+                     (default: chart directories next to this chart's directory)",
+                ),
+        )
 }
 
 fn valid_version(val: String) -> result::Result<(), String> {
@@ -0,0 +1,137 @@
+// Copyright (c) 2018 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use failure::SyncFailure;
+use handlebars::Handlebars;
+
+use export_docker::Result;
+use export_k8s::Bind;
+
+// Helm requirements file template
+const REQUIREMENTS: &'static str = include_str!("../defaults/HelmRequirements.hbs");
+
+/// The version constraint of every dependency, as charts of bound services can be of any version.
+const DEPENDENCY_VERSION: &'static str = "*";
+
+/// The charts of the services a chart's service binds to.
+pub struct Requirements {
+    dependencies: Vec<Dependency>,
+}
+
+struct Dependency {
+    name: String,
+    repository: String,
+}
+
+impl Requirements {
+    /// Creates a dependency on the chart of every service bound to, which is named after the
+    /// service. The charts are fetched from `repository` when given, and from chart directories
+    /// next to the chart's own directory otherwise.
+    pub fn new_for_binds(binds: &[Bind], repository: Option<&str>) -> Self {
+        let mut dependencies: Vec<Dependency> = Vec::new();
+        for bind in binds {
+            if dependencies.iter().any(|d| d.name == bind.service) {
+                continue;
+            }
+            let repository = match repository {
+                Some(r) => r.to_owned(),
+                None => format!("file://../{}", bind.service),
+            };
+            dependencies.push(Dependency {
+                name: bind.service.clone(),
+                repository: repository,
+            });
+        }
+
+        Requirements { dependencies: dependencies }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dependencies.is_empty()
+    }
+
+    // TODO: Implement TryInto trait instead when it's in stable std crate
+    pub fn into_string(&self) -> Result<String> {
+        let dependencies: Vec<_> = self.dependencies
+            .iter()
+            .map(|d| {
+                json!({
+                    "name": d.name,
+                    "version": DEPENDENCY_VERSION,
+                    "repository": d.repository,
+                })
+            })
+            .collect();
+        let json = json!({ "dependencies": dependencies });
+
+        let r = Handlebars::new()
+            .template_render(REQUIREMENTS, &json)
+            .map_err(SyncFailure::new)?;
+        let s = r.lines().filter(|l| *l != "").collect::<Vec<_>>().join(
+            "\n",
+        ) + "\n";
+
+        Ok(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn bind(name: &str, service: &str) -> Bind {
+        Bind {
+            name: name.to_owned(),
+            service: service.to_owned(),
+            group: "default".to_owned(),
+        }
+    }
+
+    #[test]
+    fn one_dependency_per_bound_service() {
+        let binds = vec![
+            bind("database", "postgresql"),
+            bind("cache", "redis"),
+            bind("replica", "postgresql"),
+        ];
+
+        assert_eq!(
+            Requirements::new_for_binds(&binds, None).into_string().unwrap(),
+            "dependencies:
+- name: postgresql
+  version: \"*\"
+  repository: \"file://../postgresql\"
+- name: redis
+  version: \"*\"
+  repository: \"file://../redis\"
+"
+        );
+    }
+
+    #[test]
+    fn dependencies_from_a_repository() {
+        let requirements = Requirements::new_for_binds(
+            &[bind("database", "postgresql")],
+            Some("https://charts.example.com"),
+        );
+
+        assert!(
+            requirements
+                .into_string()
+                .unwrap()
+                .contains("  repository: \"https://charts.example.com\"\n")
+        );
+    }
+}
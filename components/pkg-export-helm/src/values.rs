@@ -14,10 +14,17 @@
 
 extern crate habitat_pkg_export_docker as export_docker;
 
+use std::collections::BTreeMap;
 use std::io::Write;
 
+use serde_json::{Map, Value};
+use toml;
+
 use export_docker::Result;
 
+/// The variable holding the service's configuration, which is rendered into its `user.toml`.
+pub const CONFIG_VARIABLE: &'static str = "config";
+
 pub struct Values {
     values: Vec<ValuesEntry>,
 }
@@ -30,23 +37,271 @@ impl Values {
     pub fn add_entry(&mut self, variable: &str, value: &str) {
         self.values.push(ValuesEntry {
             variable: variable.to_owned(),
-            value: value.to_owned(),
+            value: Value::String(value.to_owned()),
+        });
+    }
+
+    /// Adds a service's configuration, such as the package's `default.toml`, as a tree of values
+    /// which can be overridden one by one, e.g. with `helm install --set config.port=9000`.
+    pub fn add_config(&mut self, config: &toml::Value) {
+        self.values.push(ValuesEntry {
+            variable: CONFIG_VARIABLE.to_owned(),
+            value: toml_to_json(config),
         });
     }
 
     pub fn generate(&self, write: &mut Write) -> Result<()> {
         let mut out = "".to_owned();
         for entry in &self.values {
-            out = out + &format!("{}: \"{}\"\n", entry.variable, entry.value);
+            yaml_entry(&mut out, 0, &entry.variable, &entry.value);
         }
 
         write.write(out.as_bytes())?;
 
         Ok(())
     }
+
+    /// Returns a JSON schema for the values, which Helm validates the values given at install
+    /// time against. Each configuration value must keep the type it has in `default.toml`.
+    pub fn schema(&self) -> Value {
+        let mut properties = Map::new();
+        for entry in &self.values {
+            properties.insert(entry.variable.clone(), schema_for(&entry.value));
+        }
+
+        json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "properties": properties,
+        })
+    }
+}
+
+/// Removes every datetime from a configuration, returning the dotted keys they were under.
+///
+/// Helm has nowhere to keep a datetime but a string, which `toToml` would then write into the
+/// `user.toml` as one, so these are left to the package's `default.toml` instead. An array
+/// holding a datetime anywhere inside it is removed as a whole.
+pub fn remove_datetimes(config: &mut toml::Value) -> Vec<String> {
+    let mut removed = Vec::new();
+    if let toml::Value::Table(ref mut table) = *config {
+        remove_datetimes_under(table, "", &mut removed);
+    }
+    removed
+}
+
+fn remove_datetimes_under(
+    table: &mut BTreeMap<String, toml::Value>,
+    path: &str,
+    removed: &mut Vec<String>,
+) {
+    let keys: Vec<String> = table.keys().cloned().collect();
+    for key in keys {
+        let key_path = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", path, key)
+        };
+        let remove = match table.get_mut(&key) {
+            Some(&mut toml::Value::Table(ref mut t)) => {
+                remove_datetimes_under(t, &key_path, removed);
+                false
+            }
+            Some(value) => contains_datetime(value),
+            None => false,
+        };
+        if remove {
+            table.remove(&key);
+            removed.push(key_path);
+        }
+    }
+}
+
+fn contains_datetime(value: &toml::Value) -> bool {
+    match *value {
+        toml::Value::Datetime(_) => true,
+        toml::Value::Array(ref a) => a.iter().any(contains_datetime),
+        toml::Value::Table(ref t) => t.values().any(contains_datetime),
+        _ => false,
+    }
 }
 
 struct ValuesEntry {
     variable: String,
-    value: String,
+    value: Value,
+}
+
+fn toml_to_json(value: &toml::Value) -> Value {
+    match *value {
+        toml::Value::String(ref s) => Value::String(s.clone()),
+        toml::Value::Integer(i) => Value::from(i),
+        // JSON has no room for NaN or infinity, so those are left out as nulls
+        toml::Value::Float(f) => Value::from(f),
+        toml::Value::Boolean(b) => Value::Bool(b),
+        toml::Value::Datetime(ref d) => Value::String(d.to_string()),
+        toml::Value::Array(ref a) => Value::Array(a.iter().map(toml_to_json).collect()),
+        toml::Value::Table(ref t) => {
+            Value::Object(
+                t.iter()
+                    .map(|(k, v)| (k.clone(), toml_to_json(v)))
+                    .collect(),
+            )
+        }
+    }
+}
+
+/// Writes a value as YAML under the given key. Tables are written as nested blocks, so they
+/// read like the TOML they came from, while arrays are written in flow style, which is as
+/// valid YAML as it is JSON.
+fn yaml_entry(out: &mut String, indent: usize, key: &str, value: &Value) {
+    let key = yaml_key(key);
+    match *value {
+        Value::Object(ref map) if !map.is_empty() => {
+            out.push_str(&format!("{:2$}{}:\n", "", key, indent));
+            for (k, v) in map {
+                yaml_entry(out, indent + 2, k, v);
+            }
+        }
+        ref value => out.push_str(&format!("{:3$}{}: {}\n", "", key, value, indent)),
+    }
+}
+
+/// Returns a key as it's written in YAML, quoting any key which YAML would otherwise read as
+/// something other than a string, such as `on` or `1`.
+fn yaml_key(key: &str) -> String {
+    let plain = match key.chars().next() {
+        Some(c) if c.is_alphabetic() || c == '_' => {
+            key.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+        }
+        _ => false,
+    };
+    let reserved = ["y", "n", "yes", "no", "on", "off", "true", "false", "null"];
+    if plain && !reserved.contains(&key.to_lowercase().as_str()) {
+        key.to_owned()
+    } else {
+        Value::String(key.to_owned()).to_string()
+    }
+}
+
+fn schema_for(value: &Value) -> Value {
+    match *value {
+        Value::Null => json!({}),
+        Value::Bool(_) => json!({ "type": "boolean" }),
+        Value::Number(ref n) if n.is_f64() => json!({ "type": "number" }),
+        Value::Number(_) => json!({ "type": "integer" }),
+        Value::String(_) => json!({ "type": "string" }),
+        Value::Array(ref a) => {
+            match a.first() {
+                Some(item) => json!({ "type": "array", "items": schema_for(item) }),
+                None => json!({ "type": "array" }),
+            }
+        }
+        Value::Object(ref map) => {
+            let properties: Map<String, Value> = map.iter()
+                .map(|(k, v)| (k.clone(), schema_for(v)))
+                .collect();
+            json!({ "type": "object", "properties": properties })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn values_with_config(raw: &str) -> Values {
+        let mut values = Values::new();
+        values.add_entry("metadataName", "redis-abcde");
+        values.add_config(&raw.parse().unwrap());
+        values
+    }
+
+    #[test]
+    fn config_is_written_as_a_tree() {
+        let values = values_with_config(
+            r#"
+port = 6379
+bind = ["0.0.0.0"]
+name = "it's \"quoted\""
+
+[tls]
+enabled = false
+ratio = 0.5
+on = "yes"
+"#,
+        );
+        let mut out = Vec::new();
+        values.generate(&mut out).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            r#"metadataName: "redis-abcde"
+config:
+  bind: ["0.0.0.0"]
+  name: "it's \"quoted\""
+  port: 6379
+  tls:
+    enabled: false
+    "on": "yes"
+    ratio: 0.5
+"#
+        );
+    }
+
+    #[test]
+    fn schema_follows_config_types() {
+        let schema = values_with_config(
+            r#"
+port = 6379
+ratio = 0.5
+enabled = true
+bind = ["0.0.0.0"]
+
+[tls]
+"#,
+        ).schema();
+        let properties = &schema["properties"];
+        let config = &properties["config"]["properties"];
+
+        assert_eq!(properties["metadataName"]["type"], "string");
+        assert_eq!(properties["config"]["type"], "object");
+        assert_eq!(config["port"]["type"], "integer");
+        assert_eq!(config["ratio"]["type"], "number");
+        assert_eq!(config["enabled"]["type"], "boolean");
+        assert_eq!(config["bind"]["type"], "array");
+        assert_eq!(config["bind"]["items"]["type"], "string");
+        assert_eq!(config["tls"]["type"], "object");
+    }
+
+    #[test]
+    fn datetimes_are_removed_from_config() {
+        let raw = r#"
+port = 6379
+since = 1979-05-27T07:32:00Z
+holidays = [1979-12-25, 1980-12-25]
+
+[tls]
+enabled = false
+expires = 2038-01-19T03:14:07Z
+
+[[windows]]
+opens = 07:00:00
+"#;
+        let mut config: toml::Value = raw.parse().unwrap();
+
+        assert_eq!(
+            remove_datetimes(&mut config),
+            vec!["holidays", "since", "tls.expires", "windows"]
+        );
+        assert_eq!(
+            config,
+            "port = 6379\n\n[tls]\nenabled = false\n"
+                .parse::<toml::Value>()
+                .unwrap()
+        );
+        let mut values = Values::new();
+        values.add_config(&config);
+        let schema = values.schema();
+        assert!(schema["properties"]["config"]["properties"]["since"].is_null());
+    }
 }
@@ -39,6 +39,7 @@ impl<'a, 'b> Cli<'a, 'b> {
             .add_runtime_args()
             .add_secret_names_args()
             .add_bind_args()
+            .add_resource_args()
     }
 
     pub fn add_docker_args(self) -> Self {
@@ -154,6 +155,34 @@ impl<'a, 'b> Cli<'a, 'b> {
             ),
         }
     }
+
+    pub fn add_resource_args(self) -> Self {
+        Cli {
+            app: self.app
+                .arg(
+                    Arg::with_name("NO_OPERATOR")
+                        .long("no-operator")
+                        .conflicts_with("RING_SECRET_NAME")
+                        .help(
+                            "Generate plain Kubernetes resources which don't need the Habitat \
+                         operator: a StatefulSet (or a Deployment for a single standalone \
+                         instance), Services for gossip and the package's exposed ports, a \
+                         ConfigMap from its default.toml and claims for its data volumes. The \
+                         package must be installed, or given as a Habitat artifact",
+                        ),
+                )
+                .arg(
+                    Arg::with_name("STORAGE_SIZE")
+                        .value_name("STORAGE_SIZE")
+                        .long("storage-size")
+                        .requires("NO_OPERATOR")
+                        .help(
+                            "Size of the volume claimed for each instance's data \
+                         (default: 1Gi)",
+                        ),
+                ),
+        }
+    }
 }
 
 fn valid_natural_number(val: String) -> result::Result<(), String> {
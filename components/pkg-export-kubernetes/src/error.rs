@@ -16,6 +16,10 @@
 pub enum Error {
     #[fail(display = "Invalid bind specification '{}'", _0)]
     InvalidBindSpec(String),
+    #[fail(display = "Package {} must be installed, or given as a Habitat artifact, to generate \
+                     plain Kubernetes resources from its metadata",
+           _0)]
+    PackageNotInstalled(String),
     #[fail(display = "Invalid topology '{}'. Possible values: standalone, leader", _0)]
     InvalidTopology(String),
 }
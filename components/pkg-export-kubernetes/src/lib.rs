@@ -20,6 +20,7 @@ extern crate handlebars;
 extern crate rand;
 #[macro_use]
 extern crate serde_json;
+extern crate tempdir;
 
 extern crate failure;
 #[macro_use]
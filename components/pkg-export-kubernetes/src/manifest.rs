@@ -12,22 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::fs::File;
 use std::str::FromStr;
 use std::io::prelude::*;
 use std::path::Path;
 
 use clap::ArgMatches;
-use hcore::package::{PackageArchive, PackageIdent};
+use hcore::package::{PackageArchive, PackageIdent, PackageInstall};
 use common::ui::UI;
 use rand;
 use rand::Rng;
+use tempdir::TempDir;
 
 use export_docker::Result;
 
+use error::Error;
 use topology::Topology;
 use manifestjson::ManifestJson;
 use bind;
 
+/// The size of the volume claimed for a service's data when none is given.
+pub const DEFAULT_STORAGE_SIZE: &'static str = "1Gi";
+const DEFAULT_CFG_FILE: &'static str = "default.toml";
+
 #[derive(Debug, Clone)]
 pub struct Manifest {
     pub metadata_name: String,
@@ -39,6 +47,61 @@ pub struct Manifest {
     pub config_secret_name: Option<String>,
     pub ring_secret_name: Option<String>,
     pub binds: Vec<bind::Bind>,
+    /// Whether to generate a custom resource for the Habitat operator, rather than plain
+    /// Kubernetes resources.
+    pub operator: bool,
+    /// The ports the package exposes. Only read when generating plain resources, or by
+    /// `load_pkg_metadata`.
+    pub exposes: Vec<String>,
+    /// The configuration the package exports, by the name it's exported as. Only read when
+    /// generating plain resources, or by `load_pkg_metadata`.
+    pub exports: BTreeMap<String, String>,
+    /// The contents of the package's `default.toml`. Only read when generating plain resources,
+    /// or by `load_pkg_metadata`.
+    pub default_config: Option<String>,
+    /// The size of the volume claimed for the service's data, such as `10Gi`.
+    pub storage_size: String,
+}
+
+/// The parts of a package's metadata which plain Kubernetes resources are generated from.
+#[derive(Default)]
+struct PkgMetadata {
+    exposes: Vec<String>,
+    exports: BTreeMap<String, String>,
+    default_config: Option<String>,
+}
+
+impl PkgMetadata {
+    /// Reads the metadata from an installed package, or from an artifact by unpacking it
+    /// somewhere temporary.
+    fn load(pkg_ident_str: &str, ident: &PackageIdent) -> Result<Self> {
+        let tmp;
+        let fs_root = if Path::new(pkg_ident_str).is_file() {
+            tmp = TempDir::new("hab-pkg-export-kubernetes")?;
+            PackageArchive::new(pkg_ident_str).unpack(Some(tmp.path()))?;
+            Some(tmp.path())
+        } else {
+            None
+        };
+        let pkg_install = PackageInstall::load(ident, fs_root).map_err(|_| {
+            Error::PackageNotInstalled(ident.to_string())
+        })?;
+        let default_cfg_path = pkg_install.installed_path().join(DEFAULT_CFG_FILE);
+        let default_config = match File::open(default_cfg_path) {
+            Ok(mut file) => {
+                let mut raw = String::new();
+                file.read_to_string(&mut raw)?;
+                Some(raw)
+            }
+            Err(_) => None,
+        };
+
+        Ok(PkgMetadata {
+            exposes: pkg_install.exposes()?,
+            exports: pkg_install.exports()?.into_iter().collect(),
+            default_config: default_config,
+        })
+    }
 }
 
 impl Manifest {
@@ -52,17 +115,7 @@ impl Manifest {
             |s| s.to_string(),
         );
         let ring_secret_name = matches.value_of("RING_SECRET_NAME").map(|s| s.to_string());
-        // clap ensures that we do have the mandatory args so unwrap() is fine here
-        let pkg_ident_str = matches.value_of("PKG_IDENT_OR_ARTIFACT").expect(
-            "No package specified",
-        );
-        let pkg_ident = if Path::new(pkg_ident_str).is_file() {
-            // We're going to use the `$pkg_origin/$pkg_name`, fuzzy form of a package
-            // identifier to ensure that update strategies will work if desired
-            PackageArchive::new(pkg_ident_str).ident()?
-        } else {
-            PackageIdent::from_str(pkg_ident_str)?
-        };
+        let (pkg_ident_str, pkg_ident) = pkg_ident_from_matches(matches)?;
 
         // To allow multiple instances of Habitat application in Kubernetes,
         // random suffix in metadata_name is needed.
@@ -77,12 +130,23 @@ impl Manifest {
                 .collect::<String>(),
         );
 
+        let operator = !matches.is_present("NO_OPERATOR");
+        let metadata = if operator {
+            PkgMetadata::default()
+        } else {
+            PkgMetadata::load(&pkg_ident_str, &pkg_ident)?
+        };
+
         let image = match matches.value_of("IMAGE_NAME") {
             Some(i) => i.to_string(),
             None => pkg_ident.origin + "/" + &pkg_ident.name,
         };
 
         let binds = bind::parse_bind_args(&matches)?;
+        let storage_size = matches
+            .value_of("STORAGE_SIZE")
+            .unwrap_or(DEFAULT_STORAGE_SIZE)
+            .to_string();
 
         Ok(Manifest {
             metadata_name: metadata_name,
@@ -94,9 +158,31 @@ impl Manifest {
             config_secret_name: config_secret_name,
             ring_secret_name: ring_secret_name,
             binds: binds,
+            operator: operator,
+            exposes: metadata.exposes,
+            exports: metadata.exports,
+            default_config: metadata.default_config,
+            storage_size: storage_size,
         })
     }
 
+    /// Reads the ports, exports and default configuration of the package given on the command
+    /// line into the manifest, for exporters which need them even when generating a custom
+    /// resource for the Habitat operator.
+    ///
+    /// # Errors
+    ///
+    /// * If the package is neither an artifact nor installed
+    pub fn load_pkg_metadata(&mut self, matches: &ArgMatches) -> Result<()> {
+        let (pkg_ident_str, pkg_ident) = pkg_ident_from_matches(matches)?;
+        let metadata = PkgMetadata::load(&pkg_ident_str, &pkg_ident)?;
+        self.exposes = metadata.exposes;
+        self.exports = metadata.exports;
+        self.default_config = metadata.default_config;
+
+        Ok(())
+    }
+
     pub fn generate(&mut self, write: &mut Write) -> Result<()> {
         let out = ManifestJson::new(&self).into_string()?;
 
@@ -105,3 +191,20 @@ impl Manifest {
         Ok(())
     }
 }
+
+/// Returns the package given on the command line, along with its identifier.
+fn pkg_ident_from_matches(matches: &ArgMatches) -> Result<(String, PackageIdent)> {
+    // clap ensures that we do have the mandatory args so unwrap() is fine here
+    let pkg_ident_str = matches.value_of("PKG_IDENT_OR_ARTIFACT").expect(
+        "No package specified",
+    );
+    let pkg_ident = if Path::new(pkg_ident_str).is_file() {
+        // We're going to use the `$pkg_origin/$pkg_name`, fuzzy form of a package
+        // identifier to ensure that update strategies will work if desired
+        PackageArchive::new(pkg_ident_str).ident()?
+    } else {
+        PackageIdent::from_str(pkg_ident_str)?
+    };
+
+    Ok((pkg_ident_str.to_string(), pkg_ident))
+}
@@ -19,18 +19,28 @@ use serde_json::Value;
 use export_docker::Result;
 
 use manifest::Manifest;
+use topology::Topology;
 
 // Kubernetes manifest template
 const MANIFESTFILE: &'static str = include_str!("../defaults/KubernetesManifest.hbs");
 const BINDFILE: &'static str = include_str!("../defaults/KubernetesBind.hbs");
+const PLAINFILE: &'static str = include_str!("../defaults/KubernetesPlainManifest.hbs");
+/// How far the contents of a file are indented to nest them in a YAML block scalar.
+const FILE_INDENT: &'static str = "    ";
 
 pub struct ManifestJson {
     pub main: Value,
     pub binds: Vec<Value>,
+    /// Whether `main` describes a custom resource for the Habitat operator, rather than plain
+    /// Kubernetes resources which carry their binds themselves.
+    pub operator: bool,
 }
 
 impl ManifestJson {
     pub fn new(manifest: &Manifest) -> Self {
+        if !manifest.operator {
+            return Self::new_plain(manifest);
+        }
         let main = json!({
             "metadata_name": manifest.metadata_name,
             "habitat_name": manifest.habitat_name,
@@ -57,13 +67,70 @@ impl ManifestJson {
         ManifestJson {
             main: main,
             binds: binds,
+            operator: true,
+        }
+    }
+
+    fn new_plain(manifest: &Manifest) -> Self {
+        let binds: Vec<Value> = manifest
+            .binds
+            .iter()
+            .map(|bind| {
+                json!({
+                    "name": bind.name.clone(),
+                    "service": bind.service.clone(),
+                    "group": bind.group.clone(),
+                })
+            })
+            .collect();
+        let exports: Vec<Value> = manifest
+            .exports
+            .iter()
+            .map(|(name, key)| json!({ "name": name, "key": key }))
+            .collect();
+        let default_config = manifest.default_config.as_ref().map(|cfg| {
+            cfg.lines()
+                .map(|l| format!("{}{}", FILE_INDENT, l))
+                .collect::<Vec<_>>()
+                .join("\n")
+        });
+        let leader = manifest.service_topology == Topology::Leader;
+        let main = json!({
+            "metadata_name": manifest.metadata_name,
+            "habitat_name": manifest.habitat_name,
+            "image": manifest.image,
+            "count": manifest.count,
+            "leader": leader,
+            // A volume can't be shared between several instances, so unless there's only one
+            // they each claim their own from a StatefulSet
+            "stateful": leader || manifest.count > 1,
+            "service_group": manifest.service_group,
+            "config_secret_name": manifest.config_secret_name,
+            "config_volume": manifest.config_secret_name.is_some() ||
+                manifest.default_config.is_some(),
+            "binds": binds,
+            "exposes": manifest.exposes,
+            "exports": exports,
+            "default_config": default_config,
+            "storage_size": manifest.storage_size,
+        });
+
+        ManifestJson {
+            main: main,
+            binds: Vec::new(),
+            operator: false,
         }
     }
 
     // TODO: Implement TryInto trait instead when it's in stable std crate
     pub fn into_string(&self) -> Result<String> {
+        let template = if self.operator {
+            MANIFESTFILE
+        } else {
+            PLAINFILE
+        };
         let r = Handlebars::new()
-            .template_render(MANIFESTFILE, &self.main)
+            .template_render(template, &self.main)
             .map_err(SyncFailure::new)?;
         let mut s = r.lines().filter(|l| *l != "").collect::<Vec<_>>().join(
             "\n",
@@ -78,3 +145,84 @@ impl ManifestJson {
         Ok(s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use bind::Bind;
+
+    fn manifest(topology: Topology, count: u64) -> Manifest {
+        let mut exports = BTreeMap::new();
+        exports.insert(String::from("port"), String::from("port"));
+        Manifest {
+            metadata_name: String::from("redis-abcde"),
+            habitat_name: String::from("redis"),
+            image: String::from("core/redis"),
+            count: count,
+            service_topology: topology,
+            service_group: None,
+            config_secret_name: None,
+            ring_secret_name: None,
+            binds: vec![
+                Bind {
+                    name: String::from("backend"),
+                    service: String::from("postgres"),
+                    group: String::from("default"),
+                },
+            ],
+            operator: false,
+            exposes: vec![String::from("6379")],
+            exports: exports,
+            default_config: Some(String::from("port = 6379\n\n[tls]\nenabled = false\n")),
+            storage_size: String::from("10Gi"),
+        }
+    }
+
+    #[test]
+    fn plain_standalone_resources() {
+        let out = ManifestJson::new(&manifest(Topology::Standalone, 1))
+            .into_string()
+            .unwrap();
+        assert!(out.contains("kind: Deployment\n"));
+        assert!(out.contains("    type: Recreate\n"));
+        assert!(out.contains("kind: PersistentVolumeClaim\n"));
+        assert!(out.contains("      storage: 10Gi\n"));
+        assert!(out.contains("          claimName: redis-abcde-data\n"));
+        assert!(out.contains("  - name: port-6379\n    port: 6379\n"));
+        assert!(out.contains(
+            "  user.toml: |\n    port = 6379\n    \n    [tls]\n    enabled = false\n",
+        ));
+        assert!(out.contains("    habitat.sh/export.port: \"port\"\n"));
+        assert!(out.contains("        - --bind\n        - backend:postgres.default\n"));
+        assert!(out.contains("        - --peer\n        - redis-abcde-hab\n"));
+        assert!(!out.contains("StatefulSet"));
+    }
+
+    #[test]
+    fn plain_standalone_resources_with_several_instances() {
+        let out = ManifestJson::new(&manifest(Topology::Standalone, 3))
+            .into_string()
+            .unwrap();
+        assert!(out.contains("kind: StatefulSet\n"));
+        assert!(out.contains("  replicas: 3\n"));
+        assert!(out.contains("  volumeClaimTemplates:\n"));
+        assert!(out.contains("        - --peer\n        - redis-abcde-hab\n"));
+        assert!(!out.contains("kind: PersistentVolumeClaim"));
+        assert!(!out.contains("claimName"));
+        assert!(!out.contains("--topology"));
+    }
+
+    #[test]
+    fn plain_leader_resources() {
+        let out = ManifestJson::new(&manifest(Topology::Leader, 3))
+            .into_string()
+            .unwrap();
+        assert!(out.contains("kind: StatefulSet\n"));
+        assert!(out.contains("  serviceName: redis-abcde-hab\n"));
+        assert!(out.contains("        - redis-abcde-0.redis-abcde-hab\n        - --topology\n"));
+        assert!(out.contains("  volumeClaimTemplates:\n"));
+        assert!(!out.contains("kind: PersistentVolumeClaim"));
+    }
+}
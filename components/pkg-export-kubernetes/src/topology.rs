@@ -18,7 +18,7 @@ use std::fmt;
 
 use error::Error;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Topology {
     Standalone,
     Leader,
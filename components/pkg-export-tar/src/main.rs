@@ -0,0 +1,84 @@
+// Copyright (c) 2018 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+extern crate clap;
+extern crate env_logger;
+extern crate flate2;
+extern crate habitat_core as hcore;
+extern crate habitat_common as common;
+extern crate habitat_pkg_export_docker as export_docker;
+extern crate handlebars;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate tar;
+#[cfg(test)]
+extern crate tempdir;
+
+extern crate failure;
+
+mod systemd;
+mod tarball;
+
+use std::env;
+
+use common::ui::UI;
+use export_docker::{BuildSpec, Cli, PkgIdentArgOptions, Result};
+use hcore::{channel, PROGRAM_NAME};
+use hcore::url as hurl;
+
+/// The version of this program when built.
+const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
+
+fn main() {
+    env_logger::init().unwrap();
+    let mut ui = UI::default_with_env();
+    let m = cli().get_matches();
+    debug!("clap cli args: {:?}", m);
+
+    if let Err(e) = export_for_cli_matches(&mut ui, &m) {
+        let _ = ui.fatal(e);
+        std::process::exit(1)
+    }
+}
+
+fn export_for_cli_matches(ui: &mut UI, matches: &clap::ArgMatches) -> Result<()> {
+    let default_channel = channel::default();
+    let default_url = hurl::default_bldr_url();
+    let spec = BuildSpec::new_from_cli_matches(&matches, &default_channel, &default_url);
+    let results = env::current_dir()?.join("results");
+
+    tarball::export(ui, spec, &results)?;
+
+    Ok(())
+}
+
+fn cli<'a, 'b>() -> clap::App<'a, 'b> {
+    let name: &str = &*PROGRAM_NAME;
+    let about = "Creates a tarball of a Habitat package, which runs the package's service in a \
+                 Supervisor with systemd once extracted onto the root of a machine";
+
+    let cli = Cli {
+        app: clap::App::new(name)
+            .about(about)
+            .version(VERSION)
+            .author("\nAuthors: The Habitat Maintainers <humans@habitat.sh>\n\n"),
+    };
+    cli.add_base_packages_args()
+        .add_builder_args()
+        .add_pkg_ident_arg(PkgIdentArgOptions { multiple: false })
+        .app
+}
@@ -0,0 +1,155 @@
+// Copyright (c) 2018 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! The systemd unit which boots a Supervisor running the exported service on the machine the
+//! tarball is extracted onto.
+
+use std::path::{Path, PathBuf};
+
+use export_docker::{BuildRootContext, Result};
+use failure::SyncFailure;
+use handlebars::Handlebars;
+use hcore::package::PackageIdent;
+
+// systemd unit template
+const UNIT: &'static str = include_str!("../defaults/systemd.service.hbs");
+
+/// The directory, relative to the root file system, which system-wide units are installed in.
+const UNIT_DIR: &'static str = "etc/systemd/system";
+
+pub struct SystemdUnit {
+    /// The unit's file name, such as `hab-redis.service`.
+    name: String,
+    content: String,
+}
+
+impl SystemdUnit {
+    /// Creates a unit running the build root's primary service with the given `hab` program.
+    /// The service's user and group are created when the unit starts, if they don't exist yet.
+    ///
+    /// # Errors
+    ///
+    /// * If the primary service package cannot be read
+    /// * If the unit cannot be rendered
+    pub fn new_for_build_root(ctx: &BuildRootContext, hab_bin: &Path) -> Result<Self> {
+        let (users, groups) = ctx.svc_users_and_groups()?;
+        let users = users
+            .iter()
+            .map(|user| {
+                // Every user's primary group is one of the service's groups, which are only
+                // known by name on the target machine
+                let group = groups
+                    .iter()
+                    .find(|group| group.gid == user.gid)
+                    .map(|group| group.name.clone())
+                    .unwrap_or_else(|| user.name.clone());
+                (user.name.clone(), group)
+            })
+            .collect();
+        let groups = groups.iter().map(|group| group.name.clone()).collect();
+
+        Self::new(ctx.primary_svc_ident(), hab_bin, users, groups)
+    }
+
+    /// Creates a unit running the given service, creating the given users, each with their
+    /// primary group, and groups when it starts.
+    ///
+    /// # Errors
+    ///
+    /// * If the unit cannot be rendered
+    pub fn new(
+        ident: &PackageIdent,
+        hab_bin: &Path,
+        users: Vec<(String, String)>,
+        groups: Vec<String>,
+    ) -> Result<Self> {
+        let users: Vec<_> = users
+            .iter()
+            .map(|&(ref name, ref group)| json!({ "name": name, "group": group }))
+            .collect();
+        let json = json!({
+            "primary_svc_ident": ident.to_string(),
+            "hab_bin": hab_bin.display().to_string(),
+            "users": users,
+            "groups": groups,
+        });
+        let content = Handlebars::new()
+            .template_render(UNIT, &json)
+            .map_err(SyncFailure::new)?;
+
+        Ok(SystemdUnit {
+            name: format!("hab-{}.service", ident.name),
+            content: content,
+        })
+    }
+
+    /// Returns the path the unit is installed at, relative to the root file system.
+    pub fn path(&self) -> PathBuf {
+        Path::new(UNIT_DIR).join(&self.name)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn unit_creates_missing_accounts_and_starts_the_service() {
+        let unit = SystemdUnit::new(
+            &PackageIdent::from_str("core/redis").unwrap(),
+            Path::new("/hab/bin/hab"),
+            vec![
+                ("redis".to_string(), "redis".to_string()),
+                ("hab".to_string(), "hab".to_string()),
+            ],
+            vec!["redis".to_string(), "hab".to_string()],
+        ).unwrap();
+
+        assert_eq!(unit.path(), Path::new("etc/systemd/system/hab-redis.service"));
+        assert_eq!(
+            unit.content(),
+            "[Unit]
+Description=Habitat Supervisor running core/redis
+Wants=network-online.target
+After=network-online.target
+
+[Service]
+ExecStartPre=/bin/sh -c 'getent group redis >/dev/null || groupadd --system redis'
+ExecStartPre=/bin/sh -c 'getent group hab >/dev/null || groupadd --system hab'
+ExecStartPre=/bin/sh -c 'getent passwd redis >/dev/null || \
+             useradd --system --no-create-home --gid redis redis'
+ExecStartPre=/bin/sh -c 'getent passwd hab >/dev/null || \
+             useradd --system --no-create-home --gid hab hab'
+ExecStart=/hab/bin/hab sup start core/redis
+# The Launcher stops the Supervisor and its services before exiting
+KillMode=mixed
+Restart=on-failure
+
+[Install]
+WantedBy=multi-user.target
+"
+        );
+    }
+}
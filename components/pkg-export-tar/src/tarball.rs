@@ -0,0 +1,235 @@
+// Copyright (c) 2018 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Tarballs holding the `/hab` tree of a build root, which boot a Supervisor running the service
+//! once extracted onto the root of a machine with systemd, without needing Docker.
+
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use common::ui::{UI, Status};
+use export_docker::{BuildRoot, BuildSpec, Result};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use tar;
+
+use systemd::SystemdUnit;
+
+/// The path, relative to the root file system, of the `hab` program the unit runs.
+const HAB_BIN_PATH: &'static str = "hab/bin/hab";
+
+/// Exports a tarball from a build specification into the given directory, returning its path.
+///
+/// # Errors
+///
+/// * If a generic and temporary build root directory cannot be created containing a root
+/// file system
+/// * If the systemd unit cannot be created
+/// * If the tarball cannot be written
+/// * If destroying the temporary build root directory fails
+pub fn export(ui: &mut UI, build_spec: BuildSpec, dst: &Path) -> Result<PathBuf> {
+    ui.begin(format!(
+        "Building a tarball with: {}",
+        build_spec.idents_or_archives.join(", ")
+    ))?;
+    let build_root = build_spec.create(ui)?;
+    let tarball = write(ui, &build_root, dst)?;
+    build_root.destroy(ui)?;
+    ui.end(format!(
+        "Tarball {} created, to be extracted with `tar -xzf {} -C /`",
+        tarball.display(),
+        tarball.display()
+    ))?;
+
+    Ok(tarball)
+}
+
+/// Writes the installed packages, a `hab` program linked from the `/hab/bin` directory and a
+/// systemd unit for the primary service into a tarball named after the primary service.
+fn write(ui: &mut UI, build_root: &BuildRoot, dst: &Path) -> Result<PathBuf> {
+    let ctx = build_root.ctx();
+    let ident = ctx.installed_primary_svc_ident()?;
+    // The build root links the `hab` program from the Habitat CLI package it installed
+    let hab_link = ctx.rootfs().join(ctx.bin_path().strip_prefix("/")?).join("hab");
+    let hab_target = fs::read_link(hab_link)?;
+    let hab_bin = Path::new("/").join(HAB_BIN_PATH);
+    let unit = SystemdUnit::new_for_build_root(ctx, &hab_bin)?;
+
+    fs::create_dir_all(dst)?;
+    let tarball = dst.join(format!(
+        "{}-{}-{}-{}.tar.gz",
+        ident.origin,
+        ident.name,
+        ident.version.as_ref().expect("Installed packages are fully qualified"),
+        ident.release.as_ref().expect("Installed packages are fully qualified")
+    ));
+    ui.status(
+        Status::Creating,
+        format!("tarball {}", tarball.display()),
+    )?;
+    ui.status(
+        Status::Creating,
+        format!("systemd unit {}", unit.name()),
+    )?;
+    write_tarball(&tarball, ctx.rootfs(), &hab_target, &unit)?;
+
+    Ok(tarball)
+}
+
+/// Writes the `/hab/pkgs` tree of a root file system, a `/hab/bin/hab` link to the given target
+/// and a systemd unit into a gzipped tarball.
+fn write_tarball(
+    tarball: &Path,
+    rootfs: &Path,
+    hab_target: &Path,
+    unit: &SystemdUnit,
+) -> Result<()> {
+    let mut builder = tar::Builder::new(GzEncoder::new(
+        File::create(tarball)?,
+        Compression::default(),
+    ));
+    // Packages link to each other, and those links must survive extraction as they are
+    builder.follow_symlinks(false);
+    builder.append_dir_all("hab/pkgs", rootfs.join("hab/pkgs"))?;
+
+    // The link and the unit are made up rather than copied from the root file system, so they
+    // are dated like the packages they come with
+    let mtime = fs::metadata(rootfs.join("hab/pkgs"))?
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let mut header = new_header(0o777, mtime);
+    header.set_entry_type(tar::EntryType::Symlink);
+    header.set_link_name(hab_target)?;
+    builder.append_data(&mut header, HAB_BIN_PATH, io::empty())?;
+
+    let mut header = new_header(0o644, mtime);
+    header.set_size(unit.content().len() as u64);
+    builder.append_data(&mut header, unit.path(), unit.content().as_bytes())?;
+
+    builder.into_inner()?.finish()?;
+
+    Ok(())
+}
+
+fn new_header(mode: u32, mtime: u64) -> tar::Header {
+    let mut header = tar::Header::new_gnu();
+    header.set_mode(mode);
+    header.set_uid(0);
+    header.set_gid(0);
+    header.set_size(0);
+    header.set_mtime(mtime);
+    header
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{Read, Write};
+    use std::os::unix::fs::symlink;
+    use std::str::FromStr;
+
+    use flate2::read::GzDecoder;
+    use hcore::package::PackageIdent;
+    use tempdir::TempDir;
+
+    use super::*;
+
+    const HAB_TARGET: &'static str = "/hab/pkgs/core/hab/0.52.0/20180110000000/bin/hab";
+
+    /// Returns the path, type and link target of every entry in a tarball, along with the
+    /// contents of the regular files, sorted by path.
+    fn entries(tarball: &Path) -> Vec<(String, tar::EntryType, Option<String>, String)> {
+        let mut archive = tar::Archive::new(GzDecoder::new(File::open(tarball).unwrap()));
+        let entries = archive.entries().unwrap();
+        let mut entries: Vec<_> = entries
+            .map(|e| {
+                let mut e = e.unwrap();
+                let path = e.path().unwrap().to_string_lossy().into_owned();
+                let entry_type = e.header().entry_type();
+                let link = e.link_name()
+                    .unwrap()
+                    .map(|l| l.to_string_lossy().into_owned());
+                let mut content = String::new();
+                e.read_to_string(&mut content).unwrap();
+                (path.trim_right_matches('/').to_string(), entry_type, link, content)
+            })
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries
+    }
+
+    #[test]
+    fn tarball_holds_packages_the_hab_program_and_the_unit() {
+        let rootfs = TempDir::new("rootfs").unwrap();
+        let redis = rootfs.path().join("hab/pkgs/core/redis/3.2.4/20170514150022");
+        fs::create_dir_all(redis.join("bin")).unwrap();
+        File::create(redis.join("bin/redis-server"))
+            .unwrap()
+            .write_all(b"redis")
+            .unwrap();
+        symlink("redis-server", redis.join("bin/redis-cli")).unwrap();
+        let unit = SystemdUnit::new(
+            &PackageIdent::from_str("core/redis").unwrap(),
+            Path::new("/hab/bin/hab"),
+            Vec::new(),
+            Vec::new(),
+        ).unwrap();
+        let dst = TempDir::new("dst").unwrap();
+        let tarball = dst.path().join("core-redis.tar.gz");
+
+        write_tarball(&tarball, rootfs.path(), Path::new(HAB_TARGET), &unit).unwrap();
+
+        let redis = "hab/pkgs/core/redis/3.2.4/20170514150022";
+        let dir = |path: &str| (path.to_string(), tar::EntryType::Directory, None, String::new());
+        assert_eq!(
+            entries(&tarball),
+            vec![
+                (
+                    String::from("etc/systemd/system/hab-redis.service"),
+                    tar::EntryType::Regular,
+                    None,
+                    unit.content().to_string(),
+                ),
+                (
+                    String::from(HAB_BIN_PATH),
+                    tar::EntryType::Symlink,
+                    Some(String::from(HAB_TARGET)),
+                    String::new(),
+                ),
+                dir("hab/pkgs"),
+                dir("hab/pkgs/core"),
+                dir("hab/pkgs/core/redis"),
+                dir("hab/pkgs/core/redis/3.2.4"),
+                dir(redis),
+                dir(&format!("{}/bin", redis)),
+                (
+                    format!("{}/bin/redis-cli", redis),
+                    tar::EntryType::Symlink,
+                    Some(String::from("redis-server")),
+                    String::new(),
+                ),
+                (
+                    format!("{}/bin/redis-server", redis),
+                    tar::EntryType::Regular,
+                    None,
+                    String::from("redis"),
+                ),
+            ]
+        );
+    }
+}
@@ -383,6 +383,11 @@ INITIAL_PWD="$(pwd)"
 pkg_arch=$(uname -m | tr '[:upper:]' '[:lower:]')
 # The target system (i.e. operating system variant) this plan will be built for
 pkg_sys=$(uname -s | tr '[:upper:]' '[:lower:]')
+# Packages for hosts still on a 2.x kernel are built for their own system variant, but only
+# when asked for with `HAB_LINUX_KERNEL2=true`
+if [[ "$pkg_sys" == "linux" && "${HAB_LINUX_KERNEL2:-}" == "true" ]]; then
+  pkg_sys="linux-kernel2"
+fi
 # The full target tuple this plan will be built for
 pkg_target="${pkg_arch}-${pkg_sys}"
 # The package's origin (i.e. acme)
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt;
 use std::str::FromStr;
 
@@ -24,13 +24,17 @@ use butterfly::rumor::service_config::ServiceConfig as ServiceConfigRumor;
 use butterfly::rumor::election::Election as ElectionRumor;
 use butterfly::rumor::election::Election_Status as ElectionStatusRumor;
 use butterfly::rumor::election::ElectionUpdate as ElectionUpdateRumor;
+use butterfly::rumor::federated_member::FederatedMember as FederatedMemberRumor;
+use butterfly::rumor::key_value::KeyValue as KeyValueRumor;
 use butterfly::rumor::service::SysInfo;
+use butterfly::rumor::shard_map::ShardMap as ShardMapRumor;
 use hcore;
 use hcore::service::ServiceGroup;
 use hcore::package::PackageIdent;
 use toml;
 
 use error::{Error, SupError};
+use manager::service::HealthCheck;
 
 static LOGKEY: &'static str = "CE";
 
@@ -48,6 +52,9 @@ pub struct CensusRing {
     last_membership_counter: usize,
     last_service_config_counter: usize,
     last_service_file_counter: usize,
+    last_shard_map_counter: usize,
+    last_key_value_counter: usize,
+    last_federated_member_counter: usize,
 }
 
 impl CensusRing {
@@ -71,6 +78,9 @@ impl CensusRing {
             last_membership_counter: 0,
             last_service_config_counter: 0,
             last_service_file_counter: 0,
+            last_shard_map_counter: 0,
+            last_key_value_counter: 0,
+            last_federated_member_counter: 0,
         }
     }
 
@@ -82,6 +92,9 @@ impl CensusRing {
         member_list: &MemberList,
         service_config_rumors: &RumorStore<ServiceConfigRumor>,
         service_file_rumors: &RumorStore<ServiceFileRumor>,
+        shard_map_rumors: &RumorStore<ShardMapRumor>,
+        key_value_rumors: &RumorStore<KeyValueRumor>,
+        federated_member_rumors: &RumorStore<FederatedMemberRumor>,
     ) {
         // If ANY new rumor, of any type, has been received,
         // reconstruct the entire census state to ensure consistency
@@ -90,16 +103,21 @@ impl CensusRing {
             (election_rumors.get_update_counter() > self.last_election_counter) ||
             (election_update_rumors.get_update_counter() > self.last_election_update_counter) ||
             (service_config_rumors.get_update_counter() > self.last_service_config_counter) ||
-            (service_file_rumors.get_update_counter() > self.last_service_file_counter)
+            (service_file_rumors.get_update_counter() > self.last_service_file_counter) ||
+            (shard_map_rumors.get_update_counter() > self.last_shard_map_counter) ||
+            (key_value_rumors.get_update_counter() > self.last_key_value_counter) ||
+            (federated_member_rumors.get_update_counter() > self.last_federated_member_counter)
         {
 
             self.changed = true;
 
-            self.populate_census(service_rumors, member_list);
+            self.populate_census(service_rumors, member_list, federated_member_rumors);
             self.update_from_election_store(election_rumors);
             self.update_from_election_update_store(election_update_rumors);
             self.update_from_service_config(service_config_rumors);
             self.update_from_service_files(service_file_rumors);
+            self.update_from_shard_maps(shard_map_rumors);
+            self.update_from_key_values(key_value_rumors);
 
             // Update our counters to reflect current state.
             self.last_membership_counter = member_list.get_update_counter();
@@ -108,6 +126,9 @@ impl CensusRing {
             self.last_election_update_counter = election_update_rumors.get_update_counter();
             self.last_service_config_counter = service_config_rumors.get_update_counter();
             self.last_service_file_counter = service_file_rumors.get_update_counter();
+            self.last_shard_map_counter = shard_map_rumors.get_update_counter();
+            self.last_key_value_counter = key_value_rumors.get_update_counter();
+            self.last_federated_member_counter = federated_member_rumors.get_update_counter();
 
         } else {
             self.changed = false;
@@ -126,11 +147,14 @@ impl CensusRing {
     /// membership lists.
     ///
     /// (Butterfly provides the health, the ServiceRumors provide the
-    /// rest).
+    /// rest). Members of peer rings of a federation are not in the
+    /// membership list; their health is the one their own ring
+    /// relayed.
     fn populate_census(
         &mut self,
         service_rumors: &RumorStore<ServiceRumor>,
         member_list: &MemberList,
+        federated_member_rumors: &RumorStore<FederatedMemberRumor>,
     ) {
         // Populate our census; new groups are created here, as are
         // new members of those groups.
@@ -142,6 +166,9 @@ impl CensusRing {
         // `census_group.update_from_service_rumors`, where new census
         // members are created, so there would be no time that there
         // is an indeterminate health anywhere.
+        //
+        // Groups whose rumors have all expired are dropped.
+        let mut live_groups = HashSet::new();
         service_rumors.with_keys(|(service_group, rumors)| if let Ok(sg) =
             service_group_from_str(service_group)
         {
@@ -152,7 +179,11 @@ impl CensusRing {
                 ),
             );
             census_group.update_from_service_rumors(rumors);
+            live_groups.insert(census_group.service_group.clone());
         });
+        self.census_groups.retain(
+            |sg, _| live_groups.contains(sg),
+        );
 
         member_list.with_members(|member| {
             let health = member_list.health_of(&member).unwrap();
@@ -163,6 +194,21 @@ impl CensusRing {
                 }
             }
         });
+
+        federated_member_rumors.with_keys(|(_, rumors)| for federated_member in
+            rumors.values()
+        {
+            if member_list.contains_member(federated_member.member_id()) {
+                continue;
+            }
+            let member = federated_member.member();
+            for group in self.census_groups.values_mut() {
+                if let Some(census_member) = group.find_member_mut(member.get_id()) {
+                    census_member.update_from_member(&member);
+                    census_member.update_from_health(federated_member.health());
+                }
+            }
+        });
     }
 
     fn update_from_election_store(&mut self, election_rumors: &RumorStore<ElectionRumor>) {
@@ -218,6 +264,28 @@ impl CensusRing {
             census_group.update_from_service_file_rumors(rumors);
         });
     }
+
+    fn update_from_shard_maps(&mut self, shard_map_rumors: &RumorStore<ShardMapRumor>) {
+        shard_map_rumors.with_keys(|(service_group, rumors)| if let Ok(sg) =
+            service_group_from_str(service_group)
+        {
+            if let Some(shard_map) = rumors.get("shard_map") {
+                if let Some(census_group) = self.census_groups.get_mut(&sg) {
+                    census_group.update_from_shard_map_rumor(shard_map);
+                }
+            }
+        });
+    }
+
+    fn update_from_key_values(&mut self, key_value_rumors: &RumorStore<KeyValueRumor>) {
+        key_value_rumors.with_keys(|(service_group, rumors)| if let Ok(sg) =
+            service_group_from_str(service_group)
+        {
+            if let Some(census_group) = self.census_groups.get_mut(&sg) {
+                census_group.update_from_key_value_rumors(rumors);
+            }
+        });
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
@@ -283,6 +351,14 @@ pub struct ServiceConfig {
     pub value: toml::Value,
 }
 
+/// A shard of a sharded or multi-leader service group, and the members assigned to it.
+#[derive(Debug, Serialize)]
+pub struct Shard {
+    pub shard: u32,
+    pub leader_id: Option<MemberId>,
+    pub member_ids: Vec<MemberId>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct CensusGroup {
     pub service_group: ServiceGroup,
@@ -290,6 +366,8 @@ pub struct CensusGroup {
     pub update_election_status: ElectionStatus,
     pub leader_id: Option<MemberId>,
     pub service_config: Option<ServiceConfig>,
+    pub shards: Vec<Shard>,
+    pub kv: BTreeMap<String, String>,
 
     local_member_id: MemberId,
     population: BTreeMap<MemberId, CensusMember>,
@@ -309,6 +387,8 @@ impl CensusGroup {
             leader_id: None,
             update_leader_id: None,
             service_config: None,
+            shards: Vec::new(),
+            kv: BTreeMap::new(),
             service_files: HashMap::new(),
             changed_service_files: Vec::new(),
         }
@@ -333,6 +413,15 @@ impl CensusGroup {
         }
     }
 
+    /// Returns the members assigned to the given shard, its leader first.
+    pub fn shard_members(&self, shard: &Shard) -> Vec<&CensusMember> {
+        shard
+            .member_ids
+            .iter()
+            .filter_map(|id| self.population.get(id))
+            .collect()
+    }
+
     /// Returns a list of all members in the census ring.
     pub fn members(&self) -> Vec<&CensusMember> {
         self.population.values().map(|cm| cm).collect()
@@ -345,11 +434,21 @@ impl CensusGroup {
             .collect()
     }
 
-    /// Return previous alive peer, the peer to your left in the ordered members list, or None if
-    /// you have no alive peers.
-    pub fn previous_peer(&self) -> Option<&CensusMember> {
-        let alive_members: Vec<&CensusMember> =
+    /// Returns the alive members ordered by member id, or, with a `zone_tag`, by the value of
+    /// that tag first, so that members of one zone follow each other.
+    pub fn alive_members_by_zone(&self, zone_tag: Option<&str>) -> Vec<&CensusMember> {
+        let mut alive_members: Vec<&CensusMember> =
             self.population.values().filter(|cm| cm.alive).collect();
+        if let Some(zone_tag) = zone_tag {
+            alive_members.sort_by(|a, b| a.tag(zone_tag).cmp(&b.tag(zone_tag)));
+        }
+        alive_members
+    }
+
+    /// Return previous alive peer, the peer to your left in the ordered members list, or None if
+    /// you have no alive peers. With a `zone_tag`, members are ordered zone by zone.
+    pub fn previous_peer(&self, zone_tag: Option<&str>) -> Option<&CensusMember> {
+        let alive_members = self.alive_members_by_zone(zone_tag);
         if alive_members.len() <= 1 || self.me().is_none() {
             return None;
         }
@@ -368,7 +467,19 @@ impl CensusGroup {
     }
 
     fn update_from_service_rumors(&mut self, rumors: &HashMap<String, ServiceRumor>) {
-        for (member_id, service_rumor) in rumors.iter() {
+        // Members whose rumor expired, or who unloaded the service, are no longer part of
+        // the group.
+        let gone: Vec<MemberId> = self.population
+            .keys()
+            .filter(|member_id| {
+                rumors.get(*member_id).map_or(true, |rumor| rumor.get_unloaded())
+            })
+            .cloned()
+            .collect();
+        for member_id in gone {
+            self.population.remove(&member_id);
+        }
+        for (member_id, service_rumor) in rumors.iter().filter(|&(_, r)| !r.get_unloaded()) {
             // Yeah - we are ourself - we're alive.
             let is_self = member_id == &self.local_member_id;
             let member = self.population
@@ -472,6 +583,33 @@ impl CensusGroup {
         }
     }
 
+    fn update_from_shard_map_rumor(&mut self, shard_map: &ShardMapRumor) {
+        self.shards = (0..shard_map.get_shard_count())
+            .map(|shard| {
+                Shard {
+                    shard: shard,
+                    leader_id: shard_map.leader_of(shard).map(|id| id.to_string()),
+                    member_ids: Vec::new(),
+                }
+            })
+            .collect();
+        for slot in shard_map.get_slots() {
+            if let Some(shard) = self.shards.get_mut(slot.get_shard() as usize) {
+                shard.member_ids.push(slot.get_member_id().to_string());
+            }
+        }
+        for census_member in self.population.values_mut() {
+            census_member.update_from_shard_map_rumor(shard_map);
+        }
+    }
+
+    fn update_from_key_value_rumors(&mut self, rumors: &HashMap<String, KeyValueRumor>) {
+        self.kv = rumors
+            .values()
+            .map(|kv| (kv.get_key().to_string(), kv.get_value().to_string()))
+            .collect();
+    }
+
     fn find_member_mut(&mut self, member_id: &str) -> Option<&mut CensusMember> {
         self.population.get_mut(member_id)
     }
@@ -497,11 +635,18 @@ pub struct CensusMember {
     pub update_election_is_running: bool,
     pub update_election_is_no_quorum: bool,
     pub update_election_is_finished: bool,
+    pub shard: Option<u32>,
+    pub shard_leader: bool,
     pub sys: SysInfo,
+    pub health: HealthCheck,
+    // The release this member rolled back from after it failed its health checks
+    pub rolled_back_from: Option<PackageIdent>,
     alive: bool,
     suspect: bool,
     confirmed: bool,
     departed: bool,
+    // The tags the member's Supervisor was started with
+    pub tags: BTreeMap<String, String>,
     // Maps must be represented last in a serializable struct for the current version of the toml
     // crate. Additionally, this deserialization method is required to correct any ordering issues
     // with the table being serialized - https://docs.rs/toml/0.4.0/toml/ser/fn.tables_last.html
@@ -526,6 +671,12 @@ impl CensusMember {
             Err(err) => warn!("Received a bad package ident from gossip data, err={}", err),
         };
         self.sys = rumor.get_sys().clone().into();
+        self.health = rumor.get_health().into();
+        self.rolled_back_from = if rumor.has_rolled_back_from() {
+            PackageIdent::from_str(rumor.get_rolled_back_from()).ok()
+        } else {
+            None
+        };
         self.cfg = toml::from_slice(rumor.get_cfg()).unwrap_or(toml::value::Table::default());
     }
 
@@ -561,10 +712,29 @@ impl CensusMember {
         self.update_leader
     }
 
+    /// Multi-leader service groups also surface their shard leaders as leaders, and the rest of
+    /// the members as followers.
+    fn update_from_shard_map_rumor(&mut self, shard_map: &ShardMapRumor) {
+        self.shard = shard_map.shard_of(&self.member_id);
+        self.shard_leader = match self.shard {
+            Some(shard) => shard_map.leader_of(shard) == Some(self.member_id.as_str()),
+            None => false,
+        };
+        if shard_map.get_multi_leader() && self.shard.is_some() {
+            self.leader = self.shard_leader;
+            self.follower = !self.shard_leader;
+        }
+    }
+
     fn update_from_member(&mut self, member: &Member) {
         self.sys.set_gossip_ip(member.get_address().to_string());
         self.sys.set_gossip_port(member.get_gossip_port() as u32);
         self.persistent = true;
+        self.tags = member
+            .get_tags()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
     }
 
     fn update_from_health(&mut self, health: Health) {
@@ -580,6 +750,11 @@ impl CensusMember {
         }
     }
 
+    /// Returns the value of the given tag, if the member carries it.
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags.get(name).map(|v| v.as_str())
+    }
+
     /// Is this member currently considered to be alive or not?
     pub fn alive(&self) -> bool {
         self.alive
@@ -599,15 +774,22 @@ fn service_group_from_str(sg: &str) -> Result<ServiceGroup, hcore::Error> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+    use std::time::SystemTime;
+
     use hcore::package::ident::PackageIdent;
     use hcore::service::ServiceGroup;
-    use butterfly::member::MemberList;
+    use butterfly::member::{Health, Member, MemberList};
+    use butterfly::message::swim::Membership;
+    use butterfly::rumor::federated_member::FederatedMember as FederatedMemberRumor;
     use butterfly::rumor::service::Service as ServiceRumor;
     use butterfly::rumor::service_config::ServiceConfig as ServiceConfigRumor;
     use butterfly::rumor::service_file::ServiceFile as ServiceFileRumor;
     use butterfly::rumor::election::Election as ElectionRumor;
     use butterfly::rumor::election::ElectionUpdate as ElectionUpdateRumor;
     use butterfly::rumor::service::SysInfo;
+    use butterfly::rumor::key_value::KeyValue as KeyValueRumor;
+    use butterfly::rumor::shard_map::ShardMap as ShardMapRumor;
     use butterfly::rumor::RumorStore;
     use census::CensusRing;
 
@@ -655,6 +837,7 @@ mod tests {
 
         let service_config_store: RumorStore<ServiceConfigRumor> = RumorStore::default();
         let service_file_store: RumorStore<ServiceFileRumor> = RumorStore::default();
+        let shard_map_store: RumorStore<ShardMapRumor> = RumorStore::default();
         let mut ring = CensusRing::new("member-b".to_string());
         ring.update_from_rumors(
             &service_store,
@@ -663,6 +846,9 @@ mod tests {
             &member_list,
             &service_config_store,
             &service_file_store,
+            &shard_map_store,
+            &RumorStore::default(),
+            &RumorStore::default(),
         );
         let census_group_one = ring.census_group_for(&sg_one).unwrap();
         assert!(census_group_one.me().is_none());
@@ -683,4 +869,209 @@ mod tests {
         assert_eq!(members[0].member_id, "member-a");
         assert_eq!(members[1].member_id, "member-b");
     }
+
+    #[test]
+    fn unloaded_and_expired_services_leave_the_census() {
+        let pg_id = PackageIdent::new("core", "redis", Some("3.2.4"), Some("20170514150022"));
+        let sg = ServiceGroup::new(None, "redis", "default", None).unwrap();
+        let service_store: RumorStore<ServiceRumor> = RumorStore::default();
+        for member_id in &["member-a", "member-b", "member-c"] {
+            let service = ServiceRumor::new(*member_id, &pg_id, &sg, &SysInfo::new(), None);
+            service_store.insert(service);
+        }
+        let mut unloaded = ServiceRumor::new("member-c", &pg_id, &sg, &SysInfo::new(), None);
+        unloaded.set_incarnation(2);
+        unloaded.set_unloaded(true);
+        service_store.insert(unloaded);
+
+        let member_list = MemberList::new();
+        let mut ring = CensusRing::new("member-a".to_string());
+        ring.update_from_rumors(
+            &service_store,
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &member_list,
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &RumorStore::default(),
+        );
+        {
+            let members = ring.census_group_for(&sg).unwrap().members();
+            assert_eq!(members.len(), 2);
+            assert_eq!(members[0].member_id, "member-a");
+            assert_eq!(members[1].member_id, "member-b");
+        }
+
+        service_store.expire_all(&*sg, SystemTime::now());
+        ring.update_from_rumors(
+            &service_store,
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &member_list,
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &RumorStore::default(),
+        );
+        assert!(ring.census_group_for(&sg).is_none());
+    }
+
+    #[test]
+    fn update_from_shard_map_rumors() {
+        let pg_id = PackageIdent::new("core", "redis", Some("3.2.4"), Some("20170514150022"));
+        let sg = ServiceGroup::new(None, "redis", "default", None).unwrap();
+        let service_store: RumorStore<ServiceRumor> = RumorStore::default();
+        for member_id in &["member-a", "member-b", "member-c"] {
+            let service = ServiceRumor::new(*member_id, &pg_id, &sg, &SysInfo::new(), None);
+            service_store.insert(service);
+        }
+
+        let mut shard_map = ShardMapRumor::new("member-a", sg.clone(), 2);
+        shard_map.assign(
+            "member-a",
+            2,
+            true,
+            &["member-a", "member-b", "member-c"],
+            &HashMap::new(),
+        );
+        let shard_map_store: RumorStore<ShardMapRumor> = RumorStore::default();
+        shard_map_store.insert(shard_map.clone());
+
+        let mut ring = CensusRing::new("member-c");
+        ring.update_from_rumors(
+            &service_store,
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &MemberList::new(),
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &shard_map_store,
+            &RumorStore::default(),
+            &RumorStore::default(),
+        );
+        let census_group = ring.census_group_for(&sg).unwrap();
+        assert_eq!(census_group.shards.len(), 2);
+        let me = census_group.me().unwrap();
+        let shard = &census_group.shards[me.shard.unwrap() as usize];
+        assert!(shard.member_ids.contains(&me.member_id));
+        for member in census_group.members() {
+            let leader_id = shard_map.leader_of(member.shard.unwrap());
+            let leader = leader_id == Some(member.member_id.as_str());
+            assert_eq!(member.shard_leader, leader);
+            assert_eq!(member.leader, leader);
+            assert_eq!(member.follower, !leader);
+        }
+        let leaders = census_group.shards.iter().filter_map(|s| s.leader_id.as_ref());
+        assert_eq!(leaders.count(), 2);
+    }
+
+    #[test]
+    fn update_from_key_value_rumors() {
+        let pg_id = PackageIdent::new("core", "redis", Some("3.2.4"), Some("20170514150022"));
+        let sg = ServiceGroup::new(None, "redis", "default", None).unwrap();
+        let service_store: RumorStore<ServiceRumor> = RumorStore::default();
+        service_store.insert(ServiceRumor::new("member-a", &pg_id, &sg, &SysInfo::new(), None));
+
+        let key_value_store: RumorStore<KeyValueRumor> = RumorStore::default();
+        for &(key, value, incarnation) in
+            &[("maintenance", "true", 1), ("maintenance", "false", 2), ("flavor", "vanilla", 1)]
+        {
+            let kv = KeyValueRumor::new("member-a", sg.clone(), key, value, incarnation);
+            key_value_store.insert(kv);
+        }
+
+        let mut ring = CensusRing::new("member-a");
+        ring.update_from_rumors(
+            &service_store,
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &MemberList::new(),
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &key_value_store,
+            &RumorStore::default(),
+        );
+        let census_group = ring.census_group_for(&sg).unwrap();
+        assert_eq!(census_group.kv.len(), 2);
+        assert_eq!(census_group.kv["maintenance"], "false");
+        assert_eq!(census_group.kv["flavor"], "vanilla");
+    }
+
+    #[test]
+    fn populate_census_with_federated_member_health() {
+        let pg_id = PackageIdent::new("core", "redis", Some("3.2.4"), Some("20170514150022"));
+        let sg = ServiceGroup::new(None, "redis", "default", None).unwrap();
+        let mut sys_info = SysInfo::new();
+        sys_info.set_ring("us-east".to_string());
+        let service_store: RumorStore<ServiceRumor> = RumorStore::default();
+        service_store.insert(ServiceRumor::new("member-a", &pg_id, &sg, &sys_info, None));
+
+        let mut member = Member::default();
+        member.set_id("member-a".to_string());
+        let mut membership = Membership::new();
+        membership.set_member(member.proto);
+        membership.set_health(Health::Alive.into());
+        let federated_member_store: RumorStore<FederatedMemberRumor> = RumorStore::default();
+        federated_member_store.insert(FederatedMemberRumor::new("gateway", "us-east", membership));
+
+        let mut ring = CensusRing::new("member-b");
+        ring.update_from_rumors(
+            &service_store,
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &MemberList::new(),
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &federated_member_store,
+        );
+        let census_group = ring.census_group_for(&sg).unwrap();
+        let census_member = census_group.members()[0];
+        assert_eq!(census_member.member_id, "member-a");
+        assert!(census_member.alive());
+        assert_eq!(census_member.sys.get_ring(), "us-east");
+    }
+
+    #[test]
+    fn previous_peer_orders_members_by_zone() {
+        let pg_id = PackageIdent::new("core", "redis", Some("3.2.4"), Some("20170514150022"));
+        let sg = ServiceGroup::new(None, "redis", "default", None).unwrap();
+        let service_store: RumorStore<ServiceRumor> = RumorStore::default();
+        let member_list = MemberList::new();
+        for &(member_id, zone) in [("member-a", "z2"), ("member-b", "z1"), ("member-c", "z3")]
+            .iter()
+        {
+            let service = ServiceRumor::new(member_id, &pg_id, &sg, &SysInfo::new(), None);
+            service_store.insert(service);
+            let mut member = Member::default();
+            member.set_id(member_id.to_string());
+            member.mut_tags().insert("az".to_string(), zone.to_string());
+            member_list.insert(member, Health::Alive);
+        }
+
+        let mut ring = CensusRing::new("member-c");
+        ring.update_from_rumors(
+            &service_store,
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &member_list,
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &RumorStore::default(),
+        );
+        let census_group = ring.census_group_for(&sg).unwrap();
+        assert_eq!(census_group.me().unwrap().tag("az"), Some("z3"));
+        assert_eq!(census_group.previous_peer(None).unwrap().member_id, "member-b");
+        assert_eq!(
+            census_group.previous_peer(Some("az")).unwrap().member_id,
+            "member-a"
+        );
+    }
 }
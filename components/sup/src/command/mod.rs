@@ -14,4 +14,5 @@
 
 //! The CLI commands.
 
+pub mod render;
 pub mod shell;
@@ -0,0 +1,461 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Renders a package's configuration templates and hooks against a simulated census, without a
+//! running Supervisor.
+//!
+//! The census is described by a TOML or JSON file listing service groups and their members:
+//!
+//! ```toml
+//! member_id = "me"
+//!
+//! [[group]]
+//! service_group = "redis.default"
+//! pkg = "core/redis/3.2.4/20170514150022"
+//! leader = "redis-a"
+//!
+//! [group.kv]
+//! maintenance = "false"
+//!
+//! [[group.member]]
+//! member_id = "redis-a"
+//! ip = "10.0.0.1"
+//! [group.member.cfg]
+//! port = 6379
+//! ```
+//!
+//! The census is built from rumors, just as a Supervisor builds its census from gossip, so
+//! templates see exactly the data they would see in a live ring.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use butterfly::member::{Health, Member, MemberList};
+use butterfly::rumor::RumorStore;
+use butterfly::rumor::election::{Election as ElectionRumor, ElectionUpdate as ElectionUpdateRumor};
+use butterfly::rumor::key_value::KeyValue as KeyValueRumor;
+use butterfly::rumor::service::{Service as ServiceRumor, SysInfo};
+use butterfly::rumor::service_config::ServiceConfig as ServiceConfigRumor;
+use hcore::fs::FS_ROOT_PATH;
+use hcore::package::{Identifiable, PackageIdent, PackageInstall};
+use hcore::service::ServiceGroup;
+use serde_json;
+use toml;
+
+use census::CensusRing;
+use config::{GossipListenAddr, GOSSIP_DEFAULT_PORT};
+use error::{Error, Result};
+use http_gateway;
+use manager::Sys;
+use manager::service::{Cfg, CfgRenderer, Pkg, ServiceBind};
+use templating::RenderContext;
+use templating::secrets::SecretsProvider;
+
+static LOGKEY: &'static str = "RN";
+
+/// A census to render templates against.
+#[derive(Debug, Deserialize)]
+pub struct SimulatedCensus {
+    /// Member ID of the member running the rendered service.
+    #[serde(default = "SimulatedCensus::default_member_id")]
+    pub member_id: String,
+    #[serde(default)]
+    pub group: Vec<SimulatedGroup>,
+}
+
+/// A service group of a simulated census.
+#[derive(Debug, Deserialize)]
+pub struct SimulatedGroup {
+    pub service_group: String,
+    /// Fully qualified package the group's members run. Not needed for the rendered service's own
+    /// group, which runs the rendered package.
+    pub pkg: Option<String>,
+    /// Member ID of the group's leader, if the group has finished a leader election.
+    pub leader: Option<String>,
+    /// Member ID of the group's update leader, if the group has finished an update election.
+    pub update_leader: Option<String>,
+    /// Configuration applied to the group with `hab config apply`.
+    pub config: Option<toml::value::Table>,
+    /// Key/value pairs gossiped to the group.
+    #[serde(default)]
+    pub kv: BTreeMap<String, String>,
+    #[serde(default)]
+    pub member: Vec<SimulatedMember>,
+}
+
+/// A member of a simulated service group.
+#[derive(Debug, Deserialize)]
+pub struct SimulatedMember {
+    pub member_id: String,
+    #[serde(default = "SimulatedMember::default_alive")]
+    pub alive: bool,
+    pub ip: Option<String>,
+    pub hostname: Option<String>,
+    /// The tags the member's Supervisor was started with.
+    #[serde(default)]
+    pub tags: BTreeMap<String, String>,
+    /// The member's exported configuration.
+    #[serde(default)]
+    pub cfg: toml::value::Table,
+}
+
+impl SimulatedCensus {
+    /// Reads a census from a file. Files ending in `.json` are read as JSON and anything else as
+    /// TOML.
+    pub fn from_file<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let mut content = String::new();
+        File::open(path)
+            .and_then(|mut f| f.read_to_string(&mut content))
+            .map_err(|e| {
+                sup_error!(Error::BadCensusFile(path.to_path_buf(), e.to_string()))
+            })?;
+        let census = match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => serde_json::from_str(&content).map_err(|e| e.to_string()),
+            _ => toml::from_str(&content).map_err(|e| e.to_string()),
+        };
+        census.map_err(|e| sup_error!(Error::BadCensusFile(path.to_path_buf(), e)))
+    }
+
+    /// Builds a census ring from rumors describing the simulated census. The rendered service's
+    /// own member is alive, runs `pkg` in `service_group` and exports `cfg`.
+    pub fn census_ring(
+        &self,
+        service_group: &ServiceGroup,
+        pkg: &PackageIdent,
+        sys: &Sys,
+        cfg: &toml::value::Table,
+    ) -> Result<CensusRing> {
+        let service_store: RumorStore<ServiceRumor> = RumorStore::default();
+        let election_store: RumorStore<ElectionRumor> = RumorStore::default();
+        let election_update_store: RumorStore<ElectionUpdateRumor> = RumorStore::default();
+        let service_config_store: RumorStore<ServiceConfigRumor> = RumorStore::default();
+        let key_value_store: RumorStore<KeyValueRumor> = RumorStore::default();
+        let member_list = MemberList::new();
+
+        service_store.insert(ServiceRumor::new(
+            self.member_id.clone(),
+            pkg,
+            service_group,
+            &sys.as_sys_info(),
+            Some(cfg),
+        ));
+        for group in self.group.iter() {
+            let sg = ServiceGroup::from_str(&group.service_group)?;
+            let ident = if &sg == service_group {
+                pkg.clone()
+            } else {
+                group.ident(&sg)?
+            };
+            for simulated in group.member.iter() {
+                let mut sys_info = SysInfo::new();
+                let ip = simulated.ip.clone().unwrap_or(String::from("127.0.0.1"));
+                sys_info.set_ip(ip.clone());
+                sys_info.set_hostname(simulated.hostname.clone().unwrap_or(
+                    simulated.member_id.clone(),
+                ));
+                sys_info.set_gossip_ip(ip.clone());
+                sys_info.set_gossip_port(GOSSIP_DEFAULT_PORT as u32);
+                sys_info.set_http_gateway_ip(ip.clone());
+                sys_info.set_http_gateway_port(http_gateway::ListenAddr::default().port() as u32);
+                service_store.insert(ServiceRumor::new(
+                    simulated.member_id.clone(),
+                    &ident,
+                    &sg,
+                    &sys_info,
+                    Some(&simulated.cfg),
+                ));
+
+                let mut member = Member::default();
+                member.set_id(simulated.member_id.clone());
+                member.set_address(ip);
+                member.set_swim_port(GOSSIP_DEFAULT_PORT as i32);
+                member.set_gossip_port(GOSSIP_DEFAULT_PORT as i32);
+                member.set_tags(
+                    simulated
+                        .tags
+                        .iter()
+                        .map(|(k, v)| (k.clone(), v.clone()))
+                        .collect(),
+                );
+                let health = if simulated.alive {
+                    Health::Alive
+                } else {
+                    Health::Confirmed
+                };
+                member_list.insert(member, health);
+            }
+            if let Some(ref leader) = group.leader {
+                let mut election = ElectionRumor::new(leader.as_str(), sg.clone(), 0);
+                election.finish();
+                election_store.insert(election);
+            }
+            if let Some(ref leader) = group.update_leader {
+                let mut election = ElectionUpdateRumor::new(leader.as_str(), sg.clone(), 0);
+                election.finish();
+                election_update_store.insert(election);
+            }
+            if let Some(ref config) = group.config {
+                let body = toml::ser::to_vec(config).map_err(
+                    |e| sup_error!(Error::TomlEncode(e)),
+                )?;
+                let mut service_config =
+                    ServiceConfigRumor::new(self.member_id.as_str(), sg.clone(), body);
+                service_config.set_incarnation(1);
+                service_config_store.insert(service_config);
+            }
+            for (key, value) in group.kv.iter() {
+                key_value_store.insert(KeyValueRumor::new(
+                    self.member_id.as_str(),
+                    sg.clone(),
+                    key.as_str(),
+                    value.as_str(),
+                    1,
+                ));
+            }
+        }
+
+        let mut ring = CensusRing::new(self.member_id.as_str());
+        ring.update_from_rumors(
+            &service_store,
+            &election_store,
+            &election_update_store,
+            &member_list,
+            &service_config_store,
+            &RumorStore::default(),
+            &RumorStore::default(),
+            &key_value_store,
+            &RumorStore::default(),
+        );
+        Ok(ring)
+    }
+
+    fn default_member_id() -> String {
+        String::from("me")
+    }
+}
+
+impl Default for SimulatedCensus {
+    fn default() -> Self {
+        SimulatedCensus {
+            member_id: Self::default_member_id(),
+            group: Vec::new(),
+        }
+    }
+}
+
+impl SimulatedGroup {
+    fn ident(&self, sg: &ServiceGroup) -> Result<PackageIdent> {
+        let ident = match self.pkg {
+            Some(ref pkg) => PackageIdent::from_str(pkg)?,
+            None => {
+                return Err(sup_error!(Error::BadCensusFile(
+                    PathBuf::new(),
+                    format!("group {} has no pkg", sg),
+                )))
+            }
+        };
+        if !ident.fully_qualified() || ident.name() != sg.service() {
+            return Err(sup_error!(Error::BadCensusFile(
+                PathBuf::new(),
+                format!(
+                    "group {} pkg must be a fully qualified {} package",
+                    sg,
+                    sg.service()
+                ),
+            )));
+        }
+        Ok(ident)
+    }
+}
+
+impl SimulatedMember {
+    fn default_alive() -> bool {
+        true
+    }
+}
+
+/// Renders each secret as a placeholder naming it, so templates using the `secret` helper can be
+/// rendered without access to any secrets.
+#[derive(Debug)]
+pub struct PlaceholderSecrets;
+
+impl SecretsProvider for PlaceholderSecrets {
+    fn get(&self, path: &str) -> Result<String> {
+        Ok(format!("<secret:{}>", path))
+    }
+}
+
+/// Renders the configuration templates and hooks of an installed package into `config/` and
+/// `hooks/` under the `dest` directory.
+pub fn start(
+    ident: &PackageIdent,
+    group: &str,
+    binds: &[ServiceBind],
+    config_from: Option<&PathBuf>,
+    user_config: Option<&Path>,
+    census: &SimulatedCensus,
+    dest: &Path,
+) -> Result<()> {
+    let fs_root_path = Path::new(&*FS_ROOT_PATH);
+    let package = PackageInstall::load(ident, Some(fs_root_path))?;
+    let pkg = Pkg::from_install(package)?;
+    let service_group = ServiceGroup::new(None, &pkg.name, group, None)?;
+
+    let mut sys = Sys::new(
+        false,
+        GossipListenAddr::default(),
+        http_gateway::ListenAddr::default(),
+    );
+    sys.member_id = census.member_id.clone();
+
+    let mut cfg = Cfg::new(&pkg, config_from)?;
+    // Only the given user configuration is rendered, never whatever happens to be on this host.
+    cfg.user = match user_config {
+        Some(path) => Some(load_user_config(path)?),
+        None => None,
+    };
+    let ring = census.census_ring(
+        &service_group,
+        &pkg.ident,
+        &sys,
+        &cfg.to_exported(&pkg)?,
+    )?;
+    if let Some(census_group) = ring.census_group_for(&service_group) {
+        cfg.update(census_group);
+    }
+    let ctx = RenderContext::new(&service_group, &sys, &pkg, &cfg, &ring, binds.iter());
+
+    let root = config_from.map(|p| p.as_path()).unwrap_or(&pkg.path);
+    for dir in &["config", "hooks"] {
+        let rendered = CfgRenderer::new(root.join(dir))?.render(&ctx)?;
+        if rendered.is_empty() {
+            continue;
+        }
+        let dest_dir = dest.join(dir);
+        fs::create_dir_all(&dest_dir)?;
+        for (name, content) in rendered {
+            let path = dest_dir.join(name);
+            File::create(&path)?.write_all(content.as_bytes())?;
+            outputln!("Rendered {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+fn load_user_config(path: &Path) -> Result<toml::Value> {
+    let mut content = String::new();
+    File::open(path)?.read_to_string(&mut content)?;
+    let table = toml::de::from_str(&content).map_err(
+        |e| sup_error!(Error::TomlParser(e)),
+    )?;
+    Ok(toml::Value::Table(table))
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+    use hcore::service::ServiceGroup;
+    use toml;
+
+    use super::*;
+
+    const CENSUS: &'static str = r#"
+member_id = "web-a"
+
+[[group]]
+service_group = "web.default"
+
+[[group.member]]
+member_id = "web-b"
+alive = false
+
+[[group]]
+service_group = "redis.default"
+pkg = "core/redis/3.2.4/20170514150022"
+leader = "redis-a"
+
+[[group.member]]
+member_id = "redis-a"
+ip = "10.0.0.1"
+[group.member.cfg]
+port = 6379
+
+[[group.member]]
+member_id = "redis-b"
+ip = "10.0.0.2"
+"#;
+
+    #[test]
+    fn census_ring_from_simulated_census() {
+        let census: SimulatedCensus = toml::from_str(CENSUS).unwrap();
+        let web = ServiceGroup::from_str("web.default").unwrap();
+        let redis = ServiceGroup::from_str("redis.default").unwrap();
+        let pkg = PackageIdent::from_str("core/web/1.0.0/20170101000000").unwrap();
+        let sys = Sys::new(
+            false,
+            GossipListenAddr::default(),
+            http_gateway::ListenAddr::default(),
+        );
+        let ring = census
+            .census_ring(&web, &pkg, &sys, &toml::value::Table::new())
+            .unwrap();
+
+        let web_group = ring.census_group_for(&web).unwrap();
+        assert_eq!(web_group.me().unwrap().member_id, "web-a");
+        assert!(web_group.me().unwrap().alive());
+        let web_b = web_group.members().into_iter().find(|m| m.member_id == "web-b");
+        assert!(!web_b.unwrap().alive());
+
+        let redis_group = ring.census_group_for(&redis).unwrap();
+        let leader = redis_group.leader().unwrap();
+        assert_eq!(leader.member_id, "redis-a");
+        assert_eq!(leader.sys.get_ip(), "10.0.0.1");
+        assert_eq!(leader.cfg["port"].as_integer(), Some(6379));
+        assert_eq!(redis_group.members().len(), 2);
+        assert!(redis_group.members().iter().all(|m| m.alive()));
+    }
+
+    #[test]
+    fn census_ring_requires_group_pkg() {
+        let census: SimulatedCensus = toml::from_str(
+            r#"
+[[group]]
+service_group = "redis.default"
+[[group.member]]
+member_id = "redis-a"
+"#,
+        ).unwrap();
+        let web = ServiceGroup::from_str("web.default").unwrap();
+        let pkg = PackageIdent::from_str("core/web/1.0.0/20170101000000").unwrap();
+        let sys = Sys::new(
+            false,
+            GossipListenAddr::default(),
+            http_gateway::ListenAddr::default(),
+        );
+        assert!(
+            census
+                .census_ring(&web, &pkg, &sys, &toml::value::Table::new())
+                .is_err()
+        );
+    }
+}
@@ -55,7 +55,7 @@ use glob;
 use handlebars;
 use hcore;
 use hcore::os::process::Pid;
-use hcore::output::StructuredOutput;
+use hcore::output::{Level, StructuredOutput};
 use hcore::package::{self, Identifiable, PackageInstall};
 use launcher_client;
 use notify;
@@ -104,6 +104,7 @@ impl SupError {
 #[derive(Debug)]
 pub enum Error {
     Departed,
+    BadCensusFile(PathBuf, String),
     BadCompositesPath(PathBuf, io::Error),
     BadDataFile(PathBuf, io::Error),
     BadDataPath(PathBuf, io::Error),
@@ -112,6 +113,7 @@ pub enum Error {
     BadPackage(PackageInstall, hcore::error::Error),
     BadSpecsPath(PathBuf, io::Error),
     BadStartStyle(String),
+    BadTrustedIdentitiesPath(PathBuf, io::Error),
     BadEnvConfig(String),
     ButterflyError(butterfly::error::Error),
     DepotClient(depot_client::Error),
@@ -126,8 +128,11 @@ pub enum Error {
     InvalidBinding(String),
     InvalidBinds(Vec<String>),
     InvalidCompositeBinding(String),
+    InvalidHealthProbe(String),
     InvalidKeyParameter(String),
     InvalidPidFile,
+    InvalidRestartPolicy(String),
+    InvalidSecretPath(String),
     InvalidTopology(String),
     InvalidUpdateStrategy(String),
     Io(io::Error),
@@ -138,6 +143,7 @@ pub enum Error {
     NameLookup(io::Error),
     NetParseError(net::AddrParseError),
     NoLauncher,
+    NoSecretsProvider,
     NotifyCreateError(notify::Error),
     NotifyError(notify::Error),
     NulError(ffi::NulError),
@@ -150,6 +156,8 @@ pub enum Error {
     ProcessLockIO(PathBuf, io::Error),
     RecvError(mpsc::RecvError),
     RenderContextSerialization(serde_json::Error),
+    SecretNotFound(String),
+    SecretsProvider(String),
     ServiceDeserializationError(serde_json::Error),
     ServiceLoaded(package::PackageIdent),
     ServiceNotLoaded(package::PackageIdent),
@@ -174,6 +182,9 @@ impl fmt::Display for SupError {
     // verbose on, and print it.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let content = match self.err {
+            Error::BadCensusFile(ref path, ref err) => {
+                format!("Unable to read census file, {}, {}", path.display(), err)
+            }
             Error::BadCompositesPath(ref path, ref err) => {
                 format!(
                     "Unable to create the composites directory '{}' ({})",
@@ -213,6 +224,13 @@ impl fmt::Display for SupError {
                 )
             }
             Error::BadStartStyle(ref style) => format!("Unknown service start style '{}'", style),
+            Error::BadTrustedIdentitiesPath(ref path, ref err) => {
+                format!(
+                    "Unable to read the trusted identities directory '{}' ({})",
+                    path.display(),
+                    err
+                )
+            }
             Error::BadEnvConfig(ref varname) => {
                 format!("Unable to find valid TOML or JSON in {} ENVVAR", varname)
             }
@@ -245,10 +263,20 @@ impl fmt::Display for SupError {
                     binding
                 )
             }
+            Error::InvalidHealthProbe(ref probe) => {
+                format!(
+                    "Invalid health probe \"{}\", must be of the form <KIND>:<TARGET> where <KIND> \
+                     is one of http, tcp or exec, optionally followed by ,interval=<SECS>, \
+                     ,timeout=<SECS>, ,threshold=<N> and, for http probes, ,status=<CODE>",
+                    probe
+                )
+            }
             Error::InvalidKeyParameter(ref e) => {
                 format!("Invalid parameter for key generation: {:?}", e)
             }
             Error::InvalidPidFile => format!("Invalid child process PID file"),
+            Error::InvalidRestartPolicy(ref p) => format!("Invalid restart policy: {}", p),
+            Error::InvalidSecretPath(ref p) => format!("Invalid secret path: {}", p),
             Error::InvalidTopology(ref t) => format!("Invalid topology: {}", t),
             Error::InvalidUpdateStrategy(ref s) => format!("Invalid update strategy: {}", s),
             Error::Io(ref err) => format!("{}", err),
@@ -263,6 +291,9 @@ impl fmt::Display for SupError {
             Error::NameLookup(ref e) => format!("Error resolving a name or IP address: {}", e),
             Error::NetParseError(ref e) => format!("Can't parse ip:port: {}", e),
             Error::NoLauncher => format!("Supervisor must be run from `hab-launch`"),
+            Error::NoSecretsProvider => {
+                format!("No secrets provider configured; start the Supervisor with --secrets")
+            }
             Error::NotifyCreateError(ref e) => format!("Notify create error: {}", e),
             Error::NotifyError(ref e) => format!("Notify error: {}", e),
             Error::NulError(ref e) => format!("{}", e),
@@ -301,6 +332,8 @@ impl fmt::Display for SupError {
             Error::RenderContextSerialization(ref e) => {
                 format!("Unable to serialize rendering context, {}", e)
             }
+            Error::SecretNotFound(ref p) => format!("Secret not found: {}", p),
+            Error::SecretsProvider(ref e) => format!("Secrets provider error, {}", e),
             Error::ServiceDeserializationError(ref e) => {
                 format!("Can't deserialize service status: {}", e)
             }
@@ -350,6 +383,9 @@ impl fmt::Display for SupError {
             &content,
         );
         so.verbose = Some(true);
+        so.level = Level::Error;
+        // Errors are usually formatted into other output, which is JSON if anything is
+        so.json = Some(false);
         write!(f, "{}", so)
     }
 }
@@ -357,6 +393,7 @@ impl fmt::Display for SupError {
 impl error::Error for SupError {
     fn description(&self) -> &str {
         match self.err {
+            Error::BadCensusFile(_, _) => "Unable to read a census file",
             Error::BadCompositesPath(_, _) => "Unable to create the composites directory",
             Error::Departed => "Supervisor has been manually departed",
             Error::BadDataFile(_, _) => "Unable to read or write to a data file",
@@ -366,6 +403,9 @@ impl error::Error for SupError {
             Error::BadPackage(_, _) => "Package was malformed or contained malformed contents",
             Error::BadSpecsPath(_, _) => "Unable to create the specs directory",
             Error::BadStartStyle(_) => "Unknown start style in service spec",
+            Error::BadTrustedIdentitiesPath(_, _) => {
+                "Unable to read the trusted identities directory"
+            }
             Error::BadEnvConfig(_) => "Unknown syntax in Env Configuration",
             Error::ButterflyError(ref err) => err.description(),
             Error::ExecCommandNotFound(_) => "Exec command was not found on filesystem or in PATH",
@@ -382,8 +422,11 @@ impl error::Error for SupError {
                 "Service binds detected that are neither required nor optional package binds"
             }
             Error::InvalidCompositeBinding(_) => "Invalid binding parameter",
+            Error::InvalidHealthProbe(_) => "Invalid health probe",
             Error::InvalidKeyParameter(_) => "Key parameter error",
             Error::InvalidPidFile => "Invalid child process PID file",
+            Error::InvalidRestartPolicy(_) => "Invalid restart policy",
+            Error::InvalidSecretPath(_) => "Invalid secret path",
             Error::InvalidTopology(_) => "Invalid topology",
             Error::InvalidUpdateStrategy(_) => "Invalid update strategy",
             Error::Io(ref err) => err.description(),
@@ -398,6 +441,7 @@ impl error::Error for SupError {
             Error::NetParseError(_) => "Can't parse IP:port",
             Error::NameLookup(_) => "Error resolving a name or IP address",
             Error::NoLauncher => "Supervisor must be run from `hab-launch`",
+            Error::NoSecretsProvider => "No secrets provider configured",
             Error::NotifyCreateError(_) => "Notify create error",
             Error::NotifyError(_) => "Notify error",
             Error::NulError(_) => {
@@ -414,6 +458,8 @@ impl error::Error for SupError {
             Error::ProcessLockIO(_, _) => "Unable to read or write to a process lock",
             Error::RecvError(_) => "A channel failed to receive a response",
             Error::RenderContextSerialization(_) => "Unable to serialize rendering context",
+            Error::SecretNotFound(_) => "Secret not found",
+            Error::SecretsProvider(_) => "Secrets provider error",
             Error::ServiceDeserializationError(_) => "Can't deserialize service status",
             Error::ServiceNotLoaded(_) => "Service status called when service not loaded",
             Error::ServiceLoaded(_) => "Service load or start called when service already loaded",
@@ -15,18 +15,25 @@
 use std::collections::HashMap;
 use std::fmt;
 use std::fs::File;
-use std::io::{self, Read};
+use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs, SocketAddr, SocketAddrV4};
 use std::ops::{Deref, DerefMut};
 use std::option;
-use std::path::Path;
+use std;
+use std::path::{Path, PathBuf};
 use std::result;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
 use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
+use butterfly::event::{Event, EventStream};
+use hcore::package::{Identifiable, PackageIdent, PackageTarget};
 use hcore::service::{ApplicationEnvironment, ServiceGroup};
 use iron::prelude::*;
+use iron::response::{BodyReader, WriteBody};
 use iron::{headers, status, typemap};
 use iron::modifiers::Header;
 use persistent;
@@ -36,12 +43,19 @@ use serde_json::{self, Value as Json};
 
 use error::{Result, Error, SupError};
 use manager;
-use manager::service::HealthCheck;
+use manager::service::{DesiredState, HealthCheck, ServiceSpec, StartStyle};
 use manager::service::hooks::{self, HealthCheckHook};
 
 static LOGKEY: &'static str = "HG";
 const APIDOCS: &'static str = include_str!(concat!(env!("OUT_DIR"), "/api.html"));
 
+/// How often an idle event stream is sent a comment, which keeps proxies from closing it and
+/// lets us notice clients that have gone away.
+const EVENTS_KEEPALIVE_SECS: u64 = 15;
+/// The most event streams served at once. Each open stream holds one of the gateway's worker
+/// threads, so the rest are kept free for the other endpoints.
+const MAX_EVENT_STREAMS: usize = 4;
+
 // Simple macro to encapsulate the HTTP metrics for each endpoint
 macro_rules! with_metrics {
     ($method:expr, $name:expr) => {{
@@ -141,20 +155,96 @@ impl typemap::Key for ManagerFs {
     type Value = manager::FsCfg;
 }
 
+/// Bearer token required by the write endpoints. When no token is configured the write
+/// endpoints refuse every request.
+struct AuthToken;
+
+impl typemap::Key for AuthToken {
+    type Value = Option<String>;
+}
+
+struct GossipEvents;
+
+impl typemap::Key for GossipEvents {
+    type Value = EventStreams;
+}
+
+/// The ring's events, and a count of the clients currently streaming them.
+#[derive(Clone)]
+struct EventStreams {
+    events: EventStream,
+    open: Arc<AtomicUsize>,
+}
+
+impl EventStreams {
+    fn new(events: EventStream) -> Self {
+        EventStreams {
+            events: events,
+            open: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Subscribes a new client to the events, or returns `None` if there are already
+    /// `MAX_EVENT_STREAMS` clients.
+    fn open(&self) -> Option<EventsBody> {
+        if self.open.fetch_add(1, Ordering::SeqCst) >= MAX_EVENT_STREAMS {
+            self.open.fetch_sub(1, Ordering::SeqCst);
+            return None;
+        }
+        Some(EventsBody {
+            events: self.events.subscribe(),
+            open: self.open.clone(),
+        })
+    }
+}
+
+/// The artifact cache served to peers, if this Supervisor shares it.
+struct SharedArtifactCache;
+
+impl typemap::Key for SharedArtifactCache {
+    type Value = Option<PathBuf>;
+}
+
 pub struct Server(Iron<Chain>, ListenAddr);
 
 impl Server {
-    pub fn new(manager_state: Arc<manager::FsCfg>, listen_addr: ListenAddr) -> Self {
+    pub fn new(
+        manager_state: Arc<manager::FsCfg>,
+        listen_addr: ListenAddr,
+        auth_token: Option<String>,
+        events: EventStream,
+        shared_artifact_cache: Option<PathBuf>,
+    ) -> Self {
         let router =
             router!(
             doc: get "/" => with_metrics!(doc, "doc"),
             butterfly: get "/butterfly" => with_metrics!(butterfly, "butterfly"),
+            butterfly_events: get "/butterfly/events" => {
+                with_metrics!(butterfly_events, "butterfly_events")
+            },
+            // The same path as a Builder download, so peers can fetch from us with a depot client
+            artifact: get "/v1/depot/pkgs/:origin/:name/:version/:release/download" => {
+                with_metrics!(artifact, "artifact")
+            },
             census: get "/census" => with_metrics!(census, "census"),
             metrics: get "/metrics" => with_metrics!(metrics, "metrics"),
             services: get "/services" => with_metrics!(services, "services"),
+            service_load: post "/services" => with_metrics!(service_load, "service_load"),
             service: get "/services/:svc/:group" => {
                 with_metrics!(service, "service")
             },
+            service_reconfigure: put "/services/:svc/:group" => {
+                with_metrics!(service_reconfigure, "service_reconfigure")
+            },
+            service_unload: delete "/services/:svc/:group" => {
+                with_metrics!(service_unload, "service_unload")
+            },
+            service_start: post "/services/:svc/:group/start" => {
+                with_metrics!(service_start, "service_start")
+            },
+            service_stop: post "/services/:svc/:group/stop" => {
+                with_metrics!(service_stop, "service_stop")
+            },
             service_org: get "/services/:svc/:group/:org" => {
                 with_metrics!(service, "service")
             },
@@ -162,15 +252,24 @@ impl Server {
                 with_metrics!(config, "config")
             },
             service_health: get "/services/:svc/:group/health" => with_metrics!(health, "health"),
+            service_kv: get "/services/:svc/:group/kv" => with_metrics!(kv, "kv"),
             service_config_org: get "/services/:svc/:group/:org/config" => {
                 with_metrics!(config, "config")
             },
             service_health_org: get "/services/:svc/:group/:org/health" => {
                 with_metrics!(health, "config")
-            }
+            },
+            service_kv_org: get "/services/:svc/:group/:org/kv" => with_metrics!(kv, "kv")
         );
         let mut chain = Chain::new(router);
         chain.link(persistent::Read::<ManagerFs>::both(manager_state));
+        chain.link(persistent::Read::<AuthToken>::both(auth_token));
+        chain.link(persistent::Read::<GossipEvents>::both(
+            EventStreams::new(events),
+        ));
+        chain.link(persistent::Read::<SharedArtifactCache>::both(
+            shared_artifact_cache,
+        ));
         Server(Iron::new(chain), listen_addr)
     }
 
@@ -192,6 +291,90 @@ struct HealthCheckBody {
     stderr: String,
 }
 
+fn artifact(req: &mut Request) -> IronResult<Response> {
+    let cache = req.get::<persistent::Read<SharedArtifactCache>>().unwrap();
+    let ident = {
+        let params = req.extensions.get::<Router>().unwrap();
+        PackageIdent::new(
+            params.find("origin").unwrap_or(""),
+            params.find("name").unwrap_or(""),
+            params.find("version"),
+            params.find("release"),
+        )
+    };
+    // Peers ask for the target they want; anything else gets the one this Supervisor runs
+    let target = match req.url
+        .as_ref()
+        .query_pairs()
+        .find(|&(ref key, _)| key == "target") {
+        Some((_, value)) => {
+            match PackageTarget::from_str(&value) {
+                Ok(target) => target,
+                Err(_) => return Ok(Response::with(status::BadRequest)),
+            }
+        }
+        None => PackageTarget::default(),
+    };
+    let start = match req.headers.get::<headers::Range>() {
+        Some(&headers::Range::Bytes(ref ranges)) if ranges.len() == 1 => {
+            match ranges[0] {
+                headers::ByteRangeSpec::AllFrom(start) => start,
+                _ => 0,
+            }
+        }
+        _ => 0,
+    };
+    let cache_path = match *cache {
+        Some(ref path) => Some(path.as_path()),
+        None => None,
+    };
+    Ok(artifact_from(cache_path, &ident, &target, start))
+}
+
+/// Serves an artifact from the shared artifact cache, from byte `start` onward.
+fn artifact_from(
+    cache_path: Option<&Path>,
+    ident: &PackageIdent,
+    target: &PackageTarget,
+    start: u64,
+) -> Response {
+    let cache_path = match cache_path {
+        Some(path) => path,
+        None => return Response::with(status::NotFound),
+    };
+    let archive_name = match ident.archive_name_with_target(target) {
+        Some(name) => name,
+        None => return Response::with(status::BadRequest),
+    };
+    if !ident.valid() || archive_name.contains(|c: char| c == '/' || c == '\\') {
+        return Response::with(status::BadRequest);
+    }
+    let mut file = match File::open(cache_path.join(&archive_name)) {
+        Ok(file) => file,
+        Err(_) => return Response::with(status::NotFound),
+    };
+    if start == 0 {
+        return Response::with((status::Ok, file));
+    }
+    let len = match file.metadata() {
+        Ok(metadata) => metadata.len(),
+        Err(_) => return Response::with(status::ServiceUnavailable),
+    };
+    if start >= len || file.seek(SeekFrom::Start(start)).is_err() {
+        return Response::with(status::RangeNotSatisfiable);
+    }
+    let body: Box<WriteBody> = Box::new(BodyReader(file));
+    Response::with((
+        status::PartialContent,
+        Header(headers::ContentLength(len - start)),
+        Header(headers::ContentRange(headers::ContentRangeSpec::Bytes {
+            range: Some((start, len - 1)),
+            instance_length: Some(len),
+        })),
+        body,
+    ))
+}
+
 fn butterfly(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
     match File::open(&state.butterfly_data_path) {
@@ -202,6 +385,22 @@ fn butterfly(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn butterfly_events(req: &mut Request) -> IronResult<Response> {
+    let streams = req.get::<persistent::Read<GossipEvents>>().unwrap();
+    let body: Box<WriteBody> = match streams.open() {
+        Some(body) => Box::new(body),
+        None => return Ok(Response::with(status::ServiceUnavailable)),
+    };
+    Ok(Response::with((
+        status::Ok,
+        Header(headers::ContentType(
+            "text/event-stream".parse().unwrap(),
+        )),
+        Header(headers::CacheControl(vec![headers::CacheDirective::NoCache])),
+        body,
+    )))
+}
+
 fn census(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
     match File::open(&state.census_data_path) {
@@ -266,6 +465,25 @@ fn health(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn kv(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    match census_group_from_file(&service_group, &state.census_data_path) {
+        Ok(Some(census_group)) => {
+            Ok(Response::with((
+                status::Ok,
+                Header(headers::ContentType::json()),
+                census_group["kv"].to_string(),
+            )))
+        }
+        Ok(None) => Ok(Response::with(status::NotFound)),
+        Err(_) => Ok(Response::with(status::ServiceUnavailable)),
+    }
+}
+
 fn service(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
     let service_group = match build_service_group(req) {
@@ -295,6 +513,87 @@ fn services(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn service_load(req: &mut Request) -> IronResult<Response> {
+    if let Some(rejection) = authorize(req) {
+        return Ok(rejection);
+    }
+    let mut spec = match spec_from_body(req) {
+        Some(spec) => spec,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let spec_file = state.spec_path(&spec.ident.name);
+    if spec_file.is_file() {
+        return Ok(Response::with(status::Conflict));
+    }
+    // "load" == persistent services, by definition
+    spec.start_style = StartStyle::Persistent;
+    // The manager installs the package, if needed, once it picks up the spec file, so a slow
+    // download doesn't tie up a gateway worker.
+    save_spec(&spec, spec_file, status::Accepted)
+}
+
+fn service_reconfigure(req: &mut Request) -> IronResult<Response> {
+    if let Some(rejection) = authorize(req) {
+        return Ok(rejection);
+    }
+    let (current, spec_file) = match loaded_spec(req) {
+        Ok(loaded) => loaded,
+        Err(response) => return Ok(response),
+    };
+    let mut spec = match spec_from_body(req) {
+        Some(spec) => spec,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    if spec.ident.name != current.ident.name {
+        return Ok(Response::with(status::BadRequest));
+    }
+    // The body reconfigures the service but neither moves it to another group nor changes
+    // whether it's running.
+    spec.group = current.group;
+    spec.desired_state = current.desired_state;
+    spec.start_style = current.start_style;
+    spec.composite = current.composite;
+    save_spec(&spec, spec_file, status::Ok)
+}
+
+fn service_unload(req: &mut Request) -> IronResult<Response> {
+    if let Some(rejection) = authorize(req) {
+        return Ok(rejection);
+    }
+    let (_, spec_file) = match loaded_spec(req) {
+        Ok(loaded) => loaded,
+        Err(response) => return Ok(response),
+    };
+    match std::fs::remove_file(&spec_file) {
+        Ok(()) => Ok(Response::with(status::NoContent)),
+        Err(err) => {
+            outputln!("Unable to unload {}, {}", spec_file.display(), err);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
+fn service_start(req: &mut Request) -> IronResult<Response> {
+    set_desired_state(req, DesiredState::Up)
+}
+
+fn service_stop(req: &mut Request) -> IronResult<Response> {
+    set_desired_state(req, DesiredState::Down)
+}
+
+fn set_desired_state(req: &mut Request, desired_state: DesiredState) -> IronResult<Response> {
+    if let Some(rejection) = authorize(req) {
+        return Ok(rejection);
+    }
+    let (mut spec, spec_file) = match loaded_spec(req) {
+        Ok(loaded) => loaded,
+        Err(response) => return Ok(response),
+    };
+    spec.desired_state = desired_state;
+    save_spec(&spec, spec_file, status::Ok)
+}
+
 fn doc(_req: &mut Request) -> IronResult<Response> {
     Ok(Response::with(
         (status::Ok, Header(headers::ContentType::html()), APIDOCS),
@@ -312,6 +611,40 @@ fn metrics(_req: &mut Request) -> IronResult<Response> {
     ))
 }
 
+/// Writes gossip events to a client as server-sent events, one JSON object per event, until the
+/// client goes away. Dropping it frees its place among the `MAX_EVENT_STREAMS`.
+struct EventsBody {
+    events: Receiver<Event>,
+    open: Arc<AtomicUsize>,
+}
+
+impl Drop for EventsBody {
+    fn drop(&mut self) {
+        self.open.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl WriteBody for EventsBody {
+    fn write_body(&mut self, res: &mut Write) -> io::Result<()> {
+        loop {
+            match self.events.recv_timeout(
+                Duration::from_secs(EVENTS_KEEPALIVE_SECS),
+            ) {
+                Ok(event) => {
+                    write!(
+                        res,
+                        "data: {}\n\n",
+                        serde_json::to_string(&event).unwrap()
+                    )?
+                }
+                Err(RecvTimeoutError::Timeout) => res.write_all(b":\n\n")?,
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+            res.flush()?;
+        }
+    }
+}
+
 impl Into<Response> for HealthCheck {
     fn into(self) -> Response {
         let status: status::Status = self.into();
@@ -329,6 +662,121 @@ impl Into<status::Status> for HealthCheck {
     }
 }
 
+/// Returns the response to send instead of handling the request if it doesn't carry the bearer
+/// token configured for the Supervisor.
+fn authorize(req: &mut Request) -> Option<Response> {
+    let expected = req.get::<persistent::Read<AuthToken>>().unwrap();
+    let expected = match *expected {
+        Some(ref token) => Some(token.as_str()),
+        None => None,
+    };
+    let given = match req.headers.get::<headers::Authorization<headers::Bearer>>() {
+        Some(&headers::Authorization(headers::Bearer { ref token })) => Some(token.as_str()),
+        None => None,
+    };
+    match authorization_failure(expected, given) {
+        Some(status::Unauthorized) => {
+            let mut response = Response::with(status::Unauthorized);
+            response.headers.set_raw(
+                "WWW-Authenticate",
+                vec![b"Bearer".to_vec()],
+            );
+            Some(response)
+        }
+        Some(failure) => Some(Response::with(failure)),
+        None => None,
+    }
+}
+
+/// Returns the status to reject a request with given the token configured for the Supervisor, if
+/// any, and the bearer token the request carries, if any.
+fn authorization_failure(expected: Option<&str>, given: Option<&str>) -> Option<status::Status> {
+    let expected = match expected {
+        Some(token) => token,
+        None => return Some(status::Forbidden),
+    };
+    match given {
+        Some(token) if tokens_match(token, expected) => None,
+        _ => Some(status::Unauthorized),
+    }
+}
+
+/// Compares tokens in constant time so the comparison doesn't leak how much of a guess was
+/// correct.
+fn tokens_match(given: &str, expected: &str) -> bool {
+    given.len() == expected.len() &&
+        given.bytes().zip(expected.bytes()).fold(
+            0,
+            |acc, (a, b)| acc | (a ^ b),
+        ) == 0
+}
+
+fn spec_from_body(req: &mut Request) -> Option<ServiceSpec> {
+    let mut body = String::new();
+    if req.body.read_to_string(&mut body).is_err() {
+        return None;
+    }
+    spec_from_str(&body)
+}
+
+/// Parses a service spec sent to the gateway, rejecting specs without a valid package identifier.
+fn spec_from_str(body: &str) -> Option<ServiceSpec> {
+    match serde_json::from_str::<ServiceSpec>(body) {
+        Ok(spec) => {
+            if spec.ident.name.is_empty() || !spec.ident.valid() {
+                None
+            } else {
+                Some(spec)
+            }
+        }
+        Err(_) => None,
+    }
+}
+
+/// Loads the spec of the service group addressed by the request along with the path of its
+/// spec file.
+fn loaded_spec(req: &mut Request) -> result::Result<(ServiceSpec, PathBuf), Response> {
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Err(Response::with(status::BadRequest)),
+    };
+    let spec_file = state.spec_path(service_group.service());
+    if !spec_file.is_file() {
+        return Err(Response::with(status::NotFound));
+    }
+    match ServiceSpec::from_file(&spec_file) {
+        Ok(ref spec) if spec.group != service_group.group() => {
+            Err(Response::with(status::NotFound))
+        }
+        Ok(spec) => Ok((spec, spec_file)),
+        Err(err) => {
+            outputln!("Unable to read {}, {}", spec_file.display(), err);
+            Err(Response::with(status::InternalServerError))
+        }
+    }
+}
+
+fn save_spec(
+    spec: &ServiceSpec,
+    spec_file: PathBuf,
+    success: status::Status,
+) -> IronResult<Response> {
+    match spec.to_file(&spec_file) {
+        Ok(()) => {
+            Ok(Response::with((
+                success,
+                Header(headers::ContentType::json()),
+                serde_json::to_string(spec).unwrap(),
+            )))
+        }
+        Err(err) => {
+            outputln!("Unable to write {}, {}", spec_file.display(), err);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
 fn build_service_group(req: &mut Request) -> Result<ServiceGroup> {
     let app_env = match req.extensions.get::<Router>().unwrap().find(
         "application_environment",
@@ -379,3 +827,181 @@ where
         Err(err) => Err(err),
     }
 }
+
+fn census_group_from_file<T>(
+    service_group: &ServiceGroup,
+    census_data_path: T,
+) -> result::Result<Option<Json>, io::Error>
+where
+    T: AsRef<Path>,
+{
+    match File::open(census_data_path) {
+        Ok(file) => {
+            match serde_json::from_reader::<_, Json>(file) {
+                Ok(mut census) => {
+                    Ok(
+                        census["census_groups"]
+                            .as_object_mut()
+                            .and_then(|groups| groups.remove(service_group.as_ref())),
+                    )
+                }
+                _ => Ok(None),
+            }
+        }
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::Write;
+    use std::str::FromStr;
+
+    use butterfly::event::{Event, EventStream};
+    use butterfly::member::Health;
+    use hcore::package::{PackageIdent, PackageTarget};
+    use iron::headers;
+    use iron::response::{Response, WriteBody};
+    use iron::status;
+    use tempdir::TempDir;
+
+    use super::{artifact_from, authorization_failure, spec_from_str, tokens_match, EventStreams,
+                MAX_EVENT_STREAMS};
+
+    fn body_of(mut response: Response) -> String {
+        let mut body = Vec::new();
+        response.body.take().unwrap().write_body(&mut body).unwrap();
+        String::from_utf8(body).unwrap()
+    }
+
+    fn shared_cache() -> (TempDir, PackageIdent, PackageTarget) {
+        let cache = TempDir::new("artifacts").unwrap();
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170101000000").unwrap();
+        let target = PackageTarget::from_str("x86_64-linux").unwrap();
+        let archive_name = ident.archive_name_with_target(&target).unwrap();
+        File::create(cache.path().join(archive_name))
+            .unwrap()
+            .write_all(b"hello world")
+            .unwrap();
+        (cache, ident, target)
+    }
+
+    #[test]
+    fn tokens_match_only_identical_tokens() {
+        assert!(tokens_match("s3cr3t", "s3cr3t"));
+        assert!(!tokens_match("s3cr3T", "s3cr3t"));
+        assert!(!tokens_match("s3cr3", "s3cr3t"));
+        assert!(!tokens_match("s3cr3tt", "s3cr3t"));
+        assert!(!tokens_match("", "s3cr3t"));
+    }
+
+    #[test]
+    fn authorization_is_refused_without_a_configured_token() {
+        assert_eq!(
+            authorization_failure(None, Some("s3cr3t")),
+            Some(status::Forbidden)
+        );
+        assert_eq!(authorization_failure(None, None), Some(status::Forbidden));
+    }
+
+    #[test]
+    fn authorization_requires_the_configured_token() {
+        assert_eq!(authorization_failure(Some("s3cr3t"), Some("s3cr3t")), None);
+        assert_eq!(
+            authorization_failure(Some("s3cr3t"), Some("guess")),
+            Some(status::Unauthorized)
+        );
+        assert_eq!(
+            authorization_failure(Some("s3cr3t"), None),
+            Some(status::Unauthorized)
+        );
+    }
+
+    #[test]
+    fn spec_from_str_parses_a_spec() {
+        let spec = spec_from_str(r#"{"ident": "core/redis/3.2.4", "group": "prod"}"#).unwrap();
+        assert_eq!(spec.ident.to_string(), "core/redis/3.2.4");
+        assert_eq!(spec.group, "prod");
+    }
+
+    #[test]
+    fn spec_from_str_rejects_invalid_specs() {
+        assert!(spec_from_str("").is_none());
+        assert!(spec_from_str("not json").is_none());
+        assert!(spec_from_str(r#"{"group": "prod"}"#).is_none());
+        assert!(spec_from_str(r#"{"ident": "core/redis/3.2.4/extra/parts"}"#).is_none());
+    }
+
+    #[test]
+    fn event_streams_receive_published_events() {
+        let streams = EventStreams::new(EventStream::default());
+        let mut body = streams.open().unwrap();
+        streams.events.publish(Event::MemberHealth {
+            member_id: String::from("a2c1f4e2"),
+            health: Health::Suspect,
+        });
+        // Hang up the stream, so the body returns once it has written what was published
+        drop(streams);
+        let mut written = Vec::new();
+        body.write_body(&mut written).unwrap();
+        let event = r#"{"event":"MemberHealth","member_id":"a2c1f4e2","health":"Suspect"}"#;
+        assert_eq!(
+            String::from_utf8(written).unwrap(),
+            format!("data: {}\n\n", event)
+        );
+    }
+
+    #[test]
+    fn event_streams_are_capped() {
+        let streams = EventStreams::new(EventStream::default());
+        let mut open: Vec<_> = (0..MAX_EVENT_STREAMS)
+            .map(|_| streams.open().unwrap())
+            .collect();
+        assert!(streams.open().is_none());
+        open.pop();
+        assert!(streams.open().is_some());
+    }
+
+    #[test]
+    fn artifacts_are_served_from_the_shared_cache() {
+        let (cache, ident, target) = shared_cache();
+        let response = artifact_from(Some(cache.path()), &ident, &target, 0);
+        assert_eq!(response.status, Some(status::Ok));
+        assert_eq!(body_of(response), "hello world");
+
+        let missing = PackageIdent::from_str("core/redis/3.2.4/20170202000000").unwrap();
+        let response = artifact_from(Some(cache.path()), &missing, &target, 0);
+        assert_eq!(response.status, Some(status::NotFound));
+
+        let partial = PackageIdent::from_str("core/redis").unwrap();
+        let response = artifact_from(Some(cache.path()), &partial, &target, 0);
+        assert_eq!(response.status, Some(status::BadRequest));
+    }
+
+    #[test]
+    fn artifacts_are_not_served_unless_the_cache_is_shared() {
+        let (_cache, ident, target) = shared_cache();
+        let response = artifact_from(None, &ident, &target, 0);
+        assert_eq!(response.status, Some(status::NotFound));
+        assert!(response.body.is_none());
+    }
+
+    #[test]
+    fn artifacts_are_served_from_the_requested_byte() {
+        let (cache, ident, target) = shared_cache();
+        let response = artifact_from(Some(cache.path()), &ident, &target, 6);
+        assert_eq!(response.status, Some(status::PartialContent));
+        assert_eq!(
+            response.headers.get::<headers::ContentRange>(),
+            Some(&headers::ContentRange(headers::ContentRangeSpec::Bytes {
+                range: Some((6, 10)),
+                instance_length: Some(11),
+            }))
+        );
+        assert_eq!(body_of(response), "world");
+
+        let response = artifact_from(Some(cache.path()), &ident, &target, 11);
+        assert_eq!(response.status, Some(status::RangeNotSatisfiable));
+    }
+}
@@ -54,8 +54,11 @@ extern crate habitat_common as common;
 extern crate habitat_core as hcore;
 extern crate habitat_depot_client as depot_client;
 extern crate habitat_eventsrv_client as eventsrv_client;
+extern crate habitat_http_client as http_client;
 extern crate habitat_launcher_client as launcher_client;
 extern crate handlebars;
+#[macro_use]
+extern crate hyper;
 extern crate iron;
 #[macro_use]
 extern crate lazy_static;
@@ -47,6 +47,7 @@ use hcore::crypto::{self, default_cache_key_path, SymKey};
 use hcore::crypto::dpapi::encrypt;
 use hcore::env as henv;
 use hcore::fs;
+use hcore::output::{Level, StructuredOutput};
 use hcore::package::PackageIdent;
 use hcore::package::install::PackageInstall;
 use hcore::package::metadata::{BindMapping, PackageType};
@@ -64,8 +65,11 @@ use sup::command;
 use sup::http_gateway;
 use sup::http_gateway::ListenAddr;
 use sup::manager::{Manager, ManagerConfig, ServiceStatus};
-use sup::manager::service::{DesiredState, ServiceBind, Topology, UpdateStrategy};
-use sup::manager::service::{CompositeSpec, ServiceSpec, StartStyle};
+use sup::manager::service::{DesiredState, RestartPolicy, ServiceBind, Topology,
+                            UpdateStrategy};
+use sup::manager::service::{CompositeSpec, HealthProbe, ServiceSpec, StartStyle};
+use sup::command::render::{PlaceholderSecrets, SimulatedCensus};
+use sup::templating::secrets::{self, SecretsConfig};
 use sup::util;
 
 /// Our output key
@@ -73,10 +77,26 @@ static LOGKEY: &'static str = "MN";
 
 static RING_ENVVAR: &'static str = "HAB_RING";
 static RING_KEY_ENVVAR: &'static str = "HAB_RING_KEY";
+static HTTP_AUTH_TOKEN_ENVVAR: &'static str = "HAB_SUP_HTTP_AUTH_TOKEN";
+static VAULT_TOKEN_ENVVAR: &'static str = "VAULT_TOKEN";
 
 fn main() {
     if let Err(err) = start() {
-        println!("{}", err);
+        if hcore::output::is_json() {
+            let content = err.to_string();
+            let mut so = StructuredOutput::new(
+                hcore::PROGRAM_NAME.as_str(),
+                LOGKEY,
+                line!(),
+                file!(),
+                column!(),
+                &content,
+            );
+            so.level = Level::Error;
+            println!("{}", so);
+        } else {
+            println!("{}", err);
+        }
         match err {
             SupError { err: Error::ProcessLocked(_), .. } => process::exit(ERR_NO_RETRY_EXCODE),
             SupError { err: Error::Departed, .. } => {
@@ -121,6 +141,7 @@ fn start() -> Result<()> {
     match app_matches.subcommand() {
         ("bash", Some(m)) => sub_bash(m),
         ("config", Some(m)) => sub_config(m),
+        ("render", Some(m)) => sub_render(m),
         ("load", Some(m)) => sub_load(m),
         ("run", Some(m)) => {
             let launcher = launcher.ok_or(sup_error!(Error::NoLauncher))?;
@@ -149,6 +170,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
         (@setting SubcommandRequiredElseHelp)
         (@arg VERBOSE: -v +global "Verbose output; shows line numbers")
         (@arg NO_COLOR: --("no-color") +global "Turn ANSI color off")
+        (@arg LOG_FORMAT: --("log-format") +global +takes_value {valid_log_format}
+            "Format of the Supervisor's and services' output; [default: text] \
+            [values: text, json]")
         (@subcommand bash =>
             (about: "Start an interactive Bash-like shell")
             (aliases: &["b", "ba", "bas"])
@@ -159,6 +183,29 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg PKG_IDENT: +required +takes_value
                 "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
         )
+        (@subcommand render =>
+            (about: "Renders the configuration templates and hooks of an installed package \
+                against a simulated census, without loading the service")
+            (aliases: &["re", "ren", "rend", "rende"])
+            (@arg PKG_IDENT: +required +takes_value
+                "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+            (@arg DEST_DIR: --dest -d +takes_value
+                "Directory the rendered config/ and hooks/ are written to [default: .]")
+            (@arg GROUP: --group +takes_value
+                "The service group; shared config and topology [default: default].")
+            (@arg BIND: --bind +takes_value +multiple
+                "One or more service groups to bind to a configuration")
+            (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
+                "Use package config from this path, rather than the package itself")
+            (@arg USER_CONFIG: --("user-config") +takes_value {file_exists}
+                "Render with the configuration overrides of this user.toml")
+            (@arg CENSUS: --census +takes_value {file_exists}
+                "A TOML or JSON file describing the census members, leaders and configuration \
+                of the service's group and the groups it binds to")
+            (@arg SECRETS: --secrets +takes_value {valid_secrets}
+                "Render secrets from encrypted files under a directory (file:<PATH>) or from a \
+                Vault server (vault:<URL>) rather than as placeholders")
+        )
         (@subcommand load =>
             (about: "Load a service to be started and supervised by Habitat from a package or \
                 artifact. Services started in this manner will persist through Supervisor \
@@ -182,9 +229,63 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Receive package updates from Builder at the specified URL \
                 [default: https://bldr.habitat.sh]")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
-                "Service topology; [default: none]")
+                "Service topology; [default: none] \
+                [values: standalone, leader, multi-leader, sharded]")
+            (@arg SHARDS: --shards +takes_value {valid_shards}
+                "Number of shards with the sharded topology, or of leaders with the multi-leader \
+                topology; [default: 1]")
+            (@arg LEADER_TAG: --("leader-tag") +takes_value {valid_tag}
+                "Prefer members carrying this tag (KEY=VALUE) when electing a leader")
+            (@arg ZONE_TAG: --("zone-tag") +takes_value
+                "Spread shard leaders and rolling updates across the values of this member tag")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] \
+                [values: none, at-once, rolling, canary, batched]")
+            (@arg UPDATE_CANARIES: --("update-canaries") +takes_value {valid_update_canaries}
+                "Number of members updated first with the canary strategy; [default: 1]")
+            (@arg UPDATE_BATCH_PERCENT: --("update-batch-percent") +takes_value
+                {valid_update_batch_percent}
+                "Percentage of members updated at a time with the canary or batched \
+                strategies; [default: 25]")
+            (@arg UPDATE_HEALTH_WINDOW: --("update-health-window") +takes_value
+                {valid_update_health_window}
+                "Seconds each stage of a canary or batched update must stay healthy before the \
+                next stage updates; [default: 120]")
+            (@arg ROLLBACK_THRESHOLD: --("rollback-threshold") +takes_value
+                {valid_rollback_threshold}
+                "Failed health checks or restarts of an updated release before rolling back to \
+                the previous release, 0 disables rollback; [default: 3]")
+            (@arg HEALTH_PROBE: --("health-probe") +takes_value +multiple {valid_health_probe}
+                "One or more built-in health probes checked alongside the health_check hook, in \
+                the form <KIND>:<TARGET>[,interval=<SECS>][,timeout=<SECS>][,threshold=<N>]\
+                [,status=<CODE>] where KIND is http, tcp or exec \
+                (ex: http:http://localhost:8080/health,status=204)")
+            (@arg RESTART_POLICY: --("restart-policy") +takes_value {valid_restart_policy}
+                "When to restart the service after its process exits; [default: always] \
+                [values: always, on-failure, never]")
+            (@arg RESTART_MAX_RETRIES: --("restart-max-retries") +takes_value
+                {valid_restart_max_retries}
+                "Restarts allowed within the restart window before the service is marked as \
+                failed, 0 allows unlimited restarts; [default: 10]")
+            (@arg RESTART_WINDOW: --("restart-window") +takes_value {valid_restart_window}
+                "Seconds over which restarts are counted against the maximum; [default: 300]")
+            (@arg RESTART_BACKOFF: --("restart-backoff") +takes_value {valid_restart_backoff}
+                "Seconds to wait before restarting, doubled with each restart within the \
+                restart window; [default: 1]")
+            (@arg RESTART_BACKOFF_MAX: --("restart-backoff-max") +takes_value
+                {valid_restart_backoff}
+                "Maximum seconds to wait before restarting; [default: 60]")
+            (@arg CPU_SHARES: --("cpu-shares") +takes_value {valid_cpu_shares}
+                "Relative CPU weight of the service, in cgroup cpu.shares units (2-262144) \
+                [default: 1024]")
+            (@arg CPU_QUOTA: --("cpu-quota") +takes_value {valid_cpu_quota}
+                "Limit the service to this many CPUs (ex: 0.5)")
+            (@arg MEMORY_LIMIT: --("memory-limit") +takes_value {valid_memory_limit}
+                "Limit the memory of the service, in bytes or with a K, M or G suffix (ex: 512M)")
+            (@arg PIDS_LIMIT: --("pids-limit") +takes_value {valid_pids_limit}
+                "Limit the number of processes of the service")
+            (@arg NOFILE_LIMIT: --("nofile-limit") +takes_value {valid_nofile_limit}
+                "Limit the number of open files of each process of the service")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
             (@arg FORCE: --force -f "Load or reload an already loaded service. If the service was \
@@ -219,6 +320,35 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Watch this file for connecting to the ring"
             )
             (@arg RING: --ring -r +takes_value "Ring key name")
+            (@arg FEDERATION_RING: --("federation-ring") +takes_value
+                "The name of this Supervisor's ring, when rings in several regions are federated")
+            (@arg FEDERATION_GATEWAY: --("federation-gateway") +takes_value +multiple
+                requires[FEDERATION_RING]
+                "Relay the services of the federated service groups to the gateway of a peer \
+                ring at this listen address (IP[:PORT])")
+            (@arg FEDERATE: --federate +takes_value +multiple {valid_service_group}
+                requires[FEDERATION_GATEWAY]
+                "One or more service groups whose services and configuration are relayed to \
+                peer rings")
+            (@arg TAG: --tag +takes_value +multiple {valid_tag}
+                "One or more tags (KEY=VALUE) gossiped with this Supervisor's membership, \
+                such as its rack or availability zone")
+            (@arg RUMOR_TTL: --("rumor-ttl") +takes_value {valid_ttl}
+                "Seconds the rumors of departed members and unloaded services are kept before \
+                they expire [default: 86400]")
+            (@arg TOMBSTONE_TTL: --("tombstone-ttl") +takes_value {valid_ttl}
+                "Seconds an expired rumor is remembered, so gossip does not bring it back \
+                [default: 604800]")
+            (@arg TRUSTED_IDENTITIES: --("trusted-identities") +takes_value {dir_exists}
+                "Accept gossip signed only by the identities whose public keys are in this \
+                directory")
+            (@arg SHARE_ARTIFACTS: --("share-artifacts")
+                "Serve this Supervisor's artifact cache from its HTTP gateway, for peers which \
+                list it in HAB_ARTIFACT_PEERS")
+            (@arg SECRETS: --secrets +takes_value {valid_secrets}
+                "Render secrets in templates from encrypted files under a directory \
+                (file:<PATH>) or from a Vault server (vault:<URL>), authenticating with the \
+                VAULT_TOKEN environment variable")
             (@arg CHANNEL: --channel +takes_value
                 "Receive Supervisor updates from the specified release channel [default: stable]")
             (@arg BLDR_URL: --url -u +takes_value {valid_url}
@@ -255,6 +385,35 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Watch this file for connecting to the ring"
             )
             (@arg RING: --ring -r +takes_value "Ring key name")
+            (@arg FEDERATION_RING: --("federation-ring") +takes_value
+                "The name of this Supervisor's ring, when rings in several regions are federated")
+            (@arg FEDERATION_GATEWAY: --("federation-gateway") +takes_value +multiple
+                requires[FEDERATION_RING]
+                "Relay the services of the federated service groups to the gateway of a peer \
+                ring at this listen address (IP[:PORT])")
+            (@arg FEDERATE: --federate +takes_value +multiple {valid_service_group}
+                requires[FEDERATION_GATEWAY]
+                "One or more service groups whose services and configuration are relayed to \
+                peer rings")
+            (@arg TAG: --tag +takes_value +multiple {valid_tag}
+                "One or more tags (KEY=VALUE) gossiped with this Supervisor's membership, \
+                such as its rack or availability zone")
+            (@arg RUMOR_TTL: --("rumor-ttl") +takes_value {valid_ttl}
+                "Seconds the rumors of departed members and unloaded services are kept before \
+                they expire [default: 86400]")
+            (@arg TOMBSTONE_TTL: --("tombstone-ttl") +takes_value {valid_ttl}
+                "Seconds an expired rumor is remembered, so gossip does not bring it back \
+                [default: 604800]")
+            (@arg TRUSTED_IDENTITIES: --("trusted-identities") +takes_value {dir_exists}
+                "Accept gossip signed only by the identities whose public keys are in this \
+                directory")
+            (@arg SHARE_ARTIFACTS: --("share-artifacts")
+                "Serve this Supervisor's artifact cache from its HTTP gateway, for peers which \
+                list it in HAB_ARTIFACT_PEERS")
+            (@arg SECRETS: --secrets +takes_value {valid_secrets}
+                "Render secrets in templates from encrypted files under a directory \
+                (file:<PATH>) or from a Vault server (vault:<URL>), authenticating with the \
+                VAULT_TOKEN environment variable")
             (@arg PKG_IDENT_OR_ARTIFACT: +required +takes_value
                 "A Habitat package identifier (ex: core/redis) or filepath to a Habitat Artifact \
                 (ex: /home/core-redis-3.0.7-21120102031201-x86_64-linux.hart)")
@@ -270,9 +429,63 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Receive package updates from Builder at the specified URL \
                 [default: https://bldr.habitat.sh]")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
-                "Service topology; [default: none]")
+                "Service topology; [default: none] \
+                [values: standalone, leader, multi-leader, sharded]")
+            (@arg SHARDS: --shards +takes_value {valid_shards}
+                "Number of shards with the sharded topology, or of leaders with the multi-leader \
+                topology; [default: 1]")
+            (@arg LEADER_TAG: --("leader-tag") +takes_value {valid_tag}
+                "Prefer members carrying this tag (KEY=VALUE) when electing a leader")
+            (@arg ZONE_TAG: --("zone-tag") +takes_value
+                "Spread shard leaders and rolling updates across the values of this member tag")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] \
+                [values: none, at-once, rolling, canary, batched]")
+            (@arg UPDATE_CANARIES: --("update-canaries") +takes_value {valid_update_canaries}
+                "Number of members updated first with the canary strategy; [default: 1]")
+            (@arg UPDATE_BATCH_PERCENT: --("update-batch-percent") +takes_value
+                {valid_update_batch_percent}
+                "Percentage of members updated at a time with the canary or batched \
+                strategies; [default: 25]")
+            (@arg UPDATE_HEALTH_WINDOW: --("update-health-window") +takes_value
+                {valid_update_health_window}
+                "Seconds each stage of a canary or batched update must stay healthy before the \
+                next stage updates; [default: 120]")
+            (@arg ROLLBACK_THRESHOLD: --("rollback-threshold") +takes_value
+                {valid_rollback_threshold}
+                "Failed health checks or restarts of an updated release before rolling back to \
+                the previous release, 0 disables rollback; [default: 3]")
+            (@arg HEALTH_PROBE: --("health-probe") +takes_value +multiple {valid_health_probe}
+                "One or more built-in health probes checked alongside the health_check hook, in \
+                the form <KIND>:<TARGET>[,interval=<SECS>][,timeout=<SECS>][,threshold=<N>]\
+                [,status=<CODE>] where KIND is http, tcp or exec \
+                (ex: http:http://localhost:8080/health,status=204)")
+            (@arg RESTART_POLICY: --("restart-policy") +takes_value {valid_restart_policy}
+                "When to restart the service after its process exits; [default: always] \
+                [values: always, on-failure, never]")
+            (@arg RESTART_MAX_RETRIES: --("restart-max-retries") +takes_value
+                {valid_restart_max_retries}
+                "Restarts allowed within the restart window before the service is marked as \
+                failed, 0 allows unlimited restarts; [default: 10]")
+            (@arg RESTART_WINDOW: --("restart-window") +takes_value {valid_restart_window}
+                "Seconds over which restarts are counted against the maximum; [default: 300]")
+            (@arg RESTART_BACKOFF: --("restart-backoff") +takes_value {valid_restart_backoff}
+                "Seconds to wait before restarting, doubled with each restart within the \
+                restart window; [default: 1]")
+            (@arg RESTART_BACKOFF_MAX: --("restart-backoff-max") +takes_value
+                {valid_restart_backoff}
+                "Maximum seconds to wait before restarting; [default: 60]")
+            (@arg CPU_SHARES: --("cpu-shares") +takes_value {valid_cpu_shares}
+                "Relative CPU weight of the service, in cgroup cpu.shares units (2-262144) \
+                [default: 1024]")
+            (@arg CPU_QUOTA: --("cpu-quota") +takes_value {valid_cpu_quota}
+                "Limit the service to this many CPUs (ex: 0.5)")
+            (@arg MEMORY_LIMIT: --("memory-limit") +takes_value {valid_memory_limit}
+                "Limit the memory of the service, in bytes or with a K, M or G suffix (ex: 512M)")
+            (@arg PIDS_LIMIT: --("pids-limit") +takes_value {valid_pids_limit}
+                "Limit the number of processes of the service")
+            (@arg NOFILE_LIMIT: --("nofile-limit") +takes_value {valid_nofile_limit}
+                "Limit the number of open files of each process of the service")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
             (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
@@ -316,6 +529,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
         (@setting SubcommandRequiredElseHelp)
         (@arg VERBOSE: -v +global "Verbose output; shows line numbers")
         (@arg NO_COLOR: --("no-color") +global "Turn ANSI color off")
+        (@arg LOG_FORMAT: --("log-format") +global +takes_value {valid_log_format}
+            "Format of the Supervisor's and services' output; [default: text] \
+            [values: text, json]")
         (@subcommand bash =>
             (about: "Start an interactive Bash-like shell")
             (aliases: &["b", "ba", "bas"])
@@ -326,6 +542,29 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg PKG_IDENT: +required +takes_value
                 "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
         )
+        (@subcommand render =>
+            (about: "Renders the configuration templates and hooks of an installed package \
+                against a simulated census, without loading the service")
+            (aliases: &["re", "ren", "rend", "rende"])
+            (@arg PKG_IDENT: +required +takes_value
+                "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+            (@arg DEST_DIR: --dest -d +takes_value
+                "Directory the rendered config/ and hooks/ are written to [default: .]")
+            (@arg GROUP: --group +takes_value
+                "The service group; shared config and topology [default: default].")
+            (@arg BIND: --bind +takes_value +multiple
+                "One or more service groups to bind to a configuration")
+            (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
+                "Use package config from this path, rather than the package itself")
+            (@arg USER_CONFIG: --("user-config") +takes_value {file_exists}
+                "Render with the configuration overrides of this user.toml")
+            (@arg CENSUS: --census +takes_value {file_exists}
+                "A TOML or JSON file describing the census members, leaders and configuration \
+                of the service's group and the groups it binds to")
+            (@arg SECRETS: --secrets +takes_value {valid_secrets}
+                "Render secrets from encrypted files under a directory (file:<PATH>) or from a \
+                Vault server (vault:<URL>) rather than as placeholders")
+        )
         (@subcommand load =>
             (about: "Load a service to be started and supervised by Habitat from a package or \
                 artifact. Services started in this manner will persist through Supervisor \
@@ -349,9 +588,63 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Receive package updates from Builder at the specified URL \
                 [default: https://bldr.habitat.sh]")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
-                "Service topology; [default: none]")
+                "Service topology; [default: none] \
+                [values: standalone, leader, multi-leader, sharded]")
+            (@arg SHARDS: --shards +takes_value {valid_shards}
+                "Number of shards with the sharded topology, or of leaders with the multi-leader \
+                topology; [default: 1]")
+            (@arg LEADER_TAG: --("leader-tag") +takes_value {valid_tag}
+                "Prefer members carrying this tag (KEY=VALUE) when electing a leader")
+            (@arg ZONE_TAG: --("zone-tag") +takes_value
+                "Spread shard leaders and rolling updates across the values of this member tag")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] \
+                [values: none, at-once, rolling, canary, batched]")
+            (@arg UPDATE_CANARIES: --("update-canaries") +takes_value {valid_update_canaries}
+                "Number of members updated first with the canary strategy; [default: 1]")
+            (@arg UPDATE_BATCH_PERCENT: --("update-batch-percent") +takes_value
+                {valid_update_batch_percent}
+                "Percentage of members updated at a time with the canary or batched \
+                strategies; [default: 25]")
+            (@arg UPDATE_HEALTH_WINDOW: --("update-health-window") +takes_value
+                {valid_update_health_window}
+                "Seconds each stage of a canary or batched update must stay healthy before the \
+                next stage updates; [default: 120]")
+            (@arg ROLLBACK_THRESHOLD: --("rollback-threshold") +takes_value
+                {valid_rollback_threshold}
+                "Failed health checks or restarts of an updated release before rolling back to \
+                the previous release, 0 disables rollback; [default: 3]")
+            (@arg HEALTH_PROBE: --("health-probe") +takes_value +multiple {valid_health_probe}
+                "One or more built-in health probes checked alongside the health_check hook, in \
+                the form <KIND>:<TARGET>[,interval=<SECS>][,timeout=<SECS>][,threshold=<N>]\
+                [,status=<CODE>] where KIND is http, tcp or exec \
+                (ex: http:http://localhost:8080/health,status=204)")
+            (@arg RESTART_POLICY: --("restart-policy") +takes_value {valid_restart_policy}
+                "When to restart the service after its process exits; [default: always] \
+                [values: always, on-failure, never]")
+            (@arg RESTART_MAX_RETRIES: --("restart-max-retries") +takes_value
+                {valid_restart_max_retries}
+                "Restarts allowed within the restart window before the service is marked as \
+                failed, 0 allows unlimited restarts; [default: 10]")
+            (@arg RESTART_WINDOW: --("restart-window") +takes_value {valid_restart_window}
+                "Seconds over which restarts are counted against the maximum; [default: 300]")
+            (@arg RESTART_BACKOFF: --("restart-backoff") +takes_value {valid_restart_backoff}
+                "Seconds to wait before restarting, doubled with each restart within the \
+                restart window; [default: 1]")
+            (@arg RESTART_BACKOFF_MAX: --("restart-backoff-max") +takes_value
+                {valid_restart_backoff}
+                "Maximum seconds to wait before restarting; [default: 60]")
+            (@arg CPU_SHARES: --("cpu-shares") +takes_value {valid_cpu_shares}
+                "Relative CPU weight of the service, in cgroup cpu.shares units (2-262144) \
+                [default: 1024]")
+            (@arg CPU_QUOTA: --("cpu-quota") +takes_value {valid_cpu_quota}
+                "Limit the service to this many CPUs (ex: 0.5)")
+            (@arg MEMORY_LIMIT: --("memory-limit") +takes_value {valid_memory_limit}
+                "Limit the memory of the service, in bytes or with a K, M or G suffix (ex: 512M)")
+            (@arg PIDS_LIMIT: --("pids-limit") +takes_value {valid_pids_limit}
+                "Limit the number of processes of the service")
+            (@arg NOFILE_LIMIT: --("nofile-limit") +takes_value {valid_nofile_limit}
+                "Limit the number of open files of each process of the service")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
             (@arg FORCE: --force -f "Load or reload an already loaded service. If the service was \
@@ -388,6 +681,35 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Watch this file for connecting to the ring"
             )
             (@arg RING: --ring -r +takes_value "Ring key name")
+            (@arg FEDERATION_RING: --("federation-ring") +takes_value
+                "The name of this Supervisor's ring, when rings in several regions are federated")
+            (@arg FEDERATION_GATEWAY: --("federation-gateway") +takes_value +multiple
+                requires[FEDERATION_RING]
+                "Relay the services of the federated service groups to the gateway of a peer \
+                ring at this listen address (IP[:PORT])")
+            (@arg FEDERATE: --federate +takes_value +multiple {valid_service_group}
+                requires[FEDERATION_GATEWAY]
+                "One or more service groups whose services and configuration are relayed to \
+                peer rings")
+            (@arg TAG: --tag +takes_value +multiple {valid_tag}
+                "One or more tags (KEY=VALUE) gossiped with this Supervisor's membership, \
+                such as its rack or availability zone")
+            (@arg RUMOR_TTL: --("rumor-ttl") +takes_value {valid_ttl}
+                "Seconds the rumors of departed members and unloaded services are kept before \
+                they expire [default: 86400]")
+            (@arg TOMBSTONE_TTL: --("tombstone-ttl") +takes_value {valid_ttl}
+                "Seconds an expired rumor is remembered, so gossip does not bring it back \
+                [default: 604800]")
+            (@arg TRUSTED_IDENTITIES: --("trusted-identities") +takes_value {dir_exists}
+                "Accept gossip signed only by the identities whose public keys are in this \
+                directory")
+            (@arg SHARE_ARTIFACTS: --("share-artifacts")
+                "Serve this Supervisor's artifact cache from its HTTP gateway, for peers which \
+                list it in HAB_ARTIFACT_PEERS")
+            (@arg SECRETS: --secrets +takes_value {valid_secrets}
+                "Render secrets in templates from encrypted files under a directory \
+                (file:<PATH>) or from a Vault server (vault:<URL>), authenticating with the \
+                VAULT_TOKEN environment variable")
             (@arg CHANNEL: --channel +takes_value
                 "Receive Supervisor updates from the specified release channel [default: stable]")
             (@arg BLDR_URL: --url -u +takes_value {valid_url}
@@ -424,6 +746,35 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Watch this file for connecting to the ring"
             )
             (@arg RING: --ring -r +takes_value "Ring key name")
+            (@arg FEDERATION_RING: --("federation-ring") +takes_value
+                "The name of this Supervisor's ring, when rings in several regions are federated")
+            (@arg FEDERATION_GATEWAY: --("federation-gateway") +takes_value +multiple
+                requires[FEDERATION_RING]
+                "Relay the services of the federated service groups to the gateway of a peer \
+                ring at this listen address (IP[:PORT])")
+            (@arg FEDERATE: --federate +takes_value +multiple {valid_service_group}
+                requires[FEDERATION_GATEWAY]
+                "One or more service groups whose services and configuration are relayed to \
+                peer rings")
+            (@arg TAG: --tag +takes_value +multiple {valid_tag}
+                "One or more tags (KEY=VALUE) gossiped with this Supervisor's membership, \
+                such as its rack or availability zone")
+            (@arg RUMOR_TTL: --("rumor-ttl") +takes_value {valid_ttl}
+                "Seconds the rumors of departed members and unloaded services are kept before \
+                they expire [default: 86400]")
+            (@arg TOMBSTONE_TTL: --("tombstone-ttl") +takes_value {valid_ttl}
+                "Seconds an expired rumor is remembered, so gossip does not bring it back \
+                [default: 604800]")
+            (@arg TRUSTED_IDENTITIES: --("trusted-identities") +takes_value {dir_exists}
+                "Accept gossip signed only by the identities whose public keys are in this \
+                directory")
+            (@arg SHARE_ARTIFACTS: --("share-artifacts")
+                "Serve this Supervisor's artifact cache from its HTTP gateway, for peers which \
+                list it in HAB_ARTIFACT_PEERS")
+            (@arg SECRETS: --secrets +takes_value {valid_secrets}
+                "Render secrets in templates from encrypted files under a directory \
+                (file:<PATH>) or from a Vault server (vault:<URL>), authenticating with the \
+                VAULT_TOKEN environment variable")
             (@arg PKG_IDENT_OR_ARTIFACT: +required +takes_value
                 "A Habitat package identifier (ex: core/redis) or filepath to a Habitat Artifact \
                 (ex: /home/core-redis-3.0.7-21120102031201-x86_64-linux.hart)")
@@ -439,9 +790,63 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Receive package updates from Builder at the specified URL \
                 [default: https://bldr.habitat.sh]")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
-                "Service topology; [default: none]")
+                "Service topology; [default: none] \
+                [values: standalone, leader, multi-leader, sharded]")
+            (@arg SHARDS: --shards +takes_value {valid_shards}
+                "Number of shards with the sharded topology, or of leaders with the multi-leader \
+                topology; [default: 1]")
+            (@arg LEADER_TAG: --("leader-tag") +takes_value {valid_tag}
+                "Prefer members carrying this tag (KEY=VALUE) when electing a leader")
+            (@arg ZONE_TAG: --("zone-tag") +takes_value
+                "Spread shard leaders and rolling updates across the values of this member tag")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] \
+                [values: none, at-once, rolling, canary, batched]")
+            (@arg UPDATE_CANARIES: --("update-canaries") +takes_value {valid_update_canaries}
+                "Number of members updated first with the canary strategy; [default: 1]")
+            (@arg UPDATE_BATCH_PERCENT: --("update-batch-percent") +takes_value
+                {valid_update_batch_percent}
+                "Percentage of members updated at a time with the canary or batched \
+                strategies; [default: 25]")
+            (@arg UPDATE_HEALTH_WINDOW: --("update-health-window") +takes_value
+                {valid_update_health_window}
+                "Seconds each stage of a canary or batched update must stay healthy before the \
+                next stage updates; [default: 120]")
+            (@arg ROLLBACK_THRESHOLD: --("rollback-threshold") +takes_value
+                {valid_rollback_threshold}
+                "Failed health checks or restarts of an updated release before rolling back to \
+                the previous release, 0 disables rollback; [default: 3]")
+            (@arg HEALTH_PROBE: --("health-probe") +takes_value +multiple {valid_health_probe}
+                "One or more built-in health probes checked alongside the health_check hook, in \
+                the form <KIND>:<TARGET>[,interval=<SECS>][,timeout=<SECS>][,threshold=<N>]\
+                [,status=<CODE>] where KIND is http, tcp or exec \
+                (ex: http:http://localhost:8080/health,status=204)")
+            (@arg RESTART_POLICY: --("restart-policy") +takes_value {valid_restart_policy}
+                "When to restart the service after its process exits; [default: always] \
+                [values: always, on-failure, never]")
+            (@arg RESTART_MAX_RETRIES: --("restart-max-retries") +takes_value
+                {valid_restart_max_retries}
+                "Restarts allowed within the restart window before the service is marked as \
+                failed, 0 allows unlimited restarts; [default: 10]")
+            (@arg RESTART_WINDOW: --("restart-window") +takes_value {valid_restart_window}
+                "Seconds over which restarts are counted against the maximum; [default: 300]")
+            (@arg RESTART_BACKOFF: --("restart-backoff") +takes_value {valid_restart_backoff}
+                "Seconds to wait before restarting, doubled with each restart within the \
+                restart window; [default: 1]")
+            (@arg RESTART_BACKOFF_MAX: --("restart-backoff-max") +takes_value
+                {valid_restart_backoff}
+                "Maximum seconds to wait before restarting; [default: 60]")
+            (@arg CPU_SHARES: --("cpu-shares") +takes_value {valid_cpu_shares}
+                "Relative CPU weight of the service, in cgroup cpu.shares units (2-262144) \
+                [default: 1024]")
+            (@arg CPU_QUOTA: --("cpu-quota") +takes_value {valid_cpu_quota}
+                "Limit the service to this many CPUs (ex: 0.5)")
+            (@arg MEMORY_LIMIT: --("memory-limit") +takes_value {valid_memory_limit}
+                "Limit the memory of the service, in bytes or with a K, M or G suffix (ex: 512M)")
+            (@arg PIDS_LIMIT: --("pids-limit") +takes_value {valid_pids_limit}
+                "Limit the number of processes of the service")
+            (@arg NOFILE_LIMIT: --("nofile-limit") +takes_value {valid_nofile_limit}
+                "Limit the number of open files of each process of the service")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
             (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
@@ -472,8 +877,7 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
 }
 
 fn sub_bash(m: &ArgMatches) -> Result<()> {
-    toggle_verbosity(m);
-    toggle_color(m);
+    toggle_output(m);
 
     command::shell::bash()
 }
@@ -485,9 +889,43 @@ fn sub_config(m: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+fn sub_render(m: &ArgMatches) -> Result<()> {
+    toggle_output(m);
+
+    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
+    let mut binds = Vec::new();
+    if let Some(bind_strs) = m.values_of("BIND") {
+        for bind_str in bind_strs {
+            binds.push(ServiceBind::from_str(bind_str)?);
+        }
+    }
+    let census = match m.value_of("CENSUS") {
+        Some(path) => SimulatedCensus::from_file(path)?,
+        None => SimulatedCensus::default(),
+    };
+    let provider = match m.value_of("SECRETS") {
+        // unwrap() is safe, because the input is validated by `valid_secrets`
+        Some(secrets) => {
+            SecretsConfig::from_str(secrets).unwrap().provider(
+                henv::var(VAULT_TOKEN_ENVVAR).ok(),
+            )?
+        }
+        None => Box::new(PlaceholderSecrets),
+    };
+    secrets::set_provider(provider);
+    command::render::start(
+        &ident,
+        m.value_of("GROUP").unwrap_or("default"),
+        &binds,
+        m.value_of("CONFIG_DIR").map(PathBuf::from).as_ref(),
+        m.value_of("USER_CONFIG").map(Path::new),
+        &census,
+        Path::new(m.value_of("DEST_DIR").unwrap_or(".")),
+    )
+}
+
 fn sub_load(m: &ArgMatches) -> Result<()> {
-    toggle_verbosity(m);
-    toggle_color(m);
+    toggle_output(m);
 
     let cfg = mgrcfg_from_matches(m)?;
     let install_source = install_source_from_input(m)?;
@@ -674,8 +1112,7 @@ fn sub_load(m: &ArgMatches) -> Result<()> {
 }
 
 fn sub_unload(m: &ArgMatches) -> Result<()> {
-    toggle_verbosity(m);
-    toggle_color(m);
+    toggle_output(m);
 
     let cfg = mgrcfg_from_matches(m)?;
     let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
@@ -706,21 +1143,21 @@ fn sub_unload(m: &ArgMatches) -> Result<()> {
 }
 
 fn sub_run(m: &ArgMatches, launcher: LauncherCli) -> Result<()> {
+    toggle_output(m);
+
     let cfg = mgrcfg_from_matches(m)?;
     let mut manager = Manager::load(cfg, launcher)?;
     manager.run()
 }
 
 fn sub_sh(m: &ArgMatches) -> Result<()> {
-    toggle_verbosity(m);
-    toggle_color(m);
+    toggle_output(m);
 
     command::shell::sh()
 }
 
 fn sub_start(m: &ArgMatches, launcher: LauncherCli) -> Result<()> {
-    toggle_verbosity(m);
-    toggle_color(m);
+    toggle_output(m);
 
     let cfg = mgrcfg_from_matches(m)?;
 
@@ -810,8 +1247,7 @@ fn sub_start(m: &ArgMatches, launcher: LauncherCli) -> Result<()> {
 }
 
 fn sub_status(m: &ArgMatches) -> Result<()> {
-    toggle_verbosity(m);
-    toggle_color(m);
+    toggle_output(m);
 
     let cfg = mgrcfg_from_matches(m)?;
     if !Manager::is_running(&cfg)? {
@@ -866,8 +1302,7 @@ fn print_statuses(statuses: Vec<ServiceStatus>) -> Result<()> {
 }
 
 fn sub_stop(m: &ArgMatches) -> Result<()> {
-    toggle_verbosity(m);
-    toggle_color(m);
+    toggle_output(m);
 
     let cfg = mgrcfg_from_matches(m)?;
 
@@ -983,28 +1418,7 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
     }
     cfg.organization = m.value_of("ORGANIZATION").map(|org| org.to_string());
     cfg.gossip_permanent = m.is_present("PERMANENT_PEER");
-    // TODO fn: Clean this up--using a for loop doesn't feel good however an iterator was
-    // causing a lot of developer/compiler type confusion
-    let mut gossip_peers: Vec<SocketAddr> = Vec::new();
-    if let Some(peers) = m.values_of("PEER") {
-        for peer in peers {
-            let peer_addr = if peer.find(':').is_some() {
-                peer.to_string()
-            } else {
-                format!("{}:{}", peer, GOSSIP_DEFAULT_PORT)
-            };
-            let addrs: Vec<SocketAddr> = match peer_addr.to_socket_addrs() {
-                Ok(addrs) => addrs.collect(),
-                Err(e) => {
-                    outputln!("Failed to resolve peer: {}", peer_addr);
-                    return Err(sup_error!(Error::NameLookup(e)));
-                }
-            };
-            let addr: SocketAddr = addrs[0];
-            gossip_peers.push(addr);
-        }
-    }
-    cfg.gossip_peers = gossip_peers;
+    cfg.gossip_peers = gossip_addrs(m, "PEER")?;
     if let Some(watch_peer_file) = m.value_of("PEER_WATCH_FILE") {
         cfg.watch_peer_file = Some(String::from(watch_peer_file));
     }
@@ -1037,15 +1451,65 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
     if let Some(ring) = ring {
         cfg.ring = Some(ring.name_with_rev());
     }
+    cfg.federation_ring = m.value_of("FEDERATION_RING").map(|ring| ring.to_string());
+    cfg.federation_gateways = gossip_addrs(m, "FEDERATION_GATEWAY")?;
+    if let Some(groups) = m.values_of("FEDERATE") {
+        // unwrap() is safe, because the input is validated by `valid_service_group`
+        cfg.federated_groups = groups.map(|sg| ServiceGroup::from_str(sg).unwrap()).collect();
+    }
+    if let Some(tags) = m.values_of("TAG") {
+        for tag in tags {
+            // unwrap() is safe, because the input is validated by `valid_tag`
+            let (key, value) = parse_tag(tag).unwrap();
+            cfg.tags.insert(key.to_string(), value.to_string());
+        }
+    }
+    // unwrap() is safe, because the input is validated by `valid_ttl`
+    cfg.rumor_ttl = m.value_of("RUMOR_TTL").map(|ttl| ttl.parse().unwrap());
+    cfg.tombstone_ttl = m.value_of("TOMBSTONE_TTL").map(|ttl| ttl.parse().unwrap());
+    cfg.trusted_identities = m.value_of("TRUSTED_IDENTITIES").map(PathBuf::from);
+    cfg.share_artifacts = m.is_present("SHARE_ARTIFACTS");
     if let Some(events) = m.value_of("EVENTS") {
         cfg.eventsrv_group = ServiceGroup::from_str(events).ok();
     }
+    cfg.http_auth_token = henv::var(HTTP_AUTH_TOKEN_ENVVAR).ok();
+    if let Some(secrets) = m.value_of("SECRETS") {
+        // unwrap() is safe, because the input is validated by `valid_secrets`
+        cfg.secrets = Some(SecretsConfig::from_str(secrets).unwrap());
+        cfg.vault_token = henv::var(VAULT_TOKEN_ENVVAR).ok();
+    }
     Ok(cfg)
 }
 
 // Various CLI Parsing Functions
 ////////////////////////////////////////////////////////////////////////
 
+/// Resolve the gossip listen addresses given to an argument, defaulting to the gossip port.
+fn gossip_addrs(m: &ArgMatches, arg: &str) -> Result<Vec<SocketAddr>> {
+    // TODO fn: Clean this up--using a for loop doesn't feel good however an iterator was
+    // causing a lot of developer/compiler type confusion
+    let mut gossip_addrs: Vec<SocketAddr> = Vec::new();
+    if let Some(peers) = m.values_of(arg) {
+        for peer in peers {
+            let peer_addr = if peer.find(':').is_some() {
+                peer.to_string()
+            } else {
+                format!("{}:{}", peer, GOSSIP_DEFAULT_PORT)
+            };
+            let addrs: Vec<SocketAddr> = match peer_addr.to_socket_addrs() {
+                Ok(addrs) => addrs.collect(),
+                Err(e) => {
+                    outputln!("Failed to resolve peer: {}", peer_addr);
+                    return Err(sup_error!(Error::NameLookup(e)));
+                }
+            };
+            let addr: SocketAddr = addrs[0];
+            gossip_addrs.push(addr);
+        }
+    }
+    Ok(gossip_addrs)
+}
+
 /// Resolve a Builder URL. Taken from CLI args, the environment, or
 /// (failing those) a default value.
 fn bldr_url(m: &ArgMatches) -> String {
@@ -1134,23 +1598,109 @@ fn set_channel(spec: &mut ServiceSpec, m: &ArgMatches) {
     spec.channel = channel(m);
 }
 
-/// Set a topology value only if specified by the user as a CLI
-/// argument.
+/// Set a topology value, its shard count and its leader and zone tags
+/// only if specified by the user as CLI arguments.
 fn set_topology_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
     if let Some(t) = m.value_of("TOPOLOGY") {
         // unwrap() is safe, because the input is validated by
         // `valid_topology`
         spec.topology = Topology::from_str(t).unwrap();
     }
+    if let Some(s) = m.value_of("SHARDS") {
+        // unwrap() is safe, because the input is validated by `valid_shards`
+        spec.shards = s.parse().unwrap();
+    }
+    if let Some(tag) = m.value_of("LEADER_TAG") {
+        spec.leader_tag = Some(tag.to_string());
+    }
+    if let Some(tag) = m.value_of("ZONE_TAG") {
+        spec.zone_tag = Some(tag.to_string());
+    }
 }
 
-/// Set an update strategy only if specified by the user as a CLI
-/// argument.
+/// Set an update strategy and its rollout and rollback settings only
+/// if specified by the user as CLI arguments.
 fn set_strategy_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
     if let Some(s) = m.value_of("STRATEGY") {
         // unwrap() is safe, because the input is validated by `valid_update_strategy`
         spec.update_strategy = UpdateStrategy::from_str(s).unwrap();
     }
+    // unwrap() is safe for the following, because the input is validated by their respective
+    // `valid_*` functions
+    if let Some(c) = m.value_of("UPDATE_CANARIES") {
+        spec.update_canaries = c.parse().unwrap();
+    }
+    if let Some(p) = m.value_of("UPDATE_BATCH_PERCENT") {
+        spec.update_batch_percent = p.parse().unwrap();
+    }
+    if let Some(w) = m.value_of("UPDATE_HEALTH_WINDOW") {
+        spec.update_health_window = w.parse().unwrap();
+    }
+    if let Some(t) = m.value_of("ROLLBACK_THRESHOLD") {
+        spec.rollback_threshold = t.parse().unwrap();
+    }
+}
+
+fn set_restart_policy_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
+    // unwrap() is safe for the following, because the input is validated by their respective
+    // `valid_*` functions
+    if let Some(p) = m.value_of("RESTART_POLICY") {
+        spec.restart_policy = RestartPolicy::from_str(p).unwrap();
+    }
+    if let Some(r) = m.value_of("RESTART_MAX_RETRIES") {
+        spec.restart_max_retries = r.parse().unwrap();
+    }
+    if let Some(w) = m.value_of("RESTART_WINDOW") {
+        spec.restart_window = w.parse().unwrap();
+    }
+    if let Some(b) = m.value_of("RESTART_BACKOFF") {
+        spec.restart_backoff = b.parse().unwrap();
+    }
+    if let Some(b) = m.value_of("RESTART_BACKOFF_MAX") {
+        spec.restart_backoff_max = b.parse().unwrap();
+    }
+}
+
+fn set_resource_limits_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
+    // unwrap() is safe for the following, because the input is validated by their respective
+    // `valid_*` functions
+    if let Some(s) = m.value_of("CPU_SHARES") {
+        spec.cpu_shares = Some(s.parse().unwrap());
+    }
+    if let Some(q) = m.value_of("CPU_QUOTA") {
+        spec.cpu_quota = Some(parse_cpu_quota(q).unwrap());
+    }
+    if let Some(l) = m.value_of("MEMORY_LIMIT") {
+        spec.memory_limit = Some(parse_memory_size(l).unwrap());
+    }
+    if let Some(l) = m.value_of("PIDS_LIMIT") {
+        spec.pids_limit = Some(l.parse().unwrap());
+    }
+    if let Some(l) = m.value_of("NOFILE_LIMIT") {
+        spec.nofile_limit = Some(l.parse().unwrap());
+    }
+}
+
+/// Parses a number of CPUs, such as `1.5`, into thousandths of a CPU.
+fn parse_cpu_quota(val: &str) -> Option<u64> {
+    match val.parse::<f64>() {
+        Ok(cpus) if cpus >= 0.001 && cpus.is_finite() => Some((cpus * 1_000.0).round() as u64),
+        _ => None,
+    }
+}
+
+/// Parses a size in bytes, optionally with a binary `K`, `M` or `G` suffix.
+fn parse_memory_size(val: &str) -> Option<u64> {
+    let (digits, multiplier) = match val.chars().last() {
+        Some('k') | Some('K') => (&val[..val.len() - 1], 1 << 10),
+        Some('m') | Some('M') => (&val[..val.len() - 1], 1 << 20),
+        Some('g') | Some('G') => (&val[..val.len() - 1], 1 << 30),
+        _ => (val, 1),
+    };
+    match digits.parse::<u64>() {
+        Ok(size) if size > 0 => size.checked_mul(multiplier),
+        _ => None,
+    }
 }
 
 /// Set bind values if given on the command line.
@@ -1169,6 +1719,17 @@ fn set_binds_from_input(spec: &mut ServiceSpec, m: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+fn set_health_probes_from_input(spec: &mut ServiceSpec, m: &ArgMatches) -> Result<()> {
+    if let Some(probe_strs) = m.values_of("HEALTH_PROBE") {
+        let mut probes = Vec::new();
+        for probe_str in probe_strs {
+            probes.push(HealthProbe::from_str(probe_str)?);
+        }
+        spec.health_probes = probes;
+    }
+    Ok(())
+}
+
 /// When loading a composite, the services within it may require
 /// additional binds that cannot be satisfied by the other services
 /// within the composite.
@@ -1269,8 +1830,11 @@ fn new_service_spec(ident: PackageIdent, m: &ArgMatches) -> Result<ServiceSpec>
     set_app_env_from_input(&mut spec, m)?;
     set_group_from_input(&mut spec, m);
     set_strategy_from_input(&mut spec, m);
+    set_restart_policy_from_input(&mut spec, m);
+    set_resource_limits_from_input(&mut spec, m);
     set_topology_from_input(&mut spec, m);
     set_binds_from_input(&mut spec, m)?;
+    set_health_probes_from_input(&mut spec, m)?;
     set_config_from_input(&mut spec, m)?;
     set_password_from_input(&mut spec, m)?;
     Ok(spec)
@@ -1285,10 +1849,13 @@ fn update_spec_from_input(mut spec: &mut ServiceSpec, m: &ArgMatches) -> Result<
     set_app_env_from_input(&mut spec, m)?;
     set_group_from_input(&mut spec, m);
     set_strategy_from_input(&mut spec, m);
+    set_restart_policy_from_input(&mut spec, m);
+    set_resource_limits_from_input(&mut spec, m);
     set_topology_from_input(&mut spec, m);
 
     // TODO (CM): Remove these for composite-member specs
     set_binds_from_input(&mut spec, m)?;
+    set_health_probes_from_input(&mut spec, m)?;
     set_config_from_input(&mut spec, m)?;
     set_password_from_input(&mut spec, m)?;
 
@@ -1320,9 +1887,11 @@ fn base_composite_service_spec(composite_name: &str, m: &ArgMatches) -> Result<S
     set_group_from_input(&mut spec, m);
 
     // For now, all a composite's services will also share the same
-    // update strategy and topology, though we may want to revisit
+    // update strategy, restart policy and topology, though we may want to revisit
     // this in the future (particularly for topology).
     set_strategy_from_input(&mut spec, m);
+    set_restart_policy_from_input(&mut spec, m);
+    set_resource_limits_from_input(&mut spec, m);
     set_topology_from_input(&mut spec, m);
 
     // TODO (CM): Not dealing with service passwords for now, since
@@ -1420,6 +1989,14 @@ fn dir_exists(val: String) -> result::Result<(), String> {
     }
 }
 
+fn file_exists(val: String) -> result::Result<(), String> {
+    if Path::new(&val).is_file() {
+        Ok(())
+    } else {
+        Err(format!("File: '{}' cannot be found", &val))
+    }
+}
+
 fn valid_service_group(val: String) -> result::Result<(), String> {
     match ServiceGroup::validate(&val) {
         Ok(()) => Ok(()),
@@ -1434,6 +2011,36 @@ fn valid_topology(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_shards(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(n) if n > 0 => Ok(()),
+        _ => Err(format!("Shards: '{}' is not a number greater than 0", &val)),
+    }
+}
+
+fn valid_tag(val: String) -> result::Result<(), String> {
+    match parse_tag(&val) {
+        Some(_) => Ok(()),
+        None => Err(format!("Tag: '{}' is not of the form KEY=VALUE", &val)),
+    }
+}
+
+/// Splits a `KEY=VALUE` tag into its key and value. The key may not be empty.
+fn parse_tag(tag: &str) -> Option<(&str, &str)> {
+    let mut parts = tag.splitn(2, '=');
+    match (parts.next(), parts.next()) {
+        (Some(key), Some(value)) if !key.is_empty() => Some((key, value)),
+        _ => None,
+    }
+}
+
+fn valid_ttl(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(n) if n > 0 => Ok(()),
+        _ => Err(format!("TTL: '{}' is not a number of seconds greater than 0", &val)),
+    }
+}
+
 fn valid_listen_gossip(val: String) -> result::Result<(), String> {
     match GossipListenAddr::from_str(&val) {
         Ok(_) => Ok(()),
@@ -1455,6 +2062,118 @@ fn valid_update_strategy(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_update_canaries(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(n) if n > 0 => Ok(()),
+        _ => Err(format!("Update canaries: '{}' is not a positive integer", &val)),
+    }
+}
+
+fn valid_update_batch_percent(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(n) if n > 0 && n <= 100 => Ok(()),
+        _ => Err(format!("Update batch percent: '{}' is not between 1 and 100", &val)),
+    }
+}
+
+fn valid_update_health_window(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Update health window: '{}' is not a number of seconds", &val)),
+    }
+}
+
+fn valid_rollback_threshold(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Rollback threshold: '{}' is not a number", &val)),
+    }
+}
+
+fn valid_health_probe(val: String) -> result::Result<(), String> {
+    match HealthProbe::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Health probe: '{}' is not valid", &val)),
+    }
+}
+
+fn valid_restart_policy(val: String) -> result::Result<(), String> {
+    match RestartPolicy::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Restart policy: '{}' is not valid", &val)),
+    }
+}
+
+fn valid_restart_max_retries(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Restart max retries: '{}' is not a number", &val)),
+    }
+}
+
+fn valid_restart_window(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Restart window: '{}' is not a number of seconds", &val)),
+    }
+}
+
+fn valid_restart_backoff(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Restart backoff: '{}' is not a number of seconds", &val)),
+    }
+}
+
+fn valid_cpu_shares(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(shares) if shares >= 2 && shares <= 262_144 => Ok(()),
+        _ => Err(format!("CPU shares: '{}' is not a number from 2 to 262144", &val)),
+    }
+}
+
+fn valid_cpu_quota(val: String) -> result::Result<(), String> {
+    match parse_cpu_quota(&val) {
+        Some(_) => Ok(()),
+        None => Err(format!("CPU quota: '{}' is not a number of CPUs", &val)),
+    }
+}
+
+fn valid_memory_limit(val: String) -> result::Result<(), String> {
+    match parse_memory_size(&val) {
+        Some(_) => Ok(()),
+        None => Err(format!("Memory limit: '{}' is not a size, ex: 512M", &val)),
+    }
+}
+
+fn valid_pids_limit(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(limit) if limit > 0 => Ok(()),
+        _ => Err(format!("Pids limit: '{}' is not a positive number", &val)),
+    }
+}
+
+fn valid_nofile_limit(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(limit) if limit > 0 => Ok(()),
+        _ => Err(format!("Open files limit: '{}' is not a positive number", &val)),
+    }
+}
+
+fn valid_secrets(val: String) -> result::Result<(), String> {
+    match SecretsConfig::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Secrets provider: '{}' must be file:<PATH> or vault:<URL>", &val)),
+    }
+}
+
+fn valid_log_format(val: String) -> result::Result<(), String> {
+    match val.as_str() {
+        "text" | "json" => Ok(()),
+        _ => Err(format!("Log format: '{}' must be text or json", &val)),
+    }
+}
+
 fn valid_url(val: String) -> result::Result<(), String> {
     match Url::parse(&val) {
         Ok(_) => Ok(()),
@@ -1580,6 +2299,13 @@ fn update_composite_service_specs(
     Ok(())
 }
 
+/// Applies the output options every subcommand takes to the global output settings.
+fn toggle_output(m: &ArgMatches) {
+    toggle_verbosity(m);
+    toggle_color(m);
+    toggle_log_format(m);
+}
+
 fn toggle_verbosity(m: &ArgMatches) {
     if m.is_present("VERBOSE") {
         hcore::output::set_verbose(true);
@@ -1592,6 +2318,12 @@ fn toggle_color(m: &ArgMatches) {
     }
 }
 
+fn toggle_log_format(m: &ArgMatches) {
+    if m.value_of("LOG_FORMAT") == Some("json") {
+        hcore::output::set_json(true);
+    }
+}
+
 // Based on UI::default_with_env, but taking into account the setting
 // of the global color variable.
 //
@@ -1617,3 +2349,56 @@ fn ui() -> UI {
 
     UI::default_with(coloring, isatty)
 }
+
+#[cfg(test)]
+mod test {
+    use hcore;
+
+    use super::*;
+
+    #[test]
+    fn run_applies_the_output_options() {
+        let matches = cli()
+            .get_matches_from_safe(vec!["hab-sup", "run", "-v", "--log-format", "json"])
+            .unwrap();
+        let (name, run) = matches.subcommand();
+        assert_eq!(name, "run");
+        let run = run.unwrap();
+        assert_eq!(run.value_of("LOG_FORMAT"), Some("json"));
+
+        toggle_output(run);
+        let (verbose, json) = (hcore::output::is_verbose(), hcore::output::is_json());
+        hcore::output::set_verbose(false);
+        hcore::output::set_json(false);
+
+        assert!(verbose);
+        assert!(json);
+    }
+
+    #[test]
+    fn cpu_quotas_are_in_thousandths_of_a_cpu() {
+        assert_eq!(parse_cpu_quota("1"), Some(1_000));
+        assert_eq!(parse_cpu_quota("1.5"), Some(1_500));
+        assert_eq!(parse_cpu_quota("0.25"), Some(250));
+        assert_eq!(parse_cpu_quota("0.001"), Some(1));
+        assert_eq!(parse_cpu_quota("0.0001"), None);
+        assert_eq!(parse_cpu_quota("0"), None);
+        assert_eq!(parse_cpu_quota("-1"), None);
+        assert_eq!(parse_cpu_quota("inf"), None);
+        assert_eq!(parse_cpu_quota("one"), None);
+    }
+
+    #[test]
+    fn memory_sizes_take_binary_suffixes() {
+        assert_eq!(parse_memory_size("1024"), Some(1024));
+        assert_eq!(parse_memory_size("512k"), Some(512 * 1024));
+        assert_eq!(parse_memory_size("512K"), Some(512 * 1024));
+        assert_eq!(parse_memory_size("256M"), Some(256 * 1024 * 1024));
+        assert_eq!(parse_memory_size("2G"), Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_memory_size("0"), None);
+        assert_eq!(parse_memory_size("G"), None);
+        assert_eq!(parse_memory_size("1.5G"), None);
+        assert_eq!(parse_memory_size("2T"), None);
+        assert_eq!(parse_memory_size("18446744073709551615G"), None);
+    }
+}
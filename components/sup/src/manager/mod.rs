@@ -25,7 +25,8 @@ mod peer_watcher;
 mod user_config_watcher;
 mod sys;
 
-use std::collections::HashMap;
+use std::cmp;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt;
 use std::fs::{self, File, OpenOptions};
 use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
@@ -35,6 +36,7 @@ use std::result;
 use std::thread;
 use std::str::FromStr;
 use std::sync::{Arc, RwLock};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
 use std::time::Duration;
 use std::mem;
 use std::ops::DerefMut;
@@ -44,13 +46,17 @@ use butterfly::member::Member;
 use butterfly::trace::Trace;
 use butterfly::server::timing::Timing;
 use butterfly::server::Suitability;
-use hcore::crypto::{default_cache_key_path, SymKey};
+use butterfly::server::federation::Federation;
+use common::ui::UI;
+use hcore::crypto::{default_cache_key_path, SigKeyPair, SymKey};
+use hcore::crypto::keys::PairType;
 use hcore::env;
-use hcore::fs::FS_ROOT_PATH;
+use hcore::fs::{cache_artifact_path, FS_ROOT_PATH};
 use hcore::service::ServiceGroup;
 use hcore::os::process::{self, Pid, Signal};
 use hcore::package::{Identifiable, PackageIdent, PackageInstall};
 use launcher_client::{LAUNCHER_LOCK_CLEAN_ENV, LAUNCHER_PID_ENV, LauncherCli};
+use prometheus::{Gauge, GaugeVec};
 use serde;
 use serde_json;
 use time::{self, Timespec, Duration as TimeDuration};
@@ -68,9 +74,17 @@ use error::{Error, Result, SupError};
 use config::GossipListenAddr;
 use census::CensusRing;
 use http_gateway;
+use templating::secrets::{self, SecretsConfig};
+use util;
 
 const MEMBER_ID_FILE: &'static str = "MEMBER_ID";
 const PROC_LOCK_FILE: &'static str = "LOCK";
+/// How often the key cache is scanned for new revisions of the ring key.
+const RING_KEY_SCAN_INTERVAL_MS: i64 = 30_000;
+/// How often the resource usage of services is refreshed from the Launcher.
+const RESOURCE_USAGE_INTERVAL_MS: i64 = 10_000;
+/// The suitability bit set for members carrying the `leader_tag` of a service.
+const LEADER_TAG_SUITABILITY: u64 = 1 << 63;
 
 static LOGKEY: &'static str = "MR";
 
@@ -79,6 +93,29 @@ lazy_static! {
     pub static ref STATE_PATH_PREFIX: PathBuf = {
         Path::new(&*FS_ROOT_PATH).join("hab/sup")
     };
+
+    static ref CENSUS_MEMBERS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "census_members",
+            "Number of members in a service group's census."),
+        &["service_group"]).unwrap();
+
+    static ref CENSUS_LEADER: GaugeVec = register_gauge_vec!(
+        opts!(
+            "census_leader",
+            "Whether this Supervisor is the elected leader of a service group; 1 or 0."),
+        &["service_group"]).unwrap();
+
+    static ref BUTTERFLY_MEMBERS: Gauge = register_gauge!(
+        opts!(
+            "butterfly_members",
+            "Number of members known to Butterfly.")).unwrap();
+
+    static ref BUTTERFLY_RUMORS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "butterfly_rumors",
+            "Number of rumors held by Butterfly by rumor type."),
+        &["type"]).unwrap();
 }
 
 /// FileSystem paths that the Manager uses to persist data to disk.
@@ -122,6 +159,11 @@ impl FsCfg {
             format!("{}.health", service_group.service()),
         )
     }
+
+    /// Returns the path of the spec file for the service of the named package.
+    pub fn spec_path(&self, name: &str) -> PathBuf {
+        self.specs_path.join(ServiceSpec::file_name_for(name))
+    }
 }
 
 #[derive(Clone, Default)]
@@ -138,6 +180,17 @@ pub struct ManagerConfig {
     pub name: Option<String>,
     pub organization: Option<String>,
     pub watch_peer_file: Option<String>,
+    pub http_auth_token: Option<String>,
+    pub secrets: Option<SecretsConfig>,
+    pub vault_token: Option<String>,
+    pub federation_ring: Option<String>,
+    pub federation_gateways: Vec<SocketAddr>,
+    pub federated_groups: Vec<ServiceGroup>,
+    pub tags: BTreeMap<String, String>,
+    pub rumor_ttl: Option<u64>,
+    pub tombstone_ttl: Option<u64>,
+    pub trusted_identities: Option<PathBuf>,
+    pub share_artifacts: bool,
 
     custom_state_path: Option<PathBuf>,
 }
@@ -154,9 +207,20 @@ pub struct Manager {
     spec_watcher: SpecWatcher,
     user_config_watcher: UserConfigWatcher,
     organization: Option<String>,
+    http_auth_token: Option<String>,
+    shared_artifact_cache: Option<PathBuf>,
     self_updater: Option<SelfUpdater>,
     service_states: HashMap<PackageIdent, Timespec>,
+    // Service groups which have census metric series, so gone groups can be dropped
+    census_metric_groups: HashSet<String>,
+    // Installs of the packages of loaded specs which weren't installed yet, by service name
+    pending_installs: HashMap<String, Receiver<result::Result<(), String>>>,
     sys: Arc<Sys>,
+    ring: Option<String>,
+    ring_key: Option<String>,
+    next_ring_key_scan: Timespec,
+    next_resource_usage_check: Timespec,
+    gossip_timing: Timing,
 }
 
 impl Manager {
@@ -278,7 +342,12 @@ impl Manager {
         } else {
             None
         };
+        if let Some(ref secrets_cfg) = cfg.secrets {
+            secrets::set_provider(secrets_cfg.provider(cfg.vault_token.clone())?);
+        }
         let mut sys = Sys::new(cfg.gossip_permanent, cfg.gossip_listen, cfg.http_listen);
+        sys.ring = cfg.federation_ring.clone();
+        sys.tags = cfg.tags.clone();
         let member = Self::load_member(&mut sys, &fs_cfg)?;
         let ring_key = match cfg.ring {
             Some(ref ring_with_revision) => {
@@ -290,8 +359,10 @@ impl Manager {
             }
             None => None,
         };
+        let ring = ring_key.as_ref().map(|k| k.name.clone());
+        let identity = Self::load_identity(&sys, &fs_cfg)?;
         let services = Arc::new(RwLock::new(Vec::new()));
-        let server = butterfly::Server::new(
+        let mut server = butterfly::Server::new(
             sys.gossip_listen(),
             sys.gossip_listen(),
             member,
@@ -299,9 +370,30 @@ impl Manager {
             ring_key,
             None,
             Some(&fs_cfg.data_path),
-            Box::new(SuitabilityLookup(services.clone())),
+            Box::new(SuitabilityLookup(services.clone(), sys.tags.clone())),
         )?;
         outputln!("Supervisor Member-ID {}", sys.member_id);
+        outputln!("Signing gossip with identity {}", identity.name_with_rev());
+        server.set_identity(identity);
+        if let Some(ref path) = cfg.trusted_identities {
+            let identities = Self::load_trusted_identities(path)?;
+            outputln!(
+                "Trusting only the {} identities in {}",
+                identities.len(),
+                path.display()
+            );
+            server.trust_identities(identities);
+        }
+        if let Some(ref federation_ring) = cfg.federation_ring {
+            outputln!("Federating as ring {}", federation_ring);
+            if !cfg.federation_gateways.is_empty() {
+                server.set_federation(Federation::new(
+                    federation_ring.clone(),
+                    cfg.federation_gateways.clone(),
+                    cfg.federated_groups.clone(),
+                ));
+            }
+        }
         for peer_addr in &cfg.gossip_peers {
             let mut peer = Member::default();
             peer.set_address(format!("{}", peer_addr.ip()));
@@ -310,6 +402,13 @@ impl Manager {
             server.member_list.add_initial_member(peer);
         }
         Self::migrate_specs(&fs_cfg);
+        let mut gossip_timing = Timing::default();
+        if let Some(rumor_ttl) = cfg.rumor_ttl {
+            gossip_timing.rumor_ttl_ms = rumor_ttl as i64 * 1000;
+        }
+        if let Some(tombstone_ttl) = cfg.tombstone_ttl {
+            gossip_timing.tombstone_ttl_ms = tombstone_ttl as i64 * 1000;
+        }
         let peer_watcher = if let Some(path) = cfg.watch_peer_file {
             Some(PeerWatcher::run(path)?)
         } else {
@@ -328,8 +427,21 @@ impl Manager {
             user_config_watcher: UserConfigWatcher::new(),
             fs_cfg: Arc::new(fs_cfg),
             organization: cfg.organization,
+            http_auth_token: cfg.http_auth_token,
+            shared_artifact_cache: if cfg.share_artifacts {
+                Some(cache_artifact_path(None::<String>))
+            } else {
+                None
+            },
             service_states: HashMap::new(),
+            census_metric_groups: HashSet::new(),
+            pending_installs: HashMap::new(),
             sys: Arc::new(sys),
+            ring_key: cfg.ring,
+            ring: ring,
+            next_ring_key_scan: Timespec::new(0, 0),
+            next_resource_usage_check: Timespec::new(0, 0),
+            gossip_timing: gossip_timing,
         })
     }
 
@@ -366,9 +478,70 @@ impl Manager {
         }
         sys.member_id = member.get_id().to_string();
         member.set_persistent(sys.permanent);
+        member.set_tags(
+            sys.tags
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect(),
+        );
         Ok(member)
     }
 
+    /// Load the identity this Supervisor signs its gossip with, generating one the first time the
+    /// Supervisor is run. The identity is a sig key pair named after the member id, kept in the
+    /// Supervisor's data directory.
+    fn load_identity(sys: &Sys, fs_cfg: &FsCfg) -> Result<SigKeyPair> {
+        match SigKeyPair::get_latest_pair_for(
+            &sys.member_id,
+            &fs_cfg.data_path,
+            Some(&PairType::Secret),
+        ) {
+            Ok(identity) => Ok(identity),
+            Err(_) => {
+                let identity = SigKeyPair::generate_pair_for_origin(&sys.member_id)?;
+                identity.to_pair_files(&fs_cfg.data_path)?;
+                Ok(identity)
+            }
+        }
+    }
+
+    /// Load the public sig keys in the directory; the identities of the members, and of any
+    /// operators, whose gossip is trusted. Files which aren't public sig keys are skipped.
+    fn load_trusted_identities(path: &Path) -> Result<Vec<SigKeyPair>> {
+        let entries = fs::read_dir(path).map_err(|err| {
+            sup_error!(Error::BadTrustedIdentitiesPath(path.to_path_buf(), err))
+        })?;
+        let mut identities = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|err| {
+                sup_error!(Error::BadTrustedIdentitiesPath(path.to_path_buf(), err))
+            })?;
+            let mut content = String::new();
+            if let Err(err) = File::open(entry.path()).and_then(|mut f| {
+                f.read_to_string(&mut content)
+            })
+            {
+                debug!("Skipping {}, {}", entry.path().display(), err);
+                continue;
+            }
+            // The key is read back by name, so it must be in a file named as the key cache
+            // would name it.
+            let identity = SigKeyPair::parse_key_str(&content).and_then(
+                |(pair_type, name_with_rev, _)| match pair_type {
+                    PairType::Public => SigKeyPair::get_pair_for(&name_with_rev, path),
+                    PairType::Secret => Err(hcore::Error::CryptoError(
+                        format!("{} is a secret key", name_with_rev),
+                    )),
+                },
+            );
+            match identity {
+                Ok(identity) => identities.push(identity),
+                Err(err) => debug!("Skipping {}, {}", entry.path().display(), err),
+            }
+        }
+        Ok(identities)
+    }
+
     pub fn spec_path_for(cfg: &ManagerConfig, spec: &ServiceSpec) -> PathBuf {
         Self::specs_path(&Self::state_path_from(cfg)).join(spec.file_name())
     }
@@ -482,6 +655,10 @@ impl Manager {
     }
 
     fn add_service(&mut self, spec: ServiceSpec) {
+        if util::pkg::installed(&spec.ident).is_none() {
+            self.install_in_background(spec);
+            return;
+        }
         outputln!("Starting {}", &spec.ident);
         // JW TODO: This clone sucks, but our data structures are a bit messy here. What we really
         // want is the service to hold the spec and, on failure, return an error with the spec
@@ -550,13 +727,18 @@ impl Manager {
             "Starting gossip-listener on {}",
             self.butterfly.gossip_addr()
         );
-        self.butterfly.start(Timing::default())?;
+        self.butterfly.start(self.gossip_timing.clone())?;
         debug!("gossip-listener started");
         self.persist_state();
         let http_listen_addr = self.sys.http_listen();
         outputln!("Starting http-gateway on {}", &http_listen_addr);
-        http_gateway::Server::new(self.fs_cfg.clone(), http_listen_addr)
-            .start()?;
+        http_gateway::Server::new(
+            self.fs_cfg.clone(),
+            http_listen_addr,
+            self.http_auth_token.clone(),
+            self.butterfly.events(),
+            self.shared_artifact_cache.clone(),
+        ).start()?;
         debug!("http-gateway started");
         let events = match self.events_group {
             Some(ref evg) => Some(events::EventsMgr::start(evg.clone())),
@@ -581,10 +763,13 @@ impl Manager {
                 return Ok(());
             }
             self.update_running_services_from_spec_watcher()?;
+            self.check_pending_installs();
             self.update_peers_from_watch_file()?;
             self.update_running_services_from_user_config_watcher();
             self.check_for_updated_packages();
             self.restart_elections();
+            self.update_shard_maps();
+            self.update_ring_keys();
             self.census_ring.update_from_rumors(
                 &self.butterfly.service_store,
                 &self.butterfly.election_store,
@@ -592,8 +777,14 @@ impl Manager {
                 &self.butterfly.member_list,
                 &self.butterfly.service_config_store,
                 &self.butterfly.service_file_store,
+                &self.butterfly.shard_map_store,
+                &self.butterfly.key_value_store,
+                &self.butterfly.federated_member_store,
             );
 
+            self.update_metrics();
+            self.update_resource_usage();
+
             if self.check_for_changed_services() {
                 self.persist_state();
             }
@@ -637,6 +828,64 @@ impl Manager {
         }
     }
 
+    /// Refresh the census and Butterfly metrics served by the http-gateway.
+    fn update_metrics(&mut self) {
+        let mut census_metric_groups = HashSet::new();
+        for census_group in self.census_ring.groups() {
+            census_metric_groups.insert(census_group.service_group.to_string());
+            let mut labels = HashMap::new();
+            labels.insert("service_group", census_group.service_group.as_ref());
+            CENSUS_MEMBERS.with(&labels).set(
+                census_group.members().len() as f64,
+            );
+            let leader = match (census_group.me(), census_group.leader()) {
+                (Some(me), Some(leader)) if me.member_id == leader.member_id => 1.0,
+                _ => 0.0,
+            };
+            CENSUS_LEADER.with(&labels).set(leader);
+        }
+        for gone in self.census_metric_groups.difference(&census_metric_groups) {
+            CENSUS_MEMBERS.remove_label_values(&[gone.as_str()]).ok();
+            CENSUS_LEADER.remove_label_values(&[gone.as_str()]).ok();
+        }
+        self.census_metric_groups = census_metric_groups;
+        BUTTERFLY_MEMBERS.set(self.butterfly.member_list.len() as f64);
+        let rumors = [
+            ("service", self.butterfly.service_store.len()),
+            ("service_config", self.butterfly.service_config_store.len()),
+            ("service_file", self.butterfly.service_file_store.len()),
+            ("election", self.butterfly.election_store.len()),
+            ("election_update", self.butterfly.update_store.len()),
+            ("departure", self.butterfly.departure_store.len()),
+            ("shard_map", self.butterfly.shard_map_store.len()),
+        ];
+        for &(kind, count) in rumors.iter() {
+            let mut labels = HashMap::new();
+            labels.insert("type", kind);
+            BUTTERFLY_RUMORS.with(&labels).set(count as f64);
+        }
+    }
+
+    /// Refresh the resource usage of running services served by the http-gateway.
+    fn update_resource_usage(&mut self) {
+        if time::get_time() < self.next_resource_usage_check {
+            return;
+        }
+        let mut changed = false;
+        for service in self.services
+            .write()
+            .expect("Services lock is poisoned!")
+            .iter_mut()
+        {
+            changed |= service.update_resource_usage(&self.launcher);
+        }
+        if changed {
+            self.persist_services_state();
+        }
+        self.next_resource_usage_check = time::get_time() +
+            TimeDuration::milliseconds(RESOURCE_USAGE_INTERVAL_MS);
+    }
+
     fn check_for_updated_supervisor(&mut self) -> Option<PackageInstall> {
         if let Some(ref mut updater) = self.self_updater {
             return updater.updated();
@@ -669,6 +918,22 @@ impl Manager {
 
     // Creates a rumor for the specified service.
     fn gossip_latest_service_rumor(&self, service: &Service) {
+        let incarnation = self.next_service_incarnation(service);
+        self.butterfly.insert_service(service.to_rumor(incarnation));
+    }
+
+    // Creates a rumor telling the ring the specified service has been unloaded, so every member
+    // drops it from its census and its rumors can expire.
+    fn gossip_unloaded_service_rumor(&self, service: &Service) {
+        let incarnation = self.next_service_incarnation(service);
+        let mut rumor = service.to_rumor(incarnation);
+        rumor.set_unloaded(true);
+        self.butterfly.insert_service(rumor);
+    }
+
+    // The incarnation of our next rumor for the specified service. An expired rumor leaves a
+    // tombstone behind, which only a newer incarnation gets past.
+    fn next_service_incarnation(&self, service: &Service) -> u64 {
         let mut incarnation = 1;
         {
             let list = self.butterfly.service_store.list.read().expect(
@@ -681,7 +946,14 @@ impl Manager {
                 incarnation = rumor.clone().get_incarnation() + 1;
             }
         }
-        self.butterfly.insert_service(service.to_rumor(incarnation));
+        self.butterfly.service_store.with_tombstone(
+            &*service.service_group,
+            &self.sys.member_id,
+            |tombstone| if let Some(tombstone) = tombstone {
+                incarnation = cmp::max(incarnation, tombstone.rumor.get_incarnation() + 1);
+            },
+        );
+        incarnation
     }
 
     fn check_for_departure(&self) -> bool {
@@ -850,10 +1122,10 @@ impl Manager {
     /// useful if you want the Supervisor to shutdown temporarily and then come back and re-attach
     /// to all running processes.
     fn remove_service(&mut self, service: &mut Service, term: bool) {
-        // JW TODO: Update service rumor to remove service from cluster
         if term {
             service.stop(&self.launcher);
         }
+        service.remove_metrics();
         if service.start_style == StartStyle::Transient {
             // JW TODO: If we cleanup our Service structure to hold the ServiceSpec instead of
             // deconstruct it (see my comments in `add_service()` in this module) then we could
@@ -904,6 +1176,54 @@ impl Manager {
         self.butterfly.restart_elections();
     }
 
+    /// Reassign the shards of sharded and multi-leader services as members join and leave.
+    fn update_shard_maps(&self) {
+        let services = self.services.read().expect("Services lock is poisoned!");
+        for service in services.iter() {
+            match service.topology {
+                Topology::Sharded => {
+                    self.butterfly.update_shard_map(
+                        &service.service_group,
+                        service.shards,
+                        false,
+                        service.zone_tag.as_ref().map(|t| t.as_str()),
+                    )
+                }
+                Topology::MultiLeader => {
+                    self.butterfly.update_shard_map(
+                        &service.service_group,
+                        service.shards,
+                        true,
+                        service.zone_tag.as_ref().map(|t| t.as_str()),
+                    )
+                }
+                Topology::Standalone | Topology::Leader => {}
+            }
+        }
+    }
+
+    /// Accepts gossip encrypted with any revision of our ring key in the key cache, so the ring
+    /// can be rotated to a new revision once it has been distributed to every Supervisor.
+    fn update_ring_keys(&mut self) {
+        if let Some(ref ring) = self.ring {
+            if time::get_time() >= self.next_ring_key_scan {
+                match SymKey::get_pairs_for(ring, &default_cache_key_path(None)) {
+                    Ok(keys) => self.butterfly.accept_ring_keys(keys),
+                    Err(err) => warn!("Unable to load revisions of ring key {}: {}", ring, err),
+                }
+                self.next_ring_key_scan = time::get_time() +
+                    TimeDuration::milliseconds(RING_KEY_SCAN_INTERVAL_MS);
+            }
+        }
+        let ring_key = self.butterfly.primary_ring_key();
+        if ring_key != self.ring_key {
+            if let Some(ref ring_key) = ring_key {
+                outputln!("Encrypting gossip with ring key {}", ring_key);
+            }
+            self.ring_key = ring_key;
+        }
+    }
+
     fn shutdown(&mut self) {
         outputln!("Gracefully departing from butterfly network.");
         self.butterfly.set_departed();
@@ -1014,10 +1334,84 @@ impl Manager {
             service = services.remove(services_idx);
         }
 
+        self.gossip_unloaded_service_rumor(&service);
         self.remove_service(&mut service, true);
         Ok(())
     }
 
+    /// Installs the package of a spec on its own thread, so a slow download doesn't hold up the
+    /// main loop. The service is added once `check_pending_installs` sees the install finish.
+    fn install_in_background(&mut self, spec: ServiceSpec) {
+        if self.pending_installs.contains_key(&spec.ident.name) {
+            return;
+        }
+        outputln!("Installing {} before starting it", &spec.ident);
+        let (tx, rx) = mpsc::channel();
+        let name = spec.ident.name.clone();
+        let result = thread::Builder::new()
+            .name(format!("install-{}", name))
+            .spawn(move || {
+                let result = util::pkg::install(
+                    // We don't want anything in here to print
+                    &mut UI::with_sinks(),
+                    &spec.bldr_url,
+                    &spec.ident.clone().into(),
+                    &spec.channel,
+                ).map(|_| ())
+                    .map_err(|err| err.to_string());
+                tx.send(result).ok();
+            });
+        match result {
+            Ok(_) => {
+                self.pending_installs.insert(name, rx);
+            }
+            Err(err) => outputln!("Unable to start installing {}, {}", name, err),
+        }
+    }
+
+    /// Adds the services whose packages finished installing in the background, as described by
+    /// their specs at that time.
+    fn check_pending_installs(&mut self) {
+        let mut finished = vec![];
+        for (name, rx) in self.pending_installs.iter() {
+            match rx.try_recv() {
+                Ok(result) => finished.push((name.clone(), result)),
+                Err(TryRecvError::Empty) => (),
+                Err(TryRecvError::Disconnected) => {
+                    finished.push((name.clone(), Err("installer exited".to_string())))
+                }
+            }
+        }
+        if finished.is_empty() {
+            return;
+        }
+        let mut specs = match self.spec_watcher.specs_from_watch_path() {
+            Ok(specs) => specs,
+            Err(err) => {
+                outputln!("Unable to read service specs, {}", err);
+                return;
+            }
+        };
+        for (name, result) in finished {
+            self.pending_installs.remove(&name);
+            // The spec may have been unloaded or stopped while its package was installing.
+            let spec = match specs.remove(&name) {
+                Some(spec) => spec,
+                None => continue,
+            };
+            match result {
+                Ok(()) if spec.desired_state == DesiredState::Up => self.add_service(spec),
+                Ok(()) => (),
+                Err(err) => {
+                    outputln!("Unable to install {}, {}", &spec.ident, err);
+                    if spec.start_style == StartStyle::Transient {
+                        self.remove_spec(&spec);
+                    }
+                }
+            }
+        }
+    }
+
     /// Remove the on disk representation of the given service spec
     fn remove_spec(&self, spec: &ServiceSpec) {
         if let Err(err) = fs::remove_file(self.fs_cfg.specs_path.join(spec.file_name())) {
@@ -1079,18 +1473,25 @@ impl fmt::Display for ServiceStatus {
     }
 }
 
+/// Looks up the suitability of our services for leader elections. Services with a `leader_tag`
+/// reserve the highest bit of the suitability for it, so members carrying the tag always win
+/// over members which don't, and the suitability hook only decides between them.
 #[derive(Debug)]
-struct SuitabilityLookup(Arc<RwLock<Vec<Service>>>);
+struct SuitabilityLookup(Arc<RwLock<Vec<Service>>>, BTreeMap<String, String>);
 
 impl Suitability for SuitabilityLookup {
     fn get(&self, service_group: &ServiceGroup) -> u64 {
-        self.0
-            .read()
-            .expect("Services lock is poisoned!")
-            .iter()
-            .find(|s| s.service_group == *service_group)
-            .and_then(|s| s.suitability())
-            .unwrap_or(u64::min_value())
+        let services = self.0.read().expect("Services lock is poisoned!");
+        let service = match services.iter().find(|s| s.service_group == *service_group) {
+            Some(service) => service,
+            None => return u64::min_value(),
+        };
+        let suitability = service.suitability().unwrap_or(u64::min_value());
+        match service.leader_tag_matches(&self.1) {
+            Some(true) => suitability | LEADER_TAG_SUITABILITY,
+            Some(false) => suitability & !LEADER_TAG_SUITABILITY,
+            None => suitability,
+        }
     }
 }
 
@@ -16,6 +16,7 @@
 
 use std;
 use std::ascii::AsciiExt;
+use std::collections::BTreeMap;
 use std::env;
 use std::fs::File;
 use std::io::prelude::*;
@@ -389,6 +390,16 @@ impl CfgRenderer {
         Ok(CfgRenderer(template))
     }
 
+    /// Renders every template, returning the rendered content of each by template name. Nothing
+    /// is written to the service's configuration directory.
+    pub fn render(&self, ctx: &RenderContext) -> Result<BTreeMap<String, String>> {
+        let mut rendered = BTreeMap::new();
+        for (template, _) in self.0.get_templates() {
+            rendered.insert(template.clone(), self.0.render(&template, ctx)?);
+        }
+        Ok(rendered)
+    }
+
     /// Compile and write all configuration files to the configuration directory.
     ///
     /// Returns `true` if the configuration has changed.
@@ -14,6 +14,8 @@
 
 use std::fmt;
 
+use butterfly::rumor::service::Service_Health;
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
 pub enum HealthCheck {
     Ok,
@@ -22,6 +24,27 @@ pub enum HealthCheck {
     Unknown,
 }
 
+impl HealthCheck {
+    /// Returns the more severe of the two results, where `Critical` outweighs `Unknown` which
+    /// outweighs `Warning` which outweighs `Ok`.
+    pub fn worst(self, other: HealthCheck) -> HealthCheck {
+        if other.severity() > self.severity() {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn severity(&self) -> u8 {
+        match *self {
+            HealthCheck::Ok => 0,
+            HealthCheck::Warning => 1,
+            HealthCheck::Unknown => 2,
+            HealthCheck::Critical => 3,
+        }
+    }
+}
+
 impl Default for HealthCheck {
     fn default() -> HealthCheck {
         HealthCheck::Unknown
@@ -40,6 +63,28 @@ impl From<i8> for HealthCheck {
     }
 }
 
+impl From<Service_Health> for HealthCheck {
+    fn from(value: Service_Health) -> HealthCheck {
+        match value {
+            Service_Health::OK => HealthCheck::Ok,
+            Service_Health::WARNING => HealthCheck::Warning,
+            Service_Health::CRITICAL => HealthCheck::Critical,
+            Service_Health::UNKNOWN => HealthCheck::Unknown,
+        }
+    }
+}
+
+impl From<HealthCheck> for Service_Health {
+    fn from(value: HealthCheck) -> Service_Health {
+        match value {
+            HealthCheck::Ok => Service_Health::OK,
+            HealthCheck::Warning => Service_Health::WARNING,
+            HealthCheck::Critical => Service_Health::CRITICAL,
+            HealthCheck::Unknown => Service_Health::UNKNOWN,
+        }
+    }
+}
+
 impl fmt::Display for HealthCheck {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
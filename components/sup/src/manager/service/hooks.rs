@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std;
+use std::collections::HashMap;
 use std::fmt;
 use std::io::BufReader;
 use std::io::prelude::*;
@@ -26,7 +27,9 @@ use std::result;
 
 use hcore;
 use hcore::crypto;
+use hcore::output::{self, Level, StructuredOutput};
 use hcore::service::ServiceGroup;
+use prometheus::HistogramVec;
 use serde::{Serialize, Serializer};
 
 use super::{health, Pkg};
@@ -38,6 +41,14 @@ use util::exec;
 pub const HOOK_PERMISSIONS: u32 = 0o755;
 static LOGKEY: &'static str = "HK";
 
+lazy_static! {
+    static ref HOOK_DURATION: HistogramVec = register_histogram_vec!(
+        histogram_opts!(
+            "hook_duration_seconds",
+            "Time spent running each service hook in seconds."),
+        &["service_group", "hook"]).unwrap();
+}
+
 pub fn stdout_log_path<T>(service_group: &ServiceGroup) -> PathBuf
 where
     T: Hook,
@@ -52,6 +63,28 @@ where
     fs::svc_logs_path(service_group.service()).join(format!("{}.stderr.log", T::file_name()))
 }
 
+/// Drops the hook duration series of a service group which is no longer running here.
+pub fn remove_metrics(service_group: &ServiceGroup) {
+    let hooks = [
+        FileUpdatedHook::file_name(),
+        HealthCheckHook::file_name(),
+        InitHook::file_name(),
+        RunHook::file_name(),
+        PostRunHook::file_name(),
+        ReloadHook::file_name(),
+        ReconfigureHook::file_name(),
+        SmokeTestHook::file_name(),
+        SuitabilityHook::file_name(),
+        PostStopHook::file_name(),
+    ];
+    for hook in hooks.iter() {
+        // Hooks which never ran have no series to remove
+        HOOK_DURATION
+            .remove_label_values(&[service_group.as_ref(), hook])
+            .ok();
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct ExitCode(i32);
 
@@ -127,24 +160,32 @@ pub trait Hook: fmt::Debug + Sized {
     where
         T: ToString,
     {
-        let mut child = match exec::run(self.path(), &pkg, svc_encrypted_password) {
-            Ok(child) => child,
-            Err(err) => {
-                outputln!(preamble service_group,
-                    "Hook failed to run, {}, {}", Self::file_name(), err);
-                return Self::ExitValue::default();
+        let mut labels = HashMap::new();
+        labels.insert("service_group", service_group.as_ref());
+        labels.insert("hook", Self::file_name());
+        let timer = HOOK_DURATION.with(&labels).start_timer();
+        let exit_value = match exec::run(self.path(), &pkg, svc_encrypted_password) {
+            Ok(mut child) => {
+                let mut hook_output =
+                    HookOutput::new(self.stdout_log_path(), self.stderr_log_path());
+                hook_output.stream_output::<Self>(service_group, &mut child);
+                match child.wait() {
+                    Ok(status) => self.handle_exit(service_group, &hook_output, &status),
+                    Err(err) => {
+                        outputln!(preamble service_group,
+                            "Hook failed to run, {}, {}", Self::file_name(), err);
+                        Self::ExitValue::default()
+                    }
+                }
             }
-        };
-        let mut hook_output = HookOutput::new(self.stdout_log_path(), self.stderr_log_path());
-        hook_output.stream_output::<Self>(service_group, &mut child);
-        match child.wait() {
-            Ok(status) => self.handle_exit(service_group, &hook_output, &status),
             Err(err) => {
                 outputln!(preamble service_group,
                     "Hook failed to run, {}, {}", Self::file_name(), err);
                 Self::ExitValue::default()
             }
-        }
+        };
+        timer.observe_duration();
+        exit_value
     }
 
     fn handle_exit<'a>(
@@ -982,10 +1023,11 @@ impl<'a> HookOutput<'a> {
             File::create(&self.stderr_log_file).expect("couldn't create log output file");
 
         let preamble_str = self.stream_preamble::<H>(service_group);
+        let source = format!("hook:{}", H::file_name());
         if let Some(ref mut stdout) = process.stdout {
             for line in BufReader::new(stdout).lines() {
                 if let Some(ref l) = line.ok() {
-                    outputln!(preamble preamble_str, l);
+                    output_line(service_group, &preamble_str, &source, Level::Info, l);
                     stdout_log.write_fmt(format_args!("{}\n", l)).expect(
                         "couldn't write line",
                     );
@@ -995,7 +1037,7 @@ impl<'a> HookOutput<'a> {
         if let Some(ref mut stderr) = process.stderr {
             for line in BufReader::new(stderr).lines() {
                 if let Some(ref l) = line.ok() {
-                    outputln!(preamble preamble_str, l);
+                    output_line(service_group, &preamble_str, &source, Level::Error, l);
                     stderr_log.write_fmt(format_args!("{}\n", l)).expect(
                         "couldn't write line",
                     );
@@ -1009,6 +1051,25 @@ impl<'a> HookOutput<'a> {
     }
 }
 
+/// Prints a line of a hook's output. JSON output carries the service group and the hook in their
+/// own fields rather than in the preamble.
+fn output_line(
+    service_group: &ServiceGroup,
+    preamble: &str,
+    source: &str,
+    level: Level,
+    content: &str,
+) {
+    let mut so = if output::is_json() {
+        StructuredOutput::new(service_group.as_ref(), LOGKEY, line!(), file!(), column!(), content)
+    } else {
+        StructuredOutput::new(preamble, LOGKEY, line!(), file!(), column!(), content)
+    };
+    so.source = Some(source);
+    so.level = level;
+    println!("{}", so);
+}
+
 #[cfg(test)]
 #[cfg(not(windows))]
 mod tests {
@@ -1035,6 +1096,7 @@ mod tests {
     use butterfly::rumor::election::Election as ElectionRumor;
     use butterfly::rumor::election::ElectionUpdate as ElectionUpdateRumor;
     use butterfly::rumor::service::SysInfo;
+    use butterfly::rumor::shard_map::ShardMap as ShardMapRumor;
     use butterfly::rumor::RumorStore;
     use super::fs as supfs;
 
@@ -1322,6 +1384,7 @@ echo "The message is Hola Mundo"
 
         let service_config_store: RumorStore<ServiceConfigRumor> = RumorStore::default();
         let service_file_store: RumorStore<ServiceFileRumor> = RumorStore::default();
+        let shard_map_store: RumorStore<ShardMapRumor> = RumorStore::default();
 
         let mut ring = CensusRing::new("member-a");
         ring.update_from_rumors(
@@ -1331,6 +1394,9 @@ echo "The message is Hola Mundo"
             &member_list,
             &service_config_store,
             &service_file_store,
+            &shard_map_store,
+            &RumorStore::default(),
+            &RumorStore::default(),
         );
 
         let bindings = iter::empty::<&ServiceBind>();
@@ -1424,6 +1490,7 @@ echo "The message is Hello"
 
         let service_config_store: RumorStore<ServiceConfigRumor> = RumorStore::default();
         let service_file_store: RumorStore<ServiceFileRumor> = RumorStore::default();
+        let shard_map_store: RumorStore<ShardMapRumor> = RumorStore::default();
 
         let mut ring = CensusRing::new("member-a");
         ring.update_from_rumors(
@@ -1433,6 +1500,9 @@ echo "The message is Hello"
             &member_list,
             &service_config_store,
             &service_file_store,
+            &shard_map_store,
+            &RumorStore::default(),
+            &RumorStore::default(),
         );
 
         let bindings = iter::empty::<&ServiceBind>();
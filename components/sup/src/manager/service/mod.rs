@@ -17,10 +17,13 @@ mod composite_spec;
 mod config;
 mod health;
 mod package;
+mod probe;
 mod spec;
 mod supervisor;
 
 use std;
+use std::cmp;
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::fmt;
 use std::fs::File;
 use std::io::BufWriter;
@@ -38,13 +41,16 @@ use hcore::package::{PackageIdent, PackageInstall};
 use hcore::service::ServiceGroup;
 use hcore::util::deserialize_using_from_str;
 use hcore::util::perm::{set_owner, set_permissions};
-use launcher_client::LauncherCli;
+use launcher_client::{LauncherCli, ResourceLimits};
+use prometheus::{CounterVec, GaugeVec};
+use rand::{Rng, thread_rng};
 use serde;
-use time::Timespec;
+use time::{self, Timespec};
 
 use super::Sys;
 use self::config::CfgRenderer;
 use self::hooks::{HOOK_PERMISSIONS, Hook, HookTable};
+use self::probe::ProbeMonitor;
 use self::supervisor::Supervisor;
 use error::{Error, Result, SupError};
 use fs;
@@ -53,9 +59,10 @@ use census::{ServiceFile, CensusRing, ElectionStatus};
 use templating::RenderContext;
 use util;
 
-pub use self::config::{Cfg, UserConfigPath};
+pub use self::config::{Cfg, CfgRenderer, UserConfigPath};
 pub use self::health::{HealthCheck, SmokeCheck};
 pub use self::package::Pkg;
+pub use self::probe::{HealthProbe, ProbeKind};
 pub use self::composite_spec::CompositeSpec;
 pub use self::spec::{DesiredState, ServiceBind, ServiceSpec, StartStyle};
 pub use self::supervisor::ProcessState;
@@ -69,6 +76,24 @@ lazy_static! {
     static ref HEALTH_CHECK_INTERVAL: Duration = {
         Duration::from_millis(30_000)
     };
+
+    static ref SERVICE_RESTARTS: CounterVec = register_counter_vec!(
+        opts!(
+            "service_restarts_total",
+            "Total number of times a service process was restarted."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_UPTIME: GaugeVec = register_gauge_vec!(
+        opts!(
+            "service_uptime_seconds",
+            "Seconds since a service process was started, 0 while it's down."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_HEALTH: GaugeVec = register_gauge_vec!(
+        opts!(
+            "service_health_check_status",
+            "Latest health check of a service; 0 ok, 1 warning, 2 critical, 3 unknown."),
+        &["service_group"]).unwrap();
 }
 
 #[derive(Debug, Serialize)]
@@ -80,7 +105,24 @@ pub struct Service {
     pub spec_ident: PackageIdent,
     pub start_style: StartStyle,
     pub topology: Topology,
+    pub shards: u32,
+    pub leader_tag: Option<String>,
+    pub zone_tag: Option<String>,
     pub update_strategy: UpdateStrategy,
+    pub update_canaries: u32,
+    pub update_batch_percent: u32,
+    pub update_health_window: u64,
+    pub rollback_threshold: u32,
+    pub restart_policy: RestartPolicy,
+    pub restart_max_retries: u32,
+    pub restart_window: u64,
+    pub restart_backoff: u64,
+    pub restart_backoff_max: u64,
+    pub cpu_shares: Option<u64>,
+    pub cpu_quota: Option<u64>,
+    pub memory_limit: Option<u64>,
+    pub pids_limit: Option<u64>,
+    pub nofile_limit: Option<u64>,
     pub cfg: Cfg,
     pub pkg: Pkg,
     pub sys: Arc<Sys>,
@@ -91,6 +133,7 @@ pub struct Service {
     config_renderer: CfgRenderer,
     health_check: HealthCheck,
     last_election_status: ElectionStatus,
+    last_shard: Option<u32>,
     needs_reload: bool,
     needs_reconfiguration: bool,
     smoke_check: SmokeCheck,
@@ -104,6 +147,32 @@ pub struct Service {
     supervisor: Supervisor,
     svc_encrypted_password: Option<String>,
     composite: Option<String>,
+    previous_release: Option<PackageIdent>,
+    failed_health_checks: u32,
+    restarts: u32,
+    rollback: Option<Rollback>,
+    health_probes: Vec<HealthProbe>,
+    #[serde(skip_serializing)]
+    probe_monitors: Vec<ProbeMonitor>,
+    // Result of the last run of the `health_check` hook, before the probes are taken into account
+    #[serde(skip_serializing)]
+    hook_health: HealthCheck,
+    // Times of the restarts within the restart window, oldest first
+    #[serde(skip_serializing)]
+    restart_history: VecDeque<Instant>,
+    #[serde(skip_serializing)]
+    next_restart: Option<Instant>,
+    // Set when the restart policy says the exited process must not be restarted
+    #[serde(skip_serializing)]
+    restart_halted: bool,
+}
+
+/// Record of an automatic rollback of a service to the release it ran before its last update.
+#[derive(Clone, Debug, Serialize)]
+pub struct Rollback {
+    pub from: PackageIdent,
+    pub to: PackageIdent,
+    pub reason: String,
 }
 
 impl Service {
@@ -139,6 +208,7 @@ impl Service {
             ),
             initialized: false,
             last_election_status: ElectionStatus::None,
+            last_shard: None,
             needs_reload: false,
             needs_reconfiguration: false,
             user_config_updated: false,
@@ -152,11 +222,38 @@ impl Service {
             spec_file: spec_file,
             start_style: spec.start_style,
             topology: spec.topology,
+            shards: spec.shards,
+            leader_tag: spec.leader_tag,
+            zone_tag: spec.zone_tag,
             update_strategy: spec.update_strategy,
+            update_canaries: spec.update_canaries,
+            update_batch_percent: spec.update_batch_percent,
+            update_health_window: spec.update_health_window,
+            rollback_threshold: spec.rollback_threshold,
+            restart_policy: spec.restart_policy,
+            restart_max_retries: spec.restart_max_retries,
+            restart_window: spec.restart_window,
+            restart_backoff: spec.restart_backoff,
+            restart_backoff_max: spec.restart_backoff_max,
+            cpu_shares: spec.cpu_shares,
+            cpu_quota: spec.cpu_quota,
+            memory_limit: spec.memory_limit,
+            pids_limit: spec.pids_limit,
+            nofile_limit: spec.nofile_limit,
             config_from: spec.config_from,
             last_health_check: None,
             svc_encrypted_password: spec.svc_encrypted_password,
             composite: spec.composite,
+            previous_release: None,
+            failed_health_checks: 0,
+            restarts: 0,
+            rollback: None,
+            health_probes: spec.health_probes,
+            probe_monitors: Vec::new(),
+            hook_health: HealthCheck::default(),
+            restart_history: VecDeque::new(),
+            next_restart: None,
+            restart_halted: false,
         })
     }
 
@@ -246,6 +343,7 @@ impl Service {
                 &self.service_group,
                 launcher,
                 self.svc_encrypted_password.as_ref(),
+                &self.resource_limits(),
             )
             .err()
         {
@@ -253,6 +351,8 @@ impl Service {
         } else {
             self.needs_reload = false;
             self.needs_reconfiguration = false;
+            self.next_restart = None;
+            self.restart_halted = false;
         }
     }
 
@@ -267,12 +367,14 @@ impl Service {
     fn reload(&mut self, launcher: &LauncherCli) {
         self.needs_reload = false;
         if self.process_down() || self.hooks.reload.is_none() {
+            SERVICE_RESTARTS.with(&self.metric_labels()).inc();
             if let Some(err) = self.supervisor
                 .restart(
                     &self.pkg,
                     &self.service_group,
                     launcher,
                     self.svc_encrypted_password.as_ref(),
+                    &self.resource_limits(),
                 )
                 .err()
             {
@@ -288,13 +390,28 @@ impl Service {
         }
     }
 
+    /// Refreshes the resource usage of the service's process. Returns whether it changed.
+    pub fn update_resource_usage(&mut self, launcher: &LauncherCli) -> bool {
+        self.supervisor.update_resource_usage(launcher)
+    }
+
+    fn resource_limits(&self) -> ResourceLimits {
+        ResourceLimits {
+            cpu_shares: self.cpu_shares,
+            cpu_quota: self.cpu_quota,
+            memory_limit: self.memory_limit,
+            pids_limit: self.pids_limit,
+            nofile_limit: self.nofile_limit,
+        }
+    }
+
     pub fn last_state_change(&self) -> Timespec {
         self.supervisor.state_entered
     }
 
     /// Performs updates and executes hooks.
     ///
-    /// Returns `true` if the service was updated.
+    /// Returns `true` if the service was updated, its health changed, or it was rolled back.
     pub fn tick(&mut self, census_ring: &CensusRing, launcher: &LauncherCli) -> bool {
         if !self.initialized {
             if !self.all_binds_satisfied(census_ring) {
@@ -303,14 +420,14 @@ impl Service {
             }
         }
 
-        let svc_updated = self.update_templates(census_ring);
+        let mut svc_updated = self.update_templates(census_ring);
         if self.update_service_files(census_ring) {
             self.file_updated();
         }
 
         match self.topology {
             Topology::Standalone => {
-                self.execute_hooks(launcher);
+                svc_updated |= self.execute_hooks(launcher);
             }
             Topology::Leader => {
                 let census_group = census_ring.census_group_for(&self.service_group).expect(
@@ -349,11 +466,34 @@ impl Service {
                                       leader_id.to_string());
                             self.last_election_status = census_group.election_status;
                         }
-                        self.execute_hooks(launcher)
+                        svc_updated |= self.execute_hooks(launcher);
                     }
                 }
             }
+            Topology::Sharded | Topology::MultiLeader => {
+                let census_group = census_ring.census_group_for(&self.service_group).expect(
+                    "Service Group's census entry missing from list!",
+                );
+                let shard = census_group.me().and_then(|me| me.shard);
+                if self.last_shard != shard {
+                    match shard {
+                        Some(shard) => {
+                            outputln!(preamble self.service_group,
+                                      "Executing hooks; assigned to shard {}", shard);
+                        }
+                        None => {
+                            outputln!(preamble self.service_group,
+                                      "Waiting to execute hooks; no shard assigned yet");
+                        }
+                    }
+                    self.last_shard = shard;
+                }
+                if shard.is_some() {
+                    svc_updated |= self.execute_hooks(launcher);
+                }
+            }
         }
+        self.update_uptime_metric();
         svc_updated
     }
 
@@ -369,7 +509,25 @@ impl Service {
         spec.bldr_url = self.bldr_url.clone();
         spec.channel = self.channel.clone();
         spec.topology = self.topology;
+        spec.shards = self.shards;
+        spec.leader_tag = self.leader_tag.clone();
+        spec.zone_tag = self.zone_tag.clone();
         spec.update_strategy = self.update_strategy;
+        spec.update_canaries = self.update_canaries;
+        spec.update_batch_percent = self.update_batch_percent;
+        spec.update_health_window = self.update_health_window;
+        spec.rollback_threshold = self.rollback_threshold;
+        spec.health_probes = self.health_probes.clone();
+        spec.restart_policy = self.restart_policy;
+        spec.restart_max_retries = self.restart_max_retries;
+        spec.restart_window = self.restart_window;
+        spec.restart_backoff = self.restart_backoff;
+        spec.restart_backoff_max = self.restart_backoff_max;
+        spec.cpu_shares = self.cpu_shares;
+        spec.cpu_quota = self.cpu_quota;
+        spec.memory_limit = self.memory_limit;
+        spec.pids_limit = self.pids_limit;
+        spec.nofile_limit = self.nofile_limit;
         spec.binds = self.binds.clone();
         spec.start_style = self.start_style;
         spec.config_from = self.config_from.clone();
@@ -413,6 +571,10 @@ impl Service {
         self.supervisor.state == ProcessState::Down
     }
 
+    fn process_failed(&self) -> bool {
+        self.supervisor.state == ProcessState::Failed
+    }
+
     /// Compares the current state of the service to the current state of the census ring and the
     /// user-config, and re-renders all templatable content to disk.
     ///
@@ -458,6 +620,19 @@ impl Service {
 
     /// Replace the package of the running service and restart its system process.
     pub fn update_package(&mut self, package: PackageInstall, launcher: &LauncherCli) {
+        if !self.switch_package(package) {
+            return;
+        }
+        if let Err(err) = self.supervisor.stop(launcher) {
+            outputln!(preamble self.service_group,
+                      "Error stopping process while updating package: {}", err);
+        }
+        self.initialized = false;
+    }
+
+    /// Replaces the package of the service, remembering the current one as the release to roll
+    /// back to. Returns `false` if the new package couldn't be loaded.
+    fn switch_package(&mut self, package: PackageInstall) -> bool {
         match Pkg::from_install(package) {
             Ok(pkg) => {
                 outputln!(preamble self.service_group,
@@ -467,7 +642,7 @@ impl Service {
                     Err(e) => {
                         outputln!(preamble self.service_group,
                                   "Failed to load config templates after updating package, {}", e);
-                        return;
+                        return false;
                     }
                 }
                 self.hooks = HookTable::load(
@@ -475,19 +650,25 @@ impl Service {
                     &Self::hooks_root(&pkg, self.config_from.as_ref()),
                     fs::svc_hooks_path(self.service_group.service()),
                 );
+                self.previous_release = Some(self.pkg.ident.clone());
                 self.pkg = pkg;
+                self.health_check = HealthCheck::default();
+                self.hook_health = HealthCheck::default();
+                self.last_health_check = None;
+                self.failed_health_checks = 0;
+                self.restarts = 0;
+                self.rollback = None;
+                // Dropping the monitors stops their probes, which are started again with the
+                // new package once the service is running.
+                self.probe_monitors.clear();
+                true
             }
             Err(err) => {
                 outputln!(preamble self.service_group,
                           "Unexpected error while updating package, {}", err);
-                return;
+                false
             }
         }
-        if let Err(err) = self.supervisor.stop(launcher) {
-            outputln!(preamble self.service_group,
-                      "Error stopping process while updating package: {}", err);
-        }
-        self.initialized = false;
     }
 
     pub fn to_rumor(&self, incarnation: u64) -> ServiceRumor {
@@ -508,6 +689,10 @@ impl Service {
             exported.as_ref(),
         );
         rumor.set_incarnation(incarnation);
+        rumor.set_health(self.health_check.into());
+        if let Some(ref rollback) = self.rollback {
+            rumor.set_rolled_back_from(rollback.from.to_string());
+        }
         rumor
     }
 
@@ -572,6 +757,18 @@ impl Service {
         })
     }
 
+    /// Returns whether a member with the given tags carries the service's `leader_tag`, or `None`
+    /// if the service has no leader tag.
+    pub fn leader_tag_matches(&self, tags: &BTreeMap<String, String>) -> Option<bool> {
+        self.leader_tag.as_ref().map(|leader_tag| {
+            let mut parts = leader_tag.splitn(2, '=');
+            match (parts.next(), parts.next()) {
+                (Some(key), Some(value)) => tags.get(key).map_or(false, |v| v == value),
+                _ => false,
+            }
+        })
+    }
+
     /// this function wraps create_dir_all so we can give friendly error
     /// messages to the user.
     fn create_dir_all<P: AsRef<Path>>(path: P) -> Result<()> {
@@ -678,12 +875,14 @@ impl Service {
         Ok(())
     }
 
-    fn execute_hooks(&mut self, launcher: &LauncherCli) {
+    /// Returns `true` if the health of the service changed or it was rolled back.
+    fn execute_hooks(&mut self, launcher: &LauncherCli) -> bool {
+        let mut health_changed = false;
         if !self.initialized {
             if self.check_process() {
                 outputln!("Reattached to {}", self.service_group);
                 self.initialized = true;
-                return;
+                return false;
             }
             self.initialize();
             if self.initialized {
@@ -692,13 +891,35 @@ impl Service {
             }
         } else {
             self.check_process();
+            if self.probe_monitors.len() != self.health_probes.len() {
+                self.probe_monitors = self.health_probes
+                    .iter()
+                    .map(|probe| probe.start(&self.service_group, &self.pkg))
+                    .collect();
+            }
+            let mut probes_changed = false;
+            for monitor in self.probe_monitors.iter_mut() {
+                probes_changed |= monitor.poll();
+            }
+            if probes_changed {
+                health_changed = self.update_health();
+            }
             match self.last_health_check {
                 Some(last_check) => {
                     if Instant::now().duration_since(last_check) >= *HEALTH_CHECK_INTERVAL {
-                        self.run_health_check_hook();
+                        health_changed |= self.run_health_check_hook();
                     }
                 }
-                None => self.run_health_check_hook(),
+                None => health_changed |= self.run_health_check_hook(),
+            }
+
+            let restart_due = self.process_down() && self.restart_due(launcher);
+            if let Some(reason) = self.rollback_reason() {
+                self.roll_back(reason, launcher);
+                return true;
+            }
+            if self.process_failed() || (self.process_down() && !restart_due) {
+                return health_changed;
             }
 
             // NOTE: if you need reconfiguration and you DON'T have a
@@ -711,6 +932,7 @@ impl Service {
                 }
             }
         }
+        health_changed
     }
 
     /// Run file_updated hook if present.
@@ -775,8 +997,9 @@ impl Service {
         )
     }
 
-    fn run_health_check_hook(&mut self) {
-        let check_result = if let Some(ref hook) = self.hooks.health_check {
+    /// Returns `true` if the health of the service differs from the previous health check.
+    fn run_health_check_hook(&mut self) -> bool {
+        self.hook_health = if let Some(ref hook) = self.hooks.health_check {
             hook.run(
                 &self.service_group,
                 &self.pkg,
@@ -789,7 +1012,187 @@ impl Service {
             }
         };
         self.last_health_check = Some(Instant::now());
+        let changed = self.update_health();
+        self.count_failed_health_checks();
+        changed
+    }
+
+    /// Counts consecutive critical health checks against the rollback threshold.
+    fn count_failed_health_checks(&mut self) {
+        if self.health_check == HealthCheck::Critical {
+            self.failed_health_checks += 1;
+        } else {
+            self.failed_health_checks = 0;
+        }
+    }
+
+    /// Combine the result of the `health_check` hook with the results of the health probes,
+    /// where the worst result wins.
+    ///
+    /// Returns `true` if the health of the service changed.
+    fn update_health(&mut self) -> bool {
+        let check_result = self.probe_monitors.iter().fold(
+            self.hook_health,
+            |health, monitor| health.worst(monitor.health()),
+        );
         self.cache_health_check(check_result);
+        SERVICE_HEALTH.with(&self.metric_labels()).set(
+            check_result as i8 as f64,
+        );
+        if self.health_check != check_result {
+            self.health_check = check_result;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn metric_labels(&self) -> HashMap<&str, &str> {
+        let mut labels = HashMap::new();
+        labels.insert("service_group", self.service_group.as_ref());
+        labels
+    }
+
+    /// Drops the metric series of this service so an unloaded or regrouped service doesn't leave
+    /// stale values behind on the metrics endpoint.
+    pub fn remove_metrics(&self) {
+        let labels = self.metric_labels();
+        // Series which were never set have nothing to remove
+        SERVICE_RESTARTS.remove(&labels).ok();
+        SERVICE_UPTIME.remove(&labels).ok();
+        SERVICE_HEALTH.remove(&labels).ok();
+        hooks::remove_metrics(&self.service_group);
+    }
+
+    fn update_uptime_metric(&self) {
+        let uptime = match self.supervisor.state {
+            ProcessState::Up => (time::get_time() - self.supervisor.state_entered).num_seconds(),
+            ProcessState::Down | ProcessState::Failed => 0,
+        };
+        SERVICE_UPTIME.with(&self.metric_labels()).set(uptime as f64);
+    }
+
+    /// Applies the restart policy to the exited process of the service. When the process is first
+    /// found down, this either schedules its restart after a backoff, leaves it down, or marks it
+    /// as failed if it restarted too often within the restart window.
+    ///
+    /// Returns `true` once a scheduled restart is due.
+    fn restart_due(&mut self, launcher: &LauncherCli) -> bool {
+        if self.restart_halted {
+            return false;
+        }
+        let now = Instant::now();
+        if let Some(next_restart) = self.next_restart {
+            if now < next_restart {
+                return false;
+            }
+            self.next_restart = None;
+            self.restart_history.push_back(now);
+            return true;
+        }
+        if self.previous_release.is_some() {
+            self.restarts += 1;
+        }
+        let restart = match self.restart_policy {
+            RestartPolicy::Always => true,
+            RestartPolicy::OnFailure => self.supervisor.exit_code(launcher) != Some(0),
+            RestartPolicy::Never => false,
+        };
+        if !restart {
+            outputln!(preamble self.service_group,
+                      "Service exited, not restarting it with restart policy {}",
+                      self.restart_policy);
+            self.restart_halted = true;
+            return false;
+        }
+        let window = Duration::from_secs(self.restart_window);
+        while self.restart_history.front().map_or(false, |t| now - *t > window) {
+            self.restart_history.pop_front();
+        }
+        if self.restart_max_retries > 0 &&
+            self.restart_history.len() >= self.restart_max_retries as usize
+        {
+            outputln!(preamble self.service_group,
+                      "Service restarted {} times within {} seconds, marking it as failed",
+                      self.restart_history.len(), self.restart_window);
+            self.supervisor.fail();
+            return false;
+        }
+        let delay = restart_delay(
+            self.restart_backoff,
+            self.restart_backoff_max,
+            self.restart_history.len(),
+        );
+        outputln!(preamble self.service_group,
+                  "Service exited, restarting it in {}ms",
+                  delay.as_secs() * 1_000 + delay.subsec_nanos() as u64 / 1_000_000);
+        self.next_restart = Some(now + delay);
+        false
+    }
+
+    /// Returns why the current release should be rolled back, if it has failed enough health
+    /// checks or restarts since it was installed by an update.
+    fn rollback_reason(&self) -> Option<String> {
+        if self.rollback_threshold == 0 || self.previous_release.is_none() {
+            return None;
+        }
+        if self.failed_health_checks >= self.rollback_threshold {
+            Some(format!("{} failed health checks", self.failed_health_checks))
+        } else if self.restarts >= self.rollback_threshold {
+            Some(format!("{} restarts", self.restarts))
+        } else {
+            None
+        }
+    }
+
+    /// Reinstall the release which ran before the last update and pin the service spec to it
+    /// so the failed release isn't picked up again.
+    fn roll_back(&mut self, reason: String, launcher: &LauncherCli) {
+        let previous = match self.previous_release.take() {
+            Some(previous) => previous,
+            None => return,
+        };
+        let fs_root_path = Path::new(&*FS_ROOT_PATH);
+        let package = match PackageInstall::load(&previous, Some(fs_root_path)) {
+            Ok(package) => package,
+            Err(err) => {
+                outputln!(preamble self.service_group,
+                          "Unable to roll back to {}, {}", previous, err);
+                return;
+            }
+        };
+        if !self.roll_back_to(package, reason) {
+            return;
+        }
+        if let Err(err) = self.supervisor.stop(launcher) {
+            outputln!(preamble self.service_group,
+                      "Error stopping process while rolling back: {}", err);
+        }
+        self.initialized = false;
+    }
+
+    /// Switches the service back to the given release and records the rollback. Returns `false`
+    /// if the release couldn't be loaded.
+    fn roll_back_to(&mut self, package: PackageInstall, reason: String) -> bool {
+        let failed = self.pkg.ident.clone();
+        let previous = package.ident.clone();
+        outputln!(preamble self.service_group,
+                  "Rolling back from {} to {} after {}", failed, previous, reason);
+        if !self.switch_package(package) {
+            return false;
+        }
+        self.previous_release = None;
+        self.rollback = Some(Rollback {
+            from: failed,
+            to: previous.clone(),
+            reason: reason,
+        });
+        self.spec_ident = previous;
+        if let Err(err) = self.to_spec().to_file(&self.spec_file) {
+            outputln!(preamble self.service_group,
+                      "Unable to pin service spec to {}, {}", self.spec_ident, err);
+        }
+        true
     }
 
     // Returns `false` if the write fails.
@@ -864,12 +1267,16 @@ impl fmt::Display for Service {
 pub enum Topology {
     Standalone,
     Leader,
+    MultiLeader,
+    Sharded,
 }
 
 impl Topology {
     fn as_str(&self) -> &str {
         match *self {
             Topology::Leader => "leader",
+            Topology::MultiLeader => "multi-leader",
+            Topology::Sharded => "sharded",
             Topology::Standalone => "standalone",
         }
     }
@@ -881,6 +1288,8 @@ impl FromStr for Topology {
     fn from_str(topology: &str) -> result::Result<Self, Self::Err> {
         match topology {
             "leader" => Ok(Topology::Leader),
+            "multi-leader" => Ok(Topology::MultiLeader),
+            "sharded" => Ok(Topology::Sharded),
             "standalone" => Ok(Topology::Standalone),
             _ => Err(sup_error!(Error::InvalidTopology(String::from(topology)))),
         }
@@ -922,6 +1331,8 @@ pub enum UpdateStrategy {
     None,
     AtOnce,
     Rolling,
+    Canary,
+    Batched,
 }
 
 impl UpdateStrategy {
@@ -930,6 +1341,8 @@ impl UpdateStrategy {
             UpdateStrategy::None => "none",
             UpdateStrategy::AtOnce => "at-once",
             UpdateStrategy::Rolling => "rolling",
+            UpdateStrategy::Canary => "canary",
+            UpdateStrategy::Batched => "batched",
         }
     }
 }
@@ -942,6 +1355,8 @@ impl FromStr for UpdateStrategy {
             "none" => Ok(UpdateStrategy::None),
             "at-once" => Ok(UpdateStrategy::AtOnce),
             "rolling" => Ok(UpdateStrategy::Rolling),
+            "canary" => Ok(UpdateStrategy::Canary),
+            "batched" => Ok(UpdateStrategy::Batched),
             _ => Err(sup_error!(
                 Error::InvalidUpdateStrategy(String::from(strategy))
             )),
@@ -979,14 +1394,107 @@ impl serde::Serialize for UpdateStrategy {
     }
 }
 
+/// Returns how long to wait before restarting a service which already restarted `restarts` times
+/// within its restart window: `backoff` seconds doubled with each restart and capped at
+/// `backoff_max` seconds, of which up to half is taken off at random so that services which
+/// crashed together don't restart in lockstep.
+fn restart_delay(backoff: u64, backoff_max: u64, restarts: usize) -> Duration {
+    let factor = 2u64.pow(cmp::min(restarts, 32) as u32);
+    let delay = cmp::min(
+        backoff.saturating_mul(factor),
+        backoff_max,
+    ).saturating_mul(1_000);
+    let jitter = thread_rng().gen_range(0, delay / 2 + 1);
+    Duration::from_millis(delay - jitter)
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RestartPolicy {
+    Always,
+    OnFailure,
+    Never,
+}
+
+impl RestartPolicy {
+    fn as_str(&self) -> &str {
+        match *self {
+            RestartPolicy::Always => "always",
+            RestartPolicy::OnFailure => "on-failure",
+            RestartPolicy::Never => "never",
+        }
+    }
+}
+
+impl FromStr for RestartPolicy {
+    type Err = SupError;
+
+    fn from_str(policy: &str) -> result::Result<Self, Self::Err> {
+        match policy {
+            "always" => Ok(RestartPolicy::Always),
+            "on-failure" => Ok(RestartPolicy::OnFailure),
+            "never" => Ok(RestartPolicy::Never),
+            _ => Err(sup_error!(Error::InvalidRestartPolicy(String::from(policy)))),
+        }
+    }
+}
+
+impl fmt::Display for RestartPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Default for RestartPolicy {
+    fn default() -> RestartPolicy {
+        RestartPolicy::Always
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for RestartPolicy {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for RestartPolicy {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::str::FromStr;
 
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    use hcore::package::{PackageIdent, PackageInstall};
+    use tempdir::TempDir;
     use toml;
 
-    use super::{Topology, UpdateStrategy};
+    use super::{restart_delay, HealthCheck, RestartPolicy, Service, ServiceSpec, Topology,
+                UpdateStrategy, SERVICE_HEALTH, SERVICE_RESTARTS, SERVICE_UPTIME};
+    use config::GossipListenAddr;
     use error::Error::*;
+    use http_gateway::ListenAddr;
+    use manager::FsCfg;
+    use manager::sys::Sys;
+
+    fn fake_install(release: &str, path: &PathBuf) -> PackageInstall {
+        PackageInstall::new_from_parts(
+            PackageIdent::new("core", "redis", Some("3.2.4"), Some(release)),
+            path.clone(),
+            path.clone(),
+            path.clone(),
+        )
+    }
 
     #[test]
     fn topology_default() {
@@ -1003,6 +1511,14 @@ mod test {
         assert_eq!(topology, Topology::Leader);
     }
 
+    #[test]
+    fn sharded_topologies_from_str() {
+        for topology_str in &["multi-leader", "sharded"] {
+            let topology = Topology::from_str(topology_str).unwrap();
+            assert_eq!(&topology.to_string(), topology_str);
+        }
+    }
+
     #[test]
     fn topology_from_str_invalid() {
         let topology_str = "dope";
@@ -1067,6 +1583,18 @@ mod test {
         assert_eq!(strategy, UpdateStrategy::AtOnce);
     }
 
+    #[test]
+    fn update_strategy_from_str_staged() {
+        assert_eq!(
+            UpdateStrategy::from_str("canary").unwrap(),
+            UpdateStrategy::Canary
+        );
+        assert_eq!(
+            UpdateStrategy::from_str("batched").unwrap(),
+            UpdateStrategy::Batched
+        );
+    }
+
     #[test]
     fn update_strategy_from_str_invalid() {
         let strategy_str = "dope";
@@ -1115,4 +1643,117 @@ mod test {
 
         assert!(toml.starts_with(r#"key = "at-once""#));
     }
+
+    #[test]
+    fn restart_policy_default() {
+        assert_eq!(RestartPolicy::default(), RestartPolicy::Always);
+    }
+
+    #[test]
+    fn restart_policy_from_str() {
+        let policy = RestartPolicy::from_str("on-failure").unwrap();
+
+        assert_eq!(policy, RestartPolicy::OnFailure);
+    }
+
+    #[test]
+    fn restart_policy_from_str_invalid() {
+        match RestartPolicy::from_str("sometimes") {
+            Err(e) => {
+                match e.err {
+                    InvalidRestartPolicy(s) => assert_eq!("sometimes", s),
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("String should fail to parse"),
+        }
+    }
+
+    #[test]
+    fn restart_policy_to_string() {
+        assert_eq!("on-failure", RestartPolicy::OnFailure.to_string())
+    }
+
+    #[test]
+    fn restart_delay_backs_off_exponentially() {
+        for restarts in 0..4 {
+            let delay = restart_delay(2, 60, restarts);
+            let max = 2 * 2u64.pow(restarts as u32) * 1_000;
+
+            assert!(delay.as_secs() * 1_000 + delay.subsec_nanos() as u64 / 1_000_000 <= max);
+            assert!(delay.as_secs() * 1_000 + delay.subsec_nanos() as u64 / 1_000_000 >= max / 2);
+        }
+    }
+
+    #[test]
+    fn restart_delay_is_capped() {
+        assert!(restart_delay(2, 60, 10).as_secs() <= 60);
+        assert!(restart_delay(2, 60, 200).as_secs() <= 60);
+    }
+
+    #[test]
+    fn remove_metrics_drops_the_series_of_the_service() {
+        let tmpdir = TempDir::new("metrics").unwrap();
+        let pkg_path = tmpdir.path().join("pkg");
+        let fs_cfg = Arc::new(FsCfg::new(tmpdir.path().join("sup")));
+        let install = fake_install("20170101000000", &pkg_path);
+        let mut spec = ServiceSpec::default_for(install.ident.clone());
+        spec.group = "metrics".to_string();
+        let sys = Arc::new(Sys::new(
+            true,
+            GossipListenAddr::default(),
+            ListenAddr::default(),
+        ));
+        let service = Service::new(sys, install, spec, fs_cfg, None).unwrap();
+        let labels = service.metric_labels();
+        service.update_uptime_metric();
+        SERVICE_HEALTH.with(&labels).set(0.0);
+        SERVICE_RESTARTS.with(&labels).inc();
+
+        service.remove_metrics();
+
+        // Removing a series which doesn't exist fails
+        assert!(SERVICE_UPTIME.remove(&labels).is_err());
+        assert!(SERVICE_HEALTH.remove(&labels).is_err());
+        assert!(SERVICE_RESTARTS.remove(&labels).is_err());
+    }
+
+    #[test]
+    fn failed_update_rolls_back_to_previous_release() {
+        let tmpdir = TempDir::new("rollback").unwrap();
+        let pkg_path = tmpdir.path().join("pkg");
+        let fs_cfg = Arc::new(FsCfg::new(tmpdir.path().join("sup")));
+        let old = fake_install("20170101000000", &pkg_path);
+        let mut spec = ServiceSpec::default_for(old.ident.clone());
+        spec.rollback_threshold = 2;
+        let sys = Arc::new(Sys::new(
+            true,
+            GossipListenAddr::default(),
+            ListenAddr::default(),
+        ));
+        let mut service = Service::new(sys, old.clone(), spec, fs_cfg, None).unwrap();
+
+        // An update to a new release which then fails its health checks
+        assert!(service.switch_package(fake_install("20170202000000", &pkg_path)));
+        assert_eq!(service.previous_release, Some(old.ident.clone()));
+        service.health_check = HealthCheck::Critical;
+        service.count_failed_health_checks();
+        assert_eq!(service.rollback_reason(), None);
+        service.count_failed_health_checks();
+        let reason = service.rollback_reason().expect("release should be rolled back");
+        assert_eq!(reason, "2 failed health checks");
+
+        assert!(service.roll_back_to(old.clone(), reason));
+        assert_eq!(service.pkg.ident, old.ident);
+        assert_eq!(service.spec_ident, old.ident);
+        assert_eq!(service.previous_release, None);
+        assert_eq!(service.rollback_reason(), None);
+        let rollback = service.rollback.clone().unwrap();
+        assert_eq!(rollback.from.release, Some("20170202000000".to_string()));
+        assert_eq!(rollback.to, old.ident);
+
+        // The spec on disk is pinned to the restored release
+        let pinned = ServiceSpec::from_file(&service.spec_file).unwrap();
+        assert_eq!(pinned.ident, old.ident);
+    }
 }
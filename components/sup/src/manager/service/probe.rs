@@ -0,0 +1,471 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Built-in health probes declared in a service spec. They let operators check the health of a
+//! service whose package doesn't ship a `health_check` hook.
+
+use std::cmp;
+use std::fmt;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::process::{Child, ExitStatus};
+use std::result;
+use std::str::FromStr;
+use std::sync::mpsc::{channel, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use hcore::service::ServiceGroup;
+use hcore::util::deserialize_using_from_str;
+use serde;
+use url::Url;
+
+use super::{HealthCheck, Pkg};
+use error::{Error, Result, SupError};
+use util;
+
+static LOGKEY: &'static str = "HP";
+const DEFAULT_INTERVAL: u64 = 10;
+const DEFAULT_TIMEOUT: u64 = 5;
+const DEFAULT_THRESHOLD: u32 = 3;
+const DEFAULT_HTTP_STATUS: u16 = 200;
+/// How often a running exec probe is checked for having exited.
+const EXEC_POLL_MS: u64 = 50;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ProbeKind {
+    /// An HTTP GET of the URL which must answer with the given status code
+    Http(String, u16),
+    /// A TCP connection to the address
+    Tcp(String),
+    /// An executable whose exit code is read like the one of a `health_check` hook
+    Exec(String),
+}
+
+/// A health probe in the form `<KIND>:<TARGET>[,interval=<SECS>][,timeout=<SECS>]
+/// [,threshold=<N>][,status=<CODE>]`, for example
+/// `http:http://localhost:8080/health,status=204` or `tcp:localhost:5432,interval=30`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct HealthProbe {
+    pub kind: ProbeKind,
+    /// Seconds between two runs of the probe
+    pub interval: u64,
+    /// Seconds a single run of the probe may take
+    pub timeout: u64,
+    /// Consecutive failed runs before the probe reports `Critical`
+    pub threshold: u32,
+}
+
+impl HealthProbe {
+    /// Start running the probe on its own thread. The thread exits once the returned monitor
+    /// is dropped.
+    pub fn start(&self, service_group: &ServiceGroup, pkg: &Pkg) -> ProbeMonitor {
+        let (tx, rx) = channel();
+        let probe = self.clone();
+        let pkg = pkg.clone();
+        thread::Builder::new()
+            .name(format!("health-probe-{}", service_group))
+            .spawn(move || {
+                let mut failures = 0;
+                loop {
+                    let next_run = Instant::now() + Duration::from_secs(probe.interval);
+                    let health = match probe.check(&pkg) {
+                        HealthCheck::Critical => {
+                            failures += 1;
+                            if failures >= probe.threshold {
+                                HealthCheck::Critical
+                            } else {
+                                HealthCheck::Warning
+                            }
+                        }
+                        health => {
+                            failures = 0;
+                            health
+                        }
+                    };
+                    if tx.send(health).is_err() {
+                        break;
+                    }
+                    let now = Instant::now();
+                    if next_run > now {
+                        thread::sleep(next_run - now);
+                    }
+                }
+            })
+            .expect("unable to start health-probe thread");
+        ProbeMonitor {
+            deadline: Duration::from_secs(self.interval + self.timeout),
+            rx: rx,
+            health: HealthCheck::default(),
+            last_report: Instant::now(),
+        }
+    }
+
+    /// Run the probe once.
+    fn check(&self, pkg: &Pkg) -> HealthCheck {
+        let timeout = Duration::from_secs(self.timeout);
+        match self.kind {
+            ProbeKind::Http(ref url, status) => {
+                match http_status(url, timeout) {
+                    Ok(code) if code == status => HealthCheck::Ok,
+                    Ok(code) => {
+                        debug!("Health probe {} answered with {}", self, code);
+                        HealthCheck::Critical
+                    }
+                    Err(err) => {
+                        debug!("Health probe {} failed, {}", self, err);
+                        HealthCheck::Critical
+                    }
+                }
+            }
+            ProbeKind::Tcp(ref address) => {
+                match connect(address, timeout) {
+                    Ok(_) => HealthCheck::Ok,
+                    Err(err) => {
+                        debug!("Health probe {} failed, {}", self, err);
+                        HealthCheck::Critical
+                    }
+                }
+            }
+            ProbeKind::Exec(ref path) => {
+                let status = match util::exec::run(path, pkg, None::<String>) {
+                    Ok(mut child) => wait_with_timeout(&mut child, timeout),
+                    Err(err) => Err(err.to_string()),
+                };
+                match status {
+                    Ok(None) => {
+                        debug!("Health probe {} timed out", self);
+                        HealthCheck::Critical
+                    }
+                    Ok(Some(status)) => {
+                        match status.code() {
+                            Some(0) => HealthCheck::Ok,
+                            Some(1) => HealthCheck::Warning,
+                            Some(2) => HealthCheck::Critical,
+                            _ => HealthCheck::Unknown,
+                        }
+                    }
+                    Err(err) => {
+                        debug!("Health probe {} failed, {}", self, err);
+                        HealthCheck::Critical
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for HealthProbe {
+    type Err = SupError;
+
+    fn from_str(probe_str: &str) -> result::Result<Self, Self::Err> {
+        let invalid = || sup_error!(Error::InvalidHealthProbe(probe_str.to_string()));
+        let mut parts: Vec<&str> = probe_str.split(',').collect();
+        let mut interval = DEFAULT_INTERVAL;
+        let mut timeout = DEFAULT_TIMEOUT;
+        let mut threshold = DEFAULT_THRESHOLD;
+        let mut status = None;
+        // Options trail the target, so only known options are split off the end and commas
+        // within the target are left alone.
+        while parts.len() > 1 {
+            let option: Vec<&str> = parts[parts.len() - 1].splitn(2, '=').collect();
+            if option.len() != 2 {
+                break;
+            }
+            match option[0] {
+                "interval" => interval = option[1].parse().map_err(|_| invalid())?,
+                "timeout" => timeout = option[1].parse().map_err(|_| invalid())?,
+                "threshold" => threshold = option[1].parse().map_err(|_| invalid())?,
+                "status" => status = Some(option[1].parse().map_err(|_| invalid())?),
+                _ => break,
+            }
+            parts.pop();
+        }
+        if interval == 0 || timeout == 0 || threshold == 0 {
+            return Err(invalid());
+        }
+        let probe = parts.join(",");
+        let values: Vec<&str> = probe.splitn(2, ':').collect();
+        if values.len() != 2 || values[1].is_empty() {
+            return Err(invalid());
+        }
+        let kind = match (values[0], status) {
+            ("http", status) => {
+                match Url::parse(values[1]) {
+                    Ok(ref url) if url.scheme() == "http" => (),
+                    _ => return Err(invalid()),
+                }
+                ProbeKind::Http(
+                    values[1].to_string(),
+                    status.unwrap_or(DEFAULT_HTTP_STATUS),
+                )
+            }
+            ("tcp", None) => ProbeKind::Tcp(values[1].to_string()),
+            ("exec", None) => ProbeKind::Exec(values[1].to_string()),
+            _ => return Err(invalid()),
+        };
+        Ok(HealthProbe {
+            kind: kind,
+            interval: interval,
+            timeout: timeout,
+            threshold: threshold,
+        })
+    }
+}
+
+impl fmt::Display for HealthProbe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ProbeKind::Http(ref url, _) => write!(f, "http:{}", url)?,
+            ProbeKind::Tcp(ref address) => write!(f, "tcp:{}", address)?,
+            ProbeKind::Exec(ref path) => write!(f, "exec:{}", path)?,
+        }
+        write!(
+            f,
+            ",interval={},timeout={},threshold={}",
+            self.interval,
+            self.timeout,
+            self.threshold
+        )?;
+        if let ProbeKind::Http(_, status) = self.kind {
+            write!(f, ",status={}", status)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for HealthProbe {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for HealthProbe {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Collects the results reported by a running health probe.
+#[derive(Debug)]
+pub struct ProbeMonitor {
+    /// Time without a report after which the probe is considered hung
+    deadline: Duration,
+    rx: Receiver<HealthCheck>,
+    health: HealthCheck,
+    last_report: Instant,
+}
+
+impl ProbeMonitor {
+    pub fn health(&self) -> HealthCheck {
+        self.health
+    }
+
+    /// Collect the reports of the probe since the last poll.
+    ///
+    /// Returns `true` if the health reported by the probe changed.
+    pub fn poll(&mut self) -> bool {
+        let previous = self.health;
+        loop {
+            match self.rx.try_recv() {
+                Ok(health) => {
+                    self.health = health;
+                    self.last_report = Instant::now();
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.health = HealthCheck::Unknown;
+                    break;
+                }
+            }
+        }
+        if self.last_report.elapsed() > self.deadline {
+            self.health = HealthCheck::Critical;
+        }
+        self.health != previous
+    }
+}
+
+/// Waits for the child to exit for at most `timeout`. A child which is still running by then is
+/// killed and `None` is returned.
+fn wait_with_timeout(
+    child: &mut Child,
+    timeout: Duration,
+) -> result::Result<Option<ExitStatus>, String> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => return Ok(Some(status)),
+            Ok(None) => (),
+            Err(err) => return Err(err.to_string()),
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            break;
+        }
+        thread::sleep(cmp::min(deadline - now, Duration::from_millis(EXEC_POLL_MS)));
+    }
+    // The child may have exited since it was last polled, which makes kill fail harmlessly.
+    child.kill().ok();
+    child.wait().map_err(|e| e.to_string())?;
+    Ok(None)
+}
+
+fn connect(address: &str, timeout: Duration) -> result::Result<TcpStream, String> {
+    let addrs: Vec<SocketAddr> = address
+        .to_socket_addrs()
+        .map_err(|e| e.to_string())?
+        .collect();
+    match addrs.first() {
+        Some(addr) => TcpStream::connect_timeout(addr, timeout).map_err(|e| e.to_string()),
+        None => Err(format!("{} did not resolve to an address", address)),
+    }
+}
+
+/// Returns the status code the URL answers a GET request with.
+fn http_status(url: &str, timeout: Duration) -> result::Result<u16, String> {
+    let url = Url::parse(url).map_err(|e| e.to_string())?;
+    let host = url.host_str().unwrap_or("localhost");
+    let port = url.port_or_known_default().unwrap_or(80);
+    let mut stream = connect(&format!("{}:{}", host, port), timeout)?;
+    stream.set_read_timeout(Some(timeout)).map_err(
+        |e| e.to_string(),
+    )?;
+    stream.set_write_timeout(Some(timeout)).map_err(
+        |e| e.to_string(),
+    )?;
+    let path = match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    };
+    write!(
+        stream,
+        "GET {} HTTP/1.0\r\nHost: {}\r\nUser-Agent: hab-sup\r\nConnection: close\r\n\r\n",
+        path,
+        host
+    ).map_err(|e| e.to_string())?;
+    // The status line is all we need, e.g. "HTTP/1.1 200 OK"
+    let mut buf = [0; 32];
+    let mut len = 0;
+    while len < buf.len() {
+        match stream.read(&mut buf[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(e) => return Err(e.to_string()),
+        }
+    }
+    let status_line = String::from_utf8_lossy(&buf[..len]);
+    match status_line.split_whitespace().nth(1).map(|c| c.parse()) {
+        Some(Ok(code)) if status_line.starts_with("HTTP/") => Ok(code),
+        _ => Err(format!("Invalid HTTP response from {}", url)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(unix)]
+    use std::process::Command;
+    use std::str::FromStr;
+
+    use super::*;
+    use error::Error::InvalidHealthProbe;
+
+    #[test]
+    fn health_probe_from_str_defaults() {
+        let probe = HealthProbe::from_str("tcp:localhost:5432").unwrap();
+
+        assert_eq!(probe.kind, ProbeKind::Tcp(String::from("localhost:5432")));
+        assert_eq!(probe.interval, DEFAULT_INTERVAL);
+        assert_eq!(probe.timeout, DEFAULT_TIMEOUT);
+        assert_eq!(probe.threshold, DEFAULT_THRESHOLD);
+    }
+
+    #[test]
+    fn health_probe_from_str_with_options() {
+        let probe = HealthProbe::from_str(
+            "http:http://localhost:8080/health?a=1,b=2,status=204,interval=30,threshold=1",
+        ).unwrap();
+
+        assert_eq!(
+            probe.kind,
+            ProbeKind::Http(String::from("http://localhost:8080/health?a=1,b=2"), 204)
+        );
+        assert_eq!(probe.interval, 30);
+        assert_eq!(probe.timeout, DEFAULT_TIMEOUT);
+        assert_eq!(probe.threshold, 1);
+    }
+
+    #[test]
+    fn health_probe_from_str_invalid() {
+        for probe_str in vec![
+            "dns:localhost",
+            "tcp:",
+            "tcp:localhost:5432,status=200",
+            "exec:/bin/check,interval=0",
+            "http:https://localhost/health",
+        ]
+        {
+            match HealthProbe::from_str(probe_str) {
+                Err(e) => {
+                    match e.err {
+                        InvalidHealthProbe(s) => assert_eq!(probe_str, s),
+                        wrong => panic!("Unexpected error returned: {:?}", wrong),
+                    }
+                }
+                Ok(_) => panic!("{} should fail to parse", probe_str),
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn wait_with_timeout_returns_the_exit_status() {
+        let mut child = Command::new("sh").args(&["-c", "exit 1"]).spawn().unwrap();
+
+        let status = wait_with_timeout(&mut child, Duration::from_secs(5)).unwrap();
+
+        assert_eq!(status.and_then(|s| s.code()), Some(1));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn wait_with_timeout_kills_a_probe_running_past_its_timeout() {
+        let mut child = Command::new("sleep").arg("30").spawn().unwrap();
+        let started = Instant::now();
+
+        let status = wait_with_timeout(&mut child, Duration::from_millis(200)).unwrap();
+
+        assert!(status.is_none());
+        assert!(started.elapsed() < Duration::from_secs(5));
+        // The probe was killed and reaped
+        assert!(child.try_wait().unwrap().is_some());
+    }
+
+    #[test]
+    fn health_probe_to_string_round_trips() {
+        let probe = HealthProbe::from_str("exec:/hab/svc/redis/check,timeout=2").unwrap();
+
+        assert_eq!(
+            "exec:/hab/svc/redis/check,interval=10,timeout=2,threshold=3",
+            probe.to_string()
+        );
+        assert_eq!(HealthProbe::from_str(&probe.to_string()).unwrap(), probe);
+    }
+}
@@ -30,11 +30,20 @@ use rand::{Rng, thread_rng};
 use serde::{self, Deserialize};
 use toml;
 
-use super::{Topology, UpdateStrategy};
+use super::{HealthProbe, RestartPolicy, Topology, UpdateStrategy};
 use error::{Error, Result, SupError};
 
 static LOGKEY: &'static str = "SS";
 static DEFAULT_GROUP: &'static str = "default";
+const DEFAULT_SHARDS: u32 = 1;
+const DEFAULT_UPDATE_CANARIES: u32 = 1;
+const DEFAULT_UPDATE_BATCH_PERCENT: u32 = 25;
+const DEFAULT_UPDATE_HEALTH_WINDOW: u64 = 120;
+const DEFAULT_ROLLBACK_THRESHOLD: u32 = 3;
+const DEFAULT_RESTART_MAX_RETRIES: u32 = 10;
+const DEFAULT_RESTART_WINDOW: u64 = 300;
+const DEFAULT_RESTART_BACKOFF: u64 = 1;
+const DEFAULT_RESTART_BACKOFF_MAX: u64 = 60;
 const SPEC_FILE_EXT: &'static str = "spec";
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -100,7 +109,44 @@ pub struct ServiceSpec {
     pub bldr_url: String,
     pub channel: String,
     pub topology: Topology,
+    // Number of shards of a `sharded` service group, or of leaders of a `multi-leader` one
+    pub shards: u32,
+    // Member tag, as `KEY=VALUE`, whose carriers are preferred when electing a leader
+    pub leader_tag: Option<String>,
+    // Member tag whose values name the zones shard leaders and rollouts are spread across
+    pub zone_tag: Option<String>,
     pub update_strategy: UpdateStrategy,
+    // Number of members updated first by the `canary` update strategy
+    pub update_canaries: u32,
+    // Percentage of members updated at a time by the `canary` and `batched` update strategies
+    pub update_batch_percent: u32,
+    // Seconds a stage must stay healthy before the next stage of a rollout begins
+    pub update_health_window: u64,
+    // Failed health checks or restarts of a new release before rolling back to the previous
+    // release; `0` disables automatic rollback
+    pub rollback_threshold: u32,
+    // Built-in probes checked alongside the package's `health_check` hook
+    pub health_probes: Vec<HealthProbe>,
+    pub restart_policy: RestartPolicy,
+    // Restarts allowed within `restart_window` before the service is marked as failed; `0`
+    // allows unlimited restarts
+    pub restart_max_retries: u32,
+    // Seconds over which restarts are counted against `restart_max_retries`
+    pub restart_window: u64,
+    // Seconds to wait before the first restart, doubled with each further restart in the window
+    pub restart_backoff: u64,
+    // Upper bound in seconds of the wait between restarts
+    pub restart_backoff_max: u64,
+    // Relative CPU weight of the service, in cgroup `cpu.shares` units
+    pub cpu_shares: Option<u64>,
+    // Hard limit on the CPU time of the service, in thousandths of a CPU
+    pub cpu_quota: Option<u64>,
+    // Memory limit of the service, in bytes
+    pub memory_limit: Option<u64>,
+    // Maximum number of processes of the service
+    pub pids_limit: Option<u64>,
+    // Maximum number of open files of each process of the service
+    pub nofile_limit: Option<u64>,
     pub binds: Vec<ServiceBind>,
     pub config_from: Option<PathBuf>,
     #[serde(deserialize_with = "deserialize_using_from_str",
@@ -176,7 +222,12 @@ impl ServiceSpec {
     }
 
     pub fn file_name(&self) -> String {
-        format!("{}.{}", &self.ident.name, SPEC_FILE_EXT)
+        Self::file_name_for(&self.ident.name)
+    }
+
+    /// Returns the spec file name of the service for the named package.
+    pub fn file_name_for(name: &str) -> String {
+        format!("{}.{}", name, SPEC_FILE_EXT)
     }
 
     pub fn validate(&self, package: &PackageInstall) -> Result<()> {
@@ -237,7 +288,25 @@ impl Default for ServiceSpec {
             bldr_url: DEFAULT_BLDR_URL.to_string(),
             channel: STABLE_CHANNEL.to_string(),
             topology: Topology::default(),
+            shards: DEFAULT_SHARDS,
+            leader_tag: None,
+            zone_tag: None,
             update_strategy: UpdateStrategy::default(),
+            update_canaries: DEFAULT_UPDATE_CANARIES,
+            update_batch_percent: DEFAULT_UPDATE_BATCH_PERCENT,
+            update_health_window: DEFAULT_UPDATE_HEALTH_WINDOW,
+            rollback_threshold: DEFAULT_ROLLBACK_THRESHOLD,
+            health_probes: Vec::default(),
+            restart_policy: RestartPolicy::default(),
+            restart_max_retries: DEFAULT_RESTART_MAX_RETRIES,
+            restart_window: DEFAULT_RESTART_WINDOW,
+            restart_backoff: DEFAULT_RESTART_BACKOFF,
+            restart_backoff_max: DEFAULT_RESTART_BACKOFF_MAX,
+            cpu_shares: None,
+            cpu_quota: None,
+            memory_limit: None,
+            pids_limit: None,
+            nofile_limit: None,
             binds: Vec::default(),
             config_from: None,
             desired_state: DesiredState::default(),
@@ -386,7 +455,25 @@ mod test {
             application_environment = "theinternet.preprod"
             bldr_url = "http://example.com/depot"
             topology = "leader"
-            update_strategy = "rolling"
+            shards = 3
+            leader_tag = "az=us-east-1a"
+            zone_tag = "rack"
+            update_strategy = "canary"
+            update_canaries = 3
+            update_batch_percent = 10
+            update_health_window = 60
+            rollback_threshold = 0
+            health_probes = ["tcp:localhost:6379,interval=30"]
+            restart_policy = "on-failure"
+            restart_max_retries = 0
+            restart_window = 60
+            restart_backoff = 5
+            restart_backoff_max = 120
+            cpu_shares = 2048
+            cpu_quota = 500
+            memory_limit = 536870912
+            pids_limit = 64
+            nofile_limit = 65536
             binds = ["cache:redis.cache@acmecorp", "db:postgres.app@acmecorp"]
             start_style = "persistent"
             config_from = "/only/for/development"
@@ -408,7 +495,28 @@ mod test {
         );
         assert_eq!(spec.bldr_url, String::from("http://example.com/depot"));
         assert_eq!(spec.topology, Topology::Leader);
-        assert_eq!(spec.update_strategy, UpdateStrategy::Rolling);
+        assert_eq!(spec.shards, 3);
+        assert_eq!(spec.leader_tag, Some(String::from("az=us-east-1a")));
+        assert_eq!(spec.zone_tag, Some(String::from("rack")));
+        assert_eq!(spec.update_strategy, UpdateStrategy::Canary);
+        assert_eq!(spec.update_canaries, 3);
+        assert_eq!(spec.update_batch_percent, 10);
+        assert_eq!(spec.update_health_window, 60);
+        assert_eq!(spec.rollback_threshold, 0);
+        assert_eq!(
+            spec.health_probes,
+            vec![HealthProbe::from_str("tcp:localhost:6379,interval=30").unwrap()]
+        );
+        assert_eq!(spec.restart_policy, RestartPolicy::OnFailure);
+        assert_eq!(spec.restart_max_retries, 0);
+        assert_eq!(spec.restart_window, 60);
+        assert_eq!(spec.restart_backoff, 5);
+        assert_eq!(spec.restart_backoff_max, 120);
+        assert_eq!(spec.cpu_shares, Some(2048));
+        assert_eq!(spec.cpu_quota, Some(500));
+        assert_eq!(spec.memory_limit, Some(536870912));
+        assert_eq!(spec.pids_limit, Some(64));
+        assert_eq!(spec.nofile_limit, Some(65536));
         assert_eq!(
             spec.binds,
             vec![
@@ -487,7 +595,25 @@ mod test {
             bldr_url: String::from("http://example.com/depot"),
             channel: String::from("unstable"),
             topology: Topology::Leader,
+            shards: 2,
+            leader_tag: Some(String::from("az=us-east-1a")),
+            zone_tag: Some(String::from("rack")),
             update_strategy: UpdateStrategy::AtOnce,
+            update_canaries: 2,
+            update_batch_percent: 50,
+            update_health_window: 300,
+            rollback_threshold: 5,
+            health_probes: vec![HealthProbe::from_str("tcp:localhost:80").unwrap()],
+            restart_policy: RestartPolicy::Never,
+            restart_max_retries: 3,
+            restart_window: 30,
+            restart_backoff: 2,
+            restart_backoff_max: 10,
+            cpu_shares: Some(512),
+            cpu_quota: Some(1500),
+            memory_limit: Some(268435456),
+            pids_limit: Some(100),
+            nofile_limit: Some(4096),
             binds: vec![
                 ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                 ServiceBind::from_str("db:postgres.app@acmecorp").unwrap(),
@@ -510,7 +636,27 @@ mod test {
         assert!(toml.contains(r#"bldr_url = "http://example.com/depot""#));
         assert!(toml.contains(r#"channel = "unstable""#));
         assert!(toml.contains(r#"topology = "leader""#));
+        assert!(toml.contains(r#"shards = 2"#));
+        assert!(toml.contains(r#"leader_tag = "az=us-east-1a""#));
+        assert!(toml.contains(r#"zone_tag = "rack""#));
         assert!(toml.contains(r#"update_strategy = "at-once""#));
+        assert!(toml.contains(r#"update_canaries = 2"#));
+        assert!(toml.contains(r#"update_batch_percent = 50"#));
+        assert!(toml.contains(r#"update_health_window = 300"#));
+        assert!(toml.contains(r#"rollback_threshold = 5"#));
+        assert!(toml.contains(
+            r#"health_probes = ["tcp:localhost:80,interval=10,timeout=5,threshold=3"]"#,
+        ));
+        assert!(toml.contains(r#"restart_policy = "never""#));
+        assert!(toml.contains(r#"restart_max_retries = 3"#));
+        assert!(toml.contains(r#"restart_window = 30"#));
+        assert!(toml.contains(r#"restart_backoff = 2"#));
+        assert!(toml.contains(r#"restart_backoff_max = 10"#));
+        assert!(toml.contains(r#"cpu_shares = 512"#));
+        assert!(toml.contains(r#"cpu_quota = 1500"#));
+        assert!(toml.contains(r#"memory_limit = 268435456"#));
+        assert!(toml.contains(r#"pids_limit = 100"#));
+        assert!(toml.contains(r#"nofile_limit = 4096"#));
         assert!(toml.contains(r#""cache:redis.cache@acmecorp""#));
         assert!(toml.contains(r#""db:postgres.app@acmecorp""#));
         assert!(toml.contains(r#"desired_state = "down""#));
@@ -646,7 +792,25 @@ mod test {
             bldr_url: String::from("http://example.com/depot"),
             channel: String::from("unstable"),
             topology: Topology::Leader,
+            shards: 2,
+            leader_tag: Some(String::from("az=us-east-1a")),
+            zone_tag: Some(String::from("rack")),
             update_strategy: UpdateStrategy::AtOnce,
+            update_canaries: 2,
+            update_batch_percent: 50,
+            update_health_window: 300,
+            rollback_threshold: 5,
+            health_probes: vec![HealthProbe::from_str("tcp:localhost:80").unwrap()],
+            restart_policy: RestartPolicy::Never,
+            restart_max_retries: 3,
+            restart_window: 30,
+            restart_backoff: 2,
+            restart_backoff_max: 10,
+            cpu_shares: Some(512),
+            cpu_quota: Some(1500),
+            memory_limit: Some(268435456),
+            pids_limit: Some(100),
+            nofile_limit: Some(4096),
             binds: vec![
                 ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                 ServiceBind::from_str("db:postgres.app@acmecorp").unwrap(),
@@ -670,7 +834,27 @@ mod test {
         assert!(toml.contains(r#"bldr_url = "http://example.com/depot""#));
         assert!(toml.contains(r#"channel = "unstable""#));
         assert!(toml.contains(r#"topology = "leader""#));
+        assert!(toml.contains(r#"shards = 2"#));
+        assert!(toml.contains(r#"leader_tag = "az=us-east-1a""#));
+        assert!(toml.contains(r#"zone_tag = "rack""#));
         assert!(toml.contains(r#"update_strategy = "at-once""#));
+        assert!(toml.contains(r#"update_canaries = 2"#));
+        assert!(toml.contains(r#"update_batch_percent = 50"#));
+        assert!(toml.contains(r#"update_health_window = 300"#));
+        assert!(toml.contains(r#"rollback_threshold = 5"#));
+        assert!(toml.contains(
+            r#"health_probes = ["tcp:localhost:80,interval=10,timeout=5,threshold=3"]"#,
+        ));
+        assert!(toml.contains(r#"restart_policy = "never""#));
+        assert!(toml.contains(r#"restart_max_retries = 3"#));
+        assert!(toml.contains(r#"restart_window = 30"#));
+        assert!(toml.contains(r#"restart_backoff = 2"#));
+        assert!(toml.contains(r#"restart_backoff_max = 10"#));
+        assert!(toml.contains(r#"cpu_shares = 512"#));
+        assert!(toml.contains(r#"cpu_quota = 1500"#));
+        assert!(toml.contains(r#"memory_limit = 268435456"#));
+        assert!(toml.contains(r#"pids_limit = 100"#));
+        assert!(toml.contains(r#"nofile_limit = 4096"#));
         assert!(toml.contains(r#""cache:redis.cache@acmecorp""#));
         assert!(toml.contains(r#""db:postgres.app@acmecorp""#));
         assert!(toml.contains(r#"desired_state = "down""#));
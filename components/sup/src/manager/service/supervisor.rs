@@ -16,7 +16,8 @@
 ///
 /// The Supervisor is responsible for running any services we are asked to start. It handles
 /// spawning the new process, watching for failure, and ensuring the service is either up or down.
-/// If the process dies, the Supervisor will restart it.
+/// If the process dies, the Supervisor will restart it according to the restart policy of the
+/// service.
 
 use std;
 use std::fmt;
@@ -28,7 +29,7 @@ use hcore::os::process::{self, Pid};
 use std::result;
 
 use hcore::service::ServiceGroup;
-use launcher_client::LauncherCli;
+use launcher_client::{self, LauncherCli, ResourceLimits};
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
 use time::{self, Timespec};
@@ -43,6 +44,8 @@ static LOGKEY: &'static str = "SV";
 pub enum ProcessState {
     Down,
     Up,
+    /// The process crashed too often to be restarted again
+    Failed,
 }
 
 impl fmt::Display for ProcessState {
@@ -50,11 +53,33 @@ impl fmt::Display for ProcessState {
         let state = match *self {
             ProcessState::Down => "down",
             ProcessState::Up => "up",
+            ProcessState::Failed => "failed",
         };
         write!(f, "{}", state)
     }
 }
 
+/// Resource usage of a service's processes, as last reported by the Launcher.
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct ResourceUsage {
+    /// Total CPU time consumed, in microseconds
+    pub cpu_usage_usec: Option<u64>,
+    /// Current memory usage, in bytes
+    pub memory_usage: Option<u64>,
+    /// Number of processes
+    pub pids: Option<u64>,
+}
+
+impl From<launcher_client::ResourceUsage> for ResourceUsage {
+    fn from(usage: launcher_client::ResourceUsage) -> Self {
+        ResourceUsage {
+            cpu_usage_usec: usage.cpu_usage_usec,
+            memory_usage: usage.memory_usage,
+            pids: usage.pids,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Supervisor {
     pub preamble: String,
@@ -62,6 +87,9 @@ pub struct Supervisor {
     pub state_entered: Timespec,
     pid: Option<Pid>,
     pid_file: PathBuf,
+    // PID of the last process found to have exited on its own
+    exited_pid: Option<Pid>,
+    resource_usage: Option<ResourceUsage>,
 }
 
 impl Supervisor {
@@ -72,6 +100,8 @@ impl Supervisor {
             state_entered: time::get_time(),
             pid: None,
             pid_file: fs::svc_pid_file(service_group.service()),
+            exited_pid: None,
+            resource_usage: None,
         }
     }
 
@@ -95,7 +125,13 @@ impl Supervisor {
             }
         }
         debug!("Could not find a live process with pid {:?}", self.pid);
-        self.change_state(ProcessState::Down);
+        if pid.is_some() {
+            self.exited_pid = pid;
+        }
+        // A failed process stays failed until it is explicitly started again
+        if self.state != ProcessState::Failed {
+            self.change_state(ProcessState::Down);
+        }
         self.cleanup_pidfile();
         self.pid = None;
         false
@@ -107,12 +143,17 @@ impl Supervisor {
         group: &ServiceGroup,
         launcher: &LauncherCli,
         svc_password: Option<T>,
+        limits: &ResourceLimits,
     ) -> Result<()>
     where
         T: ToString,
     {
         outputln!(preamble self.preamble,
             "Starting service as user={}, group={}", &pkg.svc_user, &pkg.svc_group);
+        if *limits != ResourceLimits::default() && !launcher.handles_resources() {
+            outputln!(preamble self.preamble,
+                "Not applying resource limits, as the Launcher is too old to apply them");
+        }
         let pid = launcher.spawn(
             group.to_string(),
             &pkg.svc_run,
@@ -120,6 +161,7 @@ impl Supervisor {
             &pkg.svc_group,
             svc_password,
             (*pkg.env).clone(),
+            limits,
         )?;
         self.pid = Some(pid);
         self.create_pidfile()?;
@@ -136,7 +178,7 @@ impl Supervisor {
         );
         let healthy = match self.state {
             ProcessState::Up => true,
-            ProcessState::Down => false,
+            ProcessState::Down | ProcessState::Failed => false,
         };
         (healthy, status)
     }
@@ -151,12 +193,34 @@ impl Supervisor {
         Ok(())
     }
 
+    /// Give up on restarting the exited process.
+    pub fn fail(&mut self) {
+        self.change_state(ProcessState::Failed);
+    }
+
+    /// Returns the exit code of the last process which exited on its own, or `None` if it was
+    /// terminated by a signal or its exit code is unknown.
+    pub fn exit_code(&mut self, launcher: &LauncherCli) -> Option<i32> {
+        let pid = match self.exited_pid.take() {
+            Some(pid) => pid,
+            None => return None,
+        };
+        match launcher.exit_code(pid) {
+            Ok(code) => code,
+            Err(err) => {
+                debug!("Unable to retrieve exit code of {}, {}", pid, err);
+                None
+            }
+        }
+    }
+
     pub fn restart<T>(
         &mut self,
         pkg: &Pkg,
         group: &ServiceGroup,
         launcher: &LauncherCli,
         svc_password: Option<T>,
+        limits: &ResourceLimits,
     ) -> Result<()>
     where
         T: ToString,
@@ -177,8 +241,30 @@ impl Supervisor {
                     }
                 }
             }
-            None => self.start(pkg, group, launcher, svc_password),
+            None => self.start(pkg, group, launcher, svc_password, limits),
+        }
+    }
+
+    /// Refresh the resource usage of the running process from the Launcher. Returns whether it
+    /// changed.
+    pub fn update_resource_usage(&mut self, launcher: &LauncherCli) -> bool {
+        let usage = match self.pid {
+            Some(pid) => {
+                match launcher.resource_usage(pid) {
+                    Ok(usage) => Some(ResourceUsage::from(usage)),
+                    Err(err) => {
+                        debug!("Unable to retrieve resource usage of {}, {}", pid, err);
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+        if usage == self.resource_usage {
+            return false;
         }
+        self.resource_usage = usage;
+        true
     }
 
     /// Create a PID file for a running service
@@ -232,6 +318,7 @@ impl Serialize for Supervisor {
             "state_entered",
             &self.state_entered.sec,
         )?;
+        strukt.serialize_field("resource_usage", &self.resource_usage)?;
         strukt.end()
     }
 }
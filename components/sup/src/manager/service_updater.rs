@@ -12,20 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
 use std::collections::HashMap;
 use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TryRecvError};
 use std::thread;
+use std::time::{Duration, Instant};
 
 use butterfly;
 use common::ui::UI;
 use env;
-use hcore::package::{PackageIdent, PackageInstall};
+use hcore::package::{Identifiable, PackageIdent, PackageInstall};
 use hcore::service::ServiceGroup;
 use launcher_client::LauncherCli;
 
-use census::CensusRing;
+use census::{CensusGroup, CensusMember, CensusRing};
 use manager::periodic::Periodic;
-use manager::service::{Service, Topology, UpdateStrategy};
+use manager::service::{HealthCheck, Service, Topology, UpdateStrategy};
 use util;
 
 static LOGKEY: &'static str = "SU";
@@ -51,6 +53,10 @@ enum RollingState {
 enum LeaderState {
     Polling(Receiver<PackageInstall>),
     Waiting,
+    /// Watching a canary or batched rollout; holds the package we updated from
+    Rollout(PackageIdent),
+    /// Reverting to the previous package after the canaries failed
+    RollingBack(Receiver<PackageInstall>),
 }
 
 /// Current package update state of a follower in a leader-follower
@@ -58,6 +64,9 @@ enum LeaderState {
 enum FollowerState {
     /// Waiting to be told to update
     Waiting,
+    /// Waiting for the earlier stages of a canary or batched rollout to
+    /// stay healthy; holds the time they were first seen healthy
+    AwaitingStage(Option<Instant>),
     /// Currently updating
     Updating(Receiver<PackageInstall>),
 }
@@ -67,6 +76,8 @@ enum FollowerState {
 /// To use an update strategy, the supervisor must be configured to watch a depot for new versions.
 pub struct ServiceUpdater {
     states: UpdaterStateList,
+    /// Releases which failed on the canaries and must not be rolled out again
+    failed_releases: HashMap<ServiceGroup, PackageIdent>,
     butterfly: butterfly::Server,
 }
 
@@ -74,6 +85,7 @@ impl ServiceUpdater {
     pub fn new(butterfly: butterfly::Server) -> Self {
         ServiceUpdater {
             states: UpdaterStateList::default(),
+            failed_releases: HashMap::default(),
             butterfly: butterfly,
         }
     }
@@ -93,7 +105,9 @@ impl ServiceUpdater {
                     });
                 true
             }
-            UpdateStrategy::Rolling => {
+            UpdateStrategy::Rolling |
+            UpdateStrategy::Canary |
+            UpdateStrategy::Batched => {
                 self.states.entry(service.service_group.clone()).or_insert(
                     UpdaterState::Rolling(RollingState::AwaitingElection),
                 );
@@ -118,6 +132,14 @@ impl ServiceUpdater {
         match self.states.get_mut(&service.service_group) {
             Some(&mut UpdaterState::AtOnce(ref mut rx)) => {
                 match rx.try_recv() {
+                    Ok(ref package) if !package.ident().satisfies(&service.spec_ident) => {
+                        debug!(
+                            "Ignoring {}, service is pinned to {}",
+                            package.ident(),
+                            service.spec_ident
+                        );
+                        *rx = Worker::new(service).start(&service.service_group, None);
+                    }
                     Ok(package) => {
                         service.update_package(package, launcher);
                         return true;
@@ -155,9 +177,13 @@ impl ServiceUpdater {
                         }
                     } else {
                         debug!("Rolling update, using default suitability");
+                        let tagged = census_group
+                            .me()
+                            .and_then(|me| service.leader_tag_matches(&me.tags))
+                            .unwrap_or(false);
                         self.butterfly.start_update_election(
                             service.service_group.clone(),
-                            0,
+                            if tagged { u64::max_value() } else { 0 },
                             0,
                         );
                         *st = RollingState::InElection;
@@ -175,7 +201,9 @@ impl ServiceUpdater {
                                 *st = RollingState::Leader(LeaderState::Waiting);
                             } else {
                                 debug!("We're a follower");
-                                *st = RollingState::Follower(FollowerState::Waiting);
+                                *st = RollingState::Follower(
+                                    FollowerState::idle(service.update_strategy),
+                                );
                             }
                         }
                         (Some(_), None) => return false,
@@ -184,18 +212,37 @@ impl ServiceUpdater {
                 }
             }
             Some(&mut UpdaterState::Rolling(RollingState::Leader(ref mut state))) => {
+                let mut next_state = None;
                 match *state {
                     LeaderState::Polling(ref mut rx) => {
                         match rx.try_recv() {
+                            Ok(ref package) if !package.ident().satisfies(&service.spec_ident) => {
+                                debug!(
+                                    "Ignoring {}, service is pinned to {}",
+                                    package.ident(),
+                                    service.spec_ident
+                                );
+                                *rx = Worker::new(service)
+                                    .skipping(self.failed_releases.get(&service.service_group))
+                                    .start(&service.service_group, None);
+                            }
                             Ok(package) => {
                                 debug!("Rolling Update, polling found a new package");
+                                let previous = service.pkg.ident.clone();
                                 service.update_package(package, launcher);
+                                if is_staged(service.update_strategy) {
+                                    next_state = Some(LeaderState::Rollout(previous));
+                                } else {
+                                    next_state = Some(LeaderState::Waiting);
+                                }
                                 updated = true;
                             }
                             Err(TryRecvError::Empty) => return false,
                             Err(TryRecvError::Disconnected) => {
                                 debug!("Service Updater worker has died; restarting...");
-                                *rx = Worker::new(service).start(&service.service_group, None);
+                                *rx = Worker::new(service)
+                                    .skipping(self.failed_releases.get(&service.service_group))
+                                    .start(&service.service_group, None);
                             }
                         }
                     }
@@ -210,7 +257,9 @@ impl ServiceUpdater {
                                     debug!("Update leader still waiting for followers...");
                                     return false;
                                 }
-                                let rx = Worker::new(service).start(&service.service_group, None);
+                                let rx = Worker::new(service)
+                                    .skipping(self.failed_releases.get(&service.service_group))
+                                    .start(&service.service_group, None);
                                 *state = LeaderState::Polling(rx);
                             }
                             None => {
@@ -221,9 +270,67 @@ impl ServiceUpdater {
                             }
                         }
                     }
+                    LeaderState::Rollout(ref previous) => {
+                        let census_group = match census_ring
+                            .census_group_for(&service.service_group) {
+                            Some(census_group) => census_group,
+                            None => {
+                                panic!(
+                                    "Expected census list to have service group '{}'!",
+                                    &*service.service_group
+                                )
+                            }
+                        };
+                        let target = Some(service.pkg.ident.clone());
+                        let members = rollout_order(service, census_group);
+                        if service.update_strategy == UpdateStrategy::Canary &&
+                            members.iter().enumerate().any(|(position, cm)| {
+                                rollout_stage(service, position, members.len()) == 0 &&
+                                    cm.pkg == target &&
+                                    cm.health == HealthCheck::Critical
+                            })
+                        {
+                            outputln!(preamble service.service_group,
+                                      "Canary update to {} failed health checks; \
+                                       rolling back to {}",
+                                      service.pkg.ident, previous);
+                            self.failed_releases.insert(
+                                service.service_group.clone(),
+                                service.pkg.ident.clone(),
+                            );
+                            let rx = Worker::new(service).start(
+                                &service.service_group,
+                                Some(previous.clone()),
+                            );
+                            next_state = Some(LeaderState::RollingBack(rx));
+                        } else if members.iter().all(|cm| cm.pkg == target) {
+                            debug!("Update leader finished rollout");
+                            let rx = Worker::new(service)
+                                .skipping(self.failed_releases.get(&service.service_group))
+                                .start(&service.service_group, None);
+                            next_state = Some(LeaderState::Polling(rx));
+                        } else {
+                            debug!("Update leader still waiting for rollout...");
+                            return false;
+                        }
+                    }
+                    LeaderState::RollingBack(ref mut rx) => {
+                        match rx.try_recv() {
+                            Ok(package) => {
+                                service.update_package(package, launcher);
+                                next_state = Some(LeaderState::Waiting);
+                                updated = true;
+                            }
+                            Err(TryRecvError::Empty) => return false,
+                            Err(TryRecvError::Disconnected) => {
+                                debug!("Service Updater worker has died; leaving rollback");
+                                next_state = Some(LeaderState::Waiting);
+                            }
+                        }
+                    }
                 }
-                if updated {
-                    *state = LeaderState::Waiting;
+                if let Some(next_state) = next_state {
+                    *state = next_state;
                 }
             }
             Some(&mut UpdaterState::Rolling(RollingState::Follower(ref mut state))) => {
@@ -233,7 +340,9 @@ impl ServiceUpdater {
                             Some(census_group) => {
                                 match (
                                     census_group.update_leader(),
-                                    census_group.previous_peer(),
+                                    census_group.previous_peer(
+                                        service.zone_tag.as_ref().map(|t| t.as_str()),
+                                    ),
                                     census_group.me(),
                                 ) {
                                     (Some(leader), Some(peer), Some(me)) => {
@@ -263,6 +372,62 @@ impl ServiceUpdater {
                             }
                         }
                     }
+                    FollowerState::AwaitingStage(ref mut healthy_since) => {
+                        let census_group = match census_ring
+                            .census_group_for(&service.service_group) {
+                            Some(census_group) => census_group,
+                            None => {
+                                panic!(
+                                    "Expected census list to have service group '{}'!",
+                                    &*service.service_group
+                                )
+                            }
+                        };
+                        let (leader, me) = match (census_group.update_leader(), census_group.me()) {
+                            (Some(leader), Some(me)) => (leader, me),
+                            _ => return false,
+                        };
+                        if leader.pkg == me.pkg {
+                            debug!("We're not in an update");
+                            *healthy_since = None;
+                            return false;
+                        }
+                        let members = rollout_order(service, census_group);
+                        let stage = match members.iter().position(
+                            |cm| cm.member_id == me.member_id,
+                        ) {
+                            Some(position) => rollout_stage(service, position, members.len()),
+                            None => return false,
+                        };
+                        if stage > 0 {
+                            let earlier_stages_healthy =
+                                members.iter().enumerate().all(|(position, cm)| {
+                                    rollout_stage(service, position, members.len()) >= stage ||
+                                        (cm.pkg == leader.pkg && cm.health == HealthCheck::Ok)
+                                });
+                            if !earlier_stages_healthy {
+                                debug!("We're in an update but it's not our stage");
+                                *healthy_since = None;
+                                return false;
+                            }
+                            let window = Duration::from_secs(service.update_health_window);
+                            match *healthy_since {
+                                Some(since) if since.elapsed() >= window => (),
+                                Some(_) => return false,
+                                None => {
+                                    debug!("Earlier stages are healthy; starting health window");
+                                    *healthy_since = Some(Instant::now());
+                                    return false;
+                                }
+                            }
+                        }
+                        debug!("We're in an update and it's our stage");
+                        let rx = Worker::new(service).start(
+                            &service.service_group,
+                            leader.pkg.clone(),
+                        );
+                        *state = FollowerState::Updating(rx);
+                    }
                     FollowerState::Updating(ref mut rx) => {
                         match census_ring.census_group_for(&service.service_group) {
                             Some(census_group) => {
@@ -293,7 +458,7 @@ impl ServiceUpdater {
                     }
                 }
                 if updated {
-                    *state = FollowerState::Waiting;
+                    *state = FollowerState::idle(service.update_strategy);
                 }
             }
             None => {}
@@ -302,11 +467,79 @@ impl ServiceUpdater {
     }
 }
 
+impl FollowerState {
+    /// The state a follower returns to while no update is in progress.
+    fn idle(strategy: UpdateStrategy) -> Self {
+        if is_staged(strategy) {
+            FollowerState::AwaitingStage(None)
+        } else {
+            FollowerState::Waiting
+        }
+    }
+}
+
+/// Returns `true` if the strategy rolls out updates in health-gated stages.
+fn is_staged(strategy: UpdateStrategy) -> bool {
+    strategy == UpdateStrategy::Canary || strategy == UpdateStrategy::Batched
+}
+
+/// Returns the order in which members of a group receive an update: the update leader first,
+/// followed by all other alive members ordered by member id, zone by zone if the service has a
+/// `zone_tag`.
+fn rollout_order<'a>(service: &Service, census_group: &'a CensusGroup) -> Vec<&'a CensusMember> {
+    let leader = census_group.update_leader();
+    let leader_id = leader.map(|cm| cm.member_id.clone());
+    let zone_tag = service.zone_tag.as_ref().map(|t| t.as_str());
+    leader
+        .into_iter()
+        .chain(
+            census_group
+                .alive_members_by_zone(zone_tag)
+                .into_iter()
+                .filter(|cm| Some(&cm.member_id) != leader_id.as_ref()),
+        )
+        .collect()
+}
+
+fn rollout_stage(service: &Service, position: usize, members: usize) -> usize {
+    stage_for_position(
+        service.update_strategy,
+        position,
+        members,
+        service.update_canaries,
+        service.update_batch_percent,
+    )
+}
+
+/// Returns the rollout stage of the member at `position` in the rollout order. Stage `0` is
+/// updated first and every following stage waits for all earlier stages to be healthy.
+fn stage_for_position(
+    strategy: UpdateStrategy,
+    position: usize,
+    members: usize,
+    canaries: u32,
+    batch_percent: u32,
+) -> usize {
+    let batch = cmp::max(1, (members * batch_percent as usize + 99) / 100);
+    match strategy {
+        UpdateStrategy::Canary => {
+            let canaries = canaries as usize;
+            if position < canaries {
+                0
+            } else {
+                1 + (position - canaries) / batch
+            }
+        }
+        _ => position / batch,
+    }
+}
+
 struct Worker {
     current: PackageIdent,
     spec_ident: PackageIdent,
     builder_url: String,
     channel: String,
+    skip: Option<PackageIdent>,
 }
 
 impl Periodic for Worker {
@@ -368,9 +601,16 @@ impl Worker {
             spec_ident: service.spec_ident.clone(),
             builder_url: service.bldr_url.clone(),
             channel: service.channel.clone(),
+            skip: None,
         }
     }
 
+    /// Never update to the given release while polling.
+    fn skipping(mut self, ident: Option<&PackageIdent>) -> Self {
+        self.skip = ident.cloned();
+        self
+    }
+
     /// Start a new update worker.
     ///
     /// Passing an optional package identifier will make the worker perform a run-once update to
@@ -453,7 +693,9 @@ impl Worker {
                 &self.channel,
             ) {
                 Ok(maybe_newer_package) => {
-                    if self.current < *maybe_newer_package.ident() {
+                    if self.skip.as_ref() == Some(maybe_newer_package.ident()) {
+                        debug!("Package found is a release which failed to roll out");
+                    } else if self.current < *maybe_newer_package.ident() {
                         outputln!(
                             "Updating from {} to {}",
                             self.current,
@@ -475,3 +717,33 @@ impl Worker {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::stage_for_position;
+    use manager::service::UpdateStrategy;
+
+    #[test]
+    fn canary_stages() {
+        let stages: Vec<usize> = (0..10)
+            .map(|p| stage_for_position(UpdateStrategy::Canary, p, 10, 2, 25))
+            .collect();
+
+        assert_eq!(stages, vec![0, 0, 1, 1, 1, 2, 2, 2, 3, 3]);
+    }
+
+    #[test]
+    fn batched_stages() {
+        let stages: Vec<usize> = (0..10)
+            .map(|p| stage_for_position(UpdateStrategy::Batched, p, 10, 2, 25))
+            .collect();
+
+        assert_eq!(stages, vec![0, 0, 0, 1, 1, 1, 2, 2, 2, 3]);
+    }
+
+    #[test]
+    fn stages_always_contain_one_member() {
+        assert_eq!(stage_for_position(UpdateStrategy::Batched, 1, 3, 1, 1), 1);
+        assert_eq!(stage_for_position(UpdateStrategy::Canary, 2, 3, 1, 1), 2);
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::str;
 
@@ -36,6 +37,11 @@ pub struct Sys {
     pub http_gateway_ip: IpAddr,
     pub http_gateway_port: u16,
     pub permanent: bool,
+    // The ring of a federation this Supervisor belongs to
+    pub ring: Option<String>,
+    // Operator supplied tags, gossiped with our membership
+    #[serde(default)]
+    pub tags: BTreeMap<String, String>,
 }
 
 impl Sys {
@@ -66,6 +72,8 @@ impl Sys {
             http_gateway_ip: http.ip(),
             http_gateway_port: http.port(),
             permanent: permanent,
+            ring: None,
+            tags: BTreeMap::new(),
         }
     }
 
@@ -77,6 +85,9 @@ impl Sys {
         sys_info.set_gossip_port(self.gossip_port as u32);
         sys_info.set_http_gateway_ip(self.http_gateway_ip.to_string());
         sys_info.set_http_gateway_port(self.http_gateway_port as u32);
+        if let Some(ref ring) = self.ring {
+            sys_info.set_ring(ring.clone());
+        }
         sys_info
     }
 
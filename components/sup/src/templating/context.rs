@@ -12,11 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use hcore::service::ServiceGroup;
 
-use census::{CensusGroup, CensusMember, CensusRing, ElectionStatus};
+use census::{CensusGroup, CensusMember, CensusRing, ElectionStatus, Shard};
 use manager::Sys;
 use manager::service::{Cfg, Pkg, ServiceBind};
 
@@ -42,6 +42,7 @@ impl<'a> Binds<'a> {
 pub struct BindGroup<'a> {
     pub first: Option<SvcMember<'a>>,
     pub members: Vec<SvcMember<'a>>,
+    pub kv: &'a BTreeMap<String, String>,
 }
 
 impl<'a> BindGroup<'a> {
@@ -49,6 +50,7 @@ impl<'a> BindGroup<'a> {
         BindGroup {
             first: select_first(group),
             members: group.members().iter().map(|m| SvcMember(m)).collect(),
+            kv: &group.kv,
         }
     }
 }
@@ -63,6 +65,8 @@ pub struct RenderContext<'a> {
     pub cfg: &'a Cfg,
     pub svc: Svc<'a>,
     pub bind: Binds<'a>,
+    /// Key/value pairs gossiped to the service's group.
+    pub kv: &'a BTreeMap<String, String>,
 }
 
 impl<'a> RenderContext<'a> {
@@ -86,6 +90,7 @@ impl<'a> RenderContext<'a> {
             cfg: cfg,
             svc: Svc::new(census_group),
             bind: Binds::new(bindings, census),
+            kv: &census_group.kv,
         }
     }
 }
@@ -106,6 +111,7 @@ pub struct Svc<'a> {
     pub members: Vec<SvcMember<'a>>,
     pub leader: Option<SvcMember<'a>>,
     pub update_leader: Option<SvcMember<'a>>,
+    pub shards: Vec<SvcShard<'a>>,
 }
 
 impl<'a> Svc<'a> {
@@ -132,6 +138,39 @@ impl<'a> Svc<'a> {
             leader: census_group.leader().map(|m| SvcMember(m)),
             first: select_first(census_group).expect("First should always be present on svc"),
             update_leader: census_group.update_leader().map(|m| SvcMember(m)),
+            shards: census_group
+                .shards
+                .iter()
+                .map(|s| SvcShard::new(census_group, s))
+                .collect(),
+        }
+    }
+}
+
+/// A shard of a sharded or multi-leader service group. Shards are listed in order, so a member's
+/// own shard can be looked up with `svc.me.shard`.
+#[derive(Clone, Debug, Serialize)]
+pub struct SvcShard<'a> {
+    pub shard: u32,
+    pub leader: Option<SvcMember<'a>>,
+    pub members: Vec<SvcMember<'a>>,
+}
+
+impl<'a> SvcShard<'a> {
+    fn new(census_group: &'a CensusGroup, shard: &'a Shard) -> Self {
+        let members: Vec<SvcMember<'a>> = census_group
+            .shard_members(shard)
+            .into_iter()
+            .map(|m| SvcMember(m))
+            .collect();
+        let leader = match members.first() {
+            Some(m) if shard.leader_id.as_ref() == Some(&m.0.member_id) => Some(m.clone()),
+            _ => None,
+        };
+        SvcShard {
+            shard: shard.shard,
+            leader: leader,
+            members: members,
         }
     }
 }
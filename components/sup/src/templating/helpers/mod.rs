@@ -14,6 +14,7 @@
 
 mod each_alive;
 mod pkg_path_for;
+mod secret;
 mod str_concat;
 mod str_join;
 mod str_replace;
@@ -29,6 +30,7 @@ use serde_json::{self, Value as Json};
 
 pub use self::each_alive::EACH_ALIVE;
 pub use self::pkg_path_for::PKG_PATH_FOR;
+pub use self::secret::SECRET;
 pub use self::str_concat::STR_CONCAT;
 pub use self::str_join::STR_JOIN;
 pub use self::str_replace::STR_REPLACE;
@@ -0,0 +1,44 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use handlebars::{Handlebars, Helper, HelperDef, RenderContext, RenderError};
+
+use super::super::RenderResult;
+use templating::secrets;
+
+#[derive(Clone, Copy)]
+pub struct SecretHelper;
+
+impl HelperDef for SecretHelper {
+    fn call(&self, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult<()> {
+        let path = h.param(0).and_then(|v| v.value().as_str()).ok_or_else(|| {
+            RenderError::new("Expected a secret path for \"secret\"")
+        })?;
+        // Secrets are looked up within the service group being rendered
+        let service_group = {
+            let svc = &rc.context().data()["svc"];
+            match (svc["service"].as_str(), svc["group"].as_str()) {
+                (Some(service), Some(group)) => format!("{}.{}", service, group),
+                _ => return Err(RenderError::new("\"secret\" needs a service to render for")),
+            }
+        };
+        let secret = secrets::get(&service_group, path).map_err(|e| {
+            RenderError::new(format!("Can't read secret {}: {}", path, e))
+        })?;
+        rc.writer.write(secret.into_bytes().as_ref())?;
+        Ok(())
+    }
+}
+
+pub static SECRET: SecretHelper = SecretHelper;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod helpers;
+pub mod secrets;
 mod context;
 
 use std::fmt;
@@ -37,6 +38,7 @@ impl TemplateRenderer {
         let mut handlebars = Handlebars::new();
         handlebars.register_helper("eachAlive", Box::new(helpers::EACH_ALIVE));
         handlebars.register_helper("pkgPathFor", Box::new(helpers::PKG_PATH_FOR));
+        handlebars.register_helper("secret", Box::new(helpers::SECRET));
         handlebars.register_helper("strConcat", Box::new(helpers::STR_CONCAT));
         handlebars.register_helper("strJoin", Box::new(helpers::STR_JOIN));
         handlebars.register_helper("strReplace", Box::new(helpers::STR_REPLACE));
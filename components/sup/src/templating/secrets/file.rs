@@ -0,0 +1,119 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io::{ErrorKind, Read};
+use std::path::{Path, PathBuf};
+
+use hcore::crypto::{BoxKeyPair, default_cache_key_path};
+
+use error::{Error, Result};
+use super::{SecretsProvider, validate_path};
+
+static LOGKEY: &'static str = "SF";
+
+/// Reads secrets from a directory of files encrypted with `BoxKeyPair`, the same encryption used
+/// for gossiped service configuration. A secret's path is the path of its file relative to the
+/// directory, and the keys to decrypt it are looked up in the key cache.
+#[derive(Debug)]
+pub struct FileProvider {
+    root: PathBuf,
+    cache_key_path: PathBuf,
+}
+
+impl FileProvider {
+    pub fn new<P>(root: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        FileProvider {
+            root: root.as_ref().to_path_buf(),
+            cache_key_path: default_cache_key_path(None),
+        }
+    }
+
+    #[cfg(test)]
+    fn with_cache_key_path<P, Q>(root: P, cache_key_path: Q) -> Self
+    where
+        P: AsRef<Path>,
+        Q: AsRef<Path>,
+    {
+        FileProvider {
+            root: root.as_ref().to_path_buf(),
+            cache_key_path: cache_key_path.as_ref().to_path_buf(),
+        }
+    }
+}
+
+impl SecretsProvider for FileProvider {
+    fn get(&self, path: &str) -> Result<String> {
+        validate_path(path)?;
+        let mut payload = Vec::new();
+        File::open(self.root.join(path))
+            .and_then(|mut f| f.read_to_end(&mut payload))
+            .map_err(|e| match e.kind() {
+                ErrorKind::NotFound => sup_error!(Error::SecretNotFound(path.to_string())),
+                _ => sup_error!(Error::Io(e)),
+            })?;
+        let secret = BoxKeyPair::decrypt(&payload, &self.cache_key_path)?;
+        String::from_utf8(secret).map_err(|e| sup_error!(Error::StringFromUtf8Error(e)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::{self, File};
+    use std::io::Write;
+
+    use hcore::crypto::BoxKeyPair;
+    use tempdir::TempDir;
+
+    use super::*;
+    use error::Error;
+
+    #[test]
+    fn get_decrypts_secret() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let store = TempDir::new("secrets").unwrap();
+        let user = BoxKeyPair::generate_pair_for_user("jdoe").unwrap();
+        user.to_pair_files(cache.path()).unwrap();
+        let service = BoxKeyPair::generate_pair_for_service("acme", "redis.default").unwrap();
+        service.to_pair_files(cache.path()).unwrap();
+
+        let payload = user.encrypt(b"hunter2", Some(&service)).unwrap();
+        fs::create_dir_all(store.path().join("redis")).unwrap();
+        File::create(store.path().join("redis/password"))
+            .unwrap()
+            .write_all(&payload)
+            .unwrap();
+
+        let provider = FileProvider::with_cache_key_path(store.path(), cache.path());
+        assert_eq!(provider.get("redis/password").unwrap(), "hunter2");
+    }
+
+    #[test]
+    fn get_missing_secret() {
+        let store = TempDir::new("secrets").unwrap();
+        let provider = FileProvider::new(store.path());
+        match provider.get("redis/password") {
+            Err(e) => {
+                match e.err {
+                    Error::SecretNotFound(path) => assert_eq!(path, "redis/password"),
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("Secret should not be found"),
+        }
+    }
+}
@@ -0,0 +1,304 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Providers of the secrets rendered by the `secret` template helper.
+//!
+//! Secrets are read from the provider for the templates rendering them, so they never become part
+//! of a service's configuration: they aren't gossiped, served by the `/config` endpoint, or logged
+//! along with the render context.
+//!
+//! A service group can only read the secrets under its own `<SERVICE>.<GROUP>` prefix. Secrets
+//! are cached, and a background thread refreshes the cached ones, so renders only wait on the
+//! provider the first time they read a secret.
+
+mod file;
+mod vault;
+
+pub use self::file::FileProvider;
+pub use self::vault::VaultProvider;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "SP";
+/// How long a cached secret is rendered before it has to be read from the provider again.
+const CACHE_TTL_SECS: u64 = 300;
+/// How often the cached secrets are refreshed from the provider.
+const CACHE_REFRESH_SECS: u64 = 60;
+
+lazy_static! {
+    static ref CACHE: RwLock<Option<Arc<SecretCache>>> = RwLock::new(None);
+}
+
+/// A store of secrets, each addressed by a slash separated path.
+pub trait SecretsProvider: fmt::Debug + Send + Sync {
+    /// Returns the secret stored at the given path.
+    fn get(&self, path: &str) -> Result<String>;
+}
+
+/// Which secrets provider the Supervisor renders secrets from, as given on the command line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SecretsConfig {
+    /// Secrets encrypted to a service's box key, one file per secret, under the given directory.
+    File(PathBuf),
+    /// A server speaking the Vault HTTP API at the given URL.
+    Vault(String),
+}
+
+impl SecretsConfig {
+    /// Creates the provider. A Vault provider reads its token from `token`.
+    pub fn provider(&self, token: Option<String>) -> Result<Box<SecretsProvider>> {
+        match *self {
+            SecretsConfig::File(ref path) => Ok(Box::new(FileProvider::new(path))),
+            SecretsConfig::Vault(ref url) => {
+                let token = token.ok_or(sup_error!(Error::SecretsProvider(
+                    String::from("no Vault token set"),
+                )))?;
+                Ok(Box::new(VaultProvider::new(url, token)?))
+            }
+        }
+    }
+}
+
+impl FromStr for SecretsConfig {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let mut parts = value.splitn(2, ':');
+        match (parts.next(), parts.next()) {
+            (Some("file"), Some(path)) if !path.is_empty() => {
+                Ok(SecretsConfig::File(PathBuf::from(path)))
+            }
+            (Some("vault"), Some(url)) if !url.is_empty() => {
+                Ok(SecretsConfig::Vault(url.to_string()))
+            }
+            _ => Err(sup_error!(Error::SecretsProvider(format!(
+                "invalid provider \"{}\", must be file:<PATH> or vault:<URL>",
+                value
+            )))),
+        }
+    }
+}
+
+/// Sets the provider the `secret` template helper reads secrets from, and starts refreshing the
+/// secrets it caches.
+pub fn set_provider(provider: Box<SecretsProvider>) {
+    let cache = Arc::new(SecretCache::new(provider, Duration::from_secs(CACHE_TTL_SECS)));
+    *CACHE.write().expect("Secrets cache lock poisoned") = Some(cache.clone());
+    thread::Builder::new()
+        .name(String::from("secrets-refresh"))
+        .spawn(move || loop {
+            thread::sleep(Duration::from_secs(CACHE_REFRESH_SECS));
+            cache.refresh();
+        })
+        .expect("unable to start secrets-refresh thread");
+}
+
+/// Returns the secret stored at the given path within the secrets of a service group, given as
+/// `<SERVICE>.<GROUP>`.
+pub fn get(service_group: &str, path: &str) -> Result<String> {
+    let cache = CACHE.read().expect("Secrets cache lock poisoned").clone();
+    match cache {
+        Some(cache) => cache.get(&scoped_path(service_group, path)?),
+        None => Err(sup_error!(Error::NoSecretsProvider)),
+    }
+}
+
+/// Secrets read through a provider, each kept along with when it was read from the provider and
+/// when it was last rendered.
+#[derive(Debug)]
+struct SecretCache {
+    provider: Box<SecretsProvider>,
+    ttl: Duration,
+    secrets: Mutex<HashMap<String, CachedSecret>>,
+}
+
+#[derive(Debug)]
+struct CachedSecret {
+    value: String,
+    fetched: Instant,
+    rendered: Instant,
+}
+
+impl SecretCache {
+    fn new(provider: Box<SecretsProvider>, ttl: Duration) -> Self {
+        SecretCache {
+            provider: provider,
+            ttl: ttl,
+            secrets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        if let Some(secret) = self.secrets
+            .lock()
+            .expect("Secrets cache lock poisoned")
+            .get_mut(path)
+        {
+            if secret.fetched.elapsed() < self.ttl {
+                secret.rendered = Instant::now();
+                return Ok(secret.value.clone());
+            }
+        }
+        // The lock isn't held while waiting on the provider
+        let value = self.provider.get(path)?;
+        let now = Instant::now();
+        self.secrets
+            .lock()
+            .expect("Secrets cache lock poisoned")
+            .insert(
+                path.to_string(),
+                CachedSecret {
+                    value: value.clone(),
+                    fetched: now,
+                    rendered: now,
+                },
+            );
+        Ok(value)
+    }
+
+    /// Reads the cached secrets from the provider again, and forgets the ones which weren't
+    /// rendered for longer than the TTL. A secret which can't be read keeps its cached value
+    /// until it expires.
+    fn refresh(&self) {
+        let paths: Vec<String> = {
+            let mut secrets = self.secrets.lock().expect("Secrets cache lock poisoned");
+            let ttl = self.ttl;
+            secrets.retain(|_, secret| secret.rendered.elapsed() < ttl);
+            secrets.keys().cloned().collect()
+        };
+        for path in paths {
+            match self.provider.get(&path) {
+                Ok(value) => {
+                    let mut secrets = self.secrets.lock().expect("Secrets cache lock poisoned");
+                    if let Some(secret) = secrets.get_mut(&path) {
+                        secret.value = value;
+                        secret.fetched = Instant::now();
+                    }
+                }
+                Err(err) => debug!("Unable to refresh secret {}, {}", path, err),
+            }
+        }
+    }
+}
+
+/// Places a secret path under the prefix of a service group, so a service can't read the
+/// secrets of another.
+fn scoped_path(service_group: &str, path: &str) -> Result<String> {
+    validate_path(service_group)?;
+    validate_path(path)?;
+    if service_group.contains('/') {
+        return Err(sup_error!(Error::InvalidSecretPath(service_group.to_string())));
+    }
+    Ok(format!("{}/{}", service_group, path))
+}
+
+/// Secret paths are relative and may not climb out of the store with `..`.
+fn validate_path(path: &str) -> Result<()> {
+    let valid = !path.is_empty() &&
+        Path::new(path).components().all(|c| match c {
+            Component::Normal(_) => true,
+            _ => false,
+        });
+    if valid {
+        Ok(())
+    } else {
+        Err(sup_error!(Error::InvalidSecretPath(path.to_string())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn secrets_config_from_str() {
+        assert_eq!(
+            SecretsConfig::from_str("file:/hab/secrets").unwrap(),
+            SecretsConfig::File(PathBuf::from("/hab/secrets"))
+        );
+        assert_eq!(
+            SecretsConfig::from_str("vault:https://vault.example.com:8200").unwrap(),
+            SecretsConfig::Vault(String::from("https://vault.example.com:8200"))
+        );
+        assert!(SecretsConfig::from_str("vault:").is_err());
+        assert!(SecretsConfig::from_str("keychain:login").is_err());
+    }
+
+    #[derive(Debug, Default)]
+    struct CountingProvider {
+        reads: Mutex<usize>,
+    }
+
+    impl SecretsProvider for CountingProvider {
+        fn get(&self, path: &str) -> Result<String> {
+            let mut reads = self.reads.lock().unwrap();
+            *reads += 1;
+            Ok(format!("{}#{}", path, reads))
+        }
+    }
+
+    #[test]
+    fn scoped_path_prefixes_the_service_group() {
+        assert_eq!(
+            scoped_path("redis.default", "password").unwrap(),
+            "redis.default/password"
+        );
+        assert!(scoped_path("redis.default", "../nginx.default/password").is_err());
+        assert!(scoped_path("redis.default/..", "password").is_err());
+        assert!(scoped_path("", "password").is_err());
+    }
+
+    #[test]
+    fn secret_cache_reads_the_provider_once_per_ttl() {
+        let cache = SecretCache::new(
+            Box::new(CountingProvider::default()),
+            Duration::from_secs(60),
+        );
+
+        assert_eq!(cache.get("redis.default/password").unwrap(), "redis.default/password#1");
+        assert_eq!(cache.get("redis.default/password").unwrap(), "redis.default/password#1");
+        cache.refresh();
+        assert_eq!(cache.get("redis.default/password").unwrap(), "redis.default/password#2");
+    }
+
+    #[test]
+    fn secret_cache_expires_secrets() {
+        let cache = SecretCache::new(Box::new(CountingProvider::default()), Duration::from_secs(0));
+
+        assert_eq!(cache.get("redis.default/password").unwrap(), "redis.default/password#1");
+        assert_eq!(cache.get("redis.default/password").unwrap(), "redis.default/password#2");
+        // Secrets which weren't rendered within the TTL are forgotten rather than refreshed
+        cache.refresh();
+        assert!(cache.secrets.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn validate_path_rejects_escaping_paths() {
+        assert!(validate_path("redis/password").is_ok());
+        assert!(validate_path("").is_err());
+        assert!(validate_path("/etc/shadow").is_err());
+        assert!(validate_path("redis/../../etc/shadow").is_err());
+    }
+}
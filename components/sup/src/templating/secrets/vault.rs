@@ -0,0 +1,180 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::io::Read;
+
+use http_client::ApiClient;
+use hyper::status::StatusCode;
+use serde_json::{self, Value as Json};
+use url::Url;
+
+use error::{Error, Result};
+use super::{SecretsProvider, validate_path};
+use {PRODUCT, VERSION};
+
+static LOGKEY: &'static str = "SV";
+/// The mount of the secrets engine read when the Vault URL doesn't name one.
+const DEFAULT_MOUNT: &'static str = "secret";
+
+header! { (XVaultToken, "X-Vault-Token") => [String] }
+
+/// Reads secrets from the key/value secrets engines of a Vault server.
+///
+/// The path of the Vault URL names the mount of the secrets engine, `secret` when it's empty. The
+/// last component of a secret's path names a key of the Vault secret at the rest of the path
+/// under the mount, so with `https://vault:8200/secret` the `redis.default/password` secret is
+/// the `password` key of the `secret/redis.default` secret. Both versions of the key/value
+/// engine are supported; version 2 is read through its `data` path, e.g.
+/// `https://vault:8200/secret/data`.
+pub struct VaultProvider {
+    client: ApiClient,
+    mount: String,
+    token: String,
+}
+
+impl VaultProvider {
+    pub fn new(url: &str, token: String) -> Result<Self> {
+        let (endpoint, mount) = split_mount(url)?;
+        let client = ApiClient::new(&endpoint, PRODUCT, VERSION, None).map_err(|e| {
+            sup_error!(Error::SecretsProvider(format!("{}", e)))
+        })?;
+        Ok(VaultProvider {
+            client: client,
+            mount: mount,
+            token: token,
+        })
+    }
+}
+
+impl fmt::Debug for VaultProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The token is left out on purpose
+        write!(
+            f,
+            "VaultProvider {{ client: {:?}, mount: {} }}",
+            self.client,
+            self.mount
+        )
+    }
+}
+
+impl SecretsProvider for VaultProvider {
+    fn get(&self, path: &str) -> Result<String> {
+        validate_path(path)?;
+        let (secret, key) = match path.rfind('/') {
+            Some(idx) => (&path[..idx], &path[idx + 1..]),
+            None => return Err(sup_error!(Error::InvalidSecretPath(path.to_string()))),
+        };
+        let mut res = self.client
+            .get(&format!("v1/{}/{}", self.mount, secret))
+            .header(XVaultToken(self.token.clone()))
+            .send()
+            .map_err(|e| sup_error!(Error::SecretsProvider(format!("{}", e))))?;
+        match res.status {
+            StatusCode::Ok => (),
+            StatusCode::NotFound => {
+                return Err(sup_error!(Error::SecretNotFound(path.to_string())))
+            }
+            status => {
+                return Err(sup_error!(Error::SecretsProvider(
+                    format!("Vault responded {} for {}", status, secret),
+                )))
+            }
+        }
+        let mut body = String::new();
+        res.read_to_string(&mut body).map_err(
+            |e| sup_error!(Error::Io(e)),
+        )?;
+        let json: Json = serde_json::from_str(&body).map_err(|_| {
+            sup_error!(Error::SecretsProvider(
+                format!("Vault responded with invalid JSON for {}", secret),
+            ))
+        })?;
+        secret_value(&json, key).ok_or(
+            sup_error!(Error::SecretNotFound(path.to_string())),
+        )
+    }
+}
+
+/// Splits a Vault URL into the URL of the server and the mount of the secrets engine.
+fn split_mount(url: &str) -> Result<(String, String)> {
+    let mut endpoint = Url::parse(url).map_err(|e| {
+        sup_error!(Error::SecretsProvider(format!("{}", e)))
+    })?;
+    let mount = match endpoint.path().trim_matches('/') {
+        "" => DEFAULT_MOUNT.to_string(),
+        mount => mount.to_string(),
+    };
+    endpoint.set_path("");
+    Ok((endpoint.to_string(), mount))
+}
+
+/// Picks a key out of the response to a read of a key/value secret. Version 2 of the key/value
+/// engine nests the secret's data, alongside its metadata, one level deeper than version 1.
+fn secret_value(response: &Json, key: &str) -> Option<String> {
+    let data = &response["data"];
+    let data = if data["data"].is_object() && data["metadata"].is_object() {
+        &data["data"]
+    } else {
+        data
+    };
+    match data[key] {
+        Json::String(ref value) => Some(value.clone()),
+        Json::Null => None,
+        ref value => Some(value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json;
+
+    use super::{secret_value, split_mount};
+
+    #[test]
+    fn split_mount_defaults_to_secret() {
+        assert_eq!(
+            split_mount("https://vault.example.com:8200").unwrap(),
+            (
+                String::from("https://vault.example.com:8200/"),
+                String::from("secret"),
+            )
+        );
+        assert_eq!(
+            split_mount("https://vault.example.com:8200/kv/data/").unwrap(),
+            (
+                String::from("https://vault.example.com:8200/"),
+                String::from("kv/data"),
+            )
+        );
+    }
+
+    #[test]
+    fn secret_value_from_kv_v1() {
+        let response = serde_json::from_str(r#"{"data": {"password": "hunter2", "port": 6379}}"#)
+            .unwrap();
+        assert_eq!(secret_value(&response, "password"), Some(String::from("hunter2")));
+        assert_eq!(secret_value(&response, "port"), Some(String::from("6379")));
+        assert_eq!(secret_value(&response, "user"), None);
+    }
+
+    #[test]
+    fn secret_value_from_kv_v2() {
+        let response = serde_json::from_str(
+            r#"{"data": {"data": {"password": "hunter2"}, "metadata": {"version": 3}}}"#,
+        ).unwrap();
+        assert_eq!(secret_value(&response, "password"), Some(String::from("hunter2")));
+    }
+}